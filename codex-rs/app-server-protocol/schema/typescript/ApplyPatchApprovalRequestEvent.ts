@@ -2,6 +2,7 @@
 
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { FileChange } from "./FileChange";
+import type { PatchHunk } from "./PatchHunk";
 
 export type ApplyPatchApprovalRequestEvent = { 
 /**
@@ -13,6 +14,12 @@ call_id: string,
  * Uses `#[serde(default)]` for backwards compatibility with older senders.
  */
 turn_id: string, changes: { [key in string]?: FileChange }, 
+/**
+ * The patch split into reviewable hunks, in patch order. When present,
+ * the client may answer with `ReviewDecision::ApprovedHunks` to apply
+ * only some of them.
+ */
+hunks: Array<PatchHunk>, 
 /**
  * Optional explanatory reason (e.g. request for extra write access).
  */
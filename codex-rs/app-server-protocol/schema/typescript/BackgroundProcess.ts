@@ -0,0 +1,24 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A process started via `exec_command` that is still tracked by the session.
+ */
+export type BackgroundProcess = { 
+/**
+ * Process id used to address the process in later ops.
+ */
+process_id: string, 
+/**
+ * The command that started the process.
+ */
+command: Array<string>, 
+/**
+ * The working directory the process was started in.
+ */
+cwd: string, 
+/**
+ * Whether the process was started with a TTY and accepts stdin.
+ */
+tty: boolean, };
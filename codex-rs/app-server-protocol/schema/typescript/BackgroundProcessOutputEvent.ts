@@ -0,0 +1,20 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Response payload for `Op::ReadBackgroundProcessOutput`.
+ */
+export type BackgroundProcessOutputEvent = { 
+/**
+ * Id of the process the output belongs to.
+ */
+process_id: string, 
+/**
+ * Retained output of the process, with configured secrets redacted.
+ */
+output: string, 
+/**
+ * Whether the process is still running.
+ */
+running: boolean, };
@@ -0,0 +1,20 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A patch hunk the user chose not to apply.
+ */
+export type DeclinedHunk = { 
+/**
+ * File the hunk belongs to, as in `PatchHunk::path`.
+ */
+path: string, 
+/**
+ * Index of the hunk among the hunks for `path`, as in `PatchHunk::index`.
+ */
+index: number, 
+/**
+ * Optional note from the user explaining the rejection.
+ */
+note?: string, };
@@ -0,0 +1,13 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BackgroundProcess } from "./BackgroundProcess";
+
+/**
+ * Response payload for `Op::ListBackgroundProcesses`.
+ */
+export type ListBackgroundProcessesResponseEvent = { 
+/**
+ * Processes ordered from the oldest to the most recently started.
+ */
+processes: Array<BackgroundProcess>, };
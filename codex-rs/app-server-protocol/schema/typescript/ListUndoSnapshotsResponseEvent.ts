@@ -0,0 +1,17 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { UndoSnapshot } from "./UndoSnapshot";
+
+/**
+ * Response payload for `Op::ListUndoSnapshots`.
+ */
+export type ListUndoSnapshotsResponseEvent = { 
+/**
+ * Snapshots ordered from the most recent turn to the oldest.
+ */
+snapshots: Array<UndoSnapshot>, 
+/**
+ * Whether `Op::Redo` has an undo to reverse.
+ */
+redo_available: boolean, };
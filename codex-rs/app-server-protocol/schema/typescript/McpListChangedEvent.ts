@@ -0,0 +1,14 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { McpListChangedKind } from "./McpListChangedKind";
+
+export type McpListChangedEvent = { 
+/**
+ * Server that sent the `list_changed` notification.
+ */
+server: string, 
+/**
+ * Which list changed. Tool changes take effect from the next turn.
+ */
+kind: McpListChangedKind, };
@@ -9,4 +9,36 @@ export type ParsedCommand = { "type": "read", cmd: string, name: string,
  * be resolved against the `cwd`` that will be used to run the command
  * to derive the absolute path.
  */
-path: string, } | { "type": "list_files", cmd: string, path: string | null, } | { "type": "search", cmd: string, query: string | null, path: string | null, } | { "type": "unknown", cmd: string, };
+path: string, } | { "type": "list_files", cmd: string, path: string | null, } | { "type": "search", cmd: string, query: string | null, path: string | null, } | { "type": "test", cmd: string, 
+/**
+ * The test runner, e.g. `cargo`, `pytest` or `jest`.
+ */
+runner: string, 
+/**
+ * Packages, files or name filters selecting the tests to run.
+ */
+targets: Array<string>, } | { "type": "build", cmd: string, 
+/**
+ * The build tool, e.g. `cargo`, `make` or `npm`.
+ */
+tool: string, 
+/**
+ * Packages or targets being built; empty for the default target.
+ */
+targets: Array<string>, } | { "type": "install", cmd: string, 
+/**
+ * The package manager, e.g. `npm`, `pip` or `cargo`.
+ */
+manager: string, 
+/**
+ * Packages being installed; empty when installing from a manifest.
+ */
+packages: Array<string>, } | { "type": "write", cmd: string, paths: Array<string>, } | { "type": "move", cmd: string, paths: Array<string>, } | { "type": "delete", cmd: string, paths: Array<string>, } | { "type": "git_mutation", cmd: string, 
+/**
+ * The git subcommand, e.g. `commit` or `push`.
+ */
+subcommand: string, 
+/**
+ * Positional arguments such as remotes, branches, commits or paths.
+ */
+targets: Array<string>, } | { "type": "fetch", cmd: string, url: string | null, } | { "type": "unknown", cmd: string, };
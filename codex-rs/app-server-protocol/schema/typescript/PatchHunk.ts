@@ -0,0 +1,22 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { FileChange } from "./FileChange";
+
+/**
+ * One independently reviewable piece of a patch: an added or deleted file,
+ * a single `@@` chunk of an updated file, or a pure rename.
+ */
+export type PatchHunk = { 
+/**
+ * File the hunk belongs to (the path before any move).
+ */
+path: string, 
+/**
+ * 0-based position of the hunk among the hunks for `path`.
+ */
+index: number, 
+/**
+ * The change this hunk alone would make.
+ */
+change: FileChange, };
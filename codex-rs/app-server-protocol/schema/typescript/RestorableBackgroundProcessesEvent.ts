@@ -0,0 +1,14 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BackgroundProcess } from "./BackgroundProcess";
+
+/**
+ * Payload for `EventMsg::RestorableBackgroundProcesses`.
+ */
+export type RestorableBackgroundProcessesEvent = { 
+/**
+ * Processes ordered from the oldest to the most recently started, with
+ * the ids they had in the previous session.
+ */
+processes: Array<BackgroundProcess>, };
@@ -6,4 +6,8 @@ export type ReviewTarget = { "type": "uncommittedChanges" } | { "type": "baseBra
 /**
  * Optional human-readable label (e.g., commit subject) for UIs.
  */
-title: string | null, } | { "type": "custom", instructions: string, };
+title: string | null, } | { "type": "commitRange", from: string, to: string, } | { "type": "patchFile", 
+/**
+ * Path to the patch, resolved against the session cwd when relative.
+ */
+path: string, } | { "type": "custom", instructions: string, };
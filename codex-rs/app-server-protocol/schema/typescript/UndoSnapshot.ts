@@ -0,0 +1,33 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A working tree snapshot taken before a turn.
+ */
+export type UndoSnapshot = { 
+/**
+ * Ghost commit id of the snapshot.
+ */
+id: string, 
+/**
+ * Message that started the turn the snapshot was taken for.
+ */
+user_message: string | null, 
+/**
+ * Number of user turns made since the snapshot, including its own.
+ */
+turns: number, 
+/**
+ * Files that restoring the snapshot changes, relative to the session cwd.
+ */
+changed_files: Array<string>, 
+/**
+ * Unified diff of the changes that restoring the snapshot reverts.
+ */
+unified_diff: string, 
+/**
+ * Changed files modified outside Codex since the last turn finished.
+ * Restoring requires `force` while this is non-empty.
+ */
+conflicts: Array<string>, };
@@ -23,6 +23,8 @@ export type { AskForApproval } from "./AskForApproval";
 export type { AuthMode } from "./AuthMode";
 export type { AuthStatusChangeNotification } from "./AuthStatusChangeNotification";
 export type { BackgroundEventEvent } from "./BackgroundEventEvent";
+export type { BackgroundProcess } from "./BackgroundProcess";
+export type { BackgroundProcessOutputEvent } from "./BackgroundProcessOutputEvent";
 export type { ByteRange } from "./ByteRange";
 export type { CallToolResult } from "./CallToolResult";
 export type { CancelLoginChatGptParams } from "./CancelLoginChatGptParams";
@@ -48,6 +50,7 @@ export type { ConversationGitInfo } from "./ConversationGitInfo";
 export type { ConversationSummary } from "./ConversationSummary";
 export type { CreditsSnapshot } from "./CreditsSnapshot";
 export type { CustomPrompt } from "./CustomPrompt";
+export type { DeclinedHunk } from "./DeclinedHunk";
 export type { DeprecationNoticeEvent } from "./DeprecationNoticeEvent";
 export type { DynamicToolCallRequest } from "./DynamicToolCallRequest";
 export type { ElicitationRequestEvent } from "./ElicitationRequestEvent";
@@ -96,11 +99,13 @@ export type { InterruptConversationParams } from "./InterruptConversationParams"
 export type { InterruptConversationResponse } from "./InterruptConversationResponse";
 export type { ItemCompletedEvent } from "./ItemCompletedEvent";
 export type { ItemStartedEvent } from "./ItemStartedEvent";
+export type { ListBackgroundProcessesResponseEvent } from "./ListBackgroundProcessesResponseEvent";
 export type { ListConversationsParams } from "./ListConversationsParams";
 export type { ListConversationsResponse } from "./ListConversationsResponse";
 export type { ListCustomPromptsResponseEvent } from "./ListCustomPromptsResponseEvent";
 export type { ListRemoteSkillsResponseEvent } from "./ListRemoteSkillsResponseEvent";
 export type { ListSkillsResponseEvent } from "./ListSkillsResponseEvent";
+export type { ListUndoSnapshotsResponseEvent } from "./ListUndoSnapshotsResponseEvent";
 export type { LocalShellAction } from "./LocalShellAction";
 export type { LocalShellExecAction } from "./LocalShellExecAction";
 export type { LocalShellStatus } from "./LocalShellStatus";
@@ -111,6 +116,8 @@ export type { LoginChatGptResponse } from "./LoginChatGptResponse";
 export type { LogoutChatGptResponse } from "./LogoutChatGptResponse";
 export type { McpAuthStatus } from "./McpAuthStatus";
 export type { McpInvocation } from "./McpInvocation";
+export type { McpListChangedEvent } from "./McpListChangedEvent";
+export type { McpListChangedKind } from "./McpListChangedKind";
 export type { McpListToolsResponseEvent } from "./McpListToolsResponseEvent";
 export type { McpStartupCompleteEvent } from "./McpStartupCompleteEvent";
 export type { McpStartupFailure } from "./McpStartupFailure";
@@ -126,6 +133,7 @@ export type { NewConversationResponse } from "./NewConversationResponse";
 export type { ParsedCommand } from "./ParsedCommand";
 export type { PatchApplyBeginEvent } from "./PatchApplyBeginEvent";
 export type { PatchApplyEndEvent } from "./PatchApplyEndEvent";
+export type { PatchHunk } from "./PatchHunk";
 export type { Personality } from "./Personality";
 export type { PlanDeltaEvent } from "./PlanDeltaEvent";
 export type { PlanItem } from "./PlanItem";
@@ -153,6 +161,7 @@ export type { RequestUserInputQuestionOption } from "./RequestUserInputQuestionO
 export type { Resource } from "./Resource";
 export type { ResourceTemplate } from "./ResourceTemplate";
 export type { ResponseItem } from "./ResponseItem";
+export type { RestorableBackgroundProcessesEvent } from "./RestorableBackgroundProcessesEvent";
 export type { ResumeConversationParams } from "./ResumeConversationParams";
 export type { ResumeConversationResponse } from "./ResumeConversationResponse";
 export type { ReviewCodeLocation } from "./ReviewCodeLocation";
@@ -204,6 +213,7 @@ export type { TurnDiffEvent } from "./TurnDiffEvent";
 export type { TurnItem } from "./TurnItem";
 export type { TurnStartedEvent } from "./TurnStartedEvent";
 export type { UndoCompletedEvent } from "./UndoCompletedEvent";
+export type { UndoSnapshot } from "./UndoSnapshot";
 export type { UndoStartedEvent } from "./UndoStartedEvent";
 export type { UpdatePlanArgs } from "./UpdatePlanArgs";
 export type { UserInfoResponse } from "./UserInfoResponse";
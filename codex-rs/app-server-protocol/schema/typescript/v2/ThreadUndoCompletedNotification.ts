@@ -0,0 +1,8 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Outcome of a `thread/undo/start` or `thread/redo/start` request.
+ */
+export type ThreadUndoCompletedNotification = { threadId: string, success: boolean, message: string | null, };
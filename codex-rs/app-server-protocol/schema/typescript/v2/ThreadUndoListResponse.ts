@@ -0,0 +1,14 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { UndoSnapshot } from "./UndoSnapshot";
+
+export type ThreadUndoListResponse = { 
+/**
+ * Snapshots ordered from the most recent turn to the oldest.
+ */
+data: Array<UndoSnapshot>, 
+/**
+ * Whether `thread/redo/start` has an undo to reverse.
+ */
+redoAvailable: boolean, };
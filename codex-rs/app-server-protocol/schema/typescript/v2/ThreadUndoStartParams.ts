@@ -0,0 +1,13 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ThreadUndoStartParams = { threadId: string, 
+/**
+ * Snapshot to restore, from `thread/undo/list`. Defaults to the most recent one.
+ */
+snapshotId?: string | null, 
+/**
+ * Restore even when affected files changed outside Codex since the last turn.
+ */
+force: boolean, };
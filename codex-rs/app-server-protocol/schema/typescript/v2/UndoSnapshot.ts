@@ -0,0 +1,29 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Working tree snapshot taken before a turn that `thread/undo/start` can restore.
+ */
+export type UndoSnapshot = { id: string, 
+/**
+ * Message that started the turn the snapshot was taken for.
+ */
+userMessage: string | null, 
+/**
+ * Number of turns whose file changes restoring the snapshot reverts.
+ */
+turns: number, 
+/**
+ * Files that restoring the snapshot changes, relative to the thread cwd.
+ */
+changedFiles: Array<string>, 
+/**
+ * Unified diff of the changes that restoring the snapshot reverts.
+ */
+unifiedDiff: string, 
+/**
+ * Changed files modified outside Codex since the last turn finished.
+ * Restoring requires `force` while this is non-empty.
+ */
+conflicts: Array<string>, };
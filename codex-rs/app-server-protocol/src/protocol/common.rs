@@ -216,6 +216,18 @@ client_request_definitions! {
         params: v2::ThreadRollbackParams,
         response: v2::ThreadRollbackResponse,
     },
+    ThreadUndoList => "thread/undo/list" {
+        params: v2::ThreadUndoListParams,
+        response: v2::ThreadUndoListResponse,
+    },
+    ThreadUndoStart => "thread/undo/start" {
+        params: v2::ThreadUndoStartParams,
+        response: v2::ThreadUndoStartResponse,
+    },
+    ThreadRedoStart => "thread/redo/start" {
+        params: v2::ThreadRedoStartParams,
+        response: v2::ThreadRedoStartResponse,
+    },
     ThreadList => "thread/list" {
         params: v2::ThreadListParams,
         response: v2::ThreadListResponse,
@@ -722,6 +734,7 @@ server_notification_definitions! {
     ReasoningTextDelta => "item/reasoning/textDelta" (v2::ReasoningTextDeltaNotification),
     /// Deprecated: Use `ContextCompaction` item type instead.
     ContextCompacted => "thread/compacted" (v2::ContextCompactedNotification),
+    ThreadUndoCompleted => "thread/undo/completed" (v2::ThreadUndoCompletedNotification),
     DeprecationNotice => "deprecationNotice" (v2::DeprecationNoticeNotification),
     ConfigWarning => "configWarning" (v2::ConfigWarningNotification),
 
@@ -42,6 +42,7 @@ use codex_protocol::protocol::SkillToolDependency as CoreSkillToolDependency;
 use codex_protocol::protocol::SubAgentSource as CoreSubAgentSource;
 use codex_protocol::protocol::TokenUsage as CoreTokenUsage;
 use codex_protocol::protocol::TokenUsageInfo as CoreTokenUsageInfo;
+use codex_protocol::protocol::UndoSnapshot as CoreUndoSnapshot;
 use codex_protocol::user_input::ByteRange as CoreByteRange;
 use codex_protocol::user_input::TextElement as CoreTextElement;
 use codex_protocol::user_input::UserInput as CoreUserInput;
@@ -723,6 +724,42 @@ pub enum CommandAction {
         query: Option<String>,
         path: Option<String>,
     },
+    Test {
+        command: String,
+        runner: String,
+        targets: Vec<String>,
+    },
+    Build {
+        command: String,
+        tool: String,
+        targets: Vec<String>,
+    },
+    Install {
+        command: String,
+        manager: String,
+        packages: Vec<String>,
+    },
+    Write {
+        command: String,
+        paths: Vec<String>,
+    },
+    Move {
+        command: String,
+        paths: Vec<String>,
+    },
+    Delete {
+        command: String,
+        paths: Vec<String>,
+    },
+    GitMutation {
+        command: String,
+        subcommand: String,
+        targets: Vec<String>,
+    },
+    Fetch {
+        command: String,
+        url: Option<String>,
+    },
     Unknown {
         command: String,
     },
@@ -796,6 +833,51 @@ impl CommandAction {
                 query,
                 path,
             } => CoreParsedCommand::Search { cmd, query, path },
+            CommandAction::Test {
+                command: cmd,
+                runner,
+                targets,
+            } => CoreParsedCommand::Test {
+                cmd,
+                runner,
+                targets,
+            },
+            CommandAction::Build {
+                command: cmd,
+                tool,
+                targets,
+            } => CoreParsedCommand::Build { cmd, tool, targets },
+            CommandAction::Install {
+                command: cmd,
+                manager,
+                packages,
+            } => CoreParsedCommand::Install {
+                cmd,
+                manager,
+                packages,
+            },
+            CommandAction::Write {
+                command: cmd,
+                paths,
+            } => CoreParsedCommand::Write { cmd, paths },
+            CommandAction::Move {
+                command: cmd,
+                paths,
+            } => CoreParsedCommand::Move { cmd, paths },
+            CommandAction::Delete {
+                command: cmd,
+                paths,
+            } => CoreParsedCommand::Delete { cmd, paths },
+            CommandAction::GitMutation {
+                command: cmd,
+                subcommand,
+                targets,
+            } => CoreParsedCommand::GitMutation {
+                cmd,
+                subcommand,
+                targets,
+            },
+            CommandAction::Fetch { command: cmd, url } => CoreParsedCommand::Fetch { cmd, url },
             CommandAction::Unknown { command: cmd } => CoreParsedCommand::Unknown { cmd },
         }
     }
@@ -817,6 +899,51 @@ impl From<CoreParsedCommand> for CommandAction {
                 query,
                 path,
             },
+            CoreParsedCommand::Test {
+                cmd,
+                runner,
+                targets,
+            } => CommandAction::Test {
+                command: cmd,
+                runner,
+                targets,
+            },
+            CoreParsedCommand::Build { cmd, tool, targets } => CommandAction::Build {
+                command: cmd,
+                tool,
+                targets,
+            },
+            CoreParsedCommand::Install {
+                cmd,
+                manager,
+                packages,
+            } => CommandAction::Install {
+                command: cmd,
+                manager,
+                packages,
+            },
+            CoreParsedCommand::Write { cmd, paths } => CommandAction::Write {
+                command: cmd,
+                paths,
+            },
+            CoreParsedCommand::Move { cmd, paths } => CommandAction::Move {
+                command: cmd,
+                paths,
+            },
+            CoreParsedCommand::Delete { cmd, paths } => CommandAction::Delete {
+                command: cmd,
+                paths,
+            },
+            CoreParsedCommand::GitMutation {
+                cmd,
+                subcommand,
+                targets,
+            } => CommandAction::GitMutation {
+                command: cmd,
+                subcommand,
+                targets,
+            },
+            CoreParsedCommand::Fetch { cmd, url } => CommandAction::Fetch { command: cmd, url },
             CoreParsedCommand::Unknown { cmd } => CommandAction::Unknown { command: cmd },
         }
     }
@@ -1506,6 +1633,85 @@ pub struct ThreadRollbackResponse {
     pub thread: Thread,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct ThreadUndoListParams {
+    pub thread_id: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct ThreadUndoListResponse {
+    /// Snapshots ordered from the most recent turn to the oldest.
+    pub data: Vec<UndoSnapshot>,
+    /// Whether `thread/redo/start` has an undo to reverse.
+    pub redo_available: bool,
+}
+
+/// Working tree snapshot taken before a turn that `thread/undo/start` can restore.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct UndoSnapshot {
+    pub id: String,
+    /// Message that started the turn the snapshot was taken for.
+    pub user_message: Option<String>,
+    /// Number of turns whose file changes restoring the snapshot reverts.
+    pub turns: u32,
+    /// Files that restoring the snapshot changes, relative to the thread cwd.
+    pub changed_files: Vec<PathBuf>,
+    /// Unified diff of the changes that restoring the snapshot reverts.
+    pub unified_diff: String,
+    /// Changed files modified outside Codex since the last turn finished.
+    /// Restoring requires `force` while this is non-empty.
+    pub conflicts: Vec<PathBuf>,
+}
+
+impl From<CoreUndoSnapshot> for UndoSnapshot {
+    fn from(value: CoreUndoSnapshot) -> Self {
+        Self {
+            id: value.id,
+            user_message: value.user_message,
+            turns: value.turns,
+            changed_files: value.changed_files,
+            unified_diff: value.unified_diff,
+            conflicts: value.conflicts,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct ThreadUndoStartParams {
+    pub thread_id: String,
+    /// Snapshot to restore, from `thread/undo/list`. Defaults to the most recent one.
+    #[ts(optional = nullable)]
+    pub snapshot_id: Option<String>,
+    /// Restore even when affected files changed outside Codex since the last turn.
+    #[serde(default)]
+    pub force: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct ThreadUndoStartResponse {}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct ThreadRedoStartParams {
+    pub thread_id: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct ThreadRedoStartResponse {}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
 #[serde(rename_all = "camelCase")]
 #[ts(export_to = "v2/")]
@@ -2080,6 +2286,19 @@ pub enum ReviewTarget {
         title: Option<String>,
     },
 
+    /// Review the changes between two commits, equivalent to `git diff from..to`.
+    #[serde(rename_all = "camelCase")]
+    #[ts(rename_all = "camelCase")]
+    CommitRange { from: String, to: String },
+
+    /// Review the changes described by a patch file on disk.
+    #[serde(rename_all = "camelCase")]
+    #[ts(rename_all = "camelCase")]
+    PatchFile {
+        /// Path to the patch, resolved against the session cwd when relative.
+        path: PathBuf,
+    },
+
     /// Arbitrary instructions, equivalent to the old free-form prompt.
     #[serde(rename_all = "camelCase")]
     #[ts(rename_all = "camelCase")]
@@ -2835,6 +3054,16 @@ pub struct ContextCompactedNotification {
     pub turn_id: String,
 }
 
+/// Outcome of a `thread/undo/start` or `thread/redo/start` request.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct ThreadUndoCompletedNotification {
+    pub thread_id: String,
+    pub success: bool,
+    pub message: Option<String>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
 #[serde(rename_all = "camelCase")]
 #[ts(export_to = "v2/")]
@@ -57,6 +57,7 @@ use codex_app_server_protocol::ThreadNameUpdatedNotification;
 use codex_app_server_protocol::ThreadRollbackResponse;
 use codex_app_server_protocol::ThreadTokenUsage;
 use codex_app_server_protocol::ThreadTokenUsageUpdatedNotification;
+use codex_app_server_protocol::ThreadUndoCompletedNotification;
 use codex_app_server_protocol::ToolRequestUserInputOption;
 use codex_app_server_protocol::ToolRequestUserInputParams;
 use codex_app_server_protocol::ToolRequestUserInputQuestion;
@@ -134,6 +135,7 @@ pub(crate) async fn apply_bespoke_event_handling(
             call_id,
             turn_id,
             changes,
+            hunks: _,
             reason,
             grant_root,
         }) => match api_version {
@@ -629,6 +631,16 @@ pub(crate) async fn apply_bespoke_event_handling(
                 .send_server_notification(ServerNotification::ContextCompacted(notification))
                 .await;
         }
+        EventMsg::UndoCompleted(event) => {
+            let notification = ThreadUndoCompletedNotification {
+                thread_id: conversation_id.to_string(),
+                success: event.success,
+                message: event.message,
+            };
+            outgoing
+                .send_server_notification(ServerNotification::ThreadUndoCompleted(notification))
+                .await;
+        }
         EventMsg::DeprecationNotice(event) => {
             let notification = DeprecationNoticeNotification {
                 summary: event.summary,
@@ -120,6 +120,8 @@ use codex_app_server_protocol::ThreadLoadedListParams;
 use codex_app_server_protocol::ThreadLoadedListResponse;
 use codex_app_server_protocol::ThreadReadParams;
 use codex_app_server_protocol::ThreadReadResponse;
+use codex_app_server_protocol::ThreadRedoStartParams;
+use codex_app_server_protocol::ThreadRedoStartResponse;
 use codex_app_server_protocol::ThreadResumeParams;
 use codex_app_server_protocol::ThreadResumeResponse;
 use codex_app_server_protocol::ThreadRollbackParams;
@@ -132,6 +134,10 @@ use codex_app_server_protocol::ThreadStartResponse;
 use codex_app_server_protocol::ThreadStartedNotification;
 use codex_app_server_protocol::ThreadUnarchiveParams;
 use codex_app_server_protocol::ThreadUnarchiveResponse;
+use codex_app_server_protocol::ThreadUndoListParams;
+use codex_app_server_protocol::ThreadUndoListResponse;
+use codex_app_server_protocol::ThreadUndoStartParams;
+use codex_app_server_protocol::ThreadUndoStartResponse;
 use codex_app_server_protocol::Turn;
 use codex_app_server_protocol::TurnError;
 use codex_app_server_protocol::TurnInterruptParams;
@@ -427,6 +433,20 @@ impl CodexMessageProcessor {
                     .filter(|t| !t.is_empty());
                 ApiReviewTarget::Commit { sha, title }
             }
+            ApiReviewTarget::CommitRange { from, to } => {
+                let from = from.trim().to_string();
+                let to = to.trim().to_string();
+                if from.is_empty() || to.is_empty() {
+                    return Err(invalid_request("from and to must not be empty".to_string()));
+                }
+                ApiReviewTarget::CommitRange { from, to }
+            }
+            ApiReviewTarget::PatchFile { path } => {
+                if path.as_os_str().is_empty() {
+                    return Err(invalid_request("path must not be empty".to_string()));
+                }
+                ApiReviewTarget::PatchFile { path }
+            }
             ApiReviewTarget::Custom { instructions } => {
                 let trimmed = instructions.trim().to_string();
                 if trimmed.is_empty() {
@@ -444,6 +464,8 @@ impl CodexMessageProcessor {
             ApiReviewTarget::UncommittedChanges => CoreReviewTarget::UncommittedChanges,
             ApiReviewTarget::BaseBranch { branch } => CoreReviewTarget::BaseBranch { branch },
             ApiReviewTarget::Commit { sha, title } => CoreReviewTarget::Commit { sha, title },
+            ApiReviewTarget::CommitRange { from, to } => CoreReviewTarget::CommitRange { from, to },
+            ApiReviewTarget::PatchFile { path } => CoreReviewTarget::PatchFile { path },
             ApiReviewTarget::Custom { instructions } => CoreReviewTarget::Custom { instructions },
         };
 
@@ -499,6 +521,18 @@ impl CodexMessageProcessor {
                 self.thread_rollback(to_connection_request_id(request_id), params)
                     .await;
             }
+            ClientRequest::ThreadUndoList { request_id, params } => {
+                self.thread_undo_list(to_connection_request_id(request_id), params)
+                    .await;
+            }
+            ClientRequest::ThreadUndoStart { request_id, params } => {
+                self.thread_undo_start(to_connection_request_id(request_id), params)
+                    .await;
+            }
+            ClientRequest::ThreadRedoStart { request_id, params } => {
+                self.thread_redo_start(to_connection_request_id(request_id), params)
+                    .await;
+            }
             ClientRequest::ThreadList { request_id, params } => {
                 self.thread_list(to_connection_request_id(request_id), params)
                     .await;
@@ -2298,6 +2332,97 @@ impl CodexMessageProcessor {
         }
     }
 
+    async fn thread_undo_list(
+        &self,
+        request_id: ConnectionRequestId,
+        params: ThreadUndoListParams,
+    ) {
+        let ThreadUndoListParams { thread_id } = params;
+
+        let (_, thread) = match self.load_thread(&thread_id).await {
+            Ok(v) => v,
+            Err(error) => {
+                self.outgoing.send_error(request_id, error).await;
+                return;
+            }
+        };
+
+        let snapshots = thread.list_undo_snapshots().await;
+        self.outgoing
+            .send_response(
+                request_id,
+                ThreadUndoListResponse {
+                    data: snapshots.snapshots.into_iter().map(Into::into).collect(),
+                    redo_available: snapshots.redo_available,
+                },
+            )
+            .await;
+    }
+
+    async fn thread_undo_start(
+        &self,
+        request_id: ConnectionRequestId,
+        params: ThreadUndoStartParams,
+    ) {
+        let ThreadUndoStartParams {
+            thread_id,
+            snapshot_id,
+            force,
+        } = params;
+
+        let (_, thread) = match self.load_thread(&thread_id).await {
+            Ok(v) => v,
+            Err(error) => {
+                self.outgoing.send_error(request_id, error).await;
+                return;
+            }
+        };
+
+        let op = match snapshot_id {
+            Some(snapshot_id) => Op::UndoTo { snapshot_id, force },
+            None => Op::Undo,
+        };
+        match thread.submit(op).await {
+            Ok(_) => {
+                self.outgoing
+                    .send_response(request_id, ThreadUndoStartResponse {})
+                    .await;
+            }
+            Err(err) => {
+                self.send_internal_error(request_id, format!("failed to start undo: {err}"))
+                    .await;
+            }
+        }
+    }
+
+    async fn thread_redo_start(
+        &self,
+        request_id: ConnectionRequestId,
+        params: ThreadRedoStartParams,
+    ) {
+        let ThreadRedoStartParams { thread_id } = params;
+
+        let (_, thread) = match self.load_thread(&thread_id).await {
+            Ok(v) => v,
+            Err(error) => {
+                self.outgoing.send_error(request_id, error).await;
+                return;
+            }
+        };
+
+        match thread.submit(Op::Redo).await {
+            Ok(_) => {
+                self.outgoing
+                    .send_response(request_id, ThreadRedoStartResponse {})
+                    .await;
+            }
+            Err(err) => {
+                self.send_internal_error(request_id, format!("failed to start redo: {err}"))
+                    .await;
+            }
+        }
+    }
+
     async fn thread_list(&self, request_id: ConnectionRequestId, params: ThreadListParams) {
         let ThreadListParams {
             cursor,
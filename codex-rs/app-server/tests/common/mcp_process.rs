@@ -56,10 +56,13 @@ use codex_app_server_protocol::ThreadForkParams;
 use codex_app_server_protocol::ThreadListParams;
 use codex_app_server_protocol::ThreadLoadedListParams;
 use codex_app_server_protocol::ThreadReadParams;
+use codex_app_server_protocol::ThreadRedoStartParams;
 use codex_app_server_protocol::ThreadResumeParams;
 use codex_app_server_protocol::ThreadRollbackParams;
 use codex_app_server_protocol::ThreadStartParams;
 use codex_app_server_protocol::ThreadUnarchiveParams;
+use codex_app_server_protocol::ThreadUndoListParams;
+use codex_app_server_protocol::ThreadUndoStartParams;
 use codex_app_server_protocol::TurnInterruptParams;
 use codex_app_server_protocol::TurnStartParams;
 use codex_app_server_protocol::TurnSteerParams;
@@ -439,6 +442,33 @@ impl McpProcess {
         self.send_request("thread/rollback", params).await
     }
 
+    /// Send a `thread/undo/list` JSON-RPC request.
+    pub async fn send_thread_undo_list_request(
+        &mut self,
+        params: ThreadUndoListParams,
+    ) -> anyhow::Result<i64> {
+        let params = Some(serde_json::to_value(params)?);
+        self.send_request("thread/undo/list", params).await
+    }
+
+    /// Send a `thread/undo/start` JSON-RPC request.
+    pub async fn send_thread_undo_start_request(
+        &mut self,
+        params: ThreadUndoStartParams,
+    ) -> anyhow::Result<i64> {
+        let params = Some(serde_json::to_value(params)?);
+        self.send_request("thread/undo/start", params).await
+    }
+
+    /// Send a `thread/redo/start` JSON-RPC request.
+    pub async fn send_thread_redo_start_request(
+        &mut self,
+        params: ThreadRedoStartParams,
+    ) -> anyhow::Result<i64> {
+        let params = Some(serde_json::to_value(params)?);
+        self.send_request("thread/redo/start", params).await
+    }
+
     /// Send a `thread/list` JSON-RPC request.
     pub async fn send_thread_list_request(
         &mut self,
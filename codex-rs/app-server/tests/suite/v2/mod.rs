@@ -24,6 +24,7 @@ mod thread_resume;
 mod thread_rollback;
 mod thread_start;
 mod thread_unarchive;
+mod thread_undo;
 mod turn_interrupt;
 mod turn_start;
 mod turn_steer;
@@ -0,0 +1,160 @@
+use anyhow::Result;
+use app_test_support::McpProcess;
+use app_test_support::create_mock_responses_server_repeating_assistant;
+use app_test_support::to_response;
+use codex_app_server_protocol::JSONRPCNotification;
+use codex_app_server_protocol::JSONRPCResponse;
+use codex_app_server_protocol::RequestId;
+use codex_app_server_protocol::ThreadRedoStartParams;
+use codex_app_server_protocol::ThreadRedoStartResponse;
+use codex_app_server_protocol::ThreadStartParams;
+use codex_app_server_protocol::ThreadStartResponse;
+use codex_app_server_protocol::ThreadUndoCompletedNotification;
+use codex_app_server_protocol::ThreadUndoListParams;
+use codex_app_server_protocol::ThreadUndoListResponse;
+use codex_app_server_protocol::ThreadUndoStartParams;
+use codex_app_server_protocol::ThreadUndoStartResponse;
+use pretty_assertions::assert_eq;
+use std::path::Path;
+use tempfile::TempDir;
+use tokio::time::timeout;
+
+const DEFAULT_READ_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+#[tokio::test]
+async fn thread_undo_list_is_empty_before_any_turn() -> Result<()> {
+    let server = create_mock_responses_server_repeating_assistant("Done").await;
+    let codex_home = TempDir::new()?;
+    create_config_toml(codex_home.path(), &server.uri())?;
+
+    let mut mcp = McpProcess::new(codex_home.path()).await?;
+    timeout(DEFAULT_READ_TIMEOUT, mcp.initialize()).await??;
+
+    let thread_id = start_thread(&mut mcp).await?;
+
+    let list_id = mcp
+        .send_thread_undo_list_request(ThreadUndoListParams { thread_id })
+        .await?;
+    let resp: JSONRPCResponse = timeout(
+        DEFAULT_READ_TIMEOUT,
+        mcp.read_stream_until_response_message(RequestId::Integer(list_id)),
+    )
+    .await??;
+    let ThreadUndoListResponse {
+        data,
+        redo_available,
+    } = to_response::<ThreadUndoListResponse>(resp)?;
+    assert_eq!(data, Vec::new());
+    assert!(!redo_available);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn thread_undo_and_redo_report_completion() -> Result<()> {
+    let server = create_mock_responses_server_repeating_assistant("Done").await;
+    let codex_home = TempDir::new()?;
+    create_config_toml(codex_home.path(), &server.uri())?;
+
+    let mut mcp = McpProcess::new(codex_home.path()).await?;
+    timeout(DEFAULT_READ_TIMEOUT, mcp.initialize()).await??;
+
+    let thread_id = start_thread(&mut mcp).await?;
+
+    let undo_id = mcp
+        .send_thread_undo_start_request(ThreadUndoStartParams {
+            thread_id: thread_id.clone(),
+            snapshot_id: Some("0123456789abcdef".to_string()),
+            force: false,
+        })
+        .await?;
+    let resp: JSONRPCResponse = timeout(
+        DEFAULT_READ_TIMEOUT,
+        mcp.read_stream_until_response_message(RequestId::Integer(undo_id)),
+    )
+    .await??;
+    let _: ThreadUndoStartResponse = to_response::<ThreadUndoStartResponse>(resp)?;
+    let completed = read_undo_completed(&mut mcp).await?;
+    assert_eq!(
+        completed,
+        ThreadUndoCompletedNotification {
+            thread_id: thread_id.clone(),
+            success: false,
+            message: Some("Snapshot 0123456 is no longer available to undo.".to_string()),
+        }
+    );
+
+    let redo_id = mcp
+        .send_thread_redo_start_request(ThreadRedoStartParams {
+            thread_id: thread_id.clone(),
+        })
+        .await?;
+    let resp: JSONRPCResponse = timeout(
+        DEFAULT_READ_TIMEOUT,
+        mcp.read_stream_until_response_message(RequestId::Integer(redo_id)),
+    )
+    .await??;
+    let _: ThreadRedoStartResponse = to_response::<ThreadRedoStartResponse>(resp)?;
+    let completed = read_undo_completed(&mut mcp).await?;
+    assert_eq!(
+        completed,
+        ThreadUndoCompletedNotification {
+            thread_id,
+            success: false,
+            message: Some("No undo available to redo.".to_string()),
+        }
+    );
+
+    Ok(())
+}
+
+async fn read_undo_completed(mcp: &mut McpProcess) -> Result<ThreadUndoCompletedNotification> {
+    let notification: JSONRPCNotification = timeout(
+        DEFAULT_READ_TIMEOUT,
+        mcp.read_stream_until_notification_message("thread/undo/completed"),
+    )
+    .await??;
+    let params = notification
+        .params
+        .ok_or_else(|| anyhow::anyhow!("thread/undo/completed must have params"))?;
+    Ok(serde_json::from_value(params)?)
+}
+
+fn create_config_toml(codex_home: &Path, server_uri: &str) -> std::io::Result<()> {
+    let config_toml = codex_home.join("config.toml");
+    std::fs::write(
+        config_toml,
+        format!(
+            r#"
+model = "mock-model"
+approval_policy = "never"
+sandbox_mode = "read-only"
+
+model_provider = "mock_provider"
+
+[model_providers.mock_provider]
+name = "Mock provider for test"
+base_url = "{server_uri}/v1"
+wire_api = "responses"
+request_max_retries = 0
+stream_max_retries = 0
+"#
+        ),
+    )
+}
+
+async fn start_thread(mcp: &mut McpProcess) -> Result<String> {
+    let req_id = mcp
+        .send_thread_start_request(ThreadStartParams {
+            model: Some("gpt-5.1".to_string()),
+            ..Default::default()
+        })
+        .await?;
+    let resp: JSONRPCResponse = timeout(
+        DEFAULT_READ_TIMEOUT,
+        mcp.read_stream_until_response_message(RequestId::Integer(req_id)),
+    )
+    .await??;
+    let ThreadStartResponse { thread, .. } = to_response::<ThreadStartResponse>(resp)?;
+    Ok(thread.id)
+}
@@ -1,5 +1,6 @@
 mod invocation;
 mod parser;
+mod review;
 mod seek_sequence;
 mod standalone_executable;
 
@@ -14,6 +15,9 @@ pub use parser::ParseError;
 use parser::ParseError::*;
 use parser::UpdateFileChunk;
 pub use parser::parse_patch;
+pub use review::ReviewHunk;
+pub use review::retain_hunks;
+pub use review::review_hunks;
 use similar::TextDiff;
 use thiserror::Error;
 
@@ -350,14 +354,7 @@ fn derive_new_contents_from_chunks(
         }
     };
 
-    let mut original_lines: Vec<String> = original_contents.split('\n').map(String::from).collect();
-
-    // Drop the trailing empty element that results from the final newline so
-    // that line counts match the behaviour of standard `diff`.
-    if original_lines.last().is_some_and(String::is_empty) {
-        original_lines.pop();
-    }
-
+    let original_lines = split_file_lines(&original_contents);
     let replacements = compute_replacements(&original_lines, path, chunks)?;
     let new_lines = apply_replacements(original_lines, &replacements);
     let mut new_lines = new_lines;
@@ -371,6 +368,17 @@ fn derive_new_contents_from_chunks(
     })
 }
 
+/// Split file contents into lines, dropping the trailing empty element that
+/// results from the final newline so that line counts match the behaviour of
+/// standard `diff`.
+fn split_file_lines(contents: &str) -> Vec<String> {
+    let mut lines: Vec<String> = contents.split('\n').map(String::from).collect();
+    if lines.last().is_some_and(String::is_empty) {
+        lines.pop();
+    }
+    lines
+}
+
 /// Compute a list of replacements needed to transform `original_lines` into the
 /// new lines, given the patch `chunks`. Each replacement is returned as
 /// `(start_index, old_len, new_lines)`.
@@ -378,6 +386,18 @@ fn compute_replacements(
     original_lines: &[String],
     path: &Path,
     chunks: &[UpdateFileChunk],
+) -> std::result::Result<Vec<(usize, usize, Vec<String>)>, ApplyPatchError> {
+    let mut replacements = locate_chunks(original_lines, path, chunks)?;
+    replacements.sort_by(|(lhs_idx, _, _), (rhs_idx, _, _)| lhs_idx.cmp(rhs_idx));
+    Ok(replacements)
+}
+
+/// Like [`compute_replacements`], but returns one replacement per chunk in
+/// chunk order rather than sorted by position.
+fn locate_chunks(
+    original_lines: &[String],
+    path: &Path,
+    chunks: &[UpdateFileChunk],
 ) -> std::result::Result<Vec<(usize, usize, Vec<String>)>, ApplyPatchError> {
     let mut replacements: Vec<(usize, usize, Vec<String>)> = Vec::new();
     let mut line_index: usize = 0;
@@ -459,8 +479,6 @@ fn compute_replacements(
         }
     }
 
-    replacements.sort_by(|(lhs_idx, _, _), (rhs_idx, _, _)| lhs_idx.cmp(rhs_idx));
-
     Ok(replacements)
 }
 
@@ -0,0 +1,456 @@
+//! Splitting a verified patch into individually reviewable hunks, and
+//! rebuilding a patch that only contains the hunks a reviewer accepted.
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::path::Path;
+use std::path::PathBuf;
+
+use similar::ChangeTag;
+use similar::TextDiff;
+
+use crate::ApplyPatchAction;
+use crate::ApplyPatchError;
+use crate::ApplyPatchFileChange;
+use crate::IoError;
+use crate::apply_replacements;
+use crate::locate_chunks;
+use crate::parser::Hunk;
+use crate::parser::UpdateFileChunk;
+use crate::parser::parse_patch;
+use crate::split_file_lines;
+
+/// A single reviewable unit of an [`ApplyPatchAction`].
+///
+/// Adding, deleting or renaming a file is one hunk; an update contributes one
+/// hunk per `@@` chunk. `index` counts hunks per path, in patch order, and is
+/// what [`retain_hunks`] uses to identify a hunk.
+#[derive(Debug, PartialEq)]
+pub struct ReviewHunk {
+    /// Absolute path of the file the hunk touches.
+    pub path: PathBuf,
+    pub index: usize,
+    /// The change as if this hunk were the only one applied to the file.
+    pub change: ApplyPatchFileChange,
+}
+
+/// Split `action` into its reviewable hunks, in patch order.
+pub fn review_hunks(action: &ApplyPatchAction) -> Result<Vec<ReviewHunk>, ApplyPatchError> {
+    let args = parse_patch(&action.patch)?;
+    let mut counters: HashMap<PathBuf, usize> = HashMap::new();
+    let mut out = Vec::new();
+    for hunk in args.hunks {
+        let path = hunk.resolve_path(&action.cwd);
+        let mut next_index = || {
+            let counter = counters.entry(path.clone()).or_default();
+            let index = *counter;
+            *counter += 1;
+            index
+        };
+        match &hunk {
+            Hunk::AddFile { contents, .. } => {
+                let index = next_index();
+                out.push(ReviewHunk {
+                    path,
+                    index,
+                    change: ApplyPatchFileChange::Add {
+                        content: contents.clone(),
+                    },
+                });
+            }
+            Hunk::DeleteFile { .. } => {
+                let index = next_index();
+                let content = match action.changes().get(&path) {
+                    Some(ApplyPatchFileChange::Delete { content }) => content.clone(),
+                    _ => read_file(&path)?,
+                };
+                out.push(ReviewHunk {
+                    path,
+                    index,
+                    change: ApplyPatchFileChange::Delete { content },
+                });
+            }
+            Hunk::UpdateFile {
+                move_path, chunks, ..
+            } => {
+                let move_path = move_path.as_ref().map(|p| action.cwd.join(p));
+                let original_contents = read_file(&path)?;
+                let original_lines = split_file_lines(&original_contents);
+                if chunks.is_empty() {
+                    let index = next_index();
+                    out.push(ReviewHunk {
+                        path,
+                        index,
+                        change: ApplyPatchFileChange::Update {
+                            unified_diff: String::new(),
+                            move_path,
+                            new_content: original_contents,
+                        },
+                    });
+                    continue;
+                }
+
+                let replacements = locate_chunks(&original_lines, &path, chunks)?;
+                let mut order: Vec<usize> = (0..replacements.len()).collect();
+                order.sort_by_key(|&i| replacements[i].0);
+                // Line shift introduced by the chunks that precede each chunk
+                // in the file, used to compute the `+c,d` side of its header.
+                let mut shifts = vec![0isize; replacements.len()];
+                let mut shift = 0isize;
+                for &i in &order {
+                    shifts[i] = shift;
+                    let (_, old_len, new_segment) = &replacements[i];
+                    shift += new_segment.len() as isize - *old_len as isize;
+                }
+
+                for (i, replacement) in replacements.iter().enumerate() {
+                    let index = next_index();
+                    let (start, old_len, new_segment) = replacement;
+                    let end = (*start + *old_len).min(original_lines.len());
+                    let old_segment = &original_lines[(*start).min(end)..end];
+                    let new_start = (*start as isize + shifts[i]).max(0) as usize;
+                    let unified_diff = chunk_diff(*start, new_start, old_segment, new_segment);
+                    let mut new_lines =
+                        apply_replacements(original_lines.clone(), &[replacement.clone()]);
+                    if !new_lines.last().is_some_and(String::is_empty) {
+                        new_lines.push(String::new());
+                    }
+                    out.push(ReviewHunk {
+                        path: path.clone(),
+                        index,
+                        change: ApplyPatchFileChange::Update {
+                            unified_diff,
+                            move_path: move_path.clone(),
+                            new_content: new_lines.join("\n"),
+                        },
+                    });
+                }
+            }
+        }
+    }
+    Ok(out)
+}
+
+/// Rebuild the patch in `action` keeping only the hunks for which `keep`
+/// returns true. `keep` receives the absolute path and per-path index of each
+/// hunk, matching [`ReviewHunk`].
+///
+/// A file whose hunks are all dropped is left untouched, including any
+/// rename. Returns `None` when no hunk is kept.
+///
+/// Chunks are located once against the full patch. When only some chunks of
+/// a file are kept, they are written as a single chunk anchored at the start
+/// of the file, so that dropping an earlier chunk (or its `@@` context) cannot
+/// make a kept chunk match an identical block of lines elsewhere.
+pub fn retain_hunks(
+    action: &ApplyPatchAction,
+    mut keep: impl FnMut(&Path, usize) -> bool,
+) -> Result<Option<String>, ApplyPatchError> {
+    let args = parse_patch(&action.patch)?;
+    let mut counters: HashMap<PathBuf, usize> = HashMap::new();
+    let mut body = String::new();
+    for hunk in args.hunks {
+        let resolved = hunk.resolve_path(&action.cwd);
+        let mut keep_next = || {
+            let counter = counters.entry(resolved.clone()).or_default();
+            let index = *counter;
+            *counter += 1;
+            keep(&resolved, index)
+        };
+        match hunk {
+            Hunk::AddFile { path, contents } => {
+                if keep_next() {
+                    let _ = writeln!(body, "*** Add File: {}", path.display());
+                    for line in contents.lines() {
+                        let _ = writeln!(body, "+{line}");
+                    }
+                }
+            }
+            Hunk::DeleteFile { path } => {
+                if keep_next() {
+                    let _ = writeln!(body, "*** Delete File: {}", path.display());
+                }
+            }
+            Hunk::UpdateFile {
+                path,
+                move_path,
+                chunks,
+            } => {
+                // A pure rename is a single hunk; otherwise each chunk is one.
+                let kept: Vec<UpdateFileChunk> = if chunks.is_empty() {
+                    if !keep_next() {
+                        continue;
+                    }
+                    Vec::new()
+                } else {
+                    let keep_flags: Vec<bool> = chunks.iter().map(|_| keep_next()).collect();
+                    if !keep_flags.contains(&true) {
+                        continue;
+                    }
+                    if keep_flags.contains(&false) {
+                        vec![pinned_chunk(&resolved, &chunks, &keep_flags)?]
+                    } else {
+                        chunks
+                    }
+                };
+                let _ = writeln!(body, "*** Update File: {}", path.display());
+                if let Some(move_path) = &move_path {
+                    let _ = writeln!(body, "*** Move to: {}", move_path.display());
+                }
+                for chunk in &kept {
+                    write_chunk(&mut body, chunk);
+                }
+            }
+        }
+    }
+
+    if body.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(format!("*** Begin Patch\n{body}*** End Patch\n")))
+}
+
+/// Resolve every chunk of an update against the current file, then build one
+/// chunk covering the file from its first line through the last kept chunk
+/// with only the kept replacements applied.
+fn pinned_chunk(
+    path: &Path,
+    chunks: &[UpdateFileChunk],
+    keep_flags: &[bool],
+) -> Result<UpdateFileChunk, ApplyPatchError> {
+    let original_lines = split_file_lines(&read_file(path)?);
+    let mut kept: Vec<(usize, usize, Vec<String>)> = locate_chunks(&original_lines, path, chunks)?
+        .into_iter()
+        .zip(keep_flags)
+        .filter_map(|(replacement, keep)| keep.then_some(replacement))
+        .collect();
+    kept.sort_by_key(|(start, _, _)| *start);
+    let end = kept
+        .iter()
+        .map(|(start, old_len, _)| start + old_len)
+        .max()
+        .unwrap_or_default()
+        .min(original_lines.len());
+    let old_lines = original_lines[..end].to_vec();
+    let new_lines = apply_replacements(old_lines.clone(), &kept);
+    Ok(UpdateFileChunk {
+        change_context: None,
+        old_lines,
+        new_lines,
+        is_end_of_file: false,
+    })
+}
+
+fn write_chunk(out: &mut String, chunk: &UpdateFileChunk) {
+    match &chunk.change_context {
+        Some(context) => {
+            let _ = writeln!(out, "@@ {context}");
+        }
+        None => out.push_str("@@\n"),
+    }
+    for (tag, line) in diff_lines(&chunk.old_lines, &chunk.new_lines) {
+        let _ = writeln!(out, "{tag}{line}");
+    }
+    if chunk.is_end_of_file {
+        out.push_str("*** End of File\n");
+    }
+}
+
+/// Render a single chunk as a unified diff hunk whose old side starts at the
+/// zero-based line `start` and new side at `new_start`.
+fn chunk_diff(start: usize, new_start: usize, old: &[String], new: &[String]) -> String {
+    let header_start = |start: usize, len: usize| if len == 0 { start } else { start + 1 };
+    let mut out = format!(
+        "@@ -{},{} +{},{} @@\n",
+        header_start(start, old.len()),
+        old.len(),
+        header_start(new_start, new.len()),
+        new.len()
+    );
+    for (tag, line) in diff_lines(old, new) {
+        let _ = writeln!(out, "{tag}{line}");
+    }
+    out
+}
+
+fn diff_lines<'a>(old: &'a [String], new: &'a [String]) -> Vec<(char, &'a str)> {
+    let old: Vec<&str> = old.iter().map(String::as_str).collect();
+    let new: Vec<&str> = new.iter().map(String::as_str).collect();
+    let diff = TextDiff::from_slices(&old, &new);
+    diff.iter_all_changes()
+        .map(|change| {
+            let tag = match change.tag() {
+                ChangeTag::Equal => ' ',
+                ChangeTag::Delete => '-',
+                ChangeTag::Insert => '+',
+            };
+            (tag, change.value())
+        })
+        .collect()
+}
+
+fn read_file(path: &Path) -> Result<String, ApplyPatchError> {
+    std::fs::read_to_string(path).map_err(|source| {
+        ApplyPatchError::IoError(IoError {
+            context: format!("Failed to read {}", path.display()),
+            source,
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MaybeApplyPatchVerified;
+    use crate::maybe_parse_apply_patch_verified;
+    use pretty_assertions::assert_eq;
+    use std::fs;
+    use tempfile::tempdir;
+
+    fn verified(patch: &str, cwd: &Path) -> ApplyPatchAction {
+        let argv = vec!["apply_patch".to_string(), patch.to_string()];
+        match maybe_parse_apply_patch_verified(&argv, cwd) {
+            MaybeApplyPatchVerified::Body(action) => action,
+            other => panic!("expected a verified patch, got {other:?}"),
+        }
+    }
+
+    const PATCH: &str = "*** Begin Patch
+*** Add File: new.txt
++hello
+*** Update File: src.txt
+@@
+ a
+-b
++B
+@@
+ d
+-e
++E
++F
+*** End Patch";
+
+    #[test]
+    fn splits_updates_into_one_hunk_per_chunk() {
+        let dir = tempdir().unwrap();
+        fs::write(dir.path().join("src.txt"), "a\nb\nc\nd\ne\n").unwrap();
+        let action = verified(PATCH, dir.path());
+
+        let hunks = review_hunks(&action).unwrap();
+        let summary: Vec<(PathBuf, usize)> =
+            hunks.iter().map(|h| (h.path.clone(), h.index)).collect();
+        let src = dir.path().join("src.txt");
+        assert_eq!(
+            summary,
+            vec![
+                (dir.path().join("new.txt"), 0),
+                (src.clone(), 0),
+                (src.clone(), 1),
+            ]
+        );
+        assert_eq!(
+            hunks[2].change,
+            ApplyPatchFileChange::Update {
+                unified_diff: "@@ -4,2 +4,3 @@\n d\n-e\n+E\n+F\n".to_string(),
+                move_path: None,
+                new_content: "a\nb\nc\nd\nE\nF\n".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn retain_hunks_drops_declined_chunks() {
+        let dir = tempdir().unwrap();
+        let src = dir.path().join("src.txt");
+        fs::write(&src, "a\nb\nc\nd\ne\n").unwrap();
+        let action = verified(PATCH, dir.path());
+
+        let patch = retain_hunks(&action, |path, index| !(path == src && index == 0))
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            patch,
+            "*** Begin Patch\n*** Add File: new.txt\n+hello\n*** Update File: src.txt\n@@\n a\n b\n c\n d\n-e\n+E\n+F\n*** End Patch\n"
+        );
+
+        let mut stdout = Vec::new();
+        let mut stderr = Vec::new();
+        let hunks = parse_patch(&patch).unwrap().hunks;
+        let hunks: Vec<Hunk> = hunks
+            .into_iter()
+            .map(|hunk| match hunk {
+                Hunk::AddFile { path, contents } => Hunk::AddFile {
+                    path: dir.path().join(path),
+                    contents,
+                },
+                Hunk::UpdateFile {
+                    path,
+                    move_path,
+                    chunks,
+                } => Hunk::UpdateFile {
+                    path: dir.path().join(path),
+                    move_path,
+                    chunks,
+                },
+                other => other,
+            })
+            .collect();
+        crate::apply_hunks(&hunks, &mut stdout, &mut stderr).unwrap();
+        assert_eq!(fs::read_to_string(&src).unwrap(), "a\nb\nc\nd\nE\nF\n");
+    }
+
+    #[test]
+    fn retain_hunks_keeps_later_chunks_at_their_resolved_position() {
+        let dir = tempdir().unwrap();
+        let src = dir.path().join("src.txt");
+        fs::write(&src, "fn a() {\n    x\n}\nfn b() {\n    x\n}\n").unwrap();
+        let patch = "*** Begin Patch
+*** Update File: src.txt
+@@ fn a() {
+-    x
++    first
+@@
+-    x
++    second
+*** End Patch";
+        let action = verified(patch, dir.path());
+
+        let patch = retain_hunks(&action, |_, index| index == 1)
+            .unwrap()
+            .unwrap();
+        let hunks: Vec<Hunk> = parse_patch(&patch)
+            .unwrap()
+            .hunks
+            .into_iter()
+            .map(|hunk| match hunk {
+                Hunk::UpdateFile {
+                    path,
+                    move_path,
+                    chunks,
+                } => Hunk::UpdateFile {
+                    path: dir.path().join(path),
+                    move_path,
+                    chunks,
+                },
+                other => other,
+            })
+            .collect();
+        let mut stdout = Vec::new();
+        let mut stderr = Vec::new();
+        crate::apply_hunks(&hunks, &mut stdout, &mut stderr).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(&src).unwrap(),
+            "fn a() {\n    x\n}\nfn b() {\n    second\n}\n"
+        );
+    }
+
+    #[test]
+    fn retain_hunks_returns_none_when_everything_is_declined() {
+        let dir = tempdir().unwrap();
+        fs::write(dir.path().join("src.txt"), "a\nb\nc\nd\ne\n").unwrap();
+        let action = verified(PATCH, dir.path());
+
+        assert_eq!(retain_hunks(&action, |_, _| false).unwrap(), None);
+    }
+}
@@ -0,0 +1,94 @@
+use std::io::Write;
+use std::path::PathBuf;
+
+use anyhow::Context;
+use anyhow::Result;
+use anyhow::bail;
+use codex_common::CliConfigOverrides;
+use codex_core::RolloutRecorder;
+use codex_core::command_secrets::CommandSecrets;
+use codex_core::config::Config;
+use codex_core::config::find_codex_home;
+use codex_core::find_archived_thread_path_by_id_str;
+use codex_core::find_thread_path_by_id_str;
+use codex_core::find_thread_path_by_name_str;
+use codex_core::transcript_export::ExportFormat;
+use codex_core::transcript_export::ExportOptions;
+use codex_core::transcript_export::export_transcript;
+
+#[derive(Debug, clap::Parser)]
+pub struct ExportCommand {
+    #[clap(skip)]
+    pub config_overrides: CliConfigOverrides,
+
+    /// Session id (UUID), thread name, or path to a rollout file.
+    #[arg(value_name = "SESSION")]
+    pub session: String,
+
+    /// Output format: md, html or json.
+    #[arg(long, short = 'f', default_value = "md")]
+    pub format: ExportFormat,
+
+    /// Write the export to this file instead of stdout.
+    #[arg(long, short = 'o', value_name = "FILE")]
+    pub output: Option<PathBuf>,
+
+    /// Replace the values of secrets listed under `[secrets.expose]` with `[REDACTED:NAME]`.
+    #[arg(long, default_value_t = false)]
+    pub redact: bool,
+
+    /// Leave out command and tool output.
+    #[arg(long = "omit-tool-output", default_value_t = false)]
+    pub omit_tool_output: bool,
+}
+
+pub async fn run_export_command(cmd: ExportCommand) -> Result<()> {
+    let path = resolve_rollout_path(&cmd.session).await?;
+    let history = RolloutRecorder::get_rollout_history(&path)
+        .await
+        .with_context(|| format!("failed to read session from {}", path.display()))?;
+    let redact = if cmd.redact {
+        let overrides = cmd
+            .config_overrides
+            .parse_overrides()
+            .map_err(anyhow::Error::msg)?;
+        let config = Config::load_with_cli_overrides(overrides)
+            .await
+            .context("failed to load configuration")?;
+        CommandSecrets::load(&config).await
+    } else {
+        CommandSecrets::default()
+    };
+    let options = ExportOptions {
+        redact,
+        omit_tool_output: cmd.omit_tool_output,
+    };
+    let text = export_transcript(&history.get_rollout_items(), cmd.format, &options);
+
+    match cmd.output {
+        Some(output) => std::fs::write(&output, text)
+            .with_context(|| format!("failed to write {}", output.display()))?,
+        None => std::io::stdout().write_all(text.as_bytes())?,
+    }
+    Ok(())
+}
+
+/// Accepts a rollout path, a session id (active or archived) or a thread name.
+async fn resolve_rollout_path(session: &str) -> Result<PathBuf> {
+    let candidate = PathBuf::from(session);
+    if candidate.is_file() {
+        return Ok(candidate);
+    }
+
+    let codex_home = find_codex_home()?;
+    if let Some(path) = find_thread_path_by_id_str(&codex_home, session).await? {
+        return Ok(path);
+    }
+    if let Some(path) = find_archived_thread_path_by_id_str(&codex_home, session).await? {
+        return Ok(path);
+    }
+    if let Some(path) = find_thread_path_by_name_str(&codex_home, session).await? {
+        return Ok(path);
+    }
+    bail!("no session found for `{session}`")
+}
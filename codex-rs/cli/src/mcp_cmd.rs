@@ -249,6 +249,7 @@ async fn run_add(config_overrides: &CliConfigOverrides, add_args: AddArgs) -> Re
         enabled_tools: None,
         disabled_tools: None,
         scopes: None,
+        sandbox: None,
     };
 
     servers.insert(name.clone(), new_entry);
@@ -0,0 +1,201 @@
+use std::io::Read;
+
+use anyhow::Context;
+use anyhow::Result;
+use anyhow::bail;
+use codex_common::CliConfigOverrides;
+use codex_core::command_secrets::secrets_manager;
+use codex_core::config::Config;
+use codex_secrets::SecretName;
+use codex_secrets::SecretScope;
+use codex_secrets::SecretsManager;
+use codex_secrets::environment_id_from_cwd;
+
+/// Subcommands:
+/// - `set`    — store a secret (reads the value from stdin when omitted)
+/// - `get`    — print a secret's value
+/// - `list`   — list stored secret names
+/// - `delete` — remove a secret
+///
+/// Secrets are only handed to commands when listed under `[secrets.expose]`
+/// in `config.toml`.
+#[derive(Debug, clap::Parser)]
+pub struct SecretsCli {
+    #[clap(flatten)]
+    pub config_overrides: CliConfigOverrides,
+
+    #[command(subcommand)]
+    pub subcommand: SecretsSubcommand,
+}
+
+#[derive(Debug, clap::Subcommand)]
+pub enum SecretsSubcommand {
+    Set(SetArgs),
+    Get(GetArgs),
+    List(ListArgs),
+    Delete(DeleteArgs),
+}
+
+#[derive(Debug, clap::Args)]
+pub struct ScopeArgs {
+    /// Use the scope of the current repository instead of the global scope.
+    #[arg(long)]
+    pub repo: bool,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct SetArgs {
+    /// Secret name (A-Z, 0-9 and _).
+    pub name: String,
+
+    /// Secret value. Read from stdin when omitted, which keeps it out of shell history.
+    pub value: Option<String>,
+
+    #[clap(flatten)]
+    pub scope: ScopeArgs,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct GetArgs {
+    /// Secret name.
+    pub name: String,
+
+    #[clap(flatten)]
+    pub scope: ScopeArgs,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct ListArgs {
+    /// Only list secrets in the scope of the current repository.
+    #[arg(long)]
+    pub repo: bool,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct DeleteArgs {
+    /// Secret name.
+    pub name: String,
+
+    #[clap(flatten)]
+    pub scope: ScopeArgs,
+}
+
+impl SecretsCli {
+    pub async fn run(self) -> Result<()> {
+        let SecretsCli {
+            config_overrides,
+            subcommand,
+        } = self;
+
+        let overrides = config_overrides
+            .parse_overrides()
+            .map_err(anyhow::Error::msg)?;
+        let config = Config::load_with_cli_overrides(overrides)
+            .await
+            .context("failed to load configuration")?;
+        let manager = secrets_manager(&config);
+
+        match subcommand {
+            SecretsSubcommand::Set(args) => run_set(&manager, &config, args),
+            SecretsSubcommand::Get(args) => run_get(&manager, &config, args),
+            SecretsSubcommand::List(args) => run_list(&manager, &config, args),
+            SecretsSubcommand::Delete(args) => run_delete(&manager, &config, args),
+        }
+    }
+}
+
+fn run_set(manager: &SecretsManager, config: &Config, args: SetArgs) -> Result<()> {
+    let name = SecretName::new(&args.name)?;
+    let value = match args.value {
+        Some(value) => value,
+        None => {
+            let mut value = String::new();
+            std::io::stdin()
+                .read_to_string(&mut value)
+                .context("failed to read secret value from stdin")?;
+            value.trim_end_matches(['\r', '\n']).to_string()
+        }
+    };
+    if value.is_empty() {
+        bail!("secret value must not be empty");
+    }
+    let scope = resolve_scope(config, args.scope.repo)?;
+    manager.set(&scope, &name, &value)?;
+    println!("Stored {name} ({}).", describe_scope(&scope));
+    Ok(())
+}
+
+fn run_get(manager: &SecretsManager, config: &Config, args: GetArgs) -> Result<()> {
+    let name = SecretName::new(&args.name)?;
+    let scope = resolve_scope(config, args.scope.repo)?;
+    match manager.get(&scope, &name)? {
+        Some(value) => println!("{value}"),
+        None => bail!("no secret named {name} ({})", describe_scope(&scope)),
+    }
+    Ok(())
+}
+
+fn run_list(manager: &SecretsManager, config: &Config, args: ListArgs) -> Result<()> {
+    let scope = if args.repo {
+        Some(resolve_scope(config, true)?)
+    } else {
+        None
+    };
+    let mut entries = manager.list(scope.as_ref())?;
+    if entries.is_empty() {
+        println!("No secrets stored. Add one with `codex secrets set NAME`.");
+        return Ok(());
+    }
+    entries.sort_by(|a, b| {
+        describe_scope(&a.scope)
+            .cmp(&describe_scope(&b.scope))
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    let exposed: Vec<&str> = config
+        .secrets
+        .expose
+        .iter()
+        .map(|exposure| exposure.name.as_str())
+        .collect();
+    let name_width = entries
+        .iter()
+        .map(|entry| entry.name.as_str().len())
+        .max()
+        .unwrap_or(0);
+    for entry in entries {
+        let name = entry.name.as_str();
+        let scope = describe_scope(&entry.scope);
+        let exposed = if exposed.contains(&name) {
+            "exposed"
+        } else {
+            "-"
+        };
+        println!("{name:<name_width$}  {scope}  {exposed}");
+    }
+    Ok(())
+}
+
+fn run_delete(manager: &SecretsManager, config: &Config, args: DeleteArgs) -> Result<()> {
+    let name = SecretName::new(&args.name)?;
+    let scope = resolve_scope(config, args.scope.repo)?;
+    if !manager.delete(&scope, &name)? {
+        bail!("no secret named {name} ({})", describe_scope(&scope));
+    }
+    println!("Deleted {name} ({}).", describe_scope(&scope));
+    Ok(())
+}
+
+fn resolve_scope(config: &Config, repo: bool) -> Result<SecretScope> {
+    if repo {
+        SecretScope::environment(environment_id_from_cwd(&config.cwd))
+    } else {
+        Ok(SecretScope::Global)
+    }
+}
+
+fn describe_scope(scope: &SecretScope) -> String {
+    match scope {
+        SecretScope::Global => "global".to_string(),
+        SecretScope::Environment(environment_id) => format!("repo:{environment_id}"),
+    }
+}
@@ -28,6 +28,10 @@ pub struct Prompt {
     pub parallel_tool_calls: bool,
     /// Optional output schema used to build the `text.format` controls.
     pub output_schema: Option<Value>,
+    /// Upper bound on generated tokens, including reasoning.
+    pub max_output_tokens: Option<u32>,
+    /// Sampling temperature; only accepted by non-reasoning models.
+    pub temperature: Option<f32>,
 }
 
 /// Canonical input payload for the compaction endpoint.
@@ -166,6 +170,10 @@ pub struct ResponsesApiRequest<'a> {
     pub prompt_cache_key: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<TextControls>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_output_tokens: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
 }
 
 #[derive(Debug, Serialize)]
@@ -184,6 +192,10 @@ pub struct ResponseCreateWsRequest {
     pub prompt_cache_key: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<TextControls>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_output_tokens: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
 }
 
 #[derive(Debug, Serialize)]
@@ -9,6 +9,7 @@ use codex_protocol::openai_models::ModelsResponse;
 use http::HeaderMap;
 use http::Method;
 use http::header::ETAG;
+use serde::Deserialize;
 use std::sync::Arc;
 
 pub struct ModelsClient<T: HttpTransport, A: AuthProvider> {
@@ -65,6 +66,40 @@ impl<T: HttpTransport, A: AuthProvider> ModelsClient<T, A> {
 
         Ok((models, header_etag))
     }
+
+    /// List model ids from an OpenAI-compatible `/models` endpoint, as served
+    /// by third-party providers. Only the ids are returned; these endpoints do
+    /// not describe model capabilities.
+    pub async fn list_provider_models(
+        &self,
+        extra_headers: HeaderMap,
+    ) -> Result<Vec<String>, ApiError> {
+        let resp = self
+            .session
+            .execute(Method::GET, Self::path(), extra_headers, None)
+            .await?;
+
+        let ProviderModelsResponse { data } =
+            serde_json::from_slice::<ProviderModelsResponse>(&resp.body).map_err(|e| {
+                ApiError::Stream(format!(
+                    "failed to decode models response: {e}; body: {}",
+                    String::from_utf8_lossy(&resp.body)
+                ))
+            })?;
+
+        Ok(data.into_iter().map(|model| model.id).collect())
+    }
+}
+
+/// Body of an OpenAI-compatible `GET /models` response.
+#[derive(Deserialize)]
+struct ProviderModelsResponse {
+    data: Vec<ProviderModel>,
+}
+
+#[derive(Deserialize)]
+struct ProviderModel {
+    id: String,
 }
 
 #[cfg(test)]
@@ -267,6 +267,8 @@ async fn streaming_client_retries_on_transport_error() -> Result<()> {
         tools: Vec::<Value>::new(),
         parallel_tool_calls: false,
         output_schema: None,
+        max_output_tokens: None,
+        temperature: None,
     };
 
     let options = ResponsesOptions::default();
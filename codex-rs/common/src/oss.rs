@@ -1,8 +1,11 @@
 //! OSS provider utilities shared between TUI and exec.
 
+use async_trait::async_trait;
 use codex_core::LMSTUDIO_OSS_PROVIDER_ID;
 use codex_core::OLLAMA_OSS_PROVIDER_ID;
 use codex_core::config::Config;
+use codex_core::models_manager::model_info::LocalModelMetadata;
+use codex_core::models_manager::model_info::LocalModelMetadataFetcher;
 
 /// Returns the default model for a given OSS provider.
 pub fn get_default_model_for_oss_provider(provider_id: &str) -> Option<&'static str> {
@@ -37,6 +40,44 @@ pub async fn ensure_oss_provider_ready(
     Ok(())
 }
 
+/// Asks the Ollama or LM Studio server configured for `provider_id` about a
+/// model. Install it on the models manager so every resolved model, including
+/// ones picked mid-session, reports the server's context window and
+/// capabilities.
+#[derive(Debug, Default)]
+pub struct OssModelMetadataFetcher;
+
+#[async_trait]
+impl LocalModelMetadataFetcher for OssModelMetadataFetcher {
+    async fn fetch(
+        &self,
+        provider_id: &str,
+        model: &str,
+        config: &Config,
+    ) -> Option<LocalModelMetadata> {
+        let metadata = match provider_id {
+            LMSTUDIO_OSS_PROVIDER_ID => {
+                match codex_lmstudio::LMStudioClient::try_from_provider(config).await {
+                    Ok(client) => client.fetch_model_metadata(model).await,
+                    Err(err) => Err(err),
+                }
+            }
+            OLLAMA_OSS_PROVIDER_ID => {
+                match codex_ollama::OllamaClient::try_from_oss_provider(config).await {
+                    Ok(client) => client.fetch_model_metadata(model).await,
+                    Err(err) => Err(err),
+                }
+            }
+            _ => return None,
+        };
+        metadata
+            .inspect_err(|err| {
+                tracing::warn!("Failed to query metadata for {model} from {provider_id}: {err}.");
+            })
+            .ok()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
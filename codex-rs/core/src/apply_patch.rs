@@ -1,6 +1,7 @@
 use crate::codex::TurnContext;
 use crate::function_tool::FunctionCallError;
 use crate::protocol::FileChange;
+use crate::protocol::PatchHunk;
 use crate::safety::SafetyCheck;
 use crate::safety::assess_patch_safety;
 use crate::tools::sandboxing::ExecApprovalRequirement;
@@ -80,27 +81,50 @@ pub(crate) fn convert_apply_patch_to_protocol(
     let changes = action.changes();
     let mut result = HashMap::with_capacity(changes.len());
     for (path, change) in changes {
-        let protocol_change = match change {
-            ApplyPatchFileChange::Add { content } => FileChange::Add {
-                content: content.clone(),
-            },
-            ApplyPatchFileChange::Delete { content } => FileChange::Delete {
-                content: content.clone(),
-            },
-            ApplyPatchFileChange::Update {
-                unified_diff,
-                move_path,
-                new_content: _new_content,
-            } => FileChange::Update {
-                unified_diff: unified_diff.clone(),
-                move_path: move_path.clone(),
-            },
-        };
-        result.insert(path.clone(), protocol_change);
+        result.insert(path.clone(), convert_file_change(change));
     }
     result
 }
 
+/// Split `action` into the hunks offered for individual review. Returns an
+/// empty list when the patch cannot be split, in which case it can only be
+/// approved as a whole.
+pub(crate) fn convert_apply_patch_hunks_to_protocol(action: &ApplyPatchAction) -> Vec<PatchHunk> {
+    match codex_apply_patch::review_hunks(action) {
+        Ok(hunks) => hunks
+            .into_iter()
+            .map(|hunk| PatchHunk {
+                path: hunk.path,
+                index: hunk.index,
+                change: convert_file_change(&hunk.change),
+            })
+            .collect(),
+        Err(err) => {
+            tracing::warn!("failed to split patch into hunks: {err}");
+            Vec::new()
+        }
+    }
+}
+
+fn convert_file_change(change: &ApplyPatchFileChange) -> FileChange {
+    match change {
+        ApplyPatchFileChange::Add { content } => FileChange::Add {
+            content: content.clone(),
+        },
+        ApplyPatchFileChange::Delete { content } => FileChange::Delete {
+            content: content.clone(),
+        },
+        ApplyPatchFileChange::Update {
+            unified_diff,
+            move_path,
+            new_content: _new_content,
+        } => FileChange::Update {
+            unified_diff: unified_diff.clone(),
+            move_path: move_path.clone(),
+        },
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -461,6 +461,8 @@ impl ModelClientSession {
             include: include.clone(),
             prompt_cache_key: prompt_cache_key.clone(),
             text: text.clone(),
+            max_output_tokens: api_prompt.max_output_tokens,
+            temperature: api_prompt.temperature,
         };
 
         ResponsesWsRequest::ResponseCreate(payload)
@@ -751,6 +753,8 @@ fn build_api_prompt(prompt: &Prompt, instructions: String, tools_json: Vec<Value
         tools: tools_json,
         parallel_tool_calls: prompt.parallel_tool_calls,
         output_schema: prompt.output_schema.clone(),
+        max_output_tokens: prompt.max_output_tokens,
+        temperature: prompt.temperature,
     }
 }
 
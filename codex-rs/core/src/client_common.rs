@@ -42,6 +42,12 @@ pub struct Prompt {
 
     /// Optional the output schema for the model's response.
     pub output_schema: Option<Value>,
+
+    /// Upper bound on generated tokens. Unset for regular turns.
+    pub(crate) max_output_tokens: Option<u32>,
+
+    /// Sampling temperature. Unset for regular turns.
+    pub(crate) temperature: Option<f32>,
 }
 
 impl Prompt {
@@ -264,6 +270,8 @@ mod tests {
                 verbosity: Some(OpenAiVerbosity::Low),
                 format: None,
             }),
+            max_output_tokens: None,
+            temperature: None,
         };
 
         let v = serde_json::to_value(&req).expect("json");
@@ -302,6 +310,8 @@ mod tests {
             include: vec![],
             prompt_cache_key: None,
             text: Some(text_controls),
+            max_output_tokens: None,
+            temperature: None,
         };
 
         let v = serde_json::to_value(&req).expect("json");
@@ -338,6 +348,8 @@ mod tests {
             include: vec![],
             prompt_cache_key: None,
             text: None,
+            max_output_tokens: None,
+            temperature: None,
         };
 
         let v = serde_json::to_value(&req).expect("json");
@@ -4,6 +4,9 @@ use std::fmt::Debug;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::atomic::AtomicU64;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
 
 use crate::AuthManager;
 use crate::CodexAuth;
@@ -14,6 +17,7 @@ use crate::agent::MAX_THREAD_SPAWN_DEPTH;
 use crate::agent::agent_status_from_event;
 use crate::analytics_client::AnalyticsEventsClient;
 use crate::analytics_client::build_track_events_context;
+use crate::command_secrets::CommandSecrets;
 use crate::compact;
 use crate::compact::run_inline_auto_compact_task;
 use crate::compact::should_use_remote_compact_task;
@@ -55,16 +59,19 @@ use codex_protocol::mcp::CallToolResult;
 use codex_protocol::models::BaseInstructions;
 use codex_protocol::models::format_allow_prefixes;
 use codex_protocol::openai_models::ModelInfo;
+use codex_protocol::protocol::BackgroundProcess;
 use codex_protocol::protocol::FileChange;
 use codex_protocol::protocol::HasLegacyEvent;
 use codex_protocol::protocol::ItemCompletedEvent;
 use codex_protocol::protocol::ItemStartedEvent;
 use codex_protocol::protocol::RawResponseItemEvent;
+use codex_protocol::protocol::RestorableBackgroundProcessesEvent;
 use codex_protocol::protocol::ReviewRequest;
 use codex_protocol::protocol::RolloutItem;
 use codex_protocol::protocol::SessionSource;
 use codex_protocol::protocol::SubAgentSource;
 use codex_protocol::protocol::TurnAbortReason;
+use codex_protocol::protocol::TurnCompletedItem;
 use codex_protocol::protocol::TurnContextItem;
 use codex_protocol::protocol::TurnStartedEvent;
 use codex_protocol::request_user_input::RequestUserInputArgs;
@@ -135,6 +142,7 @@ use crate::mcp::CODEX_APPS_MCP_SERVER_NAME;
 use crate::mcp::auth::compute_auth_statuses;
 use crate::mcp::effective_mcp_servers;
 use crate::mcp::maybe_prompt_and_install_mcp_dependencies;
+use crate::mcp::sampling::sampling_handler as mcp_sampling_handler;
 use crate::mcp::with_codex_apps_mcp;
 use crate::mcp_connection_manager::McpConnectionManager;
 use crate::mentions::build_connector_slug_counts;
@@ -155,8 +163,10 @@ use crate::protocol::ErrorEvent;
 use crate::protocol::Event;
 use crate::protocol::EventMsg;
 use crate::protocol::ExecApprovalRequestEvent;
+use crate::protocol::ListUndoSnapshotsResponseEvent;
 use crate::protocol::McpServerRefreshConfig;
 use crate::protocol::Op;
+use crate::protocol::PatchHunk;
 use crate::protocol::PlanDeltaEvent;
 use crate::protocol::RateLimitSnapshot;
 use crate::protocol::ReasoningContentDeltaEvent;
@@ -195,6 +205,7 @@ use crate::skills::injection::app_id_from_path;
 use crate::skills::injection::tool_kind_for_path;
 use crate::skills::resolve_skill_dependencies_for_turn;
 use crate::state::ActiveTurn;
+use crate::state::RedoEntry;
 use crate::state::SessionServices;
 use crate::state::SessionState;
 use crate::state_db;
@@ -202,6 +213,7 @@ use crate::tasks::GhostSnapshotTask;
 use crate::tasks::ReviewTask;
 use crate::tasks::SessionTask;
 use crate::tasks::SessionTaskContext;
+use crate::tasks::UndoTarget;
 use crate::tools::ToolRouter;
 use crate::tools::context::SharedTurnDiffTracker;
 use crate::tools::parallel::ToolCallRuntime;
@@ -210,7 +222,9 @@ use crate::tools::spec::ToolsConfig;
 use crate::tools::spec::ToolsConfigParams;
 use crate::turn_diff_tracker::TurnDiffTracker;
 use crate::unified_exec::UnifiedExecProcessManager;
+use crate::unified_exec::restorable_processes;
 use crate::util::backoff;
+use crate::web_fetch::WebFetchCache;
 use crate::windows_sandbox::WindowsSandboxLevelExt;
 use codex_async_utils::OrCancelExt;
 use codex_otel::OtelManager;
@@ -227,6 +241,7 @@ use codex_protocol::openai_models::ReasoningEffort as ReasoningEffortConfig;
 use codex_protocol::protocol::CodexErrorInfo;
 use codex_protocol::protocol::InitialHistory;
 use codex_protocol::user_input::UserInput;
+use codex_utils_image::pdf::DEFAULT_TOKEN_BUDGET;
 use codex_utils_readiness::Readiness;
 use codex_utils_readiness::ReadinessFlag;
 use tokio::sync::watch;
@@ -482,6 +497,14 @@ impl Codex {
     pub(crate) fn state_db(&self) -> Option<state_db::StateDbHandle> {
         self.session.state_db()
     }
+
+    pub(crate) async fn list_undo_snapshots(&self) -> ListUndoSnapshotsResponseEvent {
+        let turn_context = self
+            .session
+            .new_default_turn_with_sub_id(self.session.next_internal_sub_id())
+            .await;
+        crate::tasks::list_undo_snapshots(&self.session, &turn_context).await
+    }
 }
 
 /// Context for an initialized model agent
@@ -544,6 +567,14 @@ impl TurnContext {
         })
     }
 
+    /// Tokens a single PDF attachment may use: a quarter of the context
+    /// window, or the default budget when the window is unknown.
+    pub(crate) fn pdf_token_budget(&self) -> usize {
+        self.model_context_window()
+            .and_then(|window| usize::try_from(window / 4).ok())
+            .unwrap_or(DEFAULT_TOKEN_BUDGET)
+    }
+
     pub(crate) fn resolve_path(&self, path: Option<String>) -> PathBuf {
         path.as_ref()
             .map(PathBuf::from)
@@ -794,7 +825,8 @@ impl Session {
             model_info: &model_info,
             features: &per_turn_config.features,
             web_search_mode: per_turn_config.web_search_mode,
-        });
+        })
+        .with_local_web_search(per_turn_config.web_fetch.search_backend.is_some());
 
         let cwd = session_configuration.cwd.clone();
         TurnContext {
@@ -1023,11 +1055,14 @@ impl Session {
             };
         session_configuration.thread_name = thread_name.clone();
         let state = SessionState::new(session_configuration.clone());
+        let command_secrets = CommandSecrets::load(&config).await;
 
         let services = SessionServices {
             mcp_connection_manager: Arc::new(RwLock::new(McpConnectionManager::default())),
             mcp_startup_cancellation_token: Mutex::new(CancellationToken::new()),
             unified_exec_manager: UnifiedExecProcessManager::default(),
+            web_fetch_cache: WebFetchCache::default(),
+            command_secrets,
             analytics_events_client: AnalyticsEventsClient::new(
                 Arc::clone(&config),
                 Arc::clone(&auth_manager),
@@ -1120,6 +1155,7 @@ impl Session {
                 tx_event.clone(),
                 cancel_token,
                 sandbox_state,
+                Some(mcp_sampling_handler(Arc::downgrade(&sess))),
             )
             .await;
 
@@ -1231,6 +1267,38 @@ impl Session {
                     state.set_token_info(Some(info));
                 }
 
+                if let Some(completed_at) =
+                    Self::last_turn_completed_at_from_rollout(&rollout_items)
+                {
+                    let mut state = self.state.lock().await;
+                    state.set_last_turn_completed_at(completed_at);
+                }
+
+                // Offer to relaunch background processes that died with the previous session.
+                let restorable = restorable_processes(&rollout_items);
+                if !restorable.is_empty() {
+                    let processes = restorable
+                        .iter()
+                        .map(|launch| BackgroundProcess {
+                            process_id: launch.process_id.clone(),
+                            command: launch.command.clone(),
+                            cwd: launch.cwd.clone(),
+                            tty: launch.tty,
+                        })
+                        .collect();
+                    {
+                        let mut state = self.state.lock().await;
+                        state.set_restorable_background_processes(restorable);
+                    }
+                    self.send_event(
+                        &turn_context,
+                        EventMsg::RestorableBackgroundProcesses(
+                            RestorableBackgroundProcessesEvent { processes },
+                        ),
+                    )
+                    .await;
+                }
+
                 // Defer seeding the session's initial context until the first turn starts so
                 // turn/start overrides can be merged before we write to the rollout.
                 self.flush_rollout().await;
@@ -1286,6 +1354,15 @@ impl Session {
         }
     }
 
+    fn last_turn_completed_at_from_rollout(rollout_items: &[RolloutItem]) -> Option<SystemTime> {
+        rollout_items.iter().rev().find_map(|item| match item {
+            RolloutItem::TurnCompleted(item) => {
+                Some(UNIX_EPOCH + Duration::from_millis(item.completed_at_ms))
+            }
+            _ => None,
+        })
+    }
+
     fn last_token_info_from_rollout(rollout_items: &[RolloutItem]) -> Option<TokenUsageInfo> {
         rollout_items.iter().rev().find_map(|item| match item {
             RolloutItem::EventMsg(EventMsg::TokenCount(ev)) => ev.info.clone(),
@@ -1796,6 +1873,7 @@ impl Session {
         turn_context: &TurnContext,
         call_id: String,
         changes: HashMap<PathBuf, FileChange>,
+        hunks: Vec<PatchHunk>,
         reason: Option<String>,
         grant_root: Option<PathBuf>,
     ) -> oneshot::Receiver<ReviewDecision> {
@@ -1821,6 +1899,7 @@ impl Session {
             call_id,
             turn_id: turn_context.sub_id.clone(),
             changes,
+            hunks,
             reason,
             grant_root,
         });
@@ -2231,6 +2310,48 @@ impl Session {
         state.set_dependency_env(values);
     }
 
+    pub(crate) async fn push_redo_entry(&self, entry: RedoEntry) {
+        let mut state = self.state.lock().await;
+        state.push_redo_entry(entry);
+    }
+
+    pub(crate) async fn pop_redo_entry(&self) -> Option<RedoEntry> {
+        let mut state = self.state.lock().await;
+        state.pop_redo_entry()
+    }
+
+    pub(crate) async fn clear_redo_stack(&self) {
+        let mut state = self.state.lock().await;
+        state.clear_redo_stack();
+    }
+
+    pub(crate) async fn redo_available(&self) -> bool {
+        let state = self.state.lock().await;
+        state.redo_available()
+    }
+
+    pub(crate) async fn mark_turn_completed(&self) {
+        let completed_at = SystemTime::now();
+        {
+            let mut state = self.state.lock().await;
+            state.set_last_turn_completed_at(completed_at);
+        }
+        // Persisted so undo can still spot edits made outside Codex after a resume.
+        let completed_at_ms = completed_at
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX))
+            .unwrap_or_default();
+        self.persist_rollout_items(&[RolloutItem::TurnCompleted(TurnCompletedItem {
+            completed_at_ms,
+        })])
+        .await;
+    }
+
+    pub(crate) async fn last_turn_completed_at(&self) -> Option<SystemTime> {
+        let state = self.state.lock().await;
+        state.last_turn_completed_at()
+    }
+
     pub(crate) async fn set_server_reasoning_included(&self, included: bool) {
         let mut state = self.state.lock().await;
         state.set_server_reasoning_included(included);
@@ -2359,7 +2480,7 @@ impl Session {
             return Err(SteerInputError::NoActiveTurn(input));
         };
 
-        let Some((active_turn_id, _)) = active_turn.tasks.first() else {
+        let Some((active_turn_id, task)) = active_turn.tasks.first() else {
             return Err(SteerInputError::NoActiveTurn(input));
         };
 
@@ -2373,7 +2494,10 @@ impl Session {
         }
 
         let mut turn_state = active_turn.turn_state.lock().await;
-        turn_state.push_pending_input(input.into());
+        turn_state.push_pending_input(ResponseInputItem::from_user_input(
+            input,
+            task.turn_context.pdf_token_budget(),
+        ));
         Ok(active_turn_id.clone())
     }
 
@@ -2519,6 +2643,12 @@ impl Session {
             use_linux_sandbox_bwrap: turn_context.features.enabled(Feature::UseLinuxSandboxBwrap),
         };
         let cancel_token = self.reset_mcp_startup_cancellation_token().await;
+        let sampling_handler = self
+            .services
+            .mcp_connection_manager
+            .read()
+            .await
+            .sampling_handler();
 
         let mut refreshed_manager = McpConnectionManager::default();
         refreshed_manager
@@ -2529,6 +2659,7 @@ impl Session {
                 self.get_tx_event(),
                 cancel_token,
                 sandbox_state,
+                sampling_handler,
             )
             .await;
 
@@ -2708,7 +2839,24 @@ async fn submission_loop(sess: Arc<Session>, config: Arc<Config>, rx_sub: Receiv
                 .await;
             }
             Op::Undo => {
-                handlers::undo(&sess, sub.id.clone()).await;
+                handlers::undo(&sess, sub.id.clone(), UndoTarget::Latest).await;
+            }
+            Op::ListUndoSnapshots => {
+                handlers::list_undo_snapshots(&sess, sub.id.clone()).await;
+            }
+            Op::UndoTo { snapshot_id, force } => {
+                handlers::undo(
+                    &sess,
+                    sub.id.clone(),
+                    UndoTarget::Snapshot {
+                        id: snapshot_id,
+                        force,
+                    },
+                )
+                .await;
+            }
+            Op::Redo => {
+                handlers::undo(&sess, sub.id.clone(), UndoTarget::Redo).await;
             }
             Op::Compact => {
                 handlers::compact(&sess, sub.id.clone()).await;
@@ -2728,6 +2876,23 @@ async fn submission_loop(sess: Arc<Session>, config: Arc<Config>, rx_sub: Receiv
                 )
                 .await;
             }
+            Op::ListBackgroundProcesses => {
+                handlers::list_background_processes(&sess, sub.id.clone()).await;
+            }
+            Op::ReadBackgroundProcessOutput { process_id } => {
+                handlers::read_background_process_output(&sess, sub.id.clone(), process_id).await;
+            }
+            Op::WriteBackgroundProcessStdin { process_id, input } => {
+                handlers::write_background_process_stdin(&sess, sub.id.clone(), process_id, input)
+                    .await;
+            }
+            Op::SignalBackgroundProcess { process_id, signal } => {
+                handlers::signal_background_process(&sess, sub.id.clone(), process_id, signal)
+                    .await;
+            }
+            Op::RelaunchBackgroundProcesses { process_ids } => {
+                handlers::relaunch_background_processes(&sess, sub.id.clone(), process_ids).await;
+            }
             Op::ResolveElicitation {
                 server_name,
                 request_id,
@@ -2766,15 +2931,26 @@ mod handlers {
     use crate::rollout::session_index;
     use crate::tasks::CompactTask;
     use crate::tasks::RegularTask;
+    use crate::tasks::UndoTarget;
     use crate::tasks::UndoTask;
     use crate::tasks::UserShellCommandMode;
     use crate::tasks::UserShellCommandTask;
     use crate::tasks::execute_user_shell_command;
+    use crate::unified_exec::RelaunchOutcome;
+    use crate::unified_exec::UnifiedExecContext;
+    use crate::unified_exec::UnifiedExecError;
+    use crate::unified_exec::relaunch_message;
     use codex_protocol::custom_prompts::CustomPrompt;
+    use codex_protocol::models::DeveloperInstructions;
+    use codex_protocol::models::ResponseItem;
+    use codex_protocol::protocol::BackgroundProcessItem;
+    use codex_protocol::protocol::BackgroundProcessOutputEvent;
+    use codex_protocol::protocol::BackgroundProcessSignal;
     use codex_protocol::protocol::CodexErrorInfo;
     use codex_protocol::protocol::ErrorEvent;
     use codex_protocol::protocol::Event;
     use codex_protocol::protocol::EventMsg;
+    use codex_protocol::protocol::ListBackgroundProcessesResponseEvent;
     use codex_protocol::protocol::ListCustomPromptsResponseEvent;
     use codex_protocol::protocol::ListRemoteSkillsResponseEvent;
     use codex_protocol::protocol::ListSkillsResponseEvent;
@@ -2784,6 +2960,7 @@ mod handlers {
     use codex_protocol::protocol::RemoteSkillSummary;
     use codex_protocol::protocol::ReviewDecision;
     use codex_protocol::protocol::ReviewRequest;
+    use codex_protocol::protocol::RolloutItem;
     use codex_protocol::protocol::SkillsListEntry;
     use codex_protocol::protocol::ThreadNameUpdatedEvent;
     use codex_protocol::protocol::ThreadRolledBackEvent;
@@ -2943,6 +3120,172 @@ mod handlers {
         *previous_context = Some(turn_context);
     }
 
+    pub async fn list_background_processes(sess: &Arc<Session>, sub_id: String) {
+        let processes = sess.services.unified_exec_manager.list_processes().await;
+        sess.send_event_raw(Event {
+            id: sub_id,
+            msg: EventMsg::ListBackgroundProcessesResponse(ListBackgroundProcessesResponseEvent {
+                processes,
+            }),
+        })
+        .await;
+    }
+
+    pub async fn read_background_process_output(
+        sess: &Arc<Session>,
+        sub_id: String,
+        process_id: String,
+    ) {
+        let msg = match sess
+            .services
+            .unified_exec_manager
+            .process_output(&process_id)
+            .await
+        {
+            Ok((output, running)) => {
+                EventMsg::BackgroundProcessOutput(BackgroundProcessOutputEvent {
+                    process_id,
+                    output: String::from_utf8_lossy(&output).into_owned(),
+                    running,
+                })
+            }
+            // The process exited and was cleaned up since the client last
+            // listed it; report it as finished rather than as an error.
+            Err(UnifiedExecError::UnknownProcessId { .. }) => {
+                EventMsg::BackgroundProcessOutput(BackgroundProcessOutputEvent {
+                    process_id,
+                    output: String::new(),
+                    running: false,
+                })
+            }
+            Err(err) => background_process_error(&process_id, &err),
+        };
+        sess.send_event_raw(Event { id: sub_id, msg }).await;
+    }
+
+    pub async fn write_background_process_stdin(
+        sess: &Arc<Session>,
+        sub_id: String,
+        process_id: String,
+        input: String,
+    ) {
+        if let Err(err) = sess
+            .services
+            .unified_exec_manager
+            .write_user_input(&process_id, input.as_bytes())
+            .await
+        {
+            sess.send_event_raw(Event {
+                id: sub_id,
+                msg: background_process_error(&process_id, &err),
+            })
+            .await;
+        }
+    }
+
+    pub async fn signal_background_process(
+        sess: &Arc<Session>,
+        sub_id: String,
+        process_id: String,
+        signal: BackgroundProcessSignal,
+    ) {
+        if let Err(err) = sess
+            .services
+            .unified_exec_manager
+            .signal_process(&process_id, signal)
+            .await
+        {
+            sess.send_event_raw(Event {
+                id: sub_id,
+                msg: background_process_error(&process_id, &err),
+            })
+            .await;
+        }
+    }
+
+    pub async fn relaunch_background_processes(
+        sess: &Arc<Session>,
+        sub_id: String,
+        process_ids: Vec<String>,
+    ) {
+        let launches = {
+            let mut state = sess.state.lock().await;
+            state.take_restorable_background_processes()
+        };
+        if launches.is_empty() {
+            return;
+        }
+        // Whatever the user picked, the old processes are settled and must not
+        // be offered again on the next resume.
+        let exited: Vec<RolloutItem> = launches
+            .iter()
+            .map(|launch| {
+                RolloutItem::BackgroundProcess(BackgroundProcessItem::Exited {
+                    process_id: launch.process_id.clone(),
+                })
+            })
+            .collect();
+        sess.persist_rollout_items(&exited).await;
+
+        let sess = Arc::clone(sess);
+        tokio::spawn(async move {
+            let turn_context = sess.new_default_turn_with_sub_id(sub_id.clone()).await;
+            let mut results = Vec::with_capacity(launches.len());
+            for launch in launches {
+                if !process_ids.contains(&launch.process_id) {
+                    results.push((launch, RelaunchOutcome::Skipped));
+                    continue;
+                }
+                let context = UnifiedExecContext::new(
+                    Arc::clone(&sess),
+                    Arc::clone(&turn_context),
+                    format!("relaunch-{}", launch.process_id),
+                );
+                let outcome = match sess
+                    .services
+                    .unified_exec_manager
+                    .relaunch_process(launch.clone(), &context)
+                    .await
+                {
+                    Ok(process_id) => RelaunchOutcome::Relaunched { process_id },
+                    Err(err) => {
+                        sess.send_event_raw(Event {
+                            id: sub_id.clone(),
+                            msg: background_process_error(&launch.process_id, &err),
+                        })
+                        .await;
+                        RelaunchOutcome::Failed {
+                            error: err.to_string(),
+                        }
+                    }
+                };
+                results.push((launch, outcome));
+            }
+            let message: ResponseItem =
+                DeveloperInstructions::new(relaunch_message(&results)).into();
+            sess.record_conversation_items(&turn_context, std::slice::from_ref(&message))
+                .await;
+        });
+    }
+
+    fn background_process_error(process_id: &str, err: &UnifiedExecError) -> EventMsg {
+        let message = match err {
+            UnifiedExecError::StdinClosed => {
+                format!(
+                    "background process {process_id} was started without a TTY and does not accept input"
+                )
+            }
+            UnifiedExecError::UnknownProcessId { .. } => {
+                format!("background process {process_id} is no longer running")
+            }
+            err => format!("background process {process_id}: {err}"),
+        };
+        EventMsg::Error(ErrorEvent {
+            message,
+            codex_error_info: Some(CodexErrorInfo::Other),
+        })
+    }
+
     pub async fn resolve_elicitation(
         sess: &Arc<Session>,
         server_name: String,
@@ -3228,10 +3571,25 @@ mod handlers {
         }
     }
 
-    pub async fn undo(sess: &Arc<Session>, sub_id: String) {
+    pub async fn undo(sess: &Arc<Session>, sub_id: String, target: UndoTarget) {
         let turn_context = sess.new_default_turn_with_sub_id(sub_id).await;
-        sess.spawn_task(turn_context, Vec::new(), UndoTask::new())
+        sess.spawn_task(turn_context, Vec::new(), UndoTask::new(target))
+            .await;
+    }
+
+    pub async fn list_undo_snapshots(sess: &Arc<Session>, sub_id: String) {
+        let sess = Arc::clone(sess);
+        // Snapshotting and diffing the working tree can take a while in large
+        // repositories, so keep it off the submission loop.
+        tokio::spawn(async move {
+            let turn_context = sess.new_default_turn_with_sub_id(sub_id.clone()).await;
+            let response = crate::tasks::list_undo_snapshots(&sess, &turn_context).await;
+            sess.send_event_raw(Event {
+                id: sub_id,
+                msg: EventMsg::ListUndoSnapshotsResponse(response),
+            })
             .await;
+        });
     }
 
     pub async fn compact(sess: &Arc<Session>, sub_id: String) {
@@ -3359,6 +3717,10 @@ mod handlers {
     }
 
     pub async fn shutdown(sess: &Arc<Session>, sub_id: String) -> bool {
+        sess.services
+            .unified_exec_manager
+            .preserve_for_resume()
+            .await;
         sess.abort_all_tasks(TurnAbortReason::Interrupted).await;
         sess.services
             .unified_exec_manager
@@ -3459,7 +3821,8 @@ async fn spawn_review_thread(
     let mut review_features = sess.features.clone();
     review_features
         .disable(crate::features::Feature::WebSearchRequest)
-        .disable(crate::features::Feature::WebSearchCached);
+        .disable(crate::features::Feature::WebSearchCached)
+        .disable(crate::features::Feature::WebFetch);
     let review_web_search_mode = WebSearchMode::Disabled;
     let tools_config = ToolsConfig::new(&ToolsConfigParams {
         model_info: &review_model_info,
@@ -3707,7 +4070,8 @@ pub(crate) async fn run_turn(
             .await;
     }
 
-    let initial_input_for_turn: ResponseInputItem = ResponseInputItem::from(input.clone());
+    let initial_input_for_turn =
+        ResponseInputItem::from_user_input(input.clone(), turn_context.pdf_token_budget());
     let response_item: ResponseItem = initial_input_for_turn.clone().into();
     sess.record_user_prompt_and_emit_turn_item(turn_context.as_ref(), &input, response_item)
         .await;
@@ -4030,13 +4394,23 @@ async fn run_sampling_request(
 
     let base_instructions = sess.get_base_instructions().await;
 
+    // Models that cannot call tools (e.g. some local models) reject requests
+    // that include them, so send the turn without any.
+    let tools = if turn_context.model_info.supports_tools {
+        router.specs()
+    } else {
+        Vec::new()
+    };
+
     let prompt = Prompt {
         input,
-        tools: router.specs(),
+        tools,
         parallel_tool_calls: model_supports_parallel,
         base_instructions,
         personality: turn_context.personality,
         output_schema: turn_context.final_output_json_schema.clone(),
+        max_output_tokens: None,
+        temperature: None,
     };
 
     let mut retries = 0;
@@ -5055,6 +5429,31 @@ mod tests {
         assert_eq!(expected, history.raw_items());
     }
 
+    #[tokio::test]
+    async fn record_initial_history_restores_last_turn_completion() {
+        let (session, _turn_context) = make_session_and_context().await;
+
+        session
+            .record_initial_history(InitialHistory::Resumed(ResumedHistory {
+                conversation_id: ThreadId::default(),
+                history: vec![
+                    RolloutItem::TurnCompleted(TurnCompletedItem {
+                        completed_at_ms: 1_000,
+                    }),
+                    RolloutItem::TurnCompleted(TurnCompletedItem {
+                        completed_at_ms: 2_000,
+                    }),
+                ],
+                rollout_path: PathBuf::from("/tmp/resume.jsonl"),
+            }))
+            .await;
+
+        assert_eq!(
+            session.last_turn_completed_at().await,
+            Some(UNIX_EPOCH + Duration::from_secs(2))
+        );
+    }
+
     #[tokio::test]
     async fn resumed_history_seeds_initial_context_on_first_turn_only() {
         let (session, turn_context) = make_session_and_context().await;
@@ -5762,6 +6161,8 @@ mod tests {
             mcp_connection_manager: Arc::new(RwLock::new(McpConnectionManager::default())),
             mcp_startup_cancellation_token: Mutex::new(CancellationToken::new()),
             unified_exec_manager: UnifiedExecProcessManager::default(),
+            web_fetch_cache: WebFetchCache::default(),
+            command_secrets: CommandSecrets::default(),
             analytics_events_client: AnalyticsEventsClient::new(
                 Arc::clone(&config),
                 Arc::clone(&auth_manager),
@@ -5892,6 +6293,8 @@ mod tests {
             mcp_connection_manager: Arc::new(RwLock::new(McpConnectionManager::default())),
             mcp_startup_cancellation_token: Mutex::new(CancellationToken::new()),
             unified_exec_manager: UnifiedExecProcessManager::default(),
+            web_fetch_cache: WebFetchCache::default(),
+            command_secrets: CommandSecrets::default(),
             analytics_events_client: AnalyticsEventsClient::new(
                 Arc::clone(&config),
                 Arc::clone(&auth_manager),
@@ -353,12 +353,13 @@ async fn handle_patch_approval(
     let ApplyPatchApprovalRequestEvent {
         call_id,
         changes,
+        hunks,
         reason,
         grant_root,
         ..
     } = event;
     let decision_rx = parent_session
-        .request_patch_approval(parent_ctx, call_id, changes, reason, grant_root)
+        .request_patch_approval(parent_ctx, call_id, changes, hunks, reason, grant_root)
         .await;
     let decision = await_approval_with_cancel(
         async move { decision_rx.await.unwrap_or_default() },
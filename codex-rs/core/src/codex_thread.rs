@@ -3,6 +3,7 @@ use crate::codex::Codex;
 use crate::codex::SteerInputError;
 use crate::error::Result as CodexResult;
 use crate::protocol::Event;
+use crate::protocol::ListUndoSnapshotsResponseEvent;
 use crate::protocol::Op;
 use crate::protocol::Submission;
 use codex_protocol::config_types::Personality;
@@ -83,4 +84,9 @@ impl CodexThread {
     pub async fn config_snapshot(&self) -> ThreadConfigSnapshot {
         self.codex.thread_config_snapshot().await
     }
+
+    /// Working tree snapshots this thread can undo to, newest first.
+    pub async fn list_undo_snapshots(&self) -> ListUndoSnapshotsResponseEvent {
+        self.codex.list_undo_snapshots().await
+    }
 }
@@ -0,0 +1,378 @@
+//! Secrets handed to commands as environment variables.
+//!
+//! `[secrets.expose]` names the secrets a session may pass to commands and,
+//! optionally, the command prefixes allowed to receive each one. Values are
+//! loaded once per session from the configured backend, injected at spawn time
+//! by [`crate::exec_env::inject_command_secrets`], and every occurrence of a
+//! value in command output is replaced with `[REDACTED:NAME]` before the output
+//! reaches the model, the rollout or telemetry.
+
+use std::borrow::Cow;
+use std::fmt;
+use std::sync::Arc;
+
+use codex_secrets::SecretName;
+use codex_secrets::SecretScope;
+use codex_secrets::SecretsManager;
+use codex_secrets::environment_id_from_cwd;
+use tracing::warn;
+
+use crate::config::Config;
+use crate::config::types::SecretExposure;
+use crate::exec::ExecToolCallOutput;
+
+/// Values shorter than this are injected but not redacted: they would match
+/// too much unrelated output to be useful.
+const MIN_REDACTED_LEN: usize = 4;
+
+/// Builds the secrets store configured for `config`.
+pub fn secrets_manager(config: &Config) -> SecretsManager {
+    SecretsManager::new(config.codex_home.clone(), config.secrets.backend.clone())
+}
+
+pub(crate) struct CommandSecret {
+    pub(crate) name: String,
+    pub(crate) value: String,
+    /// Split command prefixes allowed to receive the secret; `None` allows all.
+    pub(crate) commands: Option<Vec<Vec<String>>>,
+    marker: String,
+}
+
+/// The exposed secrets of a session. Cheap to clone.
+#[derive(Clone, Default)]
+pub struct CommandSecrets {
+    /// Sorted longest value first so a secret containing another is redacted whole.
+    secrets: Arc<Vec<CommandSecret>>,
+}
+
+impl CommandSecrets {
+    /// Loads the values of every exposed secret, preferring the repository
+    /// scope of the session's cwd over the global scope. Secrets that are
+    /// missing or fail to load are skipped with a warning.
+    pub async fn load(config: &Config) -> Self {
+        if config.secrets.expose.is_empty() {
+            return Self::default();
+        }
+        let manager = secrets_manager(config);
+        let exposures = config.secrets.expose.clone();
+        let environment_id = environment_id_from_cwd(&config.cwd);
+        let loaded =
+            tokio::task::spawn_blocking(move || load_values(&manager, exposures, environment_id))
+                .await;
+        match loaded {
+            Ok(values) => Self::new(values),
+            Err(err) => {
+                warn!("failed to load command secrets: {err}");
+                Self::default()
+            }
+        }
+    }
+
+    pub(crate) fn new(values: Vec<(SecretExposure, String)>) -> Self {
+        let mut secrets: Vec<CommandSecret> = values
+            .into_iter()
+            .filter(|(_, value)| !value.is_empty())
+            .map(|(exposure, value)| CommandSecret {
+                marker: format!("[REDACTED:{}]", exposure.name),
+                name: exposure.name,
+                value,
+                commands: exposure.commands,
+            })
+            .collect();
+        secrets.sort_by_key(|secret| std::cmp::Reverse(secret.value.len()));
+        Self {
+            secrets: Arc::new(secrets),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.secrets.is_empty()
+    }
+
+    pub(crate) fn iter(&self) -> impl Iterator<Item = &CommandSecret> {
+        self.secrets.iter()
+    }
+
+    fn redactable(&self) -> impl Iterator<Item = &CommandSecret> {
+        self.secrets
+            .iter()
+            .filter(|secret| secret.value.len() >= MIN_REDACTED_LEN)
+    }
+
+    /// Replaces every secret value in `text` with `[REDACTED:NAME]`.
+    pub fn redact<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        let mut redacted = Cow::Borrowed(text);
+        for secret in self.redactable() {
+            if redacted.contains(secret.value.as_str()) {
+                redacted = Cow::Owned(redacted.replace(secret.value.as_str(), &secret.marker));
+            }
+        }
+        redacted
+    }
+
+    /// Byte-level [`Self::redact`] for raw output chunks.
+    pub(crate) fn redact_bytes<'a>(&self, bytes: &'a [u8]) -> Cow<'a, [u8]> {
+        let mut redacted = Cow::Borrowed(bytes);
+        for secret in self.redactable() {
+            if let Some(replaced) =
+                replace_bytes(&redacted, secret.value.as_bytes(), secret.marker.as_bytes())
+            {
+                redacted = Cow::Owned(replaced);
+            }
+        }
+        redacted
+    }
+
+    pub(crate) fn redact_exec_output(&self, mut output: ExecToolCallOutput) -> ExecToolCallOutput {
+        if self.is_empty() {
+            return output;
+        }
+        for stream in [
+            &mut output.stdout,
+            &mut output.stderr,
+            &mut output.aggregated_output,
+        ] {
+            if let Cow::Owned(text) = self.redact(&stream.text) {
+                stream.text = text;
+            }
+        }
+        output
+    }
+}
+
+/// Redacts output that arrives in chunks, so a secret split across two reads
+/// is still caught. The tail of each chunk that could be the start of a secret
+/// is held back, unredacted, until the following chunk shows whether it is one.
+pub(crate) struct StreamRedactor {
+    secrets: CommandSecrets,
+    held: Vec<u8>,
+}
+
+impl StreamRedactor {
+    pub(crate) fn new(secrets: CommandSecrets) -> Self {
+        Self {
+            secrets,
+            held: Vec::new(),
+        }
+    }
+
+    /// Adds `chunk` to the stream and returns the redacted output that is
+    /// safe to emit now. May be empty while a possible secret is held back.
+    pub(crate) fn push(&mut self, chunk: &[u8]) -> Vec<u8> {
+        if self.secrets.is_empty() {
+            return chunk.to_vec();
+        }
+        self.held.extend_from_slice(chunk);
+        let split = self.safe_split();
+        let held = self.held.split_off(split);
+        let ready = std::mem::replace(&mut self.held, held);
+        self.secrets.redact_bytes(&ready).into_owned()
+    }
+
+    /// Returns the redacted remainder once the stream has ended.
+    pub(crate) fn finish(&mut self) -> Vec<u8> {
+        let held = std::mem::take(&mut self.held);
+        self.secrets.redact_bytes(&held).into_owned()
+    }
+
+    /// Index before which `held` can be redacted and emitted: past it is a
+    /// suffix that could begin a secret, and no complete secret crosses it.
+    fn safe_split(&self) -> usize {
+        let raw = self.held.as_slice();
+        let partial = self
+            .secrets
+            .redactable()
+            .map(|secret| {
+                let needle = secret.value.as_bytes();
+                (1..needle.len().min(raw.len() + 1))
+                    .rev()
+                    .find(|&len| raw.ends_with(&needle[..len]))
+                    .unwrap_or(0)
+            })
+            .max()
+            .unwrap_or(0);
+        let mut split = raw.len() - partial;
+        loop {
+            let crossing = self
+                .secrets
+                .redactable()
+                .filter_map(|secret| {
+                    let needle = secret.value.as_bytes();
+                    let first = split.saturating_sub(needle.len() - 1);
+                    (first..split).find(|&start| raw[start..].starts_with(needle))
+                })
+                .min();
+            match crossing {
+                Some(start) => split = start,
+                None => return split,
+            }
+        }
+    }
+}
+
+/// Only the names are printed so values cannot leak through debug logging.
+impl fmt::Debug for CommandSecrets {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.secrets.iter().map(|secret| &secret.name))
+            .finish()
+    }
+}
+
+fn load_values(
+    manager: &SecretsManager,
+    exposures: Vec<SecretExposure>,
+    environment_id: String,
+) -> Vec<(SecretExposure, String)> {
+    let scopes = [
+        SecretScope::Environment(environment_id),
+        SecretScope::Global,
+    ];
+    let mut values = Vec::with_capacity(exposures.len());
+    for exposure in exposures {
+        let name = match SecretName::new(&exposure.name) {
+            Ok(name) => name,
+            Err(err) => {
+                warn!("skipping secret {}: {err}", exposure.name);
+                continue;
+            }
+        };
+        let mut found = None;
+        for scope in &scopes {
+            match manager.get(scope, &name) {
+                Ok(Some(value)) => {
+                    found = Some(value);
+                    break;
+                }
+                Ok(None) => {}
+                Err(err) => warn!("failed to read secret {name}: {err:#}"),
+            }
+        }
+        match found {
+            Some(value) => values.push((exposure, value)),
+            None => warn!("secret {name} is listed in [secrets.expose] but has no value"),
+        }
+    }
+    values
+}
+
+fn replace_bytes(haystack: &[u8], needle: &[u8], replacement: &[u8]) -> Option<Vec<u8>> {
+    if needle.is_empty() || haystack.len() < needle.len() {
+        return None;
+    }
+    let mut out: Option<Vec<u8>> = None;
+    let mut copied_until = 0;
+    let mut idx = 0;
+    while idx + needle.len() <= haystack.len() {
+        if haystack[idx..].starts_with(needle) {
+            let buf = out.get_or_insert_with(|| Vec::with_capacity(haystack.len()));
+            buf.extend_from_slice(&haystack[copied_until..idx]);
+            buf.extend_from_slice(replacement);
+            idx += needle.len();
+            copied_until = idx;
+        } else {
+            idx += 1;
+        }
+    }
+    out.map(|mut buf| {
+        buf.extend_from_slice(&haystack[copied_until..]);
+        buf
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exec::StreamOutput;
+    use pretty_assertions::assert_eq;
+    use std::time::Duration;
+
+    fn secrets() -> CommandSecrets {
+        CommandSecrets::new(vec![
+            (
+                SecretExposure {
+                    name: "NPM_TOKEN".to_string(),
+                    commands: None,
+                },
+                "npm_abc123".to_string(),
+            ),
+            (
+                SecretExposure {
+                    name: "NPM_TOKEN_LONG".to_string(),
+                    commands: None,
+                },
+                "npm_abc123_extended".to_string(),
+            ),
+            (
+                SecretExposure {
+                    name: "PIN".to_string(),
+                    commands: None,
+                },
+                "42".to_string(),
+            ),
+        ])
+    }
+
+    #[test]
+    fn redacts_longest_values_first_and_skips_short_ones() {
+        let secrets = secrets();
+        assert_eq!(
+            secrets.redact("token=npm_abc123_extended other=npm_abc123 pin=42"),
+            "token=[REDACTED:NPM_TOKEN_LONG] other=[REDACTED:NPM_TOKEN] pin=42"
+        );
+        assert!(matches!(secrets.redact("nothing here"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn redacts_raw_bytes_and_exec_output() {
+        let secrets = secrets();
+        assert_eq!(
+            secrets.redact_bytes(b"\xffnpm_abc123\n").as_ref(),
+            b"\xff[REDACTED:NPM_TOKEN]\n"
+        );
+
+        let output = secrets.redact_exec_output(ExecToolCallOutput {
+            exit_code: 0,
+            stdout: StreamOutput::new("npm_abc123".to_string()),
+            stderr: StreamOutput::new(String::new()),
+            aggregated_output: StreamOutput::new("npm_abc123".to_string()),
+            duration: Duration::ZERO,
+            timed_out: false,
+        });
+        assert_eq!(output.stdout.text, "[REDACTED:NPM_TOKEN]");
+        assert_eq!(output.aggregated_output.text, "[REDACTED:NPM_TOKEN]");
+    }
+
+    fn stream(chunks: &[&[u8]]) -> (Vec<Vec<u8>>, Vec<u8>) {
+        let mut redactor = StreamRedactor::new(secrets());
+        let emitted = chunks.iter().map(|chunk| redactor.push(chunk)).collect();
+        (emitted, redactor.finish())
+    }
+
+    #[test]
+    fn stream_redactor_catches_secrets_split_across_chunks() {
+        let (emitted, rest) = stream(&[b"token=npm_ab", b"c123 done\n"]);
+        assert_eq!(
+            emitted,
+            vec![b"token=".to_vec(), b"[REDACTED:NPM_TOKEN] done\n".to_vec()]
+        );
+        assert_eq!(rest, b"");
+
+        // A shorter secret at the end of a chunk may be the start of a longer one.
+        let (emitted, rest) = stream(&[b"npm_abc123", b"_extended!"]);
+        assert_eq!(
+            emitted.concat(),
+            b"[REDACTED:NPM_TOKEN_LONG]!".to_vec(),
+            "held output: {rest:?}"
+        );
+
+        // A possible secret prefix is released at the end of the stream.
+        let (emitted, rest) = stream(&[b"ok npm_a"]);
+        assert_eq!(emitted, vec![b"ok ".to_vec()]);
+        assert_eq!(rest, b"npm_a");
+
+        let (emitted, rest) = stream(&[b"npm_abc123"]);
+        assert_eq!(emitted, vec![Vec::<u8>::new()]);
+        assert_eq!(rest, b"[REDACTED:NPM_TOKEN]");
+    }
+}
@@ -76,7 +76,8 @@ async fn run_compact_task_inner(
     let compaction_item = TurnItem::ContextCompaction(ContextCompactionItem::new());
     sess.emit_turn_item_started(&turn_context, &compaction_item)
         .await;
-    let initial_input_for_turn: ResponseInputItem = ResponseInputItem::from(input);
+    let initial_input_for_turn =
+        ResponseInputItem::from_user_input(input, turn_context.pdf_token_budget());
 
     let mut history = sess.clone_history().await;
     history.record_items(
@@ -190,6 +190,23 @@ mod document_helpers {
         {
             entry["scopes"] = array_from_iter(scopes.iter().cloned());
         }
+        if let Some(sandbox) = &config.sandbox {
+            let mut table = TomlTable::new();
+            table.set_implicit(false);
+            table["mode"] = value(sandbox.mode.to_string());
+            if sandbox.network_access {
+                table["network_access"] = value(true);
+            }
+            if !sandbox.writable_roots.is_empty() {
+                table["writable_roots"] = array_from_iter(
+                    sandbox
+                        .writable_roots
+                        .iter()
+                        .map(|root| root.to_string_lossy().to_string()),
+                );
+            }
+            entry["sandbox"] = TomlItem::Table(table);
+        }
 
         entry
     }
@@ -842,7 +859,9 @@ impl ConfigEditsBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::types::McpServerSandboxConfig;
     use crate::config::types::McpServerTransportConfig;
+    use codex_protocol::config_types::SandboxMode;
     use codex_protocol::openai_models::ReasoningEffort;
     use pretty_assertions::assert_eq;
     #[cfg(unix)]
@@ -1397,6 +1416,11 @@ gpt-5 = "gpt-5.1"
                 enabled_tools: Some(vec!["one".to_string(), "two".to_string()]),
                 disabled_tools: None,
                 scopes: None,
+                sandbox: Some(McpServerSandboxConfig {
+                    mode: SandboxMode::WorkspaceWrite,
+                    network_access: true,
+                    writable_roots: Vec::new(),
+                }),
             },
         );
 
@@ -1420,6 +1444,7 @@ gpt-5 = "gpt-5.1"
                 enabled_tools: None,
                 disabled_tools: Some(vec!["forbidden".to_string()]),
                 scopes: None,
+                sandbox: None,
             },
         );
 
@@ -1451,6 +1476,10 @@ enabled_tools = [\"one\", \"two\"]
 [mcp_servers.stdio.env]
 A = \"1\"
 B = \"2\"
+
+[mcp_servers.stdio.sandbox]
+mode = \"workspace-write\"
+network_access = true
 ";
         assert_eq!(raw, expected);
     }
@@ -1486,6 +1515,7 @@ foo = { command = "cmd" }
                 enabled_tools: None,
                 disabled_tools: None,
                 scopes: None,
+                sandbox: None,
             },
         );
 
@@ -1531,6 +1561,7 @@ foo = { command = "cmd" } # keep me
                 enabled_tools: None,
                 disabled_tools: None,
                 scopes: None,
+                sandbox: None,
             },
         );
 
@@ -1575,6 +1606,7 @@ foo = { command = "cmd", args = ["--flag"] } # keep me
                 enabled_tools: None,
                 disabled_tools: None,
                 scopes: None,
+                sandbox: None,
             },
         );
 
@@ -1620,6 +1652,7 @@ foo = { command = "cmd" }
                 enabled_tools: None,
                 disabled_tools: None,
                 scopes: None,
+                sandbox: None,
             },
         );
 
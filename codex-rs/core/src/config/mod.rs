@@ -1,11 +1,14 @@
 use crate::auth::AuthCredentialsStoreMode;
 use crate::config::edit::ConfigEdit;
 use crate::config::edit::ConfigEditsBuilder;
+use crate::config::types::ApprovalRules;
+use crate::config::types::ApprovalRulesToml;
 use crate::config::types::DEFAULT_OTEL_ENVIRONMENT;
 use crate::config::types::History;
 use crate::config::types::McpServerConfig;
 use crate::config::types::McpServerDisabledReason;
 use crate::config::types::McpServerTransportConfig;
+use crate::config::types::NetworkProxyToml;
 use crate::config::types::Notice;
 use crate::config::types::NotificationMethod;
 use crate::config::types::Notifications;
@@ -13,11 +16,19 @@ use crate::config::types::OtelConfig;
 use crate::config::types::OtelConfigToml;
 use crate::config::types::OtelExporterKind;
 use crate::config::types::SandboxWorkspaceWrite;
+use crate::config::types::SecretExposure;
+use crate::config::types::SecretsConfig;
+use crate::config::types::SecretsToml;
 use crate::config::types::ShellEnvironmentPolicy;
 use crate::config::types::ShellEnvironmentPolicyToml;
 use crate::config::types::SkillsConfig;
 use crate::config::types::Tui;
+use crate::config::types::TuiKeymap;
 use crate::config::types::UriBasedFileOpener;
+use crate::config::types::WebFetchConfig;
+use crate::config::types::WebFetchToml;
+use crate::config::types::WorktreeConfig;
+use crate::config::types::WorktreeToml;
 use crate::config_loader::CloudRequirementsLoader;
 use crate::config_loader::ConfigLayerStack;
 use crate::config_loader::ConfigRequirements;
@@ -57,6 +68,10 @@ use codex_protocol::config_types::WebSearchMode;
 use codex_protocol::config_types::WindowsSandboxLevel;
 use codex_protocol::openai_models::ReasoningEffort;
 use codex_rmcp_client::OAuthCredentialsStoreMode;
+use codex_secrets::CommandSecretsBackendConfig;
+use codex_secrets::DEFAULT_COMMAND_TIMEOUT;
+use codex_secrets::SecretsBackendConfig;
+use codex_secrets::SecretsBackendKind;
 use codex_utils_absolute_path::AbsolutePathBuf;
 use codex_utils_absolute_path::AbsolutePathBufGuard;
 use schemars::JsonSchema;
@@ -68,6 +83,7 @@ use std::collections::HashMap;
 use std::io::ErrorKind;
 use std::path::Path;
 use std::path::PathBuf;
+use std::time::Duration;
 #[cfg(test)]
 use tempfile::tempdir;
 
@@ -95,6 +111,8 @@ pub use codex_git::GhostSnapshotConfig;
 /// the context window.
 pub(crate) const PROJECT_DOC_MAX_BYTES: usize = 32 * 1024; // 32 KiB
 pub(crate) const DEFAULT_AGENT_MAX_THREADS: Option<usize> = Some(6);
+/// Matches the default listen address of the `network-proxy` crate.
+const DEFAULT_NETWORK_PROXY_URL: &str = "http://127.0.0.1:3128";
 
 pub const CONFIG_TOML_FILE: &str = "config.toml";
 
@@ -213,6 +231,9 @@ pub struct Config {
     /// Show startup tooltips in the TUI welcome screen.
     pub show_tooltips: bool,
 
+    /// Syntax-highlight code blocks and diffs in the TUI.
+    pub syntax_highlighting: bool,
+
     /// Start the TUI in the specified collaboration mode (plan/default).
     pub experimental_mode: Option<ModeKind>,
 
@@ -227,6 +248,12 @@ pub struct Config {
     /// Ordered list of status line item identifiers for the TUI.
     pub tui_status_line: Option<Vec<String>>,
 
+    /// Key binding overrides from `tui.keymap` (see [`TuiKeymap`]).
+    pub tui_keymap: TuiKeymap,
+
+    /// Whether the composer uses vi-style modal editing (`tui.vim_mode`).
+    pub tui_vim_mode: bool,
+
     /// The directory that should be treated as the current working directory
     /// for the session. All relative paths inside the business-logic layer are
     /// resolved against this path.
@@ -332,6 +359,18 @@ pub struct Config {
     /// Settings for ghost snapshots (used for undo).
     pub ghost_snapshot: GhostSnapshotConfig,
 
+    /// Settings for running the session in a dedicated git worktree.
+    pub worktree: WorktreeConfig,
+
+    /// Settings for the client-side `web_fetch` and `web_search_local` tools.
+    pub web_fetch: WebFetchConfig,
+
+    /// Secrets exposed to commands as environment variables.
+    pub secrets: SecretsConfig,
+
+    /// Approval decisions keyed on what a command does.
+    pub approval_rules: ApprovalRules,
+
     /// Centralized feature flags; source of truth for feature gating.
     pub features: Features,
 
@@ -962,6 +1001,27 @@ pub struct ConfigToml {
     #[serde(default)]
     pub ghost_snapshot: Option<GhostSnapshotToml>,
 
+    /// Run sessions in a dedicated git worktree on a fresh branch.
+    #[serde(default)]
+    pub worktree: Option<WorktreeToml>,
+
+    /// Settings for the client-side `web_fetch` and `web_search_local` tools.
+    #[serde(default)]
+    pub web_fetch: Option<WebFetchToml>,
+
+    /// Local network proxy; `web_fetch` routes requests through it when enabled.
+    #[serde(default)]
+    pub network_proxy: Option<NetworkProxyToml>,
+
+    /// Secrets exposed to commands as environment variables.
+    #[serde(default)]
+    pub secrets: Option<SecretsToml>,
+
+    /// Approval decisions keyed on what a command does: reads, searches,
+    /// tests, builds, installs, file and git mutations, and fetches.
+    #[serde(default)]
+    pub approval_rules: Option<ApprovalRulesToml>,
+
     /// Markers used to detect the project root when searching parent
     /// directories for `.codex` folders. Defaults to [".git"] when unset.
     #[serde(default)]
@@ -1300,6 +1360,98 @@ pub(crate) fn resolve_web_search_mode_for_turn(
     }
 }
 
+/// Layers each table in `tables` over the previous ones, key by key.
+fn resolve_approval_rules<'a>(
+    tables: impl IntoIterator<Item = &'a ApprovalRulesToml>,
+) -> std::io::Result<ApprovalRules> {
+    let mut rules = ApprovalRules::default();
+    for table in tables {
+        for (subcommand, decision) in &table.git {
+            if !crate::parse_command::GIT_MUTATING_SUBCOMMANDS.contains(&subcommand.as_str()) {
+                return Err(std::io::Error::new(
+                    ErrorKind::InvalidInput,
+                    format!(
+                        "approval_rules.git.{subcommand}: `git {subcommand}` is not a git mutation"
+                    ),
+                ));
+            }
+            rules.git.insert(subcommand.clone(), *decision);
+        }
+        rules.read = table.read.or(rules.read);
+        rules.list_files = table.list_files.or(rules.list_files);
+        rules.search = table.search.or(rules.search);
+        rules.test = table.test.or(rules.test);
+        rules.build = table.build.or(rules.build);
+        rules.install = table.install.or(rules.install);
+        rules.write = table.write.or(rules.write);
+        rules.r#move = table.r#move.or(rules.r#move);
+        rules.delete = table.delete.or(rules.delete);
+        rules.git_mutation = table.git_mutation.or(rules.git_mutation);
+        rules.fetch = table.fetch.or(rules.fetch);
+        rules.outside_workspace = table.outside_workspace.or(rules.outside_workspace);
+    }
+    Ok(rules)
+}
+
+fn resolve_secrets_config(secrets: SecretsToml) -> std::io::Result<SecretsConfig> {
+    let invalid = |message: String| std::io::Error::new(ErrorKind::InvalidInput, message);
+    let mut expose = Vec::with_capacity(secrets.expose.len());
+    for (name, exposure) in secrets.expose {
+        codex_secrets::SecretName::new(&name)
+            .map_err(|err| invalid(format!("secrets.expose.{name}: {err}")))?;
+        let commands = exposure
+            .commands
+            .map(|commands| {
+                commands
+                    .iter()
+                    .map(|command| match shlex::split(command) {
+                        Some(words) if !words.is_empty() => Ok(words),
+                        _ => Err(invalid(format!(
+                            "secrets.expose.{name}: invalid command prefix `{command}`"
+                        ))),
+                    })
+                    .collect::<std::io::Result<Vec<_>>>()
+            })
+            .transpose()?;
+        expose.push(SecretExposure { name, commands });
+    }
+    let backend = match (secrets.backend.unwrap_or_default(), secrets.command) {
+        (SecretsBackendKind::Local, _) => SecretsBackendConfig::Local,
+        (SecretsBackendKind::Command, None) => {
+            return Err(invalid(
+                "secrets.backend = \"command\" requires a [secrets.command] table".to_string(),
+            ));
+        }
+        (SecretsBackendKind::Command, Some(command)) => {
+            if command.get.is_empty() {
+                return Err(invalid("secrets.command.get must not be empty".to_string()));
+            }
+            for (operation, template) in [
+                ("set", &command.set),
+                ("list", &command.list),
+                ("delete", &command.delete),
+            ] {
+                if template.as_ref().is_some_and(Vec::is_empty) {
+                    return Err(invalid(format!(
+                        "secrets.command.{operation} must not be empty"
+                    )));
+                }
+            }
+            SecretsBackendConfig::Command(CommandSecretsBackendConfig {
+                get: command.get,
+                set: command.set,
+                list: command.list,
+                delete: command.delete,
+                missing_exit_code: command.missing_exit_code,
+                timeout: command
+                    .timeout_ms
+                    .map_or(DEFAULT_COMMAND_TIMEOUT, Duration::from_millis),
+            })
+        }
+    };
+    Ok(SecretsConfig { backend, expose })
+}
+
 impl Config {
     #[cfg(test)]
     fn load_from_base_config_with_overrides(
@@ -1566,6 +1718,47 @@ impl Config {
 
         let check_for_update_on_startup = cfg.check_for_update_on_startup.unwrap_or(true);
 
+        let worktree = {
+            let worktree = cfg.worktree.clone().unwrap_or_default();
+            WorktreeConfig {
+                enabled: worktree.enabled.unwrap_or(false),
+                directory: worktree
+                    .directory
+                    .as_ref()
+                    .map(AbsolutePathBuf::to_path_buf)
+                    .unwrap_or_else(|| codex_home.join("worktrees")),
+                on_exit: worktree.on_exit.unwrap_or_default(),
+            }
+        };
+
+        let web_fetch = WebFetchConfig {
+            search_backend: cfg
+                .web_fetch
+                .as_ref()
+                .and_then(|web_fetch| web_fetch.search_backend.clone()),
+            proxy_url: cfg
+                .network_proxy
+                .as_ref()
+                .filter(|proxy| proxy.enabled)
+                .map(|proxy| {
+                    proxy
+                        .proxy_url
+                        .clone()
+                        .unwrap_or_else(|| DEFAULT_NETWORK_PROXY_URL.to_string())
+                }),
+        };
+
+        let secrets = resolve_secrets_config(cfg.secrets.clone().unwrap_or_default())?;
+
+        let approval_rules = resolve_approval_rules(
+            [
+                cfg.approval_rules.as_ref(),
+                config_profile.approval_rules.as_ref(),
+            ]
+            .into_iter()
+            .flatten(),
+        )?;
+
         let log_dir = cfg
             .log_dir
             .as_ref()
@@ -1673,6 +1866,10 @@ impl Config {
             web_search_mode,
             use_experimental_unified_exec_tool,
             ghost_snapshot,
+            worktree,
+            web_fetch,
+            secrets,
+            approval_rules,
             features,
             suppress_unstable_features_warning: cfg
                 .suppress_unstable_features_warning
@@ -1705,6 +1902,11 @@ impl Config {
                 .unwrap_or_default(),
             animations: cfg.tui.as_ref().map(|t| t.animations).unwrap_or(true),
             show_tooltips: cfg.tui.as_ref().map(|t| t.show_tooltips).unwrap_or(true),
+            syntax_highlighting: cfg
+                .tui
+                .as_ref()
+                .map(|t| t.syntax_highlighting)
+                .unwrap_or(true),
             experimental_mode: cfg.tui.as_ref().and_then(|t| t.experimental_mode),
             tui_alternate_screen: cfg
                 .tui
@@ -1712,6 +1914,12 @@ impl Config {
                 .map(|t| t.alternate_screen)
                 .unwrap_or_default(),
             tui_status_line: cfg.tui.as_ref().and_then(|t| t.status_line.clone()),
+            tui_keymap: cfg
+                .tui
+                .as_ref()
+                .map(|t| t.keymap.clone())
+                .unwrap_or_default(),
+            tui_vim_mode: cfg.tui.as_ref().is_some_and(|t| t.vim_mode),
             otel: {
                 let t: OtelConfigToml = cfg.otel.unwrap_or_default();
                 let log_user_prompt = t.log_user_prompt.unwrap_or(false);
@@ -1842,11 +2050,15 @@ mod tests {
     use crate::config::edit::ConfigEdit;
     use crate::config::edit::ConfigEditsBuilder;
     use crate::config::edit::apply_blocking;
+    use crate::config::types::ApprovalRuleDecision;
     use crate::config::types::FeedbackConfigToml;
     use crate::config::types::HistoryPersistence;
+    use crate::config::types::KeyChords;
     use crate::config::types::McpServerTransportConfig;
     use crate::config::types::NotificationMethod;
     use crate::config::types::Notifications;
+    use crate::config::types::WebSearchBackendToml;
+    use crate::config::types::WorktreeExitAction;
     use crate::config_loader::RequirementSource;
     use crate::features::Feature;
 
@@ -1875,6 +2087,7 @@ mod tests {
             enabled_tools: None,
             disabled_tools: None,
             scopes: None,
+            sandbox: None,
         }
     }
 
@@ -1893,6 +2106,7 @@ mod tests {
             enabled_tools: None,
             disabled_tools: None,
             scopes: None,
+            sandbox: None,
         }
     }
 
@@ -1945,11 +2159,45 @@ persistence = "none"
                 notification_method: NotificationMethod::Auto,
                 animations: true,
                 show_tooltips: true,
+                syntax_highlighting: true,
                 experimental_mode: None,
                 alternate_screen: AltScreenMode::Auto,
                 status_line: None,
+                keymap: TuiKeymap::default(),
+                vim_mode: false,
+            }
+        );
+    }
+
+    #[test]
+    fn tui_keymap_accepts_single_and_multiple_chords() {
+        let cfg = r#"
+[tui.keymap]
+submit = "ctrl+enter"
+newline = ["enter", "shift+enter"]
+"#;
+
+        let parsed = toml::from_str::<ConfigToml>(cfg).expect("keymap should parse");
+        let keymap = parsed
+            .tui
+            .expect("config should include tui section")
+            .keymap;
+
+        assert_eq!(
+            keymap,
+            TuiKeymap {
+                submit: Some(KeyChords::One("ctrl+enter".to_string())),
+                newline: Some(KeyChords::Many(vec![
+                    "enter".to_string(),
+                    "shift+enter".to_string(),
+                ])),
+                ..Default::default()
             }
         );
+        assert_eq!(
+            keymap.newline.as_ref().map(KeyChords::chords),
+            Some(&["enter".to_string(), "shift+enter".to_string()][..])
+        );
     }
 
     #[test]
@@ -2654,6 +2902,244 @@ profile = "project"
         Ok(())
     }
 
+    #[test]
+    fn worktree_config_defaults_to_codex_home_and_ask() -> std::io::Result<()> {
+        let codex_home = TempDir::new()?;
+        let cfg = toml::from_str::<ConfigToml>(
+            r#"
+[worktree]
+enabled = true
+"#,
+        )
+        .expect("worktree config should parse");
+
+        let config = Config::load_from_base_config_with_overrides(
+            cfg,
+            ConfigOverrides::default(),
+            codex_home.path().to_path_buf(),
+        )?;
+
+        assert_eq!(
+            config.worktree,
+            WorktreeConfig {
+                enabled: true,
+                directory: codex_home.path().join("worktrees"),
+                on_exit: WorktreeExitAction::Ask,
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn web_fetch_config_routes_through_enabled_network_proxy() -> std::io::Result<()> {
+        let codex_home = TempDir::new()?;
+        let cfg = toml::from_str::<ConfigToml>(
+            r#"
+[web_fetch.search_backend]
+type = "searxng"
+url = "http://localhost:8888/search"
+
+[network_proxy]
+enabled = true
+mode = "limited"
+"#,
+        )
+        .expect("web_fetch config should parse");
+
+        let config = Config::load_from_base_config_with_overrides(
+            cfg,
+            ConfigOverrides::default(),
+            codex_home.path().to_path_buf(),
+        )?;
+
+        assert_eq!(
+            config.web_fetch,
+            WebFetchConfig {
+                search_backend: Some(WebSearchBackendToml::Searxng {
+                    url: "http://localhost:8888/search".to_string(),
+                    api_key_env: None,
+                }),
+                proxy_url: Some(DEFAULT_NETWORK_PROXY_URL.to_string()),
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn approval_rules_layer_profile_over_top_level() -> std::io::Result<()> {
+        let codex_home = TempDir::new()?;
+        let cfg = toml::from_str::<ConfigToml>(
+            r#"
+profile = "careful"
+
+[approval_rules]
+read = "allow"
+test = "allow"
+install = "prompt"
+
+[approval_rules.git]
+push = "prompt"
+
+[profiles.careful.approval_rules]
+test = "prompt"
+outside_workspace = "forbidden"
+
+[profiles.careful.approval_rules.git]
+commit = "allow"
+"#,
+        )
+        .expect("approval rules should parse");
+
+        let config = Config::load_from_base_config_with_overrides(
+            cfg,
+            ConfigOverrides::default(),
+            codex_home.path().to_path_buf(),
+        )?;
+
+        assert_eq!(
+            config.approval_rules,
+            ApprovalRules {
+                read: Some(ApprovalRuleDecision::Allow),
+                test: Some(ApprovalRuleDecision::Prompt),
+                install: Some(ApprovalRuleDecision::Prompt),
+                git: HashMap::from([
+                    ("push".to_string(), ApprovalRuleDecision::Prompt),
+                    ("commit".to_string(), ApprovalRuleDecision::Allow),
+                ]),
+                outside_workspace: Some(ApprovalRuleDecision::Forbidden),
+                ..Default::default()
+            }
+        );
+
+        let cfg = toml::from_str::<ConfigToml>(
+            r#"
+[approval_rules.git]
+status = "allow"
+"#,
+        )
+        .expect("approval rules should parse");
+        let err = Config::load_from_base_config_with_overrides(
+            cfg,
+            ConfigOverrides::default(),
+            codex_home.path().to_path_buf(),
+        )
+        .expect_err("non-mutating git subcommands are rejected");
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        Ok(())
+    }
+
+    #[test]
+    fn secrets_expose_splits_command_prefixes_and_rejects_bad_names() -> std::io::Result<()> {
+        let codex_home = TempDir::new()?;
+        let cfg = toml::from_str::<ConfigToml>(
+            r#"
+[secrets.expose.NPM_TOKEN]
+commands = ["npm publish", "pnpm publish --access public"]
+
+[secrets.expose.GH_TOKEN]
+"#,
+        )
+        .expect("secrets config should parse");
+
+        let config = Config::load_from_base_config_with_overrides(
+            cfg,
+            ConfigOverrides::default(),
+            codex_home.path().to_path_buf(),
+        )?;
+
+        assert_eq!(
+            config.secrets,
+            SecretsConfig {
+                backend: SecretsBackendConfig::Local,
+                expose: vec![
+                    SecretExposure {
+                        name: "GH_TOKEN".to_string(),
+                        commands: None,
+                    },
+                    SecretExposure {
+                        name: "NPM_TOKEN".to_string(),
+                        commands: Some(vec![
+                            vec!["npm".to_string(), "publish".to_string()],
+                            vec![
+                                "pnpm".to_string(),
+                                "publish".to_string(),
+                                "--access".to_string(),
+                                "public".to_string(),
+                            ],
+                        ]),
+                    },
+                ],
+            }
+        );
+
+        let cfg = toml::from_str::<ConfigToml>("[secrets.expose.npm_token]\n")
+            .expect("secrets config should parse");
+        let err = Config::load_from_base_config_with_overrides(
+            cfg,
+            ConfigOverrides::default(),
+            codex_home.path().to_path_buf(),
+        )
+        .expect_err("lowercase secret names are rejected");
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        Ok(())
+    }
+
+    #[test]
+    fn secrets_command_backend_resolves_templates() -> std::io::Result<()> {
+        let codex_home = TempDir::new()?;
+        let cfg = toml::from_str::<ConfigToml>(
+            r#"
+[secrets]
+backend = "command"
+
+[secrets.command]
+get = ["pass", "show", "codex/{key}"]
+set = ["pass", "insert", "--multiline", "codex/{key}"]
+missing_exit_code = 1
+timeout_ms = 2500
+"#,
+        )
+        .expect("secrets config should parse");
+
+        let config = Config::load_from_base_config_with_overrides(
+            cfg,
+            ConfigOverrides::default(),
+            codex_home.path().to_path_buf(),
+        )?;
+
+        assert_eq!(
+            config.secrets.backend,
+            SecretsBackendConfig::Command(CommandSecretsBackendConfig {
+                get: vec![
+                    "pass".to_string(),
+                    "show".to_string(),
+                    "codex/{key}".to_string()
+                ],
+                set: Some(vec![
+                    "pass".to_string(),
+                    "insert".to_string(),
+                    "--multiline".to_string(),
+                    "codex/{key}".to_string(),
+                ]),
+                list: None,
+                delete: None,
+                missing_exit_code: Some(1),
+                timeout: Duration::from_millis(2500),
+            })
+        );
+
+        let cfg = toml::from_str::<ConfigToml>("[secrets]\nbackend = \"command\"\n")
+            .expect("secrets config should parse");
+        let err = Config::load_from_base_config_with_overrides(
+            cfg,
+            ConfigOverrides::default(),
+            codex_home.path().to_path_buf(),
+        )
+        .expect_err("command backend without commands is rejected");
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        Ok(())
+    }
+
     #[test]
     fn config_honors_explicit_file_oauth_store_mode() -> std::io::Result<()> {
         let codex_home = TempDir::new()?;
@@ -2759,6 +3245,7 @@ profile = "project"
                 enabled_tools: None,
                 disabled_tools: None,
                 scopes: None,
+                sandbox: None,
             },
         );
 
@@ -2915,6 +3402,7 @@ bearer_token = "secret"
                 enabled_tools: None,
                 disabled_tools: None,
                 scopes: None,
+                sandbox: None,
             },
         )]);
 
@@ -2985,6 +3473,7 @@ ZIG_VAR = "3"
                 enabled_tools: None,
                 disabled_tools: None,
                 scopes: None,
+                sandbox: None,
             },
         )]);
 
@@ -3035,6 +3524,7 @@ ZIG_VAR = "3"
                 enabled_tools: None,
                 disabled_tools: None,
                 scopes: None,
+                sandbox: None,
             },
         )]);
 
@@ -3083,6 +3573,7 @@ ZIG_VAR = "3"
                 enabled_tools: None,
                 disabled_tools: None,
                 scopes: None,
+                sandbox: None,
             },
         )]);
 
@@ -3147,6 +3638,7 @@ startup_timeout_sec = 2.0
                 enabled_tools: None,
                 disabled_tools: None,
                 scopes: None,
+                sandbox: None,
             },
         )]);
         apply_blocking(
@@ -3223,6 +3715,7 @@ X-Auth = "DOCS_AUTH"
                 enabled_tools: None,
                 disabled_tools: None,
                 scopes: None,
+                sandbox: None,
             },
         )]);
 
@@ -3252,6 +3745,7 @@ X-Auth = "DOCS_AUTH"
                 enabled_tools: None,
                 disabled_tools: None,
                 scopes: None,
+                sandbox: None,
             },
         );
         apply_blocking(
@@ -3319,6 +3813,7 @@ url = "https://example.com/mcp"
                     enabled_tools: None,
                     disabled_tools: None,
                     scopes: None,
+                    sandbox: None,
                 },
             ),
             (
@@ -3338,6 +3833,7 @@ url = "https://example.com/mcp"
                     enabled_tools: None,
                     disabled_tools: None,
                     scopes: None,
+                    sandbox: None,
                 },
             ),
         ]);
@@ -3420,6 +3916,7 @@ url = "https://example.com/mcp"
                 enabled_tools: None,
                 disabled_tools: None,
                 scopes: None,
+                sandbox: None,
             },
         )]);
 
@@ -3464,6 +3961,7 @@ url = "https://example.com/mcp"
                 enabled_tools: Some(vec!["allowed".to_string()]),
                 disabled_tools: Some(vec!["blocked".to_string()]),
                 scopes: None,
+                sandbox: None,
             },
         )]);
 
@@ -3776,6 +4274,8 @@ model_verbosity = "high"
             stream_idle_timeout_ms: Some(300_000),
             requires_openai_auth: false,
             supports_websockets: false,
+            models: HashMap::new(),
+            list_models: false,
         };
         let model_provider_map = {
             let mut model_provider_map = built_in_model_providers();
@@ -3874,6 +4374,14 @@ model_verbosity = "high"
                 web_search_mode: None,
                 use_experimental_unified_exec_tool: !cfg!(windows),
                 ghost_snapshot: GhostSnapshotConfig::default(),
+                worktree: WorktreeConfig {
+                    enabled: false,
+                    directory: fixture.codex_home().join("worktrees"),
+                    on_exit: WorktreeExitAction::Ask,
+                },
+                web_fetch: WebFetchConfig::default(),
+                secrets: SecretsConfig::default(),
+                approval_rules: ApprovalRules::default(),
                 features: Features::with_defaults(),
                 suppress_unstable_features_warning: false,
                 active_profile: Some("o3".to_string()),
@@ -3886,11 +4394,14 @@ model_verbosity = "high"
                 tui_notification_method: Default::default(),
                 animations: true,
                 show_tooltips: true,
+                syntax_highlighting: true,
                 experimental_mode: None,
                 analytics_enabled: Some(true),
                 feedback_enabled: true,
                 tui_alternate_screen: AltScreenMode::Auto,
                 tui_status_line: None,
+                tui_keymap: TuiKeymap::default(),
+                tui_vim_mode: false,
                 otel: OtelConfig::default(),
             },
             o3_profile_config
@@ -3961,6 +4472,14 @@ model_verbosity = "high"
             web_search_mode: None,
             use_experimental_unified_exec_tool: !cfg!(windows),
             ghost_snapshot: GhostSnapshotConfig::default(),
+            worktree: WorktreeConfig {
+                enabled: false,
+                directory: fixture.codex_home().join("worktrees"),
+                on_exit: WorktreeExitAction::Ask,
+            },
+            web_fetch: WebFetchConfig::default(),
+            secrets: SecretsConfig::default(),
+            approval_rules: ApprovalRules::default(),
             features: Features::with_defaults(),
             suppress_unstable_features_warning: false,
             active_profile: Some("gpt3".to_string()),
@@ -3973,11 +4492,14 @@ model_verbosity = "high"
             tui_notification_method: Default::default(),
             animations: true,
             show_tooltips: true,
+            syntax_highlighting: true,
             experimental_mode: None,
             analytics_enabled: Some(true),
             feedback_enabled: true,
             tui_alternate_screen: AltScreenMode::Auto,
             tui_status_line: None,
+            tui_keymap: TuiKeymap::default(),
+            tui_vim_mode: false,
             otel: OtelConfig::default(),
         };
 
@@ -4063,6 +4585,14 @@ model_verbosity = "high"
             web_search_mode: None,
             use_experimental_unified_exec_tool: !cfg!(windows),
             ghost_snapshot: GhostSnapshotConfig::default(),
+            worktree: WorktreeConfig {
+                enabled: false,
+                directory: fixture.codex_home().join("worktrees"),
+                on_exit: WorktreeExitAction::Ask,
+            },
+            web_fetch: WebFetchConfig::default(),
+            secrets: SecretsConfig::default(),
+            approval_rules: ApprovalRules::default(),
             features: Features::with_defaults(),
             suppress_unstable_features_warning: false,
             active_profile: Some("zdr".to_string()),
@@ -4075,11 +4605,14 @@ model_verbosity = "high"
             tui_notification_method: Default::default(),
             animations: true,
             show_tooltips: true,
+            syntax_highlighting: true,
             experimental_mode: None,
             analytics_enabled: Some(false),
             feedback_enabled: true,
             tui_alternate_screen: AltScreenMode::Auto,
             tui_status_line: None,
+            tui_keymap: TuiKeymap::default(),
+            tui_vim_mode: false,
             otel: OtelConfig::default(),
         };
 
@@ -4151,6 +4684,14 @@ model_verbosity = "high"
             web_search_mode: None,
             use_experimental_unified_exec_tool: !cfg!(windows),
             ghost_snapshot: GhostSnapshotConfig::default(),
+            worktree: WorktreeConfig {
+                enabled: false,
+                directory: fixture.codex_home().join("worktrees"),
+                on_exit: WorktreeExitAction::Ask,
+            },
+            web_fetch: WebFetchConfig::default(),
+            secrets: SecretsConfig::default(),
+            approval_rules: ApprovalRules::default(),
             features: Features::with_defaults(),
             suppress_unstable_features_warning: false,
             active_profile: Some("gpt5".to_string()),
@@ -4163,11 +4704,14 @@ model_verbosity = "high"
             tui_notification_method: Default::default(),
             animations: true,
             show_tooltips: true,
+            syntax_highlighting: true,
             experimental_mode: None,
             analytics_enabled: Some(true),
             feedback_enabled: true,
             tui_alternate_screen: AltScreenMode::Auto,
             tui_status_line: None,
+            tui_keymap: TuiKeymap::default(),
+            tui_vim_mode: false,
             otel: OtelConfig::default(),
         };
 
@@ -40,6 +40,9 @@ pub struct ConfigProfile {
     pub tools_view_image: Option<bool>,
     pub web_search: Option<WebSearchMode>,
     pub analytics: Option<crate::config::types::AnalyticsConfigToml>,
+    /// Approval rules for this profile, layered over the top-level
+    /// `[approval_rules]` table.
+    pub approval_rules: Option<crate::config::types::ApprovalRulesToml>,
     /// Optional feature toggles scoped to this profile.
     #[serde(default)]
     // Injects known feature keys into the schema and forbids unknown keys.
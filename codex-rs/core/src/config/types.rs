@@ -7,7 +7,11 @@ use crate::config_loader::RequirementSource;
 pub use codex_protocol::config_types::AltScreenMode;
 pub use codex_protocol::config_types::ModeKind;
 pub use codex_protocol::config_types::Personality;
+use codex_protocol::config_types::SandboxMode;
 pub use codex_protocol::config_types::WebSearchMode;
+use codex_protocol::protocol::SandboxPolicy;
+use codex_secrets::SecretsBackendConfig;
+use codex_secrets::SecretsBackendKind;
 use codex_utils_absolute_path::AbsolutePathBuf;
 use std::collections::BTreeMap;
 use std::collections::HashMap;
@@ -77,6 +81,39 @@ pub struct McpServerConfig {
     /// Optional OAuth scopes to request during MCP login.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub scopes: Option<Vec<String>>,
+
+    /// Sandbox applied to the server process. Only supported for stdio servers;
+    /// when unset the server runs with the user's full privileges.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sandbox: Option<McpServerSandboxConfig>,
+}
+
+/// Sandbox applied to a stdio MCP server process.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct McpServerSandboxConfig {
+    pub mode: SandboxMode,
+    /// Allow outbound network access. Only supported with `workspace-write`.
+    #[serde(default)]
+    pub network_access: bool,
+    /// Folders writable in addition to the session's working directory.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub writable_roots: Vec<AbsolutePathBuf>,
+}
+
+impl McpServerSandboxConfig {
+    pub fn sandbox_policy(&self) -> SandboxPolicy {
+        match self.mode {
+            SandboxMode::ReadOnly => SandboxPolicy::ReadOnly,
+            SandboxMode::WorkspaceWrite => SandboxPolicy::WorkspaceWrite {
+                writable_roots: self.writable_roots.clone(),
+                network_access: self.network_access,
+                exclude_tmpdir_env_var: false,
+                exclude_slash_tmp: false,
+            },
+            SandboxMode::DangerFullAccess => SandboxPolicy::DangerFullAccess,
+        }
+    }
 }
 
 // Raw MCP config shape used for deserialization and JSON Schema generation.
@@ -119,6 +156,8 @@ pub(crate) struct RawMcpServerConfig {
     pub disabled_tools: Option<Vec<String>>,
     #[serde(default)]
     pub scopes: Option<Vec<String>>,
+    #[serde(default)]
+    pub sandbox: Option<McpServerSandboxConfig>,
 }
 
 impl<'de> Deserialize<'de> for McpServerConfig {
@@ -141,6 +180,15 @@ impl<'de> Deserialize<'de> for McpServerConfig {
         let enabled_tools = raw.enabled_tools.clone();
         let disabled_tools = raw.disabled_tools.clone();
         let scopes = raw.scopes.clone();
+        let sandbox = raw.sandbox.take();
+        if let Some(sandbox) = &sandbox
+            && sandbox.network_access
+            && sandbox.mode != SandboxMode::WorkspaceWrite
+        {
+            return Err(SerdeError::custom(
+                "sandbox.network_access is only supported with mode = \"workspace-write\"",
+            ));
+        }
 
         fn throw_if_set<E, T>(transport: &str, field: &str, value: Option<&T>) -> Result<(), E>
         where
@@ -177,6 +225,7 @@ impl<'de> Deserialize<'de> for McpServerConfig {
             throw_if_set("streamable_http", "env_vars", raw.env_vars.as_ref())?;
             throw_if_set("streamable_http", "cwd", raw.cwd.as_ref())?;
             throw_if_set("streamable_http", "bearer_token", raw.bearer_token.as_ref())?;
+            throw_if_set("streamable_http", "sandbox", sandbox.as_ref())?;
             McpServerTransportConfig::StreamableHttp {
                 url,
                 bearer_token_env_var: raw.bearer_token_env_var.clone(),
@@ -196,6 +245,7 @@ impl<'de> Deserialize<'de> for McpServerConfig {
             enabled_tools,
             disabled_tools,
             scopes,
+            sandbox,
         })
     }
 }
@@ -315,6 +365,228 @@ pub enum HistoryPersistence {
     None,
 }
 
+// ===== Worktree configuration =====
+
+/// Settings for running sessions in a dedicated `git worktree`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct WorktreeToml {
+    /// When `true`, each session runs in a fresh worktree on its own branch
+    /// instead of editing the current checkout. Defaults to `false`.
+    pub enabled: Option<bool>,
+
+    /// Directory under which session worktrees are created.
+    /// Defaults to `$CODEX_HOME/worktrees`.
+    pub directory: Option<AbsolutePathBuf>,
+
+    /// What to do with the session worktree when the session ends.
+    /// Defaults to `ask`.
+    pub on_exit: Option<WorktreeExitAction>,
+}
+
+/// How the changes made in a session worktree are brought back.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, Default, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum WorktreeExitAction {
+    /// Ask interactively; non-interactive sessions keep the worktree.
+    #[default]
+    Ask,
+    /// Merge the session branch into the current branch.
+    Merge,
+    /// Cherry-pick the session commits onto the current branch.
+    CherryPick,
+    /// Delete the worktree and its branch.
+    Discard,
+    /// Leave the worktree and branch in place.
+    Keep,
+}
+
+/// Effective worktree settings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorktreeConfig {
+    pub enabled: bool,
+    pub directory: PathBuf,
+    pub on_exit: WorktreeExitAction,
+}
+
+// ===== Web fetch configuration =====
+
+/// Settings for the client-side `web_fetch` and `web_search_local` tools.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct WebFetchToml {
+    /// Search backend used by `web_search_local`. The tool is only exposed
+    /// when a backend is configured.
+    pub search_backend: Option<WebSearchBackendToml>,
+}
+
+/// A search endpoint queried by the `web_search_local` tool.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum WebSearchBackendToml {
+    /// A SearxNG-compatible endpoint that answers `GET <url>?q=...&format=json`.
+    Searxng {
+        url: String,
+        /// Environment variable holding a bearer token for the endpoint.
+        #[serde(default)]
+        api_key_env: Option<String>,
+    },
+}
+
+/// The subset of the `[network_proxy]` table that core reads to route
+/// `web_fetch` requests through the proxy policy.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default, JsonSchema)]
+pub struct NetworkProxyToml {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Defaults to `http://127.0.0.1:3128`.
+    pub proxy_url: Option<String>,
+}
+
+/// Effective web fetch settings.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct WebFetchConfig {
+    pub search_backend: Option<WebSearchBackendToml>,
+    /// Proxy that fetches are routed through when the network proxy is enabled.
+    pub proxy_url: Option<String>,
+}
+
+// ===== Secrets configuration =====
+
+/// Settings for secrets that commands may receive as environment variables.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct SecretsToml {
+    /// Where secret values are stored. Defaults to `local`; `command` uses the
+    /// commands configured under `[secrets.command]`.
+    pub backend: Option<SecretsBackendKind>,
+
+    /// Commands that read and write secrets in an external store.
+    pub command: Option<SecretsCommandToml>,
+
+    /// Secrets exposed to commands, keyed by secret name. The name is also the
+    /// environment variable the value is injected as.
+    #[serde(default)]
+    pub expose: BTreeMap<String, SecretExposureToml>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct SecretExposureToml {
+    /// Command prefixes (for example `"npm publish"`) allowed to receive the
+    /// secret. When omitted, every command receives it.
+    pub commands: Option<Vec<String>>,
+}
+
+/// Command templates for `backend = "command"`. Each is an argv run without a
+/// shell, where `{name}`, `{scope}` and `{key}` are replaced with the secret
+/// name, its scope (`global` or the repository id) and `scope/NAME`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct SecretsCommandToml {
+    /// Prints the secret value on stdout, or nothing when it is unset.
+    pub get: Vec<String>,
+    /// Stores the value it receives on stdin.
+    pub set: Option<Vec<String>>,
+    /// Prints one `global/NAME` or `env/ID/NAME` key per line.
+    pub list: Option<Vec<String>>,
+    /// Removes the secret. Only run when `get` finds a value.
+    pub delete: Option<Vec<String>>,
+    /// Exit status `get` uses for an unset secret, e.g. `1` for `pass show`.
+    pub missing_exit_code: Option<i32>,
+    /// Per-command timeout in milliseconds. Defaults to 10000.
+    pub timeout_ms: Option<u64>,
+}
+
+/// Effective secrets settings.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SecretsConfig {
+    pub backend: SecretsBackendConfig,
+    pub expose: Vec<SecretExposure>,
+}
+
+/// A secret a session may hand to commands.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SecretExposure {
+    pub name: String,
+    /// Split command prefixes; `None` exposes the secret to every command.
+    pub commands: Option<Vec<Vec<String>>>,
+}
+
+// ===== Approval rules configuration =====
+
+/// What an approval rule does with the commands it covers.
+#[derive(
+    Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, JsonSchema,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum ApprovalRuleDecision {
+    /// Run without asking. The command still runs in the sandbox.
+    Allow,
+    /// Ask before running.
+    Prompt,
+    /// Refuse to run.
+    Forbidden,
+}
+
+/// Approval decisions keyed on what a command does. Commands matched by an
+/// execpolicy rule, and categories left unset, keep the default behavior.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct ApprovalRulesToml {
+    /// Commands that print files, such as `cat` or `sed -n`.
+    pub read: Option<ApprovalRuleDecision>,
+    /// Commands that list files, such as `ls` or `rg --files`.
+    pub list_files: Option<ApprovalRuleDecision>,
+    /// Commands that search file contents, such as `rg` or `grep`.
+    pub search: Option<ApprovalRuleDecision>,
+    /// Test runs, such as `cargo test`, `pytest` or `npm test`.
+    pub test: Option<ApprovalRuleDecision>,
+    /// Builds, such as `cargo build`, `make` or `tsc`.
+    pub build: Option<ApprovalRuleDecision>,
+    /// Package installs, such as `npm install` or `pip install`.
+    pub install: Option<ApprovalRuleDecision>,
+    /// Commands that create files, such as `touch`, `mkdir` or `cp`.
+    pub write: Option<ApprovalRuleDecision>,
+    /// Commands that move files, such as `mv`.
+    pub r#move: Option<ApprovalRuleDecision>,
+    /// Commands that delete files, such as `rm`.
+    pub delete: Option<ApprovalRuleDecision>,
+    /// Git commands that change the repository or a remote, such as
+    /// `git commit` or `git push`.
+    pub git_mutation: Option<ApprovalRuleDecision>,
+    /// Decisions for individual git subcommands, e.g. `push = "prompt"`.
+    /// Take precedence over `git_mutation`.
+    #[serde(default)]
+    pub git: BTreeMap<String, ApprovalRuleDecision>,
+    /// Network fetches, such as `curl` or `wget`.
+    pub fetch: Option<ApprovalRuleDecision>,
+    /// Writes, moves and deletes that touch a path outside the working
+    /// directory and the sandbox's writable roots. Takes precedence over
+    /// `write`, `move` and `delete`.
+    pub outside_workspace: Option<ApprovalRuleDecision>,
+}
+
+/// Effective approval rules: the active profile's `[approval_rules]` layered
+/// over the top-level table key by key.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ApprovalRules {
+    pub read: Option<ApprovalRuleDecision>,
+    pub list_files: Option<ApprovalRuleDecision>,
+    pub search: Option<ApprovalRuleDecision>,
+    pub test: Option<ApprovalRuleDecision>,
+    pub build: Option<ApprovalRuleDecision>,
+    pub install: Option<ApprovalRuleDecision>,
+    pub write: Option<ApprovalRuleDecision>,
+    pub r#move: Option<ApprovalRuleDecision>,
+    pub delete: Option<ApprovalRuleDecision>,
+    pub git_mutation: Option<ApprovalRuleDecision>,
+    pub git: HashMap<String, ApprovalRuleDecision>,
+    pub fetch: Option<ApprovalRuleDecision>,
+    pub outside_workspace: Option<ApprovalRuleDecision>,
+}
+
 // ===== Analytics configuration =====
 
 /// Analytics settings loaded from config.toml. Fields are optional so we can apply defaults.
@@ -471,6 +743,10 @@ pub struct Tui {
     #[serde(default = "default_true")]
     pub show_tooltips: bool,
 
+    /// Syntax-highlight fenced code blocks and file diffs. Defaults to `true`.
+    #[serde(default = "default_true")]
+    pub syntax_highlighting: bool,
+
     /// Start the TUI in the specified collaboration mode (plan/default).
     /// Defaults to unset.
     #[serde(default)]
@@ -492,6 +768,63 @@ pub struct Tui {
     /// When set, the TUI renders the selected items as the status line.
     #[serde(default)]
     pub status_line: Option<Vec<String>>,
+
+    /// Key chords bound to named TUI actions. Unset actions keep their
+    /// default bindings.
+    #[serde(default)]
+    pub keymap: TuiKeymap,
+
+    /// Enable vi-style modal editing (normal/insert/visual) in the composer.
+    /// Defaults to `false`.
+    #[serde(default)]
+    pub vim_mode: bool,
+}
+
+/// Key chords for a single TUI action, written as `"ctrl+t"` or as a list
+/// such as `["shift+enter", "ctrl+j"]`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, JsonSchema)]
+#[serde(untagged)]
+pub enum KeyChords {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl KeyChords {
+    pub fn chords(&self) -> &[String] {
+        match self {
+            KeyChords::One(chord) => std::slice::from_ref(chord),
+            KeyChords::Many(chords) => chords,
+        }
+    }
+}
+
+/// `[tui.keymap]` overrides. Chords are parsed and checked for conflicts by
+/// the TUI when it starts.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct TuiKeymap {
+    /// Submit the composer contents. Defaults to `enter`.
+    pub submit: Option<KeyChords>,
+    /// Insert a newline in the composer. Defaults to `shift+enter` and `ctrl+j`.
+    pub newline: Option<KeyChords>,
+    /// Queue the composer contents while a task is running. Defaults to `tab`.
+    pub queue: Option<KeyChords>,
+    /// Edit the draft in `$VISUAL`/`$EDITOR`. Defaults to `ctrl+g`.
+    pub open_editor: Option<KeyChords>,
+    /// Open or close the transcript overlay. Defaults to `ctrl+t`.
+    pub toggle_transcript: Option<KeyChords>,
+    /// Step back through previous messages to edit them. Defaults to `esc`.
+    pub backtrack: Option<KeyChords>,
+    /// Interrupt the running task. Defaults to `esc`.
+    pub interrupt: Option<KeyChords>,
+    /// Approve a pending request. Defaults to `y`.
+    pub approve: Option<KeyChords>,
+    /// Approve a pending request for the rest of the session. Defaults to `a`.
+    pub approve_for_session: Option<KeyChords>,
+    /// Deny a pending request. Defaults to `n`.
+    pub deny: Option<KeyChords>,
+    /// Review a multi-hunk patch one hunk at a time. Defaults to `h`.
+    pub review_hunks: Option<KeyChords>,
 }
 
 const fn default_true() -> bool {
@@ -888,6 +1221,59 @@ mod tests {
         assert_eq!(cfg.disabled_tools, Some(vec!["blocked".to_string()]));
     }
 
+    #[test]
+    fn deserialize_server_config_with_sandbox() {
+        let cfg: McpServerConfig = toml::from_str(
+            r#"
+            command = "echo"
+
+            [sandbox]
+            mode = "workspace-write"
+            network_access = true
+        "#,
+        )
+        .expect("should deserialize sandbox");
+
+        let sandbox = cfg.sandbox.expect("sandbox should be set");
+        assert_eq!(
+            sandbox.sandbox_policy(),
+            SandboxPolicy::WorkspaceWrite {
+                writable_roots: Vec::new(),
+                network_access: true,
+                exclude_tmpdir_env_var: false,
+                exclude_slash_tmp: false,
+            }
+        );
+    }
+
+    #[test]
+    fn deserialize_rejects_sandbox_network_access_for_read_only() {
+        let err = toml::from_str::<McpServerConfig>(
+            r#"
+            command = "echo"
+            sandbox = { mode = "read-only", network_access = true }
+        "#,
+        )
+        .expect_err("should reject network access without workspace-write");
+
+        assert!(
+            err.to_string()
+                .contains("sandbox.network_access is only supported"),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn deserialize_rejects_sandbox_for_http_transport() {
+        toml::from_str::<McpServerConfig>(
+            r#"
+            url = "https://example.com"
+            sandbox = { mode = "read-only" }
+        "#,
+        )
+        .expect_err("should reject sandbox for http transport");
+    }
+
     #[test]
     fn deserialize_rejects_command_and_url() {
         toml::from_str::<McpServerConfig>(
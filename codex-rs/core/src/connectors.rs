@@ -54,6 +54,7 @@ pub async fn list_accessible_connectors_from_mcp_tools(
             tx_event,
             cancel_token.clone(),
             sandbox_state,
+            None,
         )
         .await;
 
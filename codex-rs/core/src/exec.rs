@@ -16,6 +16,8 @@ use tokio::io::BufReader;
 use tokio::process::Child;
 use tokio_util::sync::CancellationToken;
 
+use crate::command_secrets::CommandSecrets;
+use crate::command_secrets::StreamRedactor;
 use crate::error::CodexErr;
 use crate::error::Result;
 use crate::error::SandboxErr;
@@ -144,6 +146,8 @@ pub struct StdoutStream {
     pub sub_id: String,
     pub call_id: String,
     pub tx_event: Sender<Event>,
+    /// Secret values to redact from streamed output.
+    pub(crate) secrets: CommandSecrets,
 }
 
 pub async fn process_exec_tool_call(
@@ -819,6 +823,9 @@ async fn read_capped<R: AsyncRead + Unpin + Send + 'static>(
     let mut buf = Vec::with_capacity(AGGREGATE_BUFFER_INITIAL_CAPACITY.min(EXEC_OUTPUT_MAX_BYTES));
     let mut tmp = [0u8; READ_CHUNK_SIZE];
     let mut emitted_deltas: usize = 0;
+    let mut redactor = stream
+        .as_ref()
+        .map(|stream| StreamRedactor::new(stream.secrets.clone()));
 
     loop {
         let n = reader.read(&mut tmp).await?;
@@ -827,37 +834,54 @@ async fn read_capped<R: AsyncRead + Unpin + Send + 'static>(
         }
 
         if let Some(stream) = &stream
+            && let Some(redactor) = redactor.as_mut()
             && emitted_deltas < MAX_EXEC_OUTPUT_DELTAS_PER_CALL
         {
-            let chunk = tmp[..n].to_vec();
-            let msg = EventMsg::ExecCommandOutputDelta(ExecCommandOutputDeltaEvent {
-                call_id: stream.call_id.clone(),
-                stream: if is_stderr {
-                    ExecOutputStream::Stderr
-                } else {
-                    ExecOutputStream::Stdout
-                },
-                chunk,
-            });
-            let event = Event {
-                id: stream.sub_id.clone(),
-                msg,
-            };
-            #[allow(clippy::let_unit_value)]
-            let _ = stream.tx_event.send(event).await;
-            emitted_deltas += 1;
+            let chunk = redactor.push(&tmp[..n]);
+            if !chunk.is_empty() {
+                send_output_delta(stream, is_stderr, chunk).await;
+                emitted_deltas += 1;
+            }
         }
 
         append_capped(&mut buf, &tmp[..n], EXEC_OUTPUT_MAX_BYTES);
         // Continue reading to EOF to avoid back-pressure
     }
 
+    if let Some(stream) = &stream
+        && let Some(redactor) = redactor.as_mut()
+        && emitted_deltas < MAX_EXEC_OUTPUT_DELTAS_PER_CALL
+    {
+        let chunk = redactor.finish();
+        if !chunk.is_empty() {
+            send_output_delta(stream, is_stderr, chunk).await;
+        }
+    }
+
     Ok(StreamOutput {
         text: buf,
         truncated_after_lines: None,
     })
 }
 
+async fn send_output_delta(stream: &StdoutStream, is_stderr: bool, chunk: Vec<u8>) {
+    let msg = EventMsg::ExecCommandOutputDelta(ExecCommandOutputDeltaEvent {
+        call_id: stream.call_id.clone(),
+        stream: if is_stderr {
+            ExecOutputStream::Stderr
+        } else {
+            ExecOutputStream::Stdout
+        },
+        chunk,
+    });
+    let event = Event {
+        id: stream.sub_id.clone(),
+        msg,
+    };
+    #[allow(clippy::let_unit_value)]
+    let _ = stream.tx_event.send(event).await;
+}
+
 #[cfg(unix)]
 fn synthetic_exit_status(code: i32) -> ExitStatus {
     use std::os::unix::process::ExitStatusExt;
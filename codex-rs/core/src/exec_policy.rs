@@ -5,6 +5,8 @@ use std::sync::Arc;
 
 use arc_swap::ArcSwap;
 
+use crate::config::types::ApprovalRuleDecision;
+use crate::config::types::ApprovalRules;
 use crate::config_loader::ConfigLayerStack;
 use crate::config_loader::ConfigLayerStackOrdering;
 use crate::is_dangerous_command::command_might_be_dangerous;
@@ -18,15 +20,19 @@ use codex_execpolicy::PolicyParser;
 use codex_execpolicy::RuleMatch;
 use codex_execpolicy::blocking_append_allow_prefix_rule;
 use codex_protocol::approvals::ExecPolicyAmendment;
+use codex_protocol::parse_command::ParsedCommand;
 use codex_protocol::protocol::AskForApproval;
 use codex_protocol::protocol::SandboxPolicy;
+use codex_utils_absolute_path::AbsolutePathBuf;
 use thiserror::Error;
 use tokio::fs;
 use tokio::task::spawn_blocking;
 
+use crate::bash::extract_bash_command;
 use crate::bash::parse_shell_lc_plain_commands;
 use crate::features::Feature;
 use crate::features::Features;
+use crate::parse_command::parse_command;
 use crate::sandboxing::SandboxPermissions;
 use crate::tools::sandboxing::ExecApprovalRequirement;
 use shlex::try_join as shlex_try_join;
@@ -94,6 +100,9 @@ pub(crate) struct ExecApprovalRequest<'a> {
     pub(crate) sandbox_policy: &'a SandboxPolicy,
     pub(crate) sandbox_permissions: SandboxPermissions,
     pub(crate) prefix_rule: Option<Vec<String>>,
+    /// Directory the command runs in.
+    pub(crate) cwd: &'a Path,
+    pub(crate) approval_rules: &'a ApprovalRules,
 }
 
 impl ExecPolicyManager {
@@ -130,17 +139,32 @@ impl ExecPolicyManager {
             sandbox_policy,
             sandbox_permissions,
             prefix_rule,
+            cwd,
+            approval_rules,
         } = req;
         let exec_policy = self.current();
         let commands =
             parse_shell_lc_plain_commands(command).unwrap_or_else(|| vec![command.to_vec()]);
+        // Decided on the whole command so that paths after a `cd` resolve
+        // against the directory it changed to.
+        let outside_workspace = approval_rules.outside_workspace.is_some()
+            && mutates_outside_workspace(command, cwd, sandbox_policy);
         let exec_policy_fallback = |cmd: &[String]| {
-            render_decision_for_unmatched_command(
-                approval_policy,
-                sandbox_policy,
-                cmd,
-                sandbox_permissions,
-            )
+            approval_rule_decision(approval_rules, cmd, outside_workspace)
+                // A rule never lets a command run outside the sandbox without
+                // asking: escalation requests keep the default decision.
+                .filter(|decision| {
+                    !(*decision == Decision::Allow
+                        && sandbox_permissions.requires_escalated_permissions())
+                })
+                .unwrap_or_else(|| {
+                    render_decision_for_unmatched_command(
+                        approval_policy,
+                        sandbox_policy,
+                        cmd,
+                        sandbox_permissions,
+                    )
+                })
         };
         let evaluation = exec_policy.check_multiple(commands.iter(), &exec_policy_fallback);
 
@@ -356,6 +380,119 @@ pub fn render_decision_for_unmatched_command(
     }
 }
 
+/// If a command is not matched by any execpolicy rule, derive a [`Decision`]
+/// from `[approval_rules]`. The strictest rule covering any part of the
+/// command wins, but it is only allowed when every part is covered. Commands
+/// flagged as dangerous are never allowed by a rule.
+fn approval_rule_decision(
+    rules: &ApprovalRules,
+    command: &[String],
+    outside_workspace: bool,
+) -> Option<Decision> {
+    if *rules == ApprovalRules::default() {
+        return None;
+    }
+    let decisions: Vec<Option<ApprovalRuleDecision>> = approval_rule_segments(command)
+        .iter()
+        .map(|parsed| {
+            parsed
+                .as_ref()
+                .and_then(|parsed| approval_rule_for(rules, parsed, outside_workspace))
+        })
+        .collect();
+    match decisions.iter().flatten().max()? {
+        ApprovalRuleDecision::Forbidden => Some(Decision::Forbidden),
+        ApprovalRuleDecision::Prompt => Some(Decision::Prompt),
+        ApprovalRuleDecision::Allow => (decisions.iter().all(Option::is_some)
+            && !command_might_be_dangerous(command))
+        .then_some(Decision::Allow),
+    }
+}
+
+/// Classifies every segment of `command` for `[approval_rules]`.
+///
+/// [`parse_command`] builds display summaries and drops segments such as
+/// `tee`, `awk` or a leading `echo`, so it cannot be used to decide what runs.
+/// Here each pipeline and `&&` segment is classified on its own and yields
+/// `None` unless it maps to exactly one category. Scripts that cannot be split
+/// into plain segments keep their best-effort categories, so stricter rules
+/// still apply, plus a `None` so that no rule allows them.
+fn approval_rule_segments(command: &[String]) -> Vec<Option<ParsedCommand>> {
+    let segments = match parse_shell_lc_plain_commands(command) {
+        Some(segments) => segments,
+        None if extract_bash_command(command).is_some() => {
+            let mut parsed: Vec<Option<ParsedCommand>> =
+                parse_command(command).into_iter().map(Some).collect();
+            parsed.push(None);
+            return parsed;
+        }
+        None => vec![command.to_vec()],
+    };
+    segments
+        .iter()
+        .map(|segment| match parse_command(segment).as_slice() {
+            [parsed] => Some(parsed.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn approval_rule_for(
+    rules: &ApprovalRules,
+    parsed: &ParsedCommand,
+    outside_workspace: bool,
+) -> Option<ApprovalRuleDecision> {
+    match parsed {
+        ParsedCommand::Write { .. } | ParsedCommand::Move { .. } | ParsedCommand::Delete { .. }
+            if outside_workspace && rules.outside_workspace.is_some() =>
+        {
+            rules.outside_workspace
+        }
+        ParsedCommand::Read { .. } => rules.read,
+        ParsedCommand::ListFiles { .. } => rules.list_files,
+        ParsedCommand::Search { .. } => rules.search,
+        ParsedCommand::Test { .. } => rules.test,
+        ParsedCommand::Build { .. } => rules.build,
+        ParsedCommand::Install { .. } => rules.install,
+        ParsedCommand::Write { .. } => rules.write,
+        ParsedCommand::Move { .. } => rules.r#move,
+        ParsedCommand::Delete { .. } => rules.delete,
+        ParsedCommand::GitMutation { subcommand, .. } => {
+            rules.git.get(subcommand).copied().or(rules.git_mutation)
+        }
+        ParsedCommand::Fetch { .. } => rules.fetch,
+        ParsedCommand::Unknown { .. } => None,
+    }
+}
+
+/// Whether `command` writes, moves or deletes a path outside `cwd` and the
+/// sandbox's writable roots.
+fn mutates_outside_workspace(
+    command: &[String],
+    cwd: &Path,
+    sandbox_policy: &SandboxPolicy,
+) -> bool {
+    let writable_roots = sandbox_policy.get_writable_roots_with_cwd(cwd);
+    parse_command(command)
+        .iter()
+        .flat_map(|parsed| match parsed {
+            ParsedCommand::Write { paths, .. }
+            | ParsedCommand::Move { paths, .. }
+            | ParsedCommand::Delete { paths, .. } => paths.as_slice(),
+            _ => &[],
+        })
+        .any(|path| {
+            let Ok(path) = AbsolutePathBuf::resolve_path_against_base(path, cwd) else {
+                return true;
+            };
+            let path = path.as_path();
+            !path.starts_with(cwd)
+                && !writable_roots
+                    .iter()
+                    .any(|root| path.starts_with(root.root.as_path()))
+        })
+}
+
 fn default_policy_path(codex_home: &Path) -> PathBuf {
     codex_home.join(RULES_DIR_NAME).join(DEFAULT_POLICY_FILE)
 }
@@ -567,6 +704,7 @@ mod tests {
     use codex_protocol::protocol::SandboxPolicy;
     use codex_utils_absolute_path::AbsolutePathBuf;
     use pretty_assertions::assert_eq;
+    use std::collections::HashMap;
     use std::fs;
     use std::path::Path;
     use std::sync::Arc;
@@ -815,6 +953,8 @@ prefix_rule(pattern=["rm"], decision="forbidden")
                 sandbox_policy: &SandboxPolicy::DangerFullAccess,
                 sandbox_permissions: SandboxPermissions::UseDefault,
                 prefix_rule: None,
+                cwd: Path::new("/workspace"),
+                approval_rules: &ApprovalRules::default(),
             })
             .await;
 
@@ -854,6 +994,8 @@ prefix_rule(
                 sandbox_policy: &SandboxPolicy::DangerFullAccess,
                 sandbox_permissions: SandboxPermissions::UseDefault,
                 prefix_rule: None,
+                cwd: Path::new("/workspace"),
+                approval_rules: &ApprovalRules::default(),
             })
             .await;
 
@@ -884,6 +1026,8 @@ prefix_rule(
                 sandbox_policy: &SandboxPolicy::DangerFullAccess,
                 sandbox_permissions: SandboxPermissions::UseDefault,
                 prefix_rule: None,
+                cwd: Path::new("/workspace"),
+                approval_rules: &ApprovalRules::default(),
             })
             .await;
 
@@ -915,6 +1059,8 @@ prefix_rule(
                 sandbox_policy: &SandboxPolicy::DangerFullAccess,
                 sandbox_permissions: SandboxPermissions::UseDefault,
                 prefix_rule: None,
+                cwd: Path::new("/workspace"),
+                approval_rules: &ApprovalRules::default(),
             })
             .await;
 
@@ -939,6 +1085,8 @@ prefix_rule(
                 sandbox_policy: &SandboxPolicy::ReadOnly,
                 sandbox_permissions: SandboxPermissions::UseDefault,
                 prefix_rule: None,
+                cwd: Path::new("/workspace"),
+                approval_rules: &ApprovalRules::default(),
             })
             .await;
 
@@ -970,6 +1118,8 @@ prefix_rule(
                 sandbox_policy: &SandboxPolicy::ReadOnly,
                 sandbox_permissions: SandboxPermissions::RequireEscalated,
                 prefix_rule: Some(vec!["cargo".to_string(), "install".to_string()]),
+                cwd: Path::new("/workspace"),
+                approval_rules: &ApprovalRules::default(),
             })
             .await;
 
@@ -1008,6 +1158,8 @@ prefix_rule(
                     sandbox_policy: &SandboxPolicy::DangerFullAccess,
                     sandbox_permissions: SandboxPermissions::UseDefault,
                     prefix_rule: None,
+                    cwd: Path::new("/workspace"),
+                    approval_rules: &ApprovalRules::default(),
                 })
                 .await,
             ExecApprovalRequirement::NeedsApproval {
@@ -1083,6 +1235,8 @@ prefix_rule(
                 sandbox_policy: &SandboxPolicy::ReadOnly,
                 sandbox_permissions: SandboxPermissions::UseDefault,
                 prefix_rule: None,
+                cwd: Path::new("/workspace"),
+                approval_rules: &ApprovalRules::default(),
             })
             .await;
 
@@ -1111,6 +1265,8 @@ prefix_rule(
                 sandbox_policy: &SandboxPolicy::ReadOnly,
                 sandbox_permissions: SandboxPermissions::UseDefault,
                 prefix_rule: None,
+                cwd: Path::new("/workspace"),
+                approval_rules: &ApprovalRules::default(),
             })
             .await;
 
@@ -1142,6 +1298,8 @@ prefix_rule(
                 sandbox_policy: &SandboxPolicy::DangerFullAccess,
                 sandbox_permissions: SandboxPermissions::UseDefault,
                 prefix_rule: None,
+                cwd: Path::new("/workspace"),
+                approval_rules: &ApprovalRules::default(),
             })
             .await;
 
@@ -1170,6 +1328,8 @@ prefix_rule(
                 sandbox_policy: &SandboxPolicy::ReadOnly,
                 sandbox_permissions: SandboxPermissions::UseDefault,
                 prefix_rule: None,
+                cwd: Path::new("/workspace"),
+                approval_rules: &ApprovalRules::default(),
             })
             .await;
 
@@ -1209,6 +1369,8 @@ prefix_rule(
                     sandbox_policy: &SandboxPolicy::ReadOnly,
                     sandbox_permissions: SandboxPermissions::UseDefault,
                     prefix_rule: None,
+                    cwd: Path::new("/workspace"),
+                    approval_rules: &ApprovalRules::default(),
                 })
                 .await,
             ExecApprovalRequirement::NeedsApproval {
@@ -1233,6 +1395,8 @@ prefix_rule(
                 sandbox_policy: &SandboxPolicy::ReadOnly,
                 sandbox_permissions: SandboxPermissions::UseDefault,
                 prefix_rule: None,
+                cwd: Path::new("/workspace"),
+                approval_rules: &ApprovalRules::default(),
             })
             .await;
 
@@ -1264,6 +1428,8 @@ prefix_rule(
                 sandbox_policy: &SandboxPolicy::ReadOnly,
                 sandbox_permissions: SandboxPermissions::UseDefault,
                 prefix_rule: None,
+                cwd: Path::new("/workspace"),
+                approval_rules: &ApprovalRules::default(),
             })
             .await;
 
@@ -1288,6 +1454,8 @@ prefix_rule(
                 sandbox_policy: &SandboxPolicy::DangerFullAccess,
                 sandbox_permissions: SandboxPermissions::UseDefault,
                 prefix_rule: None,
+                cwd: Path::new("/workspace"),
+                approval_rules: &ApprovalRules::default(),
             })
             .await;
 
@@ -1356,6 +1524,8 @@ prefix_rule(
                     sandbox_policy: &SandboxPolicy::ReadOnly,
                     sandbox_permissions: permissions,
                     prefix_rule: None,
+                    cwd: Path::new("/workspace"),
+                    approval_rules: &ApprovalRules::default(),
                 })
                 .await,
             "{pwsh_approval_reason}"
@@ -1380,6 +1550,8 @@ prefix_rule(
                     sandbox_policy: &SandboxPolicy::ReadOnly,
                     sandbox_permissions: permissions,
                     prefix_rule: None,
+                    cwd: Path::new("/workspace"),
+                    approval_rules: &ApprovalRules::default(),
                 })
                 .await,
             r#"On all platforms, a forbidden command should require approval
@@ -1400,10 +1572,166 @@ prefix_rule(
                     sandbox_policy: &SandboxPolicy::ReadOnly,
                     sandbox_permissions: permissions,
                     prefix_rule: None,
+                    cwd: Path::new("/workspace"),
+                    approval_rules: &ApprovalRules::default(),
                 })
                 .await,
             r#"On all platforms, a forbidden command should require approval
             (unless AskForApproval::Never is specified)."#
         );
     }
+
+    async fn requirement_with_rules(
+        approval_rules: &ApprovalRules,
+        script: &str,
+        approval_policy: AskForApproval,
+    ) -> ExecApprovalRequirement {
+        let command = vec!["bash".to_string(), "-lc".to_string(), script.to_string()];
+        ExecPolicyManager::default()
+            .create_exec_approval_requirement_for_command(ExecApprovalRequest {
+                features: &Features::with_defaults(),
+                command: &command,
+                approval_policy,
+                sandbox_policy: &SandboxPolicy::new_read_only_policy(),
+                sandbox_permissions: SandboxPermissions::UseDefault,
+                prefix_rule: None,
+                cwd: Path::new("/workspace"),
+                approval_rules,
+            })
+            .await
+    }
+
+    #[tokio::test]
+    async fn approval_rules_decide_by_command_category() {
+        let rules = ApprovalRules {
+            test: Some(ApprovalRuleDecision::Allow),
+            install: Some(ApprovalRuleDecision::Prompt),
+            git_mutation: Some(ApprovalRuleDecision::Allow),
+            git: HashMap::from([("push".to_string(), ApprovalRuleDecision::Prompt)]),
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            requirement_with_rules(
+                &rules,
+                "cargo test -p codex-core",
+                AskForApproval::UnlessTrusted
+            )
+            .await,
+            ExecApprovalRequirement::Skip {
+                bypass_sandbox: false,
+                ..
+            }
+        ));
+        assert!(matches!(
+            requirement_with_rules(&rules, "git commit -m wip", AskForApproval::UnlessTrusted)
+                .await,
+            ExecApprovalRequirement::Skip { .. }
+        ));
+        assert!(matches!(
+            requirement_with_rules(&rules, "git push origin main", AskForApproval::OnRequest).await,
+            ExecApprovalRequirement::NeedsApproval { .. }
+        ));
+        assert!(matches!(
+            requirement_with_rules(&rules, "npm install left-pad", AskForApproval::OnRequest).await,
+            ExecApprovalRequirement::NeedsApproval { .. }
+        ));
+        assert_eq!(
+            requirement_with_rules(&rules, "npm install left-pad", AskForApproval::Never).await,
+            ExecApprovalRequirement::Forbidden {
+                reason: PROMPT_CONFLICT_REASON.to_string(),
+            }
+        );
+        // Parts without a rule keep the default behavior.
+        assert!(matches!(
+            requirement_with_rules(
+                &rules,
+                "cargo test && python3 script.py",
+                AskForApproval::UnlessTrusted
+            )
+            .await,
+            ExecApprovalRequirement::NeedsApproval { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn approval_rules_never_allow_unclassified_segments() {
+        let rules = ApprovalRules {
+            test: Some(ApprovalRuleDecision::Allow),
+            ..Default::default()
+        };
+
+        for script in [
+            "cargo test | tee /etc/x",
+            r#"cargo test && awk 'BEGIN{system("touch /etc/x")}'"#,
+            "cargo test | xargs rm -rf",
+            "cargo test 2>&1 | tee /etc/x",
+        ] {
+            assert!(
+                matches!(
+                    requirement_with_rules(&rules, script, AskForApproval::UnlessTrusted).await,
+                    ExecApprovalRequirement::NeedsApproval { .. }
+                ),
+                "{script} should need approval"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn approval_rules_do_not_allow_escalated_commands() {
+        let rules = ApprovalRules {
+            test: Some(ApprovalRuleDecision::Allow),
+            ..Default::default()
+        };
+        let command = vec![
+            "bash".to_string(),
+            "-lc".to_string(),
+            "cargo test".to_string(),
+        ];
+
+        let requirement = ExecPolicyManager::default()
+            .create_exec_approval_requirement_for_command(ExecApprovalRequest {
+                features: &Features::with_defaults(),
+                command: &command,
+                approval_policy: AskForApproval::OnRequest,
+                sandbox_policy: &SandboxPolicy::new_read_only_policy(),
+                sandbox_permissions: SandboxPermissions::RequireEscalated,
+                prefix_rule: None,
+                cwd: Path::new("/workspace"),
+                approval_rules: &rules,
+            })
+            .await;
+
+        assert!(matches!(
+            requirement,
+            ExecApprovalRequirement::NeedsApproval { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn approval_rules_forbid_mutations_outside_workspace() {
+        let rules = ApprovalRules {
+            delete: Some(ApprovalRuleDecision::Allow),
+            outside_workspace: Some(ApprovalRuleDecision::Forbidden),
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            requirement_with_rules(&rules, "rm build/out.txt", AskForApproval::UnlessTrusted).await,
+            ExecApprovalRequirement::Skip { .. }
+        ));
+        for script in [
+            "rm /etc/hosts",
+            "rm ../other/notes.txt",
+            "cd /tmp && rm notes.txt",
+        ] {
+            assert!(
+                matches!(
+                    requirement_with_rules(&rules, script, AskForApproval::OnRequest).await,
+                    ExecApprovalRequirement::Forbidden { .. }
+                ),
+                "{script} should be forbidden"
+            );
+        }
+    }
 }
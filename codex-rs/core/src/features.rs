@@ -109,6 +109,8 @@ pub enum Feature {
     Sqlite,
     /// Enable the get_memory tool backed by SQLite thread memories.
     MemoryTool,
+    /// Enable the client-side web_fetch and web_search_local tools.
+    WebFetch,
     /// Append additional AGENTS.md guidance to user instructions.
     ChildAgentsMd,
     /// Enforce UTF8 output in Powershell.
@@ -457,6 +459,12 @@ pub const FEATURES: &[FeatureSpec] = &[
         stage: Stage::UnderDevelopment,
         default_enabled: false,
     },
+    FeatureSpec {
+        id: Feature::WebFetch,
+        key: "web_fetch",
+        stage: Stage::UnderDevelopment,
+        default_enabled: false,
+    },
     FeatureSpec {
         id: Feature::ChildAgentsMd,
         key: "child_agents_md",
@@ -22,6 +22,7 @@ pub use codex_thread::ThreadConfigSnapshot;
 mod agent;
 mod codex_delegate;
 mod command_safety;
+pub mod command_secrets;
 pub mod config;
 pub mod config_loader;
 pub mod connectors;
@@ -54,9 +55,11 @@ pub mod parse_command;
 pub mod path_utils;
 pub mod personality_migration;
 pub mod powershell;
+pub mod prompt_args;
 mod proposed_plan_parser;
 pub mod sandboxing;
 mod session_prefix;
+pub mod session_worktree;
 mod stream_events_utils;
 mod tagged_block_parser;
 mod text_encoding;
@@ -77,6 +80,7 @@ mod event_mapping;
 pub mod review_format;
 pub mod review_prompts;
 mod thread_manager;
+mod web_fetch;
 pub mod web_search;
 pub use codex_protocol::protocol::InitialHistory;
 pub use thread_manager::NewThread;
@@ -113,6 +117,7 @@ pub use tools::registry::ToolKind;
 pub use tools::registry::set_pending_external_interceptors;
 pub use tools::registry::set_pending_external_tools;
 pub use tools::spec::create_function_tool_spec_from_schema;
+pub mod transcript_export;
 pub mod turn_diff_tracker;
 mod turn_metadata;
 pub use rollout::ARCHIVED_SESSIONS_SUBDIR;
@@ -1,4 +1,5 @@
 pub mod auth;
+pub(crate) mod sampling;
 mod skill_dependencies;
 pub(crate) use skill_dependencies::maybe_prompt_and_install_mcp_dependencies;
 
@@ -105,6 +106,7 @@ fn codex_apps_mcp_server_config(config: &Config, auth: Option<&CodexAuth>) -> Mc
         enabled_tools: None,
         disabled_tools: None,
         scopes: None,
+        sandbox: None,
     }
 }
 
@@ -178,6 +180,7 @@ pub async fn collect_mcp_snapshot(config: &Config) -> McpListToolsResponseEvent
             tx_event,
             cancel_token.clone(),
             sandbox_state,
+            None,
         )
         .await;
 
@@ -0,0 +1,328 @@
+//! Serves MCP `sampling/createMessage` requests with the session's model.
+//!
+//! The user approves each request through the elicitation prompt before it
+//! reaches this module (see `McpConnectionManager`). Completions run outside
+//! of any turn: nothing is recorded in the thread history, but token usage is
+//! added to the session totals. Only the server's own `systemPrompt` is sent as
+//! instructions; Codex's base instructions are never shared with MCP servers.
+
+use std::sync::Arc;
+use std::sync::Weak;
+
+use anyhow::Context;
+use anyhow::Result;
+use anyhow::anyhow;
+use codex_protocol::models::BaseInstructions;
+use codex_protocol::models::ContentItem;
+use codex_protocol::models::ResponseItem;
+use codex_rmcp_client::SamplingRequest;
+use codex_rmcp_client::SamplingResponse;
+use codex_utils_string::take_bytes_at_char_boundary;
+use futures::FutureExt;
+use futures::StreamExt;
+use rmcp::model::Content;
+use rmcp::model::CreateMessageResult;
+use rmcp::model::RawContent;
+use rmcp::model::Role;
+use rmcp::model::SamplingMessage;
+
+use crate::Prompt;
+use crate::client_common::ResponseEvent;
+use crate::codex::Session;
+use crate::compact::content_items_to_text;
+use crate::mcp_connection_manager::McpSamplingHandler;
+use crate::truncate::approx_bytes_for_tokens;
+
+/// The Responses API rejects smaller `max_output_tokens` values; lower limits
+/// are enforced by truncating the completion.
+const MIN_MAX_OUTPUT_TOKENS: u32 = 16;
+
+/// Builds the handler that the connection manager calls once a sampling
+/// request has been approved. Holds a weak reference so MCP clients do not
+/// keep the session alive.
+pub(crate) fn sampling_handler(session: Weak<Session>) -> McpSamplingHandler {
+    Arc::new(move |server_name, request| {
+        let session = session.clone();
+        async move {
+            let session = session
+                .upgrade()
+                .ok_or_else(|| anyhow!("session is no longer running"))?;
+            run_sampling_request(&session, &server_name, request).await
+        }
+        .boxed()
+    })
+}
+
+async fn run_sampling_request(
+    sess: &Session,
+    server_name: &str,
+    request: SamplingRequest,
+) -> Result<SamplingResponse> {
+    let turn_context = sess.new_default_turn().await;
+    let input: Vec<ResponseItem> = request
+        .messages
+        .iter()
+        .filter_map(sampling_message_to_item)
+        .collect();
+    if input.is_empty() {
+        return Err(anyhow!(
+            "sampling request from `{server_name}` had no messages"
+        ));
+    }
+
+    let prompt = Prompt {
+        input,
+        base_instructions: BaseInstructions {
+            text: request.system_prompt.clone().unwrap_or_default(),
+        },
+        personality: None,
+        max_output_tokens: Some(request.max_tokens.max(MIN_MAX_OUTPUT_TOKENS)),
+        // Reasoning models reject `temperature`.
+        temperature: request
+            .temperature
+            .filter(|_| !turn_context.model_info.supports_reasoning_summaries),
+        ..Default::default()
+    };
+    let turn_metadata_header = turn_context.resolve_turn_metadata_header().await;
+    let mut client_session = sess.services.model_client.new_session();
+    let mut stream = client_session
+        .stream(
+            &prompt,
+            &turn_context.model_info,
+            &turn_context.otel_manager,
+            turn_context.reasoning_effort,
+            turn_context.reasoning_summary,
+            turn_metadata_header.as_deref(),
+        )
+        .await
+        .context("failed to start sampling request")?;
+
+    let mut text = Vec::new();
+    let mut output_tokens = 0;
+    loop {
+        let event = stream
+            .next()
+            .await
+            .ok_or_else(|| anyhow!("stream closed before response.completed"))?
+            .context("sampling request failed")?;
+        match event {
+            ResponseEvent::OutputItemDone(ResponseItem::Message { role, content, .. })
+                if role == "assistant" =>
+            {
+                text.extend(content_items_to_text(&content));
+            }
+            ResponseEvent::RateLimits(snapshot) => {
+                sess.update_rate_limits(&turn_context, snapshot).await;
+            }
+            ResponseEvent::Completed { token_usage, .. } => {
+                output_tokens = token_usage.as_ref().map_or(0, |usage| usage.output_tokens);
+                sess.update_token_usage_info(&turn_context, token_usage.as_ref())
+                    .await;
+                break;
+            }
+            _ => {}
+        }
+    }
+
+    let (text, stop_reason) = apply_stop_conditions(
+        text.join("\n"),
+        request.stop_sequences.as_deref().unwrap_or_default(),
+        request.max_tokens,
+        output_tokens,
+    );
+    Ok(CreateMessageResult {
+        model: turn_context.model_info.slug.clone(),
+        stop_reason: Some(stop_reason.to_string()),
+        message: SamplingMessage {
+            role: Role::Assistant,
+            content: Content::text(text),
+        },
+    })
+}
+
+/// Cuts `text` at the first stop sequence or at roughly `max_tokens`, whichever
+/// comes first, and reports why the completion ended. The Responses API has no
+/// stop sequences and a floor on `max_output_tokens`, so both are enforced here.
+fn apply_stop_conditions(
+    text: String,
+    stop_sequences: &[String],
+    max_tokens: u32,
+    output_tokens: i64,
+) -> (String, &'static str) {
+    let first_stop = stop_sequences
+        .iter()
+        .filter(|sequence| !sequence.is_empty())
+        .filter_map(|sequence| text.find(sequence.as_str()))
+        .min();
+    let max_bytes = approx_bytes_for_tokens(usize::try_from(max_tokens).unwrap_or(usize::MAX));
+    match first_stop {
+        Some(end) if end <= max_bytes => (
+            text[..end].to_string(),
+            CreateMessageResult::STOP_REASON_END_SEQUENCE,
+        ),
+        _ if text.len() > max_bytes => (
+            take_bytes_at_char_boundary(&text, max_bytes).to_string(),
+            CreateMessageResult::STOP_REASON_END_MAX_TOKEN,
+        ),
+        _ if output_tokens >= i64::from(max_tokens) => {
+            (text, CreateMessageResult::STOP_REASON_END_MAX_TOKEN)
+        }
+        _ => (text, CreateMessageResult::STOP_REASON_END_TURN),
+    }
+}
+
+fn sampling_message_to_item(message: &SamplingMessage) -> Option<ResponseItem> {
+    let content = match (&message.role, &message.content.raw) {
+        (Role::User, RawContent::Text(text)) => ContentItem::InputText {
+            text: text.text.clone(),
+        },
+        (Role::User, RawContent::Image(image)) => ContentItem::InputImage {
+            image_url: format!("data:{};base64,{}", image.mime_type, image.data),
+        },
+        (Role::Assistant, RawContent::Text(text)) => ContentItem::OutputText {
+            text: text.text.clone(),
+        },
+        _ => return None,
+    };
+    let role = match message.role {
+        Role::User => "user",
+        Role::Assistant => "assistant",
+    };
+    Some(message_item(role, vec![content]))
+}
+
+fn message_item(role: &str, content: Vec<ContentItem>) -> ResponseItem {
+    ResponseItem::Message {
+        id: None,
+        role: role.to_string(),
+        content,
+        end_turn: None,
+        phase: None,
+    }
+}
+
+/// Summarizes a sampling request for the approval prompt shown to the user.
+pub(crate) fn sampling_approval_message(server_name: &str, request: &SamplingRequest) -> String {
+    const PREVIEW_MAX_CHARS: usize = 500;
+    let preview = request
+        .messages
+        .iter()
+        .rev()
+        .find_map(|message| match &message.content.raw {
+            RawContent::Text(text) if message.role == Role::User => Some(text.text.as_str()),
+            _ => None,
+        })
+        .unwrap_or_default()
+        .trim();
+    let preview = if preview.chars().count() > PREVIEW_MAX_CHARS {
+        let truncated: String = preview.chars().take(PREVIEW_MAX_CHARS).collect();
+        format!("{truncated}…")
+    } else {
+        preview.to_string()
+    };
+    let mut message = format!(
+        "MCP server `{server_name}` wants to send a request to the model (up to {} tokens).",
+        request.max_tokens
+    );
+    if !preview.is_empty() {
+        message.push_str("\n\n");
+        message.push_str(&preview);
+    }
+    message
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn sampling_request(messages: Vec<SamplingMessage>) -> SamplingRequest {
+        serde_json::from_value(serde_json::json!({
+            "messages": messages,
+            "maxTokens": 256,
+        }))
+        .expect("valid sampling request")
+    }
+
+    #[test]
+    fn sampling_messages_map_to_response_items() {
+        let user = SamplingMessage {
+            role: Role::User,
+            content: Content::text("summarize this"),
+        };
+        let assistant = SamplingMessage {
+            role: Role::Assistant,
+            content: Content::text("ok"),
+        };
+        assert_eq!(
+            sampling_message_to_item(&user),
+            Some(message_item(
+                "user",
+                vec![ContentItem::InputText {
+                    text: "summarize this".to_string()
+                }]
+            ))
+        );
+        assert_eq!(
+            sampling_message_to_item(&assistant),
+            Some(message_item(
+                "assistant",
+                vec![ContentItem::OutputText {
+                    text: "ok".to_string()
+                }]
+            ))
+        );
+    }
+
+    #[test]
+    fn stop_conditions_report_why_the_completion_ended() {
+        let stops = vec!["END".to_string()];
+        assert_eq!(
+            apply_stop_conditions("done".to_string(), &stops, 256, 2),
+            (
+                "done".to_string(),
+                CreateMessageResult::STOP_REASON_END_TURN
+            )
+        );
+        assert_eq!(
+            apply_stop_conditions("answer END trailing".to_string(), &stops, 256, 5),
+            (
+                "answer ".to_string(),
+                CreateMessageResult::STOP_REASON_END_SEQUENCE
+            )
+        );
+        // Two tokens is roughly eight bytes.
+        assert_eq!(
+            apply_stop_conditions("a much longer answer".to_string(), &stops, 2, 16),
+            (
+                "a much l".to_string(),
+                CreateMessageResult::STOP_REASON_END_MAX_TOKEN
+            )
+        );
+        assert_eq!(
+            apply_stop_conditions("cut off".to_string(), &[], 2, 2),
+            (
+                "cut off".to_string(),
+                CreateMessageResult::STOP_REASON_END_MAX_TOKEN
+            )
+        );
+    }
+
+    #[test]
+    fn approval_message_previews_last_user_message() {
+        let request = sampling_request(vec![
+            SamplingMessage {
+                role: Role::User,
+                content: Content::text("first"),
+            },
+            SamplingMessage {
+                role: Role::User,
+                content: Content::text("  classify this ticket  "),
+            },
+        ]);
+        assert_eq!(
+            sampling_approval_message("tickets", &request),
+            "MCP server `tickets` wants to send a request to the model (up to 256 tokens).\n\nclassify this ticket"
+        );
+    }
+}
@@ -385,6 +385,7 @@ fn mcp_dependency_to_server_config(
             enabled_tools: None,
             disabled_tools: None,
             scopes: None,
+            sandbox: None,
         });
     }
 
@@ -408,6 +409,7 @@ fn mcp_dependency_to_server_config(
             enabled_tools: None,
             disabled_tools: None,
             scopes: None,
+            sandbox: None,
         });
     }
 
@@ -461,6 +463,7 @@ mod tests {
                 enabled_tools: None,
                 disabled_tools: None,
                 scopes: None,
+                sandbox: None,
             },
         )]);
 
@@ -508,6 +511,7 @@ mod tests {
                 enabled_tools: None,
                 disabled_tools: None,
                 scopes: None,
+                sandbox: None,
             },
         )]);
 
@@ -14,11 +14,26 @@ use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::LazyLock;
 use std::sync::Mutex as StdMutex;
+use std::sync::RwLock as StdRwLock;
+use std::sync::Weak;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
 use std::time::Duration;
 use std::time::Instant;
 
+use crate::exec::SandboxType;
+use crate::landlock::create_linux_sandbox_command_args;
 use crate::mcp::CODEX_APPS_MCP_SERVER_NAME;
 use crate::mcp::auth::McpAuthStatusEntry;
+use crate::mcp::sampling::sampling_approval_message;
+use crate::safety::get_platform_sandbox;
+#[cfg(target_os = "macos")]
+use crate::seatbelt::MACOS_PATH_TO_SEATBELT_EXECUTABLE;
+#[cfg(target_os = "macos")]
+use crate::seatbelt::create_seatbelt_command_args;
+#[cfg(target_os = "macos")]
+use crate::spawn::CODEX_SANDBOX_ENV_VAR;
+use crate::spawn::CODEX_SANDBOX_NETWORK_DISABLED_ENV_VAR;
 use anyhow::Context;
 use anyhow::Result;
 use anyhow::anyhow;
@@ -30,15 +45,25 @@ use codex_protocol::mcp::CallToolResult;
 use codex_protocol::mcp::RequestId as ProtocolRequestId;
 use codex_protocol::protocol::Event;
 use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::McpListChangedEvent;
+use codex_protocol::protocol::McpListChangedKind;
 use codex_protocol::protocol::McpStartupCompleteEvent;
 use codex_protocol::protocol::McpStartupFailure;
 use codex_protocol::protocol::McpStartupStatus;
 use codex_protocol::protocol::McpStartupUpdateEvent;
 use codex_protocol::protocol::SandboxPolicy;
+use codex_rmcp_client::ElicitationAction;
 use codex_rmcp_client::ElicitationResponse;
+use codex_rmcp_client::ListChanged;
+use codex_rmcp_client::ListRoots;
 use codex_rmcp_client::OAuthCredentialsStoreMode;
+use codex_rmcp_client::OnListChanged;
 use codex_rmcp_client::RmcpClient;
+use codex_rmcp_client::SamplingRequest;
+use codex_rmcp_client::SamplingResponse;
 use codex_rmcp_client::SendElicitation;
+use codex_rmcp_client::SendSampling;
+use codex_rmcp_client::StdioSandboxLauncher;
 use futures::future::BoxFuture;
 use futures::future::FutureExt;
 use futures::future::Shared;
@@ -55,6 +80,8 @@ use rmcp::model::ReadResourceResult;
 use rmcp::model::RequestId;
 use rmcp::model::Resource;
 use rmcp::model::ResourceTemplate;
+use rmcp::model::Root;
+use rmcp::model::RootsCapabilities;
 use rmcp::model::Tool;
 
 use serde::Deserialize;
@@ -70,6 +97,7 @@ use tracing::warn;
 
 use crate::codex::INITIAL_SUBMIT_ID;
 use crate::config::types::McpServerConfig;
+use crate::config::types::McpServerSandboxConfig;
 use crate::config::types::McpServerTransportConfig;
 
 /// Delimiter used to separate the server name from the tool name in a fully
@@ -199,46 +227,283 @@ impl ElicitationRequestManager {
     }
 
     fn make_sender(&self, server_name: String, tx_event: Sender<Event>) -> SendElicitation {
-        let elicitation_requests = self.requests.clone();
+        let elicitation_requests = self.clone();
         Box::new(move |id, elicitation| {
             let elicitation_requests = elicitation_requests.clone();
             let tx_event = tx_event.clone();
             let server_name = server_name.clone();
             async move {
-                let (tx, rx) = oneshot::channel();
-                {
-                    let mut lock = elicitation_requests.lock().await;
-                    lock.insert((server_name.clone(), id.clone()), tx);
+                elicitation_requests
+                    .request(server_name, id, elicitation.message, tx_event)
+                    .await
+            }
+            .boxed()
+        })
+    }
+
+    /// Builds the `sampling/createMessage` handler for `server_name`. Each
+    /// request is shown to the user as an elicitation and only forwarded to
+    /// `sampling_handler` once accepted.
+    fn make_sampling_sender(
+        &self,
+        server_name: String,
+        tx_event: Sender<Event>,
+        sampling_handler: McpSamplingHandler,
+    ) -> SendSampling {
+        let elicitation_requests = self.clone();
+        Box::new(move |id, request| {
+            let elicitation_requests = elicitation_requests.clone();
+            let tx_event = tx_event.clone();
+            let server_name = server_name.clone();
+            let sampling_handler = Arc::clone(&sampling_handler);
+            async move {
+                let message = sampling_approval_message(&server_name, &request);
+                let response = elicitation_requests
+                    .request(server_name.clone(), id, message, tx_event)
+                    .await?;
+                if !matches!(response.action, ElicitationAction::Accept) {
+                    return Err(anyhow!("user declined the sampling request"));
                 }
-                let _ = tx_event
-                    .send(Event {
-                        id: "mcp_elicitation_request".to_string(),
-                        msg: EventMsg::ElicitationRequest(ElicitationRequestEvent {
-                            server_name,
-                            id: match id.clone() {
-                                rmcp::model::NumberOrString::String(value) => {
-                                    ProtocolRequestId::String(value.to_string())
-                                }
-                                rmcp::model::NumberOrString::Number(value) => {
-                                    ProtocolRequestId::Integer(value)
-                                }
-                            },
-                            message: elicitation.message,
-                        }),
-                    })
-                    .await;
-                rx.await
-                    .context("elicitation request channel closed unexpectedly")
+                sampling_handler(server_name, request).await
             }
             .boxed()
         })
     }
+
+    async fn request(
+        &self,
+        server_name: String,
+        id: RequestId,
+        message: String,
+        tx_event: Sender<Event>,
+    ) -> Result<ElicitationResponse> {
+        let (tx, rx) = oneshot::channel();
+        {
+            let mut lock = self.requests.lock().await;
+            lock.insert((server_name.clone(), id.clone()), tx);
+        }
+        let _ = tx_event
+            .send(Event {
+                id: "mcp_elicitation_request".to_string(),
+                msg: EventMsg::ElicitationRequest(ElicitationRequestEvent {
+                    server_name,
+                    id: match id {
+                        rmcp::model::NumberOrString::String(value) => {
+                            ProtocolRequestId::String(value.to_string())
+                        }
+                        rmcp::model::NumberOrString::Number(value) => {
+                            ProtocolRequestId::Integer(value)
+                        }
+                    },
+                    message,
+                }),
+            })
+            .await;
+        rx.await
+            .context("elicitation request channel closed unexpectedly")
+    }
+}
+
+/// Runs an approved `sampling/createMessage` request against the session's
+/// model. Receives the name of the requesting server.
+pub(crate) type McpSamplingHandler = Arc<
+    dyn Fn(String, SamplingRequest) -> BoxFuture<'static, Result<SamplingResponse>> + Send + Sync,
+>;
+
+/// Workspace roots reported to servers through `roots/list`: the session cwd
+/// followed by any additional writable roots of the sandbox policy.
+#[derive(Clone, Default)]
+struct WorkspaceRoots {
+    roots: Arc<StdRwLock<Vec<Root>>>,
+}
+
+impl WorkspaceRoots {
+    /// Replaces the current roots, returning whether they changed.
+    fn update(&self, sandbox_state: &SandboxState) -> bool {
+        let roots = workspace_roots(sandbox_state);
+        let mut guard = match self.roots.write() {
+            Ok(guard) => guard,
+            Err(err) => err.into_inner(),
+        };
+        if *guard == roots {
+            return false;
+        }
+        *guard = roots;
+        true
+    }
+
+    fn make_lister(&self) -> ListRoots {
+        let roots = Arc::clone(&self.roots);
+        Box::new(move || {
+            let roots = match roots.read() {
+                Ok(guard) => guard.clone(),
+                Err(err) => err.into_inner().clone(),
+            };
+            async move { Ok(roots) }.boxed()
+        })
+    }
+}
+
+fn workspace_roots(sandbox_state: &SandboxState) -> Vec<Root> {
+    let cwd = sandbox_state.sandbox_cwd.as_path();
+    let mut paths = vec![cwd.to_path_buf()];
+    for writable_root in sandbox_state
+        .sandbox_policy
+        .get_writable_roots_with_cwd(cwd)
+    {
+        let path = writable_root.root.to_path_buf();
+        if !paths.contains(&path) {
+            paths.push(path);
+        }
+    }
+    paths
+        .into_iter()
+        .filter_map(|path| {
+            let uri = url::Url::from_file_path(&path).ok()?.to_string();
+            let name = path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned());
+            Some(Root { uri, name })
+        })
+        .collect()
+}
+
+/// Handlers for requests that MCP servers send back to Codex.
+#[derive(Clone)]
+struct ServerRequestHandlers {
+    elicitation_requests: ElicitationRequestManager,
+    sampling_handler: Option<McpSamplingHandler>,
+    roots: WorkspaceRoots,
+}
+
+/// Tools last listed from a server. Shared with the server's `list_changed`
+/// handler so that a refresh shows up in the next `list_all_tools` call.
+///
+/// Each listing takes a generation from [`ServerTools::begin_refresh`]; a
+/// listing that finishes after a newer one started is dropped, so overlapping
+/// refreshes cannot leave an older list in place.
+#[derive(Clone, Default)]
+struct ServerTools {
+    tools: Arc<StdRwLock<Vec<ToolInfo>>>,
+    generation: Arc<AtomicU64>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum ToolsRefresh {
+    Changed,
+    Unchanged,
+    Stale,
+}
+
+impl ServerTools {
+    fn get(&self) -> Vec<ToolInfo> {
+        match self.tools.read() {
+            Ok(guard) => guard.clone(),
+            Err(err) => err.into_inner().clone(),
+        }
+    }
+
+    fn begin_refresh(&self) -> u64 {
+        self.generation.fetch_add(1, Ordering::SeqCst) + 1
+    }
+
+    /// Stores `tools` unless a refresh newer than `generation` has started.
+    fn finish_refresh(&self, generation: u64, tools: Vec<ToolInfo>) -> ToolsRefresh {
+        let mut guard = match self.tools.write() {
+            Ok(guard) => guard,
+            Err(err) => err.into_inner(),
+        };
+        if self.generation.load(Ordering::SeqCst) != generation {
+            return ToolsRefresh::Stale;
+        }
+        if same_tools(&guard, &tools) {
+            return ToolsRefresh::Unchanged;
+        }
+        *guard = tools;
+        ToolsRefresh::Changed
+    }
+}
+
+fn same_tools(current: &[ToolInfo], fresh: &[ToolInfo]) -> bool {
+    current.len() == fresh.len()
+        && current.iter().zip(fresh).all(|(current, fresh)| {
+            current.tool_name == fresh.tool_name
+                && serde_json::to_value(&current.tool).ok()
+                    == serde_json::to_value(&fresh.tool).ok()
+        })
+}
+
+/// Builds the handler for `notifications/tools/list_changed` and
+/// `notifications/resources/list_changed`. Tools are listed again into
+/// `tools` and the UI is only told when the list actually changed; resources
+/// are always listed live, so only the UI is told.
+fn make_list_changed_handler(
+    server_name: String,
+    client: Weak<RmcpClient>,
+    tools: ServerTools,
+    timeout: Option<Duration>,
+    tx_event: Sender<Event>,
+) -> OnListChanged {
+    Box::new(move |list| {
+        let server_name = server_name.clone();
+        let client = client.clone();
+        let tools = tools.clone();
+        let tx_event = tx_event.clone();
+        tokio::spawn(async move {
+            let kind = match list {
+                ListChanged::Tools => {
+                    let Some(client) = client.upgrade() else {
+                        return;
+                    };
+                    let generation = tools.begin_refresh();
+                    let fresh_tools = match list_tools_for_client_uncached(
+                        &server_name,
+                        &client,
+                        timeout,
+                    )
+                    .await
+                    {
+                        Ok(fresh_tools) => fresh_tools,
+                        Err(err) => {
+                            warn!(
+                                "Failed to refresh tools for MCP server '{server_name}': {err:#}"
+                            );
+                            return;
+                        }
+                    };
+                    let cache_tools =
+                        (server_name == CODEX_APPS_MCP_SERVER_NAME).then(|| fresh_tools.clone());
+                    let refresh = tools.finish_refresh(generation, fresh_tools);
+                    if refresh == ToolsRefresh::Stale {
+                        return;
+                    }
+                    if let Some(cache_tools) = cache_tools {
+                        write_cached_codex_apps_tools(&cache_tools);
+                    }
+                    if refresh == ToolsRefresh::Unchanged {
+                        return;
+                    }
+                    McpListChangedKind::Tools
+                }
+                ListChanged::Resources => McpListChangedKind::Resources,
+            };
+            let _ = tx_event
+                .send(Event {
+                    id: INITIAL_SUBMIT_ID.to_owned(),
+                    msg: EventMsg::McpListChanged(McpListChangedEvent {
+                        server: server_name,
+                        kind,
+                    }),
+                })
+                .await;
+        });
+    })
 }
 
 #[derive(Clone)]
 struct ManagedClient {
     client: Arc<RmcpClient>,
-    tools: Vec<ToolInfo>,
+    tools: ServerTools,
     tool_filter: ToolFilter,
     tool_timeout: Option<Duration>,
     server_supports_sandbox_state_capability: bool,
@@ -274,7 +539,8 @@ impl AsyncManagedClient {
         store_mode: OAuthCredentialsStoreMode,
         cancel_token: CancellationToken,
         tx_event: Sender<Event>,
-        elicitation_requests: ElicitationRequestManager,
+        request_handlers: ServerRequestHandlers,
+        sandbox_state: SandboxState,
     ) -> Self {
         let tool_filter = ToolFilter::from_config(&config);
         let fut = async move {
@@ -282,8 +548,14 @@ impl AsyncManagedClient {
                 return Err(error.into());
             }
 
-            let client =
-                Arc::new(make_rmcp_client(&server_name, config.transport, store_mode).await?);
+            let sandbox = match &config.sandbox {
+                Some(sandbox) => stdio_sandbox_launcher(sandbox, &sandbox_state)
+                    .with_context(|| format!("failed to sandbox MCP server `{server_name}`"))?,
+                None => None,
+            };
+            let client = Arc::new(
+                make_rmcp_client(&server_name, config.transport, store_mode, sandbox).await?,
+            );
             match start_server_task(
                 server_name,
                 client,
@@ -291,7 +563,7 @@ impl AsyncManagedClient {
                 config.tool_timeout_sec.unwrap_or(DEFAULT_TOOL_TIMEOUT),
                 tool_filter,
                 tx_event,
-                elicitation_requests,
+                request_handlers,
             )
             .or_cancel(&cancel_token)
             .await
@@ -313,6 +585,11 @@ impl AsyncManagedClient {
         let managed = self.client().await?;
         managed.notify_sandbox_state_change(sandbox_state).await
     }
+
+    async fn notify_roots_list_changed(&self) -> Result<()> {
+        let managed = self.client().await?;
+        managed.client.notify_roots_list_changed().await
+    }
 }
 
 pub const MCP_SANDBOX_STATE_CAPABILITY: &str = "codex/sandbox-state";
@@ -336,9 +613,15 @@ pub struct SandboxState {
 pub(crate) struct McpConnectionManager {
     clients: HashMap<String, AsyncManagedClient>,
     elicitation_requests: ElicitationRequestManager,
+    sampling_handler: Option<McpSamplingHandler>,
+    roots: WorkspaceRoots,
 }
 
 impl McpConnectionManager {
+    /// Starts every enabled server. When `sampling_handler` is set, servers
+    /// are offered the `sampling` capability and may ask the session's model
+    /// for completions after user approval.
+    #[allow(clippy::too_many_arguments)]
     pub async fn initialize(
         &mut self,
         mcp_servers: &HashMap<String, McpServerConfig>,
@@ -347,6 +630,7 @@ impl McpConnectionManager {
         tx_event: Sender<Event>,
         cancel_token: CancellationToken,
         initial_sandbox_state: SandboxState,
+        sampling_handler: Option<McpSamplingHandler>,
     ) {
         if cancel_token.is_cancelled() {
             return;
@@ -354,6 +638,13 @@ impl McpConnectionManager {
         let mut clients = HashMap::new();
         let mut join_set = JoinSet::new();
         let elicitation_requests = ElicitationRequestManager::default();
+        let roots = WorkspaceRoots::default();
+        roots.update(&initial_sandbox_state);
+        let request_handlers = ServerRequestHandlers {
+            elicitation_requests: elicitation_requests.clone(),
+            sampling_handler: sampling_handler.clone(),
+            roots: roots.clone(),
+        };
         let mcp_servers = mcp_servers.clone();
         for (server_name, cfg) in mcp_servers.into_iter().filter(|(_, cfg)| cfg.enabled) {
             let cancel_token = cancel_token.child_token();
@@ -371,7 +662,8 @@ impl McpConnectionManager {
                 store_mode,
                 cancel_token.clone(),
                 tx_event.clone(),
-                elicitation_requests.clone(),
+                request_handlers.clone(),
+                initial_sandbox_state.clone(),
             );
             clients.insert(server_name.clone(), async_managed_client.clone());
             let tx_event = tx_event.clone();
@@ -419,6 +711,8 @@ impl McpConnectionManager {
         }
         self.clients = clients;
         self.elicitation_requests = elicitation_requests.clone();
+        self.sampling_handler = sampling_handler;
+        self.roots = roots;
         tokio::spawn(async move {
             let outcomes = join_set.join_all().await;
             let mut summary = McpStartupCompleteEvent::default();
@@ -463,6 +757,11 @@ impl McpConnectionManager {
             .await
     }
 
+    /// Sampling handler to carry over when the servers are restarted.
+    pub(crate) fn sampling_handler(&self) -> Option<McpSamplingHandler> {
+        self.sampling_handler.clone()
+    }
+
     pub(crate) async fn wait_for_server_ready(&self, server_name: &str, timeout: Duration) -> bool {
         let Some(async_managed_client) = self.clients.get(server_name) else {
             return false;
@@ -485,7 +784,7 @@ impl McpConnectionManager {
                 let rmcp_client = client.client;
                 let tool_timeout = client.tool_timeout;
                 let tool_filter = client.tool_filter;
-                let mut server_tools = client.tools;
+                let mut server_tools = client.tools.get();
 
                 if server_name == CODEX_APPS_MCP_SERVER_NAME {
                     match list_tools_for_client(server_name, &rmcp_client, tool_timeout).await {
@@ -735,11 +1034,17 @@ impl McpConnectionManager {
 
     pub async fn notify_sandbox_state_change(&self, sandbox_state: &SandboxState) -> Result<()> {
         let mut join_set = JoinSet::new();
+        let roots_changed = self.roots.update(sandbox_state);
 
         for async_managed_client in self.clients.values() {
             let sandbox_state = sandbox_state.clone();
             let async_managed_client = async_managed_client.clone();
             join_set.spawn(async move {
+                if roots_changed
+                    && let Err(err) = async_managed_client.notify_roots_list_changed().await
+                {
+                    warn!("Failed to notify roots change to MCP server: {err:#}");
+                }
                 async_managed_client
                     .notify_sandbox_state_change(&sandbox_state)
                     .await
@@ -894,13 +1199,21 @@ async fn start_server_task(
     tool_timeout: Duration,
     tool_filter: ToolFilter,
     tx_event: Sender<Event>,
-    elicitation_requests: ElicitationRequestManager,
+    request_handlers: ServerRequestHandlers,
 ) -> Result<ManagedClient, StartupOutcomeError> {
+    let ServerRequestHandlers {
+        elicitation_requests,
+        sampling_handler,
+        roots,
+    } = request_handlers;
     let params = InitializeRequestParam {
         capabilities: ClientCapabilities {
             experimental: None,
-            roots: None,
-            sampling: None,
+            roots: Some(RootsCapabilities {
+                list_changed: Some(true),
+            }),
+            // https://modelcontextprotocol.io/specification/2025-06-18/client/sampling#capabilities
+            sampling: sampling_handler.as_ref().map(|_| serde_json::Map::new()),
             // https://modelcontextprotocol.io/specification/2025-06-18/client/elicitation#capabilities
             // indicates this should be an empty object.
             elicitation: Some(ElicitationCapability {
@@ -917,16 +1230,44 @@ async fn start_server_task(
         protocol_version: ProtocolVersion::V_2025_06_18,
     };
 
-    let send_elicitation = elicitation_requests.make_sender(server_name.clone(), tx_event);
+    let send_elicitation = elicitation_requests.make_sender(server_name.clone(), tx_event.clone());
+    let send_sampling = sampling_handler.map(|sampling_handler| {
+        elicitation_requests.make_sampling_sender(
+            server_name.clone(),
+            tx_event.clone(),
+            sampling_handler,
+        )
+    });
+    let tools = ServerTools::default();
+    let on_list_changed = make_list_changed_handler(
+        server_name.clone(),
+        Arc::downgrade(&client),
+        tools.clone(),
+        Some(tool_timeout),
+        tx_event,
+    );
 
     let initialize_result = client
-        .initialize(params, startup_timeout, send_elicitation)
+        .initialize(
+            params,
+            startup_timeout,
+            send_elicitation,
+            send_sampling,
+            Some(roots.make_lister()),
+            Some(on_list_changed),
+        )
         .await
         .map_err(StartupOutcomeError::from)?;
 
-    let tools = list_tools_for_client(&server_name, &client, startup_timeout)
-        .await
-        .map_err(StartupOutcomeError::from)?;
+    // A `list_changed` refresh that starts while this listing runs is newer
+    // and wins.
+    let generation = tools.begin_refresh();
+    tools.finish_refresh(
+        generation,
+        list_tools_for_client(&server_name, &client, startup_timeout)
+            .await
+            .map_err(StartupOutcomeError::from)?,
+    );
 
     let server_supports_sandbox_state_capability = initialize_result
         .capabilities
@@ -950,6 +1291,7 @@ async fn make_rmcp_client(
     server_name: &str,
     transport: McpServerTransportConfig,
     store_mode: OAuthCredentialsStoreMode,
+    sandbox: Option<StdioSandboxLauncher>,
 ) -> Result<RmcpClient, StartupOutcomeError> {
     match transport {
         McpServerTransportConfig::Stdio {
@@ -961,7 +1303,7 @@ async fn make_rmcp_client(
         } => {
             let command_os: OsString = command.into();
             let args_os: Vec<OsString> = args.into_iter().map(Into::into).collect();
-            RmcpClient::new_stdio_client(command_os, args_os, env, &env_vars, cwd)
+            RmcpClient::new_stdio_client(command_os, args_os, env, &env_vars, cwd, sandbox)
                 .await
                 .map_err(|err| StartupOutcomeError::from(anyhow!(err)))
         }
@@ -990,6 +1332,59 @@ async fn make_rmcp_client(
     }
 }
 
+/// Builds the launcher that confines a stdio server to its configured
+/// sandbox. Paths in the policy are resolved against the session's cwd.
+fn stdio_sandbox_launcher(
+    sandbox: &McpServerSandboxConfig,
+    sandbox_state: &SandboxState,
+) -> Result<Option<StdioSandboxLauncher>> {
+    let policy = sandbox.sandbox_policy();
+    if matches!(policy, SandboxPolicy::DangerFullAccess) {
+        return Ok(None);
+    }
+
+    let mut env = HashMap::new();
+    if !policy.has_full_network_access() {
+        env.insert(
+            CODEX_SANDBOX_NETWORK_DISABLED_ENV_VAR.to_string(),
+            "1".to_string(),
+        );
+    }
+    let cwd = sandbox_state.sandbox_cwd.as_path();
+    match get_platform_sandbox(false) {
+        #[cfg(target_os = "macos")]
+        Some(SandboxType::MacosSeatbelt) => {
+            env.insert(CODEX_SANDBOX_ENV_VAR.to_string(), "seatbelt".to_string());
+            Ok(Some(StdioSandboxLauncher {
+                program: PathBuf::from(MACOS_PATH_TO_SEATBELT_EXECUTABLE),
+                arg0: None,
+                args: create_seatbelt_command_args(Vec::new(), &policy, cwd),
+                env,
+            }))
+        }
+        Some(SandboxType::LinuxSeccomp) => {
+            let program = sandbox_state
+                .codex_linux_sandbox_exe
+                .clone()
+                .ok_or_else(|| anyhow!("missing codex-linux-sandbox executable path"))?;
+            Ok(Some(StdioSandboxLauncher {
+                program,
+                arg0: Some("codex-linux-sandbox".to_string()),
+                args: create_linux_sandbox_command_args(
+                    Vec::new(),
+                    &policy,
+                    cwd,
+                    sandbox_state.use_linux_sandbox_bwrap,
+                ),
+                env,
+            }))
+        }
+        _ => Err(anyhow!(
+            "MCP server sandboxing is not supported on this platform"
+        )),
+    }
+}
+
 async fn list_tools_for_client(
     server_name: &str,
     client: &Arc<RmcpClient>,
@@ -1137,9 +1532,11 @@ mod mcp_init_error_display_tests {}
 #[cfg(test)]
 mod tests {
     use super::*;
+    use codex_protocol::config_types::SandboxMode;
     use codex_protocol::protocol::McpAuthStatus;
     use rmcp::model::JsonObject;
     use std::collections::HashSet;
+    use std::path::Path;
     use std::sync::Arc;
 
     fn create_test_tool(server_name: &str, tool_name: &str) -> ToolInfo {
@@ -1161,6 +1558,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn server_tools_drop_stale_and_unchanged_refreshes() {
+        let tools = ServerTools::default();
+        let older = tools.begin_refresh();
+        let newer = tools.begin_refresh();
+
+        assert_eq!(
+            tools.finish_refresh(newer, vec![create_test_tool("docs", "search")]),
+            ToolsRefresh::Changed
+        );
+        assert_eq!(
+            tools.finish_refresh(older, vec![create_test_tool("docs", "stale")]),
+            ToolsRefresh::Stale
+        );
+        let names: Vec<String> = tools.get().into_iter().map(|t| t.tool_name).collect();
+        assert_eq!(names, vec!["search".to_string()]);
+
+        let latest = tools.begin_refresh();
+        assert_eq!(
+            tools.finish_refresh(latest, vec![create_test_tool("docs", "search")]),
+            ToolsRefresh::Unchanged
+        );
+    }
+
     #[test]
     fn test_qualify_tools_short_non_duplicated_names() {
         let tools = vec![
@@ -1331,6 +1752,7 @@ mod tests {
                 enabled_tools: None,
                 disabled_tools: None,
                 scopes: None,
+                sandbox: None,
             },
             auth_status: McpAuthStatus::Unsupported,
         };
@@ -1377,6 +1799,7 @@ mod tests {
                 enabled_tools: None,
                 disabled_tools: None,
                 scopes: None,
+                sandbox: None,
             },
             auth_status: McpAuthStatus::Unsupported,
         };
@@ -1401,4 +1824,80 @@ mod tests {
             display
         );
     }
+
+    fn sandbox_state(codex_linux_sandbox_exe: Option<PathBuf>) -> SandboxState {
+        SandboxState {
+            sandbox_policy: SandboxPolicy::DangerFullAccess,
+            codex_linux_sandbox_exe,
+            sandbox_cwd: PathBuf::from("/workspace/project"),
+            use_linux_sandbox_bwrap: false,
+        }
+    }
+
+    #[test]
+    fn stdio_sandbox_launcher_skips_danger_full_access() {
+        let sandbox = McpServerSandboxConfig {
+            mode: SandboxMode::DangerFullAccess,
+            network_access: false,
+            writable_roots: Vec::new(),
+        };
+
+        let launcher = stdio_sandbox_launcher(&sandbox, &sandbox_state(None))
+            .expect("danger-full-access needs no launcher");
+
+        assert!(launcher.is_none());
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn stdio_sandbox_launcher_wraps_server_in_linux_sandbox() {
+        let sandbox = McpServerSandboxConfig {
+            mode: SandboxMode::WorkspaceWrite,
+            network_access: false,
+            writable_roots: Vec::new(),
+        };
+        let exe = PathBuf::from("/usr/local/bin/codex");
+
+        let launcher = stdio_sandbox_launcher(&sandbox, &sandbox_state(Some(exe.clone())))
+            .expect("build launcher")
+            .expect("workspace-write should be sandboxed");
+
+        assert_eq!(launcher.program, exe);
+        assert_eq!(launcher.arg0.as_deref(), Some("codex-linux-sandbox"));
+        assert_eq!(
+            launcher.args,
+            create_linux_sandbox_command_args(
+                Vec::new(),
+                &sandbox.sandbox_policy(),
+                Path::new("/workspace/project"),
+                false,
+            )
+        );
+        assert_eq!(launcher.args.last().map(String::as_str), Some("--"));
+        assert_eq!(
+            launcher.env,
+            HashMap::from([(
+                CODEX_SANDBOX_NETWORK_DISABLED_ENV_VAR.to_string(),
+                "1".to_string()
+            )])
+        );
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn stdio_sandbox_launcher_requires_linux_sandbox_exe() {
+        let sandbox = McpServerSandboxConfig {
+            mode: SandboxMode::ReadOnly,
+            network_access: false,
+            writable_roots: Vec::new(),
+        };
+
+        let err = stdio_sandbox_launcher(&sandbox, &sandbox_state(None))
+            .expect_err("missing sandbox executable should fail");
+
+        assert_eq!(
+            err.to_string(),
+            "missing codex-linux-sandbox executable path"
+        );
+    }
 }
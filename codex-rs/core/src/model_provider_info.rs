@@ -9,6 +9,7 @@ use crate::auth::AuthMode;
 use crate::error::EnvVarError;
 use codex_api::Provider as ApiProvider;
 use codex_api::provider::RetryConfig as ApiRetryConfig;
+use codex_protocol::openai_models::ReasoningEffort;
 use http::HeaderMap;
 use http::header::HeaderName;
 use http::header::HeaderValue;
@@ -112,6 +113,34 @@ pub struct ModelProviderInfo {
     /// Whether this provider supports the Responses API WebSocket transport.
     #[serde(default)]
     pub supports_websockets: bool,
+
+    /// Per-model settings keyed by model slug. They take precedence over what
+    /// Codex infers for models listed by the provider's `/models` endpoint,
+    /// and models declared here are offered in the model picker even when the
+    /// provider does not list them.
+    #[serde(default)]
+    pub models: HashMap<String, ProviderModelOverrides>,
+
+    /// Whether to fetch the provider's `/models` endpoint and offer the models
+    /// it lists in the model picker. Off by default so that sessions do not
+    /// query providers that lack the endpoint.
+    #[serde(default)]
+    pub list_models: bool,
+}
+
+/// User-declared settings for one model served by a custom provider.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct ProviderModelOverrides {
+    /// Size of the model's context window, in tokens.
+    pub context_window: Option<i64>,
+
+    /// Whether the model accepts reasoning parameters. When false, Codex
+    /// sends no reasoning settings and hides the effort choices.
+    pub supports_reasoning: Option<bool>,
+
+    /// Reasoning effort used when none is configured.
+    pub default_reasoning_effort: Option<ReasoningEffort>,
 }
 
 impl ModelProviderInfo {
@@ -257,6 +286,8 @@ impl ModelProviderInfo {
             stream_idle_timeout_ms: None,
             requires_openai_auth: true,
             supports_websockets: true,
+            models: HashMap::new(),
+            list_models: false,
         }
     }
 
@@ -304,6 +335,8 @@ pub fn built_in_model_providers() -> HashMap<String, ModelProviderInfo> {
                 stream_idle_timeout_ms: None,
                 requires_openai_auth: false,
                 supports_websockets: false,
+                models: HashMap::new(),
+                list_models: false,
             },
         ),
         (
@@ -356,6 +389,8 @@ pub fn create_oss_provider_with_base_url(base_url: &str, wire_api: WireApi) -> M
         stream_idle_timeout_ms: None,
         requires_openai_auth: false,
         supports_websockets: false,
+        models: HashMap::new(),
+        list_models: false,
     }
 }
 
@@ -385,6 +420,8 @@ base_url = "http://localhost:11434/v1"
             stream_idle_timeout_ms: None,
             requires_openai_auth: false,
             supports_websockets: false,
+            models: HashMap::new(),
+            list_models: false,
         };
 
         let provider: ModelProviderInfo = toml::from_str(azure_provider_toml).unwrap();
@@ -416,6 +453,8 @@ query_params = { api-version = "2025-04-01-preview" }
             stream_idle_timeout_ms: None,
             requires_openai_auth: false,
             supports_websockets: false,
+            models: HashMap::new(),
+            list_models: false,
         };
 
         let provider: ModelProviderInfo = toml::from_str(azure_provider_toml).unwrap();
@@ -450,12 +489,47 @@ env_http_headers = { "X-Example-Env-Header" = "EXAMPLE_ENV_VAR" }
             stream_idle_timeout_ms: None,
             requires_openai_auth: false,
             supports_websockets: false,
+            models: HashMap::new(),
+            list_models: false,
         };
 
         let provider: ModelProviderInfo = toml::from_str(azure_provider_toml).unwrap();
         assert_eq!(expected_provider, provider);
     }
 
+    #[test]
+    fn test_deserialize_provider_model_overrides_toml() {
+        let provider_toml = r#"
+name = "Together"
+base_url = "https://api.together.xyz/v1"
+env_key = "TOGETHER_API_KEY"
+
+[models."deepseek-ai/DeepSeek-R1"]
+context_window = 163840
+default_reasoning_effort = "high"
+
+[models."meta-llama/Llama-3.3-70B-Instruct-Turbo"]
+supports_reasoning = false
+        "#;
+
+        let provider: ModelProviderInfo = toml::from_str(provider_toml).unwrap();
+        assert_eq!(
+            provider.models,
+            maplit::hashmap! {
+                "deepseek-ai/DeepSeek-R1".to_string() => ProviderModelOverrides {
+                    context_window: Some(163_840),
+                    supports_reasoning: None,
+                    default_reasoning_effort: Some(ReasoningEffort::High),
+                },
+                "meta-llama/Llama-3.3-70B-Instruct-Turbo".to_string() => ProviderModelOverrides {
+                    context_window: None,
+                    supports_reasoning: Some(false),
+                    default_reasoning_effort: None,
+                },
+            }
+        );
+    }
+
     #[test]
     fn test_deserialize_chat_wire_api_shows_helpful_error() {
         let provider_toml = r#"
@@ -5,11 +5,33 @@ use serde::Deserialize;
 use serde::Serialize;
 use std::io;
 use std::io::ErrorKind;
+use std::path::Path;
 use std::path::PathBuf;
 use std::time::Duration;
 use tokio::fs;
 use tracing::error;
 
+/// Directory under `codex_home` holding one models cache per custom provider.
+const PROVIDER_MODELS_CACHE_DIR: &str = "provider_models_cache";
+
+/// Path of the models cache for the provider configured as `provider_id` in
+/// `model_providers`.
+pub(crate) fn provider_cache_path(codex_home: &Path, provider_id: &str) -> PathBuf {
+    let file_stem: String = provider_id
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    codex_home
+        .join(PROVIDER_MODELS_CACHE_DIR)
+        .join(format!("{file_stem}.json"))
+}
+
 /// Manages loading and saving of models cache to disk.
 #[derive(Debug)]
 pub(crate) struct ModelsCacheManager {
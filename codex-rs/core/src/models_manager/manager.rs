@@ -1,3 +1,4 @@
+use super::cache;
 use super::cache::ModelsCacheManager;
 use crate::api_bridge::auth_provider_from_auth;
 use crate::api_bridge::map_api_error;
@@ -8,9 +9,14 @@ use crate::default_client::build_reqwest_client;
 use crate::error::CodexErr;
 use crate::error::Result as CoreResult;
 use crate::features::Feature;
+use crate::model_provider_info::LMSTUDIO_OSS_PROVIDER_ID;
 use crate::model_provider_info::ModelProviderInfo;
+use crate::model_provider_info::OLLAMA_OSS_PROVIDER_ID;
+use crate::model_provider_info::built_in_model_providers;
 use crate::models_manager::collaboration_mode_presets::builtin_collaboration_mode_presets;
 use crate::models_manager::model_info;
+use crate::models_manager::model_info::LocalModelMetadata;
+use crate::models_manager::model_info::LocalModelMetadataFetcher;
 use crate::models_manager::model_presets::builtin_model_presets;
 use codex_api::ModelsClient;
 use codex_api::ReqwestTransport;
@@ -19,8 +25,10 @@ use codex_protocol::openai_models::ModelInfo;
 use codex_protocol::openai_models::ModelPreset;
 use codex_protocol::openai_models::ModelsResponse;
 use http::HeaderMap;
+use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::sync::OnceLock;
 use std::time::Duration;
 use tokio::sync::RwLock;
 use tokio::sync::TryLockError;
@@ -51,6 +59,14 @@ pub struct ModelsManager {
     etag: RwLock<Option<String>>,
     cache_manager: ModelsCacheManager,
     provider: ModelProviderInfo,
+    local_model_metadata_fetcher: OnceLock<Arc<dyn LocalModelMetadataFetcher>>,
+    /// What local servers reported, keyed by provider id and model. Failed
+    /// lookups are cached as `None` so an unreachable server is not queried on
+    /// every turn.
+    local_model_metadata: RwLock<HashMap<(String, String), Option<LocalModelMetadata>>>,
+    /// Models listed by custom providers, keyed by provider id.
+    provider_models: RwLock<HashMap<String, Vec<ModelInfo>>>,
+    codex_home: PathBuf,
 }
 
 impl ModelsManager {
@@ -61,12 +77,16 @@ impl ModelsManager {
         let cache_path = codex_home.join(MODEL_CACHE_FILE);
         let cache_manager = ModelsCacheManager::new(cache_path, DEFAULT_MODEL_CACHE_TTL);
         Self {
+            codex_home,
             local_models: builtin_model_presets(auth_manager.auth_mode()),
             remote_models: RwLock::new(Self::load_remote_models_from_file().unwrap_or_default()),
             auth_manager,
             etag: RwLock::new(None),
             cache_manager,
             provider: ModelProviderInfo::create_openai_provider(),
+            local_model_metadata_fetcher: OnceLock::new(),
+            local_model_metadata: RwLock::new(HashMap::new()),
+            provider_models: RwLock::new(HashMap::new()),
         }
     }
 
@@ -84,8 +104,21 @@ impl ModelsManager {
         {
             error!("failed to refresh available models: {err}");
         }
-        let remote_models = self.get_remote_models(config).await;
-        self.build_available_models(remote_models)
+        self.available_models(config).await
+    }
+
+    /// Whether models come from the configured provider's own `/models`
+    /// endpoint instead of Codex's catalog. This is the case for providers the
+    /// user defined in `model_providers`.
+    pub fn uses_provider_models(config: &Config) -> bool {
+        !built_in_model_providers().contains_key(&config.model_provider_id)
+    }
+
+    /// Whether the configured custom provider opted into having its `/models`
+    /// endpoint fetched with `list_models = true`. Other custom providers only
+    /// offer the models declared in their `models` table.
+    pub fn fetches_provider_models(config: &Config) -> bool {
+        Self::uses_provider_models(config) && config.model_provider.list_models
     }
 
     /// List collaboration mode presets.
@@ -99,6 +132,18 @@ impl ModelsManager {
     ///
     /// Returns an error if the internal lock cannot be acquired.
     pub fn try_list_models(&self, config: &Config) -> Result<Vec<ModelPreset>, TryLockError> {
+        if Self::uses_provider_models(config) {
+            let provider_models = self
+                .provider_models
+                .try_read()?
+                .get(&config.model_provider_id)
+                .cloned()
+                .unwrap_or_default();
+            let presets = Self::build_provider_models(config, provider_models);
+            if !presets.is_empty() {
+                return Ok(presets);
+            }
+        }
         let remote_models = self.try_get_remote_models(config)?;
         Ok(self.build_available_models(remote_models))
     }
@@ -123,8 +168,7 @@ impl ModelsManager {
         {
             error!("failed to refresh available models: {err}");
         }
-        let remote_models = self.get_remote_models(config).await;
-        let available = self.build_available_models(remote_models);
+        let available = self.available_models(config).await;
         available
             .iter()
             .find(|model| model.is_default)
@@ -141,12 +185,52 @@ impl ModelsManager {
             .await
             .into_iter()
             .find(|m| m.slug == model);
-        let model = if let Some(remote) = remote {
+        let mut info = if let Some(remote) = remote {
             remote
         } else {
             model_info::find_model_info_for_slug(model)
         };
-        model_info::with_config_overrides(model, config)
+        if let Some(metadata) = self.local_model_metadata(model, config).await {
+            info = model_info::with_local_metadata(info, &metadata);
+        }
+        if let Some(overrides) = config.model_provider.models.get(model) {
+            info = model_info::with_provider_model_overrides(info, overrides);
+        }
+        model_info::with_config_overrides(info, config)
+    }
+
+    /// Install the fetcher used to ask local model servers about their models.
+    /// Only the first fetcher installed is used.
+    pub fn set_local_model_metadata_fetcher(&self, fetcher: Arc<dyn LocalModelMetadataFetcher>) {
+        let _ = self.local_model_metadata_fetcher.set(fetcher);
+    }
+
+    /// Metadata the local server behind `config`'s provider reports for
+    /// `model`. Looked up the first time each model is resolved, so models
+    /// picked mid-session (e.g. with `/model`) are covered too.
+    async fn local_model_metadata(
+        &self,
+        model: &str,
+        config: &Config,
+    ) -> Option<LocalModelMetadata> {
+        let provider_id = config.model_provider_id.as_str();
+        if !matches!(
+            provider_id,
+            OLLAMA_OSS_PROVIDER_ID | LMSTUDIO_OSS_PROVIDER_ID
+        ) {
+            return None;
+        }
+        let fetcher = self.local_model_metadata_fetcher.get()?;
+        let key = (provider_id.to_string(), model.to_string());
+        if let Some(cached) = self.local_model_metadata.read().await.get(&key) {
+            return cached.clone();
+        }
+        let metadata = fetcher.fetch(provider_id, model, config).await;
+        self.local_model_metadata
+            .write()
+            .await
+            .insert(key, metadata.clone());
+        metadata
     }
 
     /// Refresh models if the provided ETag differs from the cached ETag.
@@ -174,6 +258,12 @@ impl ModelsManager {
         config: &Config,
         refresh_strategy: RefreshStrategy,
     ) -> CoreResult<()> {
+        if Self::uses_provider_models(config) {
+            if !Self::fetches_provider_models(config) {
+                return Ok(());
+            }
+            return self.refresh_provider_models(config, refresh_strategy).await;
+        }
         if !config.features.enabled(Feature::RemoteModels)
             || self.auth_manager.auth_mode() == Some(AuthMode::ApiKey)
         {
@@ -227,6 +317,60 @@ impl ModelsManager {
         Ok(())
     }
 
+    /// Refresh the models listed by the configured custom provider, caching
+    /// them per provider under `codex_home`.
+    async fn refresh_provider_models(
+        &self,
+        config: &Config,
+        refresh_strategy: RefreshStrategy,
+    ) -> CoreResult<()> {
+        let provider_id = &config.model_provider_id;
+        let cache_manager = ModelsCacheManager::new(
+            cache::provider_cache_path(&self.codex_home, provider_id),
+            DEFAULT_MODEL_CACHE_TTL,
+        );
+        let client_version = crate::models_manager::client_version_to_whole();
+        if refresh_strategy != RefreshStrategy::Online
+            && let Some(cache) = cache_manager.load_fresh(&client_version).await
+        {
+            self.provider_models
+                .write()
+                .await
+                .insert(provider_id.clone(), cache.models);
+            return Ok(());
+        }
+        if refresh_strategy == RefreshStrategy::Offline {
+            return Ok(());
+        }
+
+        let provider = &config.model_provider;
+        let auth = self.auth_manager.auth().await;
+        let api_provider = provider.to_api_provider(self.auth_manager.auth_mode())?;
+        let api_auth = auth_provider_from_auth(auth, provider).await?;
+        let transport = ReqwestTransport::new(build_reqwest_client());
+        let client = ModelsClient::new(transport, api_provider, api_auth);
+        let slugs = timeout(
+            MODELS_REFRESH_TIMEOUT,
+            client.list_provider_models(HeaderMap::new()),
+        )
+        .await
+        .map_err(|_| CodexErr::Timeout)?
+        .map_err(map_api_error)?;
+
+        let models: Vec<ModelInfo> = slugs
+            .iter()
+            .map(|slug| model_info::provider_model_info(slug))
+            .collect();
+        cache_manager
+            .persist_cache(&models, None, client_version)
+            .await;
+        self.provider_models
+            .write()
+            .await
+            .insert(provider_id.clone(), models);
+        Ok(())
+    }
+
     async fn get_etag(&self) -> Option<String> {
         self.etag.read().await.clone()
     }
@@ -268,6 +412,52 @@ impl ModelsManager {
         true
     }
 
+    async fn available_models(&self, config: &Config) -> Vec<ModelPreset> {
+        if Self::uses_provider_models(config) {
+            let provider_models = self
+                .provider_models
+                .read()
+                .await
+                .get(&config.model_provider_id)
+                .cloned()
+                .unwrap_or_default();
+            let presets = Self::build_provider_models(config, provider_models);
+            if !presets.is_empty() {
+                return presets;
+            }
+        }
+        let remote_models = self.get_remote_models(config).await;
+        self.build_available_models(remote_models)
+    }
+
+    /// Build picker presets for a custom provider from the models it lists
+    /// plus the ones declared under its `models` table, sorted by slug.
+    ///
+    /// Returns an empty list when the provider neither lists nor declares any
+    /// model, in which case callers fall back to the built-in presets.
+    fn build_provider_models(config: &Config, mut models: Vec<ModelInfo>) -> Vec<ModelPreset> {
+        let overrides = &config.model_provider.models;
+        for slug in overrides.keys() {
+            if !models.iter().any(|model| &model.slug == slug) {
+                models.push(model_info::provider_model_info(slug));
+            }
+        }
+        models.sort_by(|a, b| a.slug.cmp(&b.slug));
+
+        let mut presets: Vec<ModelPreset> = models
+            .into_iter()
+            .map(|model| match overrides.get(&model.slug) {
+                Some(overrides) => model_info::with_provider_model_overrides(model, overrides),
+                None => model,
+            })
+            .map(Into::into)
+            .collect();
+        if let Some(default) = presets.first_mut() {
+            default.is_default = true;
+        }
+        presets
+    }
+
     /// Merge remote model metadata into picker-ready presets, preserving existing entries.
     fn build_available_models(&self, mut remote_models: Vec<ModelInfo>) -> Vec<ModelPreset> {
         remote_models.sort_by(|a, b| a.priority.cmp(&b.priority));
@@ -319,12 +509,16 @@ impl ModelsManager {
         let cache_path = codex_home.join(MODEL_CACHE_FILE);
         let cache_manager = ModelsCacheManager::new(cache_path, DEFAULT_MODEL_CACHE_TTL);
         Self {
+            codex_home,
             local_models: builtin_model_presets(auth_manager.auth_mode()),
             remote_models: RwLock::new(Self::load_remote_models_from_file().unwrap_or_default()),
             auth_manager,
             etag: RwLock::new(None),
             cache_manager,
             provider,
+            local_model_metadata_fetcher: OnceLock::new(),
+            local_model_metadata: RwLock::new(HashMap::new()),
+            provider_models: RwLock::new(HashMap::new()),
         }
     }
 
@@ -357,14 +551,21 @@ mod tests {
     use crate::auth::AuthCredentialsStoreMode;
     use crate::config::ConfigBuilder;
     use crate::features::Feature;
+    use crate::model_provider_info::ProviderModelOverrides;
     use crate::model_provider_info::WireApi;
     use chrono::Utc;
+    use codex_protocol::openai_models::InputModality;
     use codex_protocol::openai_models::ModelsResponse;
+    use codex_protocol::openai_models::ReasoningEffort;
     use core_test_support::responses::mount_models_once;
     use pretty_assertions::assert_eq;
     use serde_json::json;
     use tempfile::tempdir;
+    use wiremock::Mock;
     use wiremock::MockServer;
+    use wiremock::ResponseTemplate;
+    use wiremock::matchers::method;
+    use wiremock::matchers::path;
 
     fn remote_model(slug: &str, display: &str, priority: i32) -> ModelInfo {
         remote_model_with_visibility(slug, display, priority, "list")
@@ -427,6 +628,8 @@ mod tests {
             stream_idle_timeout_ms: Some(5_000),
             requires_openai_auth: false,
             supports_websockets: false,
+            models: HashMap::new(),
+            list_models: false,
         }
     }
 
@@ -763,6 +966,218 @@ mod tests {
         assert_eq!(available, vec![expected_hidden, expected_visible]);
     }
 
+    /// Reports a small tool-less context for `llama3.2:3b` and counts lookups.
+    #[derive(Debug, Default)]
+    struct FakeLocalServer {
+        fetches: std::sync::Mutex<Vec<(String, String)>>,
+    }
+
+    #[async_trait::async_trait]
+    impl LocalModelMetadataFetcher for FakeLocalServer {
+        async fn fetch(
+            &self,
+            provider_id: &str,
+            model: &str,
+            _config: &Config,
+        ) -> Option<LocalModelMetadata> {
+            self.fetches
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner)
+                .push((provider_id.to_string(), model.to_string()));
+            (model == "llama3.2:3b").then(|| LocalModelMetadata {
+                slug: model.to_string(),
+                context_window: Some(8_192),
+                supports_tools: Some(false),
+                supports_images: Some(false),
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn get_model_info_fetches_local_model_metadata_per_provider_and_model() {
+        let codex_home = tempdir().expect("temp dir");
+        let mut config = ConfigBuilder::default()
+            .codex_home(codex_home.path().to_path_buf())
+            .build()
+            .await
+            .expect("load default test config");
+        let auth_manager =
+            AuthManager::from_auth_for_testing(CodexAuth::from_api_key("Test API Key"));
+        let provider = provider_for("http://example.test".to_string());
+        let manager =
+            ModelsManager::with_provider(codex_home.path().to_path_buf(), auth_manager, provider);
+        let server = Arc::new(FakeLocalServer::default());
+        manager.set_local_model_metadata_fetcher(server.clone());
+
+        // Hosted providers are never sent to the local server.
+        let hosted = manager.get_model_info("llama3.2:3b", &config).await;
+        assert!(hosted.supports_tools);
+
+        config.model_provider_id = OLLAMA_OSS_PROVIDER_ID.to_string();
+        let info = manager.get_model_info("llama3.2:3b", &config).await;
+        assert_eq!(info.context_window, Some(8_192));
+        assert_eq!(info.auto_compact_token_limit(), Some(7_372));
+        assert!(!info.supports_tools);
+        assert_eq!(info.input_modalities, vec![InputModality::Text]);
+
+        // A model switched to mid-session is looked up too; repeated lookups hit the cache.
+        let other = manager.get_model_info("qwen3:8b", &config).await;
+        assert!(other.supports_tools);
+        assert_eq!(other.context_window, None);
+        manager.get_model_info("llama3.2:3b", &config).await;
+        manager.get_model_info("qwen3:8b", &config).await;
+
+        assert_eq!(
+            *server
+                .fetches
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner),
+            vec![
+                ("ollama".to_string(), "llama3.2:3b".to_string()),
+                ("ollama".to_string(), "qwen3:8b".to_string()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn custom_provider_models_are_listed_with_overrides_and_cached() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/models"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "object": "list",
+                "data": [
+                    {"id": "qwen3-coder", "object": "model", "owned_by": "together"},
+                    {"id": "deepseek-r1", "object": "model", "owned_by": "together"},
+                ],
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let codex_home = tempdir().expect("temp dir");
+        let mut config = ConfigBuilder::default()
+            .codex_home(codex_home.path().to_path_buf())
+            .build()
+            .await
+            .expect("load default test config");
+        let mut provider = provider_for(server.uri());
+        provider.models = HashMap::from([
+            (
+                "deepseek-r1".to_string(),
+                ProviderModelOverrides {
+                    context_window: Some(128_000),
+                    supports_reasoning: Some(true),
+                    default_reasoning_effort: Some(ReasoningEffort::High),
+                },
+            ),
+            (
+                "llama-unlisted".to_string(),
+                ProviderModelOverrides {
+                    context_window: None,
+                    supports_reasoning: Some(false),
+                    default_reasoning_effort: None,
+                },
+            ),
+        ]);
+        provider.list_models = true;
+        config.model_provider_id = "together".to_string();
+        config.model_provider = provider.clone();
+        assert!(ModelsManager::fetches_provider_models(&config));
+
+        let auth_manager =
+            AuthManager::from_auth_for_testing(CodexAuth::from_api_key("Test API Key"));
+        let manager = ModelsManager::with_provider(
+            codex_home.path().to_path_buf(),
+            auth_manager.clone(),
+            provider.clone(),
+        );
+        let summarize = |presets: Vec<ModelPreset>| {
+            presets
+                .into_iter()
+                .map(|preset| {
+                    (
+                        preset.model,
+                        preset.is_default,
+                        preset.default_reasoning_effort,
+                        preset.supported_reasoning_efforts.len(),
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+        let expected = vec![
+            ("deepseek-r1".to_string(), true, ReasoningEffort::High, 3),
+            (
+                "llama-unlisted".to_string(),
+                false,
+                ReasoningEffort::None,
+                0,
+            ),
+            ("qwen3-coder".to_string(), false, ReasoningEffort::None, 3),
+        ];
+
+        let available = manager
+            .list_models(&config, RefreshStrategy::OnlineIfUncached)
+            .await;
+        assert_eq!(summarize(available), expected);
+
+        let info = manager.get_model_info("deepseek-r1", &config).await;
+        assert_eq!(info.context_window, Some(128_000));
+        assert!(info.supports_reasoning_summaries);
+
+        // A new manager serves the provider's models from the cache.
+        let cached_manager =
+            ModelsManager::with_provider(codex_home.path().to_path_buf(), auth_manager, provider);
+        let cached = cached_manager
+            .list_models(&config, RefreshStrategy::Offline)
+            .await;
+        assert_eq!(summarize(cached), expected);
+    }
+
+    #[tokio::test]
+    async fn provider_models_are_not_fetched_without_opt_in() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/models"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "object": "list",
+                "data": [{"id": "qwen3-coder", "object": "model", "owned_by": "together"}],
+            })))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        let codex_home = tempdir().expect("temp dir");
+        let mut config = ConfigBuilder::default()
+            .codex_home(codex_home.path().to_path_buf())
+            .build()
+            .await
+            .expect("load default test config");
+        let mut provider = provider_for(server.uri());
+        provider.models =
+            HashMap::from([("deepseek-r1".to_string(), ProviderModelOverrides::default())]);
+        config.model_provider_id = "together".to_string();
+        config.model_provider = provider.clone();
+        assert!(!ModelsManager::fetches_provider_models(&config));
+
+        let manager = ModelsManager::with_provider(
+            codex_home.path().to_path_buf(),
+            AuthManager::from_auth_for_testing(CodexAuth::from_api_key("Test API Key")),
+            provider,
+        );
+        let available = manager
+            .list_models(&config, RefreshStrategy::OnlineIfUncached)
+            .await;
+
+        assert_eq!(
+            available
+                .into_iter()
+                .map(|preset| preset.model)
+                .collect::<Vec<_>>(),
+            vec!["deepseek-r1".to_string()]
+        );
+    }
+
     #[test]
     fn bundled_models_json_roundtrips() {
         let file_contents = include_str!("../../models.json");
@@ -1,6 +1,7 @@
 use codex_protocol::config_types::Verbosity;
 use codex_protocol::openai_models::ApplyPatchToolType;
 use codex_protocol::openai_models::ConfigShellToolType;
+use codex_protocol::openai_models::InputModality;
 use codex_protocol::openai_models::ModelInfo;
 use codex_protocol::openai_models::ModelInstructionsVariables;
 use codex_protocol::openai_models::ModelMessages;
@@ -11,8 +12,11 @@ use codex_protocol::openai_models::TruncationMode;
 use codex_protocol::openai_models::TruncationPolicyConfig;
 use codex_protocol::openai_models::default_input_modalities;
 
+use async_trait::async_trait;
+
 use crate::config::Config;
 use crate::features::Feature;
+use crate::model_provider_info::ProviderModelOverrides;
 use crate::truncate::approx_bytes_for_tokens;
 use tracing::warn;
 
@@ -68,6 +72,7 @@ macro_rules! model_info {
             effective_context_window_percent: 95,
             experimental_supported_tools: Vec::new(),
             input_modalities: default_input_modalities(),
+            supports_tools: true,
         };
 
         $(
@@ -77,6 +82,82 @@ macro_rules! model_info {
     }};
 }
 
+/// Capabilities reported by a local model server (Ollama, LM Studio) for a
+/// single model. `None` means the server did not report the capability.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LocalModelMetadata {
+    pub slug: String,
+    pub context_window: Option<i64>,
+    pub supports_tools: Option<bool>,
+    pub supports_images: Option<bool>,
+}
+
+/// Queries the server behind a local provider (Ollama, LM Studio) for a
+/// model's [`LocalModelMetadata`]. Core cannot depend on those clients, so
+/// frontends install an implementation with
+/// [`crate::models_manager::manager::ModelsManager::set_local_model_metadata_fetcher`].
+#[async_trait]
+pub trait LocalModelMetadataFetcher: std::fmt::Debug + Send + Sync {
+    /// Returns `None` when the server cannot be reached or does not know `model`.
+    async fn fetch(
+        &self,
+        provider_id: &str,
+        model: &str,
+        config: &Config,
+    ) -> Option<LocalModelMetadata>;
+}
+
+pub(crate) fn with_local_metadata(
+    mut model: ModelInfo,
+    metadata: &LocalModelMetadata,
+) -> ModelInfo {
+    if let Some(context_window) = metadata.context_window {
+        model.context_window = Some(context_window);
+        // Re-derive the compaction threshold from the real context window.
+        model.auto_compact_token_limit = None;
+    }
+    if metadata.supports_images == Some(false) {
+        model.input_modalities = vec![InputModality::Text];
+    }
+    if metadata.supports_tools == Some(false) {
+        model.supports_tools = false;
+        model.supports_parallel_tool_calls = false;
+    }
+    model
+}
+
+/// Metadata for a model listed by a custom provider's `/models` endpoint,
+/// which only reports ids. Known slugs keep their built-in metadata.
+pub(crate) fn provider_model_info(slug: &str) -> ModelInfo {
+    let mut model = find_model_info_for_slug(slug);
+    model.visibility = ModelVisibility::List;
+    model
+}
+
+pub(crate) fn with_provider_model_overrides(
+    mut model: ModelInfo,
+    overrides: &ProviderModelOverrides,
+) -> ModelInfo {
+    if let Some(context_window) = overrides.context_window {
+        model.context_window = Some(context_window);
+        model.auto_compact_token_limit = None;
+    }
+    match overrides.supports_reasoning {
+        Some(true) => model.supports_reasoning_summaries = true,
+        Some(false) => {
+            model.supports_reasoning_summaries = false;
+            model.supported_reasoning_levels = Vec::new();
+            model.default_reasoning_level = None;
+            return model;
+        }
+        None => {}
+    }
+    if let Some(effort) = overrides.default_reasoning_effort {
+        model.default_reasoning_level = Some(effort);
+    }
+    model
+}
+
 pub(crate) fn with_config_overrides(mut model: ModelInfo, config: &Config) -> ModelInfo {
     if let Some(supports_reasoning_summaries) = config.model_supports_reasoning_summaries {
         model.supports_reasoning_summaries = supports_reasoning_summaries;
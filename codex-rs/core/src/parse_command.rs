@@ -536,11 +536,207 @@ mod tests {
     }
 
     #[test]
-    fn supports_npm_run_build_is_unknown() {
+    fn supports_npm_run_build() {
         assert_parsed(
             &vec_str(&["npm", "run", "build"]),
-            vec![ParsedCommand::Unknown {
+            vec![ParsedCommand::Build {
                 cmd: "npm run build".to_string(),
+                tool: "npm".to_string(),
+                targets: vec![],
+            }],
+        );
+    }
+
+    #[test]
+    fn npm_run_lint_is_unknown() {
+        assert_parsed(
+            &vec_str(&["npm", "run", "lint"]),
+            vec![ParsedCommand::Unknown {
+                cmd: "npm run lint".to_string(),
+            }],
+        );
+    }
+
+    #[test]
+    fn supports_test_runners() {
+        assert_parsed(
+            &shlex_split_safe("cargo test -p codex-core parse_command -- --nocapture"),
+            vec![ParsedCommand::Test {
+                cmd: "cargo test -p codex-core parse_command -- --nocapture".to_string(),
+                runner: "cargo".to_string(),
+                targets: vec_str(&["codex-core", "parse_command"]),
+            }],
+        );
+        assert_parsed(
+            &vec_str(&[
+                "bash",
+                "-lc",
+                "pytest -k smoke tests/test_api.py | tail -n 20",
+            ]),
+            vec![ParsedCommand::Test {
+                cmd: "pytest -k smoke tests/test_api.py".to_string(),
+                runner: "pytest".to_string(),
+                targets: vec_str(&["tests/test_api.py"]),
+            }],
+        );
+        assert_parsed(
+            &shlex_split_safe("python3 -m pytest tests"),
+            vec![ParsedCommand::Test {
+                cmd: "python3 -m pytest tests".to_string(),
+                runner: "pytest".to_string(),
+                targets: vec_str(&["tests"]),
+            }],
+        );
+        assert_parsed(
+            &shlex_split_safe("npx jest src/app.test.ts"),
+            vec![ParsedCommand::Test {
+                cmd: "npx jest src/app.test.ts".to_string(),
+                runner: "jest".to_string(),
+                targets: vec_str(&["src/app.test.ts"]),
+            }],
+        );
+        assert_parsed(
+            &shlex_split_safe("go test ./..."),
+            vec![ParsedCommand::Test {
+                cmd: "go test ./...".to_string(),
+                runner: "go".to_string(),
+                targets: vec_str(&["./..."]),
+            }],
+        );
+    }
+
+    #[test]
+    fn supports_builds() {
+        assert_parsed(
+            &shlex_split_safe("cargo build --release -p codex-cli"),
+            vec![ParsedCommand::Build {
+                cmd: "cargo build --release -p codex-cli".to_string(),
+                tool: "cargo".to_string(),
+                targets: vec_str(&["codex-cli"]),
+            }],
+        );
+        assert_parsed(
+            &shlex_split_safe("make -j 8"),
+            vec![ParsedCommand::Build {
+                cmd: "make -j 8".to_string(),
+                tool: "make".to_string(),
+                targets: vec![],
+            }],
+        );
+        assert_parsed(
+            &shlex_split_safe("make test"),
+            vec![ParsedCommand::Test {
+                cmd: "make test".to_string(),
+                runner: "make".to_string(),
+                targets: vec_str(&["test"]),
+            }],
+        );
+    }
+
+    #[test]
+    fn supports_package_installs() {
+        assert_parsed(
+            &shlex_split_safe("npm install --save-dev typescript"),
+            vec![ParsedCommand::Install {
+                cmd: "npm install --save-dev typescript".to_string(),
+                manager: "npm".to_string(),
+                packages: vec_str(&["typescript"]),
+            }],
+        );
+        assert_parsed(
+            &shlex_split_safe("pip install -r requirements.txt requests"),
+            vec![ParsedCommand::Install {
+                cmd: "pip install -r requirements.txt requests".to_string(),
+                manager: "pip".to_string(),
+                packages: vec_str(&["requests"]),
+            }],
+        );
+        assert_parsed(
+            &shlex_split_safe("cargo add serde --features derive"),
+            vec![ParsedCommand::Install {
+                cmd: "cargo add serde --features derive".to_string(),
+                manager: "cargo".to_string(),
+                packages: vec_str(&["serde"]),
+            }],
+        );
+    }
+
+    #[test]
+    fn supports_file_mutations_relative_to_cd() {
+        assert_parsed(
+            &vec_str(&["bash", "-lc", "cd web && rm -rf dist node_modules"]),
+            vec![ParsedCommand::Delete {
+                cmd: "rm -rf dist node_modules".to_string(),
+                paths: vec_str(&["web/dist", "web/node_modules"]),
+            }],
+        );
+        assert_parsed(
+            &shlex_split_safe("mv old.rs new.rs"),
+            vec![ParsedCommand::Move {
+                cmd: "mv old.rs new.rs".to_string(),
+                paths: vec_str(&["old.rs", "new.rs"]),
+            }],
+        );
+        assert_parsed(
+            &shlex_split_safe("cp -r template out/site"),
+            vec![ParsedCommand::Write {
+                cmd: "cp -r template out/site".to_string(),
+                paths: vec_str(&["out/site"]),
+            }],
+        );
+        assert_parsed(
+            &shlex_split_safe("mkdir -p src/bin"),
+            vec![ParsedCommand::Write {
+                cmd: "mkdir -p src/bin".to_string(),
+                paths: vec_str(&["src/bin"]),
+            }],
+        );
+    }
+
+    #[test]
+    fn supports_git_mutations() {
+        let commit = "git commit -m 'fix: typo' --no-verify";
+        assert_parsed(
+            &shlex_split_safe(commit),
+            vec![ParsedCommand::GitMutation {
+                cmd: shlex_join(&shlex_split_safe(commit)),
+                subcommand: "commit".to_string(),
+                targets: vec![],
+            }],
+        );
+        assert_parsed(
+            &shlex_split_safe("git -C repo push origin main"),
+            vec![ParsedCommand::GitMutation {
+                cmd: "git -C repo push origin main".to_string(),
+                subcommand: "push".to_string(),
+                targets: vec_str(&["origin", "main"]),
+            }],
+        );
+        assert_parsed(
+            &shlex_split_safe("git reset --hard origin/main"),
+            vec![ParsedCommand::GitMutation {
+                cmd: "git reset --hard origin/main".to_string(),
+                subcommand: "reset".to_string(),
+                targets: vec_str(&["origin/main"]),
+            }],
+        );
+    }
+
+    #[test]
+    fn supports_network_fetches() {
+        let curl = "curl -sSL -H 'Accept: application/json' https://example.com/api";
+        assert_parsed(
+            &shlex_split_safe(curl),
+            vec![ParsedCommand::Fetch {
+                cmd: shlex_join(&shlex_split_safe(curl)),
+                url: Some("https://example.com/api".to_string()),
+            }],
+        );
+        assert_parsed(
+            &shlex_split_safe("wget -O out.tar.gz example.com/out.tar.gz"),
+            vec![ParsedCommand::Fetch {
+                cmd: "wget -O out.tar.gz example.com/out.tar.gz".to_string(),
+                url: Some("example.com/out.tar.gz".to_string()),
             }],
         );
     }
@@ -1367,22 +1563,7 @@ pub fn parse_command_impl(command: &[String]) -> Vec<ParsedCommand> {
             }
             continue;
         }
-        let parsed = summarize_main_tokens(tokens);
-        let parsed = match parsed {
-            ParsedCommand::Read { cmd, name, path } => {
-                if let Some(base) = &cwd {
-                    let full = join_paths(base, &path.to_string_lossy());
-                    ParsedCommand::Read {
-                        cmd,
-                        name,
-                        path: PathBuf::from(full),
-                    }
-                } else {
-                    ParsedCommand::Read { cmd, name, path }
-                }
-            }
-            other => other,
-        };
+        let parsed = resolve_against_cwd(summarize_main_tokens(tokens), cwd.as_deref());
         commands.push(parsed);
     }
 
@@ -1908,22 +2089,7 @@ fn parse_shell_lc_commands(original: &[String]) -> Option<Vec<ParsedCommand>> {
                 }
                 continue;
             }
-            let parsed = summarize_main_tokens(&tokens);
-            let parsed = match parsed {
-                ParsedCommand::Read { cmd, name, path } => {
-                    if let Some(base) = &cwd {
-                        let full = join_paths(base, &path.to_string_lossy());
-                        ParsedCommand::Read {
-                            cmd,
-                            name,
-                            path: PathBuf::from(full),
-                        }
-                    } else {
-                        ParsedCommand::Read { cmd, name, path }
-                    }
-                }
-                other => other,
-            };
+            let parsed = resolve_against_cwd(summarize_main_tokens(&tokens), cwd.as_deref());
             commands.push(parsed);
         }
         if commands.len() > 1 {
@@ -2242,9 +2408,9 @@ fn summarize_main_tokens(main_cmd: &[String]) -> ParsedCommand {
                     path,
                 }
             }
-            _ => ParsedCommand::Unknown {
+            _ => summarize_git_mutation(main_cmd, tail).unwrap_or_else(|| ParsedCommand::Unknown {
                 cmd: shlex_join(main_cmd),
-            },
+            }),
         },
         Some((head, tail)) if head == "fd" => {
             let (query, path) = parse_fd_query_and_path(tail);
@@ -2543,15 +2709,520 @@ fn summarize_main_tokens(main_cmd: &[String]) -> ParsedCommand {
                     path: None,
                 }
             } else {
-                ParsedCommand::Unknown {
+                summarize_action(main_cmd).unwrap_or_else(|| ParsedCommand::Unknown {
                     cmd: shlex_join(main_cmd),
-                }
+                })
             }
         }
         // Other commands
-        _ => ParsedCommand::Unknown {
+        _ => summarize_action(main_cmd).unwrap_or_else(|| ParsedCommand::Unknown {
             cmd: shlex_join(main_cmd),
+        }),
+    }
+}
+
+/// Git subcommands that change the working tree, index, refs or remotes.
+pub(crate) const GIT_MUTATING_SUBCOMMANDS: &[&str] = &[
+    "add",
+    "am",
+    "apply",
+    "checkout",
+    "cherry-pick",
+    "clean",
+    "commit",
+    "merge",
+    "mv",
+    "pull",
+    "push",
+    "rebase",
+    "reset",
+    "restore",
+    "revert",
+    "rm",
+    "stash",
+    "switch",
+];
+
+/// Classifies `git <subcommand>` invocations that change repository state.
+/// Global options such as `-C <dir>` before the subcommand are skipped.
+fn summarize_git_mutation(main_cmd: &[String], args: &[String]) -> Option<ParsedCommand> {
+    let mut i = 0;
+    while let Some(arg) = args.get(i) {
+        match arg.as_str() {
+            "-C" | "-c" | "--git-dir" | "--work-tree" | "--namespace" => i += 2,
+            _ if arg.starts_with('-') => i += 1,
+            _ => break,
+        }
+    }
+    let (subcommand, rest) = args.get(i..)?.split_first()?;
+    if !GIT_MUTATING_SUBCOMMANDS.contains(&subcommand.as_str()) {
+        return None;
+    }
+    let targets = positional_operands(
+        rest,
+        &[
+            "-m",
+            "--message",
+            "-F",
+            "--file",
+            "--author",
+            "--date",
+            "-C",
+            "-c",
+            "-X",
+            "-s",
+            "--strategy",
+            "--strategy-option",
+            "--onto",
+            "--pathspec-from-file",
+        ],
+    )
+    .into_iter()
+    .cloned()
+    .collect();
+    Some(ParsedCommand::GitMutation {
+        cmd: shlex_join(main_cmd),
+        subcommand: subcommand.clone(),
+        targets,
+    })
+}
+
+/// Classifies commands that act on the project rather than inspect it: test
+/// runs, builds, package installs, file mutations and network fetches.
+fn summarize_action(main_cmd: &[String]) -> Option<ParsedCommand> {
+    let tokens = strip_exec_wrapper(main_cmd);
+    let (head, tail) = tokens.split_first()?;
+    let program = program_name(head);
+    let cmd = shlex_join(main_cmd);
+    match program {
+        "cargo" => summarize_cargo(cmd, tail),
+        "go" => match tail.split_first() {
+            Some((sub, rest)) if sub == "test" => Some(ParsedCommand::Test {
+                cmd,
+                runner: "go".to_string(),
+                targets: operands(rest, &["-run", "-bench", "-count", "-timeout", "-tags"]),
+            }),
+            Some((sub, rest)) if sub == "build" => Some(ParsedCommand::Build {
+                cmd,
+                tool: "go".to_string(),
+                targets: operands(rest, &["-o", "-tags", "-ldflags", "-gcflags"]),
+            }),
+            Some((sub, rest)) if sub == "get" || sub == "install" => Some(ParsedCommand::Install {
+                cmd,
+                manager: "go".to_string(),
+                packages: operands(rest, &[]),
+            }),
+            _ => None,
+        },
+        "pytest" | "py.test" => Some(ParsedCommand::Test {
+            cmd,
+            runner: "pytest".to_string(),
+            targets: pytest_targets(tail),
+        }),
+        "jest" | "vitest" | "mocha" => Some(ParsedCommand::Test {
+            cmd,
+            runner: program.to_string(),
+            targets: operands(tail, &["-t", "--testNamePattern", "-c", "--config"]),
+        }),
+        "npm" | "yarn" | "pnpm" | "bun" => summarize_node_package_manager(cmd, program, tail),
+        "pip" | "pip3" => match tail.split_first() {
+            Some((sub, rest)) if sub == "install" => Some(ParsedCommand::Install {
+                cmd,
+                manager: "pip".to_string(),
+                packages: pip_packages(rest),
+            }),
+            _ => None,
+        },
+        "uv" => match tail {
+            [sub, rest @ ..] if sub == "add" => Some(ParsedCommand::Install {
+                cmd,
+                manager: "uv".to_string(),
+                packages: operands(rest, &[]),
+            }),
+            [pip, sub, rest @ ..] if pip == "pip" && sub == "install" => {
+                Some(ParsedCommand::Install {
+                    cmd,
+                    manager: "uv".to_string(),
+                    packages: pip_packages(rest),
+                })
+            }
+            _ => None,
+        },
+        "poetry" => match tail.split_first() {
+            Some((sub, rest)) if sub == "add" || sub == "install" => Some(ParsedCommand::Install {
+                cmd,
+                manager: "poetry".to_string(),
+                packages: operands(rest, &[]),
+            }),
+            _ => None,
+        },
+        "gem" | "brew" | "apt" | "apt-get" => match tail.split_first() {
+            Some((sub, rest)) if sub == "install" => Some(ParsedCommand::Install {
+                cmd,
+                manager: program.to_string(),
+                packages: operands(rest, &[]),
+            }),
+            _ => None,
+        },
+        "bundle" => match tail.split_first() {
+            Some((sub, _)) if sub == "install" => Some(ParsedCommand::Install {
+                cmd,
+                manager: "bundle".to_string(),
+                packages: Vec::new(),
+            }),
+            _ => None,
+        },
+        _ if is_python_command(program) => match tail {
+            [flag, module, rest @ ..] if flag == "-m" && module == "pytest" => {
+                Some(ParsedCommand::Test {
+                    cmd,
+                    runner: "pytest".to_string(),
+                    targets: pytest_targets(rest),
+                })
+            }
+            [flag, module, rest @ ..] if flag == "-m" && module == "unittest" => {
+                Some(ParsedCommand::Test {
+                    cmd,
+                    runner: "unittest".to_string(),
+                    targets: operands(rest, &["-k"]),
+                })
+            }
+            [flag, module, sub, rest @ ..]
+                if flag == "-m" && module == "pip" && sub == "install" =>
+            {
+                Some(ParsedCommand::Install {
+                    cmd,
+                    manager: "pip".to_string(),
+                    packages: pip_packages(rest),
+                })
+            }
+            _ => None,
+        },
+        "make" | "gmake" => {
+            let targets = operands(tail, &["-C", "-f", "--file", "-j", "--jobs"]);
+            if targets
+                .iter()
+                .any(|target| target == "test" || target == "check")
+            {
+                Some(ParsedCommand::Test {
+                    cmd,
+                    runner: "make".to_string(),
+                    targets,
+                })
+            } else {
+                Some(ParsedCommand::Build {
+                    cmd,
+                    tool: "make".to_string(),
+                    targets,
+                })
+            }
+        }
+        "ninja" | "tsc" => Some(ParsedCommand::Build {
+            cmd,
+            tool: program.to_string(),
+            targets: operands(tail, &["-C", "-j", "-p", "--project"]),
+        }),
+        "cmake" if tail.first().is_some_and(|arg| arg == "--build") => Some(ParsedCommand::Build {
+            cmd,
+            tool: "cmake".to_string(),
+            targets: operands(&tail[1..], &["--target", "-t", "--config", "-j"]),
+        }),
+        "gradle" | "gradlew" | "mvn" | "mvnw" | "bazel" | "bazelisk" => {
+            let targets = operands(tail, &["-p", "-f", "--file", "-D"]);
+            let runs_tests = targets
+                .iter()
+                .any(|target| target == "test" || target == "check" || target == "verify");
+            if runs_tests {
+                Some(ParsedCommand::Test {
+                    cmd,
+                    runner: program.to_string(),
+                    targets,
+                })
+            } else if targets.is_empty() {
+                None
+            } else {
+                Some(ParsedCommand::Build {
+                    cmd,
+                    tool: program.to_string(),
+                    targets,
+                })
+            }
+        }
+        "touch" | "mkdir" => Some(ParsedCommand::Write {
+            cmd,
+            paths: operands(tail, &["-m", "--mode", "-d", "--date", "-r", "--reference"]),
+        }),
+        "cp" => {
+            let mut paths = operands(tail, &["-t", "--target-directory", "-S", "--suffix"]);
+            // Only the destination is written.
+            let destination = paths.pop()?;
+            Some(ParsedCommand::Write {
+                cmd,
+                paths: vec![destination],
+            })
+        }
+        "mv" => Some(ParsedCommand::Move {
+            cmd,
+            paths: operands(tail, &["-t", "--target-directory", "-S", "--suffix"]),
+        }),
+        "rm" | "rmdir" | "unlink" => Some(ParsedCommand::Delete {
+            cmd,
+            paths: operands(tail, &[]),
+        }),
+        "curl" | "wget" => Some(ParsedCommand::Fetch {
+            cmd,
+            url: fetch_url(tail),
+        }),
+        _ => None,
+    }
+}
+
+fn summarize_cargo(cmd: String, args: &[String]) -> Option<ParsedCommand> {
+    // Flags shared by most cargo subcommands that consume a value.
+    const FLAGS_WITH_VALUES: &[&str] = &[
+        "-p",
+        "--package",
+        "--exclude",
+        "-F",
+        "--features",
+        "-j",
+        "--jobs",
+        "--target",
+        "--target-dir",
+        "--manifest-path",
+        "--profile",
+        "--bin",
+        "--example",
+        "--test",
+        "--bench",
+        "--color",
+        "--message-format",
+    ];
+    let (sub, rest) = args.split_first()?;
+    // Selected packages come first, followed by any positional filters.
+    let mut targets: Vec<String> = rest
+        .windows(2)
+        .filter(|pair| pair[0] == "-p" || pair[0] == "--package")
+        .map(|pair| pair[1].clone())
+        .collect();
+    let rest = match rest.iter().position(|arg| arg == "--") {
+        Some(idx) => &rest[..idx],
+        None => rest,
+    };
+    match sub.as_str() {
+        "test" | "t" => {
+            targets.extend(operands(rest, FLAGS_WITH_VALUES));
+            Some(ParsedCommand::Test {
+                cmd,
+                runner: "cargo".to_string(),
+                targets,
+            })
+        }
+        "nextest" if rest.first().is_some_and(|arg| arg == "run") => {
+            targets.extend(operands(&rest[1..], FLAGS_WITH_VALUES));
+            Some(ParsedCommand::Test {
+                cmd,
+                runner: "cargo nextest".to_string(),
+                targets,
+            })
+        }
+        "build" | "b" | "check" | "c" => Some(ParsedCommand::Build {
+            cmd,
+            tool: "cargo".to_string(),
+            targets,
+        }),
+        "add" | "install" => Some(ParsedCommand::Install {
+            cmd,
+            manager: "cargo".to_string(),
+            packages: operands(rest, FLAGS_WITH_VALUES),
+        }),
+        _ => None,
+    }
+}
+
+/// `npm`, `yarn`, `pnpm` and `bun` share enough of a command line to be
+/// classified together.
+fn summarize_node_package_manager(
+    cmd: String,
+    program: &str,
+    args: &[String],
+) -> Option<ParsedCommand> {
+    let Some((sub, rest)) = args.split_first() else {
+        // A bare `yarn` installs the dependencies in package.json.
+        return (program == "yarn").then(|| ParsedCommand::Install {
+            cmd,
+            manager: program.to_string(),
+            packages: Vec::new(),
+        });
+    };
+    let (script, rest) = match sub.as_str() {
+        "run" | "run-script" => match rest.split_first() {
+            Some((script, rest)) => (script.as_str(), rest),
+            None => return None,
+        },
+        other => (other, rest),
+    };
+    match script {
+        "test" | "t" => Some(ParsedCommand::Test {
+            cmd,
+            runner: program.to_string(),
+            targets: operands(rest, &[]),
+        }),
+        "install" | "i" | "add" | "ci" => Some(ParsedCommand::Install {
+            cmd,
+            manager: program.to_string(),
+            packages: operands(rest, &["--registry"]),
+        }),
+        _ if script == "build" || script.starts_with("build:") => Some(ParsedCommand::Build {
+            cmd,
+            tool: program.to_string(),
+            targets: operands(rest, &[]),
+        }),
+        _ => None,
+    }
+}
+
+/// Drops launchers that run a tool on the caller's behalf, e.g. `npx jest` or
+/// `uv run pytest`, so the tool itself can be classified.
+fn strip_exec_wrapper(tokens: &[String]) -> &[String] {
+    match tokens {
+        [head, rest @ ..] if matches!(head.as_str(), "npx" | "bunx" | "pnpx") => rest,
+        [head, sub, rest @ ..]
+            if matches!(head.as_str(), "uv" | "poetry" | "pipenv") && sub == "run" =>
+        {
+            rest
+        }
+        [head, sub, rest @ ..]
+            if matches!(head.as_str(), "npm" | "pnpm" | "yarn") && sub == "exec" =>
+        {
+            rest
+        }
+        _ => tokens,
+    }
+}
+
+/// The basename of an executable, so `./gradlew` and `/usr/bin/curl` are
+/// recognized.
+fn program_name(head: &str) -> &str {
+    head.rsplit(['/', '\\']).next().unwrap_or(head)
+}
+
+fn operands(args: &[String], flags_with_vals: &[&str]) -> Vec<String> {
+    positional_operands(args, flags_with_vals)
+        .into_iter()
+        .cloned()
+        .collect()
+}
+
+fn pytest_targets(args: &[String]) -> Vec<String> {
+    operands(
+        args,
+        &[
+            "-k",
+            "-m",
+            "-n",
+            "-c",
+            "-p",
+            "--maxfail",
+            "--rootdir",
+            "--tb",
+            "--durations",
+            "--junitxml",
+        ],
+    )
+}
+
+fn pip_packages(args: &[String]) -> Vec<String> {
+    operands(
+        args,
+        &[
+            "-r",
+            "--requirement",
+            "-c",
+            "--constraint",
+            "-i",
+            "--index-url",
+            "--extra-index-url",
+            "-t",
+            "--target",
+        ],
+    )
+}
+
+fn fetch_url(args: &[String]) -> Option<String> {
+    if let Some(url) = args.iter().find(|arg| arg.contains("://")) {
+        return Some(url.clone());
+    }
+    first_non_flag_operand(
+        args,
+        &[
+            "-o",
+            "--output",
+            "-O",
+            "--output-document",
+            "-H",
+            "--header",
+            "-d",
+            "--data",
+            "-X",
+            "--request",
+            "-u",
+            "--user",
+            "-A",
+            "--user-agent",
+            "-e",
+            "--referer",
+            "-F",
+            "--form",
+            "-T",
+            "--upload-file",
+            "-w",
+            "--write-out",
+            "-m",
+            "--max-time",
+            "-x",
+            "--proxy",
+            "-b",
+            "--cookie",
+            "-c",
+            "--cookie-jar",
+            "-P",
+            "--directory-prefix",
+        ],
+    )
+}
+
+/// Resolves the paths of a parsed command against the directory entered by
+/// earlier `cd` segments of the same script.
+fn resolve_against_cwd(parsed: ParsedCommand, cwd: Option<&str>) -> ParsedCommand {
+    let Some(base) = cwd else {
+        return parsed;
+    };
+    let join_all = |paths: Vec<String>| -> Vec<String> {
+        paths
+            .into_iter()
+            .map(|path| join_paths(base, &path))
+            .collect()
+    };
+    match parsed {
+        ParsedCommand::Read { cmd, name, path } => ParsedCommand::Read {
+            cmd,
+            name,
+            path: PathBuf::from(join_paths(base, &path.to_string_lossy())),
+        },
+        ParsedCommand::Write { cmd, paths } => ParsedCommand::Write {
+            cmd,
+            paths: join_all(paths),
+        },
+        ParsedCommand::Move { cmd, paths } => ParsedCommand::Move {
+            cmd,
+            paths: join_all(paths),
+        },
+        ParsedCommand::Delete { cmd, paths } => ParsedCommand::Delete {
+            cmd,
+            paths: join_all(paths),
         },
+        other => other,
     }
 }
 
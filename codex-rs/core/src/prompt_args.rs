@@ -0,0 +1,435 @@
+//! Argument parsing and placeholder expansion for saved custom prompts.
+//!
+//! Prompts with named `$PLACEHOLDER` variables take `key=value` inputs; other
+//! prompts take positional values for `$1..$9` and `$ARGUMENTS`. `$$` escapes a
+//! placeholder. Both the TUI composer and the MCP server expand prompts here.
+
+use codex_protocol::user_input::ByteRange;
+use codex_protocol::user_input::TextElement;
+use regex_lite::Regex;
+use shlex::Shlex;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::LazyLock;
+
+static PROMPT_ARG_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\$[A-Z][A-Z0-9_]*").unwrap_or_else(|_| std::process::abort()));
+
+#[derive(Debug)]
+pub enum PromptArgsError {
+    MissingAssignment { token: String },
+    MissingKey { token: String },
+}
+
+impl PromptArgsError {
+    pub fn describe(&self, command: &str) -> String {
+        match self {
+            PromptArgsError::MissingAssignment { token } => format!(
+                "Could not parse {command}: expected key=value but found '{token}'. Wrap values in double quotes if they contain spaces."
+            ),
+            PromptArgsError::MissingKey { token } => {
+                format!("Could not parse {command}: expected a name before '=' in '{token}'.")
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PromptArg {
+    pub text: String,
+    pub text_elements: Vec<TextElement>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PromptExpansion {
+    pub text: String,
+    pub text_elements: Vec<TextElement>,
+}
+
+/// Parse positional arguments using shlex semantics (supports quoted tokens).
+///
+/// `text_elements` must be relative to `rest`.
+pub fn parse_positional_args(rest: &str, text_elements: &[TextElement]) -> Vec<PromptArg> {
+    parse_tokens_with_elements(rest, text_elements)
+}
+
+/// Extracts the unique placeholder variable names from a prompt template.
+///
+/// A placeholder is any token that matches the pattern `$[A-Z][A-Z0-9_]*`
+/// (for example `$USER`). The function returns the variable names without
+/// the leading `$`, de-duplicated and in the order of first appearance.
+pub fn prompt_argument_names(content: &str) -> Vec<String> {
+    let mut seen = HashSet::new();
+    let mut names = Vec::new();
+    for m in PROMPT_ARG_REGEX.find_iter(content) {
+        if m.start() > 0 && content.as_bytes()[m.start() - 1] == b'$' {
+            continue;
+        }
+        let name = &content[m.start() + 1..m.end()];
+        // Exclude special positional aggregate token from named args.
+        if name == "ARGUMENTS" {
+            continue;
+        }
+        let name = name.to_string();
+        if seen.insert(name.clone()) {
+            names.push(name);
+        }
+    }
+    names
+}
+
+/// Shift a text element's byte range left by `offset`, returning `None` if empty.
+///
+/// `offset` is the byte length of the prefix removed from the original text.
+pub fn shift_text_element_left(elem: &TextElement, offset: usize) -> Option<TextElement> {
+    if elem.byte_range.end <= offset {
+        return None;
+    }
+    let start = elem.byte_range.start.saturating_sub(offset);
+    let end = elem.byte_range.end.saturating_sub(offset);
+    (start < end).then_some(elem.map_range(|_| ByteRange { start, end }))
+}
+
+/// Parses the `key=value` pairs that follow a custom prompt name.
+///
+/// The input is split using shlex rules, so quoted values are supported
+/// (for example `USER="Alice Smith"`). The function returns a map of parsed
+/// arguments, or an error if a token is missing `=` or if the key is empty.
+pub fn parse_prompt_inputs(
+    rest: &str,
+    text_elements: &[TextElement],
+) -> Result<HashMap<String, PromptArg>, PromptArgsError> {
+    let mut map = HashMap::new();
+    if rest.trim().is_empty() {
+        return Ok(map);
+    }
+
+    // Tokenize the rest of the command using shlex rules, but keep text element
+    // ranges relative to each emitted token.
+    for token in parse_tokens_with_elements(rest, text_elements) {
+        let Some((key, value)) = token.text.split_once('=') else {
+            return Err(PromptArgsError::MissingAssignment { token: token.text });
+        };
+        if key.is_empty() {
+            return Err(PromptArgsError::MissingKey { token: token.text });
+        }
+        // The token is `key=value`; translate element ranges into the value-only
+        // coordinate space by subtracting the `key=` prefix length.
+        let value_start = key.len() + 1;
+        let value_elements = token
+            .text_elements
+            .iter()
+            .filter_map(|elem| shift_text_element_left(elem, value_start))
+            .collect();
+        map.insert(
+            key.to_string(),
+            PromptArg {
+                text: value.to_string(),
+                text_elements: value_elements,
+            },
+        );
+    }
+    Ok(map)
+}
+
+/// Detect whether `content` contains numeric placeholders ($1..$9) or `$ARGUMENTS`.
+pub fn prompt_has_numeric_placeholders(content: &str) -> bool {
+    if content.contains("$ARGUMENTS") {
+        return true;
+    }
+    let bytes = content.as_bytes();
+    let mut i = 0;
+    while i + 1 < bytes.len() {
+        if bytes[i] == b'$' {
+            let b1 = bytes[i + 1];
+            if (b'1'..=b'9').contains(&b1) {
+                return true;
+            }
+        }
+        i += 1;
+    }
+    false
+}
+
+/// Expands a prompt template with the values parsed from its invocation.
+///
+/// Templates with named placeholders read `named` and fail with the names of
+/// any missing values; other templates substitute `positional` into `$1..$9`
+/// and `$ARGUMENTS`.
+pub fn expand_prompt_template(
+    content: &str,
+    named: &HashMap<String, PromptArg>,
+    positional: &[PromptArg],
+) -> Result<PromptExpansion, Vec<String>> {
+    let required = prompt_argument_names(content);
+    if required.is_empty() {
+        return Ok(expand_numeric_placeholders(content, positional));
+    }
+    let missing: Vec<String> = required
+        .into_iter()
+        .filter(|k| !named.contains_key(k))
+        .collect();
+    if !missing.is_empty() {
+        return Err(missing);
+    }
+    let (text, text_elements) = expand_named_placeholders_with_elements(content, named);
+    Ok(PromptExpansion {
+        text,
+        text_elements,
+    })
+}
+
+/// Expand `$1..$9` and `$ARGUMENTS` in `content` with values from `args`.
+pub fn expand_numeric_placeholders(content: &str, args: &[PromptArg]) -> PromptExpansion {
+    let mut out = String::with_capacity(content.len());
+    let mut out_elements = Vec::new();
+    let mut i = 0;
+    while let Some(off) = content[i..].find('$') {
+        let j = i + off;
+        out.push_str(&content[i..j]);
+        let rest = &content[j..];
+        let bytes = rest.as_bytes();
+        if bytes.len() >= 2 {
+            match bytes[1] {
+                b'$' => {
+                    out.push_str("$$");
+                    i = j + 2;
+                    continue;
+                }
+                b'1'..=b'9' => {
+                    let idx = (bytes[1] - b'1') as usize;
+                    if let Some(arg) = args.get(idx) {
+                        append_arg_with_elements(&mut out, &mut out_elements, arg);
+                    }
+                    i = j + 2;
+                    continue;
+                }
+                _ => {}
+            }
+        }
+        if rest.len() > "ARGUMENTS".len() && rest[1..].starts_with("ARGUMENTS") {
+            if !args.is_empty() {
+                append_joined_args_with_elements(&mut out, &mut out_elements, args);
+            }
+            i = j + 1 + "ARGUMENTS".len();
+            continue;
+        }
+        out.push('$');
+        i = j + 1;
+    }
+    out.push_str(&content[i..]);
+    PromptExpansion {
+        text: out,
+        text_elements: out_elements,
+    }
+}
+
+fn parse_tokens_with_elements(rest: &str, text_elements: &[TextElement]) -> Vec<PromptArg> {
+    let mut elements = text_elements.to_vec();
+    elements.sort_by_key(|elem| elem.byte_range.start);
+    // Keep element placeholders intact across shlex splitting by replacing
+    // each element range with a unique sentinel token first.
+    let (rest_for_shlex, replacements) = replace_text_elements_with_sentinels(rest, &elements);
+    Shlex::new(&rest_for_shlex)
+        .map(|token| apply_replacements_to_token(token, &replacements))
+        .collect()
+}
+
+#[derive(Debug, Clone)]
+struct ElementReplacement {
+    sentinel: String,
+    text: String,
+    placeholder: Option<String>,
+}
+
+/// Replace each text element range with a unique sentinel token.
+///
+/// The sentinel is chosen so it will survive shlex tokenization as a single word.
+fn replace_text_elements_with_sentinels(
+    rest: &str,
+    elements: &[TextElement],
+) -> (String, Vec<ElementReplacement>) {
+    let mut out = String::with_capacity(rest.len());
+    let mut replacements = Vec::new();
+    let mut cursor = 0;
+
+    for (idx, elem) in elements.iter().enumerate() {
+        let start = elem.byte_range.start;
+        let end = elem.byte_range.end;
+        out.push_str(&rest[cursor..start]);
+        let mut sentinel = format!("__CODEX_ELEM_{idx}__");
+        // Ensure we never collide with user content so a sentinel can't be mistaken for text.
+        while rest.contains(&sentinel) {
+            sentinel.push('_');
+        }
+        out.push_str(&sentinel);
+        replacements.push(ElementReplacement {
+            sentinel,
+            text: rest[start..end].to_string(),
+            placeholder: elem.placeholder(rest).map(str::to_string),
+        });
+        cursor = end;
+    }
+
+    out.push_str(&rest[cursor..]);
+    (out, replacements)
+}
+
+/// Rehydrate a shlex token by swapping sentinels back to the original text
+/// and rebuilding text element ranges relative to the resulting token.
+fn apply_replacements_to_token(token: String, replacements: &[ElementReplacement]) -> PromptArg {
+    if replacements.is_empty() {
+        return PromptArg {
+            text: token,
+            text_elements: Vec::new(),
+        };
+    }
+
+    let mut out = String::with_capacity(token.len());
+    let mut out_elements = Vec::new();
+    let mut cursor = 0;
+
+    while cursor < token.len() {
+        let Some((offset, replacement)) = next_replacement(&token, cursor, replacements) else {
+            out.push_str(&token[cursor..]);
+            break;
+        };
+        let start_in_token = cursor + offset;
+        out.push_str(&token[cursor..start_in_token]);
+        let start = out.len();
+        out.push_str(&replacement.text);
+        let end = out.len();
+        if start < end {
+            out_elements.push(TextElement::new(
+                ByteRange { start, end },
+                replacement.placeholder.clone(),
+            ));
+        }
+        cursor = start_in_token + replacement.sentinel.len();
+    }
+
+    PromptArg {
+        text: out,
+        text_elements: out_elements,
+    }
+}
+
+/// Find the earliest sentinel occurrence at or after `cursor`.
+fn next_replacement<'a>(
+    token: &str,
+    cursor: usize,
+    replacements: &'a [ElementReplacement],
+) -> Option<(usize, &'a ElementReplacement)> {
+    let slice = &token[cursor..];
+    let mut best: Option<(usize, &'a ElementReplacement)> = None;
+    for replacement in replacements {
+        if let Some(pos) = slice.find(&replacement.sentinel) {
+            match best {
+                Some((best_pos, _)) if best_pos <= pos => {}
+                _ => best = Some((pos, replacement)),
+            }
+        }
+    }
+    best
+}
+
+fn expand_named_placeholders_with_elements(
+    content: &str,
+    args: &HashMap<String, PromptArg>,
+) -> (String, Vec<TextElement>) {
+    let mut out = String::with_capacity(content.len());
+    let mut out_elements = Vec::new();
+    let mut cursor = 0;
+    for m in PROMPT_ARG_REGEX.find_iter(content) {
+        let start = m.start();
+        let end = m.end();
+        if start > 0 && content.as_bytes()[start - 1] == b'$' {
+            out.push_str(&content[cursor..end]);
+            cursor = end;
+            continue;
+        }
+        out.push_str(&content[cursor..start]);
+        cursor = end;
+        let key = &content[start + 1..end];
+        if let Some(arg) = args.get(key) {
+            append_arg_with_elements(&mut out, &mut out_elements, arg);
+        } else {
+            out.push_str(&content[start..end]);
+        }
+    }
+    out.push_str(&content[cursor..]);
+    (out, out_elements)
+}
+
+fn append_arg_with_elements(
+    out: &mut String,
+    out_elements: &mut Vec<TextElement>,
+    arg: &PromptArg,
+) {
+    let start = out.len();
+    out.push_str(&arg.text);
+    if arg.text_elements.is_empty() {
+        return;
+    }
+    out_elements.extend(arg.text_elements.iter().map(|elem| {
+        elem.map_range(|range| ByteRange {
+            start: start + range.start,
+            end: start + range.end,
+        })
+    }));
+}
+
+fn append_joined_args_with_elements(
+    out: &mut String,
+    out_elements: &mut Vec<TextElement>,
+    args: &[PromptArg],
+) {
+    // `$ARGUMENTS` joins args with single spaces while preserving element ranges.
+    for (idx, arg) in args.iter().enumerate() {
+        if idx > 0 {
+            out.push(' ');
+        }
+        append_arg_with_elements(out, out_elements, arg);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn arg(text: &str) -> PromptArg {
+        PromptArg {
+            text: text.to_string(),
+            text_elements: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn named_templates_require_every_placeholder() {
+        let named = HashMap::from([("FILE".to_string(), arg("src/main.rs"))]);
+        assert_eq!(
+            expand_prompt_template("Review $FILE for $PRIORITY", &named, &[]),
+            Err(vec!["PRIORITY".to_string()])
+        );
+        assert_eq!(
+            expand_prompt_template("Review $FILE, not $$FILE or $1", &named, &[arg("x")])
+                .map(|expansion| expansion.text),
+            Ok("Review src/main.rs, not $$FILE or $1".to_string())
+        );
+    }
+
+    #[test]
+    fn positional_templates_use_shlex_tokens() {
+        let positional = parse_positional_args("high \"needs tests\"", &[]);
+        assert_eq!(
+            expand_prompt_template(
+                "Fix ($1; $2) with $$ and $ARGUMENTS",
+                &HashMap::new(),
+                &positional
+            )
+            .map(|expansion| expansion.text),
+            Ok("Fix (high; needs tests) with $$ and high needs tests".to_string())
+        );
+    }
+}
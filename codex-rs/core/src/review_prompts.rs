@@ -19,6 +19,10 @@ const COMMIT_PROMPT_WITH_TITLE: &str = "Review the code changes introduced by co
 const COMMIT_PROMPT: &str =
     "Review the code changes introduced by commit {sha}. Provide prioritized, actionable findings.";
 
+const COMMIT_RANGE_PROMPT: &str = "Review the code changes between commits {from} and {to}. Run `git log {from}..{to}` to see the commits involved and `git diff {from}..{to}` to inspect the combined changes. Provide prioritized, actionable findings.";
+
+const PATCH_FILE_PROMPT: &str = "Review the code changes described by the patch file at {path}. Read the patch to see the proposed changes, and inspect the surrounding code in the repository for context; the patch may not be applied to the working tree. Provide prioritized, actionable findings.";
+
 pub fn resolve_review_request(
     request: ReviewRequest,
     cwd: &Path,
@@ -57,6 +61,16 @@ pub fn review_prompt(target: &ReviewTarget, cwd: &Path) -> anyhow::Result<String
                 Ok(COMMIT_PROMPT.replace("{sha}", sha))
             }
         }
+        ReviewTarget::CommitRange { from, to } => Ok(COMMIT_RANGE_PROMPT
+            .replace("{from}", from)
+            .replace("{to}", to)),
+        ReviewTarget::PatchFile { path } => {
+            let path = cwd.join(path);
+            if !path.is_file() {
+                anyhow::bail!("Patch file {} does not exist", path.display());
+            }
+            Ok(PATCH_FILE_PROMPT.replace("{path}", &path.display().to_string()))
+        }
         ReviewTarget::Custom { instructions } => {
             let prompt = instructions.trim();
             if prompt.is_empty() {
@@ -79,6 +93,18 @@ pub fn user_facing_hint(target: &ReviewTarget) -> String {
                 format!("commit {short_sha}")
             }
         }
+        ReviewTarget::CommitRange { from, to } => {
+            let short_from: String = from.chars().take(7).collect();
+            let short_to: String = to.chars().take(7).collect();
+            format!("commits {short_from}..{short_to}")
+        }
+        ReviewTarget::PatchFile { path } => {
+            let name = path
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_else(|| path.display().to_string());
+            format!("patch {name}")
+        }
         ReviewTarget::Custom { instructions } => instructions.trim().to_string(),
     }
 }
@@ -91,3 +117,49 @@ impl From<ResolvedReviewRequest> for ReviewRequest {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::path::PathBuf;
+
+    #[test]
+    fn commit_range_prompt_includes_both_ends() {
+        let target = ReviewTarget::CommitRange {
+            from: "1111111aaaa".to_string(),
+            to: "2222222bbbb".to_string(),
+        };
+
+        let prompt = review_prompt(&target, Path::new("/")).expect("commit range prompt");
+
+        assert!(prompt.contains("git diff 1111111aaaa..2222222bbbb"));
+        assert_eq!(user_facing_hint(&target), "commits 1111111..2222222");
+    }
+
+    #[test]
+    fn patch_file_prompt_resolves_relative_path() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        std::fs::write(dir.path().join("change.patch"), "diff --git a/x b/x\n")
+            .expect("write patch");
+        let target = ReviewTarget::PatchFile {
+            path: PathBuf::from("change.patch"),
+        };
+
+        let prompt = review_prompt(&target, dir.path()).expect("patch file prompt");
+
+        let expected_path = dir.path().join("change.patch");
+        assert!(prompt.contains(&expected_path.display().to_string()));
+        assert_eq!(user_facing_hint(&target), "patch change.patch");
+    }
+
+    #[test]
+    fn patch_file_prompt_rejects_missing_file() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let target = ReviewTarget::PatchFile {
+            path: PathBuf::from("missing.patch"),
+        };
+
+        assert!(review_prompt(&target, dir.path()).is_err());
+    }
+}
@@ -1044,7 +1044,9 @@ async fn read_head_summary(path: &Path, head_limit: usize) -> io::Result<HeadTai
             RolloutItem::TurnContext(_) => {
                 // Not included in `head`; skip.
             }
-            RolloutItem::Compacted(_) => {
+            RolloutItem::Compacted(_)
+            | RolloutItem::BackgroundProcess(_)
+            | RolloutItem::TurnCompleted(_) => {
                 // Not included in `head`; skip.
             }
             RolloutItem::EventMsg(ev) => {
@@ -1100,7 +1102,9 @@ pub async fn read_head_for_summary(path: &Path) -> io::Result<Vec<serde_json::Va
                 }
                 RolloutItem::Compacted(_)
                 | RolloutItem::TurnContext(_)
-                | RolloutItem::EventMsg(_) => {}
+                | RolloutItem::EventMsg(_)
+                | RolloutItem::BackgroundProcess(_)
+                | RolloutItem::TurnCompleted(_) => {}
             }
         }
     }
@@ -64,7 +64,9 @@ pub(crate) fn builder_from_items(
         RolloutItem::ResponseItem(_)
         | RolloutItem::Compacted(_)
         | RolloutItem::TurnContext(_)
-        | RolloutItem::EventMsg(_) => None,
+        | RolloutItem::EventMsg(_)
+        | RolloutItem::BackgroundProcess(_)
+        | RolloutItem::TurnCompleted(_) => None,
     }) && let Some(builder) = builder_from_session_meta(session_meta, rollout_path)
     {
         return Some(builder);
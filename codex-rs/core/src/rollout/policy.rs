@@ -9,9 +9,11 @@ pub(crate) fn is_persisted_response_item(item: &RolloutItem) -> bool {
         RolloutItem::ResponseItem(item) => should_persist_response_item(item),
         RolloutItem::EventMsg(ev) => should_persist_event_msg(ev),
         // Persist Codex executive markers so we can analyze flows (e.g., compaction, API turns).
-        RolloutItem::Compacted(_) | RolloutItem::TurnContext(_) | RolloutItem::SessionMeta(_) => {
-            true
-        }
+        RolloutItem::Compacted(_)
+        | RolloutItem::TurnContext(_)
+        | RolloutItem::SessionMeta(_)
+        | RolloutItem::BackgroundProcess(_)
+        | RolloutItem::TurnCompleted(_) => true,
     }
 }
 
@@ -88,7 +90,12 @@ pub(crate) fn should_persist_event_msg(ev: &EventMsg) -> bool {
         | EventMsg::McpListToolsResponse(_)
         | EventMsg::McpStartupUpdate(_)
         | EventMsg::McpStartupComplete(_)
+        | EventMsg::McpListChanged(_)
         | EventMsg::ListCustomPromptsResponse(_)
+        | EventMsg::ListUndoSnapshotsResponse(_)
+        | EventMsg::ListBackgroundProcessesResponse(_)
+        | EventMsg::BackgroundProcessOutput(_)
+        | EventMsg::RestorableBackgroundProcesses(_)
         | EventMsg::ListSkillsResponse(_)
         | EventMsg::ListRemoteSkillsResponse(_)
         | EventMsg::RemoteSkillDownloaded(_)
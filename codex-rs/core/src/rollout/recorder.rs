@@ -457,6 +457,12 @@ impl RolloutRecorder {
                     RolloutItem::EventMsg(_ev) => {
                         items.push(RolloutItem::EventMsg(_ev));
                     }
+                    RolloutItem::BackgroundProcess(item) => {
+                        items.push(RolloutItem::BackgroundProcess(item));
+                    }
+                    RolloutItem::TurnCompleted(item) => {
+                        items.push(RolloutItem::TurnCompleted(item));
+                    }
                 },
                 Err(e) => {
                     trace!("failed to parse rollout line: {e}");
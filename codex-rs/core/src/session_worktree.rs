@@ -0,0 +1,193 @@
+//! Runs a session in a dedicated `git worktree` so that several agents can
+//! work on the same repository without touching the user's checkout.
+
+use std::path::Path;
+use std::path::PathBuf;
+
+use codex_git::GitToolingError;
+use codex_git::GitWorktree;
+use uuid::Uuid;
+
+use crate::config::Config;
+use crate::config::types::WorktreeExitAction;
+
+const SESSION_COMMIT_MESSAGE: &str = "Apply Codex session changes";
+
+/// A worktree created for a single session.
+#[derive(Debug, Clone)]
+pub struct SessionWorktree {
+    worktree: GitWorktree,
+    cwd: PathBuf,
+}
+
+/// What happened to a session worktree when the session ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorktreeExitOutcome {
+    Merged,
+    CherryPicked,
+    Discarded,
+    Kept,
+    /// The session made no changes, so the worktree was removed.
+    Unchanged,
+}
+
+impl SessionWorktree {
+    /// Creates a worktree for the repository containing `config.cwd` on a new
+    /// `codex/<name>` branch under `config.worktree.directory`.
+    pub fn create(config: &Config) -> Result<Self, GitToolingError> {
+        let repo_root = crate::git_info::get_git_repo_root(&config.cwd).ok_or_else(|| {
+            GitToolingError::NotAGitRepository {
+                path: config.cwd.clone(),
+            }
+        })?;
+        let repo_name = repo_root
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "repo".to_string());
+        let name = format!(
+            "{}-{}",
+            chrono::Local::now().format("%Y%m%d-%H%M%S"),
+            &Uuid::new_v4().simple().to_string()[..8]
+        );
+        let path = config.worktree.directory.join(repo_name).join(&name);
+        let worktree = codex_git::create_worktree(&config.cwd, &path, &format!("codex/{name}"))?;
+
+        // Keep the session in the same subdirectory it was started from.
+        let cwd = match relative_to(&config.cwd, worktree.repo_root()) {
+            Some(subdir) => path.join(subdir),
+            None => path,
+        };
+        Ok(Self { worktree, cwd })
+    }
+
+    /// Working directory for the session inside the worktree.
+    pub fn cwd(&self) -> &Path {
+        &self.cwd
+    }
+
+    pub fn path(&self) -> &Path {
+        self.worktree.path()
+    }
+
+    pub fn branch(&self) -> &str {
+        self.worktree.branch()
+    }
+
+    pub fn repo_root(&self) -> &Path {
+        self.worktree.repo_root()
+    }
+
+    pub fn has_changes(&self) -> Result<bool, GitToolingError> {
+        codex_git::worktree_has_changes(&self.worktree)
+    }
+
+    /// Applies `action` to the worktree. Worktrees without changes are always
+    /// removed. `Ask` must be resolved by the caller; it keeps the worktree.
+    pub fn finish(
+        &self,
+        action: WorktreeExitAction,
+    ) -> Result<WorktreeExitOutcome, GitToolingError> {
+        if !self.has_changes()? {
+            codex_git::remove_worktree(&self.worktree)?;
+            return Ok(WorktreeExitOutcome::Unchanged);
+        }
+        match action {
+            WorktreeExitAction::Merge => {
+                codex_git::merge_worktree(&self.worktree, SESSION_COMMIT_MESSAGE)?;
+                Ok(WorktreeExitOutcome::Merged)
+            }
+            WorktreeExitAction::CherryPick => {
+                codex_git::cherry_pick_worktree(&self.worktree, SESSION_COMMIT_MESSAGE)?;
+                Ok(WorktreeExitOutcome::CherryPicked)
+            }
+            WorktreeExitAction::Discard => {
+                codex_git::remove_worktree(&self.worktree)?;
+                Ok(WorktreeExitOutcome::Discarded)
+            }
+            WorktreeExitAction::Ask | WorktreeExitAction::Keep => Ok(WorktreeExitOutcome::Kept),
+        }
+    }
+
+    /// One-line summary of `outcome`, printed once the session has ended.
+    pub fn outcome_message(&self, outcome: WorktreeExitOutcome) -> String {
+        let branch = self.branch();
+        let path = self.path().display();
+        let repo_root = self.repo_root().display();
+        match outcome {
+            WorktreeExitOutcome::Merged => format!("Merged {branch} into {repo_root}."),
+            WorktreeExitOutcome::CherryPicked => {
+                format!("Cherry-picked the commits from {branch} into {repo_root}.")
+            }
+            WorktreeExitOutcome::Discarded => format!("Discarded worktree {path}."),
+            WorktreeExitOutcome::Kept => format!("Kept worktree {path} on branch {branch}."),
+            WorktreeExitOutcome::Unchanged => {
+                format!("No changes were made; removed worktree {path}.")
+            }
+        }
+    }
+}
+
+fn relative_to(path: &Path, root: &Path) -> Option<PathBuf> {
+    let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    let root = root.canonicalize().unwrap_or_else(|_| root.to_path_buf());
+    path.strip_prefix(&root)
+        .ok()
+        .filter(|subdir| !subdir.as_os_str().is_empty())
+        .map(Path::to_path_buf)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::test_config;
+    use pretty_assertions::assert_eq;
+    use std::process::Command;
+    use tempfile::TempDir;
+
+    fn git(repo: &Path, args: &[&str]) {
+        let status = Command::new("git")
+            .current_dir(repo)
+            .args([
+                "-c",
+                "user.name=Tester",
+                "-c",
+                "user.email=test@example.com",
+            ])
+            .args(args)
+            .status()
+            .expect("git command");
+        assert!(status.success(), "git command failed: {args:?}");
+    }
+
+    #[test]
+    fn session_worktree_starts_in_same_subdirectory() -> anyhow::Result<()> {
+        let codex_home = TempDir::new()?;
+        let repo = TempDir::new()?;
+        git(repo.path(), &["init", "--initial-branch=main"]);
+        std::fs::create_dir_all(repo.path().join("crates/app"))?;
+        std::fs::write(repo.path().join("crates/app/lib.rs"), "fn main() {}\n")?;
+        git(repo.path(), &["add", "."]);
+        git(repo.path(), &["commit", "-m", "init"]);
+
+        let mut config = test_config();
+        config.cwd = repo.path().join("crates/app");
+        config.worktree.directory = codex_home.path().join("worktrees");
+        let worktree = SessionWorktree::create(&config)?;
+
+        assert!(worktree.branch().starts_with("codex/"));
+        assert!(
+            worktree
+                .path()
+                .starts_with(codex_home.path().join("worktrees"))
+        );
+        assert_eq!(worktree.cwd(), worktree.path().join("crates/app"));
+        assert!(worktree.cwd().join("lib.rs").is_file());
+
+        assert_eq!(
+            worktree.finish(WorktreeExitAction::Keep)?,
+            WorktreeExitOutcome::Unchanged
+        );
+        assert!(!worktree.path().exists());
+        Ok(())
+    }
+}
@@ -5,6 +5,7 @@ use crate::RolloutRecorder;
 use crate::agent::AgentControl;
 use crate::analytics_client::AnalyticsEventsClient;
 use crate::client::ModelClient;
+use crate::command_secrets::CommandSecrets;
 use crate::exec_policy::ExecPolicyManager;
 use crate::file_watcher::FileWatcher;
 use crate::hooks::Hooks;
@@ -14,6 +15,7 @@ use crate::skills::SkillsManager;
 use crate::state_db::StateDbHandle;
 use crate::tools::sandboxing::ApprovalStore;
 use crate::unified_exec::UnifiedExecProcessManager;
+use crate::web_fetch::WebFetchCache;
 use codex_otel::OtelManager;
 use tokio::sync::Mutex;
 use tokio::sync::RwLock;
@@ -37,6 +39,10 @@ pub(crate) struct SessionServices {
     pub(crate) file_watcher: Arc<FileWatcher>,
     pub(crate) agent_control: AgentControl,
     pub(crate) state_db: Option<StateDbHandle>,
+    /// Pages fetched by `web_fetch`, reused across turns of the session.
+    pub(crate) web_fetch_cache: WebFetchCache,
+    /// Secrets from `[secrets.expose]`, injected into commands and redacted from their output.
+    pub(crate) command_secrets: CommandSecrets,
     /// Session-scoped model client shared across turns.
     pub(crate) model_client: ModelClient,
 }
@@ -1,11 +1,14 @@
 //! Session-wide mutable state.
 
+use codex_git::GhostCommit;
 use codex_protocol::models::ResponseItem;
 use std::collections::HashMap;
 use std::collections::HashSet;
+use std::time::SystemTime;
 
 use crate::codex::SessionConfiguration;
 use crate::context_manager::ContextManager;
+use crate::protocol::BackgroundProcessLaunch;
 use crate::protocol::RateLimitSnapshot;
 use crate::protocol::TokenUsage;
 use crate::protocol::TokenUsageInfo;
@@ -26,6 +29,22 @@ pub(crate) struct SessionState {
     pub(crate) initial_context_seeded: bool,
     /// Previous rollout model for one-shot model-switch handling on first turn after resume.
     pub(crate) pending_resume_previous_model: Option<String>,
+    /// Undos that can still be reversed, most recent last.
+    redo_stack: Vec<RedoEntry>,
+    /// When the most recent task finished; files modified later were changed
+    /// outside Codex.
+    last_turn_completed_at: Option<SystemTime>,
+    /// Background processes from the resumed rollout that the user has not
+    /// yet decided whether to relaunch, oldest first.
+    restorable_background_processes: Vec<BackgroundProcessLaunch>,
+}
+
+/// Working tree state captured right before an undo so it can be redone.
+pub(crate) struct RedoEntry {
+    pub(crate) ghost_commit: GhostCommit,
+    /// Ghost snapshot items the undo removed from history, with their
+    /// original positions in ascending order.
+    pub(crate) removed_snapshots: Vec<(usize, ResponseItem)>,
 }
 
 impl SessionState {
@@ -41,6 +60,9 @@ impl SessionState {
             mcp_dependency_prompted: HashSet::new(),
             initial_context_seeded: false,
             pending_resume_previous_model: None,
+            redo_stack: Vec::new(),
+            last_turn_completed_at: None,
+            restorable_background_processes: Vec::new(),
         }
     }
 
@@ -128,6 +150,43 @@ impl SessionState {
     pub(crate) fn dependency_env(&self) -> HashMap<String, String> {
         self.dependency_env.clone()
     }
+
+    // Undo/redo helpers
+    pub(crate) fn push_redo_entry(&mut self, entry: RedoEntry) {
+        self.redo_stack.push(entry);
+    }
+
+    pub(crate) fn pop_redo_entry(&mut self) -> Option<RedoEntry> {
+        self.redo_stack.pop()
+    }
+
+    pub(crate) fn clear_redo_stack(&mut self) {
+        self.redo_stack.clear();
+    }
+
+    pub(crate) fn redo_available(&self) -> bool {
+        !self.redo_stack.is_empty()
+    }
+
+    pub(crate) fn set_last_turn_completed_at(&mut self, completed_at: SystemTime) {
+        self.last_turn_completed_at = Some(completed_at);
+    }
+
+    pub(crate) fn last_turn_completed_at(&self) -> Option<SystemTime> {
+        self.last_turn_completed_at
+    }
+
+    // Background process restore helpers
+    pub(crate) fn set_restorable_background_processes(
+        &mut self,
+        launches: Vec<BackgroundProcessLaunch>,
+    ) {
+        self.restorable_background_processes = launches;
+    }
+
+    pub(crate) fn take_restorable_background_processes(&mut self) -> Vec<BackgroundProcessLaunch> {
+        std::mem::take(&mut self.restorable_background_processes)
+    }
 }
 
 // Sometimes new snapshots don't include credits or plan information.
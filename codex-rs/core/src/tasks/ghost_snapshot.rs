@@ -110,6 +110,9 @@ impl SessionTask for GhostSnapshotTask {
                                     ghost_commit: ghost_commit.clone(),
                                 }])
                                 .await;
+                            // A new turn is changing the tree, so earlier undos can
+                            // no longer be redone on top of it.
+                            session.session.clear_redo_stack().await;
                             info!("ghost commit captured: {}", ghost_commit.id());
                         }
                         Ok(Err(err)) => match err {
@@ -40,7 +40,9 @@ pub(crate) use compact::CompactTask;
 pub(crate) use ghost_snapshot::GhostSnapshotTask;
 pub(crate) use regular::RegularTask;
 pub(crate) use review::ReviewTask;
+pub(crate) use undo::UndoTarget;
 pub(crate) use undo::UndoTask;
+pub(crate) use undo::list_undo_snapshots;
 pub(crate) use user_shell::UserShellCommandMode;
 pub(crate) use user_shell::UserShellCommandTask;
 pub(crate) use user_shell::execute_user_shell_command;
@@ -203,6 +205,7 @@ impl Session {
             *active = None;
         }
         drop(active);
+        self.mark_turn_completed().await;
         if !pending_input.is_empty() {
             let pending_response_items = pending_input
                 .into_iter()
@@ -1,15 +1,31 @@
+use std::path::Path;
+use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::SystemTime;
 
+use crate::codex::Session;
 use crate::codex::TurnContext;
+use crate::config::GhostSnapshotConfig;
+use crate::context_manager::is_user_turn_boundary;
+use crate::event_mapping::parse_turn_item;
 use crate::protocol::EventMsg;
+use crate::protocol::ListUndoSnapshotsResponseEvent;
 use crate::protocol::UndoCompletedEvent;
+use crate::protocol::UndoSnapshot;
 use crate::protocol::UndoStartedEvent;
+use crate::state::RedoEntry;
 use crate::state::TaskKind;
 use crate::tasks::SessionTask;
 use crate::tasks::SessionTaskContext;
 use async_trait::async_trait;
+use codex_git::CreateGhostCommitOptions;
+use codex_git::GhostCommit;
+use codex_git::GitToolingError;
 use codex_git::RestoreGhostCommitOptions;
+use codex_git::create_ghost_commit;
+use codex_git::diff_ghost_commits;
 use codex_git::restore_ghost_commit_with_options;
+use codex_protocol::items::TurnItem;
 use codex_protocol::models::ResponseItem;
 use codex_protocol::user_input::UserInput;
 use tokio_util::sync::CancellationToken;
@@ -17,11 +33,27 @@ use tracing::error;
 use tracing::info;
 use tracing::warn;
 
-pub(crate) struct UndoTask;
+/// Each listed snapshot costs a diff against the working tree, so only the most
+/// recent ones are offered.
+const MAX_LISTED_SNAPSHOTS: usize = 20;
+
+/// What an [`UndoTask`] restores the working tree to.
+pub(crate) enum UndoTarget {
+    /// The most recent ghost snapshot.
+    Latest,
+    /// A specific ghost snapshot; every snapshot taken after it is consumed too.
+    Snapshot { id: String, force: bool },
+    /// The working tree as it was right before the most recent undo.
+    Redo,
+}
+
+pub(crate) struct UndoTask {
+    target: UndoTarget,
+}
 
 impl UndoTask {
-    pub(crate) fn new() -> Self {
-        Self
+    pub(crate) fn new(target: UndoTarget) -> Self {
+        Self { target }
     }
 }
 
@@ -38,16 +70,25 @@ impl SessionTask for UndoTask {
         _input: Vec<UserInput>,
         cancellation_token: CancellationToken,
     ) -> Option<String> {
-        let _ = session
-            .session
-            .services
-            .otel_manager
-            .counter("codex.task.undo", 1, &[]);
+        let is_redo = matches!(self.target, UndoTarget::Redo);
+        let _ = session.session.services.otel_manager.counter(
+            if is_redo {
+                "codex.task.redo"
+            } else {
+                "codex.task.undo"
+            },
+            1,
+            &[],
+        );
         let sess = session.clone_session();
         sess.send_event(
             ctx.as_ref(),
             EventMsg::UndoStarted(UndoStartedEvent {
-                message: Some("Undo in progress...".to_string()),
+                message: Some(if is_redo {
+                    "Redo in progress...".to_string()
+                } else {
+                    "Undo in progress...".to_string()
+                }),
             }),
         )
         .await;
@@ -57,70 +98,346 @@ impl SessionTask for UndoTask {
                 ctx.as_ref(),
                 EventMsg::UndoCompleted(UndoCompletedEvent {
                     success: false,
-                    message: Some("Undo cancelled.".to_string()),
+                    message: Some(if is_redo {
+                        "Redo cancelled.".to_string()
+                    } else {
+                        "Undo cancelled.".to_string()
+                    }),
                 }),
             )
             .await;
             return None;
         }
 
-        let history = sess.clone_history().await;
-        let mut items = history.raw_items().to_vec();
-        let mut completed = UndoCompletedEvent {
-            success: false,
-            message: None,
+        let completed = match &self.target {
+            UndoTarget::Latest => undo(&sess, &ctx, None, true).await,
+            UndoTarget::Snapshot { id, force } => undo(&sess, &ctx, Some(id), *force).await,
+            UndoTarget::Redo => redo(&sess, &ctx).await,
         };
+        sess.send_event(ctx.as_ref(), EventMsg::UndoCompleted(completed))
+            .await;
+        None
+    }
+}
+
+/// Restores the snapshot `snapshot_id` (or the latest one) and drops it, along
+/// with every later snapshot, from history.
+async fn undo(
+    sess: &Arc<Session>,
+    ctx: &TurnContext,
+    snapshot_id: Option<&str>,
+    force: bool,
+) -> UndoCompletedEvent {
+    let history = sess.clone_history().await;
+    let mut items = history.raw_items().to_vec();
+    let mut completed = UndoCompletedEvent {
+        success: false,
+        message: None,
+    };
+
+    let snapshots = ghost_snapshots(&items);
+    let target = match snapshot_id {
+        Some(id) => snapshots.iter().find(|(_, commit)| commit.id() == id),
+        None => snapshots.last(),
+    };
+    let Some((idx, ghost_commit)) = target.cloned() else {
+        completed.message = Some(match snapshot_id {
+            Some(id) => format!("Snapshot {} is no longer available to undo.", short_id(id)),
+            None => "No ghost snapshot available to undo.".to_string(),
+        });
+        return completed;
+    };
+
+    let repo_path = ctx.cwd.clone();
+    let ghost_snapshot = ctx.ghost_snapshot.clone();
+    let last_turn_completed_at = sess.last_turn_completed_at().await;
+    let target_commit = ghost_commit.clone();
+    let prepared = tokio::task::spawn_blocking(move || {
+        let current = snapshot_working_tree(&repo_path, ghost_snapshot)?;
+        let conflicts = if force {
+            Vec::new()
+        } else {
+            let diff = diff_ghost_commits(&repo_path, &target_commit, &current)?;
+            modified_since(&repo_path, &diff.paths, last_turn_completed_at)
+        };
+        Ok::<_, GitToolingError>((current, conflicts))
+    })
+    .await;
 
-        let Some((idx, ghost_commit)) =
-            items
+    let current = match prepared {
+        Ok(Ok((_, conflicts))) if !conflicts.is_empty() => {
+            let files = conflicts
                 .iter()
-                .enumerate()
-                .rev()
-                .find_map(|(idx, item)| match item {
-                    ResponseItem::GhostSnapshot { ghost_commit } => {
-                        Some((idx, ghost_commit.clone()))
-                    }
-                    _ => None,
-                })
-        else {
-            completed.message = Some("No ghost snapshot available to undo.".to_string());
-            sess.send_event(ctx.as_ref(), EventMsg::UndoCompleted(completed))
-                .await;
-            return None;
+                .map(|path| path.display().to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            completed.message = Some(format!(
+                "Undo skipped: {files} changed outside Codex since the last turn. Undo again with force to overwrite them."
+            ));
+            return completed;
+        }
+        Ok(Ok((current, _))) => current,
+        // Without this snapshot the undo could not be redone, so leave the tree alone.
+        Ok(Err(err)) => {
+            warn!("failed to snapshot working tree before undo: {err}");
+            completed.message = Some(format!(
+                "Undo skipped: failed to snapshot the working tree so the undo could be redone: {err}"
+            ));
+            return completed;
+        }
+        Err(err) => {
+            error!("failed to snapshot working tree before undo: {err}");
+            completed.message = Some(format!(
+                "Undo skipped: failed to snapshot the working tree so the undo could be redone: {err}"
+            ));
+            return completed;
+        }
+    };
+
+    let commit_id = ghost_commit.id().to_string();
+    match restore(ctx, ghost_commit).await {
+        Ok(()) => {
+            let mut removed_snapshots = Vec::new();
+            let mut position = 0;
+            items.retain(|item| {
+                let remove = position >= idx && matches!(item, ResponseItem::GhostSnapshot { .. });
+                if remove {
+                    removed_snapshots.push((position, item.clone()));
+                }
+                position += 1;
+                !remove
+            });
+            sess.replace_history(items).await;
+            sess.push_redo_entry(RedoEntry {
+                ghost_commit: current,
+                removed_snapshots,
+            })
+            .await;
+            info!(commit_id = commit_id, "Undo restored ghost snapshot");
+            completed.success = true;
+            completed.message = Some(format!("Undo restored snapshot {}.", short_id(&commit_id)));
+        }
+        Err(message) => completed.message = Some(message),
+    }
+    completed
+}
+
+/// Reverses the most recent undo, restoring the tree and the ghost snapshots
+/// it consumed.
+async fn redo(sess: &Arc<Session>, ctx: &TurnContext) -> UndoCompletedEvent {
+    let mut completed = UndoCompletedEvent {
+        success: false,
+        message: None,
+    };
+    let Some(entry) = sess.pop_redo_entry().await else {
+        completed.message = Some("No undo available to redo.".to_string());
+        return completed;
+    };
+
+    match restore(ctx, entry.ghost_commit.clone()).await {
+        Ok(()) => {
+            let mut items = sess.clone_history().await.raw_items().to_vec();
+            for (position, item) in entry.removed_snapshots {
+                items.insert(position.min(items.len()), item);
+            }
+            sess.replace_history(items).await;
+            info!(
+                commit_id = entry.ghost_commit.id(),
+                "Redo restored ghost snapshot"
+            );
+            completed.success = true;
+            completed.message = Some("Redo restored the changes from before the undo.".to_string());
+        }
+        Err(message) => {
+            sess.push_redo_entry(entry).await;
+            completed.message = Some(message);
+        }
+    }
+    completed
+}
+
+async fn restore(ctx: &TurnContext, ghost_commit: GhostCommit) -> Result<(), String> {
+    let commit_id = ghost_commit.id().to_string();
+    let repo_path = ctx.cwd.clone();
+    let ghost_snapshot = ctx.ghost_snapshot.clone();
+    let restore_result = tokio::task::spawn_blocking(move || {
+        let options = RestoreGhostCommitOptions::new(&repo_path).ghost_snapshot(ghost_snapshot);
+        restore_ghost_commit_with_options(&options, &ghost_commit)
+    })
+    .await;
+
+    match restore_result {
+        Ok(Ok(())) => Ok(()),
+        Ok(Err(err)) => {
+            let message = format!("Failed to restore snapshot {commit_id}: {err}");
+            warn!("{message}");
+            Err(message)
+        }
+        Err(err) => {
+            let message = format!("Failed to restore snapshot {commit_id}: {err}");
+            error!("{message}");
+            Err(message)
+        }
+    }
+}
+
+/// Lists the newest [`MAX_LISTED_SNAPSHOTS`] ghost snapshots in history, newest
+/// first, with a preview of what restoring each would revert.
+pub(crate) async fn list_undo_snapshots(
+    sess: &Session,
+    ctx: &TurnContext,
+) -> ListUndoSnapshotsResponseEvent {
+    let redo_available = sess.redo_available().await;
+    let items = sess.clone_history().await.raw_items().to_vec();
+    let snapshots = ghost_snapshots(&items);
+    if snapshots.is_empty() {
+        return ListUndoSnapshotsResponseEvent {
+            snapshots: Vec::new(),
+            redo_available,
         };
+    }
 
-        let commit_id = ghost_commit.id().to_string();
-        let repo_path = ctx.cwd.clone();
-        let ghost_snapshot = ctx.ghost_snapshot.clone();
-        let restore_result = tokio::task::spawn_blocking(move || {
-            let options = RestoreGhostCommitOptions::new(&repo_path).ghost_snapshot(ghost_snapshot);
-            restore_ghost_commit_with_options(&options, &ghost_commit)
+    let user_turns = items
+        .iter()
+        .enumerate()
+        .filter(|(_, item)| is_user_turn_boundary(item))
+        .map(|(idx, _)| idx)
+        .collect::<Vec<_>>();
+    let described = snapshots
+        .into_iter()
+        .rev()
+        .take(MAX_LISTED_SNAPSHOTS)
+        .map(|(idx, commit)| {
+            // The snapshot is recorded after the user message that started its turn.
+            let turn_start = user_turns.iter().rev().find(|&&pos| pos < idx).copied();
+            let user_message = turn_start.and_then(|pos| match parse_turn_item(&items[pos]) {
+                Some(TurnItem::UserMessage(message)) => Some(message.message()),
+                _ => None,
+            });
+            let first_reverted = turn_start.unwrap_or(idx);
+            let turns = user_turns
+                .iter()
+                .filter(|&&pos| pos >= first_reverted)
+                .count()
+                .max(1);
+            (
+                commit,
+                user_message,
+                u32::try_from(turns).unwrap_or(u32::MAX),
+            )
         })
-        .await;
+        .collect::<Vec<_>>();
 
-        match restore_result {
-            Ok(Ok(())) => {
-                items.remove(idx);
-                sess.replace_history(items).await;
-                let short_id: String = commit_id.chars().take(7).collect();
-                info!(commit_id = commit_id, "Undo restored ghost snapshot");
-                completed.success = true;
-                completed.message = Some(format!("Undo restored snapshot {short_id}."));
-            }
-            Ok(Err(err)) => {
-                let message = format!("Failed to restore snapshot {commit_id}: {err}");
-                warn!("{message}");
-                completed.message = Some(message);
-            }
-            Err(err) => {
-                let message = format!("Failed to restore snapshot {commit_id}: {err}");
-                error!("{message}");
-                completed.message = Some(message);
-            }
+    let repo_path = ctx.cwd.clone();
+    let ghost_snapshot = ctx.ghost_snapshot.clone();
+    let last_turn_completed_at = sess.last_turn_completed_at().await;
+    let result = tokio::task::spawn_blocking(move || {
+        let current = snapshot_working_tree(&repo_path, ghost_snapshot)?;
+        described
+            .into_iter()
+            .map(|(commit, user_message, turns)| {
+                let diff = diff_ghost_commits(&repo_path, &commit, &current)?;
+                let conflicts = modified_since(&repo_path, &diff.paths, last_turn_completed_at);
+                Ok(UndoSnapshot {
+                    id: commit.id().to_string(),
+                    user_message,
+                    turns,
+                    changed_files: diff.paths,
+                    unified_diff: diff.unified_diff,
+                    conflicts,
+                })
+            })
+            .collect::<Result<Vec<_>, GitToolingError>>()
+    })
+    .await;
+
+    let snapshots = match result {
+        Ok(Ok(snapshots)) => snapshots,
+        Ok(Err(err)) => {
+            warn!("failed to describe undo snapshots: {err}");
+            Vec::new()
         }
+        Err(err) => {
+            error!("failed to describe undo snapshots: {err}");
+            Vec::new()
+        }
+    };
+    ListUndoSnapshotsResponseEvent {
+        snapshots,
+        redo_available,
+    }
+}
 
-        sess.send_event(ctx.as_ref(), EventMsg::UndoCompleted(completed))
-            .await;
-        None
+fn ghost_snapshots(items: &[ResponseItem]) -> Vec<(usize, GhostCommit)> {
+    items
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, item)| match item {
+            ResponseItem::GhostSnapshot { ghost_commit } => Some((idx, ghost_commit.clone())),
+            _ => None,
+        })
+        .collect()
+}
+
+fn snapshot_working_tree(
+    repo_path: &Path,
+    ghost_snapshot: GhostSnapshotConfig,
+) -> Result<GhostCommit, GitToolingError> {
+    let options = CreateGhostCommitOptions::new(repo_path).ghost_snapshot(ghost_snapshot);
+    create_ghost_commit(&options)
+}
+
+/// Returns the paths (relative to `repo_path`) whose files were modified after
+/// `since`. Without a completed turn there is nothing to compare against.
+fn modified_since(repo_path: &Path, paths: &[PathBuf], since: Option<SystemTime>) -> Vec<PathBuf> {
+    let Some(since) = since else {
+        return Vec::new();
+    };
+    paths
+        .iter()
+        .filter(|path| {
+            std::fs::metadata(repo_path.join(path))
+                .and_then(|metadata| metadata.modified())
+                .is_ok_and(|modified| modified > since)
+        })
+        .cloned()
+        .collect()
+}
+
+fn short_id(commit_id: &str) -> String {
+    commit_id.chars().take(7).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::time::Duration;
+
+    #[test]
+    fn modified_since_reports_files_changed_after_the_turn() -> std::io::Result<()> {
+        let temp = tempfile::tempdir()?;
+        std::fs::write(temp.path().join("old.txt"), "old\n")?;
+        std::fs::write(temp.path().join("new.txt"), "new\n")?;
+        let turn_end = SystemTime::now() - Duration::from_secs(60);
+        std::fs::File::options()
+            .write(true)
+            .open(temp.path().join("old.txt"))?
+            .set_modified(turn_end - Duration::from_secs(60))?;
+
+        let paths = vec![
+            PathBuf::from("old.txt"),
+            PathBuf::from("new.txt"),
+            PathBuf::from("deleted.txt"),
+        ];
+        assert_eq!(
+            modified_since(temp.path(), &paths, Some(turn_end)),
+            vec![PathBuf::from("new.txt")]
+        );
+        assert_eq!(
+            modified_since(temp.path(), &paths, None),
+            Vec::<PathBuf>::new()
+        );
+        Ok(())
     }
 }
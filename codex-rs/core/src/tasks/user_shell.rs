@@ -16,6 +16,7 @@ use crate::exec::StdoutStream;
 use crate::exec::StreamOutput;
 use crate::exec::execute_exec_env;
 use crate::exec_env::create_env;
+use crate::exec_env::inject_command_secrets;
 use crate::parse_command::parse_command;
 use crate::protocol::EventMsg;
 use crate::protocol::ExecCommandBeginEvent;
@@ -136,13 +137,19 @@ pub(crate) async fn execute_user_shell_command(
         )
         .await;
 
+    let mut env = create_env(
+        &turn_context.shell_environment_policy,
+        Some(session.conversation_id),
+    );
+    inject_command_secrets(
+        &mut env,
+        &display_command,
+        &session.services.command_secrets,
+    );
     let exec_env = ExecEnv {
         command: exec_command.clone(),
         cwd: cwd.clone(),
-        env: create_env(
-            &turn_context.shell_environment_policy,
-            Some(session.conversation_id),
-        ),
+        env,
         // TODO(zhao-oai): Now that we have ExecExpiration::Cancellation, we
         // should use that instead of an "arbitrarily large" timeout here.
         expiration: USER_SHELL_TIMEOUT_MS.into(),
@@ -157,6 +164,7 @@ pub(crate) async fn execute_user_shell_command(
         sub_id: turn_context.sub_id.clone(),
         call_id: call_id.clone(),
         tx_event: session.get_tx_event(),
+        secrets: session.services.command_secrets.clone(),
     });
 
     let sandbox_policy = SandboxPolicy::DangerFullAccess;
@@ -206,6 +214,7 @@ pub(crate) async fn execute_user_shell_command(
                 .await;
         }
         Ok(Ok(output)) => {
+            let output = session.services.command_secrets.redact_exec_output(output);
             session
                 .send_event(
                     turn_context.as_ref(),
@@ -236,7 +245,11 @@ pub(crate) async fn execute_user_shell_command(
         }
         Ok(Err(err)) => {
             error!("user shell command failed: {err:?}");
-            let message = format!("execution error: {err:?}");
+            let message = session
+                .services
+                .command_secrets
+                .redact(&format!("execution error: {err:?}"))
+                .into_owned();
             let exec_output = ExecToolCallOutput {
                 exit_code: -1,
                 stdout: StreamOutput::new(String::new()),
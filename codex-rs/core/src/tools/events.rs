@@ -273,8 +273,11 @@ impl ToolEmitter {
         ctx: ToolEventCtx<'_>,
         out: Result<ExecToolCallOutput, ToolError>,
     ) -> Result<String, FunctionCallError> {
+        // Exposed secrets never reach the model, even when the command prints them.
+        let secrets = &ctx.session.services.command_secrets;
         let (event, result) = match out {
             Ok(output) => {
+                let output = secrets.redact_exec_output(output);
                 let content = self.format_exec_output_for_model(&output, ctx);
                 let exit_code = output.exit_code;
                 let event = ToolEventStage::Success(output);
@@ -287,13 +290,16 @@ impl ToolEmitter {
             }
             Err(ToolError::Codex(CodexErr::Sandbox(SandboxErr::Timeout { output })))
             | Err(ToolError::Codex(CodexErr::Sandbox(SandboxErr::Denied { output }))) => {
+                let output = secrets.redact_exec_output(*output);
                 let response = self.format_exec_output_for_model(&output, ctx);
-                let event = ToolEventStage::Failure(ToolEventFailure::Output(*output));
+                let event = ToolEventStage::Failure(ToolEventFailure::Output(output));
                 let result = Err(FunctionCallError::RespondToModel(response));
                 (event, result)
             }
             Err(ToolError::Codex(err)) => {
-                let message = format!("execution error: {err:?}");
+                let message = secrets
+                    .redact(&format!("execution error: {err:?}"))
+                    .into_owned();
                 let event = ToolEventStage::Failure(ToolEventFailure::Message(message.clone()));
                 let result = Err(FunctionCallError::RespondToModel(message));
                 (event, result)
@@ -379,6 +385,11 @@ async fn emit_exec_stage(
         }
         ToolEventStage::Success(output)
         | ToolEventStage::Failure(ToolEventFailure::Output(output)) => {
+            let output = ctx
+                .session
+                .services
+                .command_secrets
+                .redact_exec_output(output);
             let exec_result = ExecCommandResult {
                 stdout: output.stdout.text.clone(),
                 stderr: output.stderr.text.clone(),
@@ -390,7 +401,12 @@ async fn emit_exec_stage(
             emit_exec_end(ctx, exec_input, exec_result).await;
         }
         ToolEventStage::Failure(ToolEventFailure::Message(message)) => {
-            let text = message.to_string();
+            let text = ctx
+                .session
+                .services
+                .command_secrets
+                .redact(&message)
+                .into_owned();
             let exec_result = ExecCommandResult {
                 stdout: String::new(),
                 stderr: text.clone(),
@@ -4,6 +4,7 @@ use std::path::Path;
 
 use crate::apply_patch;
 use crate::apply_patch::InternalApplyPatchInvocation;
+use crate::apply_patch::convert_apply_patch_hunks_to_protocol;
 use crate::apply_patch::convert_apply_patch_to_protocol;
 use crate::client_common::tools::FreeformTool;
 use crate::client_common::tools::FreeformToolFormat;
@@ -30,6 +31,7 @@ use crate::tools::spec::JsonSchema;
 use async_trait::async_trait;
 use codex_apply_patch::ApplyPatchAction;
 use codex_apply_patch::ApplyPatchFileChange;
+use codex_protocol::protocol::DeclinedHunk;
 use codex_utils_absolute_path::AbsolutePathBuf;
 
 pub struct ApplyPatchHandler;
@@ -60,6 +62,41 @@ fn to_abs_path(cwd: &Path, path: &Path) -> Option<AbsolutePathBuf> {
     AbsolutePathBuf::resolve_path_against_base(path, cwd).ok()
 }
 
+/// Appends the hunks the user declined during approval to the tool output, so
+/// the model knows which parts of its patch were not applied and why.
+fn with_declined_hunks(
+    result: Result<String, FunctionCallError>,
+    action: &ApplyPatchAction,
+    declined: &[DeclinedHunk],
+) -> Result<String, FunctionCallError> {
+    if declined.is_empty() {
+        return result;
+    }
+    let summary: Vec<serde_json::Value> = declined
+        .iter()
+        .map(|hunk| {
+            let path = hunk.path.strip_prefix(&action.cwd).unwrap_or(&hunk.path);
+            serde_json::json!({
+                "path": path.display().to_string(),
+                "hunk": hunk.index + 1,
+                "note": hunk.note,
+            })
+        })
+        .collect();
+    let summary = format!(
+        "The user declined {} hunk(s), which were not applied:\n{}",
+        declined.len(),
+        serde_json::Value::Array(summary)
+    );
+    match result {
+        Ok(content) => Ok(format!("{content}\n{summary}")),
+        Err(FunctionCallError::RespondToModel(content)) => Err(FunctionCallError::RespondToModel(
+            format!("{content}\n{summary}"),
+        )),
+        Err(err) => Err(err),
+    }
+}
+
 #[async_trait]
 impl ToolHandler for ApplyPatchHandler {
     fn kind(&self) -> ToolKind {
@@ -116,28 +153,24 @@ impl ToolHandler for ApplyPatchHandler {
                     }
                     InternalApplyPatchInvocation::DelegateToExec(apply) => {
                         let changes = convert_apply_patch_to_protocol(&apply.action);
+                        let hunks = convert_apply_patch_hunks_to_protocol(&apply.action);
                         let file_paths = file_paths_for_action(&apply.action);
-                        let emitter =
-                            ToolEmitter::apply_patch(changes.clone(), apply.auto_approved);
-                        let event_ctx = ToolEventCtx::new(
-                            session.as_ref(),
-                            turn.as_ref(),
-                            &call_id,
-                            Some(&tracker),
-                        );
-                        emitter.begin(event_ctx).await;
 
                         let req = ApplyPatchRequest {
                             action: apply.action,
                             file_paths,
                             changes,
+                            hunks,
                             exec_approval_requirement: apply.exec_approval_requirement,
                             timeout_ms: None,
                             codex_exe: turn.codex_linux_sandbox_exe.clone(),
                         };
 
                         let mut orchestrator = ToolOrchestrator::new();
-                        let mut runtime = ApplyPatchRuntime::new();
+                        let mut runtime = ApplyPatchRuntime::with_begin_event(
+                            Some(tracker.clone()),
+                            apply.auto_approved,
+                        );
                         let tool_ctx = ToolCtx {
                             session: session.as_ref(),
                             turn: turn.as_ref(),
@@ -153,7 +186,15 @@ impl ToolHandler for ApplyPatchHandler {
                             &call_id,
                             Some(&tracker),
                         );
-                        let content = emitter.finish(event_ctx, out).await?;
+                        let emitter = ToolEmitter::apply_patch(
+                            runtime.applied_changes(&req).clone(),
+                            apply.auto_approved,
+                        );
+                        let content = with_declined_hunks(
+                            emitter.finish(event_ctx, out).await,
+                            &req.action,
+                            runtime.declined_hunks(),
+                        )?;
                         Ok(ToolOutput::Function {
                             body: FunctionCallOutputBody::Text(content),
                             success: Some(true),
@@ -210,23 +251,22 @@ pub(crate) async fn intercept_apply_patch(
                 }
                 InternalApplyPatchInvocation::DelegateToExec(apply) => {
                     let changes = convert_apply_patch_to_protocol(&apply.action);
+                    let hunks = convert_apply_patch_hunks_to_protocol(&apply.action);
                     let approval_keys = file_paths_for_action(&apply.action);
-                    let emitter = ToolEmitter::apply_patch(changes.clone(), apply.auto_approved);
-                    let event_ctx =
-                        ToolEventCtx::new(session, turn, call_id, tracker.as_ref().copied());
-                    emitter.begin(event_ctx).await;
 
                     let req = ApplyPatchRequest {
                         action: apply.action,
                         file_paths: approval_keys,
                         changes,
+                        hunks,
                         exec_approval_requirement: apply.exec_approval_requirement,
                         timeout_ms,
                         codex_exe: turn.codex_linux_sandbox_exe.clone(),
                     };
 
                     let mut orchestrator = ToolOrchestrator::new();
-                    let mut runtime = ApplyPatchRuntime::new();
+                    let mut runtime =
+                        ApplyPatchRuntime::with_begin_event(tracker.cloned(), apply.auto_approved);
                     let tool_ctx = ToolCtx {
                         session,
                         turn,
@@ -238,7 +278,15 @@ pub(crate) async fn intercept_apply_patch(
                         .await;
                     let event_ctx =
                         ToolEventCtx::new(session, turn, call_id, tracker.as_ref().copied());
-                    let content = emitter.finish(event_ctx, out).await?;
+                    let emitter = ToolEmitter::apply_patch(
+                        runtime.applied_changes(&req).clone(),
+                        apply.auto_approved,
+                    );
+                    let content = with_declined_hunks(
+                        emitter.finish(event_ctx, out).await,
+                        &req.action,
+                        runtime.declined_hunks(),
+                    )?;
                     Ok(Some(ToolOutput::Function {
                         body: FunctionCallOutputBody::Text(content),
                         success: Some(true),
@@ -393,4 +441,42 @@ mod tests {
         let keys = file_paths_for_action(&action);
         assert_eq!(keys.len(), 2);
     }
+
+    #[test]
+    fn declined_hunks_are_reported_to_the_model() {
+        let tmp = TempDir::new().expect("tmp");
+        let cwd = tmp.path();
+        std::fs::write(cwd.join("a.txt"), "one\ntwo\n").expect("write file");
+        let patch = r#"*** Begin Patch
+*** Update File: a.txt
+@@
+-one
++ONE
+@@
+-two
++TWO
+*** End Patch"#;
+        let argv = vec!["apply_patch".to_string(), patch.to_string()];
+        let action = match codex_apply_patch::maybe_parse_apply_patch_verified(&argv, cwd) {
+            MaybeApplyPatchVerified::Body(action) => action,
+            other => panic!("expected patch body, got: {other:?}"),
+        };
+        let declined = vec![DeclinedHunk {
+            path: cwd.join("a.txt"),
+            index: 1,
+            note: Some("keep lowercase".to_string()),
+        }];
+
+        let content =
+            with_declined_hunks(Ok("Success.".to_string()), &action, &declined).expect("output");
+        let (text, json) = content.rsplit_once('\n').expect("summary line");
+        assert_eq!(
+            text,
+            "Success.\nThe user declined 1 hunk(s), which were not applied:"
+        );
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(json).expect("json"),
+            serde_json::json!([{"path": "a.txt", "hunk": 2, "note": "keep lowercase"}])
+        );
+    }
 }
@@ -13,6 +13,7 @@ mod shell;
 mod test_sync;
 mod unified_exec;
 mod view_image;
+mod web_fetch;
 
 pub use plan::PLAN_TOOL;
 use serde::Deserialize;
@@ -35,6 +36,7 @@ pub use shell::ShellHandler;
 pub use test_sync::TestSyncHandler;
 pub use unified_exec::UnifiedExecHandler;
 pub use view_image::ViewImageHandler;
+pub use web_fetch::WebFetchHandler;
 
 fn parse_arguments<T>(arguments: &str) -> Result<T, FunctionCallError>
 where
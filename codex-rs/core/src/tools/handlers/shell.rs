@@ -8,6 +8,7 @@ use std::sync::Arc;
 use crate::codex::TurnContext;
 use crate::exec::ExecParams;
 use crate::exec_env::create_env;
+use crate::exec_env::inject_command_secrets;
 use crate::exec_policy::ExecApprovalRequest;
 use crate::function_tool::FunctionCallError;
 use crate::is_safe_command::is_known_safe_command;
@@ -252,6 +253,11 @@ impl ShellHandler {
         if !dependency_env.is_empty() {
             exec_params.env.extend(dependency_env);
         }
+        inject_command_secrets(
+            &mut exec_params.env,
+            &exec_params.command,
+            &session.services.command_secrets,
+        );
 
         // Approval policy guard for explicit escalation in non-OnRequest modes.
         if exec_params
@@ -304,6 +310,8 @@ impl ShellHandler {
                 sandbox_policy: &turn.sandbox_policy,
                 sandbox_permissions: exec_params.sandbox_permissions,
                 prefix_rule,
+                cwd: &exec_params.cwd,
+                approval_rules: &turn.config.approval_rules,
             })
             .await;
 
@@ -15,12 +15,19 @@ use crate::tools::registry::ToolKind;
 use codex_protocol::models::ContentItem;
 use codex_protocol::models::ResponseInputItem;
 use codex_protocol::models::local_image_content_items_with_label_number;
+use codex_protocol::models::local_pdf_content_items;
+use codex_utils_image::pdf::PageSelection;
+use codex_utils_image::pdf::PdfRenderOptions;
+use codex_utils_image::pdf::is_pdf;
 
 pub struct ViewImageHandler;
 
 #[derive(Deserialize)]
 struct ViewImageArgs {
     path: String,
+    /// Pages of a PDF to attach, e.g. `1-3,5`.
+    #[serde(default)]
+    pages: Option<String>,
 }
 
 #[async_trait]
@@ -66,8 +73,31 @@ impl ToolHandler for ViewImageHandler {
         }
         let event_path = abs_path.clone();
 
-        let content: Vec<ContentItem> =
-            local_image_content_items_with_label_number(&abs_path, None);
+        let is_pdf = is_pdf(&abs_path);
+        let content: Vec<ContentItem> = if is_pdf {
+            let pages = args
+                .pages
+                .as_deref()
+                .map(PageSelection::parse)
+                .transpose()
+                .map_err(|err| FunctionCallError::RespondToModel(err.to_string()))?;
+            let token_budget = turn.pdf_token_budget();
+            local_pdf_content_items(
+                &abs_path,
+                None,
+                &PdfRenderOptions {
+                    pages,
+                    token_budget,
+                },
+            )
+        } else if args.pages.is_some() {
+            return Err(FunctionCallError::RespondToModel(format!(
+                "`pages` only applies to PDF files, but `{}` is not a PDF",
+                abs_path.display()
+            )));
+        } else {
+            local_image_content_items_with_label_number(&abs_path, None)
+        };
         let input = ResponseInputItem::Message {
             role: "user".to_string(),
             content,
@@ -93,7 +123,11 @@ impl ToolHandler for ViewImageHandler {
             .await;
 
         Ok(ToolOutput::Function {
-            body: FunctionCallOutputBody::Text("attached local image path".to_string()),
+            body: FunctionCallOutputBody::Text(if is_pdf {
+                "attached local PDF pages".to_string()
+            } else {
+                "attached local image path".to_string()
+            }),
             success: Some(true),
         })
     }
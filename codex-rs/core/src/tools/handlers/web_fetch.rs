@@ -0,0 +1,167 @@
+use async_trait::async_trait;
+use codex_protocol::models::FunctionCallOutputBody;
+use serde::Deserialize;
+use url::Url;
+
+use crate::function_tool::FunctionCallError;
+use crate::tools::context::ToolInvocation;
+use crate::tools::context::ToolOutput;
+use crate::tools::context::ToolPayload;
+use crate::tools::handlers::parse_arguments;
+use crate::tools::registry::ToolHandler;
+use crate::tools::registry::ToolKind;
+use crate::web_fetch::SearchResult;
+use crate::web_fetch::check_network_access;
+use crate::web_fetch::paginate;
+use crate::web_fetch::search_backend_from_config;
+
+pub struct WebFetchHandler;
+
+/// Bytes of each output reserved for the page header and continuation hint.
+const PAGE_HEADER_ALLOWANCE: usize = 512;
+const MIN_PAGE_BYTES: usize = 1024;
+const DEFAULT_SEARCH_RESULTS: usize = 5;
+const MAX_SEARCH_RESULTS: usize = 20;
+
+fn default_page() -> usize {
+    1
+}
+
+#[derive(Deserialize)]
+struct WebFetchArgs {
+    url: String,
+    #[serde(default = "default_page")]
+    page: usize,
+}
+
+#[derive(Deserialize)]
+struct WebSearchLocalArgs {
+    query: String,
+    #[serde(default)]
+    limit: Option<usize>,
+}
+
+#[async_trait]
+impl ToolHandler for WebFetchHandler {
+    fn kind(&self) -> ToolKind {
+        ToolKind::Function
+    }
+
+    async fn handle(&self, invocation: ToolInvocation) -> Result<ToolOutput, FunctionCallError> {
+        let ToolInvocation {
+            session,
+            turn,
+            tool_name,
+            payload,
+            ..
+        } = invocation;
+
+        let arguments = match payload {
+            ToolPayload::Function { arguments } => arguments,
+            _ => {
+                return Err(FunctionCallError::RespondToModel(
+                    "web_fetch handler received unsupported payload".to_string(),
+                ));
+            }
+        };
+
+        check_network_access(&turn.sandbox_policy)
+            .map_err(|err| FunctionCallError::RespondToModel(err.to_string()))?;
+
+        let content = match tool_name.as_str() {
+            "web_fetch" => {
+                let args: WebFetchArgs = parse_arguments(&arguments)?;
+                let url = Url::parse(args.url.trim()).map_err(|err| {
+                    FunctionCallError::RespondToModel(format!("invalid url `{}`: {err}", args.url))
+                })?;
+                let page = session
+                    .services
+                    .web_fetch_cache
+                    .get_or_fetch(&url, &turn.config.web_fetch)
+                    .await
+                    .map_err(|err| FunctionCallError::RespondToModel(err.to_string()))?;
+
+                let page_bytes = turn
+                    .truncation_policy
+                    .byte_budget()
+                    .saturating_sub(PAGE_HEADER_ALLOWANCE)
+                    .max(MIN_PAGE_BYTES);
+                let pages = paginate(&page.markdown, page_bytes);
+                let total = pages.len();
+                let Some(body) = args.page.checked_sub(1).and_then(|idx| pages.get(idx)) else {
+                    return Err(FunctionCallError::RespondToModel(format!(
+                        "page {} is out of range; {} has {total} page(s)",
+                        args.page, page.url
+                    )));
+                };
+                format_page(page.title.as_deref(), &page.url, args.page, total, body)
+            }
+            "web_search_local" => {
+                let args: WebSearchLocalArgs = parse_arguments(&arguments)?;
+                let backend =
+                    search_backend_from_config(&turn.config.web_fetch).ok_or_else(|| {
+                        FunctionCallError::RespondToModel(
+                            "web_search_local has no search backend configured".to_string(),
+                        )
+                    })?;
+                let limit = args
+                    .limit
+                    .unwrap_or(DEFAULT_SEARCH_RESULTS)
+                    .clamp(1, MAX_SEARCH_RESULTS);
+                let results = backend
+                    .search(&args.query, limit)
+                    .await
+                    .map_err(|err| FunctionCallError::RespondToModel(err.to_string()))?;
+                format_search_results(&args.query, &results)
+            }
+            other => {
+                return Err(FunctionCallError::RespondToModel(format!(
+                    "unsupported web fetch tool: {other}"
+                )));
+            }
+        };
+
+        Ok(ToolOutput::Function {
+            body: FunctionCallOutputBody::Text(content),
+            success: Some(true),
+        })
+    }
+}
+
+fn format_page(title: Option<&str>, url: &str, page: usize, total: usize, body: &str) -> String {
+    let mut out = String::new();
+    if let Some(title) = title {
+        out.push_str(&format!("Title: {title}\n"));
+    }
+    out.push_str(&format!("URL: {url}\nPage {page} of {total}\n\n{body}"));
+    if page < total {
+        out.push_str(&format!(
+            "\n\n[Page {page} of {total}. Call web_fetch with page={} to continue.]",
+            page + 1
+        ));
+    }
+    out
+}
+
+fn format_search_results(query: &str, results: &[SearchResult]) -> String {
+    if results.is_empty() {
+        return format!("No results for \"{query}\".");
+    }
+    let mut out = String::new();
+    for (idx, result) in results.iter().enumerate() {
+        if idx > 0 {
+            out.push('\n');
+        }
+        out.push_str(&format!(
+            "{}. {}\n   {}\n",
+            idx + 1,
+            result.title,
+            result.url
+        ));
+        if !result.snippet.is_empty() {
+            out.push_str(&format!("   {}\n", result.snippet));
+        }
+    }
+    out.push_str("\nUse web_fetch to read a result.");
+    out
+}
@@ -9,6 +9,9 @@ use crate::exec::ExecToolCallOutput;
 use crate::sandboxing::CommandSpec;
 use crate::sandboxing::SandboxPermissions;
 use crate::sandboxing::execute_env;
+use crate::tools::context::SharedTurnDiffTracker;
+use crate::tools::events::ToolEmitter;
+use crate::tools::events::ToolEventCtx;
 use crate::tools::sandboxing::Approvable;
 use crate::tools::sandboxing::ApprovalCtx;
 use crate::tools::sandboxing::ExecApprovalRequirement;
@@ -21,7 +24,9 @@ use crate::tools::sandboxing::ToolRuntime;
 use crate::tools::sandboxing::with_cached_approval;
 use codex_apply_patch::ApplyPatchAction;
 use codex_protocol::protocol::AskForApproval;
+use codex_protocol::protocol::DeclinedHunk;
 use codex_protocol::protocol::FileChange;
+use codex_protocol::protocol::PatchHunk;
 use codex_protocol::protocol::ReviewDecision;
 use codex_utils_absolute_path::AbsolutePathBuf;
 use futures::future::BoxFuture;
@@ -33,20 +38,98 @@ pub struct ApplyPatchRequest {
     pub action: ApplyPatchAction,
     pub file_paths: Vec<AbsolutePathBuf>,
     pub changes: std::collections::HashMap<PathBuf, FileChange>,
+    pub hunks: Vec<PatchHunk>,
     pub exec_approval_requirement: ExecApprovalRequirement,
     pub timeout_ms: Option<u64>,
     pub codex_exe: Option<PathBuf>,
 }
 
+/// Where to report the `PatchApplyBegin` event once the approved patch is
+/// known.
+struct PendingBegin {
+    turn_diff_tracker: Option<SharedTurnDiffTracker>,
+    auto_approved: bool,
+}
+
 #[derive(Default)]
-pub struct ApplyPatchRuntime;
+pub struct ApplyPatchRuntime {
+    /// Hunks the user declined when approving the patch hunk by hunk.
+    declined: Vec<DeclinedHunk>,
+    /// Changes of the patch that actually runs, when hunks were declined.
+    applied_changes: Option<HashMap<PathBuf, FileChange>>,
+    /// Emitted before the first attempt, after approval, so the event only
+    /// carries the accepted hunks.
+    pending_begin: Option<PendingBegin>,
+}
 
 impl ApplyPatchRuntime {
-    pub fn new() -> Self {
-        Self
+    /// Runtime that emits `PatchApplyBegin` itself right before the patch
+    /// first runs.
+    pub(crate) fn with_begin_event(
+        turn_diff_tracker: Option<SharedTurnDiffTracker>,
+        auto_approved: bool,
+    ) -> Self {
+        Self {
+            pending_begin: Some(PendingBegin {
+                turn_diff_tracker,
+                auto_approved,
+            }),
+            ..Self::default()
+        }
+    }
+
+    /// Hunks declined during approval; these were not applied.
+    pub(crate) fn declined_hunks(&self) -> &[DeclinedHunk] {
+        &self.declined
     }
 
-    fn build_command_spec(req: &ApplyPatchRequest) -> Result<CommandSpec, ToolError> {
+    /// Changes that were (or would have been) applied: `req.changes` without
+    /// the declined hunks.
+    pub(crate) fn applied_changes<'a>(
+        &'a self,
+        req: &'a ApplyPatchRequest,
+    ) -> &'a HashMap<PathBuf, FileChange> {
+        self.applied_changes.as_ref().unwrap_or(&req.changes)
+    }
+
+    /// The patch to run: the original one, or only the accepted hunks when the
+    /// user declined some of them.
+    fn patch_to_apply(&mut self, req: &ApplyPatchRequest) -> Result<String, ToolError> {
+        if self.declined.is_empty() {
+            return Ok(req.action.patch.clone());
+        }
+        let declined = &self.declined;
+        let patch = codex_apply_patch::retain_hunks(&req.action, |path, index| {
+            !declined
+                .iter()
+                .any(|hunk| hunk.path == path && hunk.index == index)
+        })
+        .map_err(|err| ToolError::Rejected(format!("failed to filter patch: {err}")))?;
+        let Some(patch) = patch else {
+            self.applied_changes = Some(HashMap::new());
+            return Err(ToolError::Rejected(
+                "all hunks were declined by user".to_string(),
+            ));
+        };
+        let command = vec!["apply_patch".to_string(), patch.clone()];
+        match codex_apply_patch::maybe_parse_apply_patch_verified(&command, &req.action.cwd) {
+            codex_apply_patch::MaybeApplyPatchVerified::Body(action) => {
+                self.applied_changes =
+                    Some(crate::apply_patch::convert_apply_patch_to_protocol(&action));
+            }
+            other => {
+                return Err(ToolError::Rejected(format!(
+                    "failed to verify filtered patch: {other:?}"
+                )));
+            }
+        }
+        Ok(patch)
+    }
+
+    fn build_command_spec(
+        req: &ApplyPatchRequest,
+        patch: String,
+    ) -> Result<CommandSpec, ToolError> {
         use std::env;
         let exe = if let Some(path) = &req.codex_exe {
             path.clone()
@@ -57,7 +140,7 @@ impl ApplyPatchRuntime {
         let program = exe.to_string_lossy().to_string();
         Ok(CommandSpec {
             program,
-            args: vec![CODEX_APPLY_PATCH_ARG1.to_string(), req.action.patch.clone()],
+            args: vec![CODEX_APPLY_PATCH_ARG1.to_string(), patch],
             cwd: req.action.cwd.clone(),
             expiration: req.timeout_ms.into(),
             // Run apply_patch with a minimal environment for determinism and to avoid leaks.
@@ -72,6 +155,7 @@ impl ApplyPatchRuntime {
             sub_id: ctx.turn.sub_id.clone(),
             call_id: ctx.call_id.clone(),
             tx_event: ctx.session.get_tx_event(),
+            secrets: ctx.session.services.command_secrets.clone(),
         })
     }
 }
@@ -103,26 +187,32 @@ impl Approvable<ApplyPatchRequest> for ApplyPatchRuntime {
         let retry_reason = ctx.retry_reason.clone();
         let approval_keys = self.approval_keys(req);
         let changes = req.changes.clone();
+        let hunks = req.hunks.clone();
+        let declined = &mut self.declined;
         Box::pin(async move {
-            if let Some(reason) = retry_reason {
+            let decision = if let Some(reason) = retry_reason {
                 let rx_approve = session
-                    .request_patch_approval(turn, call_id, changes.clone(), Some(reason), None)
+                    .request_patch_approval(turn, call_id, changes, hunks, Some(reason), None)
                     .await;
-                return rx_approve.await.unwrap_or_default();
+                rx_approve.await.unwrap_or_default()
+            } else {
+                with_cached_approval(
+                    &session.services,
+                    "apply_patch",
+                    approval_keys,
+                    || async move {
+                        let rx_approve = session
+                            .request_patch_approval(turn, call_id, changes, hunks, None, None)
+                            .await;
+                        rx_approve.await.unwrap_or_default()
+                    },
+                )
+                .await
+            };
+            if let ReviewDecision::ApprovedHunks { declined: hunks } = &decision {
+                declined.clone_from(hunks);
             }
-
-            with_cached_approval(
-                &session.services,
-                "apply_patch",
-                approval_keys,
-                || async move {
-                    let rx_approve = session
-                        .request_patch_approval(turn, call_id, changes, None, None)
-                        .await;
-                    rx_approve.await.unwrap_or_default()
-                },
-            )
-            .await
+            decision
         })
     }
 
@@ -149,7 +239,19 @@ impl ToolRuntime<ApplyPatchRequest, ExecToolCallOutput> for ApplyPatchRuntime {
         attempt: &SandboxAttempt<'_>,
         ctx: &ToolCtx<'_>,
     ) -> Result<ExecToolCallOutput, ToolError> {
-        let spec = Self::build_command_spec(req)?;
+        let patch = self.patch_to_apply(req)?;
+        if let Some(begin) = self.pending_begin.take() {
+            let emitter =
+                ToolEmitter::apply_patch(self.applied_changes(req).clone(), begin.auto_approved);
+            let event_ctx = ToolEventCtx::new(
+                ctx.session,
+                ctx.turn,
+                &ctx.call_id,
+                begin.turn_diff_tracker.as_ref(),
+            );
+            emitter.begin(event_ctx).await;
+        }
+        let spec = Self::build_command_spec(req, patch)?;
         let env = attempt
             .env_for(spec)
             .map_err(|err| ToolError::Codex(err.into()))?;
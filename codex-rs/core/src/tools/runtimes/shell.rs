@@ -58,6 +58,7 @@ impl ShellRuntime {
             sub_id: ctx.turn.sub_id.clone(),
             call_id: ctx.call_id.clone(),
             tx_event: ctx.session.get_tx_event(),
+            secrets: ctx.session.services.command_secrets.clone(),
         })
     }
 }
@@ -16,6 +16,7 @@ use codex_protocol::dynamic_tools::DynamicToolSpec;
 use codex_protocol::models::VIEW_IMAGE_TOOL_NAME;
 use codex_protocol::openai_models::ApplyPatchToolType;
 use codex_protocol::openai_models::ConfigShellToolType;
+use codex_protocol::openai_models::InputModality;
 use codex_protocol::openai_models::ModelInfo;
 use serde::Deserialize;
 use serde::Serialize;
@@ -65,6 +66,9 @@ pub(crate) struct ToolsConfig {
     pub collab_tools: bool,
     pub collaboration_modes_tools: bool,
     pub memory_tools: bool,
+    pub web_fetch: bool,
+    pub web_search_local: bool,
+    pub view_image: bool,
     pub request_rule_enabled: bool,
     pub experimental_supported_tools: Vec<String>,
 }
@@ -86,7 +90,9 @@ impl ToolsConfig {
         let include_collab_tools = features.enabled(Feature::Collab);
         let include_collaboration_modes_tools = features.enabled(Feature::CollaborationModes);
         let include_memory_tools = features.enabled(Feature::MemoryTool);
+        let include_web_fetch = features.enabled(Feature::WebFetch);
         let request_rule_enabled = features.enabled(Feature::RequestRule);
+        let include_view_image = model_info.input_modalities.contains(&InputModality::Image);
 
         let shell_type = if !features.enabled(Feature::ShellTool) {
             ConfigShellToolType::Disabled
@@ -120,10 +126,20 @@ impl ToolsConfig {
             collab_tools: include_collab_tools,
             collaboration_modes_tools: include_collaboration_modes_tools,
             memory_tools: include_memory_tools,
+            web_fetch: include_web_fetch,
+            web_search_local: false,
+            view_image: include_view_image,
             request_rule_enabled,
             experimental_supported_tools: model_info.experimental_supported_tools.clone(),
         }
     }
+
+    /// Exposes `web_search_local` alongside `web_fetch` when a search backend
+    /// is configured.
+    pub fn with_local_web_search(mut self, backend_configured: bool) -> Self {
+        self.web_search_local = self.web_fetch && backend_configured;
+        self
+    }
 }
 
 /// Generic JSON‑Schema subset needed for our tool definitions
@@ -462,16 +478,27 @@ Examples of valid command strings:
 
 fn create_view_image_tool() -> ToolSpec {
     // Support only local filesystem path.
-    let properties = BTreeMap::from([(
-        "path".to_string(),
-        JsonSchema::String {
-            description: Some("Local filesystem path to an image file".to_string()),
-        },
-    )]);
+    let properties = BTreeMap::from([
+        (
+            "path".to_string(),
+            JsonSchema::String {
+                description: Some("Local filesystem path to an image or PDF file".to_string()),
+            },
+        ),
+        (
+            "pages".to_string(),
+            JsonSchema::String {
+                description: Some(
+                    "PDF only: pages to attach, e.g. \"1-3,5\". Defaults to as many pages from the start as fit the context budget."
+                        .to_string(),
+                ),
+            },
+        ),
+    ]);
 
     ToolSpec::Function(ResponsesApiTool {
         name: VIEW_IMAGE_TOOL_NAME.to_string(),
-        description: "View a local image from the filesystem (only use if given a full filepath by the user, and the image isn't already attached to the thread context within <image ...> tags)."
+        description: "View a local image or PDF from the filesystem (only use if given a full filepath by the user, and the image isn't already attached to the thread context within <image ...> tags). PDF pages are attached as images along with their extracted text."
             .to_string(),
         strict: false,
         parameters: JsonSchema::Object {
@@ -692,6 +719,64 @@ fn create_get_memory_tool() -> ToolSpec {
     })
 }
 
+fn create_web_fetch_tool() -> ToolSpec {
+    let properties = BTreeMap::from([
+        (
+            "url".to_string(),
+            JsonSchema::String {
+                description: Some("Absolute http(s) URL of the page to fetch.".to_string()),
+            },
+        ),
+        (
+            "page".to_string(),
+            JsonSchema::Number {
+                description: Some(
+                    "1-based page of the converted document to return. Defaults to 1.".to_string(),
+                ),
+            },
+        ),
+    ]);
+
+    ToolSpec::Function(ResponsesApiTool {
+        name: "web_fetch".to_string(),
+        description: "Fetches a web page and returns it as Markdown. Long pages are split into pages; request later pages with `page`. Results are cached for the session.".to_string(),
+        strict: false,
+        parameters: JsonSchema::Object {
+            properties,
+            required: Some(vec!["url".to_string()]),
+            additional_properties: Some(false.into()),
+        },
+    })
+}
+
+fn create_web_search_local_tool() -> ToolSpec {
+    let properties = BTreeMap::from([
+        (
+            "query".to_string(),
+            JsonSchema::String {
+                description: Some("Search query.".to_string()),
+            },
+        ),
+        (
+            "limit".to_string(),
+            JsonSchema::Number {
+                description: Some("Maximum number of results (1-20). Defaults to 5.".to_string()),
+            },
+        ),
+    ]);
+
+    ToolSpec::Function(ResponsesApiTool {
+        name: "web_search_local".to_string(),
+        description: "Searches the web and returns result titles, URLs and snippets. Use web_fetch to read a result.".to_string(),
+        strict: false,
+        parameters: JsonSchema::Object {
+            properties,
+            required: Some(vec!["query".to_string()]),
+            additional_properties: Some(false.into()),
+        },
+    })
+}
+
 fn create_close_agent_tool() -> ToolSpec {
     let mut properties = BTreeMap::new();
     properties.insert(
@@ -1299,6 +1384,7 @@ pub(crate) fn build_specs(
     use crate::tools::handlers::TestSyncHandler;
     use crate::tools::handlers::UnifiedExecHandler;
     use crate::tools::handlers::ViewImageHandler;
+    use crate::tools::handlers::WebFetchHandler;
     use std::sync::Arc;
 
     let mut builder = ToolRegistryBuilder::new();
@@ -1314,6 +1400,7 @@ pub(crate) fn build_specs(
     let mcp_resource_handler = Arc::new(McpResourceHandler);
     let shell_command_handler = Arc::new(ShellCommandHandler);
     let request_user_input_handler = Arc::new(RequestUserInputHandler);
+    let web_fetch_handler = Arc::new(WebFetchHandler);
 
     match &config.shell_type {
         ConfigShellToolType::Default => {
@@ -1373,6 +1460,15 @@ pub(crate) fn build_specs(
         builder.register_handler("get_memory", get_memory_handler);
     }
 
+    if config.web_fetch {
+        builder.push_spec_with_parallel_support(create_web_fetch_tool(), true);
+        builder.register_handler("web_fetch", web_fetch_handler.clone());
+        if config.web_search_local {
+            builder.push_spec_with_parallel_support(create_web_search_local_tool(), true);
+            builder.register_handler("web_search_local", web_fetch_handler);
+        }
+    }
+
     if let Some(apply_patch_tool_type) = &config.apply_patch_tool_type {
         match apply_patch_tool_type {
             ApplyPatchToolType::Freeform => {
@@ -1436,8 +1532,10 @@ pub(crate) fn build_specs(
         Some(WebSearchMode::Disabled) | None => {}
     }
 
-    builder.push_spec_with_parallel_support(create_view_image_tool(), true);
-    builder.register_handler("view_image", view_image_handler);
+    if config.view_image {
+        builder.push_spec_with_parallel_support(create_view_image_tool(), true);
+        builder.register_handler("view_image", view_image_handler);
+    }
 
     if config.collab_tools {
         let collab_handler = Arc::new(CollabHandler);
@@ -1707,6 +1805,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn view_image_omitted_for_text_only_models() {
+        let config = test_config();
+        let mut model_info = ModelsManager::construct_model_info_offline("gpt-5-codex", &config);
+        model_info.input_modalities = vec![InputModality::Text];
+        let features = Features::with_defaults();
+        let tools_config = ToolsConfig::new(&ToolsConfigParams {
+            model_info: &model_info,
+            features: &features,
+            web_search_mode: Some(WebSearchMode::Cached),
+        });
+        let (tools, _) = build_specs(&tools_config, None, &[]).build();
+        assert!(
+            !tools
+                .iter()
+                .any(|tool| tool_name(&tool.spec) == VIEW_IMAGE_TOOL_NAME),
+            "view_image should not be offered to text-only models"
+        );
+    }
+
     #[test]
     fn request_user_input_requires_collaboration_modes_feature() {
         let config = test_config();
@@ -1761,6 +1879,44 @@ mod tests {
         assert_contains_tool_names(&tools, &["get_memory"]);
     }
 
+    #[test]
+    fn web_fetch_tools_require_feature_and_search_backend() {
+        let config = test_config();
+        let model_info = ModelsManager::construct_model_info_offline("gpt-5-codex", &config);
+        let mut features = Features::with_defaults();
+        features.disable(Feature::WebFetch);
+        let tools_config = ToolsConfig::new(&ToolsConfigParams {
+            model_info: &model_info,
+            features: &features,
+            web_search_mode: Some(WebSearchMode::Cached),
+        })
+        .with_local_web_search(true);
+        let (tools, _) = build_specs(&tools_config, None, &[]).build();
+        assert!(
+            !tools
+                .iter()
+                .any(|t| matches!(t.spec.name(), "web_fetch" | "web_search_local")),
+            "web fetch tools should be disabled when web_fetch feature is off"
+        );
+
+        features.enable(Feature::WebFetch);
+        let tools_config = ToolsConfig::new(&ToolsConfigParams {
+            model_info: &model_info,
+            features: &features,
+            web_search_mode: Some(WebSearchMode::Cached),
+        });
+        let (tools, _) = build_specs(&tools_config, None, &[]).build();
+        assert_contains_tool_names(&tools, &["web_fetch"]);
+        assert!(
+            !tools.iter().any(|t| t.spec.name() == "web_search_local"),
+            "web_search_local needs a configured search backend"
+        );
+
+        let tools_config = tools_config.with_local_web_search(true);
+        let (tools, _) = build_specs(&tools_config, None, &[]).build();
+        assert_contains_tool_names(&tools, &["web_fetch", "web_search_local"]);
+    }
+
     fn assert_model_tools(
         model_slug: &str,
         features: &Features,
@@ -0,0 +1,838 @@
+//! Human-readable exports of a recorded thread.
+//!
+//! A rollout is first reduced to an [`ExportedTranscript`]: the conversation as
+//! the user saw it, with tool calls paired with their outputs and rolled-back
+//! turns removed. That transcript is then rendered as Markdown, a standalone
+//! HTML page, or JSON.
+//!
+//! Like `review_format`, this module is UI-agnostic and returns plain strings.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use codex_protocol::models::FunctionCallOutputPayload;
+use codex_protocol::models::LocalShellAction;
+use codex_protocol::models::ResponseItem;
+use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::RolloutItem;
+use serde::Serialize;
+
+use crate::bash::extract_bash_command;
+use crate::command_secrets::CommandSecrets;
+use crate::parse_command::shlex_join;
+use crate::review_format::render_review_output_text;
+
+/// Output format of [`export_transcript`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Markdown,
+    Html,
+    Json,
+}
+
+impl ExportFormat {
+    /// File extension conventionally used for the format.
+    pub fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Markdown => "md",
+            ExportFormat::Html => "html",
+            ExportFormat::Json => "json",
+        }
+    }
+}
+
+impl FromStr for ExportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "md" | "markdown" => Ok(ExportFormat::Markdown),
+            "html" => Ok(ExportFormat::Html),
+            "json" => Ok(ExportFormat::Json),
+            other => Err(format!(
+                "unknown export format `{other}`; expected md, html or json"
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ExportOptions {
+    /// Secrets whose values are replaced with `[REDACTED:NAME]`, the same
+    /// redaction command output gets before it reaches the model. Empty by
+    /// default, which redacts nothing.
+    pub redact: CommandSecrets,
+    /// Drop command and tool output, keeping only what was run.
+    pub omit_tool_output: bool,
+}
+
+/// A thread reduced to what is worth sharing.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ExportedTranscript {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cwd: Option<PathBuf>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    pub entries: Vec<TranscriptEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TranscriptEntry {
+    User {
+        text: String,
+    },
+    Assistant {
+        text: String,
+    },
+    Reasoning {
+        text: String,
+    },
+    Command {
+        command: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        exit_code: Option<i32>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        output: Option<String>,
+    },
+    /// An `apply_patch` call, with the patch rewritten as a unified diff.
+    Patch {
+        diff: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        output: Option<String>,
+    },
+    ToolCall {
+        name: String,
+        input: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        output: Option<String>,
+    },
+    Review {
+        text: String,
+    },
+}
+
+/// Build the transcript for `items` and render it in `format`.
+pub fn export_transcript(
+    items: &[RolloutItem],
+    format: ExportFormat,
+    options: &ExportOptions,
+) -> String {
+    let transcript = build_transcript(items, options);
+    match format {
+        ExportFormat::Markdown => render_markdown(&transcript),
+        ExportFormat::Html => render_html(&transcript),
+        ExportFormat::Json => serde_json::to_string_pretty(&transcript).unwrap_or_default(),
+    }
+}
+
+/// Reduce rollout `items` to an [`ExportedTranscript`].
+pub fn build_transcript(items: &[RolloutItem], options: &ExportOptions) -> ExportedTranscript {
+    let mut transcript = ExportedTranscript::default();
+    // Entry index of every tool call, keyed by call id, so outputs recorded
+    // later can be attached to it.
+    let mut calls: HashMap<String, usize> = HashMap::new();
+    // Entry index at which each user turn starts, for rollbacks.
+    let mut turn_starts: Vec<usize> = Vec::new();
+
+    for item in items {
+        match item {
+            RolloutItem::SessionMeta(meta) => {
+                transcript
+                    .thread_id
+                    .get_or_insert_with(|| meta.meta.id.to_string());
+                transcript
+                    .started_at
+                    .get_or_insert_with(|| meta.meta.timestamp.clone());
+                transcript.cwd.get_or_insert_with(|| meta.meta.cwd.clone());
+            }
+            RolloutItem::TurnContext(context) => {
+                transcript
+                    .model
+                    .get_or_insert_with(|| context.model.clone());
+            }
+            RolloutItem::EventMsg(EventMsg::UserMessage(event)) => {
+                turn_starts.push(transcript.entries.len());
+                transcript.entries.push(TranscriptEntry::User {
+                    text: event.message.clone(),
+                });
+            }
+            RolloutItem::EventMsg(EventMsg::AgentMessage(event)) => {
+                transcript.entries.push(TranscriptEntry::Assistant {
+                    text: event.message.clone(),
+                });
+            }
+            RolloutItem::EventMsg(EventMsg::AgentReasoning(event)) => {
+                transcript.entries.push(TranscriptEntry::Reasoning {
+                    text: event.text.clone(),
+                });
+            }
+            RolloutItem::EventMsg(EventMsg::ExitedReviewMode(event)) => {
+                if let Some(output) = &event.review_output {
+                    transcript.entries.push(TranscriptEntry::Review {
+                        text: render_review_output_text(output),
+                    });
+                }
+            }
+            RolloutItem::EventMsg(EventMsg::ThreadRolledBack(event)) => {
+                let keep = turn_starts.len().saturating_sub(event.num_turns as usize);
+                if let Some(&start) = turn_starts.get(keep) {
+                    transcript.entries.truncate(start);
+                    turn_starts.truncate(keep);
+                    calls.retain(|_, index| *index < start);
+                }
+            }
+            RolloutItem::ResponseItem(ResponseItem::FunctionCall {
+                name,
+                arguments,
+                call_id,
+                ..
+            }) => {
+                calls.insert(call_id.clone(), transcript.entries.len());
+                transcript
+                    .entries
+                    .push(function_call_entry(name, arguments));
+            }
+            RolloutItem::ResponseItem(ResponseItem::CustomToolCall {
+                name,
+                input,
+                call_id,
+                ..
+            }) => {
+                calls.insert(call_id.clone(), transcript.entries.len());
+                let entry = if name == "apply_patch" {
+                    TranscriptEntry::Patch {
+                        diff: patch_to_unified_diff(input),
+                        output: None,
+                    }
+                } else {
+                    TranscriptEntry::ToolCall {
+                        name: name.clone(),
+                        input: input.clone(),
+                        output: None,
+                    }
+                };
+                transcript.entries.push(entry);
+            }
+            RolloutItem::ResponseItem(ResponseItem::LocalShellCall {
+                call_id,
+                action: LocalShellAction::Exec(exec),
+                ..
+            }) => {
+                if let Some(call_id) = call_id {
+                    calls.insert(call_id.clone(), transcript.entries.len());
+                }
+                transcript.entries.push(command_entry(&exec.command));
+            }
+            RolloutItem::ResponseItem(ResponseItem::FunctionCallOutput { call_id, output }) => {
+                if let Some(entry) = calls
+                    .get(call_id)
+                    .and_then(|&index| transcript.entries.get_mut(index))
+                {
+                    attach_output(entry, function_output_text(output));
+                }
+            }
+            RolloutItem::ResponseItem(ResponseItem::CustomToolCallOutput { call_id, output }) => {
+                if let Some(entry) = calls
+                    .get(call_id)
+                    .and_then(|&index| transcript.entries.get_mut(index))
+                {
+                    attach_output(entry, output.clone());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if options.omit_tool_output {
+        for entry in &mut transcript.entries {
+            match entry {
+                TranscriptEntry::Command { output, .. }
+                | TranscriptEntry::Patch { output, .. }
+                | TranscriptEntry::ToolCall { output, .. } => *output = None,
+                _ => {}
+            }
+        }
+    }
+    if !options.redact.is_empty() {
+        redact_transcript(&mut transcript, &options.redact);
+    }
+    transcript
+}
+
+fn function_call_entry(name: &str, arguments: &str) -> TranscriptEntry {
+    let args = serde_json::from_str::<serde_json::Value>(arguments).ok();
+    let arg = |key: &str| args.as_ref().and_then(|args| args.get(key));
+    match name {
+        "shell" | "container.exec" | "local_shell" => {
+            if let Some(command) =
+                arg("command").and_then(|c| serde_json::from_value::<Vec<String>>(c.clone()).ok())
+            {
+                return command_entry(&command);
+            }
+        }
+        "shell_command" | "exec_command" => {
+            if let Some(command) = arg("command")
+                .or_else(|| arg("cmd"))
+                .and_then(serde_json::Value::as_str)
+            {
+                return TranscriptEntry::Command {
+                    command: command.to_string(),
+                    exit_code: None,
+                    output: None,
+                };
+            }
+        }
+        "apply_patch" => {
+            if let Some(patch) = arg("input").and_then(serde_json::Value::as_str) {
+                return TranscriptEntry::Patch {
+                    diff: patch_to_unified_diff(patch),
+                    output: None,
+                };
+            }
+        }
+        _ => {}
+    }
+    TranscriptEntry::ToolCall {
+        name: name.to_string(),
+        input: arguments.to_string(),
+        output: None,
+    }
+}
+
+fn command_entry(command: &[String]) -> TranscriptEntry {
+    if let [program, patch] = command
+        && program == "apply_patch"
+    {
+        return TranscriptEntry::Patch {
+            diff: patch_to_unified_diff(patch),
+            output: None,
+        };
+    }
+    let command = match extract_bash_command(command) {
+        Some((_, script)) => script.to_string(),
+        None => shlex_join(command),
+    };
+    TranscriptEntry::Command {
+        command,
+        exit_code: None,
+        output: None,
+    }
+}
+
+fn function_output_text(output: &FunctionCallOutputPayload) -> String {
+    output.body.to_text().unwrap_or_default()
+}
+
+fn attach_output(entry: &mut TranscriptEntry, text: String) {
+    match entry {
+        TranscriptEntry::Command {
+            exit_code, output, ..
+        } => {
+            let (code, text) = split_exec_output(&text);
+            *exit_code = code;
+            *output = Some(text);
+        }
+        TranscriptEntry::Patch { output, .. } | TranscriptEntry::ToolCall { output, .. } => {
+            *output = Some(split_exec_output(&text).1);
+        }
+        _ => {}
+    }
+}
+
+/// Pull the exit code and the command output out of a shell tool result.
+///
+/// Handles both the JSON (`{"output": ..., "metadata": {"exit_code": ...}}`)
+/// and the plain-text (`Exit code: N ... Output:`) result formats; anything
+/// else is returned unchanged.
+fn split_exec_output(text: &str) -> (Option<i32>, String) {
+    if let Ok(value) = serde_json::from_str::<serde_json::Value>(text)
+        && let Some(output) = value.get("output").and_then(serde_json::Value::as_str)
+    {
+        let exit_code = value
+            .pointer("/metadata/exit_code")
+            .and_then(serde_json::Value::as_i64)
+            .and_then(|code| i32::try_from(code).ok());
+        return (exit_code, output.to_string());
+    }
+    if let Some(rest) = text.strip_prefix("Exit code: ")
+        && let Some((code, rest)) = rest.split_once('\n')
+        && let Ok(code) = code.trim().parse::<i32>()
+    {
+        let output = rest
+            .split_once("Output:\n")
+            .map_or(rest, |(_, output)| output);
+        return (Some(code), output.to_string());
+    }
+    (None, text.to_string())
+}
+
+/// Rewrite an `apply_patch` envelope as a unified diff, which renderers and
+/// readers already know how to display.
+fn patch_to_unified_diff(patch: &str) -> String {
+    let mut out = String::new();
+    for line in patch.lines() {
+        if let Some(path) = line.strip_prefix("*** Add File: ") {
+            let _ = writeln!(out, "--- /dev/null\n+++ b/{path}");
+        } else if let Some(path) = line.strip_prefix("*** Delete File: ") {
+            let _ = writeln!(out, "--- a/{path}\n+++ /dev/null");
+        } else if let Some(path) = line.strip_prefix("*** Update File: ") {
+            let _ = writeln!(out, "--- a/{path}\n+++ b/{path}");
+        } else if let Some(path) = line.strip_prefix("*** Move to: ") {
+            // Replace the `+++` line written for the preceding update.
+            if let Some(start) = out.trim_end_matches('\n').rfind('\n') {
+                out.truncate(start + 1);
+            }
+            let _ = writeln!(out, "+++ b/{path}");
+        } else if line.starts_with("*** ") {
+            // `*** Begin Patch`, `*** End Patch` and `*** End of File`.
+        } else {
+            out.push_str(line);
+            out.push('\n');
+        }
+    }
+    out
+}
+
+fn redact_transcript(transcript: &mut ExportedTranscript, secrets: &CommandSecrets) {
+    let redact = |text: &mut String| {
+        if let Cow::Owned(redacted) = secrets.redact(text) {
+            *text = redacted;
+        }
+    };
+    for entry in &mut transcript.entries {
+        match entry {
+            TranscriptEntry::User { text }
+            | TranscriptEntry::Assistant { text }
+            | TranscriptEntry::Reasoning { text }
+            | TranscriptEntry::Review { text } => redact(text),
+            TranscriptEntry::Command {
+                command, output, ..
+            } => {
+                redact(command);
+                output.iter_mut().for_each(redact);
+            }
+            TranscriptEntry::Patch { diff, output } => {
+                redact(diff);
+                output.iter_mut().for_each(redact);
+            }
+            TranscriptEntry::ToolCall { input, output, .. } => {
+                redact(input);
+                output.iter_mut().for_each(redact);
+            }
+        }
+    }
+}
+
+fn title(transcript: &ExportedTranscript) -> String {
+    match &transcript.thread_id {
+        Some(id) => format!("Codex session {id}"),
+        None => "Codex session".to_string(),
+    }
+}
+
+fn metadata(transcript: &ExportedTranscript) -> Vec<(&'static str, String)> {
+    let mut rows = Vec::new();
+    if let Some(started_at) = &transcript.started_at {
+        rows.push(("Started", started_at.clone()));
+    }
+    if let Some(cwd) = &transcript.cwd {
+        rows.push(("Working directory", cwd.display().to_string()));
+    }
+    if let Some(model) = &transcript.model {
+        rows.push(("Model", model.clone()));
+    }
+    rows
+}
+
+fn output_label(exit_code: Option<i32>) -> String {
+    match exit_code {
+        Some(code) => format!("Output (exit code {code})"),
+        None => "Output".to_string(),
+    }
+}
+
+/// Wrap `body` in a code fence long enough not to be closed by its contents.
+fn fenced(info: &str, body: &str) -> String {
+    let longest_run = body.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+    let fence = "`".repeat(longest_run.max(2) + 1);
+    let body = body.trim_end_matches('\n');
+    format!("{fence}{info}\n{body}\n{fence}\n")
+}
+
+pub fn render_markdown(transcript: &ExportedTranscript) -> String {
+    let mut out = format!("# {}\n\n", title(transcript));
+    let rows = metadata(transcript);
+    for (label, value) in &rows {
+        let _ = writeln!(out, "- **{label}:** {value}");
+    }
+    if !rows.is_empty() {
+        out.push('\n');
+    }
+
+    for entry in &transcript.entries {
+        match entry {
+            TranscriptEntry::User { text } => {
+                let _ = writeln!(out, "## User\n\n{}\n", text.trim_end());
+            }
+            TranscriptEntry::Assistant { text } => {
+                let _ = writeln!(out, "## Assistant\n\n{}\n", text.trim_end());
+            }
+            TranscriptEntry::Reasoning { text } => {
+                out.push_str("<details>\n<summary>Reasoning</summary>\n\n");
+                let _ = writeln!(out, "{}\n\n</details>\n", text.trim_end());
+            }
+            TranscriptEntry::Command {
+                command,
+                exit_code,
+                output,
+            } => {
+                out.push_str("### Command\n\n");
+                out.push_str(&fenced("sh", command));
+                if let Some(output) = output {
+                    let _ = writeln!(out, "\n{}:\n", output_label(*exit_code));
+                    out.push_str(&fenced("text", output));
+                }
+                out.push('\n');
+            }
+            TranscriptEntry::Patch { diff, output } => {
+                out.push_str("### Patch\n\n");
+                out.push_str(&fenced("diff", diff));
+                if let Some(output) = output {
+                    out.push_str("\nResult:\n\n");
+                    out.push_str(&fenced("text", output));
+                }
+                out.push('\n');
+            }
+            TranscriptEntry::ToolCall {
+                name,
+                input,
+                output,
+            } => {
+                let _ = writeln!(out, "### Tool call: `{name}`\n");
+                out.push_str(&fenced("json", input));
+                if let Some(output) = output {
+                    out.push_str("\nOutput:\n\n");
+                    out.push_str(&fenced("text", output));
+                }
+                out.push('\n');
+            }
+            TranscriptEntry::Review { text } => {
+                let _ = writeln!(out, "## Review\n\n{}\n", text.trim_end());
+            }
+        }
+    }
+    let trimmed = out.trim_end().len();
+    out.truncate(trimmed);
+    out.push('\n');
+    out
+}
+
+fn escape_html(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+const HTML_STYLE: &str = "body{font-family:system-ui,sans-serif;max-width:60rem;margin:2rem auto;padding:0 1rem;line-height:1.5}\
+section{margin:1rem 0;padding:.5rem 1rem;border-left:4px solid #ccc}\
+.user{border-color:#0969da}.assistant{border-color:#1a7f37}.review{border-color:#8250df}\
+h2,h3{margin:.25rem 0;font-size:1rem}\
+pre{background:#f6f8fa;padding:.75rem;overflow-x:auto;white-space:pre-wrap}\
+.text{white-space:pre-wrap}.add{color:#1a7f37}.del{color:#cf222e}.hunk{color:#8250df}\
+details{color:#57606a}";
+
+fn html_text(out: &mut String, class: &str, heading: &str, text: &str) {
+    let _ = writeln!(
+        out,
+        "<section class=\"{class}\"><h2>{heading}</h2><div class=\"text\">{}</div></section>",
+        escape_html(text.trim_end())
+    );
+}
+
+fn html_pre(out: &mut String, label: &str, text: &str) {
+    let _ = write!(
+        out,
+        "<p>{}</p><pre>{}</pre>",
+        escape_html(label),
+        escape_html(text.trim_end_matches('\n'))
+    );
+}
+
+fn html_diff(diff: &str) -> String {
+    let mut out = String::from("<pre>");
+    for line in diff.lines() {
+        let class = if line.starts_with("+++") || line.starts_with("---") {
+            None
+        } else if line.starts_with('+') {
+            Some("add")
+        } else if line.starts_with('-') {
+            Some("del")
+        } else if line.starts_with("@@") {
+            Some("hunk")
+        } else {
+            None
+        };
+        match class {
+            Some(class) => {
+                let _ = writeln!(out, "<span class=\"{class}\">{}</span>", escape_html(line));
+            }
+            None => {
+                let _ = writeln!(out, "{}", escape_html(line));
+            }
+        }
+    }
+    out.push_str("</pre>");
+    out
+}
+
+pub fn render_html(transcript: &ExportedTranscript) -> String {
+    let title = escape_html(&title(transcript));
+    let mut out = format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n<style>{HTML_STYLE}</style>\n</head>\n<body>\n<h1>{title}</h1>\n"
+    );
+    let rows = metadata(transcript);
+    if !rows.is_empty() {
+        out.push_str("<ul>\n");
+        for (label, value) in rows {
+            let _ = writeln!(
+                out,
+                "<li><strong>{label}:</strong> {}</li>",
+                escape_html(&value)
+            );
+        }
+        out.push_str("</ul>\n");
+    }
+
+    for entry in &transcript.entries {
+        match entry {
+            TranscriptEntry::User { text } => html_text(&mut out, "user", "User", text),
+            TranscriptEntry::Assistant { text } => {
+                html_text(&mut out, "assistant", "Assistant", text);
+            }
+            TranscriptEntry::Reasoning { text } => {
+                let _ = writeln!(
+                    out,
+                    "<details><summary>Reasoning</summary><div class=\"text\">{}</div></details>",
+                    escape_html(text.trim_end())
+                );
+            }
+            TranscriptEntry::Command {
+                command,
+                exit_code,
+                output,
+            } => {
+                let _ = write!(
+                    out,
+                    "<section class=\"command\"><h3>Command</h3><pre>{}</pre>",
+                    escape_html(command)
+                );
+                if let Some(output) = output {
+                    html_pre(&mut out, &output_label(*exit_code), output);
+                }
+                out.push_str("</section>\n");
+            }
+            TranscriptEntry::Patch { diff, output } => {
+                let _ = write!(
+                    out,
+                    "<section class=\"patch\"><h3>Patch</h3>{}",
+                    html_diff(diff)
+                );
+                if let Some(output) = output {
+                    html_pre(&mut out, "Result", output);
+                }
+                out.push_str("</section>\n");
+            }
+            TranscriptEntry::ToolCall {
+                name,
+                input,
+                output,
+            } => {
+                let _ = write!(
+                    out,
+                    "<section class=\"tool\"><h3>Tool call: <code>{}</code></h3><pre>{}</pre>",
+                    escape_html(name),
+                    escape_html(input)
+                );
+                if let Some(output) = output {
+                    html_pre(&mut out, "Output", output);
+                }
+                out.push_str("</section>\n");
+            }
+            TranscriptEntry::Review { text } => html_text(&mut out, "review", "Review", text),
+        }
+    }
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::types::SecretExposure;
+    use codex_protocol::ThreadId;
+    use codex_protocol::protocol::AgentMessageEvent;
+    use codex_protocol::protocol::SessionMeta;
+    use codex_protocol::protocol::SessionMetaLine;
+    use codex_protocol::protocol::ThreadRolledBackEvent;
+    use codex_protocol::protocol::UserMessageEvent;
+    use pretty_assertions::assert_eq;
+
+    fn user(text: &str) -> RolloutItem {
+        RolloutItem::EventMsg(EventMsg::UserMessage(UserMessageEvent {
+            message: text.to_string(),
+            images: None,
+            local_images: Vec::new(),
+            text_elements: Vec::new(),
+        }))
+    }
+
+    fn assistant(text: &str) -> RolloutItem {
+        RolloutItem::EventMsg(EventMsg::AgentMessage(AgentMessageEvent {
+            message: text.to_string(),
+        }))
+    }
+
+    fn function_call(name: &str, arguments: &str, call_id: &str) -> RolloutItem {
+        RolloutItem::ResponseItem(ResponseItem::FunctionCall {
+            id: None,
+            name: name.to_string(),
+            arguments: arguments.to_string(),
+            call_id: call_id.to_string(),
+        })
+    }
+
+    fn function_output(call_id: &str, text: &str) -> RolloutItem {
+        RolloutItem::ResponseItem(ResponseItem::FunctionCallOutput {
+            call_id: call_id.to_string(),
+            output: FunctionCallOutputPayload::from_text(text.to_string()),
+        })
+    }
+
+    fn sample_items() -> Vec<RolloutItem> {
+        vec![
+            RolloutItem::SessionMeta(SessionMetaLine {
+                meta: SessionMeta {
+                    id: ThreadId::default(),
+                    timestamp: "2025-01-01T00:00:00Z".to_string(),
+                    cwd: PathBuf::from("/repo"),
+                    ..Default::default()
+                },
+                git: None,
+            }),
+            user("Run the migration"),
+            function_call(
+                "shell",
+                r#"{"command":["bash","-lc","sqlx migrate run"]}"#,
+                "call-1",
+            ),
+            function_output(
+                "call-1",
+                r#"{"output":"Applied 2 migrations\n","metadata":{"exit_code":0}}"#,
+            ),
+            RolloutItem::ResponseItem(ResponseItem::CustomToolCall {
+                id: None,
+                status: None,
+                call_id: "call-2".to_string(),
+                name: "apply_patch".to_string(),
+                input: "*** Begin Patch\n*** Update File: a.txt\n@@\n-old\n+new\n*** End Patch"
+                    .to_string(),
+            }),
+            assistant("Done. The key is sk-abcdefghijklmnopqrstuvwxyz"),
+        ]
+    }
+
+    #[test]
+    fn builds_entries_with_outputs_attached() {
+        let transcript = build_transcript(&sample_items(), &ExportOptions::default());
+        assert_eq!(transcript.cwd, Some(PathBuf::from("/repo")));
+        assert_eq!(
+            transcript.entries,
+            vec![
+                TranscriptEntry::User {
+                    text: "Run the migration".to_string()
+                },
+                TranscriptEntry::Command {
+                    command: "sqlx migrate run".to_string(),
+                    exit_code: Some(0),
+                    output: Some("Applied 2 migrations\n".to_string()),
+                },
+                TranscriptEntry::Patch {
+                    diff: "--- a/a.txt\n+++ b/a.txt\n@@\n-old\n+new\n".to_string(),
+                    output: None,
+                },
+                TranscriptEntry::Assistant {
+                    text: "Done. The key is sk-abcdefghijklmnopqrstuvwxyz".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn options_redact_secrets_and_omit_tool_output() {
+        let options = ExportOptions {
+            redact: CommandSecrets::new(vec![(
+                SecretExposure {
+                    name: "OPENAI_API_KEY".to_string(),
+                    commands: None,
+                },
+                "sk-abcdefghijklmnopqrstuvwxyz".to_string(),
+            )]),
+            omit_tool_output: true,
+        };
+        let markdown = export_transcript(&sample_items(), ExportFormat::Markdown, &options);
+        assert!(markdown.contains("Done. The key is [REDACTED:OPENAI_API_KEY]"));
+        assert!(!markdown.contains("sk-abc"));
+        assert!(!markdown.contains("Applied 2 migrations"));
+        assert!(markdown.contains("```sh\nsqlx migrate run\n```"));
+        assert!(markdown.contains("```diff\n--- a/a.txt\n+++ b/a.txt\n@@\n-old\n+new\n```"));
+    }
+
+    #[test]
+    fn rolled_back_turns_are_dropped() {
+        let mut items = sample_items();
+        items.push(user("Now break everything"));
+        items.push(assistant("Broken."));
+        items.push(RolloutItem::EventMsg(EventMsg::ThreadRolledBack(
+            ThreadRolledBackEvent { num_turns: 1 },
+        )));
+        let transcript = build_transcript(&items, &ExportOptions::default());
+        assert_eq!(transcript.entries.len(), 4);
+    }
+
+    #[test]
+    fn html_escapes_and_highlights_diffs() {
+        let html = export_transcript(
+            &[user("<script>alert(1)</script>")],
+            ExportFormat::Html,
+            &ExportOptions::default(),
+        );
+        assert!(html.contains("&lt;script&gt;alert(1)&lt;/script&gt;"));
+        assert!(!html.contains("<script>"));
+        assert_eq!(
+            html_diff("--- a/x\n+++ b/x\n-a\n+b\n"),
+            "<pre>--- a/x\n+++ b/x\n<span class=\"del\">-a</span>\n<span class=\"add\">+b</span>\n</pre>"
+        );
+    }
+
+    #[test]
+    fn markdown_fences_outlast_backticks_in_content() {
+        assert_eq!(fenced("text", "a ``` b\n"), "````text\na ``` b\n````\n");
+    }
+}
@@ -11,13 +11,16 @@ use super::UnifiedExecContext;
 use super::process::UnifiedExecProcess;
 use crate::codex::Session;
 use crate::codex::TurnContext;
+use crate::command_secrets::StreamRedactor;
 use crate::exec::ExecToolCallOutput;
 use crate::exec::MAX_EXEC_OUTPUT_DELTAS_PER_CALL;
 use crate::exec::StreamOutput;
+use crate::protocol::BackgroundProcessItem;
 use crate::protocol::EventMsg;
 use crate::protocol::ExecCommandOutputDeltaEvent;
 use crate::protocol::ExecCommandSource;
 use crate::protocol::ExecOutputStream;
+use crate::protocol::RolloutItem;
 use crate::tools::events::ToolEmitter;
 use crate::tools::events::ToolEventCtx;
 use crate::tools::events::ToolEventStage;
@@ -54,6 +57,7 @@ pub(crate) fn start_streaming_output(
 
         let mut pending = Vec::<u8>::new();
         let mut emitted_deltas: usize = 0;
+        let mut redactor = StreamRedactor::new(session_ref.services.command_secrets.clone());
 
         let mut grace_sleep: Option<Pin<Box<Sleep>>> = None;
 
@@ -69,6 +73,13 @@ pub(crate) fn start_streaming_output(
                         sleep.as_mut().await;
                     }
                 }, if grace_sleep.is_some() => {
+                    flush_redacted_output(
+                        &mut redactor,
+                        &call_id,
+                        &session_ref,
+                        &turn_ref,
+                        emitted_deltas,
+                    ).await;
                     output_drained.notify_one();
                     break;
                 }
@@ -80,6 +91,13 @@ pub(crate) fn start_streaming_output(
                             continue;
                         },
                         Err(RecvError::Closed) => {
+                            flush_redacted_output(
+                                &mut redactor,
+                                &call_id,
+                                &session_ref,
+                                &turn_ref,
+                                emitted_deltas,
+                            ).await;
                             output_drained.notify_one();
                             break;
                         }
@@ -87,6 +105,7 @@ pub(crate) fn start_streaming_output(
 
                     process_chunk(
                         &mut pending,
+                        &mut redactor,
                         &transcript,
                         &call_id,
                         &session_ref,
@@ -123,6 +142,16 @@ pub(crate) fn spawn_exit_watcher(
 
         let exit_code = process.exit_code().unwrap_or(-1);
         let duration = Instant::now().saturating_duration_since(started_at);
+        // Processes stopped by a shutdown stay restorable on resume.
+        if !process.is_restorable() {
+            session_ref
+                .persist_rollout_items(&[RolloutItem::BackgroundProcess(
+                    BackgroundProcessItem::Exited {
+                        process_id: process_id.clone(),
+                    },
+                )])
+                .await;
+        }
         emit_exec_end_for_unified_exec(
             session_ref,
             turn_ref,
@@ -139,8 +168,10 @@ pub(crate) fn spawn_exit_watcher(
     });
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn process_chunk(
     pending: &mut Vec<u8>,
+    redactor: &mut StreamRedactor,
     transcript: &Arc<Mutex<HeadTailBuffer>>,
     call_id: &str,
     session_ref: &Arc<Session>,
@@ -159,10 +190,15 @@ async fn process_chunk(
             continue;
         }
 
+        // Secrets split across reads are held back until they can be redacted.
+        let chunk = redactor.push(&prefix);
+        if chunk.is_empty() {
+            continue;
+        }
         let event = ExecCommandOutputDeltaEvent {
             call_id: call_id.to_string(),
             stream: ExecOutputStream::Stdout,
-            chunk: prefix,
+            chunk,
         };
         session_ref
             .send_event(turn_ref.as_ref(), EventMsg::ExecCommandOutputDelta(event))
@@ -171,6 +207,28 @@ async fn process_chunk(
     }
 }
 
+/// Emits output the redactor was still holding back once the process is done.
+async fn flush_redacted_output(
+    redactor: &mut StreamRedactor,
+    call_id: &str,
+    session_ref: &Arc<Session>,
+    turn_ref: &Arc<TurnContext>,
+    emitted_deltas: usize,
+) {
+    let chunk = redactor.finish();
+    if chunk.is_empty() || emitted_deltas >= MAX_EXEC_OUTPUT_DELTAS_PER_CALL {
+        return;
+    }
+    let event = ExecCommandOutputDeltaEvent {
+        call_id: call_id.to_string(),
+        stream: ExecOutputStream::Stdout,
+        chunk,
+    };
+    session_ref
+        .send_event(turn_ref.as_ref(), EventMsg::ExecCommandOutputDelta(event))
+        .await;
+}
+
 /// Emit an ExecCommandEnd event for a unified exec session, using the transcript
 /// as the primary source of aggregated_output and falling back to the provided
 /// text when the transcript is empty.
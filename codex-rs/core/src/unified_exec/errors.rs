@@ -14,6 +14,8 @@ pub(crate) enum UnifiedExecError {
         "stdin is closed for this session; rerun exec_command with tty=true to keep stdin open"
     )]
     StdinClosed,
+    #[error("failed to signal process: {message}")]
+    SignalProcess { message: String },
     #[error("missing command line for unified exec request")]
     MissingCommandLine,
     #[error("Command denied by sandbox: {message}")]
@@ -20,6 +20,7 @@
 //! concerns remain isolated here. The implementation is split between:
 //! - `process.rs`: PTY process lifecycle + output buffering.
 //! - `process_manager.rs`: orchestration (approvals, sandboxing, reuse) and request handling.
+//! - `restore.rs`: offering processes from a resumed rollout for relaunch.
 
 use std::collections::HashMap;
 use std::collections::HashSet;
@@ -33,6 +34,7 @@ use tokio::sync::Mutex;
 
 use crate::codex::Session;
 use crate::codex::TurnContext;
+use crate::command_secrets::CommandSecrets;
 use crate::sandboxing::SandboxPermissions;
 
 mod async_watcher;
@@ -40,9 +42,15 @@ mod errors;
 mod head_tail_buffer;
 mod process;
 mod process_manager;
+mod restore;
+
+use head_tail_buffer::HeadTailBuffer;
 
 pub(crate) use errors::UnifiedExecError;
 pub(crate) use process::UnifiedExecProcess;
+pub(crate) use restore::RelaunchOutcome;
+pub(crate) use restore::relaunch_message;
+pub(crate) use restore::restorable_processes;
 
 pub(crate) const MIN_YIELD_TIME_MS: u64 = 250;
 // Minimum yield time for an empty `write_stdin`.
@@ -137,8 +145,12 @@ struct ProcessEntry {
     call_id: String,
     process_id: String,
     command: Vec<String>,
+    cwd: PathBuf,
     tty: bool,
+    started_at: tokio::time::Instant,
     last_used: tokio::time::Instant,
+    secrets: CommandSecrets,
+    transcript: Arc<tokio::sync::Mutex<HeadTailBuffer>>,
 }
 
 pub(crate) fn clamp_yield_time(yield_time_ms: u64) -> u64 {
@@ -1,6 +1,8 @@
 #![allow(clippy::module_inception)]
 
 use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
 use tokio::sync::Mutex;
 use tokio::sync::Notify;
 use tokio::sync::mpsc;
@@ -16,6 +18,7 @@ use crate::exec::is_likely_sandbox_denied;
 use crate::truncate::TruncationPolicy;
 use crate::truncate::formatted_truncate_text;
 use codex_utils_pty::ExecCommandSession;
+use codex_utils_pty::ProcessSignal;
 use codex_utils_pty::SpawnedPty;
 
 use super::UNIFIED_EXEC_OUTPUT_MAX_TOKENS;
@@ -38,6 +41,7 @@ pub(crate) struct UnifiedExecProcess {
     output_drained: Arc<Notify>,
     output_task: JoinHandle<()>,
     sandbox_type: SandboxType,
+    restorable: AtomicBool,
 }
 
 impl UnifiedExecProcess {
@@ -76,6 +80,7 @@ impl UnifiedExecProcess {
             output_drained,
             output_task,
             sandbox_type,
+            restorable: AtomicBool::new(false),
         }
     }
 
@@ -111,12 +116,26 @@ impl UnifiedExecProcess {
         self.process_handle.exit_code()
     }
 
+    pub(super) fn signal(&self, signal: ProcessSignal) -> std::io::Result<()> {
+        self.process_handle.signal(signal)
+    }
+
     pub(super) fn terminate(&self) {
         self.process_handle.terminate();
         self.cancellation_token.cancel();
         self.output_task.abort();
     }
 
+    /// Keeps the process offered for relaunch on resume even though it is
+    /// about to be stopped, e.g. because the session is shutting down.
+    pub(super) fn mark_restorable(&self) {
+        self.restorable.store(true, Ordering::SeqCst);
+    }
+
+    pub(super) fn is_restorable(&self) -> bool {
+        self.restorable.load(Ordering::SeqCst)
+    }
+
     async fn snapshot_output(&self) -> Vec<Vec<u8>> {
         let guard = self.output_buffer.lock().await;
         guard.snapshot_chunks()
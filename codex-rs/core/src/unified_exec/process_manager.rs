@@ -10,10 +10,18 @@ use tokio::time::Duration;
 use tokio::time::Instant;
 use tokio_util::sync::CancellationToken;
 
+use crate::command_secrets::CommandSecrets;
 use crate::exec_env::create_env;
+use crate::exec_env::inject_command_secrets;
 use crate::exec_policy::ExecApprovalRequest;
+use crate::protocol::BackgroundProcess;
+use crate::protocol::BackgroundProcessItem;
+use crate::protocol::BackgroundProcessLaunch;
+use crate::protocol::BackgroundProcessSignal;
 use crate::protocol::ExecCommandSource;
+use crate::protocol::RolloutItem;
 use crate::sandboxing::ExecEnv;
+use crate::sandboxing::SandboxPermissions;
 use crate::tools::events::ToolEmitter;
 use crate::tools::events::ToolEventCtx;
 use crate::tools::events::ToolEventStage;
@@ -46,6 +54,7 @@ use crate::unified_exec::process::OutputBuffer;
 use crate::unified_exec::process::OutputHandles;
 use crate::unified_exec::process::UnifiedExecProcess;
 use crate::unified_exec::resolve_max_tokens;
+use codex_utils_pty::ProcessSignal;
 
 const UNIFIED_EXEC_ENV: [(&str, &str); 10] = [
     ("NO_COLOR", "1"),
@@ -75,6 +84,8 @@ struct PreparedProcessHandles {
     command: Vec<String>,
     process_id: String,
     tty: bool,
+    secrets: CommandSecrets,
+    process: Arc<UnifiedExecProcess>,
 }
 
 impl UnifiedExecProcessManager {
@@ -122,8 +133,12 @@ impl UnifiedExecProcessManager {
             .clone()
             .unwrap_or_else(|| context.turn.cwd.clone());
 
+        let env = apply_unified_exec_env(create_env(
+            &context.turn.shell_environment_policy,
+            Some(context.session.conversation_id),
+        ));
         let process = self
-            .open_session_with_sandbox(&request, cwd.clone(), context)
+            .open_session_with_sandbox(&request, cwd.clone(), env, context)
             .await;
 
         let process = match process {
@@ -172,6 +187,12 @@ impl UnifiedExecProcessManager {
         )
         .await;
         let wall_time = Instant::now().saturating_duration_since(start);
+        let collected = context
+            .session
+            .services
+            .command_secrets
+            .redact_bytes(&collected)
+            .into_owned();
 
         let text = String::from_utf8_lossy(&collected).to_string();
         let output = formatted_truncate_text(&text, TruncationPolicy::Tokens(max_tokens));
@@ -238,6 +259,82 @@ impl UnifiedExecProcessManager {
         Ok(response)
     }
 
+    /// Starts a process recorded by a previous session again under a new id
+    /// and returns that id. The environment is rebuilt from the current
+    /// policy, and the process goes through the same approval and sandbox as
+    /// an `exec_command` call in the current turn.
+    pub(crate) async fn relaunch_process(
+        &self,
+        launch: BackgroundProcessLaunch,
+        context: &UnifiedExecContext,
+    ) -> Result<String, UnifiedExecError> {
+        let BackgroundProcessLaunch {
+            command, cwd, tty, ..
+        } = launch;
+        let request = ExecCommandRequest {
+            command: command.clone(),
+            process_id: self.allocate_process_id().await,
+            yield_time_ms: 0,
+            max_output_tokens: None,
+            workdir: Some(cwd.clone()),
+            tty,
+            sandbox_permissions: SandboxPermissions::UseDefault,
+            justification: None,
+            prefix_rule: None,
+        };
+        let process_id = request.process_id.clone();
+        let env = apply_unified_exec_env(create_env(
+            &context.turn.shell_environment_policy,
+            Some(context.session.conversation_id),
+        ));
+        let process = match self
+            .open_session_with_sandbox(&request, cwd.clone(), env, context)
+            .await
+        {
+            Ok(process) if !process.has_exited() => Arc::new(process),
+            Ok(process) => {
+                self.release_process_id(&process_id).await;
+                let exit_code = process.exit_code().unwrap_or(-1);
+                return Err(UnifiedExecError::create_process(format!(
+                    "process exited immediately with code {exit_code}"
+                )));
+            }
+            Err(err) => {
+                self.release_process_id(&process_id).await;
+                return Err(err);
+            }
+        };
+
+        let transcript = Arc::new(tokio::sync::Mutex::new(HeadTailBuffer::default()));
+        let event_ctx = ToolEventCtx::new(
+            context.session.as_ref(),
+            context.turn.as_ref(),
+            &context.call_id,
+            None,
+        );
+        let emitter = ToolEmitter::unified_exec(
+            &command,
+            cwd.clone(),
+            ExecCommandSource::UnifiedExecStartup,
+            Some(process_id.clone()),
+        );
+        emitter.emit(event_ctx, ToolEventStage::Begin).await;
+        start_streaming_output(&process, context, Arc::clone(&transcript));
+
+        self.store_process(
+            process,
+            context,
+            &command,
+            cwd,
+            Instant::now(),
+            process_id.clone(),
+            tty,
+            transcript,
+        )
+        .await;
+        Ok(process_id)
+    }
+
     pub(crate) async fn write_stdin(
         &self,
         request: WriteStdinRequest<'_>,
@@ -252,6 +349,7 @@ impl UnifiedExecProcessManager {
             command: session_command,
             process_id,
             tty,
+            secrets,
             ..
         } = self.prepare_process_handles(process_id.as_str()).await?;
 
@@ -284,6 +382,7 @@ impl UnifiedExecProcessManager {
         )
         .await;
         let wall_time = Instant::now().saturating_duration_since(start);
+        let collected = secrets.redact_bytes(&collected).into_owned();
 
         let text = String::from_utf8_lossy(&collected).to_string();
         let output = formatted_truncate_text(&text, TruncationPolicy::Tokens(max_tokens));
@@ -380,6 +479,8 @@ impl UnifiedExecProcessManager {
             command: entry.command.clone(),
             process_id: entry.process_id.clone(),
             tty: entry.tty,
+            secrets: entry.secrets.clone(),
+            process: Arc::clone(&entry.process),
         })
     }
 
@@ -405,13 +506,23 @@ impl UnifiedExecProcessManager {
         tty: bool,
         transcript: Arc<tokio::sync::Mutex<HeadTailBuffer>>,
     ) {
+        let launch = BackgroundProcessLaunch {
+            process_id: process_id.clone(),
+            command: command.to_vec(),
+            cwd: cwd.clone(),
+            tty,
+        };
         let entry = ProcessEntry {
             process: Arc::clone(&process),
             call_id: context.call_id.clone(),
             process_id: process_id.clone(),
             command: command.to_vec(),
+            cwd: cwd.clone(),
             tty,
+            started_at,
             last_used: started_at,
+            secrets: context.session.services.command_secrets.clone(),
+            transcript: Arc::clone(&transcript),
         };
         let number_processes = {
             let mut store = self.process_store.lock().await;
@@ -419,6 +530,12 @@ impl UnifiedExecProcessManager {
             store.processes.insert(process_id.clone(), entry);
             store.processes.len()
         };
+        context
+            .session
+            .persist_rollout_items(&[RolloutItem::BackgroundProcess(
+                BackgroundProcessItem::Started(launch),
+            )])
+            .await;
 
         if number_processes >= WARNING_UNIFIED_EXEC_PROCESSES {
             context
@@ -481,12 +598,14 @@ impl UnifiedExecProcessManager {
         &self,
         request: &ExecCommandRequest,
         cwd: PathBuf,
+        mut env: HashMap<String, String>,
         context: &UnifiedExecContext,
     ) -> Result<UnifiedExecProcess, UnifiedExecError> {
-        let env = apply_unified_exec_env(create_env(
-            &context.turn.shell_environment_policy,
-            Some(context.session.conversation_id),
-        ));
+        inject_command_secrets(
+            &mut env,
+            &request.command,
+            &context.session.services.command_secrets,
+        );
         let features = context.session.features();
         let mut orchestrator = ToolOrchestrator::new();
         let mut runtime = UnifiedExecRuntime::new(self);
@@ -501,6 +620,8 @@ impl UnifiedExecProcessManager {
                 sandbox_policy: &context.turn.sandbox_policy,
                 sandbox_permissions: request.sandbox_permissions,
                 prefix_rule: request.prefix_rule.clone(),
+                cwd: &cwd,
+                approval_rules: &context.turn.config.approval_rules,
             })
             .await;
         let req = UnifiedExecToolRequest::new(
@@ -641,6 +762,122 @@ impl UnifiedExecProcessManager {
             .map(|(process_id, _, _)| process_id)
     }
 
+    /// Snapshot of the tracked processes, oldest first, for user-facing listings.
+    pub(crate) async fn list_processes(&self) -> Vec<BackgroundProcess> {
+        let store = self.process_store.lock().await;
+        let mut entries: Vec<&ProcessEntry> = store.processes.values().collect();
+        entries.sort_by_key(|entry| entry.started_at);
+        entries
+            .into_iter()
+            .map(|entry| BackgroundProcess {
+                process_id: entry.process_id.clone(),
+                command: entry.command.clone(),
+                cwd: entry.cwd.clone(),
+                tty: entry.tty,
+            })
+            .collect()
+    }
+
+    /// Returns the retained transcript of a process with secrets redacted, and
+    /// whether the process is still running.
+    pub(crate) async fn process_output(
+        &self,
+        process_id: &str,
+    ) -> Result<(Vec<u8>, bool), UnifiedExecError> {
+        let (transcript, secrets, running) = {
+            let store = self.process_store.lock().await;
+            let entry =
+                store
+                    .processes
+                    .get(process_id)
+                    .ok_or(UnifiedExecError::UnknownProcessId {
+                        process_id: process_id.to_string(),
+                    })?;
+            (
+                Arc::clone(&entry.transcript),
+                entry.secrets.clone(),
+                !entry.process.has_exited(),
+            )
+        };
+        let bytes = transcript.lock().await.to_bytes();
+        Ok((secrets.redact_bytes(&bytes).into_owned(), running))
+    }
+
+    /// Writes input typed by the user to a process. Unlike `write_stdin` this
+    /// does not wait for output; the caller polls `process_output` instead.
+    pub(crate) async fn write_user_input(
+        &self,
+        process_id: &str,
+        input: &[u8],
+    ) -> Result<(), UnifiedExecError> {
+        let PreparedProcessHandles { writer_tx, tty, .. } =
+            self.prepare_process_handles(process_id).await?;
+        if !tty {
+            return Err(UnifiedExecError::StdinClosed);
+        }
+        Self::send_input(&writer_tx, input).await
+    }
+
+    /// Delivers a user-requested signal to a process. `Kill` also stops
+    /// tracking the process, like pruning does.
+    pub(crate) async fn signal_process(
+        &self,
+        process_id: &str,
+        signal: BackgroundProcessSignal,
+    ) -> Result<(), UnifiedExecError> {
+        match signal {
+            BackgroundProcessSignal::Kill => {
+                let entry = self.process_store.lock().await.remove(process_id).ok_or(
+                    UnifiedExecError::UnknownProcessId {
+                        process_id: process_id.to_string(),
+                    },
+                )?;
+                entry.process.terminate();
+                Ok(())
+            }
+            BackgroundProcessSignal::Interrupt => {
+                let PreparedProcessHandles {
+                    writer_tx,
+                    tty,
+                    process,
+                    ..
+                } = self.prepare_process_handles(process_id).await?;
+                if tty {
+                    // Let the terminal's line discipline deliver SIGINT to the
+                    // foreground job, exactly as a keypress would.
+                    Self::send_input(&writer_tx, b"\x03").await
+                } else {
+                    Self::deliver_signal(&process, ProcessSignal::Interrupt)
+                }
+            }
+            BackgroundProcessSignal::Terminate => {
+                let PreparedProcessHandles { process, .. } =
+                    self.prepare_process_handles(process_id).await?;
+                Self::deliver_signal(&process, ProcessSignal::Terminate)
+            }
+        }
+    }
+
+    fn deliver_signal(
+        process: &UnifiedExecProcess,
+        signal: ProcessSignal,
+    ) -> Result<(), UnifiedExecError> {
+        process
+            .signal(signal)
+            .map_err(|err| UnifiedExecError::SignalProcess {
+                message: err.to_string(),
+            })
+    }
+
+    /// Marks every tracked process as restorable so stopping them during
+    /// shutdown does not record them as exited in the rollout.
+    pub(crate) async fn preserve_for_resume(&self) {
+        let store = self.process_store.lock().await;
+        for entry in store.processes.values() {
+            entry.process.mark_restorable();
+        }
+    }
+
     pub(crate) async fn terminate_all_processes(&self) {
         let entries: Vec<ProcessEntry> = {
             let mut processes = self.process_store.lock().await;
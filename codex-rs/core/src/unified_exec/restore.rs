@@ -0,0 +1,152 @@
+use std::fmt::Write as _;
+
+use crate::parse_command::extract_shell_command;
+use crate::parse_command::shlex_join;
+use crate::protocol::BackgroundProcessItem;
+use crate::protocol::BackgroundProcessLaunch;
+use crate::protocol::RolloutItem;
+
+/// Replays the background process markers of a rollout and returns the
+/// processes that had not exited when it was last written, oldest first.
+pub(crate) fn restorable_processes(items: &[RolloutItem]) -> Vec<BackgroundProcessLaunch> {
+    let mut running: Vec<BackgroundProcessLaunch> = Vec::new();
+    for item in items {
+        match item {
+            RolloutItem::BackgroundProcess(BackgroundProcessItem::Started(launch)) => {
+                running.retain(|existing| existing.process_id != launch.process_id);
+                running.push(launch.clone());
+            }
+            RolloutItem::BackgroundProcess(BackgroundProcessItem::Exited { process_id }) => {
+                running.retain(|existing| &existing.process_id != process_id);
+            }
+            _ => {}
+        }
+    }
+    running
+}
+
+/// What happened to one process from a previous session after resume.
+pub(crate) enum RelaunchOutcome {
+    Relaunched {
+        process_id: String,
+    },
+    Failed {
+        error: String,
+    },
+    /// The user chose not to relaunch it.
+    Skipped,
+}
+
+/// Developer message telling the model which process ids from before the
+/// resume map to relaunched processes, and which ones are gone.
+pub(crate) fn relaunch_message(results: &[(BackgroundProcessLaunch, RelaunchOutcome)]) -> String {
+    let mut message = String::from(
+        "The session was resumed and background processes from the previous session were \
+         stopped. Process ids from before the resume are no longer valid.\n",
+    );
+    for (launch, outcome) in results {
+        let command = extract_shell_command(&launch.command)
+            .map(|(_, script)| script.to_string())
+            .unwrap_or_else(|| shlex_join(&launch.command));
+        let old_id = &launch.process_id;
+        match outcome {
+            RelaunchOutcome::Relaunched { process_id } => {
+                let _ = writeln!(
+                    message,
+                    "- `{command}` was relaunched: use process id {process_id} instead of {old_id}."
+                );
+            }
+            RelaunchOutcome::Failed { error } => {
+                let _ = writeln!(
+                    message,
+                    "- `{command}` (process id {old_id}) could not be relaunched: {error}"
+                );
+            }
+            RelaunchOutcome::Skipped => {
+                let _ = writeln!(
+                    message,
+                    "- `{command}` (process id {old_id}) was not relaunched."
+                );
+            }
+        }
+    }
+    message
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::path::PathBuf;
+
+    fn launch(process_id: &str, command: &[&str]) -> BackgroundProcessLaunch {
+        BackgroundProcessLaunch {
+            process_id: process_id.to_string(),
+            command: command.iter().map(ToString::to_string).collect(),
+            cwd: PathBuf::from("/workspace"),
+            tty: true,
+        }
+    }
+
+    fn started(process_id: &str, command: &[&str]) -> RolloutItem {
+        RolloutItem::BackgroundProcess(BackgroundProcessItem::Started(launch(process_id, command)))
+    }
+
+    fn exited(process_id: &str) -> RolloutItem {
+        RolloutItem::BackgroundProcess(BackgroundProcessItem::Exited {
+            process_id: process_id.to_string(),
+        })
+    }
+
+    #[test]
+    fn restorable_processes_skip_exited_and_keep_order() {
+        let items = vec![
+            started("1000", &["npm", "run", "dev"]),
+            started("1001", &["cargo", "watch"]),
+            started("1002", &["tail", "-f", "log"]),
+            exited("1001"),
+            // Ids can be reused once a process is gone.
+            exited("1000"),
+            started("1000", &["npm", "run", "dev"]),
+        ];
+
+        assert_eq!(
+            restorable_processes(&items),
+            vec![
+                launch("1002", &["tail", "-f", "log"]),
+                launch("1000", &["npm", "run", "dev"]),
+            ]
+        );
+    }
+
+    #[test]
+    fn relaunch_message_lists_new_ids_failures_and_skipped() {
+        let message = relaunch_message(&[
+            (
+                launch("1000", &["npm", "run", "dev"]),
+                RelaunchOutcome::Relaunched {
+                    process_id: "2000".to_string(),
+                },
+            ),
+            (
+                launch("1001", &["cargo", "watch"]),
+                RelaunchOutcome::Failed {
+                    error: "process exited immediately with code 1".to_string(),
+                },
+            ),
+            (
+                launch("1002", &["bash", "-lc", "tail -f log"]),
+                RelaunchOutcome::Skipped,
+            ),
+        ]);
+
+        assert_eq!(
+            message,
+            "The session was resumed and background processes from the previous session were \
+             stopped. Process ids from before the resume are no longer valid.\n\
+             - `npm run dev` was relaunched: use process id 2000 instead of 1000.\n\
+             - `cargo watch` (process id 1001) could not be relaunched: process exited immediately with code 1\n\
+             - `tail -f log` (process id 1002) was not relaunched.\n"
+        );
+    }
+}
@@ -0,0 +1,693 @@
+//! Minimal HTML to Markdown conversion for `web_fetch`.
+//!
+//! This is not a conforming HTML parser. It walks the tag stream, drops
+//! elements that never carry readable content and maps the common block and
+//! inline elements onto Markdown, which is enough to make documentation pages
+//! readable by the model without pulling in a DOM implementation.
+
+use std::borrow::Cow;
+use url::Url;
+
+/// Elements whose contents are dropped entirely.
+const SKIPPED_ELEMENTS: &[&str] = &[
+    "script", "style", "noscript", "svg", "template", "iframe", "canvas", "object",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ConvertedHtml {
+    pub(crate) title: Option<String>,
+    pub(crate) markdown: String,
+}
+
+/// Converts `html` to Markdown, resolving relative links against `base_url`.
+pub(crate) fn html_to_markdown(html: &str, base_url: Option<&Url>) -> ConvertedHtml {
+    let mut writer = MarkdownWriter::new(base_url);
+    let mut title = None;
+    let mut rest = html;
+    while !rest.is_empty() {
+        let Some(lt) = rest.find('<') else {
+            writer.text(rest);
+            break;
+        };
+        writer.text(&rest[..lt]);
+        rest = &rest[lt..];
+
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+        let Some(tag) = Tag::parse(rest) else {
+            // A `<` that does not open a tag is literal text.
+            writer.text("<");
+            rest = &rest[1..];
+            continue;
+        };
+        rest = &rest[tag.len..];
+
+        // Doctype declarations and processing instructions.
+        if tag.name.starts_with(['!', '?']) {
+            continue;
+        }
+        if !tag.closing
+            && !tag.self_closing
+            && (tag.name == "title" || SKIPPED_ELEMENTS.contains(&tag.name.as_str()))
+        {
+            let (inner, after) = split_raw_text(rest, &tag.name);
+            if tag.name == "title" && title.is_none() {
+                let text = collapse_whitespace(&decode_entities(inner));
+                if !text.is_empty() {
+                    title = Some(text);
+                }
+            }
+            rest = after;
+            continue;
+        }
+        writer.tag(&tag);
+    }
+
+    ConvertedHtml {
+        title,
+        markdown: writer.finish(),
+    }
+}
+
+struct Tag<'a> {
+    name: String,
+    attrs: &'a str,
+    closing: bool,
+    self_closing: bool,
+    /// Length of the tag in the source, including the angle brackets.
+    len: usize,
+}
+
+impl<'a> Tag<'a> {
+    /// Parses the tag at the start of `input`, which must begin with `<`.
+    fn parse(input: &'a str) -> Option<Self> {
+        let bytes = input.as_bytes();
+        let mut pos = 1;
+        let closing = bytes.get(pos) == Some(&b'/');
+        if closing {
+            pos += 1;
+        }
+        let first = *bytes.get(pos)?;
+        if !(first.is_ascii_alphabetic() || first == b'!' || first == b'?') {
+            return None;
+        }
+        let name_start = pos;
+        pos += 1;
+        while bytes
+            .get(pos)
+            .is_some_and(|b| b.is_ascii_alphanumeric() || *b == b'-' || *b == b':')
+        {
+            pos += 1;
+        }
+        let name = input[name_start..pos].to_ascii_lowercase();
+
+        let attrs_start = pos;
+        let mut quote = None;
+        while let Some(&b) = bytes.get(pos) {
+            match (quote, b) {
+                (None, b'"' | b'\'') => quote = Some(b),
+                (Some(q), b) if b == q => quote = None,
+                (None, b'>') => break,
+                _ => {}
+            }
+            pos += 1;
+        }
+        if pos >= bytes.len() {
+            return None;
+        }
+        let attrs = &input[attrs_start..pos];
+        Some(Self {
+            name,
+            self_closing: attrs.trim_end().ends_with('/'),
+            attrs,
+            closing,
+            len: pos + 1,
+        })
+    }
+
+    /// Returns the decoded value of attribute `name`, if present.
+    fn attr(&self, name: &str) -> Option<String> {
+        let mut rest = self.attrs;
+        loop {
+            rest = rest.trim_start_matches(|c: char| c.is_whitespace() || c == '/');
+            if rest.is_empty() {
+                return None;
+            }
+            let key_end = rest
+                .find(|c: char| c.is_whitespace() || c == '=' || c == '/')
+                .unwrap_or(rest.len());
+            let key = &rest[..key_end];
+            rest = rest[key_end..].trim_start();
+            let value = match rest.strip_prefix('=') {
+                Some(after_eq) => {
+                    let after_eq = after_eq.trim_start();
+                    let (value, after) = match after_eq.chars().next() {
+                        Some(q @ ('"' | '\'')) => {
+                            let inner = &after_eq[1..];
+                            let end = inner.find(q).unwrap_or(inner.len());
+                            (&inner[..end], inner.get(end + 1..).unwrap_or(""))
+                        }
+                        _ => {
+                            let end = after_eq.find(char::is_whitespace).unwrap_or(after_eq.len());
+                            (&after_eq[..end], &after_eq[end..])
+                        }
+                    };
+                    rest = after;
+                    value
+                }
+                None => "",
+            };
+            if key.eq_ignore_ascii_case(name) {
+                return Some(decode_entities(value).into_owned());
+            }
+        }
+    }
+}
+
+/// Splits `input` at the closing tag for raw-text element `name`, returning
+/// the element contents and the remainder after the closing tag.
+fn split_raw_text<'a>(input: &'a str, name: &str) -> (&'a str, &'a str) {
+    for (idx, _) in input.match_indices("</") {
+        let matches_name = input.as_bytes()[idx + 2..]
+            .get(..name.len())
+            .is_some_and(|candidate| candidate.eq_ignore_ascii_case(name.as_bytes()));
+        if matches_name {
+            let after = input[idx..]
+                .find('>')
+                .map_or("", |gt| &input[idx + gt + 1..]);
+            return (&input[..idx], after);
+        }
+    }
+    (input, "")
+}
+
+struct TableState {
+    rows: usize,
+    cells: usize,
+}
+
+struct MarkdownWriter<'a> {
+    base_url: Option<&'a Url>,
+    out: String,
+    pending_space: bool,
+    /// Set right after an opening inline marker so leading whitespace inside
+    /// the element does not end up between the marker and the text.
+    after_open_marker: bool,
+    head_depth: usize,
+    pre_depth: usize,
+    quote_depth: usize,
+    /// One entry per open list; `Some(n)` is the next number of an ordered list.
+    lists: Vec<Option<usize>>,
+    /// One entry per open `<a>`; links we render record where their text starts.
+    links: Vec<Option<(usize, String)>>,
+    table: Option<TableState>,
+    in_cell: bool,
+}
+
+impl<'a> MarkdownWriter<'a> {
+    fn new(base_url: Option<&'a Url>) -> Self {
+        Self {
+            base_url,
+            out: String::new(),
+            pending_space: false,
+            after_open_marker: false,
+            head_depth: 0,
+            pre_depth: 0,
+            quote_depth: 0,
+            lists: Vec::new(),
+            links: Vec::new(),
+            table: None,
+            in_cell: false,
+        }
+    }
+
+    fn text(&mut self, raw: &str) {
+        if raw.is_empty() {
+            return;
+        }
+        self.write_text(&decode_entities(raw));
+    }
+
+    fn write_text(&mut self, text: &str) {
+        if self.head_depth > 0 {
+            return;
+        }
+        if self.pre_depth > 0 {
+            self.out.push_str(text);
+            return;
+        }
+        for ch in text.chars() {
+            if ch.is_whitespace() {
+                self.pending_space = true;
+                continue;
+            }
+            if self.at_line_start() {
+                self.start_line();
+            } else if self.pending_space && !self.after_open_marker {
+                self.out.push(' ');
+            }
+            self.pending_space = false;
+            self.after_open_marker = false;
+            self.out.push(ch);
+        }
+    }
+
+    fn open_marker(&mut self, marker: &str) {
+        if self.at_line_start() {
+            self.start_line();
+        } else if self.pending_space {
+            self.out.push(' ');
+        }
+        self.pending_space = false;
+        self.out.push_str(marker);
+        self.after_open_marker = true;
+    }
+
+    fn close_marker(&mut self, marker: &str) {
+        self.after_open_marker = false;
+        self.out.push_str(marker);
+    }
+
+    fn at_line_start(&self) -> bool {
+        self.out.is_empty() || self.out.ends_with('\n')
+    }
+
+    fn start_line(&mut self) {
+        for _ in 0..self.quote_depth {
+            self.out.push_str("> ");
+        }
+    }
+
+    /// Ends the current block with a line break, or a blank line when `blank`.
+    fn block(&mut self, blank: bool) {
+        if self.in_cell {
+            self.pending_space = true;
+            return;
+        }
+        self.pending_space = false;
+        self.after_open_marker = false;
+        let trimmed = self.out.trim_end_matches(' ').len();
+        self.out.truncate(trimmed);
+        if self.out.is_empty() {
+            return;
+        }
+        if !self.out.ends_with('\n') {
+            self.out.push('\n');
+        }
+        if blank && !self.out.ends_with("\n\n") {
+            self.out.push('\n');
+        }
+    }
+
+    fn tag(&mut self, tag: &Tag<'_>) {
+        let name = tag.name.as_str();
+        if name == "head" {
+            if tag.closing {
+                self.head_depth = self.head_depth.saturating_sub(1);
+            } else if !tag.self_closing {
+                self.head_depth += 1;
+            }
+            return;
+        }
+        if self.pre_depth > 0 && !matches!(name, "pre" | "br") {
+            return;
+        }
+
+        match name {
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                self.block(true);
+                if !tag.closing {
+                    let level = usize::from(name.as_bytes()[1] - b'0');
+                    self.start_line();
+                    self.out.push_str(&"#".repeat(level));
+                    self.out.push(' ');
+                }
+            }
+            "p" | "dl" => self.block(true),
+            "div" | "section" | "article" | "main" | "header" | "footer" | "nav" | "aside"
+            | "figure" | "figcaption" | "form" | "details" | "summary" | "dt" | "address" => {
+                self.block(false)
+            }
+            "dd" => {
+                self.block(false);
+                if !tag.closing {
+                    self.start_line();
+                    self.out.push_str(": ");
+                }
+            }
+            "br" => {
+                if self.pre_depth > 0 {
+                    self.out.push('\n');
+                } else if self.in_cell {
+                    self.pending_space = true;
+                } else {
+                    let trimmed = self.out.trim_end_matches(' ').len();
+                    self.out.truncate(trimmed);
+                    self.out.push('\n');
+                    self.pending_space = false;
+                }
+            }
+            "hr" => {
+                self.block(true);
+                self.start_line();
+                self.out.push_str("---");
+                self.block(true);
+            }
+            "blockquote" => {
+                self.block(true);
+                if tag.closing {
+                    self.quote_depth = self.quote_depth.saturating_sub(1);
+                } else {
+                    self.quote_depth += 1;
+                }
+            }
+            "pre" => {
+                if tag.closing {
+                    if self.pre_depth > 0 {
+                        self.pre_depth -= 1;
+                        if !self.at_line_start() {
+                            self.out.push('\n');
+                        }
+                        self.start_line();
+                        self.out.push_str("```");
+                        self.block(true);
+                    }
+                } else {
+                    self.block(true);
+                    self.start_line();
+                    self.out.push_str("```\n");
+                    self.pre_depth += 1;
+                }
+            }
+            "ul" | "ol" => {
+                if tag.closing {
+                    self.lists.pop();
+                } else {
+                    let start = tag
+                        .attr("start")
+                        .and_then(|start| start.trim().parse().ok());
+                    self.lists
+                        .push((name == "ol").then_some(start.unwrap_or(1)));
+                }
+                self.block(self.lists.is_empty() || (!tag.closing && self.lists.len() == 1));
+            }
+            "li" => {
+                self.block(false);
+                if !tag.closing {
+                    let indent = "  ".repeat(self.lists.len().saturating_sub(1));
+                    let marker = match self.lists.last_mut() {
+                        Some(Some(next)) => {
+                            let marker = format!("{next}. ");
+                            *next += 1;
+                            marker
+                        }
+                        _ => "- ".to_string(),
+                    };
+                    self.start_line();
+                    self.out.push_str(&indent);
+                    self.out.push_str(&marker);
+                }
+            }
+            "strong" | "b" => {
+                if tag.closing {
+                    self.close_marker("**");
+                } else {
+                    self.open_marker("**");
+                }
+            }
+            "em" | "i" => {
+                if tag.closing {
+                    self.close_marker("*");
+                } else {
+                    self.open_marker("*");
+                }
+            }
+            "code" | "kbd" | "samp" => {
+                if tag.closing {
+                    self.close_marker("`");
+                } else {
+                    self.open_marker("`");
+                }
+            }
+            "a" => self.link(tag),
+            "img" => {
+                if let Some(alt) = tag.attr("alt").filter(|alt| !alt.trim().is_empty()) {
+                    self.write_text(&alt);
+                }
+            }
+            "table" => {
+                self.in_cell = false;
+                self.block(true);
+                self.table = (!tag.closing).then_some(TableState { rows: 0, cells: 0 });
+            }
+            "tr" => {
+                self.in_cell = false;
+                let Some(table) = self.table.as_mut() else {
+                    self.block(false);
+                    return;
+                };
+                if tag.closing {
+                    if table.rows == 0 && table.cells > 0 {
+                        let separator = "| --- ".repeat(table.cells);
+                        self.out.push('\n');
+                        self.out.push_str(&separator);
+                        self.out.push('|');
+                    }
+                    table.rows += 1;
+                    self.block(false);
+                } else {
+                    table.cells = 0;
+                    self.block(false);
+                    self.start_line();
+                    self.out.push('|');
+                }
+            }
+            "td" | "th" => {
+                let Some(table) = self.table.as_mut() else {
+                    self.block(false);
+                    return;
+                };
+                if tag.closing {
+                    if self.in_cell {
+                        table.cells += 1;
+                        self.in_cell = false;
+                        let trimmed = self.out.trim_end_matches(' ').len();
+                        self.out.truncate(trimmed);
+                        self.out.push_str(" |");
+                    }
+                } else {
+                    self.in_cell = true;
+                    self.out.push(' ');
+                    self.pending_space = false;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn link(&mut self, tag: &Tag<'_>) {
+        if tag.closing {
+            let Some(Some((start, href))) = self.links.pop() else {
+                return;
+            };
+            if self.out[start + 1..].trim().is_empty() {
+                self.out.truncate(start);
+            } else {
+                self.close_marker("](");
+                self.out.push_str(&href);
+                self.out.push(')');
+            }
+            return;
+        }
+        let href = tag.attr("href").and_then(|href| self.resolve_link(&href));
+        match href {
+            Some(href) => {
+                self.open_marker("[");
+                self.links.push(Some((self.out.len() - 1, href)));
+            }
+            None => self.links.push(None),
+        }
+    }
+
+    fn resolve_link(&self, href: &str) -> Option<String> {
+        let href = href.trim();
+        if href.is_empty() || href.starts_with('#') || href.starts_with("javascript:") {
+            return None;
+        }
+        let url = match self.base_url {
+            Some(base) => base.join(href).ok()?,
+            None => Url::parse(href).ok()?,
+        };
+        Some(url.to_string())
+    }
+
+    fn finish(self) -> String {
+        let mut markdown = String::with_capacity(self.out.len());
+        let mut in_fence = false;
+        let mut blank_run = 0;
+        for line in self.out.lines() {
+            if line.trim_start_matches("> ").starts_with("```") {
+                in_fence = !in_fence;
+            }
+            let line = if in_fence { line } else { line.trim_end() };
+            if line.is_empty() && !in_fence {
+                blank_run += 1;
+                if blank_run > 1 {
+                    continue;
+                }
+            } else {
+                blank_run = 0;
+            }
+            markdown.push_str(line);
+            markdown.push('\n');
+        }
+        markdown.trim().to_string()
+    }
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn decode_entities(text: &str) -> Cow<'_, str> {
+    if !text.contains('&') {
+        return Cow::Borrowed(text);
+    }
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(amp) = rest.find('&') {
+        out.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+        // Entity names are short; only look a few bytes ahead for the `;`.
+        let decoded = rest
+            .bytes()
+            .skip(1)
+            .take(12)
+            .position(|b| b == b';')
+            .and_then(|end| decode_entity(&rest[1..=end]).map(|ch| (ch, end + 2)));
+        match decoded {
+            Some((ch, len)) => {
+                out.push(ch);
+                rest = &rest[len..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    Cow::Owned(out)
+}
+
+fn decode_entity(entity: &str) -> Option<char> {
+    if let Some(number) = entity.strip_prefix('#') {
+        let code = match number.strip_prefix(['x', 'X']) {
+            Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+            None => number.parse().ok()?,
+        };
+        return char::from_u32(code);
+    }
+    let ch = match entity {
+        "amp" => '&',
+        "lt" => '<',
+        "gt" => '>',
+        "quot" => '"',
+        "apos" => '\'',
+        "nbsp" => '\u{a0}',
+        "ndash" => '–',
+        "mdash" => '—',
+        "hellip" => '…',
+        "lsquo" => '‘',
+        "rsquo" => '’',
+        "ldquo" => '“',
+        "rdquo" => '”',
+        "laquo" => '«',
+        "raquo" => '»',
+        "copy" => '©',
+        "reg" => '®',
+        "trade" => '™',
+        "middot" => '·',
+        "bull" => '•',
+        "times" => '×',
+        "rarr" => '→',
+        "larr" => '←',
+        _ => return None,
+    };
+    Some(ch)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn convert(html: &str) -> ConvertedHtml {
+        let base = Url::parse("https://docs.example.com/guide/intro.html").expect("base url");
+        html_to_markdown(html, Some(&base))
+    }
+
+    #[test]
+    fn converts_common_block_and_inline_elements() {
+        let converted = convert(
+            r##"<!DOCTYPE html>
+<html><head><title>Intro &amp; Setup</title><style>body { color: red; }</style></head>
+<body>
+  <nav><a href="#main">Skip</a></nav>
+  <h1>Getting   started</h1>
+  <p>Install the <code>codex</code> CLI with <strong>npm</strong>, then read
+  the <a href="../reference/cli.html">CLI reference</a>.</p>
+  <script>console.log("ignored")</script>
+  <ul><li>First</li><li>Second <em>item</em></li></ul>
+  <ol start="3"><li>Three</li><li>Four</li></ol>
+  <pre><code>npm install -g @openai/codex
+codex --help</code></pre>
+</body></html>"##,
+        );
+
+        assert_eq!(converted.title.as_deref(), Some("Intro & Setup"));
+        assert_eq!(
+            converted.markdown,
+            "Skip\n\n\
+             # Getting started\n\n\
+             Install the `codex` CLI with **npm**, then read the \
+             [CLI reference](https://docs.example.com/reference/cli.html).\n\n\
+             - First\n\
+             - Second *item*\n\n\
+             3. Three\n\
+             4. Four\n\n\
+             ```\n\
+             npm install -g @openai/codex\n\
+             codex --help\n\
+             ```"
+        );
+    }
+
+    #[test]
+    fn converts_tables_and_blockquotes() {
+        let converted = convert(
+            "<table><tr><th>Key</th><th>Default</th></tr>\
+             <tr><td>model</td><td><code>gpt-5</code></td></tr></table>\
+             <blockquote><p>Note: &lt;restart&gt; required.</p></blockquote>",
+        );
+
+        assert_eq!(converted.title, None);
+        assert_eq!(
+            converted.markdown,
+            "| Key | Default |\n\
+             | --- | --- |\n\
+             | model | `gpt-5` |\n\n\
+             > Note: <restart> required."
+        );
+    }
+
+    #[test]
+    fn decodes_numeric_entities_and_keeps_unknown_ones() {
+        assert_eq!(
+            decode_entities("&#65;&#x42; &bogus; AT&T"),
+            "AB &bogus; AT&T"
+        );
+    }
+}
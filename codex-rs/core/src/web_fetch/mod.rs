@@ -0,0 +1,475 @@
+//! Client-side page retrieval for the `web_fetch` and `web_search_local` tools.
+//!
+//! Responses API web search runs server-side, so local and third-party
+//! providers have no way to read documentation pages. These helpers fetch a
+//! URL (through the local network proxy when it is enabled), convert HTML to
+//! Markdown and split long pages so each one fits in a single tool output.
+
+mod html;
+mod search;
+
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::net::IpAddr;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::StatusCode;
+use reqwest::header::CONTENT_TYPE;
+use reqwest::header::LOCATION;
+use tokio::sync::Mutex;
+use url::Url;
+
+use crate::config::types::WebFetchConfig;
+use crate::default_client::get_codex_user_agent;
+use crate::protocol::SandboxPolicy;
+pub(crate) use search::SearchResult;
+pub(crate) use search::search_backend_from_config;
+
+const FETCH_TIMEOUT: Duration = Duration::from_secs(30);
+const MAX_REDIRECTS: usize = 5;
+/// Bodies beyond this size are cut off before conversion.
+const MAX_RESPONSE_BYTES: usize = 5 * 1024 * 1024;
+const MAX_CACHED_PAGES: usize = 32;
+/// Header the `network-proxy` crate attaches to requests it refuses.
+const PROXY_ERROR_HEADER: &str = "x-proxy-error";
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum WebFetchError {
+    #[error("invalid url `{0}`")]
+    InvalidUrl(String),
+    #[error("unsupported url scheme `{0}`; only http and https are allowed")]
+    UnsupportedScheme(String),
+    #[error(
+        "network access is disabled by the sandbox policy; ask the user to allow network access to fetch web pages"
+    )]
+    NetworkDisabled,
+    #[error("refusing to fetch `{0}`: it resolves to a local or private address")]
+    LocalAddress(String),
+    #[error("could not resolve `{host}`: {source}")]
+    Resolve {
+        host: String,
+        source: std::io::Error,
+    },
+    #[error("blocked by network proxy policy ({reason}): {url}")]
+    BlockedByProxy { url: String, reason: String },
+    #[error("too many redirects while fetching {0}")]
+    TooManyRedirects(String),
+    #[error("{url} returned HTTP {status}")]
+    Status { url: String, status: StatusCode },
+    #[error("unsupported content type `{0}`")]
+    UnsupportedContentType(String),
+    #[error("request failed: {0}")]
+    Request(#[from] reqwest::Error),
+}
+
+/// A fetched page converted to Markdown.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct FetchedPage {
+    /// Final URL after redirects.
+    pub(crate) url: String,
+    pub(crate) title: Option<String>,
+    pub(crate) markdown: String,
+}
+
+/// Per-session cache so paging through a document does not refetch it.
+#[derive(Default)]
+pub(crate) struct WebFetchCache {
+    inner: Mutex<CacheInner>,
+}
+
+#[derive(Default)]
+struct CacheInner {
+    pages: HashMap<String, Arc<FetchedPage>>,
+    /// Keys in insertion order, used to evict the oldest page.
+    order: VecDeque<String>,
+}
+
+impl WebFetchCache {
+    pub(crate) async fn get_or_fetch(
+        &self,
+        url: &Url,
+        config: &WebFetchConfig,
+    ) -> Result<Arc<FetchedPage>, WebFetchError> {
+        let key = cache_key(url);
+        if let Some(page) = self.inner.lock().await.pages.get(&key) {
+            return Ok(Arc::clone(page));
+        }
+
+        let page = Arc::new(fetch_page(url, config).await?);
+        let mut inner = self.inner.lock().await;
+        if inner.pages.insert(key.clone(), Arc::clone(&page)).is_none() {
+            inner.order.push_back(key);
+        }
+        while inner.order.len() > MAX_CACHED_PAGES {
+            if let Some(oldest) = inner.order.pop_front() {
+                inner.pages.remove(&oldest);
+            }
+        }
+        Ok(page)
+    }
+}
+
+fn cache_key(url: &Url) -> String {
+    let mut url = url.clone();
+    url.set_fragment(None);
+    url.to_string()
+}
+
+/// Refuses web access when the turn's sandbox policy blocks the network, the
+/// same as it would for a command.
+pub(crate) fn check_network_access(sandbox_policy: &SandboxPolicy) -> Result<(), WebFetchError> {
+    if sandbox_policy.has_full_network_access() {
+        Ok(())
+    } else {
+        Err(WebFetchError::NetworkDisabled)
+    }
+}
+
+/// Fetches `url` and converts the response to Markdown.
+///
+/// When a network proxy is configured every request goes through it so its
+/// allow/deny policy applies. Without one, hosts that resolve to loopback or
+/// private addresses are refused on every redirect hop, and the connection is
+/// pinned to the addresses that were checked so a second DNS answer cannot
+/// point it elsewhere.
+pub(crate) async fn fetch_page(
+    url: &Url,
+    config: &WebFetchConfig,
+) -> Result<FetchedPage, WebFetchError> {
+    let direct = config.proxy_url.is_none();
+
+    let mut current = url.clone();
+    let mut redirects = 0;
+    let response = loop {
+        let pinned = check_url(&current, direct).await?;
+        let mut builder = http_client_builder().redirect(reqwest::redirect::Policy::none());
+        if let Some(proxy_url) = &config.proxy_url {
+            builder = builder.proxy(reqwest::Proxy::all(proxy_url)?);
+        }
+        if let Some((domain, addrs)) = &pinned {
+            builder = builder.resolve_to_addrs(domain, addrs);
+        }
+        let client = builder.build()?;
+        let response = client.get(current.clone()).send().await?;
+        if response.status().is_redirection()
+            && let Some(location) = response.headers().get(LOCATION)
+        {
+            if redirects == MAX_REDIRECTS {
+                return Err(WebFetchError::TooManyRedirects(url.to_string()));
+            }
+            redirects += 1;
+            let location = location
+                .to_str()
+                .map_err(|_| WebFetchError::InvalidUrl(format!("{location:?}")))?;
+            current = current
+                .join(location)
+                .map_err(|_| WebFetchError::InvalidUrl(location.to_string()))?;
+            continue;
+        }
+        break response;
+    };
+
+    let status = response.status();
+    if status == StatusCode::FORBIDDEN
+        && let Some(reason) = response.headers().get(PROXY_ERROR_HEADER)
+    {
+        return Err(WebFetchError::BlockedByProxy {
+            url: current.to_string(),
+            reason: reason.to_str().unwrap_or("blocked").to_string(),
+        });
+    }
+    if !status.is_success() {
+        return Err(WebFetchError::Status {
+            url: current.to_string(),
+            status,
+        });
+    }
+
+    let content_type = response
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| {
+            value
+                .split(';')
+                .next()
+                .unwrap_or_default()
+                .trim()
+                .to_ascii_lowercase()
+        });
+    let body = read_body(response).await?;
+    let body = String::from_utf8_lossy(&body);
+
+    let is_html = match content_type.as_deref() {
+        Some("text/html" | "application/xhtml+xml") => true,
+        Some(mime) if is_text_mime(mime) => false,
+        Some(mime) => return Err(WebFetchError::UnsupportedContentType(mime.to_string())),
+        None => body.trim_start().starts_with('<'),
+    };
+    let (title, markdown) = if is_html {
+        let converted = html::html_to_markdown(&body, Some(&current));
+        (converted.title, converted.markdown)
+    } else {
+        (None, body.trim().to_string())
+    };
+
+    Ok(FetchedPage {
+        url: current.to_string(),
+        title,
+        markdown,
+    })
+}
+
+fn http_client_builder() -> reqwest::ClientBuilder {
+    reqwest::Client::builder()
+        .user_agent(get_codex_user_agent())
+        .timeout(FETCH_TIMEOUT)
+}
+
+fn is_text_mime(mime: &str) -> bool {
+    mime.starts_with("text/")
+        || mime.ends_with("+json")
+        || mime.ends_with("+xml")
+        || matches!(
+            mime,
+            "application/json" | "application/xml" | "application/javascript"
+        )
+}
+
+async fn read_body(mut response: reqwest::Response) -> Result<Vec<u8>, WebFetchError> {
+    let mut body = Vec::new();
+    while let Some(chunk) = response.chunk().await? {
+        let remaining = MAX_RESPONSE_BYTES - body.len();
+        if chunk.len() >= remaining {
+            body.extend_from_slice(&chunk[..remaining]);
+            break;
+        }
+        body.extend_from_slice(&chunk);
+    }
+    Ok(body)
+}
+
+/// Validates `url` for a direct fetch. For domain hosts, returns the domain and
+/// the addresses it was checked against so the request connects to exactly
+/// those.
+async fn check_url(
+    url: &Url,
+    direct: bool,
+) -> Result<Option<(String, Vec<SocketAddr>)>, WebFetchError> {
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(WebFetchError::UnsupportedScheme(url.scheme().to_string()));
+    }
+    if !direct {
+        // The proxy owns the policy for proxied requests.
+        return Ok(None);
+    }
+
+    let (local, pinned) = match url.host() {
+        Some(url::Host::Ipv4(ip)) => (is_local_address(IpAddr::V4(ip)), None),
+        Some(url::Host::Ipv6(ip)) => (is_local_address(IpAddr::V6(ip)), None),
+        Some(url::Host::Domain(domain)) => {
+            let normalized = domain.trim_end_matches('.').to_ascii_lowercase();
+            if normalized == "localhost" || normalized.ends_with(".localhost") {
+                (true, None)
+            } else {
+                let port = url.port_or_known_default().unwrap_or(80);
+                let addrs: Vec<SocketAddr> = tokio::net::lookup_host((normalized.as_str(), port))
+                    .await
+                    .map_err(|source| WebFetchError::Resolve {
+                        host: domain.to_string(),
+                        source,
+                    })?
+                    .collect();
+                let local = addrs.iter().any(|addr| is_local_address(addr.ip()));
+                (local, Some((domain.to_string(), addrs)))
+            }
+        }
+        None => return Err(WebFetchError::InvalidUrl(url.to_string())),
+    };
+    if local {
+        return Err(WebFetchError::LocalAddress(url.to_string()));
+    }
+    Ok(pinned)
+}
+
+fn is_local_address(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            ip.is_loopback()
+                || ip.is_private()
+                || ip.is_link_local()
+                || ip.is_unspecified()
+                || ip.is_broadcast()
+                // Carrier-grade NAT, 100.64.0.0/10.
+                || (ip.octets()[0] == 100 && (ip.octets()[1] & 0xc0) == 64)
+        }
+        IpAddr::V6(ip) => {
+            if let Some(mapped) = ip.to_ipv4_mapped() {
+                return is_local_address(IpAddr::V4(mapped));
+            }
+            ip.is_loopback()
+                || ip.is_unspecified()
+                || ip.is_unique_local()
+                || ip.is_unicast_link_local()
+        }
+    }
+}
+
+/// Splits `markdown` into pages of at most `page_bytes`, preferring to break
+/// between paragraphs, then between lines, then anywhere on a char boundary.
+pub(crate) fn paginate(markdown: &str, page_bytes: usize) -> Vec<&str> {
+    let mut pages = Vec::new();
+    let mut rest = markdown.trim();
+    while rest.len() > page_bytes {
+        let mut end = page_bytes;
+        while end > 0 && !rest.is_char_boundary(end) {
+            end -= 1;
+        }
+        if end == 0 {
+            end = rest.chars().next().map_or(rest.len(), char::len_utf8);
+        }
+        let window = &rest[..end];
+        let split = window
+            .rfind("\n\n")
+            .or_else(|| window.rfind('\n'))
+            .filter(|&idx| idx >= window.len() / 2)
+            .unwrap_or(end);
+        pages.push(rest[..split].trim_end());
+        rest = rest[split..].trim_start();
+    }
+    if !rest.is_empty() || pages.is_empty() {
+        pages.push(rest);
+    }
+    pages
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use wiremock::Mock;
+    use wiremock::MockServer;
+    use wiremock::ResponseTemplate;
+    use wiremock::matchers::method;
+    use wiremock::matchers::path;
+
+    #[test]
+    fn paginate_prefers_paragraph_boundaries() {
+        let markdown = "first paragraph\n\nsecond paragraph\n\nthird";
+        assert_eq!(
+            paginate(markdown, 36),
+            vec!["first paragraph\n\nsecond paragraph", "third"]
+        );
+        assert_eq!(paginate(markdown, 1024), vec![markdown]);
+        assert_eq!(paginate("", 1024), vec![""]);
+    }
+
+    #[test]
+    fn paginate_splits_long_lines_on_char_boundaries() {
+        let pages = paginate("ééééé", 4);
+        assert_eq!(pages, vec!["éé", "éé", "é"]);
+    }
+
+    #[test]
+    fn network_access_follows_sandbox_policy() {
+        assert!(matches!(
+            check_network_access(&SandboxPolicy::ReadOnly),
+            Err(WebFetchError::NetworkDisabled)
+        ));
+        assert!(matches!(
+            check_network_access(&SandboxPolicy::new_workspace_write_policy()),
+            Err(WebFetchError::NetworkDisabled)
+        ));
+        assert!(check_network_access(&SandboxPolicy::DangerFullAccess).is_ok());
+    }
+
+    #[tokio::test]
+    async fn only_direct_domain_fetches_are_pinned() {
+        let url = Url::parse("http://93.184.215.14/").expect("valid url");
+        assert!(matches!(check_url(&url, true).await, Ok(None)));
+        let url = Url::parse("http://example.com/").expect("valid url");
+        assert!(matches!(check_url(&url, false).await, Ok(None)));
+        let url = Url::parse("http://does-not-resolve.invalid/").expect("valid url");
+        assert!(matches!(
+            check_url(&url, true).await,
+            Err(WebFetchError::Resolve { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn direct_fetch_refuses_local_addresses() {
+        let config = WebFetchConfig::default();
+        for url in [
+            "http://127.0.0.1:8080/",
+            "http://localhost/",
+            "http://[::1]/",
+            "http://192.168.1.10/",
+        ] {
+            let url = Url::parse(url).expect("valid url");
+            let err = fetch_page(&url, &config)
+                .await
+                .expect_err("local address should be refused");
+            assert!(
+                matches!(err, WebFetchError::LocalAddress(_)),
+                "{url}: {err}"
+            );
+        }
+
+        let url = Url::parse("file:///etc/passwd").expect("valid url");
+        let err = fetch_page(&url, &config)
+            .await
+            .expect_err("file urls should be refused");
+        assert!(matches!(err, WebFetchError::UnsupportedScheme(_)));
+    }
+
+    #[tokio::test]
+    async fn proxied_fetch_converts_html_and_reports_policy_blocks() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/docs"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(
+                "<html><head><title>Docs</title></head><body><h2>Usage</h2></body></html>",
+                "text/html; charset=utf-8",
+            ))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/blocked"))
+            .respond_with(
+                ResponseTemplate::new(403)
+                    .insert_header(PROXY_ERROR_HEADER, "blocked-by-denylist")
+                    .set_body_string("Codex blocked this request: domain in denylist."),
+            )
+            .mount(&server)
+            .await;
+
+        // The mock server stands in for the network proxy: plain-HTTP requests
+        // are sent to it in absolute form and land on the path above.
+        let config = WebFetchConfig {
+            search_backend: None,
+            proxy_url: Some(server.uri()),
+        };
+
+        let url = Url::parse("http://docs.example.com/docs").expect("valid url");
+        let page = fetch_page(&url, &config).await.expect("page should fetch");
+        assert_eq!(
+            page,
+            FetchedPage {
+                url: "http://docs.example.com/docs".to_string(),
+                title: Some("Docs".to_string()),
+                markdown: "## Usage".to_string(),
+            }
+        );
+
+        let url = Url::parse("http://blocked.example.com/blocked").expect("valid url");
+        let err = fetch_page(&url, &config)
+            .await
+            .expect_err("proxy block should surface");
+        assert_eq!(
+            err.to_string(),
+            "blocked by network proxy policy (blocked-by-denylist): http://blocked.example.com/blocked"
+        );
+    }
+}
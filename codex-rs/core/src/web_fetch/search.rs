@@ -0,0 +1,141 @@
+//! Pluggable search backends for the `web_search_local` tool.
+
+use async_trait::async_trait;
+use reqwest::header::AUTHORIZATION;
+use serde::Deserialize;
+
+use super::WebFetchError;
+use super::http_client_builder;
+use crate::config::types::WebFetchConfig;
+use crate::config::types::WebSearchBackendToml;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SearchResult {
+    pub(crate) title: String,
+    pub(crate) url: String,
+    pub(crate) snippet: String,
+}
+
+#[async_trait]
+pub(crate) trait WebSearchBackend: Send + Sync {
+    async fn search(&self, query: &str, limit: usize) -> Result<Vec<SearchResult>, WebFetchError>;
+}
+
+/// Returns the configured search backend, if any.
+pub(crate) fn search_backend_from_config(
+    config: &WebFetchConfig,
+) -> Option<Box<dyn WebSearchBackend>> {
+    match config.search_backend.as_ref()? {
+        WebSearchBackendToml::Searxng { url, api_key_env } => Some(Box::new(SearxngBackend {
+            url: url.clone(),
+            api_key: api_key_env
+                .as_deref()
+                .and_then(|name| std::env::var(name).ok())
+                .filter(|key| !key.is_empty()),
+        })),
+    }
+}
+
+/// A SearxNG instance (or anything speaking its JSON API). The endpoint is
+/// configured explicitly by the user, so it is queried directly rather than
+/// through the network proxy.
+struct SearxngBackend {
+    url: String,
+    api_key: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct SearxngResponse {
+    #[serde(default)]
+    results: Vec<SearxngResult>,
+}
+
+#[derive(Deserialize)]
+struct SearxngResult {
+    #[serde(default)]
+    title: String,
+    url: String,
+    #[serde(default)]
+    content: String,
+}
+
+#[async_trait]
+impl WebSearchBackend for SearxngBackend {
+    async fn search(&self, query: &str, limit: usize) -> Result<Vec<SearchResult>, WebFetchError> {
+        let client = http_client_builder().build()?;
+        let mut request = client
+            .get(&self.url)
+            .query(&[("q", query), ("format", "json")]);
+        if let Some(api_key) = &self.api_key {
+            request = request.header(AUTHORIZATION, format!("Bearer {api_key}"));
+        }
+        let response = request.send().await?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(WebFetchError::Status {
+                url: self.url.clone(),
+                status,
+            });
+        }
+        let response: SearxngResponse = response.json().await?;
+        Ok(response
+            .results
+            .into_iter()
+            .take(limit)
+            .map(|result| SearchResult {
+                title: result.title.trim().to_string(),
+                url: result.url,
+                snippet: result.content.trim().to_string(),
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+    use wiremock::Mock;
+    use wiremock::MockServer;
+    use wiremock::ResponseTemplate;
+    use wiremock::matchers::method;
+    use wiremock::matchers::path;
+    use wiremock::matchers::query_param;
+
+    #[tokio::test]
+    async fn searxng_backend_parses_results() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/search"))
+            .and(query_param("q", "rust async"))
+            .and(query_param("format", "json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "results": [
+                    {"title": " Async Book ", "url": "https://rust-lang.github.io/async-book/", "content": "Asynchronous programming in Rust."},
+                    {"title": "Tokio", "url": "https://tokio.rs/", "content": ""},
+                ]
+            })))
+            .mount(&server)
+            .await;
+
+        let backend = search_backend_from_config(&WebFetchConfig {
+            search_backend: Some(WebSearchBackendToml::Searxng {
+                url: format!("{}/search", server.uri()),
+                api_key_env: None,
+            }),
+            proxy_url: None,
+        })
+        .expect("backend should be configured");
+
+        let results = backend.search("rust async", 1).await.expect("search");
+        assert_eq!(
+            results,
+            vec![SearchResult {
+                title: "Async Book".to_string(),
+                url: "https://rust-lang.github.io/async-book/".to_string(),
+                snippet: "Asynchronous programming in Rust.".to_string(),
+            }]
+        );
+    }
+}
@@ -57,6 +57,8 @@ async fn responses_stream_includes_subagent_header_on_review() {
         stream_idle_timeout_ms: Some(5_000),
         requires_openai_auth: false,
         supports_websockets: false,
+        models: Default::default(),
+        list_models: false,
     };
 
     let codex_home = TempDir::new().expect("failed to create TempDir");
@@ -158,6 +160,8 @@ async fn responses_stream_includes_subagent_header_on_other() {
         stream_idle_timeout_ms: Some(5_000),
         requires_openai_auth: false,
         supports_websockets: false,
+        models: Default::default(),
+        list_models: false,
     };
 
     let codex_home = TempDir::new().expect("failed to create TempDir");
@@ -255,6 +259,8 @@ async fn responses_respects_model_info_overrides_from_config() {
         stream_idle_timeout_ms: Some(5_000),
         requires_openai_auth: false,
         supports_websockets: false,
+        models: Default::default(),
+        list_models: false,
     };
 
     let codex_home = TempDir::new().expect("failed to create TempDir");
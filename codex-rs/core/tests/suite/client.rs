@@ -1238,6 +1238,8 @@ async fn azure_responses_request_includes_store_and_reasoning_ids() {
         stream_idle_timeout_ms: Some(5_000),
         requires_openai_auth: false,
         supports_websockets: false,
+        models: Default::default(),
+        list_models: false,
     };
 
     let codex_home = TempDir::new().unwrap();
@@ -1763,6 +1765,8 @@ async fn azure_overrides_assign_properties_used_for_responses_url() {
         stream_idle_timeout_ms: None,
         requires_openai_auth: false,
         supports_websockets: false,
+        models: Default::default(),
+        list_models: false,
     };
 
     // Init session
@@ -1847,6 +1851,8 @@ async fn env_var_overrides_loaded_auth() {
         stream_idle_timeout_ms: None,
         requires_openai_auth: false,
         supports_websockets: false,
+        models: Default::default(),
+        list_models: false,
     };
 
     // Init session
@@ -416,6 +416,8 @@ fn websocket_provider(server: &WebSocketTestServer) -> ModelProviderInfo {
         stream_idle_timeout_ms: Some(5_000),
         requires_openai_auth: false,
         supports_websockets: true,
+        models: Default::default(),
+        list_models: false,
     }
 }
 
@@ -78,6 +78,7 @@ async fn remote_models_remote_model_uses_unified_exec() -> Result<()> {
         visibility: ModelVisibility::List,
         supported_in_api: true,
         input_modalities: default_input_modalities(),
+        supports_tools: true,
         priority: 1,
         upgrade: None,
         base_instructions: "base instructions".to_string(),
@@ -316,6 +317,7 @@ async fn remote_models_apply_remote_base_instructions() -> Result<()> {
         visibility: ModelVisibility::List,
         supported_in_api: true,
         input_modalities: default_input_modalities(),
+        supports_tools: true,
         priority: 1,
         upgrade: None,
         base_instructions: remote_base.to_string(),
@@ -791,6 +793,7 @@ fn test_remote_model_with_policy(
         visibility,
         supported_in_api: true,
         input_modalities: default_input_modalities(),
+        supports_tools: true,
         priority,
         upgrade: None,
         base_instructions: "base instructions".to_string(),
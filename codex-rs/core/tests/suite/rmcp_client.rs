@@ -14,6 +14,7 @@ use codex_core::config::types::McpServerTransportConfig;
 use codex_core::protocol::AskForApproval;
 use codex_core::protocol::EventMsg;
 use codex_core::protocol::McpInvocation;
+use codex_core::protocol::McpListChangedKind;
 use codex_core::protocol::McpToolCallBeginEvent;
 use codex_core::protocol::Op;
 use codex_core::protocol::SandboxPolicy;
@@ -93,6 +94,7 @@ async fn stdio_server_round_trip() -> anyhow::Result<()> {
                     enabled_tools: None,
                     disabled_tools: None,
                     scopes: None,
+                    sandbox: None,
                 },
             );
             config
@@ -177,6 +179,141 @@ async fn stdio_server_round_trip() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+#[serial(mcp_test_value)]
+async fn stdio_server_tool_list_changes_apply_to_next_turn() -> anyhow::Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = responses::start_mock_server().await;
+
+    let server_name = "rmcp";
+    let load_tool = format!("mcp__{server_name}__load_project_tools");
+    let project_tool = format!("mcp__{server_name}__project_tool");
+
+    let first_turn = mount_sse_once(
+        &server,
+        responses::sse(vec![
+            responses::ev_response_created("resp-1"),
+            responses::ev_function_call("call-load", &load_tool, "{}"),
+            responses::ev_completed("resp-1"),
+        ]),
+    )
+    .await;
+    mount_sse_once(
+        &server,
+        responses::sse(vec![
+            responses::ev_assistant_message("msg-1", "Project tools loaded."),
+            responses::ev_completed("resp-2"),
+        ]),
+    )
+    .await;
+    let second_turn = mount_sse_once(
+        &server,
+        responses::sse(vec![
+            responses::ev_assistant_message("msg-2", "Done."),
+            responses::ev_completed("resp-3"),
+        ]),
+    )
+    .await;
+
+    let rmcp_test_server_bin = stdio_server_bin()?;
+    let fixture = test_codex()
+        .with_config(move |config| {
+            let mut servers = config.mcp_servers.get().clone();
+            servers.insert(
+                server_name.to_string(),
+                McpServerConfig {
+                    transport: McpServerTransportConfig::Stdio {
+                        command: rmcp_test_server_bin,
+                        args: Vec::new(),
+                        env: None,
+                        env_vars: Vec::new(),
+                        cwd: None,
+                    },
+                    enabled: true,
+                    disabled_reason: None,
+                    startup_timeout_sec: Some(Duration::from_secs(10)),
+                    tool_timeout_sec: None,
+                    enabled_tools: None,
+                    disabled_tools: None,
+                    scopes: None,
+                    sandbox: None,
+                },
+            );
+            config
+                .mcp_servers
+                .set(servers)
+                .expect("test mcp servers should accept any configuration");
+        })
+        .build(&server)
+        .await?;
+    let session_model = fixture.session_configured.model.clone();
+    let user_turn = |text: &str| Op::UserTurn {
+        items: vec![UserInput::Text {
+            text: text.into(),
+            text_elements: Vec::new(),
+        }],
+        final_output_json_schema: None,
+        cwd: fixture.cwd.path().to_path_buf(),
+        approval_policy: AskForApproval::Never,
+        sandbox_policy: SandboxPolicy::ReadOnly,
+        model: session_model.clone(),
+        effort: None,
+        summary: ReasoningSummary::Auto,
+        collaboration_mode: None,
+        personality: None,
+    };
+
+    fixture.codex.submit(user_turn("load the project")).await?;
+
+    let mut list_changed = None;
+    let mut turn_complete = false;
+    while list_changed.is_none() || !turn_complete {
+        match wait_for_event(&fixture.codex, |ev| {
+            matches!(ev, EventMsg::McpListChanged(_) | EventMsg::TurnComplete(_))
+        })
+        .await
+        {
+            EventMsg::McpListChanged(event) => list_changed = Some(event),
+            EventMsg::TurnComplete(_) => turn_complete = true,
+            _ => unreachable!("event guard guarantees McpListChanged or TurnComplete"),
+        }
+    }
+    let list_changed = list_changed.expect("list_changed event");
+    assert_eq!(list_changed.server, server_name);
+    assert_eq!(list_changed.kind, McpListChangedKind::Tools);
+
+    fixture
+        .codex
+        .submit(user_turn("use the project tool"))
+        .await?;
+    wait_for_event(&fixture.codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
+
+    let tool_names = |body: Value| -> Vec<String> {
+        body["tools"]
+            .as_array()
+            .map(|tools| {
+                tools
+                    .iter()
+                    .filter_map(|tool| tool["name"].as_str().map(str::to_string))
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+    let first_tools = tool_names(first_turn.single_request().body_json());
+    assert!(first_tools.contains(&load_tool));
+    assert!(!first_tools.contains(&project_tool));
+    let second_tools = tool_names(second_turn.single_request().body_json());
+    assert!(
+        second_tools.contains(&project_tool),
+        "refreshed tool should be exposed: {second_tools:?}"
+    );
+
+    server.verify().await;
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 #[serial(mcp_test_value)]
 async fn stdio_image_responses_round_trip() -> anyhow::Result<()> {
@@ -234,6 +371,7 @@ async fn stdio_image_responses_round_trip() -> anyhow::Result<()> {
                     enabled_tools: None,
                     disabled_tools: None,
                     scopes: None,
+                    sandbox: None,
                 },
             );
             config
@@ -382,6 +520,7 @@ async fn stdio_server_propagates_whitelisted_env_vars() -> anyhow::Result<()> {
                     enabled_tools: None,
                     disabled_tools: None,
                     scopes: None,
+                    sandbox: None,
                 },
             );
             config
@@ -540,6 +679,7 @@ async fn streamable_http_tool_call_round_trip() -> anyhow::Result<()> {
                     enabled_tools: None,
                     disabled_tools: None,
                     scopes: None,
+                    sandbox: None,
                 },
             );
             config
@@ -730,6 +870,7 @@ async fn streamable_http_with_oauth_round_trip() -> anyhow::Result<()> {
                     enabled_tools: None,
                     disabled_tools: None,
                     scopes: None,
+                    sandbox: None,
                 },
             );
             config
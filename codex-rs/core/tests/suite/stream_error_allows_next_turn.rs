@@ -78,6 +78,8 @@ async fn continue_after_stream_error() {
         stream_idle_timeout_ms: Some(2_000),
         requires_openai_auth: false,
         supports_websockets: false,
+        models: Default::default(),
+        list_models: false,
     };
 
     let TestCodex { codex, .. } = test_codex()
@@ -86,6 +86,8 @@ async fn retries_on_early_close() {
         stream_idle_timeout_ms: Some(2000),
         requires_openai_auth: false,
         supports_websockets: false,
+        models: Default::default(),
+        list_models: false,
     };
 
     let TestCodex { codex, .. } = test_codex()
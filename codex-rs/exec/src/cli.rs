@@ -15,7 +15,7 @@ pub struct Cli {
     #[command(subcommand)]
     pub command: Option<Command>,
 
-    /// Optional image(s) to attach to the initial prompt.
+    /// Optional image(s) or PDF(s) to attach to the initial prompt.
     #[arg(
         long = "image",
         short = 'i',
@@ -78,6 +78,11 @@ pub struct Cli {
     #[arg(long = "ephemeral", global = true, default_value_t = false)]
     pub ephemeral: bool,
 
+    /// Run the session in a dedicated git worktree on a fresh branch instead
+    /// of editing the current checkout.
+    #[arg(long = "worktree", global = true, default_value_t = false)]
+    pub worktree: bool,
+
     /// Path to a JSON Schema file describing the model's final response shape.
     #[arg(long = "output-schema", value_name = "FILE")]
     pub output_schema: Option<PathBuf>,
@@ -138,6 +143,11 @@ pub struct Cli {
     #[arg(long = "output-last-message", short = 'o', value_name = "FILE")]
     pub last_message_file: Option<PathBuf>,
 
+    /// Run the turns described by a YAML or JSONL script sequentially in a
+    /// single thread, checking each turn's assertions as it completes.
+    #[arg(long = "script", value_name = "FILE", value_hint = clap::ValueHint::FilePath)]
+    pub script: Option<PathBuf>,
+
     /// Initial instructions for the agent. If not provided as an argument (or
     /// if `-` is used), instructions are read from stdin.
     #[arg(value_name = "PROMPT", value_hint = clap::ValueHint::Other)]
@@ -170,7 +180,7 @@ struct ResumeArgsRaw {
     #[arg(long = "all", default_value_t = false)]
     all: bool,
 
-    /// Optional image(s) to attach to the prompt sent after resuming.
+    /// Optional image(s) or PDF(s) to attach to the prompt sent after resuming.
     #[arg(
         long = "image",
         short = 'i',
@@ -197,7 +207,7 @@ pub struct ResumeArgs {
     /// Show all sessions (disables cwd filtering).
     pub all: bool,
 
-    /// Optional image(s) to attach to the prompt sent after resuming.
+    /// Optional image(s) or PDF(s) to attach to the prompt sent after resuming.
     pub images: Vec<PathBuf>,
 
     /// Prompt to send after resuming the session. If `-` is used, read from stdin.
@@ -250,7 +260,7 @@ pub struct ReviewArgs {
     #[arg(
         long = "uncommitted",
         default_value_t = false,
-        conflicts_with_all = ["base", "commit", "prompt"]
+        conflicts_with_all = ["base", "commit", "range", "patch", "prompt"]
     )]
     pub uncommitted: bool,
 
@@ -258,7 +268,7 @@ pub struct ReviewArgs {
     #[arg(
         long = "base",
         value_name = "BRANCH",
-        conflicts_with_all = ["uncommitted", "commit", "prompt"]
+        conflicts_with_all = ["uncommitted", "commit", "range", "patch", "prompt"]
     )]
     pub base: Option<String>,
 
@@ -266,7 +276,7 @@ pub struct ReviewArgs {
     #[arg(
         long = "commit",
         value_name = "SHA",
-        conflicts_with_all = ["uncommitted", "base", "prompt"]
+        conflicts_with_all = ["uncommitted", "base", "range", "patch", "prompt"]
     )]
     pub commit: Option<String>,
 
@@ -274,9 +284,44 @@ pub struct ReviewArgs {
     #[arg(long = "title", value_name = "TITLE", requires = "commit")]
     pub commit_title: Option<String>,
 
+    /// Review the changes between two commits, given as `FROM..TO`.
+    #[arg(
+        long = "range",
+        value_name = "FROM..TO",
+        conflicts_with_all = ["uncommitted", "base", "commit", "patch", "prompt"]
+    )]
+    pub range: Option<String>,
+
+    /// Review the changes described by a patch file.
+    #[arg(
+        long = "patch",
+        value_name = "FILE",
+        value_hint = clap::ValueHint::FilePath,
+        conflicts_with_all = ["uncommitted", "base", "commit", "range", "prompt"]
+    )]
+    pub patch: Option<PathBuf>,
+
     /// Custom review instructions. If `-` is used, read from stdin.
     #[arg(value_name = "PROMPT", value_hint = clap::ValueHint::Other)]
     pub prompt: Option<String>,
+
+    /// Format of the review findings written to stdout once the review completes.
+    #[arg(long = "output-format", value_enum, default_value_t = ReviewOutputFormat::Text)]
+    pub output_format: ReviewOutputFormat,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+#[value(rename_all = "kebab-case")]
+pub enum ReviewOutputFormat {
+    /// Plain-text review summary.
+    #[default]
+    Text,
+    /// SARIF 2.1.0 log for code-scanning dashboards.
+    Sarif,
+    /// GitHub Actions workflow commands that annotate the diff.
+    GithubAnnotations,
+    /// Code Climate issues, as consumed by GitLab code quality reports.
+    Codeclimate,
 }
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
@@ -4,6 +4,8 @@ use codex_core::config::Config;
 use codex_core::protocol::Event;
 use codex_core::protocol::SessionConfiguredEvent;
 
+use crate::exec_events::ThreadEvent;
+
 pub(crate) enum CodexStatus {
     Running,
     InitiateShutdown,
@@ -22,6 +24,10 @@ pub(crate) trait EventProcessor {
     /// Handle a single event emitted by the agent.
     fn process_event(&mut self, event: Event) -> CodexStatus;
 
+    /// Report progress of a `--script` run (`script.turn_result` and
+    /// `script.completed` events).
+    fn process_script_event(&mut self, _event: ThreadEvent) {}
+
     fn print_final_output(&mut self) {}
 }
 
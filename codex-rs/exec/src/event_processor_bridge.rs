@@ -51,6 +51,10 @@ impl EventProcessor for CallbackEventProcessor {
             _ => CodexStatus::Running,
         }
     }
+
+    fn process_script_event(&mut self, event: ThreadEvent) {
+        (self.callback)(event);
+    }
 }
 
 pub(crate) fn callback_event_processor(
@@ -46,6 +46,7 @@ use std::time::Instant;
 use crate::event_processor::CodexStatus;
 use crate::event_processor::EventProcessor;
 use crate::event_processor::handle_last_message;
+use crate::exec_events::ThreadEvent;
 use codex_common::create_config_summary_entries;
 use codex_protocol::plan_tool::StepStatus;
 use codex_protocol::plan_tool::UpdatePlanArgs;
@@ -774,7 +775,12 @@ impl EventProcessor for EventProcessorWithHumanOutput {
             | EventMsg::ExecCommandOutputDelta(_)
             | EventMsg::GetHistoryEntryResponse(_)
             | EventMsg::McpListToolsResponse(_)
+            | EventMsg::McpListChanged(_)
             | EventMsg::ListCustomPromptsResponse(_)
+            | EventMsg::ListUndoSnapshotsResponse(_)
+            | EventMsg::ListBackgroundProcessesResponse(_)
+            | EventMsg::BackgroundProcessOutput(_)
+            | EventMsg::RestorableBackgroundProcesses(_)
             | EventMsg::ListSkillsResponse(_)
             | EventMsg::ListRemoteSkillsResponse(_)
             | EventMsg::RemoteSkillDownloaded(_)
@@ -801,6 +807,38 @@ impl EventProcessor for EventProcessorWithHumanOutput {
         CodexStatus::Running
     }
 
+    fn process_script_event(&mut self, event: ThreadEvent) {
+        match event {
+            ThreadEvent::ScriptTurnResult(result) => {
+                let status = if result.passed {
+                    "passed".style(self.green)
+                } else {
+                    "failed".style(self.red)
+                };
+                ts_msg!(
+                    self,
+                    "{} {} {status}",
+                    "script turn".style(self.magenta).style(self.bold),
+                    result.index + 1
+                );
+                for failure in &result.failures {
+                    ts_msg!(self, "  {}", failure.style(self.red));
+                }
+            }
+            ThreadEvent::ScriptCompleted(summary) => {
+                ts_msg!(
+                    self,
+                    "{} {} passed, {} failed, {} skipped",
+                    "script".style(self.magenta).style(self.bold),
+                    summary.passed,
+                    summary.failed,
+                    summary.skipped
+                );
+            }
+            _ => {}
+        }
+    }
+
     fn print_final_output(&mut self) {
         if let Some(usage_info) = &self.last_total_token_usage {
             eprintln!(
@@ -876,4 +876,12 @@ impl EventProcessor for EventProcessorWithJsonOutput {
             _ => CodexStatus::Running,
         }
     }
+
+    #[allow(clippy::print_stdout)]
+    fn process_script_event(&mut self, event: ThreadEvent) {
+        match serde_json::to_string(&event) {
+            Ok(line) => println!("{line}"),
+            Err(e) => error!("Failed to serialize event: {e:?}"),
+        }
+    }
 }
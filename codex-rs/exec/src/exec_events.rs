@@ -40,6 +40,12 @@ pub enum ThreadEvent {
     /// Background notification emitted alongside an active turn.
     #[serde(rename = "background_event")]
     BackgroundEvent(BackgroundEventEvent),
+    /// Emitted after each turn of a `--script` run with the outcome of its assertions.
+    #[serde(rename = "script.turn_result")]
+    ScriptTurnResult(ScriptTurnResultEvent),
+    /// Emitted once a `--script` run finishes, summarizing every turn.
+    #[serde(rename = "script.completed")]
+    ScriptCompleted(ScriptCompletedEvent),
     /// Raw protocol event payload forwarded for consumers that need full fidelity.
     #[serde(rename = "raw_event")]
     Raw(RawEvent),
@@ -143,6 +149,26 @@ pub struct BackgroundEventEvent {
     pub message: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, TS)]
+pub struct ScriptTurnResultEvent {
+    /// Zero-based position of the turn in the script.
+    pub index: usize,
+    pub prompt: String,
+    pub passed: bool,
+    /// Human-readable description of every assertion that failed.
+    pub failures: Vec<String>,
+    /// Files modified during the turn, relative to the working directory when possible.
+    pub files_changed: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, TS)]
+pub struct ScriptCompletedEvent {
+    pub passed: usize,
+    pub failed: usize,
+    /// Turns that never ran because an earlier turn failed.
+    pub skipped: usize,
+}
+
 /// Canonical representation of a thread item and its domain-specific payload.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, TS)]
 pub struct ThreadItem {
@@ -10,14 +10,19 @@ mod event_processor_bridge;
 mod event_processor_with_human_output;
 pub mod event_processor_with_jsonl_output;
 pub mod exec_events;
+mod review_export;
+mod script;
 
+use clap::ValueEnum;
 pub use cli::Cli;
 pub use cli::Color;
 pub use cli::Command;
 pub use cli::PersonalityCliArg;
 pub use cli::ResumeArgs;
 pub use cli::ReviewArgs;
+pub use cli::ReviewOutputFormat;
 use codex_cloud_requirements::cloud_requirements_loader;
+use codex_common::oss::OssModelMetadataFetcher;
 use codex_common::oss::ensure_oss_provider_ready;
 use codex_common::oss::get_default_model_for_oss_provider;
 use codex_core::AuthManager;
@@ -43,12 +48,14 @@ use codex_core::protocol::Op;
 use codex_core::protocol::ReviewRequest;
 use codex_core::protocol::ReviewTarget;
 use codex_core::protocol::SessionSource;
+use codex_core::session_worktree::SessionWorktree;
 use codex_protocol::approvals::ElicitationAction;
 use codex_protocol::config_types::SandboxMode;
 use codex_protocol::user_input::UserInput;
 use codex_utils_absolute_path::AbsolutePathBuf;
 use event_processor_with_human_output::EventProcessorWithHumanOutput;
 use event_processor_with_jsonl_output::EventProcessorWithJsonOutput;
+use exec_events::ThreadEvent;
 use serde::de::DeserializeOwned;
 use serde_json::Value;
 use std::collections::HashSet;
@@ -70,6 +77,8 @@ use uuid::Uuid;
 use crate::cli::Command as ExecCommand;
 use crate::event_processor::CodexStatus;
 use crate::event_processor::EventProcessor;
+use crate::review_export::render_review_export;
+use crate::script::ScriptRunner;
 use codex_core::default_client::set_default_client_residency_requirement;
 use codex_core::default_client::set_default_originator;
 use codex_core::find_thread_path_by_id_str;
@@ -139,13 +148,15 @@ async fn run_main_with_event_processor(
         skip_git_repo_check,
         add_dir,
         ephemeral,
+        worktree,
         color,
         last_message_file,
+        script: script_path,
         json: json_mode,
         sandbox_mode: sandbox_mode_cli_arg,
         prompt,
         output_schema: output_schema_path,
-        config_overrides,
+        mut config_overrides,
         input_items,
         input_items_path,
         input_items_json,
@@ -171,6 +182,33 @@ async fn run_main_with_event_processor(
 
     let turn_personality = resolve_turn_personality(turn_personality);
 
+    let review_output_format = match command.as_ref() {
+        Some(ExecCommand::Review(args)) => args.output_format,
+        _ => ReviewOutputFormat::Text,
+    };
+    if json_mode && review_output_format != ReviewOutputFormat::Text {
+        anyhow::bail!("--output-format cannot be combined with --json");
+    }
+    if script_path.is_some() {
+        let has_resume_prompt = matches!(
+            command.as_ref(),
+            Some(ExecCommand::Resume(args)) if args.prompt.is_some() || !args.images.is_empty()
+        );
+        if matches!(command, Some(ExecCommand::Review(_))) {
+            anyhow::bail!("--script cannot be combined with review");
+        }
+        if prompt.is_some()
+            || has_resume_prompt
+            || !images.is_empty()
+            || input_items.is_some()
+            || output_schema_path.is_some()
+        {
+            anyhow::bail!(
+                "--script cannot be combined with a prompt, images, input items or --output-schema"
+            );
+        }
+    }
+
     let (stdout_with_ansi, stderr_with_ansi) = match color {
         cli::Color::Always => (true, true),
         cli::Color::Never => (false, false),
@@ -201,6 +239,12 @@ async fn run_main_with_event_processor(
         sandbox_mode_cli_arg.map(Into::<SandboxMode>::into)
     };
 
+    if worktree {
+        config_overrides
+            .raw_overrides
+            .push("worktree.enabled=true".to_string());
+    }
+
     // Parse `-c` overrides from the CLI.
     let cli_kv_overrides = match config_overrides.parse_overrides() {
         Ok(v) => v,
@@ -317,19 +361,42 @@ async fn run_main_with_event_processor(
         additional_writable_roots: add_dir,
     };
 
-    let config = ConfigBuilder::default()
-        .cli_overrides(cli_kv_overrides)
-        .harness_overrides(overrides)
-        .cloud_requirements(cloud_requirements)
+    let mut config = ConfigBuilder::default()
+        .cli_overrides(cli_kv_overrides.clone())
+        .harness_overrides(overrides.clone())
+        .cloud_requirements(cloud_requirements.clone())
         .build()
         .await?;
     set_default_client_residency_requirement(config.enforce_residency.value());
 
+    // Run the whole session inside a dedicated worktree when requested.
+    let session_worktree = if config.worktree.enabled {
+        let session_worktree = SessionWorktree::create(&config)
+            .map_err(|err| anyhow::anyhow!("Failed to create session worktree: {err}"))?;
+        config = ConfigBuilder::default()
+            .cli_overrides(cli_kv_overrides)
+            .harness_overrides(ConfigOverrides {
+                cwd: Some(session_worktree.cwd().to_path_buf()),
+                ..overrides
+            })
+            .cloud_requirements(cloud_requirements)
+            .build()
+            .await?;
+        Some(session_worktree)
+    } else {
+        None
+    };
+
     if let Err(err) = enforce_login_restrictions(&config) {
         eprintln!("{err}");
         std::process::exit(1);
     }
 
+    let mut script = script_path
+        .as_deref()
+        .map(|path| ScriptRunner::load(path, config.cwd.as_path()))
+        .transpose()?;
+
     let otel = match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
         codex_core::otel_init::build_provider(&config, env!("CARGO_PKG_VERSION"), None, false)
     })) {
@@ -411,6 +478,9 @@ async fn run_main_with_event_processor(
         auth_manager.clone(),
         SessionSource::Exec,
     ));
+    thread_manager
+        .get_models_manager()
+        .set_local_model_metadata_fetcher(Arc::new(OssModelMetadataFetcher));
     let default_model = thread_manager
         .get_models_manager()
         .get_default_model(&config.model, &config, RefreshStrategy::OnlineIfUncached)
@@ -440,47 +510,63 @@ async fn run_main_with_event_processor(
             .await?
     };
 
-    let (initial_operation, prompt_summary) = match (command, prompt, images, input_items) {
-        (Some(ExecCommand::Review(review_cli)), _, _, _) => {
-            let review_request = build_review_request(review_cli)?;
-            let summary = codex_core::review_prompts::user_facing_hint(&review_request.target);
-            (InitialOperation::Review { review_request }, summary)
-        }
-        (Some(ExecCommand::Resume(args)), root_prompt, imgs, input_items) => {
-            let prompt_arg = args
-                .prompt
-                .clone()
-                .or_else(|| {
-                    if args.last {
-                        args.session_id.clone()
-                    } else {
-                        None
-                    }
-                })
-                .or(root_prompt);
-            let images = imgs.into_iter().chain(args.images.into_iter()).collect();
-            let (items, prompt_text) =
-                build_user_turn_items_and_summary(input_items, prompt_arg, images);
-            let output_schema = load_output_schema(output_schema_path.clone());
-            (
-                InitialOperation::UserTurn {
-                    items,
-                    output_schema,
-                },
-                prompt_text,
-            )
-        }
-        (None, root_prompt, imgs, input_items) => {
-            let (items, prompt_text) =
-                build_user_turn_items_and_summary(input_items, root_prompt, imgs);
-            let output_schema = load_output_schema(output_schema_path);
-            (
-                InitialOperation::UserTurn {
-                    items,
-                    output_schema,
-                },
-                prompt_text,
-            )
+    let (initial_operation, prompt_summary) = if let Some(script) = script.as_ref() {
+        let turn = script.current_turn();
+        let summary = format!(
+            "script {} ({} turns)",
+            script_path.as_deref().unwrap_or(Path::new("")).display(),
+            script.len()
+        );
+        (
+            InitialOperation::UserTurn {
+                items: turn.items,
+                output_schema: turn.output_schema,
+            },
+            summary,
+        )
+    } else {
+        match (command, prompt, images, input_items) {
+            (Some(ExecCommand::Review(review_cli)), _, _, _) => {
+                let review_request = build_review_request(review_cli)?;
+                let summary = codex_core::review_prompts::user_facing_hint(&review_request.target);
+                (InitialOperation::Review { review_request }, summary)
+            }
+            (Some(ExecCommand::Resume(args)), root_prompt, imgs, input_items) => {
+                let prompt_arg = args
+                    .prompt
+                    .clone()
+                    .or_else(|| {
+                        if args.last {
+                            args.session_id.clone()
+                        } else {
+                            None
+                        }
+                    })
+                    .or(root_prompt);
+                let images = imgs.into_iter().chain(args.images.into_iter()).collect();
+                let (items, prompt_text) =
+                    build_user_turn_items_and_summary(input_items, prompt_arg, images);
+                let output_schema = load_output_schema(output_schema_path.clone());
+                (
+                    InitialOperation::UserTurn {
+                        items,
+                        output_schema,
+                    },
+                    prompt_text,
+                )
+            }
+            (None, root_prompt, imgs, input_items) => {
+                let (items, prompt_text) =
+                    build_user_turn_items_and_summary(input_items, root_prompt, imgs);
+                let output_schema = load_output_schema(output_schema_path);
+                (
+                    InitialOperation::UserTurn {
+                        items,
+                        output_schema,
+                    },
+                    prompt_text,
+                )
+            }
         }
     };
 
@@ -536,25 +622,25 @@ async fn run_main_with_event_processor(
         });
     }
 
+    let user_turn = |items: Vec<UserInput>, output_schema: Option<Value>| Op::UserTurn {
+        items,
+        cwd: default_cwd.clone(),
+        approval_policy: default_approval_policy,
+        sandbox_policy: default_sandbox_policy.clone(),
+        model: default_model.clone(),
+        effort: default_effort,
+        summary: default_summary,
+        final_output_json_schema: output_schema,
+        collaboration_mode: None,
+        personality: turn_personality,
+    };
+
     match initial_operation {
         InitialOperation::UserTurn {
             items,
             output_schema,
         } => {
-            let task_id = thread
-                .submit(Op::UserTurn {
-                    items,
-                    cwd: default_cwd,
-                    approval_policy: default_approval_policy,
-                    sandbox_policy: default_sandbox_policy.clone(),
-                    model: default_model,
-                    effort: default_effort,
-                    summary: default_summary,
-                    final_output_json_schema: output_schema,
-                    collaboration_mode: None,
-                    personality: turn_personality,
-                })
-                .await?;
+            let task_id = thread.submit(user_turn(items, output_schema)).await?;
             info!("Sent prompt with event ID: {task_id}");
             task_id
         }
@@ -569,6 +655,7 @@ async fn run_main_with_event_processor(
     // Track whether a fatal error was reported by the server so we can
     // exit with a non-zero status for automation-friendly signaling.
     let mut error_seen = false;
+    let mut review_output = None;
     while let Some(envelope) = rx.recv().await {
         let ThreadEventEnvelope {
             thread_id,
@@ -588,13 +675,43 @@ async fn run_main_with_event_processor(
         if matches!(event.msg, EventMsg::Error(_)) {
             error_seen = true;
         }
+        if let EventMsg::ExitedReviewMode(ev) = &event.msg
+            && thread_id == primary_thread_id
+        {
+            review_output = ev.review_output.clone();
+        }
         if thread_id != primary_thread_id && matches!(&event.msg, EventMsg::TurnComplete(_)) {
             continue;
         }
+        let script_turn_result = match script.as_mut() {
+            Some(script) if thread_id == primary_thread_id => {
+                script.observe(&event.msg);
+                match &event.msg {
+                    EventMsg::TurnComplete(ev) => {
+                        Some(script.finish_turn(ev.last_agent_message.as_deref()))
+                    }
+                    EventMsg::TurnAborted(_) => Some(script.abort_turn()),
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
         let shutdown = event_processor.process_event(event);
         if thread_id != primary_thread_id && matches!(shutdown, CodexStatus::InitiateShutdown) {
             continue;
         }
+        if let (Some(result), Some(script)) = (script_turn_result, script.as_mut()) {
+            event_processor.process_script_event(ThreadEvent::ScriptTurnResult(result));
+            if script.advance() {
+                let turn = script.current_turn();
+                let task_id = thread
+                    .submit(user_turn(turn.items, turn.output_schema))
+                    .await?;
+                info!("Sent script turn with event ID: {task_id}");
+                continue;
+            }
+            event_processor.process_script_event(ThreadEvent::ScriptCompleted(script.summary()));
+        }
         match shutdown {
             CodexStatus::Running => continue,
             CodexStatus::InitiateShutdown => {
@@ -604,8 +721,45 @@ async fn run_main_with_event_processor(
             CodexStatus::Shutdown => continue,
         }
     }
-    event_processor.print_final_output();
-    if error_seen {
+    let review_root =
+        get_git_repo_root(config.cwd.as_path()).unwrap_or_else(|| config.cwd.to_path_buf());
+    // An export without review output would read as a clean review, so fail
+    // instead of rendering an empty report.
+    let review_export = match review_output {
+        Some(output) => Ok(render_review_export(
+            review_output_format,
+            &output,
+            &review_root,
+        )),
+        None if review_output_format != ReviewOutputFormat::Text => Err(anyhow::anyhow!(
+            "review finished without output; no {} report was written",
+            review_output_format
+                .to_possible_value()
+                .map_or_else(String::new, |value| value.get_name().to_string())
+        )),
+        None => Ok(None),
+    };
+    match &review_export {
+        #[allow(clippy::print_stdout)]
+        Ok(Some(export)) => println!("{export}"),
+        Ok(None) => event_processor.print_final_output(),
+        Err(_) => {}
+    }
+    if let Some(session_worktree) = session_worktree.as_ref() {
+        // There is nobody to ask in headless mode, so `ask` keeps the worktree.
+        match session_worktree.finish(config.worktree.on_exit) {
+            Ok(outcome) => eprintln!("{}", session_worktree.outcome_message(outcome)),
+            Err(err) => {
+                eprintln!(
+                    "Failed to finish worktree {}: {err}",
+                    session_worktree.path().display()
+                );
+                error_seen = true;
+            }
+        }
+    }
+    review_export?;
+    if error_seen || script.as_ref().is_some_and(|script| !script.success()) {
         std::process::exit(1);
     }
 
@@ -899,6 +1053,20 @@ fn build_review_request(args: ReviewArgs) -> anyhow::Result<ReviewRequest> {
             sha,
             title: args.commit_title,
         }
+    } else if let Some(range) = args.range {
+        let Some((from, to)) = range.split_once("..") else {
+            anyhow::bail!("Commit range must be of the form FROM..TO");
+        };
+        let (from, to) = (from.trim(), to.trim());
+        if from.is_empty() || to.is_empty() || to.starts_with('.') {
+            anyhow::bail!("Commit range must be of the form FROM..TO");
+        }
+        ReviewTarget::CommitRange {
+            from: from.to_string(),
+            to: to.to_string(),
+        }
+    } else if let Some(path) = args.patch {
+        ReviewTarget::PatchFile { path }
     } else if let Some(prompt_arg) = args.prompt {
         let prompt = resolve_prompt(Some(prompt_arg)).trim().to_string();
         if prompt.is_empty() {
@@ -909,7 +1077,7 @@ fn build_review_request(args: ReviewArgs) -> anyhow::Result<ReviewRequest> {
         }
     } else {
         anyhow::bail!(
-            "Specify --uncommitted, --base, --commit, or provide custom review instructions"
+            "Specify --uncommitted, --base, --commit, --range, --patch, or provide custom review instructions"
         );
     };
 
@@ -1071,7 +1239,10 @@ mod tests {
             base: None,
             commit: None,
             commit_title: None,
+            range: None,
+            patch: None,
             prompt: None,
+            output_format: ReviewOutputFormat::Text,
         })
         .expect("builds uncommitted review request");
 
@@ -1090,7 +1261,10 @@ mod tests {
             base: None,
             commit: Some("123456789".to_string()),
             commit_title: Some("Add review command".to_string()),
+            range: None,
+            patch: None,
             prompt: None,
+            output_format: ReviewOutputFormat::Text,
         })
         .expect("builds commit review request");
 
@@ -1105,6 +1279,47 @@ mod tests {
         assert_eq!(request, expected);
     }
 
+    #[test]
+    fn builds_commit_range_review_request() {
+        let request = build_review_request(ReviewArgs {
+            uncommitted: false,
+            base: None,
+            commit: None,
+            commit_title: None,
+            range: Some("main..feature".to_string()),
+            patch: None,
+            prompt: None,
+            output_format: ReviewOutputFormat::Text,
+        })
+        .expect("builds commit range review request");
+
+        let expected = ReviewRequest {
+            target: ReviewTarget::CommitRange {
+                from: "main".to_string(),
+                to: "feature".to_string(),
+            },
+            user_facing_hint: None,
+        };
+
+        assert_eq!(request, expected);
+    }
+
+    #[test]
+    fn rejects_malformed_commit_range() {
+        let result = build_review_request(ReviewArgs {
+            uncommitted: false,
+            base: None,
+            commit: None,
+            commit_title: None,
+            range: Some("main".to_string()),
+            patch: None,
+            prompt: None,
+            output_format: ReviewOutputFormat::Text,
+        });
+
+        assert!(result.is_err());
+    }
+
     #[test]
     fn builds_custom_review_request_trims_prompt() {
         let request = build_review_request(ReviewArgs {
@@ -1112,7 +1327,10 @@ mod tests {
             base: None,
             commit: None,
             commit_title: None,
+            range: None,
+            patch: None,
             prompt: Some("  custom review instructions  ".to_string()),
+            output_format: ReviewOutputFormat::Text,
         })
         .expect("builds custom review request");
 
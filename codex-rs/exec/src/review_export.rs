@@ -0,0 +1,408 @@
+//! Machine-readable exports of review findings for CI systems.
+//!
+//! `codex exec review --output-format <format>` renders the final
+//! [`ReviewOutputEvent`] with one of these exporters instead of the plain-text
+//! summary. Finding locations are rewritten relative to the repository root so
+//! code-scanning dashboards can map them onto the checked-out tree; findings
+//! outside the repository keep their absolute path.
+
+use std::path::Path;
+use std::path::PathBuf;
+
+use codex_core::protocol::ReviewFinding;
+use codex_core::protocol::ReviewOutputEvent;
+use serde_json::Value;
+use serde_json::json;
+use sha2::Digest;
+use sha2::Sha256;
+
+use crate::cli::ReviewOutputFormat;
+
+const TOOL_NAME: &str = "codex-review";
+const TOOL_INFORMATION_URI: &str = "https://github.com/openai/codex";
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// Render `output` in the requested format. Returns `None` for
+/// [`ReviewOutputFormat::Text`], which keeps the default human-readable output.
+pub(crate) fn render_review_export(
+    format: ReviewOutputFormat,
+    output: &ReviewOutputEvent,
+    repo_root: &Path,
+) -> Option<String> {
+    let repo_root = canonicalize_or_keep(repo_root);
+    let findings: Vec<ExportedFinding<'_>> = output
+        .findings
+        .iter()
+        .map(|finding| ExportedFinding::new(finding, &repo_root))
+        .collect();
+    match format {
+        ReviewOutputFormat::Text => None,
+        ReviewOutputFormat::Sarif => Some(render_json(&sarif_log(&findings))),
+        ReviewOutputFormat::GithubAnnotations => Some(github_annotations(&findings)),
+        ReviewOutputFormat::Codeclimate => Some(render_json(&codeclimate_issues(&findings))),
+    }
+}
+
+/// Resolves symlinks and `..` so paths reported by the model compare equal
+/// to the repository root. Paths that no longer exist are used as given.
+fn canonicalize_or_keep(path: &Path) -> PathBuf {
+    dunce::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+/// A finding with its location normalized relative to the repository root.
+struct ExportedFinding<'a> {
+    finding: &'a ReviewFinding,
+    /// `/`-separated path relative to the repository root, or the absolute
+    /// path when the file is outside it.
+    path: String,
+    in_repo: bool,
+    start_line: u32,
+    end_line: u32,
+}
+
+impl<'a> ExportedFinding<'a> {
+    /// `repo_root` must already be canonical.
+    fn new(finding: &'a ReviewFinding, repo_root: &Path) -> Self {
+        let location = &finding.code_location;
+        let absolute = canonicalize_or_keep(&location.absolute_file_path);
+        let relative = absolute
+            .strip_prefix(repo_root)
+            .ok()
+            .filter(|relative| relative.components().next().is_some());
+        let path = match relative {
+            Some(relative) => relative
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/"),
+            None => absolute.to_string_lossy().into_owned(),
+        };
+        // Review line ranges are inclusive and 1-based; guard against models
+        // emitting 0 or an inverted range.
+        let start_line = location.line_range.start.max(1);
+        let end_line = location.line_range.end.max(start_line);
+        Self {
+            finding,
+            path,
+            in_repo: relative.is_some(),
+            start_line,
+            end_line,
+        }
+    }
+
+    /// SARIF artifact location: repository files resolve against
+    /// `%SRCROOT%`, anything else is an absolute `file://` URI.
+    fn artifact_location(&self) -> Value {
+        if self.in_repo {
+            json!({ "uri": self.path, "uriBaseId": "%SRCROOT%" })
+        } else {
+            let path = self.path.replace('\\', "/");
+            let separator = if path.starts_with('/') { "" } else { "/" };
+            json!({ "uri": format!("file://{separator}{path}") })
+        }
+    }
+
+    fn message(&self) -> String {
+        let title = self.finding.title.trim();
+        let body = self.finding.body.trim();
+        if body.is_empty() {
+            title.to_string()
+        } else {
+            format!("{title}\n\n{body}")
+        }
+    }
+
+    fn fingerprint(&self) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(self.path.as_bytes());
+        hasher.update([0]);
+        hasher.update(self.finding.title.trim().as_bytes());
+        hasher.update([0]);
+        hasher.update(self.start_line.to_le_bytes());
+        hasher.update(self.end_line.to_le_bytes());
+        format!("{:x}", hasher.finalize())
+    }
+
+    fn rule_id(&self) -> String {
+        format!("{TOOL_NAME}/p{}", self.priority())
+    }
+
+    fn priority(&self) -> i32 {
+        self.finding.priority.clamp(0, 3)
+    }
+}
+
+fn render_json(value: &Value) -> String {
+    serde_json::to_string_pretty(value).unwrap_or_else(|_| value.to_string())
+}
+
+fn sarif_level(priority: i32) -> &'static str {
+    match priority {
+        0 | 1 => "error",
+        2 => "warning",
+        _ => "note",
+    }
+}
+
+fn sarif_log(findings: &[ExportedFinding<'_>]) -> Value {
+    let mut priorities: Vec<i32> = findings.iter().map(ExportedFinding::priority).collect();
+    priorities.sort_unstable();
+    priorities.dedup();
+    let rules: Vec<Value> = priorities
+        .into_iter()
+        .map(|priority| {
+            json!({
+                "id": format!("{TOOL_NAME}/p{priority}"),
+                "name": format!("CodexReviewP{priority}"),
+                "shortDescription": { "text": format!("Codex review finding (P{priority})") },
+                "defaultConfiguration": { "level": sarif_level(priority) },
+            })
+        })
+        .collect();
+    let results: Vec<Value> = findings
+        .iter()
+        .map(|finding| {
+            json!({
+                "ruleId": finding.rule_id(),
+                "level": sarif_level(finding.priority()),
+                "message": { "text": finding.message() },
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": finding.artifact_location(),
+                        "region": {
+                            "startLine": finding.start_line,
+                            "endLine": finding.end_line,
+                        },
+                    },
+                }],
+                "partialFingerprints": { "codexReview/v1": finding.fingerprint() },
+                "properties": {
+                    "priority": finding.finding.priority,
+                    "confidence": finding.finding.confidence_score,
+                },
+            })
+        })
+        .collect();
+    json!({
+        "$schema": SARIF_SCHEMA,
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": TOOL_NAME,
+                    "informationUri": TOOL_INFORMATION_URI,
+                    "rules": rules,
+                },
+            },
+            "results": results,
+        }],
+    })
+}
+
+/// Render GitHub Actions workflow commands (`::error file=...::message`), one
+/// per finding, so the runner annotates the diff directly.
+fn github_annotations(findings: &[ExportedFinding<'_>]) -> String {
+    findings
+        .iter()
+        .map(|finding| {
+            let command = match finding.priority() {
+                0 | 1 => "error",
+                2 => "warning",
+                _ => "notice",
+            };
+            let title = format!("[P{}] {}", finding.priority(), finding.finding.title.trim());
+            format!(
+                "::{command} file={},line={},endLine={},title={}::{}",
+                escape_annotation_property(&finding.path),
+                finding.start_line,
+                finding.end_line,
+                escape_annotation_property(&title),
+                escape_annotation_data(finding.finding.body.trim()),
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn escape_annotation_data(value: &str) -> String {
+    value
+        .replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+fn escape_annotation_property(value: &str) -> String {
+    escape_annotation_data(value)
+        .replace(':', "%3A")
+        .replace(',', "%2C")
+}
+
+fn codeclimate_severity(priority: i32) -> &'static str {
+    match priority {
+        0 => "critical",
+        1 => "major",
+        2 => "minor",
+        _ => "info",
+    }
+}
+
+/// Render a Code Climate issue array, the format GitLab's code quality
+/// report consumes.
+fn codeclimate_issues(findings: &[ExportedFinding<'_>]) -> Value {
+    Value::Array(
+        findings
+            .iter()
+            .map(|finding| {
+                json!({
+                    "type": "issue",
+                    "check_name": finding.rule_id(),
+                    "description": finding.finding.title.trim(),
+                    "content": { "body": finding.finding.body.trim() },
+                    "categories": ["Bug Risk"],
+                    "severity": codeclimate_severity(finding.priority()),
+                    "fingerprint": finding.fingerprint(),
+                    "location": {
+                        "path": finding.path,
+                        "lines": {
+                            "begin": finding.start_line,
+                            "end": finding.end_line,
+                        },
+                    },
+                })
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codex_core::protocol::ReviewCodeLocation;
+    use codex_core::protocol::ReviewLineRange;
+    use pretty_assertions::assert_eq;
+    use std::path::PathBuf;
+
+    fn output_with_finding(priority: i32, start: u32, end: u32) -> ReviewOutputEvent {
+        ReviewOutputEvent {
+            findings: vec![ReviewFinding {
+                title: "Off-by-one, in loop".to_string(),
+                body: "The loop skips\nthe last element.".to_string(),
+                confidence_score: 0.8,
+                priority,
+                code_location: ReviewCodeLocation {
+                    absolute_file_path: PathBuf::from("/repo/src/lib.rs"),
+                    line_range: ReviewLineRange { start, end },
+                },
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn text_format_has_no_export() {
+        let output = output_with_finding(1, 3, 5);
+        assert_eq!(
+            render_review_export(ReviewOutputFormat::Text, &output, Path::new("/repo")),
+            None
+        );
+    }
+
+    #[test]
+    fn sarif_maps_locations_relative_to_repo_root() {
+        let output = output_with_finding(1, 3, 5);
+        let rendered = render_review_export(ReviewOutputFormat::Sarif, &output, Path::new("/repo"))
+            .expect("sarif export");
+        let value: Value = serde_json::from_str(&rendered).expect("valid json");
+
+        let result = &value["runs"][0]["results"][0];
+        assert_eq!(result["ruleId"], "codex-review/p1");
+        assert_eq!(result["level"], "error");
+        let location = &result["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "src/lib.rs");
+        assert_eq!(location["region"]["startLine"], 3);
+        assert_eq!(location["region"]["endLine"], 5);
+        assert_eq!(
+            value["runs"][0]["tool"]["driver"]["rules"][0]["id"],
+            "codex-review/p1"
+        );
+    }
+
+    #[test]
+    fn github_annotations_escape_properties_and_data() {
+        let output = output_with_finding(2, 0, 0);
+        let rendered = render_review_export(
+            ReviewOutputFormat::GithubAnnotations,
+            &output,
+            Path::new("/repo"),
+        )
+        .expect("annotations export");
+
+        assert_eq!(
+            rendered,
+            "::warning file=src/lib.rs,line=1,endLine=1,title=[P2] Off-by-one%2C in loop::The loop skips%0Athe last element."
+        );
+    }
+
+    #[test]
+    fn codeclimate_reports_severity_and_lines() {
+        let output = output_with_finding(0, 10, 12);
+        let rendered =
+            render_review_export(ReviewOutputFormat::Codeclimate, &output, Path::new("/repo"))
+                .expect("codeclimate export");
+        let value: Value = serde_json::from_str(&rendered).expect("valid json");
+
+        let issue = &value[0];
+        assert_eq!(issue["severity"], "critical");
+        assert_eq!(issue["location"]["path"], "src/lib.rs");
+        assert_eq!(issue["location"]["lines"]["begin"], 10);
+        assert_eq!(issue["location"]["lines"]["end"], 12);
+        assert_eq!(issue["fingerprint"].as_str().map(str::len), Some(64));
+    }
+
+    #[test]
+    fn findings_outside_repo_root_keep_their_absolute_path() {
+        let mut output = output_with_finding(1, 3, 5);
+        let mut outside = output.findings[0].clone();
+        outside.code_location.absolute_file_path = PathBuf::from("/usr/include/stdio.h");
+        output.findings.push(outside);
+
+        let sarif = render_review_export(ReviewOutputFormat::Sarif, &output, Path::new("/repo"))
+            .expect("sarif export");
+        let sarif: Value = serde_json::from_str(&sarif).expect("valid json");
+        let locations: Vec<&Value> = sarif["runs"][0]["results"]
+            .as_array()
+            .expect("results")
+            .iter()
+            .map(|result| &result["locations"][0]["physicalLocation"]["artifactLocation"])
+            .collect();
+        assert_eq!(
+            locations,
+            vec![
+                &json!({ "uri": "src/lib.rs", "uriBaseId": "%SRCROOT%" }),
+                &json!({ "uri": "file:///usr/include/stdio.h" }),
+            ]
+        );
+
+        let codeclimate =
+            render_review_export(ReviewOutputFormat::Codeclimate, &output, Path::new("/repo"))
+                .expect("codeclimate export");
+        let codeclimate: Value = serde_json::from_str(&codeclimate).expect("valid json");
+        assert_eq!(codeclimate[1]["location"]["path"], "/usr/include/stdio.h");
+    }
+
+    #[test]
+    fn repo_root_and_finding_paths_are_canonicalized() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let repo = dir.path().join("repo");
+        std::fs::create_dir_all(repo.join("src")).expect("create repo");
+        std::fs::write(repo.join("src/lib.rs"), "").expect("write file");
+        let mut output = output_with_finding(2, 1, 1);
+        output.findings[0].code_location.absolute_file_path = repo.join("src/../src/lib.rs");
+
+        let rendered =
+            render_review_export(ReviewOutputFormat::Codeclimate, &output, &repo.join("."))
+                .expect("codeclimate export");
+        let value: Value = serde_json::from_str(&rendered).expect("valid json");
+        assert_eq!(value[0]["location"]["path"], "src/lib.rs");
+    }
+}
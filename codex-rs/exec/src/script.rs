@@ -0,0 +1,579 @@
+//! Multi-turn scripts for `codex exec --script`.
+//!
+//! A script is a list of turns executed sequentially in a single thread. It
+//! can be written as YAML (either a list of turns or a mapping with `turns`
+//! and `continue_on_failure`) or as JSONL with one turn object per line. Each
+//! turn carries a prompt, an optional output schema and optional assertions
+//! that are checked once the turn completes.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::hash::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::path::Path;
+use std::path::PathBuf;
+
+use anyhow::Context;
+use codex_core::protocol::EventMsg;
+use codex_protocol::user_input::UserInput;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::exec_events::ScriptCompletedEvent;
+use crate::exec_events::ScriptTurnResultEvent;
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum ScriptFile {
+    Turns(Vec<ScriptTurn>),
+    Document {
+        #[serde(default)]
+        continue_on_failure: bool,
+        turns: Vec<ScriptTurn>,
+    },
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+struct ScriptTurn {
+    prompt: String,
+    /// Either an inline JSON Schema or a path to one, relative to the script.
+    #[serde(default)]
+    output_schema: Option<Value>,
+    /// Images to attach, relative to the script.
+    #[serde(default)]
+    images: Vec<PathBuf>,
+    #[serde(default)]
+    expect: TurnExpectations,
+}
+
+/// Assertions evaluated against the outcome of a single turn.
+#[derive(Debug, Clone, Default, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+struct TurnExpectations {
+    /// Substrings that must all appear in the final agent message.
+    #[serde(default)]
+    final_message_contains: Vec<String>,
+    /// Substrings that must not appear in the final agent message.
+    #[serde(default)]
+    final_message_not_contains: Vec<String>,
+    /// Regular expression the final agent message must match.
+    #[serde(default)]
+    final_message_matches: Option<String>,
+    /// JSON value the final agent message must contain. Objects match when
+    /// every expected key matches; other values must be equal.
+    #[serde(default)]
+    final_message_json: Option<Value>,
+    /// Paths, relative to the working directory, that the turn must modify.
+    #[serde(default)]
+    files_changed: Vec<PathBuf>,
+    /// Require that the turn leaves every file untouched.
+    #[serde(default)]
+    no_files_changed: bool,
+}
+
+/// Drives a loaded script: hands out the next turn to submit and evaluates
+/// each finished turn against its expectations.
+pub(crate) struct ScriptRunner {
+    turns: Vec<ScriptTurn>,
+    continue_on_failure: bool,
+    cwd: PathBuf,
+    /// Index of the turn currently being executed.
+    current: usize,
+    changed_files: BTreeSet<PathBuf>,
+    /// Dirty files under `cwd` when the current turn started, so edits made
+    /// by shell commands are noticed too. `None` outside a git repository.
+    baseline: Option<DirtyFiles>,
+    /// Set when a turn was interrupted; the script never continues after that.
+    aborted: bool,
+    passed: usize,
+    failed: usize,
+}
+
+/// Input for a single scripted turn.
+pub(crate) struct ScriptedTurnInput {
+    pub(crate) items: Vec<UserInput>,
+    pub(crate) output_schema: Option<Value>,
+}
+
+impl ScriptRunner {
+    pub(crate) fn load(path: &Path, cwd: &Path) -> anyhow::Result<Self> {
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read script file {}", path.display()))?;
+        let is_jsonl = path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("jsonl"));
+        let (continue_on_failure, turns) = if is_jsonl {
+            (false, parse_jsonl(&contents)?)
+        } else {
+            match serde_yaml::from_str::<ScriptFile>(&contents)
+                .with_context(|| format!("Failed to parse script file {}", path.display()))?
+            {
+                ScriptFile::Turns(turns) => (false, turns),
+                ScriptFile::Document {
+                    continue_on_failure,
+                    turns,
+                } => (continue_on_failure, turns),
+            }
+        };
+        let base_dir = path.parent().unwrap_or_else(|| Path::new("."));
+        Self::new(turns, continue_on_failure, base_dir, cwd)
+    }
+
+    fn new(
+        mut turns: Vec<ScriptTurn>,
+        continue_on_failure: bool,
+        base_dir: &Path,
+        cwd: &Path,
+    ) -> anyhow::Result<Self> {
+        if turns.is_empty() {
+            anyhow::bail!("Script does not contain any turns");
+        }
+        for (index, turn) in turns.iter_mut().enumerate() {
+            let turn_number = index + 1;
+            if turn.prompt.trim().is_empty() {
+                anyhow::bail!("Script turn {turn_number} has an empty prompt");
+            }
+            if let Some(pattern) = turn.expect.final_message_matches.as_deref() {
+                regex_lite::Regex::new(pattern).with_context(|| {
+                    format!("Script turn {turn_number} has an invalid final_message_matches")
+                })?;
+            }
+            if turn.expect.no_files_changed && !turn.expect.files_changed.is_empty() {
+                anyhow::bail!(
+                    "Script turn {turn_number} cannot combine files_changed with no_files_changed"
+                );
+            }
+            if let Some(Value::String(schema_path)) = turn.output_schema.as_ref() {
+                let schema_path = base_dir.join(schema_path);
+                let schema = std::fs::read_to_string(&schema_path).with_context(|| {
+                    format!(
+                        "Failed to read output schema file {}",
+                        schema_path.display()
+                    )
+                })?;
+                let schema = serde_json::from_str(&schema).with_context(|| {
+                    format!(
+                        "Output schema file {} is not valid JSON",
+                        schema_path.display()
+                    )
+                })?;
+                turn.output_schema = Some(schema);
+            }
+            for image in &mut turn.images {
+                *image = base_dir.join(&*image);
+            }
+        }
+        Ok(Self {
+            turns,
+            continue_on_failure,
+            cwd: cwd.to_path_buf(),
+            current: 0,
+            changed_files: BTreeSet::new(),
+            baseline: dirty_files(cwd),
+            aborted: false,
+            passed: 0,
+            failed: 0,
+        })
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.turns.len()
+    }
+
+    /// Input for the turn that is about to run.
+    pub(crate) fn current_turn(&self) -> ScriptedTurnInput {
+        let turn = &self.turns[self.current];
+        let mut items: Vec<UserInput> = turn
+            .images
+            .iter()
+            .map(|path| UserInput::LocalImage { path: path.clone() })
+            .collect();
+        items.push(UserInput::Text {
+            text: turn.prompt.clone(),
+            // CLI input doesn't track UI element ranges, so none are available here.
+            text_elements: Vec::new(),
+        });
+        ScriptedTurnInput {
+            items,
+            output_schema: turn.output_schema.clone(),
+        }
+    }
+
+    /// Record file changes reported by patches in the turn that is currently
+    /// running. The working tree is compared as well once the turn ends.
+    pub(crate) fn observe(&mut self, msg: &EventMsg) {
+        if let EventMsg::PatchApplyEnd(ev) = msg
+            && ev.success
+        {
+            for (path, change) in &ev.changes {
+                self.changed_files.insert(self.cwd.join(path));
+                if let codex_core::protocol::FileChange::Update {
+                    move_path: Some(move_path),
+                    ..
+                } = change
+                {
+                    self.changed_files.insert(self.cwd.join(move_path));
+                }
+            }
+        }
+    }
+
+    /// Evaluate the current turn once it completed with `last_agent_message`.
+    pub(crate) fn finish_turn(
+        &mut self,
+        last_agent_message: Option<&str>,
+    ) -> ScriptTurnResultEvent {
+        self.record_tree_changes();
+        let failures = self.evaluate(last_agent_message);
+        self.record_result(failures)
+    }
+
+    /// Mark the current turn as failed because it never completed.
+    pub(crate) fn abort_turn(&mut self) -> ScriptTurnResultEvent {
+        self.record_tree_changes();
+        self.aborted = true;
+        self.record_result(vec!["turn was aborted before completing".to_string()])
+    }
+
+    /// Advance to the next turn. Returns `false` when the script is done,
+    /// either because every turn ran or because a failure stopped it.
+    pub(crate) fn advance(&mut self) -> bool {
+        if self.aborted || (self.failed > 0 && !self.continue_on_failure) {
+            return false;
+        }
+        self.current += 1;
+        self.changed_files.clear();
+        self.baseline = dirty_files(&self.cwd);
+        self.current < self.turns.len()
+    }
+
+    /// Adds every file whose contents differ from the start of the turn.
+    fn record_tree_changes(&mut self) {
+        let (Some(before), Some(after)) = (self.baseline.as_ref(), dirty_files(&self.cwd)) else {
+            return;
+        };
+        let changed: Vec<PathBuf> = before
+            .keys()
+            .chain(after.keys())
+            .filter(|path| before.get(*path) != after.get(*path))
+            .map(|path| self.cwd.join(path))
+            .collect();
+        self.changed_files.extend(changed);
+    }
+
+    pub(crate) fn summary(&self) -> ScriptCompletedEvent {
+        ScriptCompletedEvent {
+            passed: self.passed,
+            failed: self.failed,
+            skipped: self.turns.len() - self.passed - self.failed,
+        }
+    }
+
+    pub(crate) fn success(&self) -> bool {
+        self.passed == self.turns.len()
+    }
+
+    fn record_result(&mut self, failures: Vec<String>) -> ScriptTurnResultEvent {
+        let passed = failures.is_empty();
+        if passed {
+            self.passed += 1;
+        } else {
+            self.failed += 1;
+        }
+        ScriptTurnResultEvent {
+            index: self.current,
+            prompt: self.turns[self.current].prompt.clone(),
+            passed,
+            failures,
+            files_changed: self
+                .changed_files
+                .iter()
+                .map(|path| {
+                    path.strip_prefix(&self.cwd)
+                        .unwrap_or(path)
+                        .display()
+                        .to_string()
+                })
+                .collect(),
+        }
+    }
+
+    fn evaluate(&self, last_agent_message: Option<&str>) -> Vec<String> {
+        let expect = &self.turns[self.current].expect;
+        let message = last_agent_message.unwrap_or_default();
+        let mut failures = Vec::new();
+
+        for needle in &expect.final_message_contains {
+            if !message.contains(needle.as_str()) {
+                failures.push(format!("final message does not contain {needle:?}"));
+            }
+        }
+        for needle in &expect.final_message_not_contains {
+            if message.contains(needle.as_str()) {
+                failures.push(format!("final message unexpectedly contains {needle:?}"));
+            }
+        }
+        if let Some(pattern) = expect.final_message_matches.as_deref()
+            && let Ok(regex) = regex_lite::Regex::new(pattern)
+            && !regex.is_match(message)
+        {
+            failures.push(format!("final message does not match /{pattern}/"));
+        }
+        if let Some(expected) = expect.final_message_json.as_ref() {
+            match serde_json::from_str::<Value>(message) {
+                Ok(actual) if json_contains(&actual, expected) => {}
+                Ok(_) => failures.push(format!("final message JSON does not match {expected}")),
+                Err(err) => failures.push(format!("final message is not valid JSON: {err}")),
+            }
+        }
+        for expected in &expect.files_changed {
+            if !self.changed_files.contains(&self.cwd.join(expected)) {
+                failures.push(format!("{} was not changed", expected.display()));
+            }
+        }
+        if expect.no_files_changed && !self.changed_files.is_empty() {
+            failures.push(format!(
+                "expected no file changes but {} file(s) changed",
+                self.changed_files.len()
+            ));
+        }
+        failures
+    }
+}
+
+/// Files under a directory that differ from `HEAD` or are untracked, keyed by
+/// their path relative to that directory, with a hash of their contents
+/// (`None` once deleted).
+type DirtyFiles = BTreeMap<PathBuf, Option<u64>>;
+
+fn dirty_files(cwd: &Path) -> Option<DirtyFiles> {
+    let tracked = git_stdout(cwd, &["diff", "HEAD", "--name-only", "--relative", "-z"])?;
+    let untracked = git_stdout(cwd, &["ls-files", "--others", "--exclude-standard", "-z"])?;
+    Some(
+        tracked
+            .split('\0')
+            .chain(untracked.split('\0'))
+            .filter(|path| !path.is_empty())
+            .map(|path| {
+                let path = PathBuf::from(path);
+                let hash = std::fs::read(cwd.join(&path)).ok().map(|contents| {
+                    let mut hasher = DefaultHasher::new();
+                    contents.hash(&mut hasher);
+                    hasher.finish()
+                });
+                (path, hash)
+            })
+            .collect(),
+    )
+}
+
+fn git_stdout(cwd: &Path, args: &[&str]) -> Option<String> {
+    let output = std::process::Command::new("git")
+        .current_dir(cwd)
+        .args(args)
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+fn parse_jsonl(contents: &str) -> anyhow::Result<Vec<ScriptTurn>> {
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            serde_json::from_str(line)
+                .with_context(|| format!("Failed to parse script line {}", index + 1))
+        })
+        .collect()
+}
+
+/// Returns true when `actual` contains everything in `expected`: objects are
+/// matched key by key, everything else must compare equal.
+fn json_contains(actual: &Value, expected: &Value) -> bool {
+    match (actual, expected) {
+        (Value::Object(actual), Value::Object(expected)) => expected.iter().all(|(key, value)| {
+            actual
+                .get(key)
+                .is_some_and(|actual| json_contains(actual, value))
+        }),
+        _ => actual == expected,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codex_core::protocol::FileChange;
+    use codex_core::protocol::PatchApplyEndEvent;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    fn load(name: &str, contents: &str) -> ScriptRunner {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join(name);
+        std::fs::write(&path, contents).expect("write script");
+        ScriptRunner::load(&path, Path::new("/repo")).expect("load script")
+    }
+
+    #[test]
+    fn loads_yaml_document_and_jsonl() {
+        let yaml = load(
+            "smoke.yaml",
+            "continue_on_failure: true\nturns:\n  - prompt: first\n  - prompt: second\n    expect:\n      final_message_contains: [done]\n",
+        );
+        assert_eq!(yaml.len(), 2);
+        assert!(yaml.continue_on_failure);
+        assert_eq!(
+            yaml.turns[1].expect.final_message_contains,
+            vec!["done".to_string()]
+        );
+
+        let jsonl = load(
+            "smoke.jsonl",
+            "{\"prompt\":\"one\"}\n\n{\"prompt\":\"two\",\"output_schema\":{\"type\":\"object\"}}\n",
+        );
+        assert_eq!(jsonl.len(), 2);
+        assert!(!jsonl.continue_on_failure);
+        assert_eq!(
+            jsonl.current_turn().items,
+            vec![UserInput::Text {
+                text: "one".to_string(),
+                text_elements: Vec::new(),
+            }]
+        );
+        assert_eq!(
+            jsonl.turns[1].output_schema,
+            Some(json!({"type": "object"}))
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_scripts() {
+        let base = Path::new("/scripts");
+        let cwd = Path::new("/repo");
+        let turn = |prompt: &str| ScriptTurn {
+            prompt: prompt.to_string(),
+            output_schema: None,
+            images: Vec::new(),
+            expect: TurnExpectations::default(),
+        };
+
+        assert!(ScriptRunner::new(Vec::new(), false, base, cwd).is_err());
+        assert!(ScriptRunner::new(vec![turn("  ")], false, base, cwd).is_err());
+
+        let mut bad_regex = turn("check");
+        bad_regex.expect.final_message_matches = Some("(".to_string());
+        assert!(ScriptRunner::new(vec![bad_regex], false, base, cwd).is_err());
+    }
+
+    #[test]
+    fn files_changed_includes_edits_outside_patches() -> anyhow::Result<()> {
+        let repo = tempfile::tempdir()?;
+        let git = |args: &[&str]| {
+            let status = std::process::Command::new("git")
+                .current_dir(repo.path())
+                .args([
+                    "-c",
+                    "user.name=Tester",
+                    "-c",
+                    "user.email=test@example.com",
+                ])
+                .args(args)
+                .status()
+                .expect("git command");
+            assert!(status.success(), "git command failed: {args:?}");
+        };
+        git(&["init", "--initial-branch=main"]);
+        std::fs::write(repo.path().join("tracked.txt"), "base\n")?;
+        std::fs::write(repo.path().join("dirty.txt"), "base\n")?;
+        git(&["add", "."]);
+        git(&["commit", "-m", "init"]);
+        // Already modified before the turn and left alone by it.
+        std::fs::write(repo.path().join("dirty.txt"), "local\n")?;
+
+        let mut runner = ScriptRunner::new(
+            vec![ScriptTurn {
+                prompt: "edit with sed".to_string(),
+                output_schema: None,
+                images: Vec::new(),
+                expect: TurnExpectations {
+                    files_changed: vec![PathBuf::from("tracked.txt")],
+                    ..Default::default()
+                },
+            }],
+            false,
+            repo.path(),
+            repo.path(),
+        )?;
+        std::fs::write(repo.path().join("tracked.txt"), "edited\n")?;
+        std::fs::write(repo.path().join("new.txt"), "new\n")?;
+
+        let result = runner.finish_turn(Some("done"));
+        assert!(result.passed, "{:?}", result.failures);
+        assert_eq!(
+            result.files_changed,
+            vec!["new.txt".to_string(), "tracked.txt".to_string()]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn evaluates_expectations_and_stops_on_failure() {
+        let mut runner = load(
+            "smoke.yaml",
+            r#"
+- prompt: edit the readme
+  expect:
+    final_message_matches: "^Updated"
+    files_changed: [README.md]
+- prompt: report status
+  expect:
+    final_message_json: {status: ok}
+    no_files_changed: true
+- prompt: never runs
+"#,
+        );
+
+        runner.observe(&EventMsg::PatchApplyEnd(PatchApplyEndEvent {
+            call_id: "call-1".to_string(),
+            turn_id: "turn-1".to_string(),
+            stdout: String::new(),
+            stderr: String::new(),
+            success: true,
+            changes: HashMap::from([(
+                PathBuf::from("/repo/README.md"),
+                FileChange::Add {
+                    content: "hello".to_string(),
+                },
+            )]),
+        }));
+        let first = runner.finish_turn(Some("Updated README.md"));
+        assert!(first.passed, "{:?}", first.failures);
+        assert_eq!(first.files_changed, vec!["README.md".to_string()]);
+        assert!(runner.advance());
+
+        let second = runner.finish_turn(Some(r#"{"status":"failed","extra":1}"#));
+        assert_eq!(second.index, 1);
+        assert_eq!(
+            second.failures,
+            vec![r#"final message JSON does not match {"status":"ok"}"#.to_string()]
+        );
+        assert!(!runner.advance());
+        assert!(!runner.success());
+        assert_eq!(
+            runner.summary(),
+            ScriptCompletedEvent {
+                passed: 1,
+                failed: 1,
+                skipped: 1,
+            }
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use codex_core::LMSTUDIO_OSS_PROVIDER_ID;
 use codex_core::config::Config;
+use codex_core::models_manager::model_info::LocalModelMetadata;
 use std::io;
 use std::path::Path;
 
@@ -123,6 +124,47 @@ impl LMStudioClient {
         }
     }
 
+    // Return the context length and capabilities LM Studio reports for a model.
+    pub async fn fetch_model_metadata(&self, model: &str) -> io::Result<LocalModelMetadata> {
+        // The native REST API lives next to the OpenAI-compatible `/v1` routes.
+        let host_root = self.base_url.trim_end_matches('/');
+        let host_root = host_root.strip_suffix("/v1").unwrap_or(host_root);
+        // Model ids such as `openai/gpt-oss-20b` contain `/`, so encode the id as one segment.
+        let url = format!("{host_root}/api/v0/models/{}", urlencoding::encode(model));
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .map_err(|e| io::Error::other(format!("Request failed: {e}")))?;
+
+        if !response.status().is_success() {
+            return Err(io::Error::other(format!(
+                "Failed to fetch model metadata: {}",
+                response.status()
+            )));
+        }
+        let json: serde_json::Value = response.json().await.map_err(|e| {
+            io::Error::new(io::ErrorKind::InvalidData, format!("JSON parse error: {e}"))
+        })?;
+        let context_window = json["loaded_context_length"]
+            .as_i64()
+            .or_else(|| json["max_context_length"].as_i64());
+        let supports_images = json["type"].as_str().map(|kind| kind == "vlm");
+        // Older LM Studio builds do not report capabilities at all.
+        let supports_tools = json["capabilities"].as_array().map(|capabilities| {
+            capabilities
+                .iter()
+                .any(|capability| capability.as_str() == Some("tool_use"))
+        });
+        Ok(LocalModelMetadata {
+            slug: model.to_string(),
+            context_window,
+            supports_tools,
+            supports_images,
+        })
+    }
+
     // Find lms, checking fallback paths if not in PATH
     fn find_lms() -> std::io::Result<String> {
         Self::find_lms_with_home_dir(None)
@@ -299,6 +341,54 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_fetch_model_metadata() {
+        if std::env::var(codex_core::spawn::CODEX_SANDBOX_NETWORK_DISABLED_ENV_VAR).is_ok() {
+            tracing::info!(
+                "{} is set; skipping test_fetch_model_metadata",
+                codex_core::spawn::CODEX_SANDBOX_NETWORK_DISABLED_ENV_VAR
+            );
+            return;
+        }
+
+        let server = wiremock::MockServer::start().await;
+        wiremock::Mock::given(wiremock::matchers::method("GET"))
+            .and(wiremock::matchers::path(
+                "/api/v0/models/qwen%2Fqwen2-vl-7b-instruct",
+            ))
+            .respond_with(
+                wiremock::ResponseTemplate::new(200).set_body_raw(
+                    serde_json::json!({
+                        "id": "qwen/qwen2-vl-7b-instruct",
+                        "type": "vlm",
+                        "state": "loaded",
+                        "max_context_length": 32768,
+                        "loaded_context_length": 4096,
+                        "capabilities": ["tool_use"]
+                    })
+                    .to_string(),
+                    "application/json",
+                ),
+            )
+            .mount(&server)
+            .await;
+
+        let client = LMStudioClient::from_host_root(format!("{}/v1", server.uri()));
+        let metadata = client
+            .fetch_model_metadata("qwen/qwen2-vl-7b-instruct")
+            .await
+            .expect("fetch metadata");
+        assert_eq!(
+            metadata,
+            LocalModelMetadata {
+                slug: "qwen/qwen2-vl-7b-instruct".to_string(),
+                context_window: Some(4096),
+                supports_tools: Some(true),
+                supports_images: Some(true),
+            }
+        );
+    }
+
     #[tokio::test]
     async fn test_check_server_happy_path() {
         if std::env::var(codex_core::spawn::CODEX_SANDBOX_NETWORK_DISABLED_ENV_VAR).is_ok() {
@@ -263,6 +263,7 @@ async fn run_codex_tool_session_inner(
                         reason,
                         grant_root,
                         changes,
+                        hunks: _,
                     }) => {
                         handle_patch_approval_request(
                             call_id,
@@ -305,7 +306,9 @@ async fn run_codex_tool_session_inner(
                     EventMsg::AgentReasoningDelta(_) => {
                         // TODO: think how we want to support this in the MCP
                     }
-                    EventMsg::McpStartupUpdate(_) | EventMsg::McpStartupComplete(_) => {
+                    EventMsg::McpStartupUpdate(_)
+                    | EventMsg::McpStartupComplete(_)
+                    | EventMsg::McpListChanged(_) => {
                         // Ignored in MCP tool runner.
                     }
                     EventMsg::AgentMessage(AgentMessageEvent { .. }) => {
@@ -321,6 +324,10 @@ async fn run_codex_tool_session_inner(
                     | EventMsg::McpToolCallEnd(_)
                     | EventMsg::McpListToolsResponse(_)
                     | EventMsg::ListCustomPromptsResponse(_)
+                    | EventMsg::ListUndoSnapshotsResponse(_)
+                    | EventMsg::ListBackgroundProcessesResponse(_)
+                    | EventMsg::BackgroundProcessOutput(_)
+                    | EventMsg::RestorableBackgroundProcesses(_)
                     | EventMsg::ListSkillsResponse(_)
                     | EventMsg::ListRemoteSkillsResponse(_)
                     | EventMsg::RemoteSkillDownloaded(_)
@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 use std::path::PathBuf;
 
+use codex_common::oss::OssModelMetadataFetcher;
 use codex_core::AuthManager;
 use codex_core::ThreadManager;
 use codex_core::config::Config;
@@ -21,7 +22,10 @@ use rmcp::model::JsonRpcError;
 use rmcp::model::JsonRpcNotification;
 use rmcp::model::JsonRpcRequest;
 use rmcp::model::JsonRpcResponse;
+use rmcp::model::ListPromptsResult;
+use rmcp::model::PromptsCapability;
 use rmcp::model::RequestId;
+use rmcp::model::ResourcesCapability;
 use rmcp::model::ServerCapabilities;
 use rmcp::model::ToolsCapability;
 use serde_json::json;
@@ -34,13 +38,18 @@ use crate::codex_tool_config::CodexToolCallReplyParam;
 use crate::codex_tool_config::create_tool_for_codex_tool_call_param;
 use crate::codex_tool_config::create_tool_for_codex_tool_call_reply_param;
 use crate::outgoing_message::OutgoingMessageSender;
+use crate::prompts;
+use crate::resources;
+use crate::resources::ResourceSubscriptions;
 
 pub(crate) struct MessageProcessor {
     outgoing: Arc<OutgoingMessageSender>,
     initialized: bool,
     codex_linux_sandbox_exe: Option<PathBuf>,
+    config: Arc<Config>,
     thread_manager: Arc<ThreadManager>,
     running_requests_id_to_codex_uuid: Arc<Mutex<HashMap<RequestId, ThreadId>>>,
+    resource_subscriptions: ResourceSubscriptions,
 }
 
 impl MessageProcessor {
@@ -62,12 +71,17 @@ impl MessageProcessor {
             auth_manager,
             SessionSource::Mcp,
         ));
+        thread_manager
+            .get_models_manager()
+            .set_local_model_metadata_fetcher(Arc::new(OssModelMetadataFetcher));
         Self {
             outgoing,
             initialized: false,
             codex_linux_sandbox_exe,
+            config,
             thread_manager,
             running_requests_id_to_codex_uuid: Arc::new(Mutex::new(HashMap::new())),
+            resource_subscriptions: ResourceSubscriptions::default(),
         }
     }
 
@@ -83,25 +97,26 @@ impl MessageProcessor {
                 self.handle_ping(request_id).await;
             }
             ClientRequest::ListResourcesRequest(params) => {
-                self.handle_list_resources(params.params);
+                self.handle_list_resources(request_id, params.params).await;
             }
             ClientRequest::ListResourceTemplatesRequest(params) => {
-                self.handle_list_resource_templates(params.params);
+                self.handle_list_resource_templates(request_id, params.params)
+                    .await;
             }
             ClientRequest::ReadResourceRequest(params) => {
-                self.handle_read_resource(params.params);
+                self.handle_read_resource(request_id, params.params).await;
             }
             ClientRequest::SubscribeRequest(params) => {
-                self.handle_subscribe(params.params);
+                self.handle_subscribe(request_id, params.params).await;
             }
             ClientRequest::UnsubscribeRequest(params) => {
-                self.handle_unsubscribe(params.params);
+                self.handle_unsubscribe(request_id, params.params).await;
             }
             ClientRequest::ListPromptsRequest(params) => {
-                self.handle_list_prompts(params.params);
+                self.handle_list_prompts(request_id, params.params).await;
             }
             ClientRequest::GetPromptRequest(params) => {
-                self.handle_get_prompt(params.params);
+                self.handle_get_prompt(request_id, params.params).await;
             }
             ClientRequest::ListToolsRequest(params) => {
                 self.handle_list_tools(request_id, params.params).await;
@@ -222,6 +237,13 @@ impl MessageProcessor {
                 tools: Some(ToolsCapability {
                     list_changed: Some(true),
                 }),
+                prompts: Some(PromptsCapability {
+                    list_changed: Some(false),
+                }),
+                resources: Some(ResourcesCapability {
+                    subscribe: Some(true),
+                    list_changed: Some(false),
+                }),
                 ..Default::default()
             },
             instructions: None,
@@ -256,32 +278,93 @@ impl MessageProcessor {
         self.outgoing.send_response(id, json!({})).await;
     }
 
-    fn handle_list_resources(&self, params: Option<rmcp::model::PaginatedRequestParam>) {
+    async fn handle_list_resources(
+        &self,
+        id: RequestId,
+        params: Option<rmcp::model::PaginatedRequestParam>,
+    ) {
         tracing::info!("resources/list -> params: {:?}", params);
+        let cursor = params.and_then(|params| params.cursor);
+        match resources::list_resources(&self.config, cursor.as_deref()).await {
+            Ok(result) => self.outgoing.send_response(id, result).await,
+            Err(err) => self.outgoing.send_error(id, err).await,
+        }
     }
 
-    fn handle_list_resource_templates(&self, params: Option<rmcp::model::PaginatedRequestParam>) {
+    async fn handle_list_resource_templates(
+        &self,
+        id: RequestId,
+        params: Option<rmcp::model::PaginatedRequestParam>,
+    ) {
         tracing::info!("resources/templates/list -> params: {:?}", params);
+        self.outgoing
+            .send_response(id, resources::list_resource_templates())
+            .await;
     }
 
-    fn handle_read_resource(&self, params: rmcp::model::ReadResourceRequestParam) {
+    async fn handle_read_resource(
+        &self,
+        id: RequestId,
+        params: rmcp::model::ReadResourceRequestParam,
+    ) {
         tracing::info!("resources/read -> params: {:?}", params);
+        match resources::read_resource(&self.config, &params.uri).await {
+            Ok(result) => self.outgoing.send_response(id, result).await,
+            Err(err) => self.outgoing.send_error(id, err).await,
+        }
     }
 
-    fn handle_subscribe(&self, params: rmcp::model::SubscribeRequestParam) {
+    async fn handle_subscribe(&self, id: RequestId, params: rmcp::model::SubscribeRequestParam) {
         tracing::info!("resources/subscribe -> params: {:?}", params);
+        match self
+            .resource_subscriptions
+            .subscribe(&self.config, params.uri, self.outgoing.clone())
+            .await
+        {
+            Ok(()) => self.outgoing.send_response(id, json!({})).await,
+            Err(err) => self.outgoing.send_error(id, err).await,
+        }
     }
 
-    fn handle_unsubscribe(&self, params: rmcp::model::UnsubscribeRequestParam) {
+    async fn handle_unsubscribe(
+        &self,
+        id: RequestId,
+        params: rmcp::model::UnsubscribeRequestParam,
+    ) {
         tracing::info!("resources/unsubscribe -> params: {:?}", params);
+        self.resource_subscriptions.unsubscribe(&params.uri).await;
+        self.outgoing.send_response(id, json!({})).await;
     }
 
-    fn handle_list_prompts(&self, params: Option<rmcp::model::PaginatedRequestParam>) {
+    async fn handle_list_prompts(
+        &self,
+        id: RequestId,
+        params: Option<rmcp::model::PaginatedRequestParam>,
+    ) {
         tracing::info!("prompts/list -> params: {:?}", params);
+        let skills_manager = self.thread_manager.skills_manager();
+        let result = ListPromptsResult {
+            meta: None,
+            prompts: prompts::list_prompts(&self.config, &skills_manager).await,
+            next_cursor: None,
+        };
+        self.outgoing.send_response(id, result).await;
     }
 
-    fn handle_get_prompt(&self, params: rmcp::model::GetPromptRequestParam) {
+    async fn handle_get_prompt(&self, id: RequestId, params: rmcp::model::GetPromptRequestParam) {
         tracing::info!("prompts/get -> params: {:?}", params);
+        let skills_manager = self.thread_manager.skills_manager();
+        match prompts::get_prompt(
+            &self.config,
+            &skills_manager,
+            &params.name,
+            params.arguments,
+        )
+        .await
+        {
+            Ok(result) => self.outgoing.send_response(id, result).await,
+            Err(err) => self.outgoing.send_error(id, err).await,
+        }
     }
 
     async fn handle_list_tools(
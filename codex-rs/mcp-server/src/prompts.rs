@@ -0,0 +1,232 @@
+//! Publishes the user's custom prompts and skills as MCP prompts.
+//!
+//! Custom prompts are listed as `prompts:<name>` with one argument per named
+//! `$PLACEHOLDER` (or a single `ARGUMENTS` argument when the prompt only uses
+//! `$1..$9`/`$ARGUMENTS`) and expand exactly as they do in the TUI. Skills are listed as `skills:<name>` and expand to
+//! the skill's instructions followed by an optional request.
+
+use std::collections::HashMap;
+
+use codex_core::config::Config;
+use codex_core::custom_prompts::discover_prompts_in;
+use codex_core::prompt_args::PromptArg;
+use codex_core::prompt_args::expand_prompt_template;
+use codex_core::prompt_args::parse_positional_args;
+use codex_core::prompt_args::prompt_argument_names;
+use codex_core::prompt_args::prompt_has_numeric_placeholders;
+use codex_core::skills::SkillMetadata;
+use codex_core::skills::SkillsManager;
+use codex_protocol::custom_prompts::CustomPrompt;
+use rmcp::model::ErrorData;
+use rmcp::model::GetPromptResult;
+use rmcp::model::JsonObject;
+use rmcp::model::Prompt;
+use rmcp::model::PromptArgument;
+use rmcp::model::PromptMessage;
+use rmcp::model::PromptMessageRole;
+use serde_json::json;
+
+pub(crate) const CUSTOM_PROMPT_PREFIX: &str = "prompts:";
+pub(crate) const SKILL_PROMPT_PREFIX: &str = "skills:";
+
+/// Argument carrying whitespace-separated values for `$1..$9` and `$ARGUMENTS`.
+const POSITIONAL_ARGUMENT: &str = "ARGUMENTS";
+/// Optional argument appended after a skill's instructions.
+const SKILL_REQUEST_ARGUMENT: &str = "request";
+
+pub(crate) async fn list_prompts(config: &Config, skills_manager: &SkillsManager) -> Vec<Prompt> {
+    let mut prompts = custom_prompts(config)
+        .await
+        .iter()
+        .map(custom_prompt_to_mcp)
+        .collect::<Vec<_>>();
+    prompts.extend(
+        enabled_skills(config, skills_manager)
+            .iter()
+            .map(skill_to_mcp),
+    );
+    prompts
+}
+
+pub(crate) async fn get_prompt(
+    config: &Config,
+    skills_manager: &SkillsManager,
+    name: &str,
+    arguments: Option<JsonObject>,
+) -> Result<GetPromptResult, ErrorData> {
+    let arguments = arguments
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(key, value)| {
+            let value = match value {
+                serde_json::Value::String(value) => value,
+                other => other.to_string(),
+            };
+            (key, value)
+        })
+        .collect::<HashMap<_, _>>();
+
+    if let Some(prompt_name) = name.strip_prefix(CUSTOM_PROMPT_PREFIX)
+        && let Some(prompt) = custom_prompts(config)
+            .await
+            .into_iter()
+            .find(|prompt| prompt.name == prompt_name)
+    {
+        let text = expand_custom_prompt(&prompt.content, &arguments)?;
+        return Ok(GetPromptResult {
+            description: prompt.description,
+            messages: vec![PromptMessage::new_text(PromptMessageRole::User, text)],
+        });
+    }
+
+    if let Some(skill_name) = name.strip_prefix(SKILL_PROMPT_PREFIX)
+        && let Some(skill) = enabled_skills(config, skills_manager)
+            .into_iter()
+            .find(|skill| skill.name == skill_name)
+    {
+        let contents = tokio::fs::read_to_string(&skill.path)
+            .await
+            .map_err(|err| {
+                ErrorData::internal_error(
+                    format!("failed to read skill {}: {err}", skill.path.display()),
+                    None,
+                )
+            })?;
+        let mut text = format!(
+            "<skill>\n<name>{}</name>\n<path>{}</path>\n{contents}\n</skill>",
+            skill.name,
+            skill.path.display()
+        );
+        if let Some(request) = arguments
+            .get(SKILL_REQUEST_ARGUMENT)
+            .map(|request| request.trim())
+            .filter(|request| !request.is_empty())
+        {
+            text.push_str("\n\n");
+            text.push_str(request);
+        }
+        return Ok(GetPromptResult {
+            description: Some(skill.description),
+            messages: vec![PromptMessage::new_text(PromptMessageRole::User, text)],
+        });
+    }
+
+    Err(ErrorData::invalid_params(
+        format!("prompt not found: {name}"),
+        Some(json!({ "name": name })),
+    ))
+}
+
+async fn custom_prompts(config: &Config) -> Vec<CustomPrompt> {
+    discover_prompts_in(&config.codex_home.join("prompts")).await
+}
+
+fn enabled_skills(config: &Config, skills_manager: &SkillsManager) -> Vec<SkillMetadata> {
+    skills_manager.skills_for_config(config).enabled_skills()
+}
+
+fn custom_prompt_to_mcp(prompt: &CustomPrompt) -> Prompt {
+    let mut arguments = prompt_argument_names(&prompt.content)
+        .into_iter()
+        .map(|name| PromptArgument {
+            name,
+            title: None,
+            description: None,
+            required: Some(true),
+        })
+        .collect::<Vec<_>>();
+    // Positional placeholders are only expanded in prompts without named ones.
+    if arguments.is_empty() && prompt_has_numeric_placeholders(&prompt.content) {
+        arguments.push(PromptArgument {
+            name: POSITIONAL_ARGUMENT.to_string(),
+            title: None,
+            description: Some(prompt.argument_hint.clone().unwrap_or_else(|| {
+                "Whitespace-separated values for $1..$9 and $ARGUMENTS".to_string()
+            })),
+            required: Some(false),
+        });
+    }
+    Prompt::new(
+        format!("{CUSTOM_PROMPT_PREFIX}{}", prompt.name),
+        prompt.description.clone(),
+        (!arguments.is_empty()).then_some(arguments),
+    )
+}
+
+fn skill_to_mcp(skill: &SkillMetadata) -> Prompt {
+    let description = skill
+        .short_description
+        .clone()
+        .unwrap_or_else(|| skill.description.clone());
+    Prompt::new(
+        format!("{SKILL_PROMPT_PREFIX}{}", skill.name),
+        Some(description),
+        Some(vec![PromptArgument {
+            name: SKILL_REQUEST_ARGUMENT.to_string(),
+            title: None,
+            description: Some("What to use the skill for".to_string()),
+            required: Some(false),
+        }]),
+    )
+}
+
+/// Expands `content` through the same template code the TUI uses. Named
+/// placeholders read their own arguments; `$1..$9`/`$ARGUMENTS` read the
+/// shell-split `ARGUMENTS` argument.
+fn expand_custom_prompt(
+    content: &str,
+    arguments: &HashMap<String, String>,
+) -> Result<String, ErrorData> {
+    let named = arguments
+        .iter()
+        .map(|(key, value)| {
+            (
+                key.clone(),
+                PromptArg {
+                    text: value.clone(),
+                    text_elements: Vec::new(),
+                },
+            )
+        })
+        .collect::<HashMap<_, _>>();
+    let positional = arguments
+        .get(POSITIONAL_ARGUMENT)
+        .map(|value| parse_positional_args(value, &[]))
+        .unwrap_or_default();
+    expand_prompt_template(content, &named, &positional)
+        .map(|expansion| expansion.text)
+        .map_err(|missing| {
+            ErrorData::invalid_params(
+                format!("missing required arguments: {}", missing.join(", ")),
+                Some(json!({ "missing": missing })),
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn expands_named_and_positional_arguments() {
+        let arguments = HashMap::from([
+            ("FILE".to_string(), "src/main.rs".to_string()),
+            ("ARGUMENTS".to_string(), "high \"needs tests\"".to_string()),
+        ]);
+        assert_eq!(
+            expand_custom_prompt("Review $FILE with $$FILE", &arguments).expect("expand"),
+            "Review src/main.rs with $$FILE"
+        );
+        assert_eq!(
+            expand_custom_prompt("Fix ($1; $2) and $ARGUMENTS", &arguments).expect("expand"),
+            "Fix (high; needs tests) and high needs tests"
+        );
+    }
+
+    #[test]
+    fn expansion_reports_missing_named_arguments() {
+        let err = expand_custom_prompt("Review $FILE", &HashMap::new()).expect_err("missing");
+        assert_eq!(err.message, "missing required arguments: FILE");
+    }
+}
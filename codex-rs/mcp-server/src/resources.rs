@@ -0,0 +1,287 @@
+//! Publishes recorded threads as MCP resources.
+//!
+//! Each rollout is exposed as `codex://threads/<thread_id>` with its JSONL
+//! contents as the resource body, capped to the most recent lines and with
+//! exposed command secrets redacted. Subscribing to a thread polls its rollout
+//! file and emits `notifications/resources/updated` whenever it changes.
+
+use std::collections::HashMap;
+use std::io::SeekFrom;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::SystemTime;
+
+use codex_core::RolloutRecorder;
+use codex_core::ThreadItem;
+use codex_core::ThreadSortKey;
+use codex_core::command_secrets::CommandSecrets;
+use codex_core::config::Config;
+use codex_core::find_archived_thread_path_by_id_str;
+use codex_core::find_thread_path_by_id_str;
+use codex_core::parse_cursor;
+use rmcp::model::ErrorData;
+use rmcp::model::ListResourceTemplatesResult;
+use rmcp::model::ListResourcesResult;
+use rmcp::model::RawResource;
+use rmcp::model::RawResourceTemplate;
+use rmcp::model::ReadResourceResult;
+use rmcp::model::Resource;
+use rmcp::model::ResourceContents;
+use rmcp::model::ResourceTemplate;
+use serde_json::json;
+use tokio::io::AsyncReadExt;
+use tokio::io::AsyncSeekExt;
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+
+use crate::outgoing_message::OutgoingMessageSender;
+use crate::outgoing_message::OutgoingNotification;
+
+const THREAD_URI_PREFIX: &str = "codex://threads/";
+const THREAD_MIME_TYPE: &str = "application/jsonl";
+const THREADS_PAGE_SIZE: usize = 50;
+const TITLE_MAX_CHARS: usize = 80;
+const SUBSCRIPTION_POLL_INTERVAL: Duration = Duration::from_secs(2);
+/// Largest rollout body returned by `resources/read`. Longer rollouts keep
+/// their most recent lines.
+const MAX_RESOURCE_BYTES: u64 = 1024 * 1024;
+
+pub(crate) async fn list_resources(
+    config: &Config,
+    cursor: Option<&str>,
+) -> Result<ListResourcesResult, ErrorData> {
+    let cursor = match cursor {
+        Some(token) => Some(parse_cursor(token).ok_or_else(|| {
+            ErrorData::invalid_params(
+                format!("invalid cursor: {token}"),
+                Some(json!({ "cursor": token })),
+            )
+        })?),
+        None => None,
+    };
+    let page = RolloutRecorder::list_threads(
+        &config.codex_home,
+        THREADS_PAGE_SIZE,
+        cursor.as_ref(),
+        ThreadSortKey::UpdatedAt,
+        &[],
+        None,
+        &config.model_provider_id,
+    )
+    .await
+    .map_err(|err| ErrorData::internal_error(format!("failed to list threads: {err}"), None))?;
+
+    let resources = page.items.iter().filter_map(thread_to_resource).collect();
+    let next_cursor = page
+        .next_cursor
+        .and_then(|cursor| serde_json::to_value(cursor).ok())
+        .and_then(|value| value.as_str().map(str::to_owned));
+    Ok(ListResourcesResult {
+        resources,
+        next_cursor,
+        meta: None,
+    })
+}
+
+pub(crate) fn list_resource_templates() -> ListResourceTemplatesResult {
+    let template = RawResourceTemplate {
+        uri_template: format!("{THREAD_URI_PREFIX}{{thread_id}}"),
+        name: "thread".to_string(),
+        title: Some("Codex thread".to_string()),
+        description: Some(
+            "Recorded rollout of a Codex thread, one JSON event per line".to_string(),
+        ),
+        mime_type: Some(THREAD_MIME_TYPE.to_string()),
+    };
+    ListResourceTemplatesResult {
+        resource_templates: vec![ResourceTemplate::new(template, None)],
+        next_cursor: None,
+        meta: None,
+    }
+}
+
+pub(crate) async fn read_resource(
+    config: &Config,
+    uri: &str,
+) -> Result<ReadResourceResult, ErrorData> {
+    let path = resolve_thread_path(config, uri).await?;
+    let text = read_rollout_tail(&path, MAX_RESOURCE_BYTES)
+        .await
+        .map_err(|err| {
+            ErrorData::internal_error(
+                format!("failed to read rollout {}: {err}", path.display()),
+                None,
+            )
+        })?;
+    let text = CommandSecrets::load(config)
+        .await
+        .redact(&text)
+        .into_owned();
+    Ok(ReadResourceResult {
+        contents: vec![ResourceContents::TextResourceContents {
+            uri: uri.to_string(),
+            mime_type: Some(THREAD_MIME_TYPE.to_string()),
+            text,
+            meta: None,
+        }],
+    })
+}
+
+/// Reads at most `max_bytes` from the end of `path`, dropping the partial
+/// line the cut lands in so every returned line is a whole JSON event.
+async fn read_rollout_tail(path: &Path, max_bytes: u64) -> std::io::Result<String> {
+    let mut file = tokio::fs::File::open(path).await?;
+    let start = file.metadata().await?.len().saturating_sub(max_bytes);
+    file.seek(SeekFrom::Start(start)).await?;
+    let mut bytes = Vec::new();
+    file.take(max_bytes).read_to_end(&mut bytes).await?;
+    if start > 0 {
+        let first_line_end = bytes
+            .iter()
+            .position(|byte| *byte == b'\n')
+            .map_or(bytes.len(), |idx| idx + 1);
+        bytes.drain(..first_line_end);
+    }
+    Ok(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+/// Background watchers for `resources/subscribe`, keyed by resource URI.
+#[derive(Default)]
+pub(crate) struct ResourceSubscriptions {
+    watchers: Mutex<HashMap<String, JoinHandle<()>>>,
+}
+
+impl ResourceSubscriptions {
+    pub(crate) async fn subscribe(
+        &self,
+        config: &Config,
+        uri: String,
+        outgoing: Arc<OutgoingMessageSender>,
+    ) -> Result<(), ErrorData> {
+        let path = resolve_thread_path(config, &uri).await?;
+        let mut watchers = self.watchers.lock().await;
+        if watchers.contains_key(&uri) {
+            return Ok(());
+        }
+        let handle = tokio::spawn(watch_rollout(path, uri.clone(), outgoing));
+        watchers.insert(uri, handle);
+        Ok(())
+    }
+
+    pub(crate) async fn unsubscribe(&self, uri: &str) {
+        if let Some(handle) = self.watchers.lock().await.remove(uri) {
+            handle.abort();
+        }
+    }
+}
+
+impl Drop for ResourceSubscriptions {
+    fn drop(&mut self) {
+        for handle in self.watchers.get_mut().values() {
+            handle.abort();
+        }
+    }
+}
+
+async fn watch_rollout(path: PathBuf, uri: String, outgoing: Arc<OutgoingMessageSender>) {
+    let mut last = file_fingerprint(&path).await;
+    let mut interval = tokio::time::interval(SUBSCRIPTION_POLL_INTERVAL);
+    interval.tick().await;
+    loop {
+        interval.tick().await;
+        let current = file_fingerprint(&path).await;
+        if current != last {
+            last = current;
+            outgoing
+                .send_notification(OutgoingNotification {
+                    method: "notifications/resources/updated".to_string(),
+                    params: Some(json!({ "uri": uri })),
+                })
+                .await;
+        }
+    }
+}
+
+async fn file_fingerprint(path: &Path) -> Option<(SystemTime, u64)> {
+    let metadata = tokio::fs::metadata(path).await.ok()?;
+    Some((metadata.modified().ok()?, metadata.len()))
+}
+
+async fn resolve_thread_path(config: &Config, uri: &str) -> Result<PathBuf, ErrorData> {
+    let not_found = || {
+        ErrorData::resource_not_found(
+            format!("resource not found: {uri}"),
+            Some(json!({ "uri": uri })),
+        )
+    };
+    let thread_id = uri.strip_prefix(THREAD_URI_PREFIX).ok_or_else(not_found)?;
+    let lookup_error = |err: std::io::Error| {
+        ErrorData::internal_error(format!("failed to locate thread: {err}"), None)
+    };
+    if let Some(path) = find_thread_path_by_id_str(&config.codex_home, thread_id)
+        .await
+        .map_err(lookup_error)?
+    {
+        return Ok(path);
+    }
+    find_archived_thread_path_by_id_str(&config.codex_home, thread_id)
+        .await
+        .map_err(lookup_error)?
+        .ok_or_else(not_found)
+}
+
+fn thread_to_resource(item: &ThreadItem) -> Option<Resource> {
+    let thread_id = item.thread_id?.to_string();
+    let title = item.first_user_message.as_deref().map(|message| {
+        let line = message.lines().next().unwrap_or_default().trim();
+        if line.chars().count() > TITLE_MAX_CHARS {
+            let truncated: String = line.chars().take(TITLE_MAX_CHARS - 1).collect();
+            format!("{truncated}…")
+        } else {
+            line.to_string()
+        }
+    });
+    let description = [
+        item.updated_at.as_deref().map(|ts| format!("updated {ts}")),
+        item.cwd.as_ref().map(|cwd| format!("in {}", cwd.display())),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>()
+    .join(" ");
+    let raw = RawResource {
+        uri: format!("{THREAD_URI_PREFIX}{thread_id}"),
+        name: thread_id,
+        title,
+        description: (!description.is_empty()).then_some(description),
+        mime_type: Some(THREAD_MIME_TYPE.to_string()),
+        size: None,
+        icons: None,
+        meta: None,
+    };
+    Some(Resource::new(raw, None))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[tokio::test]
+    async fn rollout_tail_keeps_only_whole_recent_lines() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("rollout.jsonl");
+        std::fs::write(&path, "{\"a\":1}\n{\"b\":2}\n{\"c\":3}\n").expect("write rollout");
+
+        assert_eq!(
+            read_rollout_tail(&path, 12).await.expect("read"),
+            "{\"c\":3}\n"
+        );
+        assert_eq!(
+            read_rollout_tail(&path, 1024).await.expect("read"),
+            "{\"a\":1}\n{\"b\":2}\n{\"c\":3}\n"
+        );
+    }
+}
@@ -164,6 +164,13 @@ impl McpProcess {
             result,
             json!({
                 "capabilities": {
+                    "prompts": {
+                        "listChanged": false
+                    },
+                    "resources": {
+                        "listChanged": false,
+                        "subscribe": true
+                    },
                     "tools": {
                         "listChanged": true
                     },
@@ -208,6 +215,16 @@ impl McpProcess {
         .await
     }
 
+    /// Sends an arbitrary MCP request (e.g. `prompts/list`) and returns the id
+    /// used so the caller can wait for the matching response.
+    pub async fn send_mcp_request(
+        &mut self,
+        method: &str,
+        params: Option<serde_json::Value>,
+    ) -> anyhow::Result<i64> {
+        self.send_request(method, params).await
+    }
+
     async fn send_request(
         &mut self,
         method: &str,
@@ -313,6 +330,33 @@ impl McpProcess {
         }
     }
 
+    pub async fn read_stream_until_notification_message(
+        &mut self,
+        method: &str,
+    ) -> anyhow::Result<JsonRpcNotification<CustomNotification>> {
+        eprintln!("in read_stream_until_notification_message({method})");
+
+        loop {
+            let message = self.read_jsonrpc_message().await?;
+            match message {
+                JsonRpcMessage::Notification(notification) => {
+                    if notification.notification.method == method {
+                        return Ok(notification);
+                    }
+                }
+                JsonRpcMessage::Request(_) => {
+                    anyhow::bail!("unexpected JSONRPCMessage::Request: {message:?}");
+                }
+                JsonRpcMessage::Error(_) => {
+                    anyhow::bail!("unexpected JSONRPCMessage::Error: {message:?}");
+                }
+                JsonRpcMessage::Response(_) => {
+                    eprintln!("response: {message:?}");
+                }
+            }
+        }
+    }
+
     /// Reads notifications until a legacy TurnComplete event is observed:
     /// Method "codex/event" with params.msg.type == "task_complete".
     pub async fn read_stream_until_legacy_task_complete_notification(
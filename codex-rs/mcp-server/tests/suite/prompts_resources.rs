@@ -0,0 +1,192 @@
+use std::path::Path;
+
+use mcp_test_support::McpProcess;
+use pretty_assertions::assert_eq;
+use rmcp::model::RequestId;
+use serde_json::json;
+use tempfile::TempDir;
+use tokio::time::timeout;
+
+const DEFAULT_READ_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+const THREAD_ID: &str = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn custom_prompts_are_listed_and_expanded() -> anyhow::Result<()> {
+    let codex_home = TempDir::new()?;
+    let prompts_dir = codex_home.path().join("prompts");
+    std::fs::create_dir_all(&prompts_dir)?;
+    std::fs::write(
+        prompts_dir.join("review.md"),
+        "---\ndescription: Review a file\nargument-hint: PRIORITY\n---\nReview $FILE with priority $1.",
+    )?;
+
+    let mut mcp = McpProcess::new(codex_home.path()).await?;
+    timeout(DEFAULT_READ_TIMEOUT, mcp.initialize()).await??;
+
+    let list_id = mcp.send_mcp_request("prompts/list", None).await?;
+    let list = timeout(
+        DEFAULT_READ_TIMEOUT,
+        mcp.read_stream_until_response_message(RequestId::Number(list_id)),
+    )
+    .await??;
+    let review = list.result["prompts"]
+        .as_array()
+        .and_then(|prompts| {
+            prompts
+                .iter()
+                .find(|prompt| prompt["name"] == "prompts:review")
+        })
+        .cloned()
+        .expect("custom prompt should be listed");
+    assert_eq!(
+        review,
+        json!({
+            "name": "prompts:review",
+            "description": "Review a file",
+            "arguments": [
+                { "name": "FILE", "required": true },
+                { "name": "ARGUMENTS", "description": "PRIORITY", "required": false },
+            ],
+        })
+    );
+
+    let get_id = mcp
+        .send_mcp_request(
+            "prompts/get",
+            Some(json!({
+                "name": "prompts:review",
+                "arguments": { "FILE": "src/lib.rs", "ARGUMENTS": "high" },
+            })),
+        )
+        .await?;
+    let get = timeout(
+        DEFAULT_READ_TIMEOUT,
+        mcp.read_stream_until_response_message(RequestId::Number(get_id)),
+    )
+    .await??;
+    assert_eq!(
+        get.result,
+        json!({
+            "description": "Review a file",
+            "messages": [{
+                "role": "user",
+                "content": { "type": "text", "text": "Review src/lib.rs with priority high." },
+            }],
+        })
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn threads_are_readable_resources_with_update_notifications() -> anyhow::Result<()> {
+    let codex_home = TempDir::new()?;
+    let rollout_path = write_rollout(codex_home.path(), "Fix the flaky test")?;
+    let uri = format!("codex://threads/{THREAD_ID}");
+
+    let mut mcp = McpProcess::new(codex_home.path()).await?;
+    timeout(DEFAULT_READ_TIMEOUT, mcp.initialize()).await??;
+
+    let list_id = mcp.send_mcp_request("resources/list", None).await?;
+    let list = timeout(
+        DEFAULT_READ_TIMEOUT,
+        mcp.read_stream_until_response_message(RequestId::Number(list_id)),
+    )
+    .await??;
+    let resources = list.result["resources"]
+        .as_array()
+        .cloned()
+        .unwrap_or_default();
+    assert_eq!(resources.len(), 1);
+    assert_eq!(resources[0]["uri"], json!(uri));
+    assert_eq!(resources[0]["name"], json!(THREAD_ID));
+    assert_eq!(resources[0]["title"], json!("Fix the flaky test"));
+    assert_eq!(resources[0]["mimeType"], json!("application/jsonl"));
+
+    let read_id = mcp
+        .send_mcp_request("resources/read", Some(json!({ "uri": uri })))
+        .await?;
+    let read = timeout(
+        DEFAULT_READ_TIMEOUT,
+        mcp.read_stream_until_response_message(RequestId::Number(read_id)),
+    )
+    .await??;
+    assert_eq!(
+        read.result["contents"][0]["text"],
+        json!(std::fs::read_to_string(&rollout_path)?)
+    );
+
+    let subscribe_id = mcp
+        .send_mcp_request("resources/subscribe", Some(json!({ "uri": uri })))
+        .await?;
+    let subscribe = timeout(
+        DEFAULT_READ_TIMEOUT,
+        mcp.read_stream_until_response_message(RequestId::Number(subscribe_id)),
+    )
+    .await??;
+    assert_eq!(subscribe.result, json!({}));
+
+    let mut contents = std::fs::read_to_string(&rollout_path)?;
+    contents.push_str(&rollout_line(
+        "event_msg",
+        json!({ "type": "user_message", "message": "again", "kind": "plain" }),
+    ));
+    std::fs::write(&rollout_path, contents)?;
+
+    let updated = timeout(
+        DEFAULT_READ_TIMEOUT,
+        mcp.read_stream_until_notification_message("notifications/resources/updated"),
+    )
+    .await??;
+    assert_eq!(updated.notification.params, Some(json!({ "uri": uri })));
+
+    Ok(())
+}
+
+fn write_rollout(codex_home: &Path, preview: &str) -> anyhow::Result<std::path::PathBuf> {
+    let dir = codex_home
+        .join("sessions")
+        .join("2025")
+        .join("01")
+        .join("02");
+    std::fs::create_dir_all(&dir)?;
+    let path = dir.join(format!("rollout-2025-01-02T12-00-00-{THREAD_ID}.jsonl"));
+    let contents = [
+        rollout_line(
+            "session_meta",
+            json!({
+                "id": THREAD_ID,
+                "timestamp": "2025-01-02T12:00:00Z",
+                "cwd": "/",
+                "originator": "codex",
+                "cli_version": "0.0.0",
+                "source": "cli",
+                "model_provider": "openai",
+            }),
+        ),
+        rollout_line(
+            "response_item",
+            json!({
+                "type": "message",
+                "role": "user",
+                "content": [{ "type": "input_text", "text": preview }],
+            }),
+        ),
+        rollout_line(
+            "event_msg",
+            json!({ "type": "user_message", "message": preview, "kind": "plain" }),
+        ),
+    ]
+    .concat();
+    std::fs::write(&path, contents)?;
+    Ok(path)
+}
+
+fn rollout_line(kind: &str, payload: serde_json::Value) -> String {
+    let line = json!({
+        "timestamp": "2025-01-02T12:00:00Z",
+        "type": kind,
+        "payload": payload,
+    });
+    format!("{line}\n")
+}
@@ -14,6 +14,7 @@ use crate::url::is_openai_compatible_base_url;
 use codex_core::ModelProviderInfo;
 use codex_core::OLLAMA_OSS_PROVIDER_ID;
 use codex_core::config::Config;
+use codex_core::models_manager::model_info::LocalModelMetadata;
 
 const OLLAMA_CONNECTION_ERROR: &str = "No running Ollama server detected. Start it with: `ollama serve` (after installing). Install instructions: https://github.com/ollama/ollama?tab=readme-ov-file#ollama";
 
@@ -123,6 +124,26 @@ impl OllamaClient {
         Ok(names)
     }
 
+    /// Query `/api/show` for the context length and capabilities of `model`.
+    pub async fn fetch_model_metadata(&self, model: &str) -> io::Result<LocalModelMetadata> {
+        let url = format!("{}/api/show", self.host_root.trim_end_matches('/'));
+        let resp = self
+            .client
+            .post(url)
+            .json(&serde_json::json!({ "model": model }))
+            .send()
+            .await
+            .map_err(io::Error::other)?;
+        if !resp.status().is_success() {
+            return Err(io::Error::other(format!(
+                "failed to fetch metadata for {model}: HTTP {}",
+                resp.status()
+            )));
+        }
+        let val = resp.json::<JsonValue>().await.map_err(io::Error::other)?;
+        Ok(model_metadata_from_show(model, &val))
+    }
+
     /// Query the server for its version string, returning `None` when unavailable.
     pub async fn fetch_version(&self) -> io::Result<Option<Version>> {
         let version_url = format!("{}/api/version", self.host_root.trim_end_matches('/'));
@@ -257,6 +278,47 @@ impl OllamaClient {
     }
 }
 
+/// Build metadata from an `/api/show` response. The context window prefers a
+/// `num_ctx` override from the model's parameters over the architecture's
+/// trained context length; capabilities are only known on servers that report
+/// them.
+fn model_metadata_from_show(model: &str, val: &JsonValue) -> LocalModelMetadata {
+    let num_ctx = val
+        .get("parameters")
+        .and_then(JsonValue::as_str)
+        .and_then(|parameters| {
+            parameters.lines().find_map(|line| {
+                let mut parts = line.split_whitespace();
+                match (parts.next(), parts.next()) {
+                    (Some("num_ctx"), Some(value)) => value.parse::<i64>().ok(),
+                    _ => None,
+                }
+            })
+        });
+    let capabilities = val
+        .get("capabilities")
+        .and_then(JsonValue::as_array)
+        .map(|capabilities| {
+            capabilities
+                .iter()
+                .filter_map(JsonValue::as_str)
+                .collect::<Vec<_>>()
+        });
+    LocalModelMetadata {
+        slug: model.to_string(),
+        // Without `num_ctx` Ollama serves the model with its runtime default
+        // (`OLLAMA_CONTEXT_LENGTH` on the server), not the trained context
+        // length, and that default is not exposed over the API.
+        context_window: num_ctx,
+        supports_tools: capabilities
+            .as_ref()
+            .map(|capabilities| capabilities.contains(&"tools")),
+        supports_images: capabilities
+            .as_ref()
+            .map(|capabilities| capabilities.contains(&"vision")),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -330,6 +392,77 @@ mod tests {
         assert_eq!(version, Some(Version::new(0, 14, 1)));
     }
 
+    #[tokio::test]
+    async fn test_fetch_model_metadata() {
+        if std::env::var(codex_core::spawn::CODEX_SANDBOX_NETWORK_DISABLED_ENV_VAR).is_ok() {
+            tracing::info!(
+                "{} is set; skipping test_fetch_model_metadata",
+                codex_core::spawn::CODEX_SANDBOX_NETWORK_DISABLED_ENV_VAR
+            );
+            return;
+        }
+
+        let server = wiremock::MockServer::start().await;
+        wiremock::Mock::given(wiremock::matchers::method("POST"))
+            .and(wiremock::matchers::path("/api/show"))
+            .and(wiremock::matchers::body_json(
+                serde_json::json!({ "model": "llama3.2:3b" }),
+            ))
+            .respond_with(
+                wiremock::ResponseTemplate::new(200).set_body_raw(
+                    serde_json::json!({
+                        "parameters": "stop \"<|eot_id|>\"\nnum_ctx 16384",
+                        "model_info": {
+                            "general.architecture": "llama",
+                            "llama.context_length": 131072
+                        },
+                        "capabilities": ["completion", "tools"]
+                    })
+                    .to_string(),
+                    "application/json",
+                ),
+            )
+            .mount(&server)
+            .await;
+
+        let client = OllamaClient::from_host_root(server.uri());
+        let metadata = client
+            .fetch_model_metadata("llama3.2:3b")
+            .await
+            .expect("fetch metadata");
+        assert_eq!(
+            metadata,
+            LocalModelMetadata {
+                slug: "llama3.2:3b".to_string(),
+                context_window: Some(16384),
+                supports_tools: Some(true),
+                supports_images: Some(false),
+            }
+        );
+    }
+
+    #[test]
+    fn model_metadata_leaves_context_window_unknown_without_num_ctx() {
+        let metadata = model_metadata_from_show(
+            "gemma3",
+            &serde_json::json!({
+                "model_info": {
+                    "general.architecture": "gemma3",
+                    "gemma3.context_length": 131072
+                }
+            }),
+        );
+        assert_eq!(
+            metadata,
+            LocalModelMetadata {
+                slug: "gemma3".to_string(),
+                context_window: None,
+                supports_tools: None,
+                supports_images: None,
+            }
+        );
+    }
+
     #[tokio::test]
     async fn test_probe_server_happy_path_openai_compat_and_native() {
         if std::env::var(codex_core::spawn::CODEX_SANDBOX_NETWORK_DISABLED_ENV_VAR).is_ok() {
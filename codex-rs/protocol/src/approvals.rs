@@ -78,6 +78,18 @@ pub enum ElicitationAction {
     Cancel,
 }
 
+/// One independently reviewable piece of a patch: an added or deleted file,
+/// a single `@@` chunk of an updated file, or a pure rename.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, JsonSchema, TS)]
+pub struct PatchHunk {
+    /// File the hunk belongs to (the path before any move).
+    pub path: PathBuf,
+    /// 0-based position of the hunk among the hunks for `path`.
+    pub index: usize,
+    /// The change this hunk alone would make.
+    pub change: FileChange,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
 pub struct ApplyPatchApprovalRequestEvent {
     /// Responses API call id for the associated patch apply call, if available.
@@ -87,6 +99,11 @@ pub struct ApplyPatchApprovalRequestEvent {
     #[serde(default)]
     pub turn_id: String,
     pub changes: HashMap<PathBuf, FileChange>,
+    /// The patch split into reviewable hunks, in patch order. When present,
+    /// the client may answer with `ReviewDecision::ApprovedHunks` to apply
+    /// only some of them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hunks: Vec<PatchHunk>,
     /// Optional explanatory reason (e.g. request for extra write access).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reason: Option<String>,
@@ -2,6 +2,11 @@ use std::collections::HashMap;
 use std::path::Path;
 
 use codex_utils_image::load_and_resize_to_fit;
+use codex_utils_image::pdf::DEFAULT_TOKEN_BUDGET;
+use codex_utils_image::pdf::PdfRenderOptions;
+use codex_utils_image::pdf::format_pages;
+use codex_utils_image::pdf::is_pdf;
+use codex_utils_image::pdf::load_pdf;
 use serde::Deserialize;
 use serde::Deserializer;
 use serde::Serialize;
@@ -514,8 +519,25 @@ pub fn local_image_label_text(label_number: usize) -> String {
     format!("[Image #{label_number}]")
 }
 
+pub fn local_pdf_label_text(label_number: usize) -> String {
+    format!("[PDF #{label_number}]")
+}
+
+/// Placeholder for a local attachment: `[PDF #N]` for PDFs and `[Image #N]`
+/// otherwise. Images and PDFs share one numbering sequence.
+pub fn local_attachment_label_text(path: &std::path::Path, label_number: usize) -> String {
+    if is_pdf(path) {
+        local_pdf_label_text(label_number)
+    } else {
+        local_image_label_text(label_number)
+    }
+}
+
 pub fn local_image_open_tag_text(label_number: usize) -> String {
-    let label = local_image_label_text(label_number);
+    local_open_tag_text(&local_image_label_text(label_number))
+}
+
+fn local_open_tag_text(label: &str) -> String {
     format!("{LOCAL_IMAGE_OPEN_TAG_PREFIX}{label}{LOCAL_IMAGE_OPEN_TAG_SUFFIX}")
 }
 
@@ -563,6 +585,26 @@ pub fn local_image_content_items_with_label_number(
     path: &std::path::Path,
     label_number: Option<usize>,
 ) -> Vec<ContentItem> {
+    local_attachment_content_items(path, label_number, DEFAULT_TOKEN_BUDGET)
+}
+
+/// Content items for a local image or PDF attachment. PDF pages are attached
+/// until they would exceed `pdf_token_budget`.
+pub fn local_attachment_content_items(
+    path: &std::path::Path,
+    label_number: Option<usize>,
+    pdf_token_budget: usize,
+) -> Vec<ContentItem> {
+    if is_pdf(path) {
+        return local_pdf_content_items(
+            path,
+            label_number,
+            &PdfRenderOptions {
+                pages: None,
+                token_budget: pdf_token_budget,
+            },
+        );
+    }
     match load_and_resize_to_fit(path) {
         Ok(image) => {
             let mut items = Vec::with_capacity(3);
@@ -606,6 +648,62 @@ pub fn local_image_content_items_with_label_number(
     }
 }
 
+/// Content items for a PDF attachment: each attached page rendered as an
+/// image followed by its extracted text, wrapped like a labeled local image
+/// but named `[PDF #N]`.
+pub fn local_pdf_content_items(
+    path: &std::path::Path,
+    label_number: Option<usize>,
+    options: &PdfRenderOptions,
+) -> Vec<ContentItem> {
+    let pdf = match load_pdf(path, options) {
+        Ok(pdf) => pdf,
+        Err(err) => {
+            return vec![ContentItem::InputText {
+                text: format!(
+                    "Codex could not read the PDF at `{}`: {err}",
+                    path.display()
+                ),
+            }];
+        }
+    };
+
+    let mut items = Vec::new();
+    if let Some(label_number) = label_number {
+        items.push(ContentItem::InputText {
+            text: local_open_tag_text(&local_pdf_label_text(label_number)),
+        });
+    }
+    for page in pdf.pages {
+        items.push(ContentItem::InputText {
+            text: format!("Page {} of {}", page.number, pdf.page_count),
+        });
+        if let Some(image) = page.image {
+            items.push(ContentItem::InputImage {
+                image_url: image.into_data_url(),
+            });
+        }
+        if let Some(text) = page.text {
+            items.push(ContentItem::InputText { text });
+        }
+    }
+    if !pdf.omitted_pages.is_empty() {
+        items.push(ContentItem::InputText {
+            text: format!(
+                "Pages {} of `{}` were not attached to stay within the context budget. Use `{VIEW_IMAGE_TOOL_NAME}` with `pages` to read them.",
+                format_pages(&pdf.omitted_pages),
+                path.display()
+            ),
+        });
+    }
+    if label_number.is_some() {
+        items.push(ContentItem::InputText {
+            text: LOCAL_IMAGE_CLOSE_TAG.to_string(),
+        });
+    }
+    items
+}
+
 impl From<ResponseInputItem> for ResponseItem {
     fn from(item: ResponseInputItem) -> Self {
         match item {
@@ -703,6 +801,14 @@ pub enum ReasoningItemContent {
 
 impl From<Vec<UserInput>> for ResponseInputItem {
     fn from(items: Vec<UserInput>) -> Self {
+        Self::from_user_input(items, DEFAULT_TOKEN_BUDGET)
+    }
+}
+
+impl ResponseInputItem {
+    /// Builds the user message for `items`, attaching local PDFs within
+    /// `pdf_token_budget` tokens each.
+    pub fn from_user_input(items: Vec<UserInput>, pdf_token_budget: usize) -> Self {
         let mut image_index = 0;
         Self::Message {
             role: "user".to_string(),
@@ -721,7 +827,7 @@ impl From<Vec<UserInput>> for ResponseInputItem {
                     ],
                     UserInput::LocalImage { path } => {
                         image_index += 1;
-                        local_image_content_items_with_label_number(&path, Some(image_index))
+                        local_attachment_content_items(&path, Some(image_index), pdf_token_budget)
                     }
                     UserInput::Skill { .. } | UserInput::Mention { .. } => Vec::new(), // Tool bodies are injected later in core
                 })
@@ -1631,6 +1737,53 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn local_attachment_labels_name_pdfs() -> Result<()> {
+        let dir = tempdir()?;
+        let pdf_path = dir.path().join("spec.pdf");
+        std::fs::write(&pdf_path, b"%PDF-1.7\n")?;
+        let image_path = dir.path().join("shot.png");
+        std::fs::write(&image_path, b"not a pdf")?;
+
+        assert_eq!(local_attachment_label_text(&pdf_path, 2), "[PDF #2]");
+        assert_eq!(local_attachment_label_text(&image_path, 3), "[Image #3]");
+        Ok(())
+    }
+
+    #[test]
+    fn local_pdf_read_error_adds_placeholder() -> Result<()> {
+        let dir = tempdir()?;
+        let pdf_path = dir.path().join("spec.pdf");
+        // Only a header: either poppler rejects it or is not installed.
+        std::fs::write(&pdf_path, b"%PDF-1.7\n")?;
+
+        let item = ResponseInputItem::from(vec![UserInput::LocalImage {
+            path: pdf_path.clone(),
+        }]);
+
+        match item {
+            ResponseInputItem::Message { content, .. } => {
+                assert_eq!(content.len(), 1);
+                match &content[0] {
+                    ContentItem::InputText { text } => {
+                        assert!(
+                            text.contains("could not read the PDF"),
+                            "placeholder should mention the PDF: {text}"
+                        );
+                        assert!(
+                            text.contains(&pdf_path.display().to_string()),
+                            "placeholder should mention path: {text}"
+                        );
+                    }
+                    other => panic!("expected placeholder text but found {other:?}"),
+                }
+            }
+            other => panic!("expected message response but got {other:?}"),
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn local_image_non_image_adds_placeholder() -> Result<()> {
         let dir = tempdir()?;
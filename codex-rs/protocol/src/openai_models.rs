@@ -212,6 +212,10 @@ const fn default_effective_context_window_percent() -> i64 {
     95
 }
 
+const fn default_supports_tools() -> bool {
+    true
+}
+
 /// Model metadata returned by the Codex backend `/models` endpoint.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, TS, JsonSchema)]
 pub struct ModelInfo {
@@ -249,6 +253,9 @@ pub struct ModelInfo {
     /// Input modalities accepted by the backend for this model.
     #[serde(default = "default_input_modalities")]
     pub input_modalities: Vec<InputModality>,
+    /// Whether the model can call tools. When `false`, turns are sent without tools.
+    #[serde(default = "default_supports_tools")]
+    pub supports_tools: bool,
 }
 
 impl ModelInfo {
@@ -506,6 +513,7 @@ mod tests {
             effective_context_window_percent: 95,
             experimental_supported_tools: vec![],
             input_modalities: default_input_modalities(),
+            supports_tools: true,
         }
     }
 
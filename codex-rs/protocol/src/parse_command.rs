@@ -25,7 +25,79 @@ pub enum ParsedCommand {
         query: Option<String>,
         path: Option<String>,
     },
+    /// Runs tests, e.g. `cargo test`, `pytest` or `npm test`.
+    Test {
+        cmd: String,
+        /// The test runner, e.g. `cargo`, `pytest` or `jest`.
+        runner: String,
+        /// Packages, files or name filters selecting the tests to run.
+        targets: Vec<String>,
+    },
+    /// Builds the project, e.g. `cargo build`, `make` or `npm run build`.
+    Build {
+        cmd: String,
+        /// The build tool, e.g. `cargo`, `make` or `npm`.
+        tool: String,
+        /// Packages or targets being built; empty for the default target.
+        targets: Vec<String>,
+    },
+    /// Installs packages, e.g. `npm install`, `pip install` or `cargo add`.
+    Install {
+        cmd: String,
+        /// The package manager, e.g. `npm`, `pip` or `cargo`.
+        manager: String,
+        /// Packages being installed; empty when installing from a manifest.
+        packages: Vec<String>,
+    },
+    /// Creates or overwrites files, e.g. `touch`, `mkdir` or `cp`.
+    Write {
+        cmd: String,
+        paths: Vec<String>,
+    },
+    /// Moves or renames files with `mv`. The last path is the destination.
+    Move {
+        cmd: String,
+        paths: Vec<String>,
+    },
+    /// Deletes files, e.g. `rm` or `rmdir`.
+    Delete {
+        cmd: String,
+        paths: Vec<String>,
+    },
+    /// Changes repository state, e.g. `git commit`, `git push` or `git reset`.
+    GitMutation {
+        cmd: String,
+        /// The git subcommand, e.g. `commit` or `push`.
+        subcommand: String,
+        /// Positional arguments such as remotes, branches, commits or paths.
+        targets: Vec<String>,
+    },
+    /// Fetches a URL, e.g. with `curl` or `wget`.
+    Fetch {
+        cmd: String,
+        url: Option<String>,
+    },
     Unknown {
         cmd: String,
     },
 }
+
+impl ParsedCommand {
+    /// The command as it will be displayed.
+    pub fn cmd(&self) -> &str {
+        match self {
+            ParsedCommand::Read { cmd, .. }
+            | ParsedCommand::ListFiles { cmd, .. }
+            | ParsedCommand::Search { cmd, .. }
+            | ParsedCommand::Test { cmd, .. }
+            | ParsedCommand::Build { cmd, .. }
+            | ParsedCommand::Install { cmd, .. }
+            | ParsedCommand::Write { cmd, .. }
+            | ParsedCommand::Move { cmd, .. }
+            | ParsedCommand::Delete { cmd, .. }
+            | ParsedCommand::GitMutation { cmd, .. }
+            | ParsedCommand::Fetch { cmd, .. }
+            | ParsedCommand::Unknown { cmd } => cmd,
+        }
+    }
+}
@@ -53,6 +53,7 @@ pub use crate::approvals::ApplyPatchApprovalRequestEvent;
 pub use crate::approvals::ElicitationAction;
 pub use crate::approvals::ExecApprovalRequestEvent;
 pub use crate::approvals::ExecPolicyAmendment;
+pub use crate::approvals::PatchHunk;
 pub use crate::request_user_input::RequestUserInputEvent;
 
 /// Open/close tags for special user-input blocks. Used across crates to avoid
@@ -291,6 +292,24 @@ pub enum Op {
     /// Request Codex to undo a turn (turn are stacked so it is the same effect as CMD + Z).
     Undo,
 
+    /// Request the snapshots the working tree can be restored to.
+    /// Reply is delivered via `EventMsg::ListUndoSnapshotsResponse`.
+    ListUndoSnapshots,
+
+    /// Restore the working tree to the snapshot taken before an earlier turn.
+    /// Snapshots for that turn and every later turn are consumed.
+    UndoTo {
+        /// Id of the snapshot, as reported by `ListUndoSnapshots`.
+        snapshot_id: String,
+        /// Restore even when files touched by the undo changed outside Codex
+        /// since the last turn finished.
+        #[serde(default)]
+        force: bool,
+    },
+
+    /// Restore the working tree to its state before the most recent undo.
+    Redo,
+
     /// Request Codex to drop the last N user turns from in-memory context.
     ///
     /// This does not attempt to revert local filesystem changes. Clients are
@@ -313,10 +332,59 @@ pub enum Op {
         command: String,
     },
 
+    /// Request the background processes started via `exec_command` that are
+    /// still tracked by the session.
+    /// Reply is delivered via `EventMsg::ListBackgroundProcessesResponse`.
+    ListBackgroundProcesses,
+
+    /// Request the recent output of a background process.
+    /// Reply is delivered via `EventMsg::BackgroundProcessOutput`.
+    ReadBackgroundProcessOutput {
+        /// Id of the process, as reported by `ListBackgroundProcesses`.
+        process_id: String,
+    },
+
+    /// Write user-typed input to the stdin of a background process.
+    /// Only processes started with a TTY accept input.
+    WriteBackgroundProcessStdin {
+        /// Id of the process, as reported by `ListBackgroundProcesses`.
+        process_id: String,
+        /// Raw input, including any trailing newline.
+        input: String,
+    },
+
+    /// Send a signal to a background process.
+    SignalBackgroundProcess {
+        /// Id of the process, as reported by `ListBackgroundProcesses`.
+        process_id: String,
+        signal: BackgroundProcessSignal,
+    },
+
+    /// Relaunch background processes that were running before the session was
+    /// resumed. The model is told the new process ids in a developer message.
+    /// Processes not listed are forgotten and not offered again.
+    RelaunchBackgroundProcesses {
+        /// Ids from the previous session, as reported by
+        /// `EventMsg::RestorableBackgroundProcesses`. Empty to relaunch none.
+        process_ids: Vec<String>,
+    },
+
     /// Request the list of available models.
     ListModels,
 }
 
+/// Signal that `Op::SignalBackgroundProcess` delivers to a background process.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, JsonSchema, TS)]
+#[serde(rename_all = "snake_case")]
+pub enum BackgroundProcessSignal {
+    /// Interrupt the process, as if Ctrl-C was pressed in its terminal.
+    Interrupt,
+    /// Ask the process to terminate (SIGTERM).
+    Terminate,
+    /// Kill the process and stop tracking it.
+    Kill,
+}
+
 /// Determines the conditions under which the user is consulted to approve
 /// running the command proposed by Codex.
 #[derive(
@@ -764,6 +832,9 @@ pub enum EventMsg {
     /// Aggregate MCP startup completion summary.
     McpStartupComplete(McpStartupCompleteEvent),
 
+    /// An MCP server changed its tools or resources after startup.
+    McpListChanged(McpListChangedEvent),
+
     McpToolCallBegin(McpToolCallBeginEvent),
 
     McpToolCallEnd(McpToolCallEndEvent),
@@ -806,6 +877,19 @@ pub enum EventMsg {
 
     UndoCompleted(UndoCompletedEvent),
 
+    /// Snapshots available to `Op::UndoTo`.
+    ListUndoSnapshotsResponse(ListUndoSnapshotsResponseEvent),
+
+    /// Background processes tracked by the session.
+    ListBackgroundProcessesResponse(ListBackgroundProcessesResponseEvent),
+
+    /// Recent output of a background process.
+    BackgroundProcessOutput(BackgroundProcessOutputEvent),
+
+    /// Background processes that were running when a resumed session was last
+    /// active and can be relaunched with `Op::RelaunchBackgroundProcesses`.
+    RestorableBackgroundProcesses(RestorableBackgroundProcessesEvent),
+
     /// Notification that a model stream experienced an error or disconnect
     /// and the system is handling it (e.g., retrying with backoff).
     StreamError(StreamErrorEvent),
@@ -1693,6 +1777,36 @@ pub enum RolloutItem {
     Compacted(CompactedItem),
     TurnContext(TurnContextItem),
     EventMsg(EventMsg),
+    BackgroundProcess(BackgroundProcessItem),
+    TurnCompleted(TurnCompletedItem),
+}
+
+/// Marks the end of a turn, recorded so a resumed session knows when Codex last
+/// touched the working tree.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+pub struct TurnCompletedItem {
+    /// Milliseconds since the Unix epoch.
+    pub completed_at_ms: u64,
+}
+
+/// Lifecycle marker for a long-running `exec_command` process, recorded so a
+/// resumed session can offer to relaunch processes that were still running.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum BackgroundProcessItem {
+    /// The process outlived its initial `exec_command` call.
+    Started(BackgroundProcessLaunch),
+    /// The process exited on its own or was killed by the user or model.
+    Exited { process_id: String },
+}
+
+/// Everything needed to start a background process again.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+pub struct BackgroundProcessLaunch {
+    pub process_id: String,
+    pub command: Vec<String>,
+    pub cwd: PathBuf,
+    pub tty: bool,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, JsonSchema, TS)]
@@ -1794,6 +1908,19 @@ pub enum ReviewTarget {
         title: Option<String>,
     },
 
+    /// Review the changes between two commits, equivalent to `git diff from..to`.
+    #[serde(rename_all = "camelCase")]
+    #[ts(rename_all = "camelCase")]
+    CommitRange { from: String, to: String },
+
+    /// Review the changes described by a patch file on disk.
+    #[serde(rename_all = "camelCase")]
+    #[ts(rename_all = "camelCase")]
+    PatchFile {
+        /// Path to the patch, resolved against the session cwd when relative.
+        path: PathBuf,
+    },
+
     /// Arbitrary instructions provided by the user.
     #[serde(rename_all = "camelCase")]
     #[ts(rename_all = "camelCase")]
@@ -1995,6 +2122,73 @@ pub struct UndoCompletedEvent {
     pub message: Option<String>,
 }
 
+/// A working tree snapshot taken before a turn.
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS, PartialEq)]
+pub struct UndoSnapshot {
+    /// Ghost commit id of the snapshot.
+    pub id: String,
+    /// Message that started the turn the snapshot was taken for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_message: Option<String>,
+    /// Number of user turns made since the snapshot, including its own.
+    pub turns: u32,
+    /// Files that restoring the snapshot changes, relative to the session cwd.
+    pub changed_files: Vec<PathBuf>,
+    /// Unified diff of the changes that restoring the snapshot reverts.
+    pub unified_diff: String,
+    /// Changed files modified outside Codex since the last turn finished.
+    /// Restoring requires `force` while this is non-empty.
+    pub conflicts: Vec<PathBuf>,
+}
+
+/// Response payload for `Op::ListUndoSnapshots`.
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
+pub struct ListUndoSnapshotsResponseEvent {
+    /// Snapshots ordered from the most recent turn to the oldest.
+    pub snapshots: Vec<UndoSnapshot>,
+    /// Whether `Op::Redo` has an undo to reverse.
+    pub redo_available: bool,
+}
+
+/// A process started via `exec_command` that is still tracked by the session.
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS, PartialEq)]
+pub struct BackgroundProcess {
+    /// Process id used to address the process in later ops.
+    pub process_id: String,
+    /// The command that started the process.
+    pub command: Vec<String>,
+    /// The working directory the process was started in.
+    pub cwd: PathBuf,
+    /// Whether the process was started with a TTY and accepts stdin.
+    pub tty: bool,
+}
+
+/// Response payload for `Op::ListBackgroundProcesses`.
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
+pub struct ListBackgroundProcessesResponseEvent {
+    /// Processes ordered from the oldest to the most recently started.
+    pub processes: Vec<BackgroundProcess>,
+}
+
+/// Response payload for `Op::ReadBackgroundProcessOutput`.
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
+pub struct BackgroundProcessOutputEvent {
+    /// Id of the process the output belongs to.
+    pub process_id: String,
+    /// Retained output of the process, with configured secrets redacted.
+    pub output: String,
+    /// Whether the process is still running.
+    pub running: bool,
+}
+
+/// Payload for `EventMsg::RestorableBackgroundProcesses`.
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
+pub struct RestorableBackgroundProcessesEvent {
+    /// Processes ordered from the oldest to the most recently started, with
+    /// the ids they had in the previous session.
+    pub processes: Vec<BackgroundProcess>,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
 pub struct ThreadRolledBackEvent {
     /// Number of user turns that were removed from context.
@@ -2085,6 +2279,21 @@ pub struct McpStartupUpdateEvent {
     pub status: McpStartupStatus,
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
+pub struct McpListChangedEvent {
+    /// Server that sent the `list_changed` notification.
+    pub server: String,
+    /// Which list changed. Tool changes take effect from the next turn.
+    pub kind: McpListChangedKind,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, JsonSchema, TS)]
+#[serde(rename_all = "snake_case")]
+pub enum McpListChangedKind {
+    Tools,
+    Resources,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
 #[serde(rename_all = "snake_case", tag = "state")]
 #[ts(rename_all = "snake_case", tag = "state")]
@@ -2326,6 +2535,24 @@ pub enum ReviewDecision {
     /// User has denied this command and the agent should not do anything until
     /// the user's next command.
     Abort,
+
+    /// User has approved a patch except for the listed hunks, which must not be
+    /// applied. Only meaningful in response to an `ApplyPatchApprovalRequest`
+    /// that lists `hunks`.
+    ApprovedHunks { declined: Vec<DeclinedHunk> },
+}
+
+/// A patch hunk the user chose not to apply.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, JsonSchema, TS)]
+pub struct DeclinedHunk {
+    /// File the hunk belongs to, as in `PatchHunk::path`.
+    pub path: PathBuf,
+    /// Index of the hunk among the hunks for `path`, as in `PatchHunk::index`.
+    pub index: usize,
+    /// Optional note from the user explaining the rejection.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub note: Option<String>,
 }
 
 impl ReviewDecision {
@@ -2338,6 +2565,7 @@ impl ReviewDecision {
             ReviewDecision::ApprovedForSession => "approved_for_session",
             ReviewDecision::Denied => "denied",
             ReviewDecision::Abort => "abort",
+            ReviewDecision::ApprovedHunks { .. } => "approved_hunks",
         }
     }
 }
@@ -2740,4 +2968,45 @@ mod tests {
         assert_eq!(value["msg"]["cancelled"][0], "c");
         Ok(())
     }
+
+    #[test]
+    fn background_process_rollout_items_round_trip() -> Result<()> {
+        let started = RolloutItem::BackgroundProcess(BackgroundProcessItem::Started(
+            BackgroundProcessLaunch {
+                process_id: "1000".to_string(),
+                command: vec!["npm".to_string(), "run".to_string(), "dev".to_string()],
+                cwd: PathBuf::from("/workspace"),
+                tty: true,
+            },
+        ));
+        let value = serde_json::to_value(&started)?;
+        assert_eq!(
+            value,
+            json!({
+                "type": "background_process",
+                "payload": {
+                    "status": "started",
+                    "process_id": "1000",
+                    "command": ["npm", "run", "dev"],
+                    "cwd": "/workspace",
+                    "tty": true,
+                },
+            })
+        );
+
+        let exited: RolloutItem = serde_json::from_value(json!({
+            "type": "background_process",
+            "payload": {"status": "exited", "process_id": "1000"},
+        }))?;
+        let RolloutItem::BackgroundProcess(item) = exited else {
+            panic!("expected background process item");
+        };
+        assert_eq!(
+            item,
+            BackgroundProcessItem::Exited {
+                process_id: "1000".to_string()
+            }
+        );
+        Ok(())
+    }
 }
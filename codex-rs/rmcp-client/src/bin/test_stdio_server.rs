@@ -1,12 +1,15 @@
 use std::borrow::Cow;
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
 
 use rmcp::ErrorData as McpError;
 use rmcp::ServiceExt;
 use rmcp::handler::server::ServerHandler;
 use rmcp::model::CallToolRequestParam;
 use rmcp::model::CallToolResult;
+use rmcp::model::CreateMessageRequestParam;
 use rmcp::model::JsonObject;
 use rmcp::model::ListResourceTemplatesResult;
 use rmcp::model::ListResourcesResult;
@@ -29,6 +32,7 @@ use tokio::task;
 #[derive(Clone)]
 struct TestToolServer {
     tools: Arc<Vec<Tool>>,
+    project_tools_loaded: Arc<AtomicBool>,
     resources: Arc<Vec<Resource>>,
     resource_templates: Arc<Vec<ResourceTemplate>>,
 }
@@ -47,11 +51,15 @@ impl TestToolServer {
             Self::echo_tool(),
             Self::image_tool(),
             Self::image_scenario_tool(),
+            Self::sample_tool(),
+            Self::list_roots_tool(),
+            Self::load_project_tools_tool(),
         ];
         let resources = vec![Self::memo_resource()];
         let resource_templates = vec![Self::memo_template()];
         Self {
             tools: Arc::new(tools),
+            project_tools_loaded: Arc::new(AtomicBool::new(false)),
             resources: Arc::new(resources),
             resource_templates: Arc::new(resource_templates),
         }
@@ -148,6 +156,76 @@ impl TestToolServer {
         )
     }
 
+    /// Asks the client to sample the model via `sampling/createMessage` and
+    /// returns the raw result as structured content.
+    fn sample_tool() -> Tool {
+        #[expect(clippy::expect_used)]
+        let schema: JsonObject = serde_json::from_value(json!({
+            "type": "object",
+            "properties": {
+                "prompt": { "type": "string" }
+            },
+            "required": ["prompt"],
+            "additionalProperties": false
+        }))
+        .expect("sample tool schema should deserialize");
+
+        Tool::new(
+            Cow::Borrowed("sample"),
+            Cow::Borrowed("Request a completion from the client's model."),
+            Arc::new(schema),
+        )
+    }
+
+    /// Returns the client's `roots/list` response as structured content.
+    fn list_roots_tool() -> Tool {
+        #[expect(clippy::expect_used)]
+        let schema: JsonObject = serde_json::from_value(json!({
+            "type": "object",
+            "properties": {},
+            "additionalProperties": false
+        }))
+        .expect("list_roots tool schema should deserialize");
+
+        Tool::new(
+            Cow::Borrowed("list_roots"),
+            Cow::Borrowed("List the client's workspace roots."),
+            Arc::new(schema),
+        )
+    }
+
+    /// Reveals `project_tool` and announces it with `notifications/tools/list_changed`,
+    /// like servers that load tools lazily per project.
+    fn load_project_tools_tool() -> Tool {
+        Self::no_args_tool(
+            "load_project_tools",
+            "Load the tools of the current project.",
+        )
+    }
+
+    fn project_tool() -> Tool {
+        Self::no_args_tool(
+            "project_tool",
+            "Tool that exists once the project is loaded.",
+        )
+    }
+
+    fn no_args_tool(name: &'static str, description: &'static str) -> Tool {
+        #[expect(clippy::expect_used)]
+        let schema: JsonObject = serde_json::from_value(json!({
+            "type": "object",
+            "properties": {},
+            "additionalProperties": false
+        }))
+        .expect("tool schema should deserialize");
+
+        Tool::new(
+            Cow::Borrowed(name),
+            Cow::Borrowed(description),
+            Arc::new(schema),
+        )
+    }
+
     fn memo_resource() -> Resource {
         let raw = RawResource {
             uri: MEMO_URI.to_string(),
@@ -187,6 +265,11 @@ struct EchoArgs {
     env_var: Option<String>,
 }
 
+#[derive(Deserialize)]
+struct SampleArgs {
+    prompt: String,
+}
+
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "snake_case")]
 /// Scenarios for `image_scenario`, intended to exercise Codex TUI handling of MCP image outputs.
@@ -230,10 +313,13 @@ impl ServerHandler for TestToolServer {
         _request: Option<PaginatedRequestParam>,
         _context: rmcp::service::RequestContext<rmcp::service::RoleServer>,
     ) -> impl std::future::Future<Output = Result<ListToolsResult, McpError>> + Send + '_ {
-        let tools = self.tools.clone();
+        let mut tools = (*self.tools).clone();
+        if self.project_tools_loaded.load(Ordering::SeqCst) {
+            tools.push(Self::project_tool());
+        }
         async move {
             Ok(ListToolsResult {
-                tools: (*tools).clone(),
+                tools,
                 next_cursor: None,
                 meta: None,
             })
@@ -292,7 +378,7 @@ impl ServerHandler for TestToolServer {
     async fn call_tool(
         &self,
         request: CallToolRequestParam,
-        _context: rmcp::service::RequestContext<rmcp::service::RoleServer>,
+        context: rmcp::service::RequestContext<rmcp::service::RoleServer>,
     ) -> Result<CallToolResult, McpError> {
         match request.name.as_ref() {
             "echo" => {
@@ -347,6 +433,43 @@ impl ServerHandler for TestToolServer {
                 let args = Self::parse_call_args::<ImageScenarioArgs>(&request, "image_scenario")?;
                 Self::image_scenario_result(args)
             }
+            "sample" => {
+                let args = Self::parse_call_args::<SampleArgs>(&request, "sample")?;
+                let params: CreateMessageRequestParam = serde_json::from_value(json!({
+                    "messages": [{
+                        "role": "user",
+                        "content": { "type": "text", "text": args.prompt }
+                    }],
+                    "maxTokens": 64
+                }))
+                .map_err(|err| McpError::internal_error(err.to_string(), None))?;
+                let result = context
+                    .peer
+                    .create_message(params)
+                    .await
+                    .map_err(|err| McpError::internal_error(err.to_string(), None))?;
+                Self::structured_result(&result)
+            }
+            "list_roots" => {
+                let result = context
+                    .peer
+                    .list_roots()
+                    .await
+                    .map_err(|err| McpError::internal_error(err.to_string(), None))?;
+                Self::structured_result(&result)
+            }
+            "load_project_tools" => {
+                self.project_tools_loaded.store(true, Ordering::SeqCst);
+                context
+                    .peer
+                    .notify_tool_list_changed()
+                    .await
+                    .map_err(|err| McpError::internal_error(err.to_string(), None))?;
+                Self::structured_result(&json!({ "loaded": true }))
+            }
+            "project_tool" if self.project_tools_loaded.load(Ordering::SeqCst) => {
+                Self::structured_result(&json!({ "project": "ready" }))
+            }
             other => Err(McpError::invalid_params(
                 format!("unknown tool: {other}"),
                 None,
@@ -372,6 +495,17 @@ impl TestToolServer {
         }
     }
 
+    fn structured_result(value: &impl serde::Serialize) -> Result<CallToolResult, McpError> {
+        let structured_content = serde_json::to_value(value)
+            .map_err(|err| McpError::internal_error(err.to_string(), None))?;
+        Ok(CallToolResult {
+            content: Vec::new(),
+            structured_content: Some(structured_content),
+            is_error: Some(false),
+            meta: None,
+        })
+    }
+
     fn image_scenario_result(args: ImageScenarioArgs) -> Result<CallToolResult, McpError> {
         let (mime_type, valid_data_b64) = if let Some(data_url) = &args.data_url {
             parse_data_url(data_url).ok_or_else(|| {
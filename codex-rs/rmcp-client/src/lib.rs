@@ -21,7 +21,14 @@ pub use perform_oauth_login::perform_oauth_login_return_url;
 pub use rmcp::model::ElicitationAction;
 pub use rmcp_client::Elicitation;
 pub use rmcp_client::ElicitationResponse;
+pub use rmcp_client::ListChanged;
+pub use rmcp_client::ListRoots;
 pub use rmcp_client::ListToolsWithConnectorIdResult;
+pub use rmcp_client::OnListChanged;
 pub use rmcp_client::RmcpClient;
+pub use rmcp_client::SamplingRequest;
+pub use rmcp_client::SamplingResponse;
 pub use rmcp_client::SendElicitation;
+pub use rmcp_client::SendSampling;
+pub use rmcp_client::StdioSandboxLauncher;
 pub use rmcp_client::ToolWithConnectorId;
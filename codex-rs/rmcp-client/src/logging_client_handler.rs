@@ -6,6 +6,9 @@ use rmcp::model::CancelledNotificationParam;
 use rmcp::model::ClientInfo;
 use rmcp::model::CreateElicitationRequestParam;
 use rmcp::model::CreateElicitationResult;
+use rmcp::model::CreateMessageRequestParam;
+use rmcp::model::CreateMessageResult;
+use rmcp::model::ListRootsResult;
 use rmcp::model::LoggingLevel;
 use rmcp::model::LoggingMessageNotificationParam;
 use rmcp::model::ProgressNotificationParam;
@@ -17,19 +20,41 @@ use tracing::error;
 use tracing::info;
 use tracing::warn;
 
+use crate::rmcp_client::ListChanged;
+use crate::rmcp_client::ListRoots;
+use crate::rmcp_client::OnListChanged;
 use crate::rmcp_client::SendElicitation;
+use crate::rmcp_client::SendSampling;
 
 #[derive(Clone)]
 pub(crate) struct LoggingClientHandler {
     client_info: ClientInfo,
     send_elicitation: Arc<SendElicitation>,
+    send_sampling: Option<Arc<SendSampling>>,
+    list_roots: Option<Arc<ListRoots>>,
+    on_list_changed: Option<Arc<OnListChanged>>,
 }
 
 impl LoggingClientHandler {
-    pub(crate) fn new(client_info: ClientInfo, send_elicitation: SendElicitation) -> Self {
+    pub(crate) fn new(
+        client_info: ClientInfo,
+        send_elicitation: SendElicitation,
+        send_sampling: Option<SendSampling>,
+        list_roots: Option<ListRoots>,
+        on_list_changed: Option<OnListChanged>,
+    ) -> Self {
         Self {
             client_info,
             send_elicitation: Arc::new(send_elicitation),
+            send_sampling: send_sampling.map(Arc::new),
+            list_roots: list_roots.map(Arc::new),
+            on_list_changed: on_list_changed.map(Arc::new),
+        }
+    }
+
+    fn notify_list_changed(&self, list: ListChanged) {
+        if let Some(on_list_changed) = &self.on_list_changed {
+            on_list_changed(list);
         }
     }
 }
@@ -45,6 +70,36 @@ impl ClientHandler for LoggingClientHandler {
             .map_err(|err| rmcp::ErrorData::internal_error(err.to_string(), None))
     }
 
+    async fn create_message(
+        &self,
+        params: CreateMessageRequestParam,
+        context: RequestContext<RoleClient>,
+    ) -> Result<CreateMessageResult, rmcp::ErrorData> {
+        let Some(send_sampling) = &self.send_sampling else {
+            return Err(rmcp::ErrorData::method_not_found::<
+                rmcp::model::CreateMessageRequestMethod,
+            >());
+        };
+        send_sampling(context.id, params)
+            .await
+            .map_err(|err| rmcp::ErrorData::internal_error(err.to_string(), None))
+    }
+
+    async fn list_roots(
+        &self,
+        _context: RequestContext<RoleClient>,
+    ) -> Result<ListRootsResult, rmcp::ErrorData> {
+        let Some(list_roots) = &self.list_roots else {
+            return Err(rmcp::ErrorData::method_not_found::<
+                rmcp::model::ListRootsRequestMethod,
+            >());
+        };
+        let roots = list_roots()
+            .await
+            .map_err(|err| rmcp::ErrorData::internal_error(err.to_string(), None))?;
+        Ok(ListRootsResult { roots })
+    }
+
     async fn on_cancelled(
         &self,
         params: CancelledNotificationParam,
@@ -77,10 +132,12 @@ impl ClientHandler for LoggingClientHandler {
 
     async fn on_resource_list_changed(&self, _context: NotificationContext<RoleClient>) {
         info!("MCP server resource list changed");
+        self.notify_list_changed(ListChanged::Resources);
     }
 
     async fn on_tool_list_changed(&self, _context: NotificationContext<RoleClient>) {
         info!("MCP server tool list changed");
+        self.notify_list_changed(ListChanged::Tools);
     }
 
     async fn on_prompt_list_changed(&self, _context: NotificationContext<RoleClient>) {
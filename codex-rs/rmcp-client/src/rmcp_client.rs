@@ -17,6 +17,8 @@ use rmcp::model::ClientNotification;
 use rmcp::model::ClientRequest;
 use rmcp::model::CreateElicitationRequestParam;
 use rmcp::model::CreateElicitationResult;
+use rmcp::model::CreateMessageRequestParam;
+use rmcp::model::CreateMessageResult;
 use rmcp::model::CustomNotification;
 use rmcp::model::CustomRequest;
 use rmcp::model::Extensions;
@@ -29,6 +31,7 @@ use rmcp::model::PaginatedRequestParam;
 use rmcp::model::ReadResourceRequestParam;
 use rmcp::model::ReadResourceResult;
 use rmcp::model::RequestId;
+use rmcp::model::Root;
 use rmcp::model::ServerResult;
 use rmcp::model::Tool;
 use rmcp::service::RoleClient;
@@ -88,6 +91,44 @@ pub type SendElicitation = Box<
     dyn Fn(RequestId, Elicitation) -> BoxFuture<'static, Result<ElicitationResponse>> + Send + Sync,
 >;
 
+pub type SamplingRequest = CreateMessageRequestParam;
+pub type SamplingResponse = CreateMessageResult;
+
+/// Interface for forwarding `sampling/createMessage` requests to the host's
+/// model client and awaiting the completion.
+pub type SendSampling = Box<
+    dyn Fn(RequestId, SamplingRequest) -> BoxFuture<'static, Result<SamplingResponse>>
+        + Send
+        + Sync,
+>;
+
+/// Interface for answering `roots/list` with the host's current workspace roots.
+pub type ListRoots = Box<dyn Fn() -> BoxFuture<'static, Result<Vec<Root>>> + Send + Sync>;
+
+/// Server-side list announced as changed through `notifications/*/list_changed`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListChanged {
+    Tools,
+    Resources,
+}
+
+/// Callback invoked for `notifications/tools/list_changed` and
+/// `notifications/resources/list_changed`. Runs on the transport task, so it
+/// must not block.
+pub type OnListChanged = Box<dyn Fn(ListChanged) + Send + Sync>;
+
+/// Launcher that confines a stdio server. The server is spawned as
+/// `program args.. <server command> <server args..>`.
+#[derive(Debug, Clone)]
+pub struct StdioSandboxLauncher {
+    pub program: PathBuf,
+    /// Overrides `argv[0]` of the launcher. Ignored on non-Unix platforms.
+    pub arg0: Option<String>,
+    pub args: Vec<String>,
+    /// Extra environment variables for the sandboxed process.
+    pub env: HashMap<String, String>,
+}
+
 pub struct ToolWithConnectorId {
     pub tool: Tool,
     pub connector_id: Option<String>,
@@ -112,6 +153,7 @@ impl RmcpClient {
         env: Option<HashMap<String, String>>,
         env_vars: &[String],
         cwd: Option<PathBuf>,
+        sandbox: Option<StdioSandboxLauncher>,
     ) -> io::Result<Self> {
         let program_name = program.to_string_lossy().into_owned();
 
@@ -121,13 +163,25 @@ impl RmcpClient {
         // Resolve program to executable path (platform-specific)
         let resolved_program = program_resolver::resolve(program, &envs)?;
 
-        let mut command = Command::new(resolved_program);
+        let (mut command, sandbox_env) = match sandbox {
+            Some(launcher) => {
+                let mut command = Command::new(&launcher.program);
+                #[cfg(unix)]
+                if let Some(arg0) = &launcher.arg0 {
+                    command.arg0(arg0);
+                }
+                command.args(&launcher.args).arg(resolved_program);
+                (command, launcher.env)
+            }
+            None => (Command::new(resolved_program), HashMap::new()),
+        };
         command
             .kill_on_drop(true)
             .stdin(Stdio::piped())
             .stdout(Stdio::piped())
             .env_clear()
             .envs(envs)
+            .envs(sandbox_env)
             .args(&args);
         if let Some(cwd) = cwd {
             command.current_dir(cwd);
@@ -218,13 +272,26 @@ impl RmcpClient {
 
     /// Perform the initialization handshake with the MCP server.
     /// https://modelcontextprotocol.io/specification/2025-06-18/basic/lifecycle#initialization
+    ///
+    /// `send_sampling` and `list_roots` should only be provided when `params`
+    /// advertises the matching `sampling` / `roots` client capabilities;
+    /// requests for a missing handler are rejected with "method not found".
     pub async fn initialize(
         &self,
         params: InitializeRequestParam,
         timeout: Option<Duration>,
         send_elicitation: SendElicitation,
+        send_sampling: Option<SendSampling>,
+        list_roots: Option<ListRoots>,
+        on_list_changed: Option<OnListChanged>,
     ) -> Result<InitializeResult> {
-        let client_handler = LoggingClientHandler::new(params.clone(), send_elicitation);
+        let client_handler = LoggingClientHandler::new(
+            params.clone(),
+            send_elicitation,
+            send_sampling,
+            list_roots,
+            on_list_changed,
+        );
 
         let (transport, oauth_persistor) = {
             let mut guard = self.state.lock().await;
@@ -405,6 +472,14 @@ impl RmcpClient {
         Ok(result)
     }
 
+    /// Tells the server that the workspace roots changed so it can re-query
+    /// them with `roots/list`.
+    pub async fn notify_roots_list_changed(&self) -> Result<()> {
+        let service = self.service().await?;
+        service.notify_roots_list_changed().await?;
+        Ok(())
+    }
+
     pub async fn send_custom_notification(
         &self,
         method: &str,
@@ -0,0 +1,106 @@
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use codex_rmcp_client::ElicitationAction;
+use codex_rmcp_client::ElicitationResponse;
+use codex_rmcp_client::ListChanged;
+use codex_rmcp_client::RmcpClient;
+use codex_utils_cargo_bin::CargoBinError;
+use futures::FutureExt as _;
+use pretty_assertions::assert_eq;
+use rmcp::model::ClientCapabilities;
+use rmcp::model::ElicitationCapability;
+use rmcp::model::Implementation;
+use rmcp::model::InitializeRequestParam;
+use rmcp::model::ProtocolVersion;
+use serde_json::json;
+use tokio::sync::mpsc;
+
+fn stdio_server_bin() -> Result<PathBuf, CargoBinError> {
+    codex_utils_cargo_bin::cargo_bin("test_stdio_server")
+}
+
+fn init_params() -> InitializeRequestParam {
+    InitializeRequestParam {
+        capabilities: ClientCapabilities {
+            experimental: None,
+            roots: None,
+            sampling: None,
+            elicitation: Some(ElicitationCapability {
+                schema_validation: None,
+            }),
+        },
+        client_info: Implementation {
+            name: "codex-test".into(),
+            version: "0.0.0-test".into(),
+            title: Some("Codex rmcp list_changed test".into()),
+            icons: None,
+            website_url: None,
+        },
+        protocol_version: ProtocolVersion::V_2025_06_18,
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn rmcp_client_reports_tool_list_changes() -> anyhow::Result<()> {
+    let client = RmcpClient::new_stdio_client(
+        stdio_server_bin()?.into(),
+        Vec::<OsString>::new(),
+        None,
+        &[],
+        None,
+        None,
+    )
+    .await?;
+
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    client
+        .initialize(
+            init_params(),
+            Some(Duration::from_secs(5)),
+            Box::new(|_, _| {
+                async {
+                    Ok(ElicitationResponse {
+                        action: ElicitationAction::Accept,
+                        content: Some(json!({})),
+                    })
+                }
+                .boxed()
+            }),
+            None,
+            None,
+            Some(Box::new(move |list| {
+                let _ = tx.send(list);
+            })),
+        )
+        .await?;
+
+    let tool_names = |tools: &[rmcp::model::Tool]| {
+        tools
+            .iter()
+            .map(|tool| tool.name.to_string())
+            .collect::<Vec<_>>()
+    };
+    let before = client
+        .list_tools(None, Some(Duration::from_secs(5)))
+        .await?;
+    assert!(!tool_names(&before.tools).contains(&"project_tool".to_string()));
+
+    client
+        .call_tool(
+            "load_project_tools".to_string(),
+            Some(json!({})),
+            Some(Duration::from_secs(5)),
+        )
+        .await?;
+    let list = tokio::time::timeout(Duration::from_secs(5), rx.recv()).await?;
+    assert_eq!(list, Some(ListChanged::Tools));
+
+    let after = client
+        .list_tools(None, Some(Duration::from_secs(5)))
+        .await?;
+    assert!(tool_names(&after.tools).contains(&"project_tool".to_string()));
+
+    Ok(())
+}
@@ -53,6 +53,7 @@ async fn rmcp_client_can_list_and_read_resources() -> anyhow::Result<()> {
         None,
         &[],
         None,
+        None,
     )
     .await?;
 
@@ -69,6 +70,9 @@ async fn rmcp_client_can_list_and_read_resources() -> anyhow::Result<()> {
                 }
                 .boxed()
             }),
+            None,
+            None,
+            None,
         )
         .await?;
 
@@ -0,0 +1,140 @@
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use codex_rmcp_client::ElicitationAction;
+use codex_rmcp_client::ElicitationResponse;
+use codex_rmcp_client::RmcpClient;
+use codex_rmcp_client::SamplingResponse;
+use codex_utils_cargo_bin::CargoBinError;
+use futures::FutureExt as _;
+use pretty_assertions::assert_eq;
+use rmcp::model::ClientCapabilities;
+use rmcp::model::Content;
+use rmcp::model::ElicitationCapability;
+use rmcp::model::Implementation;
+use rmcp::model::InitializeRequestParam;
+use rmcp::model::ProtocolVersion;
+use rmcp::model::Role;
+use rmcp::model::Root;
+use rmcp::model::RootsCapabilities;
+use rmcp::model::SamplingMessage;
+use serde_json::json;
+
+fn stdio_server_bin() -> Result<PathBuf, CargoBinError> {
+    codex_utils_cargo_bin::cargo_bin("test_stdio_server")
+}
+
+fn init_params() -> InitializeRequestParam {
+    InitializeRequestParam {
+        capabilities: ClientCapabilities {
+            experimental: None,
+            roots: Some(RootsCapabilities {
+                list_changed: Some(true),
+            }),
+            sampling: Some(serde_json::Map::new()),
+            elicitation: Some(ElicitationCapability {
+                schema_validation: None,
+            }),
+        },
+        client_info: Implementation {
+            name: "codex-test".into(),
+            version: "0.0.0-test".into(),
+            title: Some("Codex rmcp sampling test".into()),
+            icons: None,
+            website_url: None,
+        },
+        protocol_version: ProtocolVersion::V_2025_06_18,
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn rmcp_client_serves_sampling_and_roots_requests() -> anyhow::Result<()> {
+    let client = RmcpClient::new_stdio_client(
+        stdio_server_bin()?.into(),
+        Vec::<OsString>::new(),
+        None,
+        &[],
+        None,
+        None,
+    )
+    .await?;
+
+    client
+        .initialize(
+            init_params(),
+            Some(Duration::from_secs(5)),
+            Box::new(|_, _| {
+                async {
+                    Ok(ElicitationResponse {
+                        action: ElicitationAction::Accept,
+                        content: Some(json!({})),
+                    })
+                }
+                .boxed()
+            }),
+            Some(Box::new(|_, request| {
+                async move {
+                    let prompt = serde_json::to_value(&request.messages)?;
+                    Ok(SamplingResponse {
+                        model: "mock-model".to_string(),
+                        stop_reason: Some(SamplingResponse::STOP_REASON_END_TURN.to_string()),
+                        message: SamplingMessage {
+                            role: Role::Assistant,
+                            content: Content::text(format!(
+                                "sampled {}",
+                                prompt[0]["content"]["text"]
+                            )),
+                        },
+                    })
+                }
+                .boxed()
+            })),
+            Some(Box::new(|| {
+                async {
+                    Ok(vec![Root {
+                        uri: "file:///workspace/project".to_string(),
+                        name: Some("project".to_string()),
+                    }])
+                }
+                .boxed()
+            })),
+            None,
+        )
+        .await?;
+
+    let sampled = client
+        .call_tool(
+            "sample".to_string(),
+            Some(json!({ "prompt": "hello" })),
+            Some(Duration::from_secs(5)),
+        )
+        .await?;
+    assert_eq!(
+        sampled.structured_content,
+        Some(json!({
+            "model": "mock-model",
+            "stopReason": "endTurn",
+            "role": "assistant",
+            "content": { "type": "text", "text": "sampled \"hello\"" },
+        }))
+    );
+
+    let roots = client
+        .call_tool(
+            "list_roots".to_string(),
+            Some(json!({})),
+            Some(Duration::from_secs(5)),
+        )
+        .await?;
+    assert_eq!(
+        roots.structured_content,
+        Some(json!({
+            "roots": [{ "uri": "file:///workspace/project", "name": "project" }],
+        }))
+    );
+
+    client.notify_roots_list_changed().await?;
+
+    Ok(())
+}
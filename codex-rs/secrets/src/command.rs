@@ -0,0 +1,445 @@
+use std::io::Read;
+use std::io::Write;
+use std::process::Child;
+use std::process::Command;
+use std::process::ExitStatus;
+use std::process::Stdio;
+use std::thread::JoinHandle;
+use std::time::Duration;
+use std::time::Instant;
+
+use anyhow::Context;
+use anyhow::Result;
+use tracing::warn;
+
+use super::SecretListEntry;
+use super::SecretName;
+use super::SecretScope;
+use super::SecretsBackend;
+use super::local::parse_canonical_key;
+
+pub const DEFAULT_COMMAND_TIMEOUT: Duration = Duration::from_secs(10);
+
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Command templates for a secrets store driven by an external CLI such as
+/// `pass`, `op` or `vault`.
+///
+/// Each template is an argv (program first) run without a shell. The
+/// placeholders `{name}`, `{scope}` (`global` or the environment id) and
+/// `{key}` (`global/NAME` or `env/ID/NAME`) are substituted in every argument.
+///
+/// - `get` prints the value on stdout; empty output, or exiting with
+///   `missing_exit_code`, means the secret is unset.
+/// - `set` receives the value on stdin, never on the command line.
+/// - `list` prints one key per line in the `{key}` format.
+/// - `delete` removes the secret. It only runs after `get` finds a value, so
+///   deleting reports whether anything was removed; exiting with
+///   `missing_exit_code` also counts as nothing to delete.
+///
+/// A command that exits unsuccessfully or outlives `timeout` is an error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandSecretsBackendConfig {
+    pub get: Vec<String>,
+    pub set: Option<Vec<String>>,
+    pub list: Option<Vec<String>>,
+    pub delete: Option<Vec<String>>,
+    pub missing_exit_code: Option<i32>,
+    pub timeout: Duration,
+}
+
+#[derive(Debug, Clone)]
+pub struct CommandSecretsBackend {
+    config: CommandSecretsBackendConfig,
+}
+
+struct CommandOutput {
+    status: ExitStatus,
+    stdout: String,
+    stderr: String,
+}
+
+impl CommandSecretsBackend {
+    pub fn new(config: CommandSecretsBackendConfig) -> Self {
+        Self { config }
+    }
+
+    pub fn set(&self, scope: &SecretScope, name: &SecretName, value: &str) -> Result<()> {
+        anyhow::ensure!(!value.is_empty(), "secret value must not be empty");
+        let template = configured("set", self.config.set.as_deref())?;
+        self.run("set", template, Some((scope, name)), Some(value), None)?;
+        Ok(())
+    }
+
+    pub fn get(&self, scope: &SecretScope, name: &SecretName) -> Result<Option<String>> {
+        let output = self.run(
+            "get",
+            &self.config.get,
+            Some((scope, name)),
+            None,
+            self.config.missing_exit_code,
+        )?;
+        if !output.status.success() {
+            return Ok(None);
+        }
+        let value = output.stdout.trim_end_matches(['\r', '\n']);
+        Ok((!value.is_empty()).then(|| value.to_string()))
+    }
+
+    pub fn delete(&self, scope: &SecretScope, name: &SecretName) -> Result<bool> {
+        let template = configured("delete", self.config.delete.as_deref())?;
+        // Stores such as `pass rm --force` succeed whether or not the secret
+        // existed, so look it up first to report what was actually removed.
+        if self.get(scope, name)?.is_none() {
+            return Ok(false);
+        }
+        let output = self.run(
+            "delete",
+            template,
+            Some((scope, name)),
+            None,
+            self.config.missing_exit_code,
+        )?;
+        Ok(output.status.success())
+    }
+
+    pub fn list(&self, scope_filter: Option<&SecretScope>) -> Result<Vec<SecretListEntry>> {
+        let template = configured("list", self.config.list.as_deref())?;
+        let output = self.run("list", template, None, None, None)?;
+        let mut entries = Vec::new();
+        for line in output.stdout.lines().map(str::trim) {
+            if line.is_empty() {
+                continue;
+            }
+            let Some(entry) = parse_canonical_key(line) else {
+                warn!("skipping invalid secret key from list command: {line}");
+                continue;
+            };
+            if let Some(scope) = scope_filter
+                && entry.scope != *scope
+            {
+                continue;
+            }
+            entries.push(entry);
+        }
+        Ok(entries)
+    }
+
+    fn run(
+        &self,
+        operation: &str,
+        template: &[String],
+        target: Option<(&SecretScope, &SecretName)>,
+        stdin: Option<&str>,
+        accepted_exit_code: Option<i32>,
+    ) -> Result<CommandOutput> {
+        let argv: Vec<String> = template
+            .iter()
+            .map(|arg| match target {
+                Some((scope, name)) => expand_placeholders(arg, scope, name),
+                None => arg.clone(),
+            })
+            .collect();
+        let (program, args) = argv
+            .split_first()
+            .with_context(|| format!("the `{operation}` secrets command is empty"))?;
+
+        let mut child = Command::new(program)
+            .args(args)
+            .stdin(if stdin.is_some() {
+                Stdio::piped()
+            } else {
+                Stdio::null()
+            })
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .with_context(|| format!("failed to run `{operation}` secrets command `{program}`"))?;
+
+        let output = wait_with_timeout(child, stdin, self.config.timeout).with_context(|| {
+            format!("`{operation}` secrets command `{program}` did not complete")
+        })?;
+        if !output.status.success()
+            && (accepted_exit_code.is_none() || output.status.code() != accepted_exit_code)
+        {
+            let stderr = output.stderr.trim();
+            anyhow::bail!(
+                "`{operation}` secrets command `{program}` failed with {}{}",
+                output.status,
+                if stderr.is_empty() {
+                    String::new()
+                } else {
+                    format!(": {stderr}")
+                }
+            );
+        }
+        Ok(output)
+    }
+}
+
+impl SecretsBackend for CommandSecretsBackend {
+    fn set(&self, scope: &SecretScope, name: &SecretName, value: &str) -> Result<()> {
+        CommandSecretsBackend::set(self, scope, name, value)
+    }
+
+    fn get(&self, scope: &SecretScope, name: &SecretName) -> Result<Option<String>> {
+        CommandSecretsBackend::get(self, scope, name)
+    }
+
+    fn delete(&self, scope: &SecretScope, name: &SecretName) -> Result<bool> {
+        CommandSecretsBackend::delete(self, scope, name)
+    }
+
+    fn list(&self, scope_filter: Option<&SecretScope>) -> Result<Vec<SecretListEntry>> {
+        CommandSecretsBackend::list(self, scope_filter)
+    }
+}
+
+fn configured<'a>(operation: &str, template: Option<&'a [String]>) -> Result<&'a [String]> {
+    template.ok_or_else(|| {
+        anyhow::anyhow!("the command secrets backend has no `{operation}` command configured")
+    })
+}
+
+fn expand_placeholders(arg: &str, scope: &SecretScope, name: &SecretName) -> String {
+    let scope_str = match scope {
+        SecretScope::Global => "global",
+        SecretScope::Environment(environment_id) => environment_id.as_str(),
+    };
+    arg.replace("{key}", &scope.canonical_key(name))
+        .replace("{scope}", scope_str)
+        .replace("{name}", name.as_str())
+}
+
+fn wait_with_timeout(
+    mut child: Child,
+    stdin: Option<&str>,
+    timeout: Duration,
+) -> Result<CommandOutput> {
+    // Drain both pipes on their own threads so a chatty command cannot block
+    // on a full pipe while we wait for it to exit.
+    let stdout = child.stdout.take().map(spawn_reader);
+    let stderr = child.stderr.take().map(spawn_reader);
+    // Feed stdin from its own thread too, after the readers are running, so a
+    // command that echoes before reading all of its input cannot deadlock us
+    // and a command that never reads is still bound by the timeout. Dropping
+    // the pipe when the write finishes closes the command's stdin.
+    let writer = stdin
+        .zip(child.stdin.take())
+        .map(|(input, pipe)| spawn_writer(pipe, input.as_bytes().to_vec()));
+
+    let deadline = Instant::now() + timeout;
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        if Instant::now() >= deadline {
+            let _ = child.kill();
+            let _ = child.wait();
+            // The readers are left detached: a grandchild may still hold the
+            // pipes open.
+            anyhow::bail!("timed out after {}ms", timeout.as_millis());
+        }
+        std::thread::sleep(POLL_INTERVAL);
+    };
+
+    if let Some(writer) = writer {
+        match writer.join() {
+            Ok(result) => result.context("failed to write to the command's stdin")?,
+            Err(_) => anyhow::bail!("the stdin writer thread panicked"),
+        }
+    }
+
+    Ok(CommandOutput {
+        status,
+        stdout: join_reader(stdout),
+        stderr: join_reader(stderr),
+    })
+}
+
+fn spawn_writer<W: Write + Send + 'static>(
+    mut writer: W,
+    input: Vec<u8>,
+) -> JoinHandle<std::io::Result<()>> {
+    std::thread::spawn(move || {
+        writer.write_all(&input)?;
+        writer.flush()
+    })
+}
+
+fn spawn_reader<R: Read + Send + 'static>(mut reader: R) -> JoinHandle<Vec<u8>> {
+    std::thread::spawn(move || {
+        let mut buf = Vec::new();
+        let _ = reader.read_to_end(&mut buf);
+        buf
+    })
+}
+
+fn join_reader(handle: Option<JoinHandle<Vec<u8>>>) -> String {
+    handle
+        .and_then(|handle| handle.join().ok())
+        .map(|buf| String::from_utf8_lossy(&buf).into_owned())
+        .unwrap_or_default()
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::os::unix::fs::PermissionsExt;
+    use std::path::Path;
+
+    /// A stand-in for `pass`/`op`: stores each secret as a file named after
+    /// its key, with `/` mapped to `:`.
+    const STORE_SCRIPT: &str = r#"#!/bin/sh
+set -eu
+dir="$1"
+op="$2"
+file() { printf '%s/%s' "$dir" "$(printf '%s' "$1" | tr / :)"; }
+case "$op" in
+  get) if [ -f "$(file "$3")" ]; then cat "$(file "$3")"; else exit 4; fi ;;
+  set) cat > "$(file "$3")" ;;
+  delete) rm "$(file "$3")" ;;
+  list) ls "$dir" | tr : / ;;
+  echo) cat ;;
+  hang) sleep 5 ;;
+  fail) echo "vault is sealed" >&2; exit 3 ;;
+esac
+"#;
+
+    fn write_script(dir: &Path) -> String {
+        let path = dir.join("store.sh");
+        std::fs::write(&path, STORE_SCRIPT).expect("write script");
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755))
+            .expect("chmod script");
+        path.to_string_lossy().into_owned()
+    }
+
+    fn template(script: &str, store: &Path, args: &[&str]) -> Vec<String> {
+        let mut argv = vec![script.to_string(), store.to_string_lossy().into_owned()];
+        argv.extend(args.iter().map(ToString::to_string));
+        argv
+    }
+
+    fn backend(script_dir: &Path, store: &Path, timeout: Duration) -> CommandSecretsBackend {
+        let script = write_script(script_dir);
+        CommandSecretsBackend::new(CommandSecretsBackendConfig {
+            get: template(&script, store, &["get", "{key}"]),
+            set: Some(template(&script, store, &["set", "{key}"])),
+            list: Some(template(&script, store, &["list"])),
+            delete: Some(template(&script, store, &["delete", "{scope}/{name}"])),
+            missing_exit_code: Some(4),
+            timeout,
+        })
+    }
+
+    #[test]
+    fn command_backend_round_trips_through_script() -> Result<()> {
+        let script_dir = tempfile::tempdir().expect("tempdir");
+        let store = tempfile::tempdir().expect("tempdir");
+        let backend = backend(script_dir.path(), store.path(), DEFAULT_COMMAND_TIMEOUT);
+        let name = SecretName::new("NPM_TOKEN")?;
+        let repo = SecretScope::environment("codex")?;
+
+        assert_eq!(backend.get(&SecretScope::Global, &name)?, None);
+        backend.set(&SecretScope::Global, &name, "npm-global\nsecond line")?;
+        backend.set(&repo, &name, "npm-repo")?;
+        assert_eq!(
+            backend.get(&SecretScope::Global, &name)?,
+            Some("npm-global\nsecond line".to_string())
+        );
+        assert_eq!(backend.get(&repo, &name)?, Some("npm-repo".to_string()));
+
+        let mut listed = backend.list(None)?;
+        listed.sort_by_key(|entry| entry.scope != SecretScope::Global);
+        assert_eq!(
+            listed,
+            vec![
+                SecretListEntry {
+                    scope: SecretScope::Global,
+                    name: name.clone(),
+                },
+                SecretListEntry {
+                    scope: repo.clone(),
+                    name: name.clone(),
+                },
+            ]
+        );
+        assert_eq!(backend.list(Some(&repo))?.len(), 1);
+
+        assert!(backend.delete(&SecretScope::Global, &name)?);
+        assert_eq!(backend.get(&SecretScope::Global, &name)?, None);
+        assert!(!backend.delete(&SecretScope::Global, &name)?);
+        Ok(())
+    }
+
+    #[test]
+    fn command_backend_streams_stdin_larger_than_the_pipe_buffer() -> Result<()> {
+        let script_dir = tempfile::tempdir().expect("tempdir");
+        let store = tempfile::tempdir().expect("tempdir");
+        let script = write_script(script_dir.path());
+        // `echo` copies stdin to stdout as it reads, so writing the whole
+        // value before draining stdout would fill both pipes and hang.
+        let echoing = CommandSecretsBackend::new(CommandSecretsBackendConfig {
+            get: template(&script, store.path(), &["get", "{key}"]),
+            set: Some(template(&script, store.path(), &["echo"])),
+            list: None,
+            delete: None,
+            missing_exit_code: Some(4),
+            timeout: DEFAULT_COMMAND_TIMEOUT,
+        });
+        let value = "x".repeat(1024 * 1024);
+        echoing.set(&SecretScope::Global, &SecretName::new("BIG")?, &value)?;
+        Ok(())
+    }
+
+    #[test]
+    fn command_backend_reports_failures_and_timeouts() -> Result<()> {
+        let script_dir = tempfile::tempdir().expect("tempdir");
+        let store = tempfile::tempdir().expect("tempdir");
+        let script = write_script(script_dir.path());
+        let name = SecretName::new("NPM_TOKEN")?;
+
+        let failing = CommandSecretsBackend::new(CommandSecretsBackendConfig {
+            get: template(&script, store.path(), &["fail"]),
+            set: None,
+            list: None,
+            delete: None,
+            missing_exit_code: Some(4),
+            timeout: DEFAULT_COMMAND_TIMEOUT,
+        });
+        let err = failing
+            .get(&SecretScope::Global, &name)
+            .expect_err("failing command is an error");
+        assert!(
+            format!("{err:#}").contains("vault is sealed"),
+            "unexpected error: {err:#}"
+        );
+        let err = failing
+            .set(&SecretScope::Global, &name, "value")
+            .expect_err("set is not configured");
+        assert!(
+            err.to_string().contains("no `set` command"),
+            "unexpected error: {err:#}"
+        );
+
+        let hanging = CommandSecretsBackend::new(CommandSecretsBackendConfig {
+            get: template(&script, store.path(), &["hang"]),
+            set: None,
+            list: None,
+            delete: None,
+            missing_exit_code: None,
+            timeout: Duration::from_millis(200),
+        });
+        let started = Instant::now();
+        let err = hanging
+            .get(&SecretScope::Global, &name)
+            .expect_err("hanging command times out");
+        assert!(
+            format!("{err:#}").contains("timed out"),
+            "unexpected error: {err:#}"
+        );
+        assert!(started.elapsed() < Duration::from_secs(4));
+        Ok(())
+    }
+}
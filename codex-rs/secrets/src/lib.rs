@@ -12,8 +12,12 @@ use serde::Serialize;
 use sha2::Digest;
 use sha2::Sha256;
 
+mod command;
 mod local;
 
+pub use command::CommandSecretsBackend;
+pub use command::CommandSecretsBackendConfig;
+pub use command::DEFAULT_COMMAND_TIMEOUT;
 pub use local::LocalSecretsBackend;
 
 const KEYRING_SERVICE: &str = "codex";
@@ -81,6 +85,25 @@ pub struct SecretListEntry {
 pub enum SecretsBackendKind {
     #[default]
     Local,
+    /// External commands configured under `[secrets.command]`.
+    Command,
+}
+
+/// A resolved backend selection, including the settings the backend needs.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum SecretsBackendConfig {
+    #[default]
+    Local,
+    Command(CommandSecretsBackendConfig),
+}
+
+impl SecretsBackendConfig {
+    pub fn kind(&self) -> SecretsBackendKind {
+        match self {
+            Self::Local => SecretsBackendKind::Local,
+            Self::Command(_) => SecretsBackendKind::Command,
+        }
+    }
 }
 
 pub trait SecretsBackend: Send + Sync {
@@ -96,25 +119,20 @@ pub struct SecretsManager {
 }
 
 impl SecretsManager {
-    pub fn new(codex_home: PathBuf, backend_kind: SecretsBackendKind) -> Self {
-        let backend: Arc<dyn SecretsBackend> = match backend_kind {
-            SecretsBackendKind::Local => {
-                let keyring_store: Arc<dyn KeyringStore> = Arc::new(DefaultKeyringStore);
-                Arc::new(LocalSecretsBackend::new(codex_home, keyring_store))
-            }
-        };
-        Self { backend }
+    pub fn new(codex_home: PathBuf, backend_config: SecretsBackendConfig) -> Self {
+        Self::new_with_keyring_store(codex_home, backend_config, Arc::new(DefaultKeyringStore))
     }
 
     pub fn new_with_keyring_store(
         codex_home: PathBuf,
-        backend_kind: SecretsBackendKind,
+        backend_config: SecretsBackendConfig,
         keyring_store: Arc<dyn KeyringStore>,
     ) -> Self {
-        let backend: Arc<dyn SecretsBackend> = match backend_kind {
-            SecretsBackendKind::Local => {
+        let backend: Arc<dyn SecretsBackend> = match backend_config {
+            SecretsBackendConfig::Local => {
                 Arc::new(LocalSecretsBackend::new(codex_home, keyring_store))
             }
+            SecretsBackendConfig::Command(config) => Arc::new(CommandSecretsBackend::new(config)),
         };
         Self { backend }
     }
@@ -223,7 +241,7 @@ mod tests {
         let keyring = Arc::new(MockKeyringStore::default());
         let manager = SecretsManager::new_with_keyring_store(
             codex_home.path().to_path_buf(),
-            SecretsBackendKind::Local,
+            SecretsBackendConfig::Local,
             keyring,
         );
         let scope = SecretScope::Global;
@@ -300,7 +300,7 @@ fn decrypt_with_passphrase(ciphertext: &[u8], passphrase: &SecretString) -> Resu
     decrypt(&identity, ciphertext).context("failed to decrypt secrets file")
 }
 
-fn parse_canonical_key(canonical_key: &str) -> Option<SecretListEntry> {
+pub(crate) fn parse_canonical_key(canonical_key: &str) -> Option<SecretListEntry> {
     let mut parts = canonical_key.split('/');
     let scope_kind = parts.next()?;
     match scope_kind {
@@ -22,6 +22,7 @@ use crate::history_cell;
 use crate::history_cell::HistoryCell;
 #[cfg(not(debug_assertions))]
 use crate::history_cell::UpdateAvailableHistoryCell;
+use crate::keymap::Keymap;
 use crate::model_migration::ModelMigrationOutcome;
 use crate::model_migration::migration_copy_for_models;
 use crate::model_migration::run_model_migration_prompt;
@@ -34,6 +35,7 @@ use crate::tui::TuiEvent;
 use crate::update_action::UpdateAction;
 use codex_ansi_escape::ansi_escape_line;
 use codex_app_server_protocol::ConfigLayerSource;
+use codex_common::oss::OssModelMetadataFetcher;
 use codex_core::AuthManager;
 use codex_core::CodexAuth;
 use codex_core::ThreadManager;
@@ -44,6 +46,7 @@ use codex_core::config::edit::ConfigEdit;
 use codex_core::config::edit::ConfigEditsBuilder;
 use codex_core::config_loader::ConfigLayerStackOrdering;
 use codex_core::features::Feature;
+use codex_core::models_manager::manager::ModelsManager;
 use codex_core::models_manager::manager::RefreshStrategy;
 use codex_core::models_manager::model_presets::HIDE_GPT_5_1_CODEX_MAX_MIGRATION_PROMPT_CONFIG;
 use codex_core::models_manager::model_presets::HIDE_GPT5_1_MIGRATION_PROMPT_CONFIG;
@@ -533,6 +536,8 @@ pub(crate) struct App {
     has_emitted_history_lines: bool,
 
     pub(crate) enhanced_keys_supported: bool,
+    /// Bindings for the app-level shortcuts (transcript, editor, backtrack).
+    pub(crate) keymap: Keymap,
 
     /// Controls the animation thread that sends CommitTick events.
     pub(crate) commit_anim_running: Arc<AtomicBool>,
@@ -940,6 +945,20 @@ impl App {
             auth_manager.clone(),
             SessionSource::Cli,
         ));
+        thread_manager
+            .get_models_manager()
+            .set_local_model_metadata_fetcher(Arc::new(OssModelMetadataFetcher));
+        if ModelsManager::fetches_provider_models(&config) {
+            // Fetch the custom provider's model list in the background so the
+            // `/model` picker can offer it without delaying startup.
+            let models_manager = thread_manager.get_models_manager();
+            let config = config.clone();
+            tokio::spawn(async move {
+                models_manager
+                    .list_models(&config, RefreshStrategy::OnlineIfUncached)
+                    .await;
+            });
+        }
         let mut model = thread_manager
             .get_models_manager()
             .get_default_model(&config.model, &config, RefreshStrategy::Offline)
@@ -1100,6 +1119,7 @@ impl App {
             app_event_tx,
             chat_widget,
             auth_manager: auth_manager.clone(),
+            keymap: Keymap::from_config_or_default(&config.tui_keymap),
             config,
             active_profile,
             cli_kv_overrides,
@@ -2189,9 +2209,18 @@ impl App {
             AppEvent::OpenReviewCommitPicker(cwd) => {
                 self.chat_widget.show_review_commit_picker(&cwd).await;
             }
+            AppEvent::OpenReviewCommitRangePicker(cwd) => {
+                self.chat_widget.show_review_commit_range_picker(&cwd).await;
+            }
+            AppEvent::OpenReviewPatchFilePrompt => {
+                self.chat_widget.show_review_patch_file_prompt();
+            }
             AppEvent::OpenReviewCustomPrompt => {
                 self.chat_widget.show_review_custom_prompt();
             }
+            AppEvent::OpenUndoSnapshot(snapshot) => {
+                self.chat_widget.open_undo_snapshot_popup(snapshot);
+            }
             AppEvent::SubmitUserMessageWithMode {
                 text,
                 collaboration_mode,
@@ -2473,22 +2502,21 @@ impl App {
     async fn handle_key_event(&mut self, tui: &mut tui::Tui, key_event: KeyEvent) {
         match key_event {
             KeyEvent {
-                code: KeyCode::Char('t'),
-                modifiers: crossterm::event::KeyModifiers::CONTROL,
                 kind: KeyEventKind::Press,
                 ..
-            } => {
+            } if self.keymap.toggle_transcript.is_press(key_event) => {
                 // Enter alternate screen and set viewport to full size.
                 let _ = tui.enter_alt_screen();
-                self.overlay = Some(Overlay::new_transcript(self.transcript_cells.clone()));
+                self.overlay = Some(Overlay::new_transcript(
+                    self.transcript_cells.clone(),
+                    &self.keymap,
+                ));
                 tui.frame_requester().schedule_frame();
             }
             KeyEvent {
-                code: KeyCode::Char('g'),
-                modifiers: crossterm::event::KeyModifiers::CONTROL,
                 kind: KeyEventKind::Press,
                 ..
-            } => {
+            } if self.keymap.open_editor.is_press(key_event) => {
                 // Only launch the external editor if there is no overlay and the bottom pane is not in use.
                 // Note that it can be launched while a task is running to enable editing while the previous turn is ongoing.
                 if self.overlay.is_none()
@@ -2502,13 +2530,10 @@ impl App {
             // with the composer focused and empty. In any other state, forward
             // Esc so the active UI (e.g. status indicator, modals, popups)
             // handles it.
-            KeyEvent {
-                code: KeyCode::Esc,
-                kind: KeyEventKind::Press | KeyEventKind::Repeat,
-                ..
-            } => {
+            key_event if self.keymap.backtrack.is_press(key_event) => {
                 if self.chat_widget.is_normal_backtrack_mode()
                     && self.chat_widget.composer_is_empty()
+                    && !self.chat_widget.composer_captures_key(key_event)
                 {
                     self.handle_backtrack_esc_key(tui);
                 } else {
@@ -2532,10 +2557,10 @@ impl App {
                 kind: KeyEventKind::Press | KeyEventKind::Repeat,
                 ..
             } => {
-                // Any non-Esc key press should cancel a primed backtrack.
+                // Any other key press should cancel a primed backtrack.
                 // This avoids stale "Esc-primed" state after the user starts typing
                 // (even if they later backspace to empty).
-                if key_event.code != KeyCode::Esc && self.backtrack.primed {
+                if self.backtrack.primed {
                     self.reset_backtrack_state();
                 }
                 self.chat_widget.handle_key_event(key_event);
@@ -2705,6 +2730,7 @@ mod tests {
             deferred_history_lines: Vec::new(),
             has_emitted_history_lines: false,
             enhanced_keys_supported: false,
+            keymap: Keymap::default(),
             commit_anim_running: Arc::new(AtomicBool::new(false)),
             status_line_invalid_items_warned: Arc::new(AtomicBool::new(false)),
             backtrack: BacktrackState::default(),
@@ -2759,6 +2785,7 @@ mod tests {
                 deferred_history_lines: Vec::new(),
                 has_emitted_history_lines: false,
                 enhanced_keys_supported: false,
+                keymap: Keymap::default(),
                 commit_anim_running: Arc::new(AtomicBool::new(false)),
                 status_line_invalid_items_warned: Arc::new(AtomicBool::new(false)),
                 backtrack: BacktrackState::default(),
@@ -99,21 +99,23 @@ pub(crate) struct PendingBacktrackRollback {
 impl App {
     /// Route overlay events while the transcript overlay is active.
     ///
-    /// If backtrack preview is active, Esc / Left steps selection, Right steps forward, Enter
-    /// confirms. Otherwise, Esc begins preview mode and all other events are forwarded to the
-    /// overlay.
+    /// If backtrack preview is active, the `backtrack` binding (Esc by default) / Left steps
+    /// selection, Right steps forward, Enter confirms. Otherwise, the `backtrack` binding begins
+    /// preview mode and all other events are forwarded to the overlay. While a transcript search
+    /// query is being typed, every event goes to the overlay.
     pub(crate) async fn handle_backtrack_overlay_event(
         &mut self,
         tui: &mut tui::Tui,
         event: TuiEvent,
     ) -> Result<bool> {
-        if self.backtrack.overlay_preview_active {
+        if let Some(Overlay::Transcript(t)) = &self.overlay
+            && t.is_search_input_active()
+        {
+            self.overlay_forward_event(tui, event)?;
+            Ok(true)
+        } else if self.backtrack.overlay_preview_active {
             match event {
-                TuiEvent::Key(KeyEvent {
-                    code: KeyCode::Esc,
-                    kind: KeyEventKind::Press | KeyEventKind::Repeat,
-                    ..
-                }) => {
+                TuiEvent::Key(key_event) if self.keymap.backtrack.is_press(key_event) => {
                     self.overlay_step_backtrack(tui, event)?;
                     Ok(true)
                 }
@@ -147,11 +149,8 @@ impl App {
                     Ok(true)
                 }
             }
-        } else if let TuiEvent::Key(KeyEvent {
-            code: KeyCode::Esc,
-            kind: KeyEventKind::Press | KeyEventKind::Repeat,
-            ..
-        }) = event
+        } else if let TuiEvent::Key(key_event) = event
+            && self.keymap.backtrack.is_press(key_event)
         {
             // First Esc in transcript overlay: begin backtrack preview at latest user message.
             self.begin_overlay_backtrack_preview(tui);
@@ -220,7 +219,10 @@ impl App {
     /// Open transcript overlay (enters alternate screen and shows full transcript).
     pub(crate) fn open_transcript_overlay(&mut self, tui: &mut tui::Tui) {
         let _ = tui.enter_alt_screen();
-        self.overlay = Some(Overlay::new_transcript(self.transcript_cells.clone()));
+        self.overlay = Some(Overlay::new_transcript(
+            self.transcript_cells.clone(),
+            &self.keymap,
+        ));
         tui.frame_requester().schedule_frame();
     }
 
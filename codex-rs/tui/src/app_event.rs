@@ -14,6 +14,7 @@ use codex_chatgpt::connectors::AppInfo;
 use codex_common::approval_presets::ApprovalPreset;
 use codex_core::protocol::Event;
 use codex_core::protocol::RateLimitSnapshot;
+use codex_core::protocol::UndoSnapshot;
 use codex_file_search::FileMatch;
 use codex_protocol::ThreadId;
 use codex_protocol::openai_models::ModelPreset;
@@ -268,9 +269,18 @@ pub(crate) enum AppEvent {
     /// Open the commit picker option from the review popup.
     OpenReviewCommitPicker(PathBuf),
 
+    /// Open the commit range picker option from the review popup.
+    OpenReviewCommitRangePicker(PathBuf),
+
+    /// Open the patch file prompt option from the review popup.
+    OpenReviewPatchFilePrompt,
+
     /// Open the custom prompt option from the review popup.
     OpenReviewCustomPrompt,
 
+    /// Open the restore confirmation for a snapshot picked in the undo popup.
+    OpenUndoSnapshot(UndoSnapshot),
+
     /// Submit a user message with an explicit collaboration mask.
     SubmitUserMessageWithMode {
         text: String,
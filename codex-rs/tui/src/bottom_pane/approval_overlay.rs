@@ -1,10 +1,13 @@
 use std::collections::HashMap;
 use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::Mutex;
 
 use crate::app_event::AppEvent;
 use crate::app_event_sender::AppEventSender;
 use crate::bottom_pane::BottomPaneView;
 use crate::bottom_pane::CancellationEvent;
+use crate::bottom_pane::custom_prompt_view::CustomPromptView;
 use crate::bottom_pane::list_selection_view::ListSelectionView;
 use crate::bottom_pane::list_selection_view::SelectionItem;
 use crate::bottom_pane::list_selection_view::SelectionViewParams;
@@ -13,15 +16,18 @@ use crate::exec_command::strip_bash_lc_and_escape;
 use crate::history_cell;
 use crate::key_hint;
 use crate::key_hint::KeyBinding;
+use crate::keymap::Keymap;
 use crate::render::highlight::highlight_bash_to_lines;
 use crate::render::renderable::ColumnRenderable;
 use crate::render::renderable::Renderable;
 use codex_core::features::Feature;
 use codex_core::features::Features;
+use codex_core::protocol::DeclinedHunk;
 use codex_core::protocol::ElicitationAction;
 use codex_core::protocol::ExecPolicyAmendment;
 use codex_core::protocol::FileChange;
 use codex_core::protocol::Op;
+use codex_core::protocol::PatchHunk;
 use codex_core::protocol::ReviewDecision;
 use codex_protocol::mcp::RequestId;
 use crossterm::event::KeyCode;
@@ -50,6 +56,9 @@ pub(crate) enum ApprovalRequest {
         reason: Option<String>,
         cwd: PathBuf,
         changes: HashMap<PathBuf, FileChange>,
+        /// The patch split into hunks that can be accepted or rejected one by
+        /// one. Empty when the patch can only be approved as a whole.
+        hunks: Vec<PatchHunk>,
     },
     McpElicitation {
         server_name: String,
@@ -69,10 +78,18 @@ pub(crate) struct ApprovalOverlay {
     current_complete: bool,
     done: bool,
     features: Features,
+    keymap: Keymap,
+    /// Set while the user steps through the current patch hunk by hunk.
+    hunk_review: Option<HunkReview>,
 }
 
 impl ApprovalOverlay {
-    pub fn new(request: ApprovalRequest, app_event_tx: AppEventSender, features: Features) -> Self {
+    pub fn new(
+        request: ApprovalRequest,
+        app_event_tx: AppEventSender,
+        features: Features,
+        keymap: Keymap,
+    ) -> Self {
         let mut view = Self {
             current_request: None,
             current_variant: None,
@@ -83,6 +100,8 @@ impl ApprovalOverlay {
             current_complete: false,
             done: false,
             features,
+            keymap,
+            hunk_review: None,
         };
         view.set_current(request);
         view
@@ -97,7 +116,8 @@ impl ApprovalOverlay {
         let ApprovalRequestState { variant, header } = ApprovalRequestState::from(request);
         self.current_variant = Some(variant.clone());
         self.current_complete = false;
-        let (options, params) = Self::build_options(variant, header, &self.features);
+        self.hunk_review = None;
+        let (options, params) = Self::build_options(variant, header, &self.features, &self.keymap);
         self.options = options;
         self.list = ListSelectionView::new(params, self.app_event_tx.clone());
     }
@@ -106,57 +126,109 @@ impl ApprovalOverlay {
         variant: ApprovalVariant,
         header: Box<dyn Renderable>,
         features: &Features,
+        keymap: &Keymap,
     ) -> (Vec<ApprovalOption>, SelectionViewParams) {
         let (options, title) = match &variant {
             ApprovalVariant::Exec {
                 proposed_execpolicy_amendment,
                 ..
             } => (
-                exec_options(proposed_execpolicy_amendment.clone(), features),
+                exec_options(proposed_execpolicy_amendment.clone(), features, keymap),
                 "Would you like to run the following command?".to_string(),
             ),
-            ApprovalVariant::ApplyPatch { .. } => (
-                patch_options(),
+            ApprovalVariant::ApplyPatch { hunks, .. } => (
+                patch_options(hunks.len(), keymap),
                 "Would you like to make the following edits?".to_string(),
             ),
             ApprovalVariant::McpElicitation { server_name, .. } => (
-                elicitation_options(),
+                elicitation_options(keymap),
                 format!("{server_name} needs your approval."),
             ),
         };
 
-        let header = Box::new(ColumnRenderable::with([
-            Line::from(title.bold()).into(),
-            Line::from("").into(),
-            header,
-        ]));
+        let params = selection_params(&options, title, header);
+        (options, params)
+    }
 
-        let items = options
-            .iter()
-            .map(|opt| SelectionItem {
-                name: opt.label.clone(),
-                display_shortcut: opt
-                    .display_shortcut
-                    .or_else(|| opt.additional_shortcuts.first().copied()),
-                dismiss_on_select: false,
-                ..Default::default()
-            })
-            .collect();
+    /// Show the hunk the review is currently positioned on.
+    fn show_current_hunk(&mut self) {
+        let Some(review) = self.hunk_review.as_ref() else {
+            return;
+        };
+        let options = hunk_options(&self.keymap);
+        let title = format!(
+            "Apply hunk {} of {}?",
+            review.position + 1,
+            review.hunks.len()
+        );
+        let params = selection_params(&options, title, review.current_header());
+        self.options = options;
+        self.list = ListSelectionView::new(params, self.app_event_tx.clone());
+    }
 
-        let params = SelectionViewParams {
-            footer_hint: Some(Line::from(vec![
-                "Press ".into(),
-                key_hint::plain(KeyCode::Enter).into(),
-                " to confirm or ".into(),
-                key_hint::plain(KeyCode::Esc).into(),
-                " to cancel".into(),
-            ])),
-            items,
-            header,
-            ..Default::default()
+    fn handle_hunk_decision(&mut self, decision: HunkDecision) {
+        let Some(review) = self.hunk_review.as_mut() else {
+            return;
         };
+        match decision {
+            HunkDecision::Accept => {}
+            HunkDecision::Reject => review.decline(None),
+            HunkDecision::RejectWithNote => {
+                review.start_note();
+                return;
+            }
+        }
+        self.advance_hunk();
+    }
 
-        (options, params)
+    /// Move to the next hunk, or send the decision once every hunk has been
+    /// reviewed.
+    fn advance_hunk(&mut self) {
+        let Some(review) = self.hunk_review.as_mut() else {
+            return;
+        };
+        review.position += 1;
+        if review.position < review.hunks.len() {
+            self.show_current_hunk();
+            return;
+        }
+        let Some(review) = self.hunk_review.take() else {
+            return;
+        };
+        let decision = if review.declined.is_empty() {
+            ReviewDecision::Approved
+        } else {
+            ReviewDecision::ApprovedHunks {
+                declined: review.declined,
+            }
+        };
+        self.handle_patch_decision(&review.id, decision);
+        self.current_complete = true;
+        self.advance_queue();
+    }
+
+    /// Forward a key to the note prompt, if one is open. Returns whether the
+    /// key was consumed.
+    fn handle_note_key_event(&mut self, key_event: KeyEvent) -> bool {
+        let Some(review) = self.hunk_review.as_mut() else {
+            return false;
+        };
+        let Some(prompt) = review.note_prompt.as_mut() else {
+            return false;
+        };
+        prompt.handle_key_event(key_event);
+        if !prompt.is_complete() {
+            return true;
+        }
+        review.note_prompt = None;
+        #[expect(clippy::unwrap_used)]
+        let note = review.note.lock().unwrap().take();
+        // Cancelling the prompt returns to the current hunk undecided.
+        if let Some(note) = note {
+            review.decline(Some(note));
+            self.advance_hunk();
+        }
+        true
     }
 
     fn apply_selection(&mut self, actual_idx: usize) {
@@ -166,6 +238,22 @@ impl ApprovalOverlay {
         let Some(option) = self.options.get(actual_idx) else {
             return;
         };
+        match option.decision {
+            ApprovalDecision::ReviewHunks => {
+                if let Some(ApprovalVariant::ApplyPatch { id, cwd, hunks }) = &self.current_variant
+                {
+                    self.hunk_review =
+                        Some(HunkReview::new(id.clone(), cwd.clone(), hunks.clone()));
+                }
+                self.show_current_hunk();
+                return;
+            }
+            ApprovalDecision::Hunk(decision) => {
+                self.handle_hunk_decision(decision);
+                return;
+            }
+            ApprovalDecision::Review(_) | ApprovalDecision::McpElicitation(_) => {}
+        }
         if let Some(variant) = self.current_variant.as_ref() {
             match (variant, &option.decision) {
                 (ApprovalVariant::Exec { id, command, .. }, ApprovalDecision::Review(decision)) => {
@@ -259,11 +347,60 @@ impl ApprovalOverlay {
             }
         }
     }
+
+    /// The note prompt while one is open, otherwise the option list.
+    fn active_view(&self) -> &dyn Renderable {
+        match self
+            .hunk_review
+            .as_ref()
+            .and_then(|review| review.note_prompt.as_ref())
+        {
+            Some(prompt) => prompt,
+            None => &self.list,
+        }
+    }
+}
+
+fn selection_params(
+    options: &[ApprovalOption],
+    title: String,
+    header: Box<dyn Renderable>,
+) -> SelectionViewParams {
+    let header = Box::new(ColumnRenderable::with([
+        Line::from(title.bold()).into(),
+        Line::from("").into(),
+        header,
+    ]));
+
+    let items = options
+        .iter()
+        .map(|opt| SelectionItem {
+            name: opt.label.clone(),
+            display_shortcut: opt
+                .display_shortcut
+                .or_else(|| opt.additional_shortcuts.first().copied()),
+            dismiss_on_select: false,
+            ..Default::default()
+        })
+        .collect();
+
+    SelectionViewParams {
+        footer_hint: Some(Line::from(vec![
+            "Press ".into(),
+            key_hint::plain(KeyCode::Enter).into(),
+            " to confirm or ".into(),
+            key_hint::plain(KeyCode::Esc).into(),
+            " to cancel".into(),
+        ])),
+        items,
+        header,
+        ..Default::default()
+    }
 }
 
 impl BottomPaneView for ApprovalOverlay {
     fn handle_key_event(&mut self, key_event: KeyEvent) {
-        if self.try_handle_shortcut(&key_event) {
+        if self.handle_note_key_event(key_event) || self.try_handle_shortcut(&key_event) {
             return;
         }
         self.list.handle_key_event(key_event);
@@ -307,6 +444,17 @@ impl BottomPaneView for ApprovalOverlay {
         self.done
     }
 
+    fn handle_paste(&mut self, pasted: String) -> bool {
+        match self
+            .hunk_review
+            .as_mut()
+            .and_then(|review| review.note_prompt.as_mut())
+        {
+            Some(prompt) => prompt.handle_paste(pasted),
+            None => false,
+        }
+    }
+
     fn try_consume_approval_request(
         &mut self,
         request: ApprovalRequest,
@@ -318,15 +466,15 @@ impl BottomPaneView for ApprovalOverlay {
 
 impl Renderable for ApprovalOverlay {
     fn desired_height(&self, width: u16) -> u16 {
-        self.list.desired_height(width)
+        self.active_view().desired_height(width)
     }
 
     fn render(&self, area: Rect, buf: &mut Buffer) {
-        self.list.render(area, buf);
+        self.active_view().render(area, buf);
     }
 
     fn cursor_pos(&self, area: Rect) -> Option<(u16, u16)> {
-        self.list.cursor_pos(area)
+        self.active_view().cursor_pos(area)
     }
 }
 
@@ -369,6 +517,7 @@ impl From<ApprovalRequest> for ApprovalRequestState {
                 reason,
                 cwd,
                 changes,
+                hunks,
             } => {
                 let mut header: Vec<Box<dyn Renderable>> = Vec::new();
                 if let Some(reason) = reason
@@ -380,9 +529,9 @@ impl From<ApprovalRequest> for ApprovalRequestState {
                     ));
                     header.push(Box::new(Line::from("")));
                 }
-                header.push(DiffSummary::new(changes, cwd).into());
+                header.push(DiffSummary::new(changes, cwd.clone()).into());
                 Self {
-                    variant: ApprovalVariant::ApplyPatch { id },
+                    variant: ApprovalVariant::ApplyPatch { id, cwd, hunks },
                     header: Box::new(ColumnRenderable::with(header)),
                 }
             }
@@ -418,6 +567,8 @@ enum ApprovalVariant {
     },
     ApplyPatch {
         id: String,
+        cwd: PathBuf,
+        hunks: Vec<PatchHunk>,
     },
     McpElicitation {
         server_name: String,
@@ -429,6 +580,72 @@ enum ApprovalVariant {
 enum ApprovalDecision {
     Review(ReviewDecision),
     McpElicitation(ElicitationAction),
+    /// Step through the patch hunk by hunk instead of deciding on all of it.
+    ReviewHunks,
+    Hunk(HunkDecision),
+}
+
+#[derive(Clone, Copy)]
+enum HunkDecision {
+    Accept,
+    Reject,
+    RejectWithNote,
+}
+
+/// Progress through a patch being reviewed hunk by hunk.
+struct HunkReview {
+    id: String,
+    cwd: PathBuf,
+    hunks: Vec<PatchHunk>,
+    position: usize,
+    declined: Vec<DeclinedHunk>,
+    /// Prompt collecting a rejection note for the current hunk, when open.
+    note_prompt: Option<CustomPromptView>,
+    /// Written by `note_prompt` when the user submits a note.
+    note: Arc<Mutex<Option<String>>>,
+}
+
+impl HunkReview {
+    fn new(id: String, cwd: PathBuf, hunks: Vec<PatchHunk>) -> Self {
+        Self {
+            id,
+            cwd,
+            hunks,
+            position: 0,
+            declined: Vec::new(),
+            note_prompt: None,
+            note: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    fn current_header(&self) -> Box<dyn Renderable> {
+        let hunk = &self.hunks[self.position];
+        let changes = HashMap::from([(hunk.path.clone(), hunk.change.clone())]);
+        DiffSummary::new(changes, self.cwd.clone()).into()
+    }
+
+    fn decline(&mut self, note: Option<String>) {
+        let hunk = &self.hunks[self.position];
+        self.declined.push(DeclinedHunk {
+            path: hunk.path.clone(),
+            index: hunk.index,
+            note,
+        });
+    }
+
+    fn start_note(&mut self) {
+        let note = Arc::clone(&self.note);
+        self.note_prompt = Some(CustomPromptView::new(
+            "Why reject this hunk?".to_string(),
+            "Type a note for Codex and press Enter".to_string(),
+            None,
+            Box::new(move |text: String| {
+                #[expect(clippy::unwrap_used)]
+                let mut note = note.lock().unwrap();
+                *note = Some(text);
+            }),
+        ));
+    }
 }
 
 #[derive(Clone)]
@@ -450,12 +667,13 @@ impl ApprovalOption {
 fn exec_options(
     proposed_execpolicy_amendment: Option<ExecPolicyAmendment>,
     features: &Features,
+    keymap: &Keymap,
 ) -> Vec<ApprovalOption> {
     vec![ApprovalOption {
         label: "Yes, proceed".to_string(),
         decision: ApprovalDecision::Review(ReviewDecision::Approved),
         display_shortcut: None,
-        additional_shortcuts: vec![key_hint::plain(KeyCode::Char('y'))],
+        additional_shortcuts: keymap.approve.iter().collect(),
     }]
     .into_iter()
     .chain(
@@ -485,47 +703,84 @@ fn exec_options(
         label: "No, and tell Codex what to do differently".to_string(),
         decision: ApprovalDecision::Review(ReviewDecision::Abort),
         display_shortcut: Some(key_hint::plain(KeyCode::Esc)),
-        additional_shortcuts: vec![key_hint::plain(KeyCode::Char('n'))],
+        additional_shortcuts: keymap.deny.iter().collect(),
     }])
     .collect()
 }
 
-fn patch_options() -> Vec<ApprovalOption> {
-    vec![
+fn patch_options(hunk_count: usize, keymap: &Keymap) -> Vec<ApprovalOption> {
+    let review_hunks = (hunk_count > 1).then(|| ApprovalOption {
+        label: format!("Review each of the {hunk_count} hunks"),
+        decision: ApprovalDecision::ReviewHunks,
+        display_shortcut: None,
+        additional_shortcuts: keymap.review_hunks.iter().collect(),
+    });
+    let mut options = vec![
         ApprovalOption {
             label: "Yes, proceed".to_string(),
             decision: ApprovalDecision::Review(ReviewDecision::Approved),
             display_shortcut: None,
-            additional_shortcuts: vec![key_hint::plain(KeyCode::Char('y'))],
+            additional_shortcuts: keymap.approve.iter().collect(),
         },
         ApprovalOption {
             label: "Yes, and don't ask again for these files".to_string(),
             decision: ApprovalDecision::Review(ReviewDecision::ApprovedForSession),
             display_shortcut: None,
-            additional_shortcuts: vec![key_hint::plain(KeyCode::Char('a'))],
+            additional_shortcuts: keymap.approve_for_session.iter().collect(),
+        },
+    ];
+    options.extend(review_hunks);
+    options.push(ApprovalOption {
+        label: "No, and tell Codex what to do differently".to_string(),
+        decision: ApprovalDecision::Review(ReviewDecision::Abort),
+        display_shortcut: Some(key_hint::plain(KeyCode::Esc)),
+        additional_shortcuts: keymap.deny.iter().collect(),
+    });
+    options
+}
+
+fn hunk_options(keymap: &Keymap) -> Vec<ApprovalOption> {
+    vec![
+        ApprovalOption {
+            label: "Yes, apply this hunk".to_string(),
+            decision: ApprovalDecision::Hunk(HunkDecision::Accept),
+            display_shortcut: None,
+            additional_shortcuts: keymap.approve.iter().collect(),
         },
         ApprovalOption {
-            label: "No, and tell Codex what to do differently".to_string(),
+            label: "No, skip this hunk".to_string(),
+            decision: ApprovalDecision::Hunk(HunkDecision::Reject),
+            display_shortcut: None,
+            additional_shortcuts: keymap.deny.iter().collect(),
+        },
+        ApprovalOption {
+            label: "No, and add a note for Codex".to_string(),
+            decision: ApprovalDecision::Hunk(HunkDecision::RejectWithNote),
+            display_shortcut: None,
+            additional_shortcuts: vec![key_hint::plain(KeyCode::Char('r'))],
+        },
+        ApprovalOption {
+            label: "Cancel the whole patch".to_string(),
             decision: ApprovalDecision::Review(ReviewDecision::Abort),
             display_shortcut: Some(key_hint::plain(KeyCode::Esc)),
-            additional_shortcuts: vec![key_hint::plain(KeyCode::Char('n'))],
+            additional_shortcuts: Vec::new(),
         },
     ]
 }
 
-fn elicitation_options() -> Vec<ApprovalOption> {
+fn elicitation_options(keymap: &Keymap) -> Vec<ApprovalOption> {
     vec![
         ApprovalOption {
             label: "Yes, provide the requested info".to_string(),
             decision: ApprovalDecision::McpElicitation(ElicitationAction::Accept),
             display_shortcut: None,
-            additional_shortcuts: vec![key_hint::plain(KeyCode::Char('y'))],
+            additional_shortcuts: keymap.approve.iter().collect(),
         },
         ApprovalOption {
             label: "No, but continue without it".to_string(),
             decision: ApprovalDecision::McpElicitation(ElicitationAction::Decline),
             display_shortcut: None,
-            additional_shortcuts: vec![key_hint::plain(KeyCode::Char('n'))],
+            additional_shortcuts: keymap.deny.iter().collect(),
         },
         ApprovalOption {
             label: "Cancel this request".to_string(),
@@ -540,6 +795,8 @@ fn elicitation_options() -> Vec<ApprovalOption> {
 mod tests {
     use super::*;
     use crate::app_event::AppEvent;
+    use codex_core::config::types::KeyChords;
+    use codex_core::config::types::TuiKeymap;
     use pretty_assertions::assert_eq;
     use tokio::sync::mpsc::unbounded_channel;
 
@@ -552,11 +809,109 @@ mod tests {
         }
     }
 
+    fn make_two_hunk_patch_request() -> ApprovalRequest {
+        let path = PathBuf::from("/tmp/a.txt");
+        let hunk = |index: usize, diff: &str| PatchHunk {
+            path: path.clone(),
+            index,
+            change: FileChange::Update {
+                unified_diff: diff.to_string(),
+                move_path: None,
+            },
+        };
+        ApprovalRequest::ApplyPatch {
+            id: "patch".to_string(),
+            reason: None,
+            cwd: PathBuf::from("/tmp"),
+            changes: HashMap::from([(
+                path.clone(),
+                FileChange::Update {
+                    unified_diff: "@@ -1,2 +1,2 @@\n-one\n-two\n+ONE\n+TWO\n".to_string(),
+                    move_path: None,
+                },
+            )]),
+            hunks: vec![
+                hunk(0, "@@ -1,1 +1,1 @@\n-one\n+ONE\n"),
+                hunk(1, "@@ -2,1 +2,1 @@\n-two\n+TWO\n"),
+            ],
+        }
+    }
+
+    #[test]
+    fn hunk_review_sends_declined_hunks_with_notes() {
+        let (tx, mut rx) = unbounded_channel::<AppEvent>();
+        let tx = AppEventSender::new(tx);
+        let mut view = ApprovalOverlay::new(
+            make_two_hunk_patch_request(),
+            tx,
+            Features::with_defaults(),
+            Keymap::default(),
+        );
+
+        view.handle_key_event(KeyEvent::new(KeyCode::Char('h'), KeyModifiers::NONE));
+        view.handle_key_event(KeyEvent::new(KeyCode::Char('y'), KeyModifiers::NONE));
+        view.handle_key_event(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::NONE));
+        assert!(!view.is_complete());
+        for c in "nope".chars() {
+            view.handle_key_event(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+        }
+        view.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert!(view.is_complete());
+
+        let mut decision = None;
+        while let Ok(ev) = rx.try_recv() {
+            if let AppEvent::CodexOp(Op::PatchApproval { decision: d, .. }) = ev {
+                decision = Some(d);
+            }
+        }
+        assert_eq!(
+            decision,
+            Some(ReviewDecision::ApprovedHunks {
+                declined: vec![DeclinedHunk {
+                    path: PathBuf::from("/tmp/a.txt"),
+                    index: 1,
+                    note: Some("nope".to_string()),
+                }],
+            })
+        );
+    }
+
+    #[test]
+    fn hunk_review_accepting_everything_approves_the_patch() {
+        let (tx, mut rx) = unbounded_channel::<AppEvent>();
+        let tx = AppEventSender::new(tx);
+        let mut view = ApprovalOverlay::new(
+            make_two_hunk_patch_request(),
+            tx,
+            Features::with_defaults(),
+            Keymap::default(),
+        );
+
+        view.handle_key_event(KeyEvent::new(KeyCode::Char('h'), KeyModifiers::NONE));
+        view.handle_key_event(KeyEvent::new(KeyCode::Char('y'), KeyModifiers::NONE));
+        assert!(!view.is_complete());
+        view.handle_key_event(KeyEvent::new(KeyCode::Char('y'), KeyModifiers::NONE));
+        assert!(view.is_complete());
+
+        let mut decision = None;
+        while let Ok(ev) = rx.try_recv() {
+            if let AppEvent::CodexOp(Op::PatchApproval { decision: d, .. }) = ev {
+                decision = Some(d);
+            }
+        }
+        assert_eq!(decision, Some(ReviewDecision::Approved));
+    }
+
     #[test]
     fn ctrl_c_aborts_and_clears_queue() {
         let (tx, _rx) = unbounded_channel::<AppEvent>();
         let tx = AppEventSender::new(tx);
-        let mut view = ApprovalOverlay::new(make_exec_request(), tx, Features::with_defaults());
+        let mut view = ApprovalOverlay::new(
+            make_exec_request(),
+            tx,
+            Features::with_defaults(),
+            Keymap::default(),
+        );
         view.enqueue_request(make_exec_request());
         assert_eq!(CancellationEvent::Handled, view.on_ctrl_c());
         assert!(view.queue.is_empty());
@@ -567,7 +922,12 @@ mod tests {
     fn shortcut_triggers_selection() {
         let (tx, mut rx) = unbounded_channel::<AppEvent>();
         let tx = AppEventSender::new(tx);
-        let mut view = ApprovalOverlay::new(make_exec_request(), tx, Features::with_defaults());
+        let mut view = ApprovalOverlay::new(
+            make_exec_request(),
+            tx,
+            Features::with_defaults(),
+            Keymap::default(),
+        );
         assert!(!view.is_complete());
         view.handle_key_event(KeyEvent::new(KeyCode::Char('y'), KeyModifiers::NONE));
         // We expect at least one CodexOp message in the queue.
@@ -581,6 +941,55 @@ mod tests {
         assert!(saw_op, "expected approval decision to emit an op");
     }
 
+    #[test]
+    fn approval_shortcuts_follow_keymap() {
+        let (tx, mut rx) = unbounded_channel::<AppEvent>();
+        let tx = AppEventSender::new(tx);
+        let keymap = Keymap::from_config(&TuiKeymap {
+            deny: Some(KeyChords::One("d".to_string())),
+            ..Default::default()
+        })
+        .expect("valid keymap");
+        let mut view =
+            ApprovalOverlay::new(make_exec_request(), tx, Features::with_defaults(), keymap);
+
+        view.handle_key_event(KeyEvent::new(KeyCode::Char('n'), KeyModifiers::NONE));
+        assert!(!view.is_complete());
+        assert!(rx.try_recv().is_err());
+
+        view.handle_key_event(KeyEvent::new(KeyCode::Char('d'), KeyModifiers::NONE));
+        let mut decision = None;
+        while let Ok(ev) = rx.try_recv() {
+            if let AppEvent::CodexOp(Op::ExecApproval { decision: d, .. }) = ev {
+                decision = Some(d);
+            }
+        }
+        assert_eq!(decision, Some(ReviewDecision::Abort));
+    }
+
+    #[test]
+    fn hunk_review_shortcut_follows_keymap() {
+        let (tx, _rx) = unbounded_channel::<AppEvent>();
+        let tx = AppEventSender::new(tx);
+        let keymap = Keymap::from_config(&TuiKeymap {
+            review_hunks: Some(KeyChords::One("v".to_string())),
+            ..Default::default()
+        })
+        .expect("valid keymap");
+        let mut view = ApprovalOverlay::new(
+            make_two_hunk_patch_request(),
+            tx,
+            Features::with_defaults(),
+            keymap,
+        );
+
+        view.handle_key_event(KeyEvent::new(KeyCode::Char('v'), KeyModifiers::NONE));
+        view.handle_key_event(KeyEvent::new(KeyCode::Char('y'), KeyModifiers::NONE));
+        assert!(!view.is_complete());
+        view.handle_key_event(KeyEvent::new(KeyCode::Char('y'), KeyModifiers::NONE));
+        assert!(view.is_complete());
+    }
+
     #[test]
     fn exec_prefix_option_emits_execpolicy_amendment() {
         let (tx, mut rx) = unbounded_channel::<AppEvent>();
@@ -596,6 +1005,7 @@ mod tests {
             },
             tx,
             Features::with_defaults(),
+            Keymap::default(),
         );
         view.handle_key_event(KeyEvent::new(KeyCode::Char('p'), KeyModifiers::NONE));
         let mut saw_op = false;
@@ -638,6 +1048,7 @@ mod tests {
                 features.disable(Feature::ExecPolicy);
                 features
             },
+            Keymap::default(),
         );
         assert_eq!(view.options.len(), 2);
         view.handle_key_event(KeyEvent::new(KeyCode::Char('p'), KeyModifiers::NONE));
@@ -657,7 +1068,12 @@ mod tests {
             proposed_execpolicy_amendment: None,
         };
 
-        let view = ApprovalOverlay::new(exec_request, tx, Features::with_defaults());
+        let view = ApprovalOverlay::new(
+            exec_request,
+            tx,
+            Features::with_defaults(),
+            Keymap::default(),
+        );
         let mut buf = Buffer::empty(Rect::new(0, 0, 80, view.desired_height(80)));
         view.render(Rect::new(0, 0, 80, view.desired_height(80)), &mut buf);
 
@@ -707,7 +1123,12 @@ mod tests {
     fn enter_sets_last_selected_index_without_dismissing() {
         let (tx_raw, mut rx) = unbounded_channel::<AppEvent>();
         let tx = AppEventSender::new(tx_raw);
-        let mut view = ApprovalOverlay::new(make_exec_request(), tx, Features::with_defaults());
+        let mut view = ApprovalOverlay::new(
+            make_exec_request(),
+            tx,
+            Features::with_defaults(),
+            Keymap::default(),
+        );
         view.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
 
         assert!(
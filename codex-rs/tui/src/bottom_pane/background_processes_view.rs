@@ -0,0 +1,598 @@
+//! Interactive overlay for background terminals opened via `/ps`.
+//!
+//! The view lists the processes the session is tracking, shows the recent output of the
+//! selected one, and forwards typed input and signals to it. Output is not pushed by core;
+//! while the view is open a poller periodically requests the process list and the selected
+//! process's transcript, and stops on its own once the view is dropped.
+
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::Weak;
+use std::time::Duration;
+
+use codex_core::protocol::BackgroundProcess;
+use codex_core::protocol::BackgroundProcessOutputEvent;
+use codex_core::protocol::BackgroundProcessSignal;
+use codex_core::protocol::Op;
+use crossterm::event::KeyCode;
+use crossterm::event::KeyEvent;
+use crossterm::event::KeyModifiers;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Constraint;
+use ratatui::layout::Layout;
+use ratatui::layout::Rect;
+use ratatui::style::Stylize;
+use ratatui::text::Line;
+use ratatui::widgets::Block;
+use ratatui::widgets::Paragraph;
+use ratatui::widgets::Widget;
+
+use crate::app_event::AppEvent;
+use crate::app_event_sender::AppEventSender;
+use crate::exec_command::strip_bash_lc_and_escape;
+use crate::key_hint;
+use crate::render::Insets;
+use crate::render::RectExt as _;
+use crate::render::renderable::Renderable;
+use crate::style::user_message_style;
+
+use super::CancellationEvent;
+use super::bottom_pane_view::BottomPaneView;
+use super::popup_consts::MAX_POPUP_ROWS;
+use super::scroll_state::ScrollState;
+use super::selection_popup_common::GenericDisplayRow;
+use super::selection_popup_common::render_rows_single_line;
+
+/// Number of trailing output lines shown for the selected process.
+const OUTPUT_ROWS: usize = 12;
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+const INPUT_PROMPT_PREFIX: &str = "> ";
+
+pub(crate) struct BackgroundProcessesView {
+    processes: Vec<BackgroundProcess>,
+    state: ScrollState,
+    /// Process id the poller reads output for; shared so the poller can
+    /// follow selection changes and notice when the view is dropped.
+    selected_process_id: Arc<Mutex<Option<String>>>,
+    output: Option<BackgroundProcessOutputEvent>,
+    input: String,
+    complete: bool,
+    app_event_tx: AppEventSender,
+}
+
+impl BackgroundProcessesView {
+    pub(crate) fn new(processes: Vec<BackgroundProcess>, app_event_tx: AppEventSender) -> Self {
+        let mut view = Self {
+            processes: Vec::new(),
+            state: ScrollState::new(),
+            selected_process_id: Arc::new(Mutex::new(None)),
+            output: None,
+            input: String::new(),
+            complete: false,
+            app_event_tx,
+        };
+        view.set_processes(processes);
+        view
+    }
+
+    /// Start requesting fresh process state until the view is dropped.
+    pub(crate) fn spawn_poller(&self) {
+        let selected_process_id = Arc::downgrade(&self.selected_process_id);
+        let app_event_tx = self.app_event_tx.clone();
+        tokio::spawn(poll_background_processes(selected_process_id, app_event_tx));
+    }
+
+    /// Replace the process list, keeping the current selection when the
+    /// selected process is still tracked.
+    fn set_processes(&mut self, processes: Vec<BackgroundProcess>) {
+        let previously_selected = self.selected_process().map(|p| p.process_id.clone());
+        self.processes = processes;
+        let len = self.processes.len();
+        self.state.selected_idx = previously_selected
+            .and_then(|process_id| {
+                self.processes
+                    .iter()
+                    .position(|process| process.process_id == process_id)
+            })
+            .or_else(|| (len > 0).then_some(0));
+        self.state.clamp_selection(len);
+        self.state.ensure_visible(len, Self::max_visible_rows(len));
+        self.sync_selected_process_id();
+    }
+
+    fn set_output(&mut self, output: BackgroundProcessOutputEvent) {
+        if self
+            .selected_process()
+            .is_some_and(|process| process.process_id == output.process_id)
+        {
+            self.output = Some(output);
+        }
+    }
+
+    fn selected_process(&self) -> Option<&BackgroundProcess> {
+        self.state
+            .selected_idx
+            .and_then(|idx| self.processes.get(idx))
+    }
+
+    fn sync_selected_process_id(&mut self) {
+        let selected = self.selected_process().map(|p| p.process_id.clone());
+        if self
+            .output
+            .as_ref()
+            .is_some_and(|output| Some(&output.process_id) != selected.as_ref())
+        {
+            self.output = None;
+        }
+        let changed = match self.selected_process_id.lock() {
+            Ok(mut guard) if *guard != selected => {
+                guard.clone_from(&selected);
+                true
+            }
+            _ => false,
+        };
+        // Fetch output for a newly selected process right away rather than
+        // waiting for the next poll.
+        if changed && let Some(process_id) = selected {
+            self.app_event_tx
+                .send(AppEvent::CodexOp(Op::ReadBackgroundProcessOutput {
+                    process_id,
+                }));
+        }
+    }
+
+    fn max_visible_rows(len: usize) -> usize {
+        MAX_POPUP_ROWS.min(len.max(1))
+    }
+
+    fn move_up(&mut self) {
+        let len = self.processes.len();
+        self.state.move_up_wrap(len);
+        self.state.ensure_visible(len, Self::max_visible_rows(len));
+        self.sync_selected_process_id();
+    }
+
+    fn move_down(&mut self) {
+        let len = self.processes.len();
+        self.state.move_down_wrap(len);
+        self.state.ensure_visible(len, Self::max_visible_rows(len));
+        self.sync_selected_process_id();
+    }
+
+    fn write_stdin(&mut self, input: String) {
+        let Some(process) = self.selected_process() else {
+            return;
+        };
+        if !process.tty {
+            return;
+        }
+        let process_id = process.process_id.clone();
+        self.app_event_tx
+            .send(AppEvent::CodexOp(Op::WriteBackgroundProcessStdin {
+                process_id,
+                input,
+            }));
+    }
+
+    fn submit_input(&mut self) {
+        let mut input = std::mem::take(&mut self.input);
+        input.push('\n');
+        self.write_stdin(input);
+    }
+
+    fn send_signal(&mut self, signal: BackgroundProcessSignal) {
+        let Some(process_id) = self.selected_process().map(|p| p.process_id.clone()) else {
+            return;
+        };
+        self.app_event_tx
+            .send(AppEvent::CodexOp(Op::SignalBackgroundProcess {
+                process_id: process_id.clone(),
+                signal,
+            }));
+        if signal == BackgroundProcessSignal::Kill {
+            // Core stops tracking killed processes immediately; drop it here
+            // too instead of waiting for the next poll.
+            let processes = self
+                .processes
+                .iter()
+                .filter(|process| process.process_id != process_id)
+                .cloned()
+                .collect();
+            self.set_processes(processes);
+        }
+    }
+
+    fn build_rows(&self) -> Vec<GenericDisplayRow> {
+        self.processes
+            .iter()
+            .enumerate()
+            .map(|(idx, process)| {
+                let prefix = if self.state.selected_idx == Some(idx) {
+                    '›'
+                } else {
+                    ' '
+                };
+                let command = strip_bash_lc_and_escape(&process.command);
+                GenericDisplayRow {
+                    name: format!("{prefix} {command}"),
+                    description: Some(process.cwd.display().to_string()),
+                    ..Default::default()
+                }
+            })
+            .collect()
+    }
+
+    fn rows_height(&self) -> u16 {
+        self.processes
+            .len()
+            .clamp(1, MAX_POPUP_ROWS)
+            .try_into()
+            .unwrap_or(1)
+    }
+
+    fn output_lines(&self) -> Vec<Line<'static>> {
+        let Some(output) = self.output.as_ref() else {
+            return if self.selected_process().is_some() {
+                vec![Line::from("loading output…".dim().italic())]
+            } else {
+                Vec::new()
+            };
+        };
+        let mut lines: Vec<Line<'static>> = terminal_lines(&output.output, OUTPUT_ROWS)
+            .into_iter()
+            .map(Line::from)
+            .collect();
+        if lines.is_empty() {
+            lines.push(Line::from("no output yet".dim().italic()));
+        }
+        if !output.running {
+            if lines.len() >= OUTPUT_ROWS {
+                lines.remove(0);
+            }
+            lines.push(Line::from("process exited".dim().italic()));
+        }
+        lines
+    }
+
+    fn input_line(&self) -> Line<'static> {
+        match self.selected_process() {
+            Some(process) if process.tty => {
+                Line::from(vec![INPUT_PROMPT_PREFIX.dim(), self.input.clone().into()])
+            }
+            Some(_) => Line::from(
+                "stdin is closed: the process was started without a TTY"
+                    .dim()
+                    .italic(),
+            ),
+            None => Line::from(""),
+        }
+    }
+}
+
+impl BottomPaneView for BackgroundProcessesView {
+    fn handle_key_event(&mut self, key_event: KeyEvent) {
+        match key_event {
+            KeyEvent {
+                code: KeyCode::Up, ..
+            } => self.move_up(),
+            KeyEvent {
+                code: KeyCode::Down,
+                ..
+            } => self.move_down(),
+            KeyEvent {
+                code: KeyCode::Esc, ..
+            } => self.complete = true,
+            KeyEvent {
+                code: KeyCode::Enter,
+                ..
+            } => self.submit_input(),
+            KeyEvent {
+                code: KeyCode::Backspace,
+                ..
+            } => {
+                self.input.pop();
+            }
+            KeyEvent {
+                code: KeyCode::Char(c),
+                modifiers,
+                ..
+            } if modifiers.contains(KeyModifiers::CONTROL) => match c.to_ascii_lowercase() {
+                'd' => self.write_stdin("\u{0004}".to_string()),
+                'x' => self.send_signal(BackgroundProcessSignal::Terminate),
+                'k' => self.send_signal(BackgroundProcessSignal::Kill),
+                _ => {}
+            },
+            KeyEvent {
+                code: KeyCode::Char(c),
+                modifiers,
+                ..
+            } if !modifiers.contains(KeyModifiers::ALT) => {
+                if self.selected_process().is_some_and(|process| process.tty) {
+                    self.input.push(c);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn is_complete(&self) -> bool {
+        self.complete
+    }
+
+    /// Ctrl-C interrupts the selected process rather than closing the view,
+    /// so it behaves like it would in the process's own terminal.
+    fn on_ctrl_c(&mut self) -> CancellationEvent {
+        if self.selected_process().is_some() {
+            self.send_signal(BackgroundProcessSignal::Interrupt);
+        } else {
+            self.complete = true;
+        }
+        CancellationEvent::Handled
+    }
+
+    fn prefer_esc_to_handle_key_event(&self) -> bool {
+        true
+    }
+
+    fn try_consume_background_processes(
+        &mut self,
+        processes: Vec<BackgroundProcess>,
+    ) -> Option<Vec<BackgroundProcess>> {
+        self.set_processes(processes);
+        None
+    }
+
+    fn try_consume_background_process_output(
+        &mut self,
+        output: BackgroundProcessOutputEvent,
+    ) -> Option<BackgroundProcessOutputEvent> {
+        self.set_output(output);
+        None
+    }
+
+    fn handle_paste(&mut self, pasted: String) -> bool {
+        if !self.selected_process().is_some_and(|process| process.tty) {
+            return false;
+        }
+        self.input.push_str(&pasted);
+        true
+    }
+}
+
+impl Renderable for BackgroundProcessesView {
+    fn desired_height(&self, _width: u16) -> u16 {
+        // Title, list, output label + rows, input line, separators, insets and footer.
+        let fixed: u16 = 1 + 1 + 1 + 1 + OUTPUT_ROWS as u16 + 1 + 1 + 2 + 1;
+        fixed.saturating_add(self.rows_height())
+    }
+
+    fn render(&self, area: Rect, buf: &mut Buffer) {
+        if area.height == 0 || area.width == 0 {
+            return;
+        }
+
+        let [content_area, footer_area] =
+            Layout::vertical([Constraint::Fill(1), Constraint::Length(1)]).areas(area);
+
+        Block::default()
+            .style(user_message_style())
+            .render(content_area, buf);
+
+        let [
+            title_area,
+            _,
+            list_area,
+            _,
+            output_label_area,
+            output_area,
+            _,
+            input_area,
+        ] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(self.rows_height()),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(OUTPUT_ROWS as u16),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .areas(content_area.inset(Insets::vh(1, 2)));
+
+        Line::from("Background terminals".bold()).render(title_area, buf);
+
+        if list_area.height > 0 {
+            let render_area = Rect {
+                x: list_area.x.saturating_sub(2),
+                y: list_area.y,
+                width: content_area.width.saturating_sub(2).max(1),
+                height: list_area.height,
+            };
+            let rows = self.build_rows();
+            render_rows_single_line(
+                render_area,
+                buf,
+                &rows,
+                &self.state,
+                render_area.height as usize,
+                "No background terminals running.",
+            );
+        }
+
+        Line::from("Output".dim()).render(output_label_area, buf);
+        Paragraph::new(self.output_lines()).render(output_area, buf);
+        self.input_line().render(input_area, buf);
+
+        let hint_area = Rect {
+            x: footer_area.x + 2,
+            y: footer_area.y,
+            width: footer_area.width.saturating_sub(2),
+            height: footer_area.height,
+        };
+        background_processes_hint_line()
+            .dim()
+            .render(hint_area, buf);
+    }
+}
+
+async fn poll_background_processes(
+    selected_process_id: Weak<Mutex<Option<String>>>,
+    app_event_tx: AppEventSender,
+) {
+    let mut interval = tokio::time::interval(POLL_INTERVAL);
+    loop {
+        interval.tick().await;
+        let Some(selected_process_id) = selected_process_id.upgrade() else {
+            break;
+        };
+        let process_id = selected_process_id
+            .lock()
+            .ok()
+            .and_then(|guard| guard.clone());
+        app_event_tx.send(AppEvent::CodexOp(Op::ListBackgroundProcesses));
+        if let Some(process_id) = process_id {
+            app_event_tx.send(AppEvent::CodexOp(Op::ReadBackgroundProcessOutput {
+                process_id,
+            }));
+        }
+    }
+}
+
+/// Returns the last `max_lines` non-empty lines of raw terminal output. Carriage
+/// returns overwrite the line they appear on, as progress bars expect.
+fn terminal_lines(output: &str, max_lines: usize) -> Vec<String> {
+    let mut lines: Vec<String> = output
+        .split('\n')
+        .map(|line| {
+            let line = line.trim_end_matches('\r');
+            let line = line.rsplit('\r').next().unwrap_or(line);
+            line.chars()
+                .map(|c| if c == '\t' { ' ' } else { c })
+                .filter(|c| !c.is_control())
+                .collect::<String>()
+        })
+        .collect();
+    while lines.last().is_some_and(|line| line.trim().is_empty()) {
+        lines.pop();
+    }
+    let start = lines.len().saturating_sub(max_lines);
+    lines.split_off(start)
+}
+
+fn background_processes_hint_line() -> Line<'static> {
+    Line::from(vec![
+        key_hint::plain(KeyCode::Enter).into(),
+        " send line · ".into(),
+        key_hint::ctrl(KeyCode::Char('c')).into(),
+        " interrupt · ".into(),
+        key_hint::ctrl(KeyCode::Char('d')).into(),
+        " EOF · ".into(),
+        key_hint::ctrl(KeyCode::Char('x')).into(),
+        " terminate · ".into(),
+        key_hint::ctrl(KeyCode::Char('k')).into(),
+        " kill · ".into(),
+        key_hint::plain(KeyCode::Esc).into(),
+        " close".into(),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::path::PathBuf;
+    use tokio::sync::mpsc::UnboundedReceiver;
+    use tokio::sync::mpsc::unbounded_channel;
+
+    fn process(process_id: &str, tty: bool) -> BackgroundProcess {
+        BackgroundProcess {
+            process_id: process_id.to_string(),
+            command: vec!["npm".to_string(), "run".to_string(), "dev".to_string()],
+            cwd: PathBuf::from("/workspace"),
+            tty,
+        }
+    }
+
+    fn drain_ops(rx: &mut UnboundedReceiver<AppEvent>) -> Vec<Op> {
+        let mut ops = Vec::new();
+        while let Ok(event) = rx.try_recv() {
+            if let AppEvent::CodexOp(op) = event {
+                ops.push(op);
+            }
+        }
+        ops
+    }
+
+    fn key(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent::new(code, modifiers)
+    }
+
+    #[test]
+    fn typed_input_and_signals_target_selected_process() {
+        let (tx_raw, mut rx) = unbounded_channel::<AppEvent>();
+        let mut view = BackgroundProcessesView::new(
+            vec![process("1000", true), process("1001", true)],
+            AppEventSender::new(tx_raw),
+        );
+        view.handle_key_event(key(KeyCode::Down, KeyModifiers::NONE));
+        drain_ops(&mut rx);
+
+        view.handle_key_event(key(KeyCode::Char('y'), KeyModifiers::NONE));
+        view.handle_key_event(key(KeyCode::Enter, KeyModifiers::NONE));
+        assert_eq!(view.on_ctrl_c(), CancellationEvent::Handled);
+        assert!(!view.is_complete());
+
+        assert_eq!(
+            drain_ops(&mut rx),
+            vec![
+                Op::WriteBackgroundProcessStdin {
+                    process_id: "1001".to_string(),
+                    input: "y\n".to_string(),
+                },
+                Op::SignalBackgroundProcess {
+                    process_id: "1001".to_string(),
+                    signal: BackgroundProcessSignal::Interrupt,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn kill_drops_process_and_selects_next() {
+        let (tx_raw, mut rx) = unbounded_channel::<AppEvent>();
+        let mut view = BackgroundProcessesView::new(
+            vec![process("1000", true), process("1001", false)],
+            AppEventSender::new(tx_raw),
+        );
+        drain_ops(&mut rx);
+
+        view.handle_key_event(key(KeyCode::Char('k'), KeyModifiers::CONTROL));
+
+        assert_eq!(
+            drain_ops(&mut rx),
+            vec![
+                Op::SignalBackgroundProcess {
+                    process_id: "1000".to_string(),
+                    signal: BackgroundProcessSignal::Kill,
+                },
+                Op::ReadBackgroundProcessOutput {
+                    process_id: "1001".to_string(),
+                },
+            ]
+        );
+
+        // The remaining process has no TTY, so typing goes nowhere.
+        view.handle_key_event(key(KeyCode::Char('y'), KeyModifiers::NONE));
+        view.handle_key_event(key(KeyCode::Enter, KeyModifiers::NONE));
+        assert_eq!(drain_ops(&mut rx), Vec::new());
+    }
+
+    #[test]
+    fn terminal_lines_apply_carriage_returns_and_keep_tail() {
+        let output = "one\ntwo\r\nprogress 10%\rprogress 100%\n\n";
+        assert_eq!(
+            terminal_lines(output, 2),
+            vec!["two".to_string(), "progress 100%".to_string()]
+        );
+    }
+}
@@ -1,5 +1,7 @@
 use crate::bottom_pane::ApprovalRequest;
 use crate::render::renderable::Renderable;
+use codex_protocol::protocol::BackgroundProcess;
+use codex_protocol::protocol::BackgroundProcessOutputEvent;
 use codex_protocol::request_user_input::RequestUserInputEvent;
 use crossterm::event::KeyEvent;
 
@@ -58,6 +60,24 @@ pub(crate) trait BottomPaneView: Renderable {
         Some(request)
     }
 
+    /// Try to handle a refreshed background process list; return the original
+    /// value if not consumed.
+    fn try_consume_background_processes(
+        &mut self,
+        processes: Vec<BackgroundProcess>,
+    ) -> Option<Vec<BackgroundProcess>> {
+        Some(processes)
+    }
+
+    /// Try to handle background process output; return the original value if
+    /// not consumed.
+    fn try_consume_background_process_output(
+        &mut self,
+        output: BackgroundProcessOutputEvent,
+    ) -> Option<BackgroundProcessOutputEvent> {
+        Some(output)
+    }
+
     /// Try to handle request_user_input; return the original value if not
     /// consumed.
     fn try_consume_user_input_request(
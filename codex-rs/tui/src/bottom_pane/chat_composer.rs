@@ -87,6 +87,7 @@ use crate::bottom_pane::selection_popup_common::truncate_line_with_ellipsis_if_o
 use crate::key_hint;
 use crate::key_hint::KeyBinding;
 use crate::key_hint::has_ctrl_or_alt;
+use crate::keymap::Keymap;
 use crate::ui_consts::FOOTER_INDENT_COLS;
 use crossterm::event::KeyCode;
 use crossterm::event::KeyEvent;
@@ -111,6 +112,7 @@ use super::command_popup::CommandPopup;
 use super::command_popup::CommandPopupFlags;
 use super::file_search_popup::FileSearchPopup;
 use super::footer::CollaborationModeIndicator;
+use super::footer::FooterKeyHints;
 use super::footer::FooterMode;
 use super::footer::FooterProps;
 use super::footer::SummaryLeft;
@@ -129,6 +131,7 @@ use super::footer::render_footer_line;
 use super::footer::reset_mode_after_activity;
 use super::footer::single_line_footer_layout;
 use super::footer::toggle_shortcut_mode;
+use super::footer::with_vim_mode;
 use super::paste_burst::CharDecision;
 use super::paste_burst::PasteBurst;
 use super::skill_popup::MentionItem;
@@ -149,7 +152,7 @@ use crate::style::user_message_style;
 use codex_common::fuzzy_match::fuzzy_match;
 use codex_protocol::custom_prompts::CustomPrompt;
 use codex_protocol::custom_prompts::PROMPTS_CMD_PREFIX;
-use codex_protocol::models::local_image_label_text;
+use codex_protocol::models::local_attachment_label_text;
 use codex_protocol::user_input::ByteRange;
 use codex_protocol::user_input::TextElement;
 
@@ -159,6 +162,9 @@ use crate::app_event_sender::AppEventSender;
 use crate::bottom_pane::LocalImageAttachment;
 use crate::bottom_pane::textarea::TextArea;
 use crate::bottom_pane::textarea::TextAreaState;
+use crate::bottom_pane::vim::VimAction;
+use crate::bottom_pane::vim::VimMode;
+use crate::bottom_pane::vim::VimState;
 use crate::clipboard_paste::normalize_pasted_path;
 use crate::clipboard_paste::pasted_image_format;
 use crate::history_cell;
@@ -265,12 +271,16 @@ pub(crate) struct ChatComposer {
     quit_shortcut_key: KeyBinding,
     esc_backtrack_hint: bool,
     use_shift_enter_hint: bool,
+    keymap: Keymap,
+    /// Vi-style modal editing state; `None` unless `tui.vim_mode` is enabled.
+    vim: Option<VimState>,
     dismissed_file_popup_token: Option<String>,
     current_file_query: Option<String>,
     pending_pastes: Vec<(String, String)>,
     large_paste_counters: HashMap<usize, usize>,
     has_focus: bool,
-    /// Invariant: attached images are labeled `[Image #1]..[Image #N]` in vec order.
+    /// Invariant: attachments are labeled `[Image #1]..[Image #N]` in vec order,
+    /// with `[PDF #N]` for PDFs.
     attached_images: Vec<AttachedImage>,
     placeholder_text: String,
     is_task_running: bool,
@@ -361,6 +371,8 @@ impl ChatComposer {
             quit_shortcut_key: key_hint::ctrl(KeyCode::Char('c')),
             esc_backtrack_hint: false,
             use_shift_enter_hint,
+            keymap: Keymap::default(),
+            vim: None,
             dismissed_file_popup_token: None,
             current_file_query: None,
             pending_pastes: Vec::new(),
@@ -432,6 +444,27 @@ impl ChatComposer {
         self.collaboration_modes_enabled = enabled;
     }
 
+    /// Use the user's bindings for submit, newline, queue and backtrack, and in footer hints.
+    pub(crate) fn set_keymap(&mut self, keymap: Keymap) {
+        self.keymap = keymap;
+    }
+
+    pub(crate) fn set_vim_mode_enabled(&mut self, enabled: bool) {
+        if enabled != self.vim.is_some() {
+            self.vim = enabled.then(VimState::new);
+            self.textarea.set_selection(None);
+        }
+    }
+
+    pub(crate) fn vim_mode(&self) -> Option<VimMode> {
+        self.vim.as_ref().map(VimState::mode)
+    }
+
+    /// Esc belongs to vim while in insert/visual mode or halfway through a command.
+    pub(crate) fn vim_captures_key(&self, key_event: KeyEvent) -> bool {
+        key_event.code == KeyCode::Esc && self.vim.as_ref().is_some_and(VimState::captures_esc)
+    }
+
     pub fn set_connectors_enabled(&mut self, enabled: bool) {
         self.connectors_enabled = enabled;
     }
@@ -577,6 +610,11 @@ impl ChatComposer {
                 self.attach_image(path_buf);
                 true
             }
+            Err(err) if codex_utils_image::pdf::is_pdf(&path_buf) => {
+                tracing::debug!("attaching pasted PDF (not an image: {err})");
+                self.attach_image(path_buf);
+                true
+            }
             Err(err) => {
                 tracing::trace!("ERR: {err}");
                 false
@@ -775,7 +813,7 @@ impl ChatComposer {
             .filter_map(|elem| elem.placeholder(&text).map(str::to_string))
             .collect();
         for (idx, path) in local_image_paths.into_iter().enumerate() {
-            let placeholder = local_image_label_text(idx + 1);
+            let placeholder = local_attachment_label_text(&path, idx + 1);
             if image_placeholders.contains(&placeholder) {
                 self.attached_images
                     .push(AttachedImage { placeholder, path });
@@ -860,7 +898,7 @@ impl ChatComposer {
     /// Insert an attachment placeholder and track it for the next submission.
     pub fn attach_image(&mut self, path: PathBuf) {
         let image_number = self.attached_images.len() + 1;
-        let placeholder = local_image_label_text(image_number);
+        let placeholder = local_attachment_label_text(&path, image_number);
         // Insert as an element to match large paste placeholder behavior:
         // styled distinctly and treated atomically for cursor/mutations.
         self.textarea.insert_element(&placeholder);
@@ -984,6 +1022,11 @@ impl ChatComposer {
             return (InputResult::None, false);
         }
 
+        if self.handle_vim_key(key_event) {
+            self.sync_popups();
+            return (InputResult::None, true);
+        }
+
         let result = match &mut self.active_popup {
             ActivePopup::Command(_) => self.handle_key_event_with_slash_popup(key_event),
             ActivePopup::File(_) => self.handle_key_event_with_file_popup(key_event),
@@ -994,9 +1037,52 @@ impl ChatComposer {
         // Update (or hide/show) popup after processing the key.
         self.sync_popups();
 
+        // A submitted or dispatched draft starts the next one in insert mode.
+        if !matches!(result.0, InputResult::None)
+            && self.textarea.is_empty()
+            && let Some(vim) = self.vim.as_mut()
+        {
+            vim.reset(&mut self.textarea);
+        }
+
         result
     }
 
+    /// Routes a key through vim mode first. Returns true when vim consumed it.
+    ///
+    /// In insert mode an open popup keeps Esc so it can dismiss itself; the next Esc then
+    /// leaves insert mode.
+    fn handle_vim_key(&mut self, key_event: KeyEvent) -> bool {
+        let popup_active = self.popup_active();
+        let Some(vim) = self.vim.as_ref() else {
+            return false;
+        };
+        let is_insert = vim.mode() == VimMode::Insert;
+        if is_insert && (popup_active || key_event.code != KeyCode::Esc) {
+            return false;
+        }
+        if let Some(pasted) = self.paste_burst.flush_before_modified_input() {
+            self.handle_paste(pasted);
+        }
+
+        let elements_before = if self.pending_pastes.is_empty() && self.attached_images.is_empty() {
+            None
+        } else {
+            Some(self.textarea.element_payloads())
+        };
+        let Some(vim) = self.vim.as_mut() else {
+            return false;
+        };
+        if vim.handle_key(key_event, &mut self.textarea) == VimAction::PassThrough {
+            return false;
+        }
+        if let Some(elements_before) = elements_before {
+            self.reconcile_deleted_elements(elements_before);
+        }
+        self.footer_mode = reset_mode_after_activity(self.footer_mode);
+        true
+    }
+
     /// Return true if either the slash-command popup or the file-search popup is active.
     pub(crate) fn popup_active(&self) -> bool {
         !matches!(self.active_popup, ActivePopup::None)
@@ -1356,14 +1442,28 @@ impl ChatComposer {
                 };
 
                 let sel_path = sel.to_string_lossy().to_string();
-                // If selected path looks like an image (png/jpeg), attach as image instead of inserting text.
-                let is_image = Self::is_image_path(&sel_path);
-                if is_image {
-                    // Determine dimensions; if that fails fall back to normal path insertion.
+                // If selected path looks like an image (png/jpeg) or PDF, attach it instead of inserting text.
+                let is_attachable = Self::is_attachable_path(&sel_path);
+                if is_attachable {
+                    // Check the file is readable as such; if not, fall back to normal path insertion.
                     let path_buf = PathBuf::from(&sel_path);
-                    match image::image_dimensions(&path_buf) {
-                        Ok((width, height)) => {
-                            tracing::debug!("selected image dimensions={}x{}", width, height);
+                    let attachable = if Self::is_pdf_path(&sel_path) {
+                        if codex_utils_image::pdf::is_pdf(&path_buf) {
+                            Ok(())
+                        } else {
+                            Err("missing PDF header".to_string())
+                        }
+                    } else {
+                        match image::image_dimensions(&path_buf) {
+                            Ok((width, height)) => {
+                                tracing::debug!("selected image dimensions={}x{}", width, height);
+                                Ok(())
+                            }
+                            Err(err) => Err(err.to_string()),
+                        }
+                    };
+                    match attachable {
+                        Ok(()) => {
                             // Remove the current @token (mirror logic from insert_selected_path without inserting text)
                             // using the flat text and byte-offset cursor API.
                             let cursor_offset = self.textarea.cursor();
@@ -1394,7 +1494,7 @@ impl ChatComposer {
                             self.textarea.insert_str(" ");
                         }
                         Err(err) => {
-                            tracing::trace!("image dimensions lookup failed: {err}");
+                            tracing::trace!("attachment lookup failed: {err}");
                             // Fallback to plain path insertion if metadata read fails.
                             self.insert_selected_path(&sel_path);
                         }
@@ -1487,9 +1587,16 @@ impl ChatComposer {
         result
     }
 
-    fn is_image_path(path: &str) -> bool {
+    fn is_attachable_path(path: &str) -> bool {
         let lower = path.to_ascii_lowercase();
-        lower.ends_with(".png") || lower.ends_with(".jpg") || lower.ends_with(".jpeg")
+        lower.ends_with(".png")
+            || lower.ends_with(".jpg")
+            || lower.ends_with(".jpeg")
+            || Self::is_pdf_path(path)
+    }
+
+    fn is_pdf_path(path: &str) -> bool {
+        path.to_ascii_lowercase().ends_with(".pdf")
     }
 
     fn trim_text_elements(
@@ -2201,7 +2308,7 @@ impl ChatComposer {
         if self.handle_shortcut_overlay_key(&key_event) {
             return (InputResult::None, true);
         }
-        if key_event.code == KeyCode::Esc {
+        if self.keymap.backtrack.is_press(key_event) {
             if self.is_empty() {
                 let next_mode = esc_hint_mode(self.footer_mode, self.is_task_running);
                 if next_mode != self.footer_mode {
@@ -2255,20 +2362,17 @@ impl ChatComposer {
                 }
                 self.handle_input_basic(key_event)
             }
-            KeyEvent {
-                code: KeyCode::Tab,
-                modifiers: KeyModifiers::NONE,
-                kind: KeyEventKind::Press,
-                ..
-            } if self.is_task_running => self.handle_submission(true),
-            KeyEvent {
-                code: KeyCode::Enter,
-                modifiers: KeyModifiers::NONE,
-                ..
-            } => {
+            key_event if self.is_task_running && self.keymap.queue.is_press(key_event) => {
+                self.handle_submission(true)
+            }
+            key_event if self.keymap.submit.is_press(key_event) => {
                 let should_queue = !self.steer_enabled;
                 self.handle_submission(should_queue)
             }
+            // The textarea turns Enter into a newline, which keeps paste-burst handling intact.
+            key_event if self.keymap.newline.is_press(key_event) => {
+                self.handle_input_basic(KeyEvent::new(KeyCode::Enter, KeyModifiers::SHIFT))
+            }
             input => self.handle_input_basic(input),
         }
     }
@@ -2473,7 +2577,7 @@ impl ChatComposer {
 
     fn relabel_attached_images_and_update_placeholders(&mut self) {
         for idx in 0..self.attached_images.len() {
-            let expected = local_image_label_text(idx + 1);
+            let expected = local_attachment_label_text(&self.attached_images[idx].path, idx + 1);
             let current = self.attached_images[idx].placeholder.clone();
             if current == expected {
                 continue;
@@ -2534,6 +2638,7 @@ impl ChatComposer {
             context_window_used_tokens: self.context_window_used_tokens,
             status_line_value: self.status_line_value.clone(),
             status_line_enabled: self.status_line_enabled,
+            key_hints: FooterKeyHints::new(&self.keymap),
         }
     }
 
@@ -3192,6 +3297,7 @@ impl ChatComposer {
                         footer_props.context_window_used_tokens,
                     ))
                 };
+                let right_line = with_vim_mode(self.vim_mode(), right_line);
                 let right_width = right_line.as_ref().map(|l| l.width() as u16).unwrap_or(0);
                 if status_line_active
                     && let Some(max_left) = max_left_width_for_right(hint_rect, right_width)
@@ -3223,6 +3329,7 @@ impl ChatComposer {
                                 show_cycle_hint,
                                 show_shortcuts_hint,
                                 show_queue_hint,
+                                footer_props.key_hints.queue,
                             ))
                         }
                         FooterMode::EscHint
@@ -3405,6 +3512,7 @@ fn prompt_selection_action(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use codex_protocol::models::local_image_label_text;
     use image::ImageBuffer;
     use image::Rgba;
     use pretty_assertions::assert_eq;
@@ -6055,6 +6163,30 @@ mod tests {
         assert_eq!(imgs, vec![tmp_path]);
     }
 
+    #[test]
+    fn pasting_pdf_filepath_attaches_pdf() {
+        let tmp = tempdir().expect("create TempDir");
+        let tmp_path: PathBuf = tmp.path().join("codex_tui_test_paste.pdf");
+        std::fs::write(&tmp_path, b"%PDF-1.7\n%%EOF\n").expect("failed to write temp pdf");
+
+        let (tx, _rx) = unbounded_channel::<AppEvent>();
+        let sender = AppEventSender::new(tx);
+        let mut composer = ChatComposer::new(
+            true,
+            sender,
+            false,
+            "Ask Codex to do anything".to_string(),
+            false,
+        );
+
+        let needs_redraw = composer.handle_paste(tmp_path.to_string_lossy().to_string());
+        assert!(needs_redraw);
+        assert!(composer.textarea.text().starts_with("[Image #1] "));
+
+        let attachments = composer.take_recent_submission_images();
+        assert_eq!(attachments, vec![tmp_path]);
+    }
+
     #[test]
     fn selecting_custom_prompt_without_args_submits_content() {
         let prompt_text = "Hello from saved prompt";
@@ -7385,4 +7517,42 @@ mod tests {
         };
         assert_eq!(composer.cursor_pos(area), None);
     }
+
+    #[test]
+    fn vim_mode_edits_in_normal_mode_and_resets_after_submit() {
+        use crossterm::event::KeyCode;
+        use crossterm::event::KeyEvent;
+        use crossterm::event::KeyModifiers;
+
+        let (tx, _rx) = unbounded_channel::<AppEvent>();
+        let sender = AppEventSender::new(tx);
+        let mut composer = ChatComposer::new(
+            true,
+            sender,
+            false,
+            "Ask Codex to do anything".to_string(),
+            false,
+        );
+        composer.set_vim_mode_enabled(true);
+        assert_eq!(composer.vim_mode(), Some(VimMode::Insert));
+
+        type_chars_humanlike(&mut composer, &['a', 'b', 'c']);
+        let esc = KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE);
+        assert!(composer.vim_captures_key(esc));
+        let _ = composer.handle_key_event(esc);
+        assert_eq!(composer.vim_mode(), Some(VimMode::Normal));
+        assert!(!composer.vim_captures_key(esc));
+
+        // Normal-mode keys edit the draft instead of being inserted.
+        type_chars_humanlike(&mut composer, &['0', 'x']);
+        assert_eq!(composer.textarea.text(), "bc");
+
+        let (result, _) =
+            composer.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert!(matches!(
+            result,
+            InputResult::Submitted { .. } | InputResult::Queued { .. }
+        ));
+        assert_eq!(composer.vim_mode(), Some(VimMode::Insert));
+    }
 }
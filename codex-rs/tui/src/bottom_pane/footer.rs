@@ -32,8 +32,10 @@
 //! In short: `single_line_footer_layout` chooses *what* best fits, and the two
 //! render helpers choose whether to draw the chosen line or the default
 //! `FooterProps` mapping.
+use crate::bottom_pane::vim::VimMode;
 use crate::key_hint;
 use crate::key_hint::KeyBinding;
+use crate::keymap::Keymap;
 use crate::render::line_utils::prefix_lines;
 use crate::status::format_tokens_compact;
 use crate::ui_consts::FOOTER_INDENT_COLS;
@@ -70,6 +72,40 @@ pub(crate) struct FooterProps {
     pub(crate) context_window_used_tokens: Option<i64>,
     pub(crate) status_line_value: Option<Line<'static>>,
     pub(crate) status_line_enabled: bool,
+    /// Keys from the user's keymap that the hints advertise.
+    pub(crate) key_hints: FooterKeyHints,
+}
+
+/// Keymap bindings shown in footer hints, resolved once per render.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct FooterKeyHints {
+    /// Newline key usable without enhanced keyboard reporting.
+    pub(crate) newline: KeyBinding,
+    /// Newline key to show when the terminal reports modified Enter.
+    pub(crate) enhanced_newline: KeyBinding,
+    pub(crate) queue: KeyBinding,
+    pub(crate) open_editor: KeyBinding,
+    pub(crate) backtrack: KeyBinding,
+    pub(crate) toggle_transcript: KeyBinding,
+}
+
+impl FooterKeyHints {
+    pub(crate) fn new(keymap: &Keymap) -> Self {
+        Self {
+            newline: keymap.newline_hint(false),
+            enhanced_newline: keymap.newline_hint(true),
+            queue: keymap.queue.primary(),
+            open_editor: keymap.open_editor.primary(),
+            backtrack: keymap.backtrack.primary(),
+            toggle_transcript: keymap.toggle_transcript.primary(),
+        }
+    }
+}
+
+impl Default for FooterKeyHints {
+    fn default() -> Self {
+        Self::new(&Keymap::default())
+    }
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -250,6 +286,7 @@ struct LeftSideState {
 fn left_side_line(
     collaboration_mode_indicator: Option<CollaborationModeIndicator>,
     state: LeftSideState,
+    queue_key: KeyBinding,
 ) -> Line<'static> {
     let mut line = Line::from("");
     match state.hint {
@@ -259,11 +296,11 @@ fn left_side_line(
             line.push_span(" for shortcuts".dim());
         }
         SummaryHintKind::QueueMessage => {
-            line.push_span(key_hint::plain(KeyCode::Tab));
+            line.push_span(queue_key);
             line.push_span(" to queue message".dim());
         }
         SummaryHintKind::QueueShort => {
-            line.push_span(key_hint::plain(KeyCode::Tab));
+            line.push_span(queue_key);
             line.push_span(" to queue".dim());
         }
     };
@@ -293,6 +330,7 @@ pub(crate) fn single_line_footer_layout(
     show_cycle_hint: bool,
     show_shortcuts_hint: bool,
     show_queue_hint: bool,
+    queue_key: KeyBinding,
 ) -> (SummaryLeft, bool) {
     let hint_kind = if show_queue_hint {
         SummaryHintKind::QueueMessage
@@ -305,7 +343,7 @@ pub(crate) fn single_line_footer_layout(
         hint: hint_kind,
         show_cycle_hint,
     };
-    let default_line = left_side_line(collaboration_mode_indicator, default_state);
+    let default_line = left_side_line(collaboration_mode_indicator, default_state, queue_key);
     let default_width = default_line.width() as u16;
     if default_width > 0 && can_show_left_with_context(area, default_width, context_width) {
         return (SummaryLeft::Default, true);
@@ -315,7 +353,7 @@ pub(crate) fn single_line_footer_layout(
         if state == default_state {
             default_line.clone()
         } else {
-            left_side_line(collaboration_mode_indicator, state)
+            left_side_line(collaboration_mode_indicator, state, queue_key)
         }
     };
     let state_width = |state: LeftSideState| -> u16 { state_line(state).width() as u16 };
@@ -423,8 +461,12 @@ pub(crate) fn single_line_footer_layout(
         };
         // Compute the width without going through `state_line` so we do not
         // depend on `default_state` (which may still be a queue variant).
-        let mode_only_width =
-            left_side_line(Some(collaboration_mode_indicator), mode_only_state).width() as u16;
+        let mode_only_width = left_side_line(
+            Some(collaboration_mode_indicator),
+            mode_only_state,
+            queue_key,
+        )
+        .width() as u16;
         if !context_requires_cycle_hint
             && can_show_left_with_context(area, mode_only_width, context_width)
         {
@@ -432,6 +474,7 @@ pub(crate) fn single_line_footer_layout(
                 SummaryLeft::Custom(left_side_line(
                     Some(collaboration_mode_indicator),
                     mode_only_state,
+                    queue_key,
                 )),
                 true, // show_context
             );
@@ -441,6 +484,7 @@ pub(crate) fn single_line_footer_layout(
                 SummaryLeft::Custom(left_side_line(
                     Some(collaboration_mode_indicator),
                     mode_only_state,
+                    queue_key,
                 )),
                 false, // show_context
             );
@@ -586,7 +630,11 @@ fn footer_from_props_lines(
                 },
                 show_cycle_hint,
             };
-            vec![left_side_line(collaboration_mode_indicator, state)]
+            vec![left_side_line(
+                collaboration_mode_indicator,
+                state,
+                props.key_hints.queue,
+            )]
         }
         FooterMode::ShortcutOverlay => {
             let state = ShortcutsState {
@@ -594,10 +642,14 @@ fn footer_from_props_lines(
                 esc_backtrack_hint: props.esc_backtrack_hint,
                 is_wsl: props.is_wsl,
                 collaboration_modes_enabled: props.collaboration_modes_enabled,
+                key_hints: props.key_hints,
             };
             shortcut_overlay_lines(state)
         }
-        FooterMode::EscHint => vec![esc_hint_line(props.esc_backtrack_hint)],
+        FooterMode::EscHint => vec![esc_hint_line(
+            props.esc_backtrack_hint,
+            props.key_hints.backtrack,
+        )],
         FooterMode::ComposerHasDraft => {
             let state = LeftSideState {
                 hint: if show_queue_hint {
@@ -607,7 +659,11 @@ fn footer_from_props_lines(
                 },
                 show_cycle_hint,
             };
-            vec![left_side_line(collaboration_mode_indicator, state)]
+            vec![left_side_line(
+                collaboration_mode_indicator,
+                state,
+                props.key_hints.queue,
+            )]
         }
     }
 }
@@ -657,14 +713,14 @@ struct ShortcutsState {
     esc_backtrack_hint: bool,
     is_wsl: bool,
     collaboration_modes_enabled: bool,
+    key_hints: FooterKeyHints,
 }
 
 fn quit_shortcut_reminder_line(key: KeyBinding) -> Line<'static> {
     Line::from(vec![key.into(), " again to quit".into()]).dim()
 }
 
-fn esc_hint_line(esc_backtrack_hint: bool) -> Line<'static> {
-    let esc = key_hint::plain(KeyCode::Esc);
+fn esc_hint_line(esc_backtrack_hint: bool, esc: KeyBinding) -> Line<'static> {
     if esc_backtrack_hint {
         Line::from(vec![esc.into(), " again to edit previous message".into()]).dim()
     } else {
@@ -790,6 +846,28 @@ pub(crate) fn context_window_line(percent: Option<i64>, used_tokens: Option<i64>
     Line::from(vec![Span::from("100% context left").dim()])
 }
 
+/// Prefixes the right-side footer line with the composer's vim mode, e.g.
+/// `NORMAL · 100% context left`.
+pub(crate) fn with_vim_mode(
+    mode: Option<VimMode>,
+    line: Option<Line<'static>>,
+) -> Option<Line<'static>> {
+    let Some(mode) = mode else {
+        return line;
+    };
+    let label = match mode {
+        VimMode::Normal => Span::from("NORMAL").cyan().bold(),
+        VimMode::Insert => Span::from("INSERT").dim(),
+        VimMode::Visual => Span::from("VISUAL").magenta().bold(),
+    };
+    let mut spans = vec![label];
+    if let Some(line) = line {
+        spans.push(" · ".dim());
+        spans.extend(line.spans);
+    }
+    Some(Line::from(spans))
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 enum ShortcutId {
     Commands,
@@ -820,8 +898,6 @@ impl ShortcutBinding {
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 enum DisplayCondition {
     Always,
-    WhenShiftEnterHint,
-    WhenNotShiftEnterHint,
     WhenUnderWSL,
     WhenCollaborationModesEnabled,
 }
@@ -830,8 +906,6 @@ impl DisplayCondition {
     fn matches(self, state: ShortcutsState) -> bool {
         match self {
             DisplayCondition::Always => true,
-            DisplayCondition::WhenShiftEnterHint => state.use_shift_enter_hint,
-            DisplayCondition::WhenNotShiftEnterHint => !state.use_shift_enter_hint,
             DisplayCondition::WhenUnderWSL => state.is_wsl,
             DisplayCondition::WhenCollaborationModesEnabled => state.collaboration_modes_enabled,
         }
@@ -840,19 +914,33 @@ impl DisplayCondition {
 
 struct ShortcutDescriptor {
     id: ShortcutId,
+    /// Fixed bindings. Empty for shortcuts whose key comes from the user's keymap.
     bindings: &'static [ShortcutBinding],
     prefix: &'static str,
     label: &'static str,
 }
 
 impl ShortcutDescriptor {
-    fn binding_for(&self, state: ShortcutsState) -> Option<&'static ShortcutBinding> {
-        self.bindings.iter().find(|binding| binding.matches(state))
+    fn binding_for(&self, state: ShortcutsState) -> Option<KeyBinding> {
+        let hints = state.key_hints;
+        match self.id {
+            ShortcutId::InsertNewline if state.use_shift_enter_hint => Some(hints.enhanced_newline),
+            ShortcutId::InsertNewline => Some(hints.newline),
+            ShortcutId::QueueMessageTab => Some(hints.queue),
+            ShortcutId::ExternalEditor => Some(hints.open_editor),
+            ShortcutId::EditPrevious => Some(hints.backtrack),
+            ShortcutId::ShowTranscript => Some(hints.toggle_transcript),
+            _ => self
+                .bindings
+                .iter()
+                .find(|binding| binding.matches(state))
+                .map(|binding| binding.key),
+        }
     }
 
     fn overlay_entry(&self, state: ShortcutsState) -> Option<Line<'static>> {
-        let binding = self.binding_for(state)?;
-        let mut line = Line::from(vec![self.prefix.into(), binding.key.into()]);
+        let key = self.binding_for(state)?;
+        let mut line = Line::from(vec![self.prefix.into(), key.into()]);
         match self.id {
             ShortcutId::EditPrevious => {
                 if state.esc_backtrack_hint {
@@ -860,7 +948,7 @@ impl ShortcutDescriptor {
                 } else {
                     line.extend(vec![
                         " ".into(),
-                        key_hint::plain(KeyCode::Esc).into(),
+                        key.into(),
                         " to edit previous message".into(),
                     ]);
                 }
@@ -892,25 +980,13 @@ const SHORTCUTS: &[ShortcutDescriptor] = &[
     },
     ShortcutDescriptor {
         id: ShortcutId::InsertNewline,
-        bindings: &[
-            ShortcutBinding {
-                key: key_hint::shift(KeyCode::Enter),
-                condition: DisplayCondition::WhenShiftEnterHint,
-            },
-            ShortcutBinding {
-                key: key_hint::ctrl(KeyCode::Char('j')),
-                condition: DisplayCondition::WhenNotShiftEnterHint,
-            },
-        ],
+        bindings: &[],
         prefix: "",
         label: " for newline",
     },
     ShortcutDescriptor {
         id: ShortcutId::QueueMessageTab,
-        bindings: &[ShortcutBinding {
-            key: key_hint::plain(KeyCode::Tab),
-            condition: DisplayCondition::Always,
-        }],
+        bindings: &[],
         prefix: "",
         label: " to queue message",
     },
@@ -942,19 +1018,13 @@ const SHORTCUTS: &[ShortcutDescriptor] = &[
     },
     ShortcutDescriptor {
         id: ShortcutId::ExternalEditor,
-        bindings: &[ShortcutBinding {
-            key: key_hint::ctrl(KeyCode::Char('g')),
-            condition: DisplayCondition::Always,
-        }],
+        bindings: &[],
         prefix: "",
         label: " to edit in external editor",
     },
     ShortcutDescriptor {
         id: ShortcutId::EditPrevious,
-        bindings: &[ShortcutBinding {
-            key: key_hint::plain(KeyCode::Esc),
-            condition: DisplayCondition::Always,
-        }],
+        bindings: &[],
         prefix: "",
         label: "",
     },
@@ -969,10 +1039,7 @@ const SHORTCUTS: &[ShortcutDescriptor] = &[
     },
     ShortcutDescriptor {
         id: ShortcutId::ShowTranscript,
-        bindings: &[ShortcutBinding {
-            key: key_hint::ctrl(KeyCode::Char('t')),
-            condition: DisplayCondition::Always,
-        }],
+        bindings: &[],
         prefix: "",
         label: " to view transcript",
     },
@@ -1105,6 +1172,7 @@ mod tests {
                         show_cycle_hint,
                         show_shortcuts_hint,
                         show_queue_hint,
+                        props.key_hints.queue,
                     );
                     match summary_left {
                         SummaryLeft::Default => {
@@ -1197,6 +1265,7 @@ mod tests {
                 context_window_used_tokens: None,
                 status_line_value: None,
                 status_line_enabled: false,
+                key_hints: FooterKeyHints::default(),
             },
         );
 
@@ -1215,6 +1284,7 @@ mod tests {
                 context_window_used_tokens: None,
                 status_line_value: None,
                 status_line_enabled: false,
+                key_hints: FooterKeyHints::default(),
             },
         );
 
@@ -1233,6 +1303,7 @@ mod tests {
                 context_window_used_tokens: None,
                 status_line_value: None,
                 status_line_enabled: false,
+                key_hints: FooterKeyHints::default(),
             },
         );
 
@@ -1251,6 +1322,7 @@ mod tests {
                 context_window_used_tokens: None,
                 status_line_value: None,
                 status_line_enabled: false,
+                key_hints: FooterKeyHints::default(),
             },
         );
 
@@ -1269,6 +1341,7 @@ mod tests {
                 context_window_used_tokens: None,
                 status_line_value: None,
                 status_line_enabled: false,
+                key_hints: FooterKeyHints::default(),
             },
         );
 
@@ -1287,6 +1360,7 @@ mod tests {
                 context_window_used_tokens: None,
                 status_line_value: None,
                 status_line_enabled: false,
+                key_hints: FooterKeyHints::default(),
             },
         );
 
@@ -1305,6 +1379,7 @@ mod tests {
                 context_window_used_tokens: None,
                 status_line_value: None,
                 status_line_enabled: false,
+                key_hints: FooterKeyHints::default(),
             },
         );
 
@@ -1323,6 +1398,7 @@ mod tests {
                 context_window_used_tokens: None,
                 status_line_value: None,
                 status_line_enabled: false,
+                key_hints: FooterKeyHints::default(),
             },
         );
 
@@ -1341,6 +1417,7 @@ mod tests {
                 context_window_used_tokens: Some(123_456),
                 status_line_value: None,
                 status_line_enabled: false,
+                key_hints: FooterKeyHints::default(),
             },
         );
 
@@ -1359,6 +1436,7 @@ mod tests {
                 context_window_used_tokens: None,
                 status_line_value: None,
                 status_line_enabled: false,
+                key_hints: FooterKeyHints::default(),
             },
         );
 
@@ -1377,6 +1455,7 @@ mod tests {
                 context_window_used_tokens: None,
                 status_line_value: None,
                 status_line_enabled: false,
+                key_hints: FooterKeyHints::default(),
             },
         );
 
@@ -1393,6 +1472,7 @@ mod tests {
             context_window_used_tokens: None,
             status_line_value: None,
             status_line_enabled: false,
+            key_hints: FooterKeyHints::default(),
         };
 
         snapshot_footer_with_mode_indicator(
@@ -1422,6 +1502,7 @@ mod tests {
             context_window_used_tokens: None,
             status_line_value: None,
             status_line_enabled: false,
+            key_hints: FooterKeyHints::default(),
         };
 
         snapshot_footer_with_mode_indicator(
@@ -1444,6 +1525,7 @@ mod tests {
             context_window_used_tokens: None,
             status_line_value: Some(Line::from("Status line content".to_string())),
             status_line_enabled: true,
+            key_hints: FooterKeyHints::default(),
         };
 
         snapshot_footer("footer_status_line_overrides_shortcuts", props);
@@ -1461,6 +1543,7 @@ mod tests {
             context_window_used_tokens: None,
             status_line_value: None, // command timed out / empty
             status_line_enabled: true,
+            key_hints: FooterKeyHints::default(),
         };
 
         snapshot_footer_with_mode_indicator(
@@ -1483,6 +1566,7 @@ mod tests {
             context_window_used_tokens: None,
             status_line_value: None,
             status_line_enabled: false,
+            key_hints: FooterKeyHints::default(),
         };
 
         snapshot_footer_with_mode_indicator(
@@ -1505,6 +1589,7 @@ mod tests {
             context_window_used_tokens: None,
             status_line_value: None,
             status_line_enabled: true,
+            key_hints: FooterKeyHints::default(),
         };
 
         // has status line and no collaboration mode
@@ -1530,6 +1615,7 @@ mod tests {
                 "Status line content that should truncate before the mode indicator".to_string(),
             )),
             status_line_enabled: true,
+            key_hints: FooterKeyHints::default(),
         };
 
         snapshot_footer_with_mode_indicator(
@@ -1558,6 +1644,7 @@ mod tests {
                     .to_string(),
             )),
             status_line_enabled: true,
+            key_hints: FooterKeyHints::default(),
         };
 
         let screen =
@@ -1607,9 +1694,9 @@ mod tests {
                 esc_backtrack_hint: false,
                 is_wsl,
                 collaboration_modes_enabled: false,
+                key_hints: FooterKeyHints::default(),
             })
-            .expect("shortcut binding")
-            .key;
+            .expect("shortcut binding");
 
         assert_eq!(actual_key, expected_key);
     }
@@ -22,6 +22,7 @@ use crate::bottom_pane::queued_user_messages::QueuedUserMessages;
 use crate::bottom_pane::unified_exec_footer::UnifiedExecFooter;
 use crate::key_hint;
 use crate::key_hint::KeyBinding;
+use crate::keymap::Keymap;
 use crate::render::renderable::FlexRenderable;
 use crate::render::renderable::Renderable;
 use crate::render::renderable::RenderableItem;
@@ -30,6 +31,8 @@ use bottom_pane_view::BottomPaneView;
 use codex_core::features::Features;
 use codex_core::skills::model::SkillMetadata;
 use codex_file_search::FileMatch;
+use codex_protocol::protocol::BackgroundProcess;
+use codex_protocol::protocol::BackgroundProcessOutputEvent;
 use codex_protocol::request_user_input::RequestUserInputEvent;
 use codex_protocol::user_input::TextElement;
 use crossterm::event::KeyCode;
@@ -41,12 +44,14 @@ use std::time::Duration;
 
 mod app_link_view;
 mod approval_overlay;
+mod background_processes_view;
 mod multi_select_picker;
 mod request_user_input;
 mod status_line_setup;
 pub(crate) use app_link_view::AppLinkView;
 pub(crate) use approval_overlay::ApprovalOverlay;
 pub(crate) use approval_overlay::ApprovalRequest;
+use background_processes_view::BackgroundProcessesView;
 pub(crate) use request_user_input::RequestUserInputOverlay;
 mod bottom_pane_view;
 
@@ -86,6 +91,7 @@ mod scroll_state;
 mod selection_popup_common;
 mod textarea;
 mod unified_exec_footer;
+mod vim;
 pub(crate) use feedback_view::FeedbackNoteView;
 
 /// How long the "press again to quit" hint stays visible.
@@ -148,6 +154,7 @@ pub(crate) struct BottomPane {
     is_task_running: bool,
     esc_backtrack_hint: bool,
     animations_enabled: bool,
+    keymap: Keymap,
 
     /// Inline status indicator shown above the composer while a task is running.
     status: Option<StatusIndicatorWidget>,
@@ -205,11 +212,34 @@ impl BottomPane {
             queued_user_messages: QueuedUserMessages::new(),
             esc_backtrack_hint: false,
             animations_enabled,
+            keymap: Keymap::default(),
             context_window_percent: None,
             context_window_used_tokens: None,
         }
     }
 
+    /// Apply the user's key bindings to the composer, status indicator and future approvals.
+    pub(crate) fn set_keymap(&mut self, keymap: Keymap) {
+        self.composer.set_keymap(keymap.clone());
+        if let Some(status) = self.status.as_mut() {
+            status.set_interrupt_key(keymap.interrupt.primary());
+        }
+        self.keymap = keymap;
+        self.request_redraw();
+    }
+
+    /// Turn vi-style modal editing in the composer on or off (`tui.vim_mode`).
+    pub(crate) fn set_vim_mode_enabled(&mut self, enabled: bool) {
+        self.composer.set_vim_mode_enabled(enabled);
+        self.request_redraw();
+    }
+
+    /// True when the composer's vim mode wants `key_event` (Esc leaving insert or visual
+    /// mode) instead of it interrupting a task or priming backtrack.
+    pub(crate) fn composer_captures_key(&self, key_event: KeyEvent) -> bool {
+        self.composer.vim_captures_key(key_event)
+    }
+
     pub fn set_skills(&mut self, skills: Option<Vec<SkillMetadata>>) {
         self.composer.set_skill_mentions(skills);
         self.request_redraw();
@@ -338,12 +368,13 @@ impl BottomPane {
             self.request_redraw();
             InputResult::None
         } else {
-            // If a task is running and a status line is visible, allow Esc to
+            // If a task is running and a status line is visible, allow the interrupt key to
             // send an interrupt even while the composer has focus.
             // When a popup is active, prefer dismissing it over interrupting the task.
-            if key_event.code == KeyCode::Esc
+            if self.keymap.interrupt.is_press(key_event)
                 && self.is_task_running
                 && !self.composer.popup_active()
+                && !self.composer.vim_captures_key(key_event)
                 && let Some(status) = &self.status
             {
                 // Send Op::Interrupt
@@ -581,11 +612,7 @@ impl BottomPane {
         if running {
             if !was_running {
                 if self.status.is_none() {
-                    self.status = Some(StatusIndicatorWidget::new(
-                        self.app_event_tx.clone(),
-                        self.frame_requester.clone(),
-                        self.animations_enabled,
-                    ));
+                    self.status = Some(self.new_status_indicator());
                 }
                 if let Some(status) = self.status.as_mut() {
                     status.set_interrupt_hint_visible(true);
@@ -598,6 +625,16 @@ impl BottomPane {
         }
     }
 
+    fn new_status_indicator(&self) -> StatusIndicatorWidget {
+        let mut status = StatusIndicatorWidget::new(
+            self.app_event_tx.clone(),
+            self.frame_requester.clone(),
+            self.animations_enabled,
+        );
+        status.set_interrupt_key(self.keymap.interrupt.primary());
+        status
+    }
+
     /// Hide the status indicator while leaving task-running state untouched.
     pub(crate) fn hide_status_indicator(&mut self) {
         if self.status.take().is_some() {
@@ -607,11 +644,7 @@ impl BottomPane {
 
     pub(crate) fn ensure_status_indicator(&mut self) {
         if self.status.is_none() {
-            self.status = Some(StatusIndicatorWidget::new(
-                self.app_event_tx.clone(),
-                self.frame_requester.clone(),
-                self.animations_enabled,
-            ));
+            self.status = Some(self.new_status_indicator());
             self.request_redraw();
         }
     }
@@ -708,11 +741,41 @@ impl BottomPane {
         };
 
         // Otherwise create a new approval modal overlay.
-        let modal = ApprovalOverlay::new(request, self.app_event_tx.clone(), features.clone());
+        let modal = ApprovalOverlay::new(
+            request,
+            self.app_event_tx.clone(),
+            features.clone(),
+            self.keymap.clone(),
+        );
         self.pause_status_timer_for_modal();
         self.push_view(Box::new(modal));
     }
 
+    /// Open the background terminals overlay and start polling the processes.
+    pub(crate) fn show_background_processes(&mut self, processes: Vec<BackgroundProcess>) {
+        let view = BackgroundProcessesView::new(processes, self.app_event_tx.clone());
+        view.spawn_poller();
+        self.push_view(Box::new(view));
+    }
+
+    /// Forward a refreshed process list to an open background terminals overlay.
+    pub(crate) fn on_background_processes(&mut self, processes: Vec<BackgroundProcess>) {
+        if let Some(view) = self.view_stack.last_mut()
+            && view.try_consume_background_processes(processes).is_none()
+        {
+            self.request_redraw();
+        }
+    }
+
+    /// Forward process output to an open background terminals overlay.
+    pub(crate) fn on_background_process_output(&mut self, output: BackgroundProcessOutputEvent) {
+        if let Some(view) = self.view_stack.last_mut()
+            && view.try_consume_background_process_output(output).is_none()
+        {
+            self.request_redraw();
+        }
+    }
+
     /// Called when the agent requests user input.
     pub fn push_user_input_request(&mut self, request: RequestUserInputEvent) {
         let request = if let Some(view) = self.view_stack.last_mut() {
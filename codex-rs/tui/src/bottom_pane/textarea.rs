@@ -7,6 +7,7 @@ use crossterm::event::KeyModifiers;
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
 use ratatui::style::Color;
+use ratatui::style::Modifier;
 use ratatui::style::Style;
 use ratatui::widgets::StatefulWidgetRef;
 use ratatui::widgets::WidgetRef;
@@ -36,6 +37,8 @@ pub(crate) struct TextArea {
     preferred_col: Option<usize>,
     elements: Vec<TextElement>,
     kill_buffer: String,
+    /// Byte range highlighted as a selection (vim visual mode).
+    selection: Option<Range<usize>>,
 }
 
 #[derive(Debug, Clone)]
@@ -59,6 +62,7 @@ impl TextArea {
             preferred_col: None,
             elements: Vec::new(),
             kill_buffer: String::new(),
+            selection: None,
         }
     }
 
@@ -96,6 +100,7 @@ impl TextArea {
         self.wrap_cache.replace(None);
         self.preferred_col = None;
         self.kill_buffer.clear();
+        self.selection = None;
     }
 
     pub fn text(&self) -> &str {
@@ -165,6 +170,11 @@ impl TextArea {
         self.preferred_col = None;
     }
 
+    /// Highlight `selection` when rendering; `None` clears the highlight.
+    pub(crate) fn set_selection(&mut self, selection: Option<Range<usize>>) {
+        self.selection = selection;
+    }
+
     pub fn desired_height(&self, width: u16) -> u16 {
         self.wrapped_lines(width).len() as u16
     }
@@ -224,14 +234,14 @@ impl TextArea {
         self.cursor_pos = self.clamp_pos_to_nearest_boundary(self.cursor_pos);
     }
 
-    fn beginning_of_line(&self, pos: usize) -> usize {
+    pub(crate) fn beginning_of_line(&self, pos: usize) -> usize {
         self.text[..pos].rfind('\n').map(|i| i + 1).unwrap_or(0)
     }
     fn beginning_of_current_line(&self) -> usize {
         self.beginning_of_line(self.cursor_pos)
     }
 
-    fn end_of_line(&self, pos: usize) -> usize {
+    pub(crate) fn end_of_line(&self, pos: usize) -> usize {
         self.text[pos..]
             .find('\n')
             .map(|i| i + pos)
@@ -1001,7 +1011,7 @@ impl TextArea {
         self.shift_elements(start, end.saturating_sub(start), inserted_len);
     }
 
-    fn prev_atomic_boundary(&self, pos: usize) -> usize {
+    pub(crate) fn prev_atomic_boundary(&self, pos: usize) -> usize {
         if pos == 0 {
             return 0;
         }
@@ -1027,7 +1037,7 @@ impl TextArea {
         }
     }
 
-    fn next_atomic_boundary(&self, pos: usize) -> usize {
+    pub(crate) fn next_atomic_boundary(&self, pos: usize) -> usize {
         if pos >= self.text.len() {
             return self.text.len();
         }
@@ -1229,6 +1239,18 @@ impl TextArea {
                 let style = Style::default().fg(Color::Cyan);
                 buf.set_string(area.x + x_off, y, styled, style);
             }
+
+            if let Some(selection) = &self.selection {
+                let overlap_start = selection.start.max(line_range.start);
+                let overlap_end = selection.end.min(line_range.end);
+                if overlap_start < overlap_end {
+                    let selected = &self.text[overlap_start..overlap_end];
+                    let x_off = self.text[line_range.start..overlap_start].width() as u16;
+                    let width = selected.width() as u16;
+                    let rect = Rect::new(area.x + x_off, y, width, 1).intersection(area);
+                    buf.set_style(rect, Style::default().add_modifier(Modifier::REVERSED));
+                }
+            }
         }
     }
 
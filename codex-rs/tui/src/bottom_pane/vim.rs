@@ -0,0 +1,1141 @@
+//! Optional vi-style modal editing for the composer.
+//!
+//! `VimState` sits in front of [`TextArea::input`]. In insert mode almost every key falls
+//! through to the regular editing path (so paste bursts, popups and emacs-style shortcuts keep
+//! working); in normal and visual mode plain characters are interpreted here as counts,
+//! motions, operators and text objects. Keys with Ctrl/Alt, Enter, Tab and the arrow keys are
+//! passed through in every mode so submission, history navigation and global shortcuts behave
+//! the same as without vim mode.
+//!
+//! Edits go through the textarea's element-aware primitives, so paste and image placeholders
+//! still behave as a single character.
+
+use super::textarea::TextArea;
+use codex_protocol::user_input::TextElement as UserTextElement;
+use crossterm::event::KeyCode;
+use crossterm::event::KeyEvent;
+use crossterm::event::KeyEventKind;
+use crossterm::event::KeyModifiers;
+use std::ops::Range;
+
+/// Oldest undo states are dropped beyond this depth.
+const MAX_UNDO_DEPTH: usize = 100;
+/// Counts are clamped so a stray `99999999dd` cannot spin for long.
+const MAX_COUNT: usize = 10_000;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum VimMode {
+    Normal,
+    Insert,
+    Visual,
+}
+
+/// Whether the vim layer consumed a key or the composer should handle it as usual.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum VimAction {
+    Handled,
+    PassThrough,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Operator {
+    Delete,
+    Change,
+    Yank,
+}
+
+impl Operator {
+    fn from_char(ch: char) -> Option<Self> {
+        match ch {
+            'd' => Some(Operator::Delete),
+            'c' => Some(Operator::Change),
+            'y' => Some(Operator::Yank),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Pending {
+    None,
+    /// First `g` of `gg`.
+    G,
+    /// `i` or `a` typed after an operator; waiting for the object (`w`, `"`, ...).
+    TextObject {
+        inner: bool,
+    },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Motion {
+    Left,
+    Right,
+    Up,
+    Down,
+    WordForward,
+    WordBackward,
+    WordEnd,
+    LineStart,
+    FirstNonBlank,
+    LineEnd,
+    FirstLine,
+    LastLine,
+}
+
+impl Motion {
+    fn from_char(ch: char) -> Option<Self> {
+        match ch {
+            'h' => Some(Motion::Left),
+            'l' => Some(Motion::Right),
+            'k' => Some(Motion::Up),
+            'j' => Some(Motion::Down),
+            'w' => Some(Motion::WordForward),
+            'b' => Some(Motion::WordBackward),
+            'e' => Some(Motion::WordEnd),
+            '0' => Some(Motion::LineStart),
+            '^' => Some(Motion::FirstNonBlank),
+            '$' => Some(Motion::LineEnd),
+            'G' => Some(Motion::LastLine),
+            _ => None,
+        }
+    }
+
+    /// Motions that make an operator act on whole lines.
+    fn is_linewise(self) -> bool {
+        matches!(
+            self,
+            Motion::Up | Motion::Down | Motion::FirstLine | Motion::LastLine
+        )
+    }
+}
+
+#[derive(Debug, Default)]
+struct Register {
+    text: String,
+    /// Yanked with `yy`/`dd`/`j`/`k`; pasted on its own line.
+    linewise: bool,
+}
+
+#[derive(Debug)]
+struct Snapshot {
+    text: String,
+    elements: Vec<UserTextElement>,
+    cursor: usize,
+}
+
+impl Snapshot {
+    fn capture(textarea: &TextArea) -> Self {
+        Self {
+            text: textarea.text().to_string(),
+            elements: textarea.text_elements(),
+            cursor: textarea.cursor(),
+        }
+    }
+
+    fn restore(&self, textarea: &mut TextArea) {
+        textarea.set_text_with_elements(&self.text, &self.elements);
+        textarea.set_cursor(self.cursor);
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct VimState {
+    mode: VimMode,
+    count: Option<usize>,
+    /// Operator waiting for a motion, with the count typed before it.
+    operator: Option<(Operator, usize)>,
+    pending: Pending,
+    visual_anchor: usize,
+    register: Register,
+    undo_stack: Vec<Snapshot>,
+    redo_stack: Vec<Snapshot>,
+}
+
+impl VimState {
+    /// New drafts start in insert mode so typing works immediately.
+    pub(crate) fn new() -> Self {
+        Self {
+            mode: VimMode::Insert,
+            count: None,
+            operator: None,
+            pending: Pending::None,
+            visual_anchor: 0,
+            register: Register::default(),
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
+        }
+    }
+
+    pub(crate) fn mode(&self) -> VimMode {
+        self.mode
+    }
+
+    /// True when Esc has a vim meaning right now (leave insert/visual mode or cancel a
+    /// half-typed command) and should not trigger interrupt or backtrack.
+    pub(crate) fn captures_esc(&self) -> bool {
+        self.mode != VimMode::Normal || self.has_pending()
+    }
+
+    /// Back to insert mode with a fresh undo history, e.g. after the draft was submitted.
+    pub(crate) fn reset(&mut self, textarea: &mut TextArea) {
+        self.mode = VimMode::Insert;
+        self.clear_pending();
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+        textarea.set_selection(None);
+    }
+
+    pub(crate) fn handle_key(&mut self, key: KeyEvent, textarea: &mut TextArea) -> VimAction {
+        if key.kind == KeyEventKind::Release {
+            return VimAction::PassThrough;
+        }
+        let action = match self.mode {
+            VimMode::Insert => self.handle_insert_key(key, textarea),
+            VimMode::Normal => self.handle_normal_key(key, textarea),
+            VimMode::Visual => self.handle_visual_key(key, textarea),
+        };
+        if self.mode == VimMode::Visual {
+            textarea.set_selection(Some(self.visual_range(textarea)));
+        }
+        action
+    }
+
+    fn handle_insert_key(&mut self, key: KeyEvent, textarea: &mut TextArea) -> VimAction {
+        if key.code == KeyCode::Esc && key.modifiers == KeyModifiers::NONE {
+            self.enter_normal(textarea);
+            // Like vi, leaving insert mode steps back onto the last inserted character.
+            if textarea.cursor() > textarea.beginning_of_line(textarea.cursor()) {
+                textarea.move_cursor_left();
+            }
+            return VimAction::Handled;
+        }
+        VimAction::PassThrough
+    }
+
+    fn handle_normal_key(&mut self, key: KeyEvent, textarea: &mut TextArea) -> VimAction {
+        let Some(ch) = plain_char(key) else {
+            return self.handle_special_key(key, textarea);
+        };
+
+        match self.pending {
+            Pending::G => {
+                self.pending = Pending::None;
+                if ch == 'g' {
+                    self.motion_or_operate(Motion::FirstLine, textarea);
+                } else {
+                    self.clear_pending();
+                }
+                return VimAction::Handled;
+            }
+            Pending::TextObject { inner } => {
+                let operator = self.operator.take();
+                self.clear_pending();
+                if let Some((operator, _)) = operator
+                    && let Some(range) = text_object(textarea, ch, inner)
+                {
+                    self.apply_operator(operator, range, textarea);
+                }
+                return VimAction::Handled;
+            }
+            Pending::None => {}
+        }
+
+        if self.push_count_digit(ch) {
+            return VimAction::Handled;
+        }
+
+        if let Some((operator, _)) = self.operator {
+            match ch {
+                'i' | 'a' => self.pending = Pending::TextObject { inner: ch == 'i' },
+                'g' => self.pending = Pending::G,
+                _ if Operator::from_char(ch) == Some(operator) => {
+                    let count = self.take_operator_count();
+                    let cursor = textarea.cursor();
+                    let last = line_down(textarea, cursor, count - 1);
+                    self.apply_linewise(operator, cursor, last, textarea);
+                }
+                _ => match Motion::from_char(ch) {
+                    Some(motion) => self.motion_or_operate(motion, textarea),
+                    None => self.clear_pending(),
+                },
+            }
+            return VimAction::Handled;
+        }
+
+        if let Some(motion) = Motion::from_char(ch) {
+            self.motion_or_operate(motion, textarea);
+            return VimAction::Handled;
+        }
+
+        let count = self.take_count();
+        match ch {
+            'g' => self.pending = Pending::G,
+            'd' | 'c' | 'y' => {
+                self.operator = Operator::from_char(ch).map(|operator| (operator, count));
+            }
+            'x' => {
+                let cursor = textarea.cursor();
+                let end = advance_right(textarea, cursor, count, true);
+                self.apply_operator(Operator::Delete, cursor..end, textarea);
+            }
+            'X' => {
+                let cursor = textarea.cursor();
+                let start = advance_left(textarea, cursor, count);
+                self.apply_operator(Operator::Delete, start..cursor, textarea);
+            }
+            's' => {
+                let cursor = textarea.cursor();
+                let end = advance_right(textarea, cursor, count, true);
+                self.apply_operator(Operator::Change, cursor..end, textarea);
+            }
+            'D' | 'C' => {
+                let cursor = textarea.cursor();
+                let end = textarea.end_of_line(cursor);
+                let operator = if ch == 'D' {
+                    Operator::Delete
+                } else {
+                    Operator::Change
+                };
+                self.apply_operator(operator, cursor..end, textarea);
+            }
+            'S' | 'Y' => {
+                let cursor = textarea.cursor();
+                let last = line_down(textarea, cursor, count - 1);
+                let operator = if ch == 'S' {
+                    Operator::Change
+                } else {
+                    Operator::Yank
+                };
+                self.apply_linewise(operator, cursor, last, textarea);
+            }
+            'i' => self.enter_insert(textarea),
+            'a' => {
+                let cursor = textarea.cursor();
+                if cursor < textarea.end_of_line(cursor) {
+                    textarea.move_cursor_right();
+                }
+                self.enter_insert(textarea);
+            }
+            'I' => {
+                let target = first_non_blank(textarea, textarea.cursor());
+                textarea.set_cursor(target);
+                self.enter_insert(textarea);
+            }
+            'A' => {
+                let target = textarea.end_of_line(textarea.cursor());
+                textarea.set_cursor(target);
+                self.enter_insert(textarea);
+            }
+            'o' | 'O' => {
+                self.push_undo(textarea);
+                let cursor = textarea.cursor();
+                let at = if ch == 'o' {
+                    textarea.end_of_line(cursor)
+                } else {
+                    textarea.beginning_of_line(cursor)
+                };
+                textarea.insert_str_at(at, "\n");
+                textarea.set_cursor(if ch == 'o' { at + 1 } else { at });
+                self.mode = VimMode::Insert;
+            }
+            'p' | 'P' => self.paste(ch == 'p', count, textarea),
+            'u' => self.undo(count, textarea),
+            'v' => {
+                self.mode = VimMode::Visual;
+                self.visual_anchor = textarea.cursor();
+            }
+            // Unbound characters are swallowed so they never leak into the draft.
+            _ => {}
+        }
+        VimAction::Handled
+    }
+
+    fn handle_visual_key(&mut self, key: KeyEvent, textarea: &mut TextArea) -> VimAction {
+        let Some(ch) = plain_char(key) else {
+            return self.handle_special_key(key, textarea);
+        };
+
+        if self.pending == Pending::G {
+            self.pending = Pending::None;
+            if ch == 'g' {
+                self.motion_or_operate(Motion::FirstLine, textarea);
+            }
+            return VimAction::Handled;
+        }
+        if self.push_count_digit(ch) {
+            return VimAction::Handled;
+        }
+        if let Some(motion) = Motion::from_char(ch) {
+            self.motion_or_operate(motion, textarea);
+            return VimAction::Handled;
+        }
+
+        let selection = self.visual_range(textarea);
+        match ch {
+            'g' => self.pending = Pending::G,
+            'v' => self.enter_normal(textarea),
+            'o' => {
+                let cursor = textarea.cursor();
+                textarea.set_cursor(self.visual_anchor);
+                self.visual_anchor = cursor;
+            }
+            'd' | 'x' => {
+                self.enter_normal(textarea);
+                self.apply_operator(Operator::Delete, selection, textarea);
+            }
+            'c' | 's' => {
+                self.enter_normal(textarea);
+                self.apply_operator(Operator::Change, selection, textarea);
+            }
+            'y' => {
+                self.enter_normal(textarea);
+                self.apply_operator(Operator::Yank, selection, textarea);
+            }
+            _ => self.clear_pending(),
+        }
+        VimAction::Handled
+    }
+
+    /// Non-character keys in normal and visual mode.
+    fn handle_special_key(&mut self, key: KeyEvent, textarea: &mut TextArea) -> VimAction {
+        match key {
+            KeyEvent {
+                code: KeyCode::Esc,
+                modifiers: KeyModifiers::NONE,
+                ..
+            } if self.captures_esc() => {
+                self.enter_normal(textarea);
+                VimAction::Handled
+            }
+            KeyEvent {
+                code: KeyCode::Char('r'),
+                modifiers: KeyModifiers::CONTROL,
+                ..
+            } if self.mode == VimMode::Normal => {
+                let count = self.take_count();
+                self.redo(count, textarea);
+                VimAction::Handled
+            }
+            _ => {
+                self.clear_pending();
+                VimAction::PassThrough
+            }
+        }
+    }
+
+    fn push_count_digit(&mut self, ch: char) -> bool {
+        let Some(digit) = ch.to_digit(10) else {
+            return false;
+        };
+        // A leading `0` is the line-start motion, not a count.
+        if digit == 0 && self.count.is_none() {
+            return false;
+        }
+        let count = self.count.unwrap_or(0).saturating_mul(10) + digit as usize;
+        self.count = Some(count.min(MAX_COUNT));
+        true
+    }
+
+    fn take_count(&mut self) -> usize {
+        self.count.take().unwrap_or(1)
+    }
+
+    /// `2d3w` deletes six words: the counts around the operator multiply.
+    fn take_operator_count(&mut self) -> usize {
+        let before = self.operator.take().map_or(1, |(_, count)| count);
+        (before * self.take_count()).min(MAX_COUNT)
+    }
+
+    fn has_pending(&self) -> bool {
+        self.count.is_some() || self.operator.is_some() || self.pending != Pending::None
+    }
+
+    fn clear_pending(&mut self) {
+        self.count = None;
+        self.operator = None;
+        self.pending = Pending::None;
+    }
+
+    fn enter_normal(&mut self, textarea: &mut TextArea) {
+        self.mode = VimMode::Normal;
+        self.clear_pending();
+        textarea.set_selection(None);
+    }
+
+    fn enter_insert(&mut self, textarea: &mut TextArea) {
+        self.push_undo(textarea);
+        self.mode = VimMode::Insert;
+    }
+
+    /// Moves the cursor, or applies the pending operator over the motion.
+    fn motion_or_operate(&mut self, motion: Motion, textarea: &mut TextArea) {
+        if let Some((operator, _)) = self.operator {
+            let count = self.take_operator_count();
+            self.operate_over_motion(operator, motion, count, textarea);
+            return;
+        }
+
+        let count = self.take_count();
+        let cursor = textarea.cursor();
+        match motion {
+            Motion::Up | Motion::Down => {
+                let at_edge = if motion == Motion::Up {
+                    textarea.beginning_of_line(cursor) == 0
+                } else {
+                    textarea.end_of_line(cursor) == textarea.text().len()
+                };
+                if !at_edge {
+                    for _ in 0..count {
+                        if motion == Motion::Up {
+                            textarea.move_cursor_up();
+                        } else {
+                            textarea.move_cursor_down();
+                        }
+                    }
+                }
+            }
+            _ => {
+                let target = motion_target(textarea, cursor, motion, count, false);
+                textarea.set_cursor(target);
+            }
+        }
+        self.clamp_cursor(textarea);
+    }
+
+    fn operate_over_motion(
+        &mut self,
+        operator: Operator,
+        motion: Motion,
+        count: usize,
+        textarea: &mut TextArea,
+    ) {
+        let cursor = textarea.cursor();
+        if motion.is_linewise() {
+            let other = match motion {
+                Motion::Up => line_up(textarea, cursor, count),
+                Motion::Down => line_down(textarea, cursor, count),
+                Motion::FirstLine => 0,
+                _ => textarea.text().len(),
+            };
+            self.apply_linewise(operator, cursor.min(other), cursor.max(other), textarea);
+            return;
+        }
+
+        // `cw` on a word behaves like `ce`: the trailing whitespace survives.
+        let motion = if operator == Operator::Change
+            && motion == Motion::WordForward
+            && char_at(textarea.text(), cursor).is_some_and(|ch| !ch.is_whitespace())
+        {
+            Motion::WordEnd
+        } else {
+            motion
+        };
+        let target = motion_target(textarea, cursor, motion, count, true);
+        let (start, mut end) = if target < cursor {
+            (target, cursor)
+        } else {
+            (cursor, target)
+        };
+        if motion == Motion::WordEnd {
+            end = textarea.next_atomic_boundary(end);
+        }
+        // `dw` on the last word of a line stops at the line end.
+        if motion == Motion::WordForward && textarea.text()[start..end].contains('\n') {
+            end = end.min(textarea.end_of_line(start)).max(start);
+        }
+        self.apply_operator(operator, start..end, textarea);
+    }
+
+    fn apply_linewise(
+        &mut self,
+        operator: Operator,
+        first: usize,
+        last: usize,
+        textarea: &mut TextArea,
+    ) {
+        let start = textarea.beginning_of_line(first);
+        let end = textarea.end_of_line(last);
+        let len = textarea.text().len();
+        let range = match operator {
+            // Keep one (now empty) line to type into.
+            Operator::Change | Operator::Yank => start..end,
+            Operator::Delete if end < len => start..end + 1,
+            Operator::Delete if start > 0 => start - 1..end,
+            Operator::Delete => start..end,
+        };
+        self.register = Register {
+            text: textarea.text()[start..end].to_string(),
+            linewise: true,
+        };
+        match operator {
+            Operator::Yank => {}
+            Operator::Delete => {
+                self.push_undo(textarea);
+                textarea.replace_range(range, "");
+                let pos = textarea.cursor().min(textarea.text().len());
+                let target = first_non_blank(textarea, textarea.beginning_of_line(pos));
+                textarea.set_cursor(target);
+            }
+            Operator::Change => {
+                self.push_undo(textarea);
+                textarea.replace_range(range.clone(), "");
+                textarea.set_cursor(range.start);
+                self.mode = VimMode::Insert;
+            }
+        }
+        self.clamp_cursor(textarea);
+    }
+
+    fn apply_operator(&mut self, operator: Operator, range: Range<usize>, textarea: &mut TextArea) {
+        if range.is_empty() && operator != Operator::Change {
+            return;
+        }
+        self.register = Register {
+            text: textarea.text()[range.clone()].to_string(),
+            linewise: false,
+        };
+        match operator {
+            Operator::Yank => textarea.set_cursor(range.start),
+            Operator::Delete => {
+                self.push_undo(textarea);
+                textarea.replace_range(range.clone(), "");
+                textarea.set_cursor(range.start);
+            }
+            Operator::Change => {
+                self.push_undo(textarea);
+                textarea.replace_range(range.clone(), "");
+                textarea.set_cursor(range.start);
+                self.mode = VimMode::Insert;
+            }
+        }
+        self.clamp_cursor(textarea);
+    }
+
+    fn paste(&mut self, after: bool, count: usize, textarea: &mut TextArea) {
+        if self.register.text.is_empty() && !self.register.linewise {
+            return;
+        }
+        self.push_undo(textarea);
+        let cursor = textarea.cursor();
+        if self.register.linewise {
+            let block = vec![self.register.text.as_str(); count].join("\n");
+            if after {
+                let at = textarea.end_of_line(cursor);
+                textarea.insert_str_at(at, &format!("\n{block}"));
+                textarea.set_cursor(at + 1);
+            } else {
+                let at = textarea.beginning_of_line(cursor);
+                textarea.insert_str_at(at, &format!("{block}\n"));
+                textarea.set_cursor(at);
+            }
+        } else {
+            let block = self.register.text.repeat(count);
+            let at = if after && cursor < textarea.end_of_line(cursor) {
+                textarea.next_atomic_boundary(cursor)
+            } else {
+                cursor
+            };
+            textarea.insert_str_at(at, &block);
+            let end = at + block.len();
+            textarea.set_cursor(textarea.prev_atomic_boundary(end).max(at));
+        }
+        self.clamp_cursor(textarea);
+    }
+
+    fn push_undo(&mut self, textarea: &TextArea) {
+        // Entering and leaving insert mode without typing should not add an empty undo step.
+        if self
+            .undo_stack
+            .last()
+            .is_some_and(|snapshot| snapshot.text == textarea.text())
+        {
+            return;
+        }
+        self.undo_stack.push(Snapshot::capture(textarea));
+        if self.undo_stack.len() > MAX_UNDO_DEPTH {
+            self.undo_stack.remove(0);
+        }
+        self.redo_stack.clear();
+    }
+
+    fn undo(&mut self, count: usize, textarea: &mut TextArea) {
+        for _ in 0..count {
+            let Some(snapshot) = self.undo_stack.pop() else {
+                break;
+            };
+            self.redo_stack.push(Snapshot::capture(textarea));
+            snapshot.restore(textarea);
+        }
+        self.clamp_cursor(textarea);
+    }
+
+    fn redo(&mut self, count: usize, textarea: &mut TextArea) {
+        for _ in 0..count {
+            let Some(snapshot) = self.redo_stack.pop() else {
+                break;
+            };
+            self.undo_stack.push(Snapshot::capture(textarea));
+            snapshot.restore(textarea);
+        }
+        self.clamp_cursor(textarea);
+    }
+
+    /// In normal and visual mode the cursor sits on a character, never past the line end.
+    fn clamp_cursor(&self, textarea: &mut TextArea) {
+        if self.mode == VimMode::Insert {
+            return;
+        }
+        let cursor = textarea.cursor();
+        if cursor == textarea.end_of_line(cursor) && cursor > textarea.beginning_of_line(cursor) {
+            textarea.set_cursor(textarea.prev_atomic_boundary(cursor));
+        }
+    }
+
+    /// The selection includes the character under the cursor, as in vi.
+    fn visual_range(&self, textarea: &TextArea) -> Range<usize> {
+        let cursor = textarea.cursor();
+        let anchor = self.visual_anchor.min(textarea.text().len());
+        let start = anchor.min(cursor);
+        let end = textarea.next_atomic_boundary(anchor.max(cursor));
+        start..end
+    }
+}
+
+fn plain_char(key: KeyEvent) -> Option<char> {
+    match key {
+        KeyEvent {
+            code: KeyCode::Char(ch),
+            modifiers: KeyModifiers::NONE | KeyModifiers::SHIFT,
+            ..
+        } => Some(ch),
+        _ => None,
+    }
+}
+
+fn motion_target(
+    textarea: &TextArea,
+    cursor: usize,
+    motion: Motion,
+    count: usize,
+    for_operator: bool,
+) -> usize {
+    let text = textarea.text();
+    match motion {
+        Motion::Left => advance_left(textarea, cursor, count),
+        Motion::Right => advance_right(textarea, cursor, count, for_operator),
+        Motion::Up => line_up(textarea, cursor, count),
+        Motion::Down => line_down(textarea, cursor, count),
+        Motion::WordForward => (0..count).fold(cursor, |pos, _| word_forward(text, pos)),
+        Motion::WordBackward => (0..count).fold(cursor, |pos, _| word_backward(text, pos)),
+        Motion::WordEnd => (0..count).fold(cursor, |pos, _| word_end(text, pos)),
+        Motion::LineStart => textarea.beginning_of_line(cursor),
+        Motion::FirstNonBlank => first_non_blank(textarea, cursor),
+        Motion::LineEnd => textarea.end_of_line(cursor),
+        Motion::FirstLine => 0,
+        Motion::LastLine => textarea.beginning_of_line(text.len()),
+    }
+}
+
+fn advance_left(textarea: &TextArea, pos: usize, count: usize) -> usize {
+    let bol = textarea.beginning_of_line(pos);
+    (0..count).fold(pos, |pos, _| textarea.prev_atomic_boundary(pos).max(bol))
+}
+
+/// Moves right within the line. Outside of an operator the cursor stops on the last character.
+fn advance_right(textarea: &TextArea, pos: usize, count: usize, allow_line_end: bool) -> usize {
+    let eol = textarea.end_of_line(pos);
+    (0..count).fold(pos, |pos, _| {
+        let next = textarea.next_atomic_boundary(pos).min(eol);
+        if next < eol || allow_line_end {
+            next
+        } else {
+            pos
+        }
+    })
+}
+
+/// Start of the line `count` lines above `pos`.
+fn line_up(textarea: &TextArea, pos: usize, count: usize) -> usize {
+    (0..count).fold(textarea.beginning_of_line(pos), |bol, _| {
+        if bol == 0 {
+            0
+        } else {
+            textarea.beginning_of_line(bol - 1)
+        }
+    })
+}
+
+/// End of the line `count` lines below `pos`.
+fn line_down(textarea: &TextArea, pos: usize, count: usize) -> usize {
+    let len = textarea.text().len();
+    (0..count).fold(textarea.end_of_line(pos), |eol, _| {
+        if eol >= len {
+            len
+        } else {
+            textarea.end_of_line(eol + 1)
+        }
+    })
+}
+
+fn first_non_blank(textarea: &TextArea, pos: usize) -> usize {
+    let text = textarea.text();
+    let bol = textarea.beginning_of_line(pos);
+    let eol = textarea.end_of_line(pos);
+    text[bol..eol]
+        .char_indices()
+        .find(|(_, ch)| !matches!(ch, ' ' | '\t'))
+        .map_or(eol, |(offset, _)| bol + offset)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum CharClass {
+    Whitespace,
+    Word,
+    Punctuation,
+}
+
+fn char_class(ch: char) -> CharClass {
+    if ch.is_whitespace() {
+        CharClass::Whitespace
+    } else if ch.is_alphanumeric() || ch == '_' {
+        CharClass::Word
+    } else {
+        CharClass::Punctuation
+    }
+}
+
+fn char_at(text: &str, pos: usize) -> Option<char> {
+    text.get(pos..)?.chars().next()
+}
+
+fn char_before(text: &str, pos: usize) -> Option<(usize, char)> {
+    text.get(..pos)?.char_indices().next_back()
+}
+
+/// `w`: start of the next word, where runs of word characters and runs of punctuation are
+/// separate words.
+fn word_forward(text: &str, mut pos: usize) -> usize {
+    if let Some(ch) = char_at(text, pos) {
+        let class = char_class(ch);
+        if class != CharClass::Whitespace {
+            while let Some(ch) = char_at(text, pos)
+                && char_class(ch) == class
+            {
+                pos += ch.len_utf8();
+            }
+        }
+    }
+    while let Some(ch) = char_at(text, pos)
+        && ch.is_whitespace()
+    {
+        pos += ch.len_utf8();
+    }
+    pos
+}
+
+/// `b`: start of the current or previous word.
+fn word_backward(text: &str, mut pos: usize) -> usize {
+    while let Some((idx, ch)) = char_before(text, pos)
+        && ch.is_whitespace()
+    {
+        pos = idx;
+    }
+    if let Some((_, ch)) = char_before(text, pos) {
+        let class = char_class(ch);
+        while let Some((idx, ch)) = char_before(text, pos)
+            && char_class(ch) == class
+        {
+            pos = idx;
+        }
+    }
+    pos
+}
+
+/// `e`: last character of the current or next word.
+fn word_end(text: &str, pos: usize) -> usize {
+    let Some(ch) = char_at(text, pos) else {
+        return pos;
+    };
+    let mut pos = pos + ch.len_utf8();
+    while let Some(ch) = char_at(text, pos)
+        && ch.is_whitespace()
+    {
+        pos += ch.len_utf8();
+    }
+    let Some(ch) = char_at(text, pos) else {
+        return char_before(text, pos).map_or(pos, |(idx, _)| idx);
+    };
+    let class = char_class(ch);
+    while let Some(ch) = char_at(text, pos) {
+        let next = pos + ch.len_utf8();
+        match char_at(text, next) {
+            Some(next_ch) if char_class(next_ch) == class => pos = next,
+            _ => break,
+        }
+    }
+    pos
+}
+
+fn text_object(textarea: &TextArea, object: char, inner: bool) -> Option<Range<usize>> {
+    let cursor = textarea.cursor();
+    match object {
+        'w' => word_object(textarea.text(), cursor, inner),
+        '"' | '\'' | '`' => {
+            let bol = textarea.beginning_of_line(cursor);
+            let eol = textarea.end_of_line(cursor);
+            quote_object(textarea.text(), cursor, object, inner, bol..eol)
+        }
+        _ => None,
+    }
+}
+
+/// `iw` is the run of same-class characters under the cursor; `aw` adds the whitespace after
+/// it (or before it, at the end of a line).
+fn word_object(text: &str, cursor: usize, inner: bool) -> Option<Range<usize>> {
+    let ch = char_at(text, cursor).filter(|ch| *ch != '\n')?;
+    let class = char_class(ch);
+    let same_run = |ch: char| ch != '\n' && char_class(ch) == class;
+    let is_blank = |ch: char| matches!(ch, ' ' | '\t');
+
+    let mut start = cursor;
+    while let Some((idx, ch)) = char_before(text, start)
+        && same_run(ch)
+    {
+        start = idx;
+    }
+    let mut end = cursor + ch.len_utf8();
+    while let Some(ch) = char_at(text, end)
+        && same_run(ch)
+    {
+        end += ch.len_utf8();
+    }
+    if inner {
+        return Some(start..end);
+    }
+
+    if class == CharClass::Whitespace {
+        // `aw` on blanks takes the following word too.
+        if let Some(ch) = char_at(text, end).filter(|ch| *ch != '\n') {
+            let next_class = char_class(ch);
+            while let Some(ch) = char_at(text, end)
+                && ch != '\n'
+                && char_class(ch) == next_class
+            {
+                end += ch.len_utf8();
+            }
+        }
+        return Some(start..end);
+    }
+
+    let word_end = end;
+    while let Some(ch) = char_at(text, end)
+        && is_blank(ch)
+    {
+        end += ch.len_utf8();
+    }
+    if end == word_end {
+        while let Some((idx, ch)) = char_before(text, start)
+            && is_blank(ch)
+        {
+            start = idx;
+        }
+    }
+    Some(start..end)
+}
+
+/// Quoted string on the cursor line that contains the cursor, or the first one after it.
+fn quote_object(
+    text: &str,
+    cursor: usize,
+    quote: char,
+    inner: bool,
+    line: Range<usize>,
+) -> Option<Range<usize>> {
+    let quotes: Vec<usize> = text[line.clone()]
+        .char_indices()
+        .filter(|(_, ch)| *ch == quote)
+        .map(|(idx, _)| line.start + idx)
+        .collect();
+    let (open, close) = quotes
+        .chunks_exact(2)
+        .map(|pair| (pair[0], pair[1]))
+        .find(|(_, close)| *close >= cursor)?;
+    if inner {
+        Some(open + quote.len_utf8()..close)
+    } else {
+        let mut end = close + quote.len_utf8();
+        while let Some(ch) = char_at(text, end)
+            && matches!(ch, ' ' | '\t')
+            && end < line.end
+        {
+            end += ch.len_utf8();
+        }
+        Some(open..end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn textarea(text: &str, cursor: usize) -> TextArea {
+        let mut textarea = TextArea::new();
+        textarea.insert_str(text);
+        textarea.set_cursor(cursor);
+        textarea
+    }
+
+    fn normal(text: &str, cursor: usize) -> (VimState, TextArea) {
+        let mut vim = VimState::new();
+        vim.mode = VimMode::Normal;
+        (vim, textarea(text, cursor))
+    }
+
+    fn press(vim: &mut VimState, textarea: &mut TextArea, keys: &str) {
+        for ch in keys.chars() {
+            let code = if ch == '\u{1b}' {
+                KeyCode::Esc
+            } else {
+                KeyCode::Char(ch)
+            };
+            let key = KeyEvent::new(code, KeyModifiers::NONE);
+            if vim.handle_key(key, textarea) == VimAction::PassThrough {
+                textarea.input(key);
+            }
+        }
+    }
+
+    #[test]
+    fn esc_leaves_insert_mode_and_steps_back() {
+        let mut vim = VimState::new();
+        let mut textarea = textarea("hello", 5);
+
+        assert_eq!(
+            vim.handle_key(
+                KeyEvent::new(KeyCode::Char('x'), KeyModifiers::NONE),
+                &mut textarea
+            ),
+            VimAction::PassThrough
+        );
+        assert!(vim.captures_esc());
+        press(&mut vim, &mut textarea, "\u{1b}");
+
+        assert_eq!(vim.mode(), VimMode::Normal);
+        assert_eq!(textarea.cursor(), 4);
+        assert!(!vim.captures_esc());
+    }
+
+    #[test]
+    fn word_motions_and_line_motions() {
+        let (mut vim, mut textarea) = normal("foo.bar baz\n  qux", 0);
+
+        press(&mut vim, &mut textarea, "w");
+        assert_eq!(textarea.cursor(), 3);
+        press(&mut vim, &mut textarea, "2w");
+        assert_eq!(textarea.cursor(), 8);
+        press(&mut vim, &mut textarea, "e");
+        assert_eq!(textarea.cursor(), 10);
+        press(&mut vim, &mut textarea, "b");
+        assert_eq!(textarea.cursor(), 8);
+        press(&mut vim, &mut textarea, "$");
+        assert_eq!(textarea.cursor(), 10);
+        press(&mut vim, &mut textarea, "0");
+        assert_eq!(textarea.cursor(), 0);
+        press(&mut vim, &mut textarea, "G^");
+        assert_eq!(textarea.cursor(), 14);
+        press(&mut vim, &mut textarea, "gg");
+        assert_eq!(textarea.cursor(), 0);
+    }
+
+    #[test]
+    fn operators_take_counts_and_fill_the_register() {
+        let (mut vim, mut textarea) = normal("one two three four", 0);
+
+        press(&mut vim, &mut textarea, "2dw");
+        assert_eq!(textarea.text(), "three four");
+
+        press(&mut vim, &mut textarea, "yep");
+        assert_eq!(textarea.text(), "tthreehree four");
+
+        press(&mut vim, &mut textarea, "u");
+        assert_eq!(textarea.text(), "three four");
+
+        press(&mut vim, &mut textarea, "cwfive\u{1b}");
+        assert_eq!(textarea.text(), "five four");
+        assert_eq!(vim.mode(), VimMode::Normal);
+
+        press(&mut vim, &mut textarea, "$D");
+        assert_eq!(textarea.text(), "five fou");
+    }
+
+    #[test]
+    fn linewise_delete_and_paste() {
+        let (mut vim, mut textarea) = normal("a\nb\nc\nd", 2);
+
+        press(&mut vim, &mut textarea, "2dd");
+        assert_eq!(textarea.text(), "a\nd");
+        assert_eq!(textarea.cursor(), 2);
+
+        press(&mut vim, &mut textarea, "P");
+        assert_eq!(textarea.text(), "a\nb\nc\nd");
+
+        press(&mut vim, &mut textarea, "Gddk");
+        assert_eq!(textarea.text(), "a\nb\nc");
+
+        press(&mut vim, &mut textarea, "ggyyjp");
+        assert_eq!(textarea.text(), "a\nb\na\nc");
+    }
+
+    #[test]
+    fn text_objects_for_words_and_quotes() {
+        let (mut vim, mut textarea) = normal(r#"say "hello there" now"#, 7);
+
+        press(&mut vim, &mut textarea, "ci\"bye\u{1b}");
+        assert_eq!(textarea.text(), r#"say "bye" now"#);
+
+        press(&mut vim, &mut textarea, "0da\"");
+        assert_eq!(textarea.text(), "say now");
+
+        press(&mut vim, &mut textarea, "0daw");
+        assert_eq!(textarea.text(), "now");
+    }
+
+    #[test]
+    fn visual_mode_selects_and_deletes() {
+        let (mut vim, mut textarea) = normal("hello world", 0);
+
+        press(&mut vim, &mut textarea, "vl");
+        assert_eq!(vim.mode(), VimMode::Visual);
+        assert_eq!(vim.visual_range(&textarea), 0..2);
+
+        press(&mut vim, &mut textarea, "ed");
+        assert_eq!(textarea.text(), " world");
+        assert_eq!(vim.mode(), VimMode::Normal);
+
+        press(&mut vim, &mut textarea, "u");
+        assert_eq!(textarea.text(), "hello world");
+    }
+
+    #[test]
+    fn undo_and_redo_cover_insert_sessions() {
+        let (mut vim, mut textarea) = normal("abc", 0);
+
+        press(&mut vim, &mut textarea, "A");
+        assert_eq!(vim.mode(), VimMode::Insert);
+        textarea.insert_str("def");
+        press(&mut vim, &mut textarea, "\u{1b}x");
+        assert_eq!(textarea.text(), "abcde");
+
+        press(&mut vim, &mut textarea, "u");
+        assert_eq!(textarea.text(), "abcdef");
+        press(&mut vim, &mut textarea, "u");
+        assert_eq!(textarea.text(), "abc");
+
+        vim.handle_key(
+            KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL),
+            &mut textarea,
+        );
+        assert_eq!(textarea.text(), "abcdef");
+    }
+}
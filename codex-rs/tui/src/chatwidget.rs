@@ -41,6 +41,8 @@ use crate::version::CODEX_CLI_VERSION;
 use codex_app_server_protocol::ConfigLayerSource;
 use codex_backend_client::Client as BackendClient;
 use codex_chatgpt::connectors;
+use codex_core::RolloutRecorder;
+use codex_core::command_secrets::CommandSecrets;
 use codex_core::config::Config;
 use codex_core::config::ConstraintResult;
 use codex_core::config::types::Notifications;
@@ -73,8 +75,12 @@ use codex_core::protocol::ExecCommandEndEvent;
 use codex_core::protocol::ExecCommandOutputDeltaEvent;
 use codex_core::protocol::ExecCommandSource;
 use codex_core::protocol::ExitedReviewModeEvent;
+use codex_core::protocol::ListBackgroundProcessesResponseEvent;
 use codex_core::protocol::ListCustomPromptsResponseEvent;
 use codex_core::protocol::ListSkillsResponseEvent;
+use codex_core::protocol::ListUndoSnapshotsResponseEvent;
+use codex_core::protocol::McpListChangedEvent;
+use codex_core::protocol::McpListChangedKind;
 use codex_core::protocol::McpListToolsResponseEvent;
 use codex_core::protocol::McpStartupCompleteEvent;
 use codex_core::protocol::McpStartupStatus;
@@ -84,6 +90,7 @@ use codex_core::protocol::McpToolCallEndEvent;
 use codex_core::protocol::Op;
 use codex_core::protocol::PatchApplyBeginEvent;
 use codex_core::protocol::RateLimitSnapshot;
+use codex_core::protocol::RestorableBackgroundProcessesEvent;
 use codex_core::protocol::ReviewRequest;
 use codex_core::protocol::ReviewTarget;
 use codex_core::protocol::SkillMetadata as ProtocolSkillMetadata;
@@ -95,6 +102,7 @@ use codex_core::protocol::TurnAbortReason;
 use codex_core::protocol::TurnCompleteEvent;
 use codex_core::protocol::TurnDiffEvent;
 use codex_core::protocol::UndoCompletedEvent;
+use codex_core::protocol::UndoSnapshot;
 use codex_core::protocol::UndoStartedEvent;
 use codex_core::protocol::UserMessageEvent;
 use codex_core::protocol::ViewImageToolCallEvent;
@@ -102,6 +110,9 @@ use codex_core::protocol::WarningEvent;
 use codex_core::protocol::WebSearchBeginEvent;
 use codex_core::protocol::WebSearchEndEvent;
 use codex_core::skills::model::SkillMetadata;
+use codex_core::transcript_export::ExportFormat;
+use codex_core::transcript_export::ExportOptions;
+use codex_core::transcript_export::export_transcript;
 #[cfg(target_os = "windows")]
 use codex_core::windows_sandbox::WindowsSandboxLevelExt;
 use codex_otel::OtelManager;
@@ -115,7 +126,7 @@ use codex_protocol::config_types::Personality;
 use codex_protocol::config_types::Settings;
 #[cfg(target_os = "windows")]
 use codex_protocol::config_types::WindowsSandboxLevel;
-use codex_protocol::models::local_image_label_text;
+use codex_protocol::models::local_attachment_label_text;
 use codex_protocol::parse_command::ParsedCommand;
 use codex_protocol::request_user_input::RequestUserInputEvent;
 use codex_protocol::user_input::TextElement;
@@ -134,6 +145,7 @@ use ratatui::style::Stylize;
 use ratatui::text::Line;
 use ratatui::widgets::Paragraph;
 use ratatui::widgets::Wrap;
+use tokio::io::AsyncWriteExt;
 use tokio::sync::mpsc::UnboundedSender;
 use tokio::task::JoinHandle;
 use tracing::debug;
@@ -186,6 +198,7 @@ use crate::history_cell::PlainHistoryCell;
 use crate::history_cell::WebSearchCell;
 use crate::key_hint;
 use crate::key_hint::KeyBinding;
+use crate::keymap::Keymap;
 use crate::markdown::append_markdown;
 use crate::render::Insets;
 use crate::render::renderable::ColumnRenderable;
@@ -292,9 +305,14 @@ fn is_unified_exec_source(source: ExecCommandSource) -> bool {
 
 fn is_standard_tool_call(parsed_cmd: &[ParsedCommand]) -> bool {
     !parsed_cmd.is_empty()
-        && parsed_cmd
-            .iter()
-            .all(|parsed| !matches!(parsed, ParsedCommand::Unknown { .. }))
+        && parsed_cmd.iter().all(|parsed| {
+            matches!(
+                parsed,
+                ParsedCommand::Read { .. }
+                    | ParsedCommand::ListFiles { .. }
+                    | ParsedCommand::Search { .. }
+            )
+        })
 }
 
 const RATE_LIMIT_WARNING_THRESHOLDS: [f64; 3] = [75.0, 90.0, 95.0];
@@ -516,6 +534,9 @@ pub(crate) struct ChatWidget {
     unified_exec_wait_streak: Option<UnifiedExecWaitStreak>,
     task_complete_pending: bool,
     unified_exec_processes: Vec<UnifiedExecProcessSummary>,
+    /// Set by `/ps` so the next process list opens the background terminals
+    /// overlay instead of refreshing an open one.
+    background_processes_requested: bool,
     /// Tracks whether codex-core currently considers an agent turn to be in progress.
     ///
     /// This is kept separate from `mcp_startup_status` so that MCP startup progress (or completion)
@@ -576,6 +597,9 @@ pub(crate) struct ChatWidget {
     had_work_activity: bool,
     // Whether the current turn emitted a plan update.
     saw_plan_update_this_turn: bool,
+    // MCP list changes already announced since the last turn started, so a
+    // server that sends a burst of `list_changed` notifications adds one cell.
+    announced_mcp_list_changes: Vec<(String, McpListChangedKind)>,
     // Whether the current turn emitted a proposed plan item.
     saw_plan_item_this_turn: bool,
     // Incremental buffer for streamed plan content.
@@ -677,7 +701,7 @@ pub(crate) fn create_initial_user_message(
             .into_iter()
             .enumerate()
             .map(|(idx, path)| LocalImageAttachment {
-                placeholder: local_image_label_text(idx + 1),
+                placeholder: local_attachment_label_text(&path, idx + 1),
                 path,
             })
             .collect();
@@ -691,7 +715,7 @@ pub(crate) fn create_initial_user_message(
 }
 
 // When merging multiple queued drafts (e.g., after interrupt), each draft starts numbering
-// its attachments at [Image #1]. Reassign placeholder labels based on the attachment list so
+// its attachments at [Image #1] (or [PDF #1]). Reassign placeholder labels based on the attachment list so
 // the combined local_image_paths order matches the labels, even if placeholders were moved
 // in the text (e.g., [Image #2] appearing before [Image #1]).
 fn remap_placeholders_for_message(message: UserMessage, next_label: &mut usize) -> UserMessage {
@@ -713,7 +737,7 @@ fn remap_placeholders_for_message(message: UserMessage, next_label: &mut usize)
     let mut mapping: HashMap<String, String> = HashMap::new();
     let mut remapped_images = Vec::new();
     for attachment in local_images {
-        let new_placeholder = local_image_label_text(*next_label);
+        let new_placeholder = local_attachment_label_text(&attachment.path, *next_label);
         *next_label += 1;
         mapping.insert(attachment.placeholder.clone(), new_placeholder.clone());
         remapped_images.push(LocalImageAttachment {
@@ -1214,6 +1238,7 @@ impl ChatWidget {
         self.agent_turn_running = true;
         self.saw_plan_update_this_turn = false;
         self.saw_plan_item_this_turn = false;
+        self.announced_mcp_list_changes.clear();
         self.plan_delta_buffer.clear();
         self.plan_item_active = false;
         self.adaptive_chunking.reset();
@@ -1592,6 +1617,22 @@ impl ChatWidget {
         self.request_redraw();
     }
 
+    fn on_mcp_list_changed(&mut self, ev: McpListChangedEvent) {
+        let change = (ev.server.clone(), ev.kind);
+        if self.announced_mcp_list_changes.contains(&change) {
+            return;
+        }
+        self.announced_mcp_list_changes.push(change);
+        let list = match ev.kind {
+            McpListChangedKind::Tools => "tools",
+            McpListChangedKind::Resources => "resources",
+        };
+        self.add_info_message(
+            format!("MCP server `{}` updated its {list}.", ev.server),
+            Some("Run /mcp to see the current list.".to_string()),
+        );
+    }
+
     /// Handle a turn aborted due to user interrupt (Esc).
     /// When there are queued user messages, restore them into the composer
     /// separated by newlines rather than auto‑submitting the next one.
@@ -2270,6 +2311,7 @@ impl ChatWidget {
             reason: ev.reason,
             changes: ev.changes.clone(),
             cwd: self.config.cwd.clone(),
+            hunks: ev.hunks,
         };
         self.bottom_pane
             .push_approval_request(request, &self.config.features);
@@ -2503,6 +2545,7 @@ impl ChatWidget {
             unified_exec_wait_streak: None,
             task_complete_pending: false,
             unified_exec_processes: Vec::new(),
+            background_processes_requested: false,
             agent_turn_running: false,
             mcp_startup_status: None,
             connectors_cache: ConnectorsCacheState::default(),
@@ -2525,6 +2568,7 @@ impl ChatWidget {
             needs_final_message_separator: false,
             had_work_activity: false,
             saw_plan_update_this_turn: false,
+            announced_mcp_list_changes: Vec::new(),
             saw_plan_item_this_turn: false,
             plan_delta_buffer: String::new(),
             plan_item_active: false,
@@ -2556,6 +2600,12 @@ impl ChatWidget {
         widget.bottom_pane.set_collaboration_modes_enabled(
             widget.config.features.enabled(Feature::CollaborationModes),
         );
+        widget
+            .bottom_pane
+            .set_keymap(Keymap::from_config_or_default(&widget.config.tui_keymap));
+        widget
+            .bottom_pane
+            .set_vim_mode_enabled(widget.config.tui_vim_mode);
         widget.sync_personality_command_enabled();
         #[cfg(target_os = "windows")]
         widget.bottom_pane.set_windows_degraded_sandbox_active(
@@ -2664,6 +2714,7 @@ impl ChatWidget {
             unified_exec_wait_streak: None,
             task_complete_pending: false,
             unified_exec_processes: Vec::new(),
+            background_processes_requested: false,
             agent_turn_running: false,
             mcp_startup_status: None,
             connectors_cache: ConnectorsCacheState::default(),
@@ -2676,6 +2727,7 @@ impl ChatWidget {
             thread_name: None,
             forked_from: None,
             saw_plan_update_this_turn: false,
+            announced_mcp_list_changes: Vec::new(),
             saw_plan_item_this_turn: false,
             plan_delta_buffer: String::new(),
             plan_item_active: false,
@@ -2717,6 +2769,12 @@ impl ChatWidget {
         widget.bottom_pane.set_collaboration_modes_enabled(
             widget.config.features.enabled(Feature::CollaborationModes),
         );
+        widget
+            .bottom_pane
+            .set_keymap(Keymap::from_config_or_default(&widget.config.tui_keymap));
+        widget
+            .bottom_pane
+            .set_vim_mode_enabled(widget.config.tui_vim_mode);
         widget.sync_personality_command_enabled();
 
         widget
@@ -2814,6 +2872,7 @@ impl ChatWidget {
             unified_exec_wait_streak: None,
             task_complete_pending: false,
             unified_exec_processes: Vec::new(),
+            background_processes_requested: false,
             agent_turn_running: false,
             mcp_startup_status: None,
             connectors_cache: ConnectorsCacheState::default(),
@@ -2836,6 +2895,7 @@ impl ChatWidget {
             needs_final_message_separator: false,
             had_work_activity: false,
             saw_plan_update_this_turn: false,
+            announced_mcp_list_changes: Vec::new(),
             saw_plan_item_this_turn: false,
             plan_delta_buffer: String::new(),
             plan_item_active: false,
@@ -2867,6 +2927,12 @@ impl ChatWidget {
         widget.bottom_pane.set_collaboration_modes_enabled(
             widget.config.features.enabled(Feature::CollaborationModes),
         );
+        widget
+            .bottom_pane
+            .set_keymap(Keymap::from_config_or_default(&widget.config.tui_keymap));
+        widget
+            .bottom_pane
+            .set_vim_mode_enabled(widget.config.tui_vim_mode);
         widget.sync_personality_command_enabled();
         #[cfg(target_os = "windows")]
         widget.bottom_pane.set_windows_degraded_sandbox_active(
@@ -3225,9 +3291,13 @@ impl ChatWidget {
                 }
                 self.request_quit_without_confirmation();
             }
-            // SlashCommand::Undo => {
-            //     self.app_event_tx.send(AppEvent::CodexOp(Op::Undo));
-            // }
+            SlashCommand::Undo => {
+                self.app_event_tx
+                    .send(AppEvent::CodexOp(Op::ListUndoSnapshots));
+            }
+            SlashCommand::Redo => {
+                self.app_event_tx.send(AppEvent::CodexOp(Op::Redo));
+            }
             SlashCommand::Diff => {
                 self.add_diff_in_progress();
                 let tx = self.app_event_tx.clone();
@@ -3261,7 +3331,8 @@ impl ChatWidget {
                 self.open_status_line_setup();
             }
             SlashCommand::Ps => {
-                self.add_ps_output();
+                self.background_processes_requested = true;
+                self.submit_op(Op::ListBackgroundProcesses);
             }
             SlashCommand::Mcp => {
                 self.add_mcp_output();
@@ -3269,6 +3340,9 @@ impl ChatWidget {
             SlashCommand::Apps => {
                 self.add_connectors_output();
             }
+            SlashCommand::Export => {
+                self.export_session_transcript("");
+            }
             SlashCommand::Rollout => {
                 if let Some(path) = self.rollout_path() {
                     self.add_info_message(
@@ -3312,6 +3386,7 @@ impl ChatWidget {
                                 },
                             ),
                         ]),
+                        hunks: Vec::new(),
                         reason: None,
                         grant_root: Some(PathBuf::from("/tmp")),
                     }),
@@ -3387,6 +3462,15 @@ impl ChatWidget {
                     self.queue_user_message(user_message);
                 }
             }
+            SlashCommand::Export if !trimmed.is_empty() => {
+                let Some((prepared_args, _prepared_elements)) =
+                    self.bottom_pane.prepare_inline_args_submission(false)
+                else {
+                    return;
+                };
+                self.export_session_transcript(&prepared_args);
+                self.bottom_pane.drain_pending_submission_state();
+            }
             SlashCommand::Review if !trimmed.is_empty() => {
                 let Some((prepared_args, _prepared_elements)) =
                     self.bottom_pane.prepare_inline_args_submission(false)
@@ -3407,6 +3491,105 @@ impl ChatWidget {
         }
     }
 
+    /// Handles `/export [md|html|json] [path] [--redact] [--omit-tool-output]` by rendering the
+    /// current rollout into `path`, or into `$CODEX_HOME/exports` when no path is given. An
+    /// existing file is never overwritten.
+    fn export_session_transcript(&mut self, args: &str) {
+        const USAGE: &str = "Usage: /export [md|html|json] [path] [--redact] [--omit-tool-output]";
+        let mut format = None;
+        let mut output = None;
+        let mut redact = false;
+        let mut omit_tool_output = false;
+        for arg in args.split_whitespace() {
+            match arg {
+                "--redact" => redact = true,
+                "--omit-tool-output" => omit_tool_output = true,
+                other if other.contains(['.', '/', std::path::MAIN_SEPARATOR]) => {
+                    output = Some(self.config.cwd.join(other));
+                }
+                other => match other.parse::<ExportFormat>() {
+                    Ok(parsed) => format = Some(parsed),
+                    Err(err) => {
+                        self.add_error_message(format!("{err}. {USAGE}"));
+                        return;
+                    }
+                },
+            }
+        }
+        let format = format
+            .or_else(|| {
+                output
+                    .as_ref()
+                    .and_then(|path| path.extension())
+                    .and_then(|extension| extension.to_str())
+                    .and_then(|extension| extension.parse().ok())
+            })
+            .unwrap_or(ExportFormat::Markdown);
+        let Some(rollout_path) = self.rollout_path() else {
+            self.add_info_message("Rollout path is not available yet.".to_string(), None);
+            return;
+        };
+        let output = output.unwrap_or_else(|| {
+            let stem = self
+                .thread_id
+                .map_or_else(|| "session".to_string(), |id| id.to_string());
+            self.config
+                .codex_home
+                .join("exports")
+                .join(format!("codex-{stem}.{}", format.extension()))
+        });
+        let config = self.config.clone();
+        let tx = self.app_event_tx.clone();
+        tokio::spawn(async move {
+            let result = async {
+                let history = RolloutRecorder::get_rollout_history(&rollout_path).await?;
+                let options = ExportOptions {
+                    redact: if redact {
+                        CommandSecrets::load(&config).await
+                    } else {
+                        CommandSecrets::default()
+                    },
+                    omit_tool_output,
+                };
+                let text = export_transcript(&history.get_rollout_items(), format, &options);
+                if let Some(parent) = output.parent() {
+                    tokio::fs::create_dir_all(parent).await?;
+                }
+                let mut file = tokio::fs::OpenOptions::new()
+                    .write(true)
+                    .create_new(true)
+                    .open(&output)
+                    .await?;
+                file.write_all(text.as_bytes()).await?;
+                file.flush().await?;
+                std::io::Result::Ok(options.redact.is_empty())
+            }
+            .await;
+            let cell = match result {
+                Ok(nothing_redacted) => {
+                    let note = (redact && nothing_redacted).then(|| {
+                        "no secrets are exposed under [secrets.expose], so nothing was redacted"
+                            .to_string()
+                    });
+                    history_cell::new_info_event(
+                        format!("Exported session to {}", output.display()),
+                        note,
+                    )
+                }
+                Err(err) if err.kind() == std::io::ErrorKind::AlreadyExists => {
+                    history_cell::new_error_event(format!(
+                        "Failed to export session: {} already exists. Pass a different path to /export.",
+                        output.display()
+                    ))
+                }
+                Err(err) => {
+                    history_cell::new_error_event(format!("Failed to export session: {err}"))
+                }
+            };
+            tx.send(AppEvent::InsertHistoryCell(Box::new(cell)));
+        });
+    }
+
     fn show_rename_prompt(&mut self) {
         let tx = self.app_event_tx.clone();
         let has_name = self
@@ -3711,6 +3894,9 @@ impl ChatWidget {
             | EventMsg::PlanDelta(_)
             | EventMsg::AgentReasoningDelta(_)
             | EventMsg::TerminalInteraction(_)
+            | EventMsg::ListBackgroundProcessesResponse(_)
+            | EventMsg::BackgroundProcessOutput(_)
+            | EventMsg::RestorableBackgroundProcesses(_)
             | EventMsg::ExecCommandOutputDelta(_) => {}
             _ => {
                 tracing::trace!("handle_codex_event: {:?}", msg);
@@ -3766,6 +3952,7 @@ impl ChatWidget {
             }
             EventMsg::McpStartupUpdate(ev) => self.on_mcp_startup_update(ev),
             EventMsg::McpStartupComplete(ev) => self.on_mcp_startup_complete(ev),
+            EventMsg::McpListChanged(ev) => self.on_mcp_list_changed(ev),
             EventMsg::TurnAborted(ev) => match ev.reason {
                 TurnAbortReason::Interrupted => {
                     self.on_interrupted_turn(ev.reason);
@@ -3806,6 +3993,16 @@ impl ChatWidget {
             EventMsg::McpListToolsResponse(ev) => self.on_list_mcp_tools(ev),
             EventMsg::ListCustomPromptsResponse(ev) => self.on_list_custom_prompts(ev),
             EventMsg::ListSkillsResponse(ev) => self.on_list_skills(ev),
+            EventMsg::ListUndoSnapshotsResponse(ev) => self.on_list_undo_snapshots(ev),
+            EventMsg::ListBackgroundProcessesResponse(ev) => {
+                self.on_list_background_processes(ev);
+            }
+            EventMsg::BackgroundProcessOutput(ev) => {
+                self.bottom_pane.on_background_process_output(ev);
+            }
+            EventMsg::RestorableBackgroundProcesses(ev) => {
+                self.open_relaunch_background_processes_popup(ev);
+            }
             EventMsg::ListRemoteSkillsResponse(_) | EventMsg::RemoteSkillDownloaded(_) => {}
             EventMsg::SkillsUpdateAvailable => {
                 self.submit_op(Op::ListSkills {
@@ -4275,6 +4472,70 @@ impl ChatWidget {
         self.add_to_history(history_cell::new_unified_exec_processes_output(processes));
     }
 
+    fn on_list_background_processes(&mut self, ev: ListBackgroundProcessesResponseEvent) {
+        if !std::mem::take(&mut self.background_processes_requested) {
+            // Periodic refresh requested by an open overlay.
+            self.bottom_pane.on_background_processes(ev.processes);
+            return;
+        }
+        if ev.processes.is_empty() {
+            self.add_ps_output();
+        } else {
+            self.bottom_pane.show_background_processes(ev.processes);
+        }
+    }
+
+    pub(crate) fn open_relaunch_background_processes_popup(
+        &mut self,
+        ev: RestorableBackgroundProcessesEvent,
+    ) {
+        let process_ids: Vec<String> = ev
+            .processes
+            .iter()
+            .map(|process| process.process_id.clone())
+            .collect();
+        let commands: Vec<String> = ev
+            .processes
+            .iter()
+            .map(|process| strip_bash_lc_and_escape(&process.command))
+            .collect();
+        let items = vec![
+            SelectionItem {
+                name: "Relaunch all".to_string(),
+                description: Some(commands.join(", ")),
+                actions: vec![Box::new(move |tx| {
+                    tx.send(AppEvent::CodexOp(Op::RelaunchBackgroundProcesses {
+                        process_ids: process_ids.clone(),
+                    }));
+                })],
+                dismiss_on_select: true,
+                ..Default::default()
+            },
+            SelectionItem {
+                name: "Don't relaunch".to_string(),
+                actions: vec![Box::new(|tx| {
+                    tx.send(AppEvent::CodexOp(Op::RelaunchBackgroundProcesses {
+                        process_ids: Vec::new(),
+                    }));
+                })],
+                dismiss_on_select: true,
+                ..Default::default()
+            },
+        ];
+
+        self.bottom_pane.show_selection_view(SelectionViewParams {
+            title: Some("Relaunch background processes from the previous session?".to_string()),
+            subtitle: Some(format!(
+                "{} running when this session was last active.",
+                plural(ev.processes.len(), "process was", "processes were")
+            )),
+            footer_hint: Some(standard_popup_hint_line()),
+            items,
+            ..Default::default()
+        });
+        self.request_redraw();
+    }
+
     fn stop_rate_limit_poller(&mut self) {
         if let Some(handle) = self.rate_limit_poller.take() {
             handle.abort();
@@ -6373,6 +6634,10 @@ impl ChatWidget {
         self.bottom_pane.composer_is_empty()
     }
 
+    pub(crate) fn composer_captures_key(&self, key_event: KeyEvent) -> bool {
+        self.bottom_pane.composer_captures_key(key_event)
+    }
+
     pub(crate) fn submit_user_message_with_mode(
         &mut self,
         text: String,
@@ -6444,6 +6709,127 @@ impl ChatWidget {
         self.set_skills_from_response(&ev);
     }
 
+    fn on_list_undo_snapshots(&mut self, ev: ListUndoSnapshotsResponseEvent) {
+        let ListUndoSnapshotsResponseEvent {
+            snapshots,
+            redo_available,
+        } = ev;
+        if snapshots.is_empty() && !redo_available {
+            self.add_info_message("No earlier turns to undo.".to_string(), None);
+            return;
+        }
+
+        let mut items: Vec<SelectionItem> = Vec::with_capacity(snapshots.len() + 1);
+        for snapshot in snapshots {
+            let name = undo_snapshot_title(&snapshot);
+            let description = undo_snapshot_summary(&snapshot);
+            let search_value = snapshot.user_message.clone();
+            items.push(SelectionItem {
+                name,
+                description: Some(description),
+                actions: vec![Box::new(move |tx| {
+                    tx.send(AppEvent::OpenUndoSnapshot(snapshot.clone()));
+                })],
+                dismiss_on_select: false,
+                search_value,
+                ..Default::default()
+            });
+        }
+        if redo_available {
+            items.push(SelectionItem {
+                name: "Redo last undo".to_string(),
+                description: Some("reapply the file changes it reverted".to_string()),
+                actions: vec![Box::new(|tx| {
+                    tx.send(AppEvent::CodexOp(Op::Redo));
+                })],
+                dismiss_on_select: true,
+                ..Default::default()
+            });
+        }
+
+        self.bottom_pane.show_selection_view(SelectionViewParams {
+            title: Some("Restore files to before a turn".to_string()),
+            subtitle: Some(
+                "File changes from that turn and every later turn are reverted.".to_string(),
+            ),
+            footer_hint: Some(standard_popup_hint_line()),
+            items,
+            ..Default::default()
+        });
+        self.request_redraw();
+    }
+
+    pub(crate) fn open_undo_snapshot_popup(&mut self, snapshot: UndoSnapshot) {
+        let UndoSnapshot {
+            id,
+            changed_files,
+            unified_diff,
+            conflicts,
+            ..
+        } = snapshot.clone();
+        let mut items: Vec<SelectionItem> = Vec::new();
+
+        items.push(SelectionItem {
+            name: "Preview changes".to_string(),
+            description: Some(format!(
+                "{} to revert",
+                plural(changed_files.len(), "file", "files")
+            )),
+            actions: vec![Box::new(move |tx| {
+                tx.send(AppEvent::DiffResult(unified_diff.clone()));
+            })],
+            dismiss_on_select: false,
+            ..Default::default()
+        });
+
+        let subtitle = if conflicts.is_empty() {
+            let snapshot_id = id.clone();
+            items.push(SelectionItem {
+                name: "Restore".to_string(),
+                actions: vec![Box::new(move |tx| {
+                    tx.send(AppEvent::CodexOp(Op::UndoTo {
+                        snapshot_id: snapshot_id.clone(),
+                        force: false,
+                    }));
+                })],
+                dismiss_on_select: true,
+                ..Default::default()
+            });
+            None
+        } else {
+            let files = conflicts
+                .iter()
+                .map(|path| path.display().to_string())
+                .collect::<Vec<_>>();
+            let snapshot_id = id.clone();
+            items.push(SelectionItem {
+                name: "Restore and overwrite outside changes".to_string(),
+                description: Some(proper_join(&files)),
+                actions: vec![Box::new(move |tx| {
+                    tx.send(AppEvent::CodexOp(Op::UndoTo {
+                        snapshot_id: snapshot_id.clone(),
+                        force: true,
+                    }));
+                })],
+                dismiss_on_select: true,
+                ..Default::default()
+            });
+            Some(format!(
+                "{} changed outside Codex since the last turn.",
+                plural(conflicts.len(), "file", "files")
+            ))
+        };
+
+        self.bottom_pane.show_selection_view(SelectionViewParams {
+            title: Some(undo_snapshot_title(&snapshot)),
+            subtitle,
+            footer_hint: Some(standard_popup_hint_line()),
+            items,
+            ..Default::default()
+        });
+        self.request_redraw();
+    }
+
     pub(crate) fn on_connectors_loaded(&mut self, result: Result<ConnectorsSnapshot, String>) {
         self.connectors_cache = match result {
             Ok(connectors) => ConnectorsCacheState::Ready(connectors),
@@ -6500,6 +6886,28 @@ impl ChatWidget {
             ..Default::default()
         });
 
+        items.push(SelectionItem {
+            name: "Review a range of commits".to_string(),
+            description: Some("(base commit to HEAD)".into()),
+            actions: vec![Box::new({
+                let cwd = self.config.cwd.clone();
+                move |tx| {
+                    tx.send(AppEvent::OpenReviewCommitRangePicker(cwd.clone()));
+                }
+            })],
+            dismiss_on_select: false,
+            ..Default::default()
+        });
+
+        items.push(SelectionItem {
+            name: "Review a patch file".to_string(),
+            actions: vec![Box::new(move |tx| {
+                tx.send(AppEvent::OpenReviewPatchFilePrompt);
+            })],
+            dismiss_on_select: false,
+            ..Default::default()
+        });
+
         items.push(SelectionItem {
             name: "Custom review instructions".to_string(),
             actions: vec![Box::new(move |tx| {
@@ -6592,6 +7000,69 @@ impl ChatWidget {
         });
     }
 
+    pub(crate) async fn show_review_commit_range_picker(&mut self, cwd: &Path) {
+        let commits = codex_core::git_info::recent_commits(cwd, 100).await;
+
+        let mut items: Vec<SelectionItem> = Vec::with_capacity(commits.len());
+        for entry in commits {
+            let subject = entry.subject.clone();
+            let sha = entry.sha.clone();
+            let search_val = format!("{subject} {sha}");
+
+            items.push(SelectionItem {
+                name: subject.clone(),
+                actions: vec![Box::new(move |tx3: &AppEventSender| {
+                    tx3.send(AppEvent::CodexOp(Op::Review {
+                        review_request: ReviewRequest {
+                            target: ReviewTarget::CommitRange {
+                                from: sha.clone(),
+                                to: "HEAD".to_string(),
+                            },
+                            user_facing_hint: None,
+                        },
+                    }));
+                })],
+                dismiss_on_select: true,
+                search_value: Some(search_val),
+                ..Default::default()
+            });
+        }
+
+        self.bottom_pane.show_selection_view(SelectionViewParams {
+            title: Some("Select a base commit".to_string()),
+            subtitle: Some("Changes after this commit up to HEAD will be reviewed.".to_string()),
+            footer_hint: Some(standard_popup_hint_line()),
+            items,
+            is_searchable: true,
+            search_placeholder: Some("Type to search commits".to_string()),
+            ..Default::default()
+        });
+    }
+
+    pub(crate) fn show_review_patch_file_prompt(&mut self) {
+        let tx = self.app_event_tx.clone();
+        let view = CustomPromptView::new(
+            "Review a patch file".to_string(),
+            "Type the path to a .patch or .diff file and press Enter".to_string(),
+            None,
+            Box::new(move |path: String| {
+                let trimmed = path.trim();
+                if trimmed.is_empty() {
+                    return;
+                }
+                tx.send(AppEvent::CodexOp(Op::Review {
+                    review_request: ReviewRequest {
+                        target: ReviewTarget::PatchFile {
+                            path: PathBuf::from(trimmed),
+                        },
+                        user_facing_hint: None,
+                    },
+                }));
+            }),
+        );
+        self.bottom_pane.show_view(Box::new(view));
+    }
+
     pub(crate) fn show_review_custom_prompt(&mut self) {
         let tx = self.app_event_tx.clone();
         let view = CustomPromptView::new(
@@ -6821,6 +7292,49 @@ fn extract_first_bold(s: &str) -> Option<String> {
     None
 }
 
+const UNDO_SNAPSHOT_TITLE_GRAPHEMES: usize = 60;
+
+fn undo_snapshot_title(snapshot: &UndoSnapshot) -> String {
+    match snapshot
+        .user_message
+        .as_deref()
+        .and_then(|message| message.lines().map(str::trim).find(|line| !line.is_empty()))
+    {
+        Some(line) => truncate_text(line, UNDO_SNAPSHOT_TITLE_GRAPHEMES),
+        None => format!(
+            "Snapshot {}",
+            snapshot.id.chars().take(7).collect::<String>()
+        ),
+    }
+}
+
+fn undo_snapshot_summary(snapshot: &UndoSnapshot) -> String {
+    let mut parts = vec![format!(
+        "reverts {}",
+        plural(snapshot.turns as usize, "turn", "turns")
+    )];
+    if snapshot.changed_files.is_empty() {
+        parts.push("no file changes".to_string());
+    } else {
+        parts.push(plural(snapshot.changed_files.len(), "file", "files"));
+    }
+    if !snapshot.conflicts.is_empty() {
+        parts.push(format!(
+            "{} changed outside Codex",
+            snapshot.conflicts.len()
+        ));
+    }
+    parts.join(" · ")
+}
+
+fn plural(count: usize, one: &str, many: &str) -> String {
+    if count == 1 {
+        format!("{count} {one}")
+    } else {
+        format!("{count} {many}")
+    }
+}
+
 async fn fetch_rate_limits(base_url: String, auth: CodexAuth) -> Option<RateLimitSnapshot> {
     match BackendClient::from_auth(base_url, &auth) {
         Ok(client) => match client.get_rate_limits().await {
@@ -30,6 +30,8 @@ use codex_core::protocol::AgentReasoningDeltaEvent;
 use codex_core::protocol::AgentReasoningEvent;
 use codex_core::protocol::ApplyPatchApprovalRequestEvent;
 use codex_core::protocol::BackgroundEventEvent;
+use codex_core::protocol::BackgroundProcess;
+use codex_core::protocol::BackgroundProcessOutputEvent;
 use codex_core::protocol::CreditsSnapshot;
 use codex_core::protocol::Event;
 use codex_core::protocol::EventMsg;
@@ -40,6 +42,10 @@ use codex_core::protocol::ExecCommandSource;
 use codex_core::protocol::ExecPolicyAmendment;
 use codex_core::protocol::ExitedReviewModeEvent;
 use codex_core::protocol::FileChange;
+use codex_core::protocol::ListBackgroundProcessesResponseEvent;
+use codex_core::protocol::ListUndoSnapshotsResponseEvent;
+use codex_core::protocol::McpListChangedEvent;
+use codex_core::protocol::McpListChangedKind;
 use codex_core::protocol::McpStartupCompleteEvent;
 use codex_core::protocol::McpStartupStatus;
 use codex_core::protocol::McpStartupUpdateEvent;
@@ -47,6 +53,7 @@ use codex_core::protocol::Op;
 use codex_core::protocol::PatchApplyBeginEvent;
 use codex_core::protocol::PatchApplyEndEvent;
 use codex_core::protocol::RateLimitWindow;
+use codex_core::protocol::RestorableBackgroundProcessesEvent;
 use codex_core::protocol::ReviewRequest;
 use codex_core::protocol::ReviewTarget;
 use codex_core::protocol::SessionSource;
@@ -58,6 +65,7 @@ use codex_core::protocol::TokenUsageInfo;
 use codex_core::protocol::TurnCompleteEvent;
 use codex_core::protocol::TurnStartedEvent;
 use codex_core::protocol::UndoCompletedEvent;
+use codex_core::protocol::UndoSnapshot;
 use codex_core::protocol::UndoStartedEvent;
 use codex_core::protocol::ViewImageToolCallEvent;
 use codex_core::protocol::WarningEvent;
@@ -928,6 +936,7 @@ async fn make_chatwidget_manual(
         unified_exec_wait_streak: None,
         task_complete_pending: false,
         unified_exec_processes: Vec::new(),
+        background_processes_requested: false,
         agent_turn_running: false,
         mcp_startup_status: None,
         connectors_cache: ConnectorsCacheState::default(),
@@ -951,6 +960,7 @@ async fn make_chatwidget_manual(
         needs_final_message_separator: false,
         had_work_activity: false,
         saw_plan_update_this_turn: false,
+        announced_mcp_list_changes: Vec::new(),
         saw_plan_item_this_turn: false,
         plan_delta_buffer: String::new(),
         plan_item_active: false,
@@ -2423,10 +2433,10 @@ async fn review_popup_custom_prompt_action_sends_event() {
     // Open the preset selection popup
     chat.open_review_popup();
 
-    // Move selection down to the fourth item: "Custom review instructions"
-    chat.handle_key_event(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
-    chat.handle_key_event(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
-    chat.handle_key_event(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
+    // Move selection down to the last item: "Custom review instructions"
+    for _ in 0..5 {
+        chat.handle_key_event(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
+    }
     // Activate
     chat.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
 
@@ -2441,6 +2451,54 @@ async fn review_popup_custom_prompt_action_sends_event() {
     assert!(found, "expected OpenReviewCustomPrompt event to be sent");
 }
 
+/// Selecting the patch file option from the review popup sends
+/// OpenReviewPatchFilePrompt, and submitting a path queues a PatchFile review.
+#[tokio::test]
+async fn review_popup_patch_file_action_sends_review_op() {
+    let (mut chat, mut rx, _op_rx) = make_chatwidget_manual(None).await;
+
+    chat.open_review_popup();
+
+    // Move selection down to the fifth item: "Review a patch file"
+    for _ in 0..4 {
+        chat.handle_key_event(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
+    }
+    chat.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+
+    let mut found = false;
+    while let Ok(ev) = rx.try_recv() {
+        if let AppEvent::OpenReviewPatchFilePrompt = ev {
+            found = true;
+            break;
+        }
+    }
+    assert!(found, "expected OpenReviewPatchFilePrompt event to be sent");
+
+    chat.show_review_patch_file_prompt();
+    chat.handle_paste("fix.patch".to_string());
+    chat.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+
+    let mut review_request = None;
+    while let Ok(ev) = rx.try_recv() {
+        if let AppEvent::CodexOp(Op::Review {
+            review_request: req,
+        }) = ev
+        {
+            review_request = Some(req);
+            break;
+        }
+    }
+    assert_eq!(
+        review_request,
+        Some(ReviewRequest {
+            target: ReviewTarget::PatchFile {
+                path: PathBuf::from("fix.patch"),
+            },
+            user_facing_hint: None,
+        })
+    );
+}
+
 #[tokio::test]
 async fn slash_init_skips_when_project_doc_exists() {
     let (mut chat, mut rx, mut op_rx) = make_chatwidget_manual(None).await;
@@ -2959,6 +3017,164 @@ async fn undo_failure_events_render_error_message() {
     );
 }
 
+#[tokio::test]
+async fn undo_picker_restores_selected_snapshot_with_force_on_conflicts() {
+    let (mut chat, mut rx, _op_rx) = make_chatwidget_manual(None).await;
+
+    let snapshot = UndoSnapshot {
+        id: "0123456789abcdef".to_string(),
+        user_message: Some("add a logger\nwith details".to_string()),
+        turns: 2,
+        changed_files: vec![PathBuf::from("src/log.rs"), PathBuf::from("src/main.rs")],
+        unified_diff: "diff --git a/src/log.rs b/src/log.rs\n".to_string(),
+        conflicts: vec![PathBuf::from("src/main.rs")],
+    };
+    chat.handle_codex_event(Event {
+        id: "list-undo".to_string(),
+        msg: EventMsg::ListUndoSnapshotsResponse(ListUndoSnapshotsResponseEvent {
+            snapshots: vec![snapshot.clone()],
+            redo_available: true,
+        }),
+    });
+
+    let popup = render_bottom_popup(&chat, 100);
+    assert!(
+        popup.contains("add a logger"),
+        "expected turn message: {popup}"
+    );
+    assert!(
+        popup.contains("reverts 2 turns · 2 files · 1 changed outside Codex"),
+        "expected snapshot summary: {popup}"
+    );
+    assert!(popup.contains("Redo last undo"), "expected redo: {popup}");
+
+    chat.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+    match rx.try_recv() {
+        Ok(AppEvent::OpenUndoSnapshot(opened)) => assert_eq!(opened, snapshot),
+        other => panic!("unexpected app event: {other:?}"),
+    }
+
+    chat.open_undo_snapshot_popup(snapshot);
+    let popup = render_bottom_popup(&chat, 100);
+    assert!(
+        popup.contains("1 file changed outside Codex since the last turn."),
+        "expected conflict warning: {popup}"
+    );
+
+    chat.handle_key_event(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
+    chat.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+    match rx.try_recv() {
+        Ok(AppEvent::CodexOp(Op::UndoTo { snapshot_id, force })) => {
+            assert_eq!(snapshot_id, "0123456789abcdef");
+            assert!(force, "conflicting restore should overwrite");
+        }
+        other => panic!("unexpected app event: {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn ps_opens_background_terminals_overlay() {
+    let (mut chat, mut rx, mut op_rx) = make_chatwidget_manual(None).await;
+
+    chat.dispatch_command(SlashCommand::Ps);
+    match op_rx.try_recv() {
+        Ok(Op::ListBackgroundProcesses) => {}
+        other => panic!("unexpected op: {other:?}"),
+    }
+
+    chat.handle_codex_event(Event {
+        id: "list-processes".to_string(),
+        msg: EventMsg::ListBackgroundProcessesResponse(ListBackgroundProcessesResponseEvent {
+            processes: vec![BackgroundProcess {
+                process_id: "1000".to_string(),
+                command: vec!["npm".to_string(), "run".to_string(), "dev".to_string()],
+                cwd: PathBuf::from("/workspace"),
+                tty: true,
+            }],
+        }),
+    });
+    match rx.try_recv() {
+        Ok(AppEvent::CodexOp(Op::ReadBackgroundProcessOutput { process_id })) => {
+            assert_eq!(process_id, "1000");
+        }
+        other => panic!("unexpected app event: {other:?}"),
+    }
+
+    chat.handle_codex_event(Event {
+        id: "read-output".to_string(),
+        msg: EventMsg::BackgroundProcessOutput(BackgroundProcessOutputEvent {
+            process_id: "1000".to_string(),
+            output: "compiled\nready on :3000\n".to_string(),
+            running: true,
+        }),
+    });
+    let popup = render_bottom_popup(&chat, 100);
+    assert!(popup.contains("npm run dev"), "expected command: {popup}");
+    assert!(popup.contains("ready on :3000"), "expected output: {popup}");
+
+    chat.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+    assert!(
+        chat.bottom_pane.no_modal_or_popup_active(),
+        "Esc should close the overlay"
+    );
+}
+
+#[tokio::test]
+async fn resume_offers_to_relaunch_background_processes() {
+    let (mut chat, mut rx, _op_rx) = make_chatwidget_manual(None).await;
+
+    chat.handle_codex_event(Event {
+        id: "resume".to_string(),
+        msg: EventMsg::RestorableBackgroundProcesses(RestorableBackgroundProcessesEvent {
+            processes: vec![BackgroundProcess {
+                process_id: "1000".to_string(),
+                command: vec![
+                    "bash".to_string(),
+                    "-lc".to_string(),
+                    "npm run dev".to_string(),
+                ],
+                cwd: PathBuf::from("/workspace"),
+                tty: true,
+            }],
+        }),
+    });
+    let popup = render_bottom_popup(&chat, 100);
+    assert!(
+        popup.contains("Relaunch background processes"),
+        "expected relaunch prompt: {popup}"
+    );
+    assert!(popup.contains("npm run dev"), "expected command: {popup}");
+
+    chat.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+    match rx.try_recv() {
+        Ok(AppEvent::CodexOp(Op::RelaunchBackgroundProcesses { process_ids })) => {
+            assert_eq!(process_ids, vec!["1000".to_string()]);
+        }
+        other => panic!("unexpected app event: {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn undo_picker_reports_when_nothing_to_undo() {
+    let (mut chat, mut rx, _op_rx) = make_chatwidget_manual(None).await;
+
+    chat.handle_codex_event(Event {
+        id: "list-undo".to_string(),
+        msg: EventMsg::ListUndoSnapshotsResponse(ListUndoSnapshotsResponseEvent {
+            snapshots: Vec::new(),
+            redo_available: false,
+        }),
+    });
+
+    let cells = drain_insert_history(&mut rx);
+    assert_eq!(cells.len(), 1, "expected info message");
+    let rendered = lines_to_single_string(&cells[0]);
+    assert!(
+        rendered.contains("No earlier turns to undo."),
+        "expected empty message, got {rendered:?}"
+    );
+}
+
 #[tokio::test]
 async fn undo_started_hides_interrupt_hint() {
     let (mut chat, _rx, _op_rx) = make_chatwidget_manual(None).await;
@@ -4032,6 +4248,7 @@ async fn approval_modal_patch_snapshot() -> anyhow::Result<()> {
         call_id: "call-approve-patch".into(),
         turn_id: "turn-approve-patch".into(),
         changes,
+        hunks: Vec::new(),
         reason: Some("The model wants to apply changes".into()),
         grant_root: Some(PathBuf::from("/tmp")),
     };
@@ -4385,6 +4602,36 @@ async fn mcp_startup_complete_does_not_clear_running_task() {
     assert!(chat.bottom_pane.status_indicator_visible());
 }
 
+#[tokio::test]
+async fn mcp_list_changed_adds_info_message() {
+    let (mut chat, mut rx, _op_rx) = make_chatwidget_manual(None).await;
+
+    chat.handle_codex_event(Event {
+        id: "mcp-list-changed".into(),
+        msg: EventMsg::McpListChanged(McpListChangedEvent {
+            server: "docs".into(),
+            kind: McpListChangedKind::Tools,
+        }),
+    });
+
+    let cells = drain_insert_history(&mut rx);
+    let message = lines_to_single_string(cells.last().expect("info message"));
+    assert!(
+        message.contains("MCP server `docs` updated its tools."),
+        "unexpected message: {message}"
+    );
+
+    // A burst of notifications from the same server is announced once.
+    chat.handle_codex_event(Event {
+        id: "mcp-list-changed".into(),
+        msg: EventMsg::McpListChanged(McpListChangedEvent {
+            server: "docs".into(),
+            kind: McpListChangedKind::Tools,
+        }),
+    });
+    assert!(drain_insert_history(&mut rx).is_empty());
+}
+
 #[tokio::test]
 async fn background_event_updates_status_header() {
     let (mut chat, mut rx, _op_rx) = make_chatwidget_manual(None).await;
@@ -4417,6 +4664,7 @@ async fn apply_patch_events_emit_history_cells() {
         call_id: "c1".into(),
         turn_id: "turn-c1".into(),
         changes,
+        hunks: Vec::new(),
         reason: None,
         grant_root: None,
     };
@@ -4516,6 +4764,7 @@ async fn apply_patch_manual_approval_adjusts_header() {
             call_id: "c1".into(),
             turn_id: "turn-c1".into(),
             changes: proposed_changes,
+            hunks: Vec::new(),
             reason: None,
             grant_root: None,
         }),
@@ -4565,6 +4814,7 @@ async fn apply_patch_manual_flow_snapshot() {
             call_id: "c1".into(),
             turn_id: "turn-c1".into(),
             changes: proposed_changes,
+            hunks: Vec::new(),
             reason: Some("Manual review required".into()),
             grant_root: None,
         }),
@@ -4616,6 +4866,7 @@ async fn apply_patch_approval_sends_op_with_submission_id() {
         call_id: "call-999".into(),
         turn_id: "turn-999".into(),
         changes,
+        hunks: Vec::new(),
         reason: None,
         grant_root: None,
     };
@@ -4656,6 +4907,7 @@ async fn apply_patch_full_flow_integration_like() {
             call_id: "call-1".into(),
             turn_id: "turn-call-1".into(),
             changes,
+            hunks: Vec::new(),
             reason: None,
             grant_root: None,
         }),
@@ -4736,6 +4988,7 @@ async fn apply_patch_untrusted_shows_approval_modal() -> anyhow::Result<()> {
             call_id: "call-1".into(),
             turn_id: "turn-call-1".into(),
             changes,
+            hunks: Vec::new(),
             reason: None,
             grant_root: None,
         }),
@@ -4787,6 +5040,7 @@ async fn apply_patch_request_shows_diff_summary() -> anyhow::Result<()> {
             call_id: "call-apply".into(),
             turn_id: "turn-apply".into(),
             changes,
+            hunks: Vec::new(),
             reason: None,
             grant_root: None,
         }),
@@ -98,6 +98,11 @@ pub struct Cli {
     #[arg(long = "search", default_value_t = false)]
     pub web_search: bool,
 
+    /// Run the session in a dedicated git worktree on a fresh branch instead
+    /// of editing the current checkout.
+    #[arg(long = "worktree", default_value_t = false)]
+    pub worktree: bool,
+
     /// Additional directories that should be writable alongside the primary workspace.
     #[arg(long = "add-dir", value_name = "DIR", value_hint = ValueHint::DirPath)]
     pub add_dir: Vec<PathBuf>,
@@ -12,12 +12,17 @@ use std::collections::HashMap;
 use std::path::Path;
 use std::path::PathBuf;
 
+use crate::color::blend;
 use crate::exec_command::relativize_to_home;
 use crate::render::Insets;
+use crate::render::highlight::Language;
+use crate::render::highlight::highlight_code_to_lines;
 use crate::render::line_utils::prefix_lines;
 use crate::render::renderable::ColumnRenderable;
 use crate::render::renderable::InsetRenderable;
 use crate::render::renderable::Renderable;
+use crate::terminal_palette::best_color;
+use crate::terminal_palette::default_bg;
 use codex_core::git_info::get_git_repo_root;
 use codex_core::protocol::FileChange;
 
@@ -39,16 +44,22 @@ impl DiffSummary {
     }
 }
 
-impl Renderable for FileChange {
+/// A single file's change, rendered with the syntax of its path.
+struct FileChangeView {
+    change: FileChange,
+    language: Option<Language>,
+}
+
+impl Renderable for FileChangeView {
     fn render(&self, area: Rect, buf: &mut Buffer) {
         let mut lines = vec![];
-        render_change(self, &mut lines, area.width as usize);
+        render_change(&self.change, self.language, &mut lines, area.width as usize);
         Paragraph::new(lines).render(area, buf);
     }
 
     fn desired_height(&self, width: u16) -> u16 {
         let mut lines = vec![];
-        render_change(self, &mut lines, width as usize);
+        render_change(&self.change, self.language, &mut lines, width as usize);
         lines.len() as u16
     }
 }
@@ -66,8 +77,12 @@ impl From<DiffSummary> for Box<dyn Renderable> {
             path.extend(render_line_count_summary(row.added, row.removed));
             rows.push(Box::new(path));
             rows.push(Box::new(RtLine::from("")));
+            let language = row.language();
             rows.push(Box::new(InsetRenderable::new(
-                Box::new(row.change) as Box<dyn Renderable>,
+                Box::new(FileChangeView {
+                    change: row.change,
+                    language,
+                }) as Box<dyn Renderable>,
                 Insets::tlbr(0, 2, 0, 0),
             )));
         }
@@ -96,6 +111,14 @@ struct Row {
     change: FileChange,
 }
 
+impl Row {
+    /// The language of the file's contents after the change (a rename may
+    /// change the extension).
+    fn language(&self) -> Option<Language> {
+        Language::from_path(self.move_path.as_ref().unwrap_or(&self.path))
+    }
+}
+
 fn collect_rows(changes: &HashMap<PathBuf, FileChange>) -> Vec<Row> {
     let mut rows: Vec<Row> = Vec::new();
     for (path, change) in changes.iter() {
@@ -186,34 +209,43 @@ fn render_changes_block(rows: Vec<Row>, wrap_cols: usize, cwd: &Path) -> Vec<RtL
         }
 
         let mut lines = vec![];
-        render_change(&r.change, &mut lines, wrap_cols - 4);
+        render_change(&r.change, r.language(), &mut lines, wrap_cols - 4);
         out.extend(prefix_lines(lines, "    ".into(), "    ".into()));
     }
 
     out
 }
 
-fn render_change(change: &FileChange, out: &mut Vec<RtLine<'static>>, width: usize) {
+fn render_change(
+    change: &FileChange,
+    language: Option<Language>,
+    out: &mut Vec<RtLine<'static>>,
+    width: usize,
+) {
     match change {
         FileChange::Add { content } => {
+            let highlighted = language.and_then(|lang| highlight_code_to_lines(content, lang));
             let line_number_width = line_number_width(content.lines().count());
             for (i, raw) in content.lines().enumerate() {
                 out.extend(push_wrapped_diff_line(
                     i + 1,
                     DiffLineType::Insert,
                     raw,
+                    highlighted.as_ref().and_then(|lines| lines.get(i)),
                     width,
                     line_number_width,
                 ));
             }
         }
         FileChange::Delete { content } => {
+            let highlighted = language.and_then(|lang| highlight_code_to_lines(content, lang));
             let line_number_width = line_number_width(content.lines().count());
             for (i, raw) in content.lines().enumerate() {
                 out.extend(push_wrapped_diff_line(
                     i + 1,
                     DiffLineType::Delete,
                     raw,
+                    highlighted.as_ref().and_then(|lines| lines.get(i)),
                     width,
                     line_number_width,
                 ));
@@ -253,6 +285,13 @@ fn render_change(change: &FileChange, out: &mut Vec<RtLine<'static>>, width: usi
                     }
                     is_first_hunk = false;
 
+                    // Highlight each side of the hunk as a whole so constructs
+                    // spanning several lines (strings, comments) keep their style.
+                    let (old_side, new_side) = language
+                        .map(|lang| highlight_hunk_sides(h, lang))
+                        .unwrap_or_default();
+                    let (mut old_idx, mut new_idx) = (0, 0);
+
                     let mut old_ln = h.old_range().start();
                     let mut new_ln = h.new_range().start();
                     for l in h.lines() {
@@ -263,10 +302,12 @@ fn render_change(change: &FileChange, out: &mut Vec<RtLine<'static>>, width: usi
                                     new_ln,
                                     DiffLineType::Insert,
                                     s,
+                                    new_side.as_ref().and_then(|lines| lines.get(new_idx)),
                                     width,
                                     line_number_width,
                                 ));
                                 new_ln += 1;
+                                new_idx += 1;
                             }
                             diffy::Line::Delete(text) => {
                                 let s = text.trim_end_matches('\n');
@@ -274,10 +315,12 @@ fn render_change(change: &FileChange, out: &mut Vec<RtLine<'static>>, width: usi
                                     old_ln,
                                     DiffLineType::Delete,
                                     s,
+                                    old_side.as_ref().and_then(|lines| lines.get(old_idx)),
                                     width,
                                     line_number_width,
                                 ));
                                 old_ln += 1;
+                                old_idx += 1;
                             }
                             diffy::Line::Context(text) => {
                                 let s = text.trim_end_matches('\n');
@@ -285,11 +328,14 @@ fn render_change(change: &FileChange, out: &mut Vec<RtLine<'static>>, width: usi
                                     new_ln,
                                     DiffLineType::Context,
                                     s,
+                                    new_side.as_ref().and_then(|lines| lines.get(new_idx)),
                                     width,
                                     line_number_width,
                                 ));
                                 old_ln += 1;
                                 new_ln += 1;
+                                old_idx += 1;
+                                new_idx += 1;
                             }
                         }
                     }
@@ -299,6 +345,37 @@ fn render_change(change: &FileChange, out: &mut Vec<RtLine<'static>>, width: usi
     }
 }
 
+type HighlightedSide = Option<Vec<RtLine<'static>>>;
+
+/// Highlight the pre-image (context + deletions) and post-image (context +
+/// insertions) of a hunk, one line per hunk line on that side.
+fn highlight_hunk_sides(
+    hunk: &Hunk<'_, str>,
+    language: Language,
+) -> (HighlightedSide, HighlightedSide) {
+    let mut old_text = String::new();
+    let mut new_text = String::new();
+    for line in hunk.lines() {
+        match line {
+            diffy::Line::Insert(text) => push_hunk_line(&mut new_text, text),
+            diffy::Line::Delete(text) => push_hunk_line(&mut old_text, text),
+            diffy::Line::Context(text) => {
+                push_hunk_line(&mut old_text, text);
+                push_hunk_line(&mut new_text, text);
+            }
+        }
+    }
+    (
+        highlight_code_to_lines(&old_text, language),
+        highlight_code_to_lines(&new_text, language),
+    )
+}
+
+fn push_hunk_line(side: &mut String, text: &str) {
+    side.push_str(text.trim_end_matches('\n'));
+    side.push('\n');
+}
+
 /// Format a path for display relative to the current working directory when
 /// possible, keeping output stable in jj/no-`.git` workspaces (e.g. image
 /// tool calls should show `example.png` instead of an absolute path).
@@ -342,67 +419,104 @@ pub(crate) fn calculate_add_remove_from_diff(diff: &str) -> (usize, usize) {
     }
 }
 
+/// Render one diff line, wrapping it to `width`. When `syntax` holds the
+/// highlighted spans for `text`, the diff style is layered over them.
 fn push_wrapped_diff_line(
     line_number: usize,
     kind: DiffLineType,
     text: &str,
+    syntax: Option<&RtLine<'static>>,
     width: usize,
     line_number_width: usize,
 ) -> Vec<RtLine<'static>> {
     let ln_str = line_number.to_string();
-    let mut remaining_text: &str = text;
 
     // Reserve a fixed number of spaces (equal to the widest line number plus a
     // trailing spacer) so the sign column stays aligned across the diff block.
     let gutter_width = line_number_width.max(1);
     let prefix_cols = gutter_width + 1;
 
-    let mut first = true;
-    let (sign_char, line_style) = match kind {
-        DiffLineType::Insert => ('+', style_add()),
-        DiffLineType::Delete => ('-', style_del()),
-        DiffLineType::Context => (' ', style_context()),
+    let sign_char = match kind {
+        DiffLineType::Insert => '+',
+        DiffLineType::Delete => '-',
+        DiffLineType::Context => ' ',
     };
-    let mut lines: Vec<RtLine<'static>> = Vec::new();
-
-    loop {
-        // Fit the content for the current terminal row:
-        // compute how many columns are available after the prefix, then split
-        // at a UTF-8 character boundary so this row's chunk fits exactly.
-        let available_content_cols = width.saturating_sub(prefix_cols + 1).max(1);
-        let split_at_byte_index = remaining_text
-            .char_indices()
-            .nth(available_content_cols)
-            .map(|(i, _)| i)
-            .unwrap_or_else(|| remaining_text.len());
-        let (chunk, rest) = remaining_text.split_at(split_at_byte_index);
-        remaining_text = rest;
-
-        if first {
-            // Build gutter (right-aligned line number plus spacer) as a dimmed span
-            let gutter = format!("{ln_str:>gutter_width$} ");
-            // Content with a sign ('+'/'-'/' ') styled per diff kind
-            let content = format!("{sign_char}{chunk}");
-            lines.push(RtLine::from(vec![
-                RtSpan::styled(gutter, style_gutter()),
-                RtSpan::styled(content, line_style),
-            ]));
-            first = false;
-        } else {
-            // Continuation lines keep a space for the sign column so content aligns
-            let gutter = format!("{:gutter_width$}  ", "");
-            lines.push(RtLine::from(vec![
-                RtSpan::styled(gutter, style_gutter()),
-                RtSpan::styled(chunk.to_string(), line_style),
-            ]));
+    let (sign_style, content_spans) = match syntax {
+        Some(line) => {
+            // Prefer a background tint so token colors stay visible; without a
+            // known palette the diff color wins over the syntax colors.
+            let overlay = diff_background(&kind)
+                .map(|bg| Style::default().bg(bg))
+                .unwrap_or_else(|| line_style(&kind));
+            let spans = line
+                .spans
+                .iter()
+                .map(|span| RtSpan::styled(span.content.clone(), span.style.patch(overlay)))
+                .collect();
+            (line_style(&kind).patch(overlay), spans)
         }
-        if remaining_text.is_empty() {
-            break;
+        None => {
+            let style = line_style(&kind);
+            (style, vec![RtSpan::styled(text.to_string(), style)])
         }
+    };
+
+    // Fit the content for each terminal row: compute how many columns are
+    // available after the prefix, then split spans at character boundaries so
+    // each row's chunk fits exactly.
+    let available_content_cols = width.saturating_sub(prefix_cols + 1).max(1);
+    let rows = wrap_spans(content_spans, available_content_cols);
+
+    let mut lines: Vec<RtLine<'static>> = Vec::with_capacity(rows.len());
+    for (i, row) in rows.into_iter().enumerate() {
+        let mut spans = if i == 0 {
+            // Build gutter (right-aligned line number plus spacer) as a dimmed
+            // span, followed by the sign ('+'/'-'/' ') styled per diff kind.
+            vec![
+                RtSpan::styled(format!("{ln_str:>gutter_width$} "), style_gutter()),
+                RtSpan::styled(sign_char.to_string(), sign_style),
+            ]
+        } else {
+            // Continuation lines keep a space for the sign column so content aligns
+            vec![RtSpan::styled(
+                format!("{:gutter_width$}  ", ""),
+                style_gutter(),
+            )]
+        };
+        spans.extend(row);
+        lines.push(RtLine::from(spans));
     }
     lines
 }
 
+/// Split `spans` into rows of at most `cols` characters, preserving styles.
+/// Always yields at least one (possibly empty) row.
+fn wrap_spans(spans: Vec<RtSpan<'static>>, cols: usize) -> Vec<Vec<RtSpan<'static>>> {
+    let mut rows: Vec<Vec<RtSpan<'static>>> = vec![Vec::new()];
+    let mut used = 0;
+    for span in spans {
+        let mut rest: &str = &span.content;
+        while !rest.is_empty() {
+            if used == cols {
+                rows.push(Vec::new());
+                used = 0;
+            }
+            let split_at_byte_index = rest
+                .char_indices()
+                .nth(cols - used)
+                .map(|(i, _)| i)
+                .unwrap_or(rest.len());
+            let (chunk, tail) = rest.split_at(split_at_byte_index);
+            used += chunk.chars().count();
+            if let Some(row) = rows.last_mut() {
+                row.push(RtSpan::styled(chunk.to_string(), span.style));
+            }
+            rest = tail;
+        }
+    }
+    rows
+}
+
 fn line_number_width(max_line_number: usize) -> usize {
     if max_line_number == 0 {
         1
@@ -427,6 +541,29 @@ fn style_del() -> Style {
     Style::default().fg(Color::Red)
 }
 
+fn line_style(kind: &DiffLineType) -> Style {
+    match kind {
+        DiffLineType::Insert => style_add(),
+        DiffLineType::Delete => style_del(),
+        DiffLineType::Context => style_context(),
+    }
+}
+
+/// A faint green/red wash over the terminal's background for highlighted
+/// insertions/deletions, or `None` when the background color is unknown or
+/// the terminal can't show the blend.
+fn diff_background(kind: &DiffLineType) -> Option<Color> {
+    let tint = match kind {
+        DiffLineType::Insert => (0, 200, 0),
+        DiffLineType::Delete => (220, 0, 0),
+        DiffLineType::Context => return None,
+    };
+    match best_color(blend(tint, default_bg()?, 0.18)) {
+        Color::Reset => None,
+        color => Some(color),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -497,8 +634,14 @@ mod tests {
         let long_line = "this is a very long line that should wrap across multiple terminal columns and continue";
 
         // Call the wrapping function directly so we can precisely control the width
-        let lines =
-            push_wrapped_diff_line(1, DiffLineType::Insert, long_line, 80, line_number_width(1));
+        let lines = push_wrapped_diff_line(
+            1,
+            DiffLineType::Insert,
+            long_line,
+            None,
+            80,
+            line_number_width(1),
+        );
 
         // Render into a small terminal to capture the visual layout
         snapshot_lines("wrap_behavior_insert", lines, 90, 8);
@@ -524,6 +667,47 @@ mod tests {
         snapshot_lines("apply_update_block", lines, 80, 12);
     }
 
+    #[test]
+    fn update_block_highlights_by_file_extension() {
+        let mut changes: HashMap<PathBuf, FileChange> = HashMap::new();
+        let original = "fn main() {\n    let x = 1;\n}\n";
+        let modified = "fn main() {\n    let x = 2;\n}\n";
+        let patch = diffy::create_patch(original, modified).to_string();
+
+        changes.insert(
+            PathBuf::from("main.rs"),
+            FileChange::Update {
+                unified_diff: patch,
+                move_path: None,
+            },
+        );
+
+        let lines = diff_summary_for_tests(&changes);
+        let context = lines
+            .iter()
+            .find(|line| line.spans.iter().any(|span| span.content == "fn"))
+            .expect("context line");
+        let keyword = context
+            .spans
+            .iter()
+            .find(|span| span.content == "fn")
+            .expect("fn span");
+        assert_eq!(keyword.style.fg, Some(Color::Magenta));
+
+        // Without a known terminal background the diff color stays on top.
+        let inserted = lines
+            .iter()
+            .find(|line| line.spans.iter().any(|span| span.content == "2"))
+            .expect("inserted line");
+        assert!(
+            inserted
+                .spans
+                .iter()
+                .filter(|span| span.content == "let")
+                .all(|span| span.style.fg == Some(Color::Green))
+        );
+    }
+
     #[test]
     fn ui_snapshot_apply_update_with_rename_block() {
         let mut changes: HashMap<PathBuf, FileChange> = HashMap::new();
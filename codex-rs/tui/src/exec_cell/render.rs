@@ -193,6 +193,69 @@ pub(crate) fn spinner(start_time: Option<Instant>, animations_enabled: bool) ->
     }
 }
 
+/// Summarizes every parsed command of a call, or returns `None` when any of
+/// them has no dedicated summary (exploring commands and unknown commands).
+fn command_summaries(parsed: &[ParsedCommand]) -> Option<Vec<(&'static str, Vec<Span<'static>>)>> {
+    if parsed.is_empty() {
+        return None;
+    }
+    parsed.iter().map(command_summary).collect()
+}
+
+/// Label and summary for test, build, install, file, git and fetch commands,
+/// e.g. `Test cargo codex-core` or `Move a.rs → b.rs`.
+fn command_summary(parsed: &ParsedCommand) -> Option<(&'static str, Vec<Span<'static>>)> {
+    let summary = match parsed {
+        ParsedCommand::Test {
+            runner, targets, ..
+        } => ("Test", tool_with_targets(runner, targets)),
+        ParsedCommand::Build { tool, targets, .. } => ("Build", tool_with_targets(tool, targets)),
+        ParsedCommand::Install {
+            manager, packages, ..
+        } => ("Install", tool_with_targets(manager, packages)),
+        ParsedCommand::Write { cmd, paths } => ("Write", path_list(paths, cmd)),
+        ParsedCommand::Move { cmd, paths } => match paths.split_last() {
+            Some((destination, sources)) if !sources.is_empty() => {
+                let mut spans = path_list(sources, cmd);
+                spans.push(" → ".dim());
+                spans.push(destination.clone().into());
+                ("Move", spans)
+            }
+            _ => ("Move", path_list(paths, cmd)),
+        },
+        ParsedCommand::Delete { cmd, paths } => ("Delete", path_list(paths, cmd)),
+        ParsedCommand::GitMutation {
+            subcommand,
+            targets,
+            ..
+        } => ("Git", tool_with_targets(subcommand, targets)),
+        ParsedCommand::Fetch { cmd, url } => {
+            ("Fetch", vec![url.clone().unwrap_or(cmd.clone()).into()])
+        }
+        ParsedCommand::Read { .. }
+        | ParsedCommand::ListFiles { .. }
+        | ParsedCommand::Search { .. }
+        | ParsedCommand::Unknown { .. } => return None,
+    };
+    Some(summary)
+}
+
+fn tool_with_targets(tool: &str, targets: &[String]) -> Vec<Span<'static>> {
+    let mut spans = vec![tool.to_string().into()];
+    for target in targets {
+        spans.push(" ".into());
+        spans.push(target.clone().into());
+    }
+    spans
+}
+
+fn path_list(paths: &[String], cmd: &str) -> Vec<Span<'static>> {
+    if paths.is_empty() {
+        return vec![cmd.to_string().into()];
+    }
+    Itertools::intersperse(paths.iter().cloned().map(Into::into), ", ".dim()).collect()
+}
+
 impl HistoryCell for ExecCell {
     fn display_lines(&self, width: u16) -> Vec<Line<'static>> {
         if self.is_exploring_cell() {
@@ -329,8 +392,11 @@ impl ExecCell {
                             };
                             lines.push(("Search", spans));
                         }
-                        ParsedCommand::Unknown { cmd } => {
-                            lines.push(("Run", vec![cmd.clone().into()]));
+                        other => {
+                            lines.push(
+                                command_summary(other)
+                                    .unwrap_or(("Run", vec![other.cmd().to_string().into()])),
+                            );
                         }
                     }
                 }
@@ -377,58 +443,118 @@ impl ExecCell {
             "Ran"
         };
 
-        let mut header_line = if is_interaction {
-            Line::from(vec![bullet.clone(), " ".into()])
+        // Recognized commands (tests, builds, file and git changes, ...) are
+        // summarized instead of echoing the raw command line; the full command
+        // remains available in the transcript.
+        let summaries = if is_interaction || call.is_user_shell_command() {
+            None
         } else {
-            Line::from(vec![bullet.clone(), " ".into(), title.bold(), " ".into()])
+            command_summaries(&call.parsed)
         };
-        let header_prefix_width = header_line.width();
 
-        let cmd_display = if call.is_unified_exec_interaction() {
-            format_unified_exec_interaction(&call.command, call.interaction_input.as_deref())
-        } else {
-            strip_bash_lc_and_escape(&call.command)
-        };
-        let highlighted_lines = highlight_bash_to_lines(&cmd_display);
-
-        let continuation_wrap_width = layout.command_continuation.wrap_width(width);
-        let continuation_opts =
-            RtOptions::new(continuation_wrap_width).word_splitter(WordSplitter::NoHyphenation);
-
-        let mut continuation_lines: Vec<Line<'static>> = Vec::new();
-
-        if let Some((first, rest)) = highlighted_lines.split_first() {
-            let available_first_width = (width as usize).saturating_sub(header_prefix_width).max(1);
-            let first_opts =
-                RtOptions::new(available_first_width).word_splitter(WordSplitter::NoHyphenation);
-            let mut first_wrapped: Vec<Line<'static>> = Vec::new();
-            push_owned_lines(&word_wrap_line(first, first_opts), &mut first_wrapped);
-            let mut first_wrapped_iter = first_wrapped.into_iter();
-            if let Some(first_segment) = first_wrapped_iter.next() {
-                header_line.extend(first_segment);
+        let mut lines: Vec<Line<'static>> = Vec::new();
+        if let Some(summaries) = summaries {
+            let mut summaries = summaries.into_iter();
+            let mut continuation_lines: Vec<Line<'static>> = Vec::new();
+            if let Some((label, spans)) = summaries.next() {
+                let mut header_line =
+                    Line::from(vec![bullet.clone(), " ".into(), label.bold(), " ".into()]);
+                let available_first_width =
+                    (width as usize).saturating_sub(header_line.width()).max(1);
+                let first_opts = RtOptions::new(available_first_width)
+                    .word_splitter(WordSplitter::NoHyphenation);
+                let mut first_wrapped: Vec<Line<'static>> = Vec::new();
+                push_owned_lines(
+                    &word_wrap_line(&Line::from(spans), first_opts),
+                    &mut first_wrapped,
+                );
+                let mut first_wrapped_iter = first_wrapped.into_iter();
+                if let Some(first_segment) = first_wrapped_iter.next() {
+                    header_line.extend(first_segment);
+                }
+                lines.push(header_line);
+                continuation_lines.extend(first_wrapped_iter);
             }
-            continuation_lines.extend(first_wrapped_iter);
-
-            for line in rest {
+            let continuation_wrap_width = layout.command_continuation.wrap_width(width);
+            for (label, spans) in summaries {
+                let initial_indent = Line::from(vec![label.bold(), " ".into()]);
+                let subsequent_indent = " ".repeat(initial_indent.width()).into();
                 push_owned_lines(
-                    &word_wrap_line(line, continuation_opts.clone()),
+                    &word_wrap_line(
+                        &Line::from(spans),
+                        RtOptions::new(continuation_wrap_width)
+                            .initial_indent(initial_indent)
+                            .subsequent_indent(subsequent_indent),
+                    ),
                     &mut continuation_lines,
                 );
             }
-        }
+            let continuation_lines = Self::limit_lines_from_start(
+                &continuation_lines,
+                layout.command_continuation_max_lines,
+            );
+            if !continuation_lines.is_empty() {
+                lines.extend(prefix_lines(
+                    continuation_lines,
+                    Span::from(layout.command_continuation.initial_prefix).dim(),
+                    Span::from(layout.command_continuation.subsequent_prefix).dim(),
+                ));
+            }
+        } else {
+            let mut header_line = if is_interaction {
+                Line::from(vec![bullet.clone(), " ".into()])
+            } else {
+                Line::from(vec![bullet.clone(), " ".into(), title.bold(), " ".into()])
+            };
+            let header_prefix_width = header_line.width();
+
+            let cmd_display = if call.is_unified_exec_interaction() {
+                format_unified_exec_interaction(&call.command, call.interaction_input.as_deref())
+            } else {
+                strip_bash_lc_and_escape(&call.command)
+            };
+            let highlighted_lines = highlight_bash_to_lines(&cmd_display);
+
+            let continuation_wrap_width = layout.command_continuation.wrap_width(width);
+            let continuation_opts =
+                RtOptions::new(continuation_wrap_width).word_splitter(WordSplitter::NoHyphenation);
+
+            let mut continuation_lines: Vec<Line<'static>> = Vec::new();
+
+            if let Some((first, rest)) = highlighted_lines.split_first() {
+                let available_first_width =
+                    (width as usize).saturating_sub(header_prefix_width).max(1);
+                let first_opts = RtOptions::new(available_first_width)
+                    .word_splitter(WordSplitter::NoHyphenation);
+                let mut first_wrapped: Vec<Line<'static>> = Vec::new();
+                push_owned_lines(&word_wrap_line(first, first_opts), &mut first_wrapped);
+                let mut first_wrapped_iter = first_wrapped.into_iter();
+                if let Some(first_segment) = first_wrapped_iter.next() {
+                    header_line.extend(first_segment);
+                }
+                continuation_lines.extend(first_wrapped_iter);
+
+                for line in rest {
+                    push_owned_lines(
+                        &word_wrap_line(line, continuation_opts.clone()),
+                        &mut continuation_lines,
+                    );
+                }
+            }
 
-        let mut lines: Vec<Line<'static>> = vec![header_line];
+            lines.push(header_line);
 
-        let continuation_lines = Self::limit_lines_from_start(
-            &continuation_lines,
-            layout.command_continuation_max_lines,
-        );
-        if !continuation_lines.is_empty() {
-            lines.extend(prefix_lines(
-                continuation_lines,
-                Span::from(layout.command_continuation.initial_prefix).dim(),
-                Span::from(layout.command_continuation.subsequent_prefix).dim(),
-            ));
+            let continuation_lines = Self::limit_lines_from_start(
+                &continuation_lines,
+                layout.command_continuation_max_lines,
+            );
+            if !continuation_lines.is_empty() {
+                lines.extend(prefix_lines(
+                    continuation_lines,
+                    Span::from(layout.command_continuation.initial_prefix).dim(),
+                    Span::from(layout.command_continuation.subsequent_prefix).dim(),
+                ));
+            }
         }
 
         if let Some(output) = call.output.as_ref() {
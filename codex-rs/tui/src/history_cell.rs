@@ -755,6 +755,19 @@ pub fn new_approval_decision_cell(
                 ],
             )
         }
+        ApprovedHunks { declined } => {
+            let snippet = Span::from(exec_snippet(&command)).dim();
+            (
+                "✔ ".green(),
+                vec![
+                    "You ".into(),
+                    "approved".bold(),
+                    " ".into(),
+                    snippet,
+                    format!(" except {} hunk(s)", declined.len()).bold(),
+                ],
+            )
+        }
         Denied => {
             let snippet = Span::from(exec_snippet(&command)).dim();
             (
@@ -2497,6 +2510,7 @@ mod tests {
             enabled_tools: None,
             disabled_tools: None,
             scopes: None,
+            sandbox: None,
         };
         let mut servers = config.mcp_servers.get().clone();
         servers.insert("docs".to_string(), stdio_config);
@@ -2519,6 +2533,7 @@ mod tests {
             enabled_tools: None,
             disabled_tools: None,
             scopes: None,
+            sandbox: None,
         };
         servers.insert("http".to_string(), http_config);
         config
@@ -3316,6 +3331,150 @@ mod tests {
             .join("\n");
         insta::assert_snapshot!(rendered);
     }
+    fn completed_exec_cell(command: &str, parsed: Vec<ParsedCommand>) -> ExecCell {
+        let call_id = "c_summary".to_string();
+        let mut cell = ExecCell::new(
+            ExecCall {
+                call_id: call_id.clone(),
+                command: vec!["bash".into(), "-lc".into(), command.to_string()],
+                parsed,
+                output: None,
+                source: ExecCommandSource::Agent,
+                start_time: Some(Instant::now()),
+                duration: None,
+                interaction_input: None,
+            },
+            true,
+        );
+        cell.complete_call(
+            &call_id,
+            CommandOutput {
+                exit_code: 0,
+                formatted_output: String::new(),
+                aggregated_output: String::new(),
+            },
+            Duration::from_millis(5),
+        );
+        cell
+    }
+
+    #[test]
+    fn ran_cell_summarizes_recognized_commands_snapshot() {
+        let strings = |items: &[&str]| items.iter().map(ToString::to_string).collect::<Vec<_>>();
+        let commands = vec![
+            (
+                "cargo test -p codex-core",
+                ParsedCommand::Test {
+                    cmd: "cargo test -p codex-core".to_string(),
+                    runner: "cargo".to_string(),
+                    targets: strings(&["codex-core"]),
+                },
+            ),
+            (
+                "cargo build",
+                ParsedCommand::Build {
+                    cmd: "cargo build".to_string(),
+                    tool: "cargo".to_string(),
+                    targets: Vec::new(),
+                },
+            ),
+            (
+                "npm install left-pad",
+                ParsedCommand::Install {
+                    cmd: "npm install left-pad".to_string(),
+                    manager: "npm".to_string(),
+                    packages: strings(&["left-pad"]),
+                },
+            ),
+            (
+                "touch a.txt b.txt",
+                ParsedCommand::Write {
+                    cmd: "touch a.txt b.txt".to_string(),
+                    paths: strings(&["a.txt", "b.txt"]),
+                },
+            ),
+            (
+                "mv old.rs new.rs",
+                ParsedCommand::Move {
+                    cmd: "mv old.rs new.rs".to_string(),
+                    paths: strings(&["old.rs", "new.rs"]),
+                },
+            ),
+            (
+                "rm -rf target/",
+                ParsedCommand::Delete {
+                    cmd: "rm -rf target/".to_string(),
+                    paths: strings(&["target/"]),
+                },
+            ),
+            (
+                "git commit -m wip",
+                ParsedCommand::GitMutation {
+                    cmd: "git commit -m wip".to_string(),
+                    subcommand: "commit".to_string(),
+                    targets: Vec::new(),
+                },
+            ),
+            (
+                "curl https://example.com",
+                ParsedCommand::Fetch {
+                    cmd: "curl https://example.com".to_string(),
+                    url: Some("https://example.com".to_string()),
+                },
+            ),
+        ];
+
+        let rendered = commands
+            .into_iter()
+            .map(|(command, parsed)| {
+                let cell = completed_exec_cell(command, vec![parsed]);
+                render_lines(&cell.display_lines(80)).join("\n")
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        insta::assert_snapshot!(rendered);
+    }
+
+    #[test]
+    fn ran_cell_summarizes_each_segment_of_a_chain_snapshot() {
+        let chained = completed_exec_cell(
+            "cargo build && cargo test",
+            vec![
+                ParsedCommand::Build {
+                    cmd: "cargo build".to_string(),
+                    tool: "cargo".to_string(),
+                    targets: Vec::new(),
+                },
+                ParsedCommand::Test {
+                    cmd: "cargo test".to_string(),
+                    runner: "cargo".to_string(),
+                    targets: Vec::new(),
+                },
+            ],
+        );
+        // A single unrecognized segment falls back to showing the command.
+        let mixed = completed_exec_cell(
+            "cargo test | tee out.log",
+            vec![
+                ParsedCommand::Test {
+                    cmd: "cargo test".to_string(),
+                    runner: "cargo".to_string(),
+                    targets: Vec::new(),
+                },
+                ParsedCommand::Unknown {
+                    cmd: "tee out.log".to_string(),
+                },
+            ],
+        );
+
+        let rendered = [chained, mixed]
+            .iter()
+            .map(|cell| render_lines(&cell.display_lines(80)).join("\n"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        insta::assert_snapshot!(rendered);
+    }
+
     #[test]
     fn user_history_cell_wraps_and_prefixes_each_line_snapshot() {
         let msg = "one two three four five six seven";
@@ -1,3 +1,5 @@
+use std::fmt;
+
 use crossterm::event::KeyCode;
 use crossterm::event::KeyEvent;
 use crossterm::event::KeyEventKind;
@@ -26,6 +28,14 @@ impl KeyBinding {
         Self { key, modifiers }
     }
 
+    pub(crate) fn key(&self) -> KeyCode {
+        self.key
+    }
+
+    pub(crate) fn modifiers(&self) -> KeyModifiers {
+        self.modifiers
+    }
+
     pub fn is_press(&self, event: KeyEvent) -> bool {
         self.key == event.code
             && self.modifiers == event.modifiers
@@ -74,7 +84,13 @@ impl From<KeyBinding> for Span<'static> {
 }
 impl From<&KeyBinding> for Span<'static> {
     fn from(binding: &KeyBinding) -> Self {
-        let KeyBinding { key, modifiers } = binding;
+        Span::styled(binding.to_string(), key_hint_style())
+    }
+}
+
+impl fmt::Display for KeyBinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let KeyBinding { key, modifiers } = self;
         let modifiers = modifiers_to_string(*modifiers);
         let key = match key {
             KeyCode::Enter => "enter".to_string(),
@@ -87,7 +103,7 @@ impl From<&KeyBinding> for Span<'static> {
             KeyCode::PageDown => "pgdn".to_string(),
             _ => format!("{key}").to_ascii_lowercase(),
         };
-        Span::styled(format!("{modifiers}{key}"), key_hint_style())
+        write!(f, "{modifiers}{key}")
     }
 }
 
@@ -0,0 +1,709 @@
+//! User-configurable key bindings for the TUI.
+//!
+//! `[tui.keymap]` in `config.toml` maps named actions to one or more key chords such as
+//! `"ctrl+t"` or `["shift+enter", "ctrl+j"]`. Actions that are not configured keep their
+//! defaults. [`Keymap::from_config`] parses every chord and rejects bindings that would make two
+//! actions fire on the same key in the same part of the UI; the TUI calls it at startup so a bad
+//! keymap is reported before the terminal is taken over.
+
+use std::collections::HashMap;
+
+use codex_core::config::types::KeyChords;
+use codex_core::config::types::TuiKeymap;
+use crossterm::event::KeyCode;
+use crossterm::event::KeyEvent;
+use crossterm::event::KeyModifiers;
+use thiserror::Error;
+
+use crate::key_hint;
+use crate::key_hint::KeyBinding;
+
+/// A key with a fixed meaning that is handled outside the keymap.
+struct ReservedKey {
+    binding: KeyBinding,
+    /// Actions live in any of these contexts cannot use the key.
+    contexts: &'static [KeymapContext],
+    purpose: &'static str,
+}
+
+const ALL_CONTEXTS: &[KeymapContext] = &[
+    KeymapContext::Idle,
+    KeymapContext::Running,
+    KeymapContext::Approval,
+    KeymapContext::Transcript,
+];
+const COMPOSER_CONTEXTS: &[KeymapContext] = &[KeymapContext::Idle, KeymapContext::Running];
+
+const fn composer_editing(binding: KeyBinding) -> ReservedKey {
+    ReservedKey {
+        binding,
+        contexts: COMPOSER_CONTEXTS,
+        purpose: "editing the composer",
+    }
+}
+
+const RESERVED: &[ReservedKey] = &[
+    // Handled before any keymap lookup (interrupt, clear, quit).
+    ReservedKey {
+        binding: key_hint::ctrl(KeyCode::Char('c')),
+        contexts: ALL_CONTEXTS,
+        purpose: "interrupting and quitting",
+    },
+    ReservedKey {
+        binding: key_hint::ctrl(KeyCode::Char('d')),
+        contexts: COMPOSER_CONTEXTS,
+        purpose: "quitting from an empty composer",
+    },
+    ReservedKey {
+        binding: key_hint::shift(KeyCode::BackTab),
+        contexts: COMPOSER_CONTEXTS,
+        purpose: "cycling collaboration modes",
+    },
+    composer_editing(key_hint::ctrl(KeyCode::Char('a'))),
+    composer_editing(key_hint::ctrl(KeyCode::Char('e'))),
+    composer_editing(key_hint::ctrl(KeyCode::Char('k'))),
+    composer_editing(key_hint::ctrl(KeyCode::Char('u'))),
+    composer_editing(key_hint::ctrl(KeyCode::Char('w'))),
+    ReservedKey {
+        binding: key_hint::plain(KeyCode::Esc),
+        contexts: &[KeymapContext::Approval],
+        purpose: "cancelling the approval prompt",
+    },
+    ReservedKey {
+        binding: key_hint::plain(KeyCode::Char('p')),
+        contexts: &[KeymapContext::Approval],
+        purpose: "approving a command prefix",
+    },
+];
+
+/// Where an action is live. Two actions may only share a chord when they never share a context.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum KeymapContext {
+    /// Composer focused, no task running.
+    Idle,
+    /// Composer focused while a task is running.
+    Running,
+    /// Approval prompt in the bottom pane.
+    Approval,
+    /// Full-screen transcript overlay.
+    Transcript,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+enum KeymapAction {
+    Submit,
+    Newline,
+    Queue,
+    OpenEditor,
+    ToggleTranscript,
+    Backtrack,
+    Interrupt,
+    Approve,
+    ApproveForSession,
+    Deny,
+    ReviewHunks,
+}
+
+impl KeymapAction {
+    const ALL: [KeymapAction; 11] = [
+        KeymapAction::Submit,
+        KeymapAction::Newline,
+        KeymapAction::Queue,
+        KeymapAction::OpenEditor,
+        KeymapAction::ToggleTranscript,
+        KeymapAction::Backtrack,
+        KeymapAction::Interrupt,
+        KeymapAction::Approve,
+        KeymapAction::ApproveForSession,
+        KeymapAction::Deny,
+        KeymapAction::ReviewHunks,
+    ];
+
+    /// Name of the action in `[tui.keymap]`.
+    fn name(self) -> &'static str {
+        match self {
+            KeymapAction::Submit => "submit",
+            KeymapAction::Newline => "newline",
+            KeymapAction::Queue => "queue",
+            KeymapAction::OpenEditor => "open_editor",
+            KeymapAction::ToggleTranscript => "toggle_transcript",
+            KeymapAction::Backtrack => "backtrack",
+            KeymapAction::Interrupt => "interrupt",
+            KeymapAction::Approve => "approve",
+            KeymapAction::ApproveForSession => "approve_for_session",
+            KeymapAction::Deny => "deny",
+            KeymapAction::ReviewHunks => "review_hunks",
+        }
+    }
+
+    fn contexts(self) -> &'static [KeymapContext] {
+        use KeymapContext::*;
+        match self {
+            KeymapAction::Submit | KeymapAction::Newline => &[Idle, Running],
+            KeymapAction::Queue | KeymapAction::Interrupt => &[Running],
+            // The app handles these before the bottom pane sees the key.
+            KeymapAction::OpenEditor => &[Idle, Running, Approval],
+            KeymapAction::ToggleTranscript => &[Idle, Running, Approval, Transcript],
+            KeymapAction::Backtrack => &[Idle, Transcript],
+            KeymapAction::Approve
+            | KeymapAction::ApproveForSession
+            | KeymapAction::Deny
+            | KeymapAction::ReviewHunks => &[Approval],
+        }
+    }
+
+    fn default_bindings(self) -> Vec<KeyBinding> {
+        match self {
+            KeymapAction::Submit => vec![key_hint::plain(KeyCode::Enter)],
+            KeymapAction::Newline => vec![
+                key_hint::shift(KeyCode::Enter),
+                key_hint::ctrl(KeyCode::Char('j')),
+            ],
+            KeymapAction::Queue => vec![key_hint::plain(KeyCode::Tab)],
+            KeymapAction::OpenEditor => vec![key_hint::ctrl(KeyCode::Char('g'))],
+            KeymapAction::ToggleTranscript => vec![key_hint::ctrl(KeyCode::Char('t'))],
+            KeymapAction::Backtrack | KeymapAction::Interrupt => {
+                vec![key_hint::plain(KeyCode::Esc)]
+            }
+            KeymapAction::Approve => vec![key_hint::plain(KeyCode::Char('y'))],
+            KeymapAction::ApproveForSession => vec![key_hint::plain(KeyCode::Char('a'))],
+            KeymapAction::Deny => vec![key_hint::plain(KeyCode::Char('n'))],
+            KeymapAction::ReviewHunks => vec![key_hint::plain(KeyCode::Char('h'))],
+        }
+    }
+
+    fn configured(self, config: &TuiKeymap) -> Option<&KeyChords> {
+        match self {
+            KeymapAction::Submit => config.submit.as_ref(),
+            KeymapAction::Newline => config.newline.as_ref(),
+            KeymapAction::Queue => config.queue.as_ref(),
+            KeymapAction::OpenEditor => config.open_editor.as_ref(),
+            KeymapAction::ToggleTranscript => config.toggle_transcript.as_ref(),
+            KeymapAction::Backtrack => config.backtrack.as_ref(),
+            KeymapAction::Interrupt => config.interrupt.as_ref(),
+            KeymapAction::Approve => config.approve.as_ref(),
+            KeymapAction::ApproveForSession => config.approve_for_session.as_ref(),
+            KeymapAction::Deny => config.deny.as_ref(),
+            KeymapAction::ReviewHunks => config.review_hunks.as_ref(),
+        }
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub(crate) enum KeymapError {
+    #[error("tui.keymap.{action}: invalid key `{chord}`: {reason}")]
+    InvalidChord {
+        action: &'static str,
+        chord: String,
+        reason: String,
+    },
+    #[error("tui.keymap.{action}: at least one key is required")]
+    Empty { action: &'static str },
+    #[error("tui.keymap.{action}: {chord} is reserved for {purpose}")]
+    Reserved {
+        action: &'static str,
+        chord: String,
+        purpose: &'static str,
+    },
+    #[error(
+        "tui.keymap.{action}: {chord} types a character; add ctrl or alt, or use a non-character key"
+    )]
+    Printable { action: &'static str, chord: String },
+    #[error("tui.keymap: {chord} is bound to both `{first}` and `{second}`")]
+    Conflict {
+        chord: String,
+        first: &'static str,
+        second: &'static str,
+    },
+}
+
+/// The chords bound to one action. Never empty.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct KeyBindings(Vec<KeyBinding>);
+
+impl KeyBindings {
+    pub(crate) fn is_press(&self, event: KeyEvent) -> bool {
+        self.0.iter().any(|binding| binding.is_press(event))
+    }
+
+    /// The binding advertised in hints.
+    pub(crate) fn primary(&self) -> KeyBinding {
+        self.0[0]
+    }
+
+    pub(crate) fn iter(&self) -> impl Iterator<Item = KeyBinding> + '_ {
+        self.0.iter().copied()
+    }
+}
+
+/// Resolved key bindings for every configurable action.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct Keymap {
+    pub(crate) submit: KeyBindings,
+    pub(crate) newline: KeyBindings,
+    pub(crate) queue: KeyBindings,
+    pub(crate) open_editor: KeyBindings,
+    pub(crate) toggle_transcript: KeyBindings,
+    pub(crate) backtrack: KeyBindings,
+    pub(crate) interrupt: KeyBindings,
+    pub(crate) approve: KeyBindings,
+    pub(crate) approve_for_session: KeyBindings,
+    pub(crate) deny: KeyBindings,
+    pub(crate) review_hunks: KeyBindings,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self::from_bindings(
+            KeymapAction::ALL
+                .into_iter()
+                .map(|action| (action, KeyBindings(action.default_bindings())))
+                .collect(),
+        )
+    }
+}
+
+impl Keymap {
+    pub(crate) fn from_config(config: &TuiKeymap) -> Result<Self, KeymapError> {
+        let mut bindings = HashMap::new();
+        for action in KeymapAction::ALL {
+            let action_bindings = match action.configured(config) {
+                Some(chords) => parse_action_chords(action, chords)?,
+                None => action.default_bindings(),
+            };
+            check_reserved(action, &action_bindings)?;
+            if matches!(action, KeymapAction::Submit | KeymapAction::Newline)
+                && let Some(binding) = action_bindings.iter().find(|binding| types_text(**binding))
+            {
+                return Err(KeymapError::Printable {
+                    action: action.name(),
+                    chord: binding.to_string(),
+                });
+            }
+            bindings.insert(action, KeyBindings(action_bindings));
+        }
+        check_conflicts(&bindings)?;
+        Ok(Self::from_bindings(bindings))
+    }
+
+    /// Like [`Keymap::from_config`], but falls back to the defaults. Used when a config is
+    /// reloaded mid-session, after the startup check has already reported any error.
+    pub(crate) fn from_config_or_default(config: &TuiKeymap) -> Self {
+        Self::from_config(config).unwrap_or_else(|err| {
+            tracing::warn!("ignoring invalid keymap: {err}");
+            Self::default()
+        })
+    }
+
+    fn from_bindings(mut bindings: HashMap<KeymapAction, KeyBindings>) -> Self {
+        let mut take = |action: KeymapAction| {
+            bindings
+                .remove(&action)
+                .unwrap_or_else(|| KeyBindings(action.default_bindings()))
+        };
+        Self {
+            submit: take(KeymapAction::Submit),
+            newline: take(KeymapAction::Newline),
+            queue: take(KeymapAction::Queue),
+            open_editor: take(KeymapAction::OpenEditor),
+            toggle_transcript: take(KeymapAction::ToggleTranscript),
+            backtrack: take(KeymapAction::Backtrack),
+            interrupt: take(KeymapAction::Interrupt),
+            approve: take(KeymapAction::Approve),
+            approve_for_session: take(KeymapAction::ApproveForSession),
+            deny: take(KeymapAction::Deny),
+            review_hunks: take(KeymapAction::ReviewHunks),
+        }
+    }
+
+    /// The newline binding to advertise. Modified Enter only reaches us when the terminal
+    /// supports enhanced keyboard reporting, so prefer another binding when it does not.
+    pub(crate) fn newline_hint(&self, enhanced_keys_supported: bool) -> KeyBinding {
+        self.newline
+            .iter()
+            .find(|binding| enhanced_keys_supported || !requires_enhanced_keys(*binding))
+            .unwrap_or_else(|| self.newline.primary())
+    }
+}
+
+fn requires_enhanced_keys(binding: KeyBinding) -> bool {
+    binding.key() == KeyCode::Enter
+        && binding
+            .modifiers()
+            .intersects(KeyModifiers::SHIFT | KeyModifiers::CONTROL)
+}
+
+fn parse_action_chords(
+    action: KeymapAction,
+    chords: &KeyChords,
+) -> Result<Vec<KeyBinding>, KeymapError> {
+    let chords = chords.chords();
+    if chords.is_empty() {
+        return Err(KeymapError::Empty {
+            action: action.name(),
+        });
+    }
+    let mut bindings = Vec::with_capacity(chords.len());
+    for chord in chords {
+        let binding = parse_key_chord(chord).map_err(|reason| KeymapError::InvalidChord {
+            action: action.name(),
+            chord: chord.clone(),
+            reason,
+        })?;
+        if !bindings.contains(&binding) {
+            bindings.push(binding);
+        }
+    }
+    Ok(bindings)
+}
+
+fn check_reserved(action: KeymapAction, bindings: &[KeyBinding]) -> Result<(), KeymapError> {
+    let reserved = RESERVED.iter().find(|reserved| {
+        bindings.contains(&reserved.binding)
+            && reserved
+                .contexts
+                .iter()
+                .any(|context| action.contexts().contains(context))
+    });
+    match reserved {
+        Some(reserved) => Err(KeymapError::Reserved {
+            action: action.name(),
+            chord: reserved.binding.to_string(),
+            purpose: reserved.purpose,
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Whether `binding` inserts a character into the composer, which would make it impossible to
+/// type that character.
+fn types_text(binding: KeyBinding) -> bool {
+    matches!(binding.key(), KeyCode::Char(_))
+        && binding
+            .modifiers()
+            .difference(KeyModifiers::SHIFT)
+            .is_empty()
+}
+
+fn check_conflicts(bindings: &HashMap<KeymapAction, KeyBindings>) -> Result<(), KeymapError> {
+    for (idx, first) in KeymapAction::ALL.iter().enumerate() {
+        for second in &KeymapAction::ALL[idx + 1..] {
+            let shares_context = first
+                .contexts()
+                .iter()
+                .any(|context| second.contexts().contains(context));
+            if !shares_context {
+                continue;
+            }
+            if let Some(binding) = bindings[first]
+                .iter()
+                .find(|binding| bindings[second].0.contains(binding))
+            {
+                return Err(KeymapError::Conflict {
+                    chord: binding.to_string(),
+                    first: first.name(),
+                    second: second.name(),
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Parses a chord such as `ctrl+t`, `shift+enter`, `alt+up` or `?`.
+fn parse_key_chord(chord: &str) -> Result<KeyBinding, String> {
+    let chord = chord.trim();
+    let (modifier_part, key_part) = if chord == "+" {
+        ("", "+")
+    } else if let Some(prefix) = chord.strip_suffix("++") {
+        (prefix, "+")
+    } else {
+        chord.rsplit_once('+').unwrap_or(("", chord))
+    };
+
+    let mut modifiers = KeyModifiers::NONE;
+    if !modifier_part.is_empty() {
+        for modifier in modifier_part.split('+') {
+            modifiers |= match modifier.trim().to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => KeyModifiers::CONTROL,
+                "alt" | "option" | "meta" => KeyModifiers::ALT,
+                "shift" => KeyModifiers::SHIFT,
+                "" => return Err("empty modifier".to_string()),
+                other => return Err(format!("unknown modifier `{other}`")),
+            };
+        }
+    }
+
+    let key_part = key_part.trim();
+    let mut chars = key_part.chars();
+    let key = match (chars.next(), chars.next()) {
+        (None, _) => return Err("missing key".to_string()),
+        (Some(c), None) => {
+            // Terminals report Shift+letter as the uppercase letter with SHIFT set.
+            if c.is_ascii_uppercase() {
+                modifiers |= KeyModifiers::SHIFT;
+            }
+            if modifiers.contains(KeyModifiers::SHIFT) && c.is_ascii_alphabetic() {
+                KeyCode::Char(c.to_ascii_uppercase())
+            } else {
+                KeyCode::Char(c)
+            }
+        }
+        _ => match key_part.to_ascii_lowercase().as_str() {
+            "enter" | "return" => KeyCode::Enter,
+            "esc" | "escape" => KeyCode::Esc,
+            "tab" if modifiers.contains(KeyModifiers::SHIFT) => KeyCode::BackTab,
+            "tab" => KeyCode::Tab,
+            "space" => KeyCode::Char(' '),
+            "backspace" => KeyCode::Backspace,
+            "delete" | "del" => KeyCode::Delete,
+            "insert" | "ins" => KeyCode::Insert,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            "pageup" | "pgup" => KeyCode::PageUp,
+            "pagedown" | "pgdn" => KeyCode::PageDown,
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            name => match name.strip_prefix('f').and_then(|n| n.parse::<u8>().ok()) {
+                Some(n) if (1..=24).contains(&n) => KeyCode::F(n),
+                _ => return Err(format!("unknown key `{key_part}`")),
+            },
+        },
+    };
+    Ok(KeyBinding::new(key, modifiers))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn parses_common_chords() {
+        assert_eq!(
+            parse_key_chord("ctrl+t"),
+            Ok(key_hint::ctrl(KeyCode::Char('t')))
+        );
+        assert_eq!(
+            parse_key_chord("Shift + Enter"),
+            Ok(key_hint::shift(KeyCode::Enter))
+        );
+        assert_eq!(
+            parse_key_chord("ctrl+alt+v"),
+            Ok(key_hint::ctrl_alt(KeyCode::Char('v')))
+        );
+        assert_eq!(
+            parse_key_chord("shift+tab"),
+            Ok(key_hint::shift(KeyCode::BackTab))
+        );
+        assert_eq!(
+            parse_key_chord("shift+y"),
+            Ok(key_hint::shift(KeyCode::Char('Y')))
+        );
+        assert_eq!(
+            parse_key_chord("Y"),
+            Ok(key_hint::shift(KeyCode::Char('Y')))
+        );
+        assert_eq!(parse_key_chord("f5"), Ok(key_hint::plain(KeyCode::F(5))));
+        assert_eq!(
+            parse_key_chord("+"),
+            Ok(key_hint::plain(KeyCode::Char('+')))
+        );
+        assert_eq!(
+            parse_key_chord("ctrl++"),
+            Ok(key_hint::ctrl(KeyCode::Char('+')))
+        );
+        assert_eq!(
+            parse_key_chord("hyper+x"),
+            Err("unknown modifier `hyper`".to_string())
+        );
+        assert_eq!(
+            parse_key_chord("ctrl+enterr"),
+            Err("unknown key `enterr`".to_string())
+        );
+    }
+
+    #[test]
+    fn defaults_are_conflict_free() {
+        assert_eq!(
+            Keymap::from_config(&TuiKeymap::default()),
+            Ok(Keymap::default())
+        );
+    }
+
+    #[test]
+    fn configured_chords_replace_defaults() {
+        let keymap = Keymap::from_config(&TuiKeymap {
+            submit: Some(KeyChords::One("ctrl+enter".to_string())),
+            newline: Some(KeyChords::Many(vec![
+                "enter".to_string(),
+                "alt+enter".to_string(),
+            ])),
+            ..Default::default()
+        })
+        .expect("valid keymap");
+
+        assert!(
+            keymap
+                .submit
+                .is_press(KeyEvent::new(KeyCode::Enter, KeyModifiers::CONTROL))
+        );
+        assert!(
+            !keymap
+                .submit
+                .is_press(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE))
+        );
+        assert!(
+            keymap
+                .newline
+                .is_press(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE))
+        );
+        assert_eq!(
+            keymap.toggle_transcript,
+            Keymap::default().toggle_transcript
+        );
+    }
+
+    #[test]
+    fn rejects_chords_shared_within_a_context() {
+        let err = Keymap::from_config(&TuiKeymap {
+            open_editor: Some(KeyChords::One("ctrl+t".to_string())),
+            ..Default::default()
+        })
+        .expect_err("open_editor and toggle_transcript overlap");
+        assert_eq!(
+            err,
+            KeymapError::Conflict {
+                chord: "ctrl + t".to_string(),
+                first: "open_editor",
+                second: "toggle_transcript",
+            }
+        );
+    }
+
+    #[test]
+    fn allows_chords_shared_across_contexts() {
+        // `approve` only applies to approval prompts, `queue` only to a running composer.
+        let keymap = Keymap::from_config(&TuiKeymap {
+            approve: Some(KeyChords::One("tab".to_string())),
+            ..Default::default()
+        })
+        .expect("approve and queue never overlap");
+        assert_eq!(keymap.approve.primary(), key_hint::plain(KeyCode::Tab));
+    }
+
+    #[test]
+    fn rejects_empty_and_reserved_bindings() {
+        assert_eq!(
+            Keymap::from_config(&TuiKeymap {
+                submit: Some(KeyChords::Many(Vec::new())),
+                ..Default::default()
+            }),
+            Err(KeymapError::Empty { action: "submit" })
+        );
+        assert_eq!(
+            Keymap::from_config(&TuiKeymap {
+                deny: Some(KeyChords::One("ctrl+c".to_string())),
+                ..Default::default()
+            }),
+            Err(KeymapError::Reserved {
+                action: "deny",
+                chord: "ctrl + c".to_string(),
+                purpose: "interrupting and quitting",
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_hard_coded_keys_only_where_they_are_live() {
+        assert_eq!(
+            Keymap::from_config(&TuiKeymap {
+                approve: Some(KeyChords::One("p".to_string())),
+                ..Default::default()
+            }),
+            Err(KeymapError::Reserved {
+                action: "approve",
+                chord: "p".to_string(),
+                purpose: "approving a command prefix",
+            })
+        );
+        assert_eq!(
+            Keymap::from_config(&TuiKeymap {
+                deny: Some(KeyChords::One("esc".to_string())),
+                ..Default::default()
+            }),
+            Err(KeymapError::Reserved {
+                action: "deny",
+                chord: "esc".to_string(),
+                purpose: "cancelling the approval prompt",
+            })
+        );
+        for (chord, purpose) in [
+            ("ctrl+a", "editing the composer"),
+            ("ctrl+w", "editing the composer"),
+            ("ctrl+d", "quitting from an empty composer"),
+            ("shift+tab", "cycling collaboration modes"),
+        ] {
+            let err = Keymap::from_config(&TuiKeymap {
+                queue: Some(KeyChords::One(chord.to_string())),
+                ..Default::default()
+            })
+            .expect_err(chord);
+            assert!(
+                matches!(err, KeymapError::Reserved { action: "queue", purpose: reserved, .. } if reserved == purpose),
+                "{chord}: {err}"
+            );
+        }
+        // Composer editing keys are free while an approval prompt has focus.
+        assert!(
+            Keymap::from_config(&TuiKeymap {
+                approve: Some(KeyChords::One("ctrl+a".to_string())),
+                ..Default::default()
+            })
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn rejects_printable_submit_and_newline_keys() {
+        assert_eq!(
+            Keymap::from_config(&TuiKeymap {
+                submit: Some(KeyChords::Many(vec!["enter".to_string(), "s".to_string()])),
+                ..Default::default()
+            }),
+            Err(KeymapError::Printable {
+                action: "submit",
+                chord: "s".to_string(),
+            })
+        );
+        assert!(matches!(
+            Keymap::from_config(&TuiKeymap {
+                newline: Some(KeyChords::One("shift+n".to_string())),
+                ..Default::default()
+            }),
+            Err(KeymapError::Printable {
+                action: "newline",
+                ..
+            })
+        ));
+        assert!(
+            Keymap::from_config(&TuiKeymap {
+                newline: Some(KeyChords::One("alt+n".to_string())),
+                ..Default::default()
+            })
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn newline_hint_skips_modified_enter_without_enhanced_keys() {
+        let keymap = Keymap::default();
+        assert_eq!(keymap.newline_hint(true), key_hint::shift(KeyCode::Enter));
+        assert_eq!(
+            keymap.newline_hint(false),
+            key_hint::ctrl(KeyCode::Char('j'))
+        );
+    }
+}
@@ -23,6 +23,7 @@ use codex_core::config::ConfigOverrides;
 use codex_core::config::find_codex_home;
 use codex_core::config::load_config_as_toml_with_cli_overrides;
 use codex_core::config::resolve_oss_provider;
+use codex_core::config::types::WorktreeExitAction;
 use codex_core::config_loader::CloudRequirementsLoader;
 use codex_core::config_loader::ConfigLoadError;
 use codex_core::config_loader::format_config_error_with_source;
@@ -32,6 +33,7 @@ use codex_core::find_thread_path_by_name_str;
 use codex_core::path_utils;
 use codex_core::protocol::AskForApproval;
 use codex_core::read_session_meta_line;
+use codex_core::session_worktree::SessionWorktree;
 use codex_core::terminal::Multiplexer;
 use codex_core::windows_sandbox::WindowsSandboxLevelExt;
 use codex_protocol::config_types::AltScreenMode;
@@ -79,6 +81,7 @@ mod get_git_diff;
 mod history_cell;
 pub mod insert_history;
 mod key_hint;
+mod keymap;
 pub mod live_wrap;
 mod markdown;
 mod markdown_render;
@@ -110,6 +113,7 @@ mod update_prompt;
 mod updates;
 mod version;
 
+mod worktree_prompt;
 mod wrapping;
 
 #[cfg(test)]
@@ -159,6 +163,11 @@ pub async fn run_main_with_shutdown_token(
             .raw_overrides
             .push("web_search=\"live\"".to_string());
     }
+    if cli.worktree {
+        cli.config_overrides
+            .raw_overrides
+            .push("worktree.enabled=true".to_string());
+    }
 
     // When using `--oss`, let the bootstrapper pick the model (defaulting to
     // gpt-oss:20b) and ensure it is present locally. Also, force the built‑in
@@ -273,7 +282,7 @@ pub async fn run_main_with_shutdown_token(
 
     let additional_dirs = cli.add_dir.clone();
 
-    let overrides = ConfigOverrides {
+    let mut overrides = ConfigOverrides {
         model,
         approval_policy,
         sandbox_mode,
@@ -286,7 +295,7 @@ pub async fn run_main_with_shutdown_token(
         ..Default::default()
     };
 
-    let config = load_config_or_exit(
+    let mut config = load_config_or_exit(
         cli_kv_overrides.clone(),
         overrides.clone(),
         cloud_requirements.clone(),
@@ -294,19 +303,57 @@ pub async fn run_main_with_shutdown_token(
     .await;
     set_default_client_residency_requirement(config.enforce_residency.value());
 
+    // Move the whole session into a dedicated worktree before anything else
+    // resolves paths against the original checkout.
+    let session_worktree = if config.worktree.enabled {
+        let session_worktree = match SessionWorktree::create(&config) {
+            Ok(session_worktree) => session_worktree,
+            #[allow(clippy::print_stderr)]
+            Err(err) => {
+                eprintln!("Error creating session worktree: {err}");
+                std::process::exit(1);
+            }
+        };
+        overrides.cwd = Some(session_worktree.cwd().to_path_buf());
+        config = match ConfigBuilder::default()
+            .cli_overrides(cli_kv_overrides.clone())
+            .harness_overrides(overrides.clone())
+            .cloud_requirements(cloud_requirements.clone())
+            .build()
+            .await
+        {
+            Ok(config) => config,
+            #[allow(clippy::print_stderr)]
+            Err(err) => {
+                eprintln!("Error loading configuration: {err}");
+                exit_discarding_worktree(Some(&session_worktree));
+            }
+        };
+        Some(session_worktree)
+    } else {
+        None
+    };
+
     if let Some(warning) = add_dir_warning_message(&cli.add_dir, config.sandbox_policy.get()) {
         #[allow(clippy::print_stderr)]
         {
             eprintln!("Error adding directories: {warning}");
-            std::process::exit(1);
+            exit_discarding_worktree(session_worktree.as_ref());
         }
     }
 
     #[allow(clippy::print_stderr)]
     if let Err(err) = enforce_login_restrictions(&config) {
         eprintln!("{err}");
-        std::process::exit(1);
+        exit_discarding_worktree(session_worktree.as_ref());
+    }
+
+    #[allow(clippy::print_stderr)]
+    if let Err(err) = keymap::Keymap::from_config(&config.tui_keymap) {
+        eprintln!("Error loading config.toml: {err}");
+        exit_discarding_worktree(session_worktree.as_ref());
     }
+    render::highlight::set_syntax_highlighting_enabled(config.syntax_highlighting);
 
     let log_dir = codex_core::config::log_dir(&config)?;
     std::fs::create_dir_all(&log_dir)?;
@@ -413,11 +460,13 @@ pub async fn run_main_with_shutdown_token(
         cloud_requirements,
         feedback,
         shutdown_token,
+        session_worktree,
     )
     .await
     .map_err(|err| std::io::Error::other(err.to_string()))
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn run_ratatui_app(
     cli: Cli,
     initial_config: Config,
@@ -426,6 +475,7 @@ async fn run_ratatui_app(
     mut cloud_requirements: CloudRequirementsLoader,
     feedback: codex_feedback::CodexFeedback,
     shutdown_token: Option<CancellationToken>,
+    session_worktree: Option<SessionWorktree>,
 ) -> color_eyre::Result<AppExitInfo> {
     color_eyre::install()?;
 
@@ -703,6 +753,7 @@ async fn run_ratatui_app(
 
     let use_alt_screen = determine_alt_screen_mode(no_alt_screen, config.tui_alternate_screen);
     tui.set_alt_screen_enabled(use_alt_screen);
+    let worktree_on_exit = config.worktree.on_exit;
 
     let _shutdown_token = shutdown_token;
     let app_result = App::run(
@@ -720,7 +771,17 @@ async fn run_ratatui_app(
     )
     .await;
 
+    let worktree_outcome = match session_worktree.as_ref() {
+        Some(session_worktree) => {
+            Some(finish_session_worktree(&mut tui, session_worktree, worktree_on_exit).await)
+        }
+        None => None,
+    };
+
     restore();
+    if let Some(message) = worktree_outcome {
+        print_after_restore(&message);
+    }
     // Mark the end of the recorded session.
     session_log::log_session_end();
     // ignore error when collecting usage – report underlying error instead
@@ -798,6 +859,41 @@ pub(crate) async fn resolve_cwd_for_resume_or_fork(
     Ok(Some(history_cwd))
 }
 
+/// Applies the configured exit action to the session worktree, asking the user
+/// when the session left changes behind. Returns the message to print once the
+/// terminal has been restored.
+async fn finish_session_worktree(
+    tui: &mut Tui,
+    session_worktree: &SessionWorktree,
+    on_exit: WorktreeExitAction,
+) -> String {
+    let action = match (on_exit, session_worktree.has_changes()) {
+        (WorktreeExitAction::Ask, Ok(true)) => worktree_prompt::run_worktree_exit_prompt(
+            tui,
+            session_worktree.path(),
+            session_worktree.branch(),
+        )
+        .await
+        .unwrap_or(WorktreeExitAction::Keep),
+        (action, _) => action,
+    };
+    match session_worktree.finish(action) {
+        Ok(outcome) => session_worktree.outcome_message(outcome),
+        Err(err) => format!(
+            "Failed to finish worktree {}: {err}",
+            session_worktree.path().display()
+        ),
+    }
+}
+
+#[expect(
+    clippy::print_stderr,
+    reason = "TUI should no longer be displayed, so we can write to stderr."
+)]
+fn print_after_restore(message: &str) {
+    eprintln!("{message}");
+}
+
 #[expect(
     clippy::print_stderr,
     reason = "TUI should no longer be displayed, so we can write to stderr."
@@ -865,6 +961,23 @@ fn get_login_status(config: &Config) -> LoginStatus {
     }
 }
 
+/// Exits with status 1, first removing the session worktree (and its branch)
+/// created for a session that never started.
+fn exit_discarding_worktree(session_worktree: Option<&SessionWorktree>) -> ! {
+    if let Some(session_worktree) = session_worktree
+        && let Err(err) = session_worktree.finish(WorktreeExitAction::Discard)
+    {
+        #[allow(clippy::print_stderr)]
+        {
+            eprintln!(
+                "Failed to remove worktree {}: {err}",
+                session_worktree.path().display()
+            );
+        }
+    }
+    std::process::exit(1);
+}
+
 async fn load_config_or_exit(
     cli_kv_overrides: Vec<(String, toml::Value)>,
     overrides: ConfigOverrides,
@@ -1,3 +1,5 @@
+use crate::render::highlight::Language;
+use crate::render::highlight::highlight_code_to_lines;
 use crate::render::line_utils::line_to_static;
 use crate::wrapping::RtOptions;
 use crate::wrapping::word_wrap_line;
@@ -99,6 +101,9 @@ where
     pending_marker_line: bool,
     in_paragraph: bool,
     in_code_block: bool,
+    /// Source of a fenced code block in a recognized language, buffered so it
+    /// can be highlighted as a whole when the block ends.
+    code_block_source: Option<(Language, String)>,
     wrap_width: Option<usize>,
     current_line_content: Option<Line<'static>>,
     current_initial_indent: Vec<Span<'static>>,
@@ -124,6 +129,7 @@ where
             pending_marker_line: false,
             in_paragraph: false,
             in_code_block: false,
+            code_block_source: None,
             wrap_width,
             current_line_content: None,
             current_initial_indent: Vec::new(),
@@ -274,6 +280,10 @@ where
     }
 
     fn text(&mut self, text: CowStr<'a>) {
+        if let Some((_, source)) = self.code_block_source.as_mut() {
+            source.push_str(&text);
+            return;
+        }
         if self.pending_marker_line {
             self.push_line(Line::default());
         }
@@ -394,7 +404,7 @@ where
         self.needs_newline = false;
     }
 
-    fn start_codeblock(&mut self, _lang: Option<String>, indent: Option<Span<'static>>) {
+    fn start_codeblock(&mut self, lang: Option<String>, indent: Option<Span<'static>>) {
         self.flush_current_line();
         if !self.text.lines.is_empty() {
             self.push_blank_line();
@@ -406,14 +416,49 @@ where
             false,
         ));
         self.needs_newline = true;
+        self.code_block_source = lang
+            .as_deref()
+            .and_then(Language::from_fence_info)
+            .map(|language| (language, String::new()));
     }
 
     fn end_codeblock(&mut self) {
+        if let Some((language, source)) = self.code_block_source.take()
+            && !source.is_empty()
+        {
+            match highlight_code_to_lines(&source, language) {
+                Some(lines) => self.push_highlighted_code(lines, source.ends_with('\n')),
+                None => self.text(source.into()),
+            }
+        }
         self.needs_newline = true;
         self.in_code_block = false;
         self.indent_stack.pop();
     }
 
+    fn push_highlighted_code(&mut self, mut lines: Vec<Line<'static>>, trailing_newline: bool) {
+        if trailing_newline
+            && lines
+                .last()
+                .is_some_and(|line| line.spans.iter().all(|span| span.content.is_empty()))
+        {
+            lines.pop();
+        }
+        let base = self.inline_styles.last().copied().unwrap_or_default();
+        for line in lines {
+            self.push_line(Line::default());
+            for span in line
+                .spans
+                .into_iter()
+                .filter(|span| !span.content.is_empty())
+            {
+                let style = base.patch(span.style);
+                self.push_span(span.style(style));
+            }
+        }
+        self.needs_newline = false;
+    }
+
     fn push_inline_style(&mut self, style: Style) {
         let current = self.inline_styles.last().copied().unwrap_or_default();
         let merged = current.patch(style);
@@ -1,4 +1,5 @@
 use pretty_assertions::assert_eq;
+use ratatui::style::Color;
 use ratatui::style::Stylize;
 use ratatui::text::Line;
 use ratatui::text::Span;
@@ -653,11 +654,34 @@ fn link() {
 
 #[test]
 fn code_block_unhighlighted() {
-    let text = render_markdown_text("```rust\nfn main() {}\n```\n");
+    let text = render_markdown_text("```text\nfn main() {}\n```\n");
     let expected = Text::from_iter([Line::from_iter(["", "fn main() {}"])]);
     assert_eq!(text, expected);
 }
 
+#[test]
+fn code_block_highlighted_by_fence_language() {
+    let text = render_markdown_text("```rust\nfn main() {\n    let x = 1;\n}\n```\n");
+    let lines: Vec<String> = text
+        .lines
+        .iter()
+        .map(|l| {
+            l.spans
+                .iter()
+                .map(|s| s.content.clone())
+                .collect::<String>()
+        })
+        .collect();
+    assert_eq!(lines, vec!["fn main() {", "    let x = 1;", "}"]);
+
+    let keyword = text.lines[0]
+        .spans
+        .iter()
+        .find(|s| s.content == "fn")
+        .expect("fn span");
+    assert_eq!(keyword.style.fg, Some(Color::Magenta));
+}
+
 #[test]
 fn code_block_multiple_lines_root() {
     let md = "```\nfirst\nsecond\n```\n";
@@ -14,8 +14,13 @@
 //! `TranscriptOverlay::sync_live_tail` uses the key to decide when the cached tail must be
 //! recomputed. `ChatWidget` is responsible for producing a key that changes when the active cell
 //! mutates in place or when its transcript output is time-dependent.
+//!
+//! The transcript overlay also supports an incremental `/` search over the committed cells. Matches
+//! are found in the wrapped transcript lines, so they are recomputed lazily on render whenever the
+//! query, the cells, or the width change; `n`/`N` then step between them.
 
 use std::io::Result;
+use std::ops::Range;
 use std::sync::Arc;
 
 use crate::chatwidget::ActiveCellTranscriptKey;
@@ -23,6 +28,8 @@ use crate::history_cell::HistoryCell;
 use crate::history_cell::UserHistoryCell;
 use crate::key_hint;
 use crate::key_hint::KeyBinding;
+use crate::keymap::KeyBindings;
+use crate::keymap::Keymap;
 use crate::render::Insets;
 use crate::render::renderable::InsetRenderable;
 use crate::render::renderable::Renderable;
@@ -31,6 +38,8 @@ use crate::tui;
 use crate::tui::TuiEvent;
 use crossterm::event::KeyCode;
 use crossterm::event::KeyEvent;
+use crossterm::event::KeyEventKind;
+use crossterm::event::KeyModifiers;
 use ratatui::buffer::Buffer;
 use ratatui::buffer::Cell;
 use ratatui::layout::Rect;
@@ -51,8 +60,8 @@ pub(crate) enum Overlay {
 }
 
 impl Overlay {
-    pub(crate) fn new_transcript(cells: Vec<Arc<dyn HistoryCell>>) -> Self {
-        Self::Transcript(TranscriptOverlay::new(cells))
+    pub(crate) fn new_transcript(cells: Vec<Arc<dyn HistoryCell>>, keymap: &Keymap) -> Self {
+        Self::Transcript(TranscriptOverlay::new(cells).with_keymap(keymap))
     }
 
     pub(crate) fn new_static_with_lines(lines: Vec<Line<'static>>, title: String) -> Self {
@@ -100,8 +109,13 @@ const KEY_CTRL_U: KeyBinding = key_hint::ctrl(KeyCode::Char('u'));
 const KEY_Q: KeyBinding = key_hint::plain(KeyCode::Char('q'));
 const KEY_ESC: KeyBinding = key_hint::plain(KeyCode::Esc);
 const KEY_ENTER: KeyBinding = key_hint::plain(KeyCode::Enter);
-const KEY_CTRL_T: KeyBinding = key_hint::ctrl(KeyCode::Char('t'));
 const KEY_CTRL_C: KeyBinding = key_hint::ctrl(KeyCode::Char('c'));
+const KEY_SLASH: KeyBinding = key_hint::plain(KeyCode::Char('/'));
+const KEY_N: KeyBinding = key_hint::plain(KeyCode::Char('n'));
+const KEY_SHIFT_N: KeyBinding = key_hint::shift(KeyCode::Char('N'));
+// Some terminals report Shift+n as a bare uppercase `N`.
+const KEY_UPPER_N: KeyBinding = key_hint::plain(KeyCode::Char('N'));
+const KEY_BACKSPACE: KeyBinding = key_hint::plain(KeyCode::Backspace);
 
 // Common pager navigation hints rendered on the first line
 const PAGER_KEY_HINTS: &[(&[KeyBinding], &str)] = &[
@@ -140,6 +154,8 @@ struct PagerView {
     last_rendered_height: Option<usize>,
     /// If set, on next render ensure this chunk is visible.
     pending_scroll_chunk: Option<usize>,
+    /// If set, on next render ensure this content row is visible.
+    pending_scroll_row: Option<usize>,
 }
 
 impl PagerView {
@@ -151,6 +167,7 @@ impl PagerView {
             last_content_height: None,
             last_rendered_height: None,
             pending_scroll_chunk: None,
+            pending_scroll_row: None,
         }
     }
 
@@ -173,6 +190,9 @@ impl PagerView {
         if let Some(idx) = self.pending_scroll_chunk.take() {
             self.ensure_chunk_visible(idx, content_area);
         }
+        if let Some(row) = self.pending_scroll_row.take() {
+            self.ensure_row_visible(row, content_area, content_height);
+        }
         self.scroll_offset = self
             .scroll_offset
             .min(content_height.saturating_sub(content_area.height as usize));
@@ -350,6 +370,26 @@ impl PagerView {
         self.pending_scroll_chunk = Some(chunk_index);
     }
 
+    /// Request that the given content row be scrolled into view on next render.
+    fn scroll_row_into_view(&mut self, row: usize) {
+        self.pending_scroll_row = Some(row);
+    }
+
+    fn ensure_row_visible(&mut self, row: usize, area: Rect, content_height: usize) {
+        if area.height == 0 {
+            return;
+        }
+        let page = area.height as usize;
+        // Resolve the "pinned to bottom" sentinel first so a row that is
+        // already on screen does not cause a jump.
+        self.scroll_offset = self.scroll_offset.min(content_height.saturating_sub(page));
+        if row < self.scroll_offset {
+            self.scroll_offset = row;
+        } else if row >= self.scroll_offset + page {
+            self.scroll_offset = row + 1 - page;
+        }
+    }
+
     fn ensure_chunk_visible(&mut self, idx: usize, area: Rect) {
         if area.height == 0 || idx >= self.renderables.len() {
             return;
@@ -405,12 +445,23 @@ impl Renderable for CachedRenderable {
 struct CellRenderable {
     cell: Arc<dyn HistoryCell>,
     style: Style,
+    /// Search matches to highlight in the cell's transcript lines.
+    highlights: Vec<SearchHighlight>,
 }
 
 impl Renderable for CellRenderable {
     fn render(&self, area: Rect, buf: &mut Buffer) {
-        let p =
-            Paragraph::new(Text::from(self.cell.transcript_lines(area.width))).style(self.style);
+        let mut lines = self.cell.transcript_lines(area.width);
+        for highlight in &self.highlights {
+            if let Some(line) = lines.get_mut(highlight.line) {
+                *line = highlight_range(
+                    std::mem::take(line),
+                    highlight.range.clone(),
+                    highlight.style(),
+                );
+            }
+        }
+        let p = Paragraph::new(Text::from(lines)).style(self.style);
         p.render(area, buf);
     }
 
@@ -419,6 +470,143 @@ impl Renderable for CellRenderable {
     }
 }
 
+/// A search match to highlight within a single cell.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct SearchHighlight {
+    line: usize,
+    /// Byte range within the concatenated span text of the line.
+    range: Range<usize>,
+    current: bool,
+}
+
+impl SearchHighlight {
+    fn style(&self) -> Style {
+        if self.current {
+            Style::default().cyan().reversed().bold()
+        } else {
+            Style::default().reversed()
+        }
+    }
+}
+
+/// Restyle the bytes in `range` of `line`, splitting spans at its boundaries.
+fn highlight_range(line: Line<'static>, range: Range<usize>, style: Style) -> Line<'static> {
+    let Line {
+        spans,
+        style: line_style,
+        alignment,
+    } = line;
+    let mut out = Vec::with_capacity(spans.len() + 2);
+    let mut offset = 0;
+    for span in spans {
+        let len = span.content.len();
+        let start = range.start.clamp(offset, offset + len) - offset;
+        let end = range.end.clamp(offset, offset + len) - offset;
+        offset += len;
+        if start == end {
+            out.push(span);
+            continue;
+        }
+        let content = span.content.as_ref();
+        if start > 0 {
+            out.push(Span::styled(content[..start].to_string(), span.style));
+        }
+        out.push(Span::styled(
+            content[start..end].to_string(),
+            span.style.patch(style),
+        ));
+        if end < len {
+            out.push(Span::styled(content[end..].to_string(), span.style));
+        }
+    }
+    Line {
+        spans: out,
+        style: line_style,
+        alignment,
+    }
+}
+
+/// A match of the transcript search query.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct SearchMatch {
+    cell: usize,
+    /// Index into the cell's transcript lines.
+    line: usize,
+    range: Range<usize>,
+    /// Content row of the match within the pager.
+    row: usize,
+}
+
+/// State of the incremental `/` search in the transcript overlay.
+#[derive(Default)]
+struct TranscriptSearch {
+    query: String,
+    /// Whether the query is still being typed.
+    editing: bool,
+    /// Scroll offset when the search started. While typing, the current match
+    /// is the first one at or below it, wrapping around to the top.
+    origin: usize,
+    matches: Vec<SearchMatch>,
+    current: Option<usize>,
+    /// Width `matches` were computed for; `None` when they are stale.
+    width: Option<u16>,
+    /// Scroll the current match into view once matches are up to date.
+    reveal: bool,
+}
+
+impl TranscriptSearch {
+    fn highlights_for(&self, cell: usize) -> Vec<SearchHighlight> {
+        self.matches
+            .iter()
+            .enumerate()
+            .filter(|(_, m)| m.cell == cell)
+            .map(|(i, m)| SearchHighlight {
+                line: m.line,
+                range: m.range.clone(),
+                current: self.current == Some(i),
+            })
+            .collect()
+    }
+
+    fn current_match(&self) -> Option<&SearchMatch> {
+        self.current.and_then(|i| self.matches.get(i))
+    }
+}
+
+/// Find every occurrence of `query` in the transcript lines of `cells`.
+///
+/// Uses smart case: the search ignores ASCII case unless `query` contains an
+/// uppercase character. Rows are left at zero for the caller to fill in.
+fn find_matches(cells: &[Arc<dyn HistoryCell>], query: &str, width: u16) -> Vec<SearchMatch> {
+    if query.is_empty() {
+        return Vec::new();
+    }
+    let case_sensitive = query.chars().any(char::is_uppercase);
+    let needle = if case_sensitive {
+        query.to_string()
+    } else {
+        query.to_ascii_lowercase()
+    };
+    let mut matches = Vec::new();
+    for (cell_idx, cell) in cells.iter().enumerate() {
+        for (line_idx, line) in cell.transcript_lines(width).iter().enumerate() {
+            let mut text: String = line.spans.iter().map(|s| s.content.as_ref()).collect();
+            if !case_sensitive {
+                text.make_ascii_lowercase();
+            }
+            for (start, found) in text.match_indices(&needle) {
+                matches.push(SearchMatch {
+                    cell: cell_idx,
+                    line: line_idx,
+                    range: start..start + found.len(),
+                    row: 0,
+                });
+            }
+        }
+    }
+    matches
+}
+
 pub(crate) struct TranscriptOverlay {
     /// Pager UI state and the renderables currently displayed.
     ///
@@ -431,6 +619,11 @@ pub(crate) struct TranscriptOverlay {
     /// Cache key for the render-only live tail appended after committed cells.
     live_tail_key: Option<LiveTailKey>,
     is_done: bool,
+    /// Closes the overlay, in addition to `q` and Ctrl+C.
+    toggle_keys: KeyBindings,
+    /// Advertised as the key that steps back to earlier messages.
+    backtrack_key: KeyBinding,
+    search: TranscriptSearch,
 }
 
 /// Cache key for the active-cell "live tail" appended to the transcript overlay.
@@ -456,7 +649,7 @@ impl TranscriptOverlay {
     pub(crate) fn new(transcript_cells: Vec<Arc<dyn HistoryCell>>) -> Self {
         Self {
             view: PagerView::new(
-                Self::render_cells(&transcript_cells, None),
+                Self::render_cells(&transcript_cells, None, &TranscriptSearch::default()),
                 "T R A N S C R I P T".to_string(),
                 usize::MAX,
             ),
@@ -464,12 +657,23 @@ impl TranscriptOverlay {
             highlight_cell: None,
             live_tail_key: None,
             is_done: false,
+            toggle_keys: Keymap::default().toggle_transcript,
+            backtrack_key: KEY_ESC,
+            search: TranscriptSearch::default(),
         }
     }
 
+    /// Use the user's bindings for closing the overlay and stepping back.
+    pub(crate) fn with_keymap(mut self, keymap: &Keymap) -> Self {
+        self.toggle_keys = keymap.toggle_transcript.clone();
+        self.backtrack_key = keymap.backtrack.primary();
+        self
+    }
+
     fn render_cells(
         cells: &[Arc<dyn HistoryCell>],
         highlight_cell: Option<usize>,
+        search: &TranscriptSearch,
     ) -> Vec<Box<dyn Renderable>> {
         cells
             .iter()
@@ -484,11 +688,13 @@ impl TranscriptOverlay {
                         } else {
                             user_message_style()
                         },
+                        highlights: search.highlights_for(i),
                     })) as Box<dyn Renderable>
                 } else {
                     Box::new(CachedRenderable::new(CellRenderable {
                         cell: c.clone(),
                         style: Style::default(),
+                        highlights: search.highlights_for(i),
                     })) as Box<dyn Renderable>
                 };
                 if !c.is_stream_continuation() && i > 0 {
@@ -518,7 +724,8 @@ impl TranscriptOverlay {
         let had_prior_cells = !self.cells.is_empty();
         let tail_renderable = self.take_live_tail_renderable();
         self.cells.push(cell);
-        self.view.renderables = Self::render_cells(&self.cells, self.highlight_cell);
+        self.search.width = None;
+        self.view.renderables = Self::render_cells(&self.cells, self.highlight_cell, &self.search);
         if let Some(tail) = tail_renderable {
             let tail = if !had_prior_cells
                 && self
@@ -605,12 +812,142 @@ impl TranscriptOverlay {
 
     fn rebuild_renderables(&mut self) {
         let tail_renderable = self.take_live_tail_renderable();
-        self.view.renderables = Self::render_cells(&self.cells, self.highlight_cell);
+        self.view.renderables = Self::render_cells(&self.cells, self.highlight_cell, &self.search);
         if let Some(tail) = tail_renderable {
             self.view.renderables.push(tail);
         }
     }
 
+    /// Whether the search query is being typed, in which case every key
+    /// belongs to the search prompt rather than to the pager or `App`.
+    pub(crate) fn is_search_input_active(&self) -> bool {
+        self.search.editing
+    }
+
+    /// Recompute search matches if they are stale for `width`.
+    fn refresh_search(&mut self, width: u16) {
+        if self.search.width == Some(width) {
+            return;
+        }
+        let previous = self
+            .search
+            .current_match()
+            .map(|m| (m.cell, m.line, m.range.start));
+        let mut matches = find_matches(&self.cells, &self.search.query, width);
+
+        // Content row at which each cell's transcript lines start, skipping
+        // the spacing inset above the cell.
+        let mut top = 0;
+        let mut cell_rows = Vec::with_capacity(self.cells.len());
+        for (cell, renderable) in self.cells.iter().zip(&self.view.renderables) {
+            let height = renderable.desired_height(width) as usize;
+            let inset = height.saturating_sub(cell.desired_transcript_height(width) as usize);
+            cell_rows.push(top + inset);
+            top += height;
+        }
+        for m in &mut matches {
+            m.row = cell_rows.get(m.cell).copied().unwrap_or(top) + m.line;
+        }
+
+        self.search.current = if matches.is_empty() {
+            None
+        } else if self.search.editing {
+            Some(
+                matches
+                    .iter()
+                    .position(|m| m.row >= self.search.origin)
+                    .unwrap_or(0),
+            )
+        } else {
+            // Rewrapping can move the match, so fall back to the first match
+            // in the same cell.
+            let position = previous.and_then(|(cell, line, start)| {
+                matches
+                    .iter()
+                    .position(|m| (m.cell, m.line, m.range.start) == (cell, line, start))
+                    .or_else(|| matches.iter().position(|m| m.cell >= cell))
+            });
+            Some(position.unwrap_or(0))
+        };
+        self.search.matches = matches;
+        self.search.width = Some(width);
+        self.rebuild_renderables();
+    }
+
+    fn reveal_current_match(&mut self) {
+        if let Some(row) = self.search.current_match().map(|m| m.row) {
+            self.view.scroll_row_into_view(row);
+        }
+    }
+
+    /// Step to the next (or previous) match, wrapping around at either end.
+    fn step_search(&mut self, forward: bool) {
+        let len = self.search.matches.len();
+        let Some(current) = self.search.current else {
+            return;
+        };
+        self.search.current = Some(if forward {
+            (current + 1) % len
+        } else {
+            (current + len - 1) % len
+        });
+        self.rebuild_renderables();
+        self.reveal_current_match();
+    }
+
+    fn start_search(&mut self) {
+        self.search = TranscriptSearch {
+            editing: true,
+            origin: self.view.scroll_offset,
+            ..Default::default()
+        };
+        self.rebuild_renderables();
+    }
+
+    fn clear_search(&mut self) {
+        self.search = TranscriptSearch::default();
+        self.rebuild_renderables();
+    }
+
+    /// Apply a change to the query being typed and jump to the new match.
+    fn edit_search(&mut self, edit: impl FnOnce(&mut String)) {
+        edit(&mut self.search.query);
+        self.search.width = None;
+        self.search.reveal = true;
+    }
+
+    fn handle_search_key(&mut self, key_event: KeyEvent) {
+        match key_event {
+            e if KEY_ESC.is_press(e) || KEY_CTRL_C.is_press(e) => self.clear_search(),
+            e if KEY_ENTER.is_press(e) => {
+                if self.search.query.is_empty() {
+                    self.clear_search();
+                } else {
+                    self.search.editing = false;
+                }
+            }
+            e if KEY_BACKSPACE.is_press(e) => {
+                if self.search.query.is_empty() {
+                    self.clear_search();
+                } else {
+                    self.edit_search(|query| {
+                        query.pop();
+                    });
+                }
+            }
+            KeyEvent {
+                code: KeyCode::Char(c),
+                modifiers,
+                ..
+            } if !modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+                && key_event.kind != KeyEventKind::Release =>
+            {
+                self.edit_search(|query| query.push(c));
+            }
+            _ => {}
+        }
+    }
+
     /// Removes and returns the cached live-tail renderable, if present.
     ///
     /// The live tail is represented as a single optional renderable appended after the committed
@@ -636,15 +973,41 @@ impl TranscriptOverlay {
     fn render_hints(&self, area: Rect, buf: &mut Buffer) {
         let line1 = Rect::new(area.x, area.y, area.width, 1);
         let line2 = Rect::new(area.x, area.y.saturating_add(1), area.width, 1);
-        render_key_hints(line1, buf, PAGER_KEY_HINTS);
+        let match_count = match self.search.current {
+            Some(current) => format!("{}/{}", current + 1, self.search.matches.len()),
+            None => "no matches".to_string(),
+        };
+        if self.search.editing {
+            let mut spans: Vec<Span<'static>> = vec![
+                " /".into(),
+                self.search.query.clone().into(),
+                " ".reversed(),
+            ];
+            if !self.search.query.is_empty() {
+                spans.push("   ".into());
+                spans.push(match_count.dim());
+            }
+            Paragraph::new(Line::from(spans)).render_ref(line1, buf);
+        } else {
+            let search_hint;
+            let mut pairs: Vec<(&[KeyBinding], &str)> = PAGER_KEY_HINTS.to_vec();
+            if self.search.query.is_empty() {
+                pairs.push((&[KEY_SLASH], "to search"));
+            } else {
+                search_hint = format!("for next/prev match ({match_count})");
+                pairs.push((&[KEY_N, KEY_SHIFT_N], &search_hint));
+            }
+            render_key_hints(line1, buf, &pairs);
+        }
 
+        let step_back = [self.backtrack_key, KEY_LEFT];
         let mut pairs: Vec<(&[KeyBinding], &str)> = vec![(&[KEY_Q], "to quit")];
         if self.highlight_cell.is_some() {
-            pairs.push((&[KEY_ESC, KEY_LEFT], "to edit prev"));
+            pairs.push((&step_back, "to edit prev"));
             pairs.push((&[KEY_RIGHT], "to edit next"));
             pairs.push((&[KEY_ENTER], "to edit message"));
         } else {
-            pairs.push((&[KEY_ESC], "to edit prev"));
+            pairs.push((&step_back[..1], "to edit prev"));
         }
         render_key_hints(line2, buf, &pairs);
     }
@@ -653,6 +1016,10 @@ impl TranscriptOverlay {
         let top_h = area.height.saturating_sub(3);
         let top = Rect::new(area.x, area.y, area.width, top_h);
         let bottom = Rect::new(area.x, area.y + top_h, area.width, 3);
+        self.refresh_search(area.width);
+        if std::mem::take(&mut self.search.reveal) {
+            self.reveal_current_match();
+        }
         self.view.render(top, buf);
         self.render_hints(bottom, buf);
     }
@@ -661,11 +1028,41 @@ impl TranscriptOverlay {
 impl TranscriptOverlay {
     pub(crate) fn handle_event(&mut self, tui: &mut tui::Tui, event: TuiEvent) -> Result<()> {
         match event {
+            TuiEvent::Key(key_event) if self.search.editing => {
+                self.handle_search_key(key_event);
+                tui.frame_requester().schedule_frame();
+                Ok(())
+            }
+            TuiEvent::Paste(pasted) if self.search.editing => {
+                // The query is a single line.
+                let pasted = pasted.replace(['\r', '\n'], " ");
+                self.edit_search(|query| query.push_str(&pasted));
+                tui.frame_requester().schedule_frame();
+                Ok(())
+            }
             TuiEvent::Key(key_event) => match key_event {
-                e if KEY_Q.is_press(e) || KEY_CTRL_C.is_press(e) || KEY_CTRL_T.is_press(e) => {
+                e if KEY_Q.is_press(e)
+                    || KEY_CTRL_C.is_press(e)
+                    || self.toggle_keys.is_press(e) =>
+                {
                     self.is_done = true;
                     Ok(())
                 }
+                e if KEY_SLASH.is_press(e) => {
+                    self.start_search();
+                    tui.frame_requester().schedule_frame();
+                    Ok(())
+                }
+                e if KEY_N.is_press(e) => {
+                    self.step_search(true);
+                    tui.frame_requester().schedule_frame();
+                    Ok(())
+                }
+                e if KEY_SHIFT_N.is_press(e) || KEY_UPPER_N.is_press(e) => {
+                    self.step_search(false);
+                    tui.frame_requester().schedule_frame();
+                    Ok(())
+                }
                 other => self.view.handle_key_event(tui, other),
             },
             TuiEvent::Draw => {
@@ -788,6 +1185,7 @@ mod tests {
     use codex_protocol::parse_command::ParsedCommand;
     use ratatui::Terminal;
     use ratatui::backend::TestBackend;
+    use ratatui::style::Modifier;
     use ratatui::text::Text;
 
     #[derive(Debug)]
@@ -1255,4 +1653,101 @@ mod tests {
             "expected view to report at bottom after scrolling to end"
         );
     }
+
+    fn type_query(overlay: &mut TranscriptOverlay, query: &str) {
+        for c in query.chars() {
+            overlay.handle_search_key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+        }
+    }
+
+    fn text_cell(text: &str) -> Arc<dyn HistoryCell> {
+        Arc::new(TestCell {
+            lines: vec![Line::from(text.to_string())],
+        })
+    }
+
+    #[test]
+    fn transcript_search_uses_smart_case_and_wraps_around() {
+        let mut overlay = TranscriptOverlay::new(vec![
+            text_cell("alpha"),
+            text_cell("beta Alpha alpha"),
+            text_cell("gamma"),
+        ]);
+        let area = Rect::new(0, 0, 80, 12);
+        let mut buf = Buffer::empty(area);
+
+        overlay.start_search();
+        assert!(overlay.is_search_input_active());
+        type_query(&mut overlay, "alpha");
+        overlay.render(area, &mut buf);
+        let found: Vec<(usize, Range<usize>)> = overlay
+            .search
+            .matches
+            .iter()
+            .map(|m| (m.cell, m.range.clone()))
+            .collect();
+        assert_eq!(found, vec![(0, 0..5), (1, 5..10), (1, 11..16)]);
+
+        overlay.handle_search_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert!(!overlay.is_search_input_active());
+        // The overlay starts pinned to the bottom, so the search wraps to the top.
+        assert_eq!(overlay.search.current, Some(0));
+        overlay.step_search(false);
+        assert_eq!(overlay.search.current, Some(2));
+        overlay.step_search(true);
+        assert_eq!(overlay.search.current, Some(0));
+
+        // An uppercase letter makes the search case-sensitive.
+        overlay.start_search();
+        type_query(&mut overlay, "Alpha");
+        overlay.render(area, &mut buf);
+        let found: Vec<usize> = overlay.search.matches.iter().map(|m| m.cell).collect();
+        assert_eq!(found, vec![1]);
+        assert!(buffer_to_text(&buf, area).contains(" /Alpha"));
+    }
+
+    #[test]
+    fn transcript_search_scrolls_to_and_highlights_the_current_match() {
+        let cells = (0..40)
+            .map(|i| text_cell(&format!("line-{i}")))
+            .collect::<Vec<_>>();
+        let mut overlay = TranscriptOverlay::new(cells);
+        let area = Rect::new(0, 0, 40, 12);
+        assert!(!transcript_line_numbers(&mut overlay, area).contains(&5));
+
+        overlay.start_search();
+        type_query(&mut overlay, "line-5");
+        assert!(transcript_line_numbers(&mut overlay, area).contains(&5));
+
+        let mut buf = Buffer::empty(area);
+        overlay.render(area, &mut buf);
+        let row = overlay.search.matches[0].row - overlay.view.scroll_offset;
+        let cell = &buf[(0, area.y + 1 + row as u16)];
+        assert_eq!(cell.symbol(), "l");
+        assert!(cell.modifier.contains(Modifier::REVERSED));
+
+        // Esc while typing clears the search and its highlights.
+        overlay.handle_search_key(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        assert!(!overlay.is_search_input_active());
+        overlay.render(area, &mut buf);
+        assert!(overlay.search.matches.is_empty());
+        let cell = &buf[(0, area.y + 1 + row as u16)];
+        assert!(!cell.modifier.contains(Modifier::REVERSED));
+    }
+
+    #[test]
+    fn highlight_range_splits_spans_at_match_boundaries() {
+        let line = Line::from(vec!["ab".into(), "cd".red(), "ef".into()]);
+        let highlighted = highlight_range(line, 1..3, Style::default().reversed());
+        assert_eq!(
+            highlighted.spans,
+            vec![
+                Span::from("a"),
+                Span::from("b").reversed(),
+                Span::from("c").red().reversed(),
+                Span::from("d").red(),
+                Span::from("ef"),
+            ]
+        );
+    }
 }
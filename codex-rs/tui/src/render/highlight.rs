@@ -1,8 +1,16 @@
+use crate::color::blend;
+use crate::terminal_palette::best_color;
+use crate::terminal_palette::default_bg;
+use crate::terminal_palette::default_fg;
+use ratatui::style::Color;
 use ratatui::style::Style;
 use ratatui::style::Stylize;
 use ratatui::text::Line;
 use ratatui::text::Span;
+use std::path::Path;
 use std::sync::OnceLock;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
 use tree_sitter_highlight::Highlight;
 use tree_sitter_highlight::HighlightConfiguration;
 use tree_sitter_highlight::HighlightEvent;
@@ -109,38 +117,366 @@ fn push_segment(lines: &mut Vec<Line<'static>>, segment: &str, style: Option<Sty
 /// bash highlight query. The highlighter is streamed so multi-line content is
 /// split into `Line`s while preserving style boundaries.
 pub(crate) fn highlight_bash_to_lines(script: &str) -> Vec<Line<'static>> {
-    let mut highlighter = Highlighter::new();
-    let iterator =
-        match highlighter.highlight(highlight_config(), script.as_bytes(), None, |_| None) {
-            Ok(iter) => iter,
-            Err(_) => return vec![script.to_string().into()],
+    highlight_to_lines(highlight_config(), script, |h| highlight_for(h).style())
+        .unwrap_or_else(|| vec![script.to_string().into()])
+}
+
+/// Largest input handed to a grammar; bigger blobs render as plain text.
+const MAX_HIGHLIGHT_BYTES: usize = 256 * 1024;
+
+static SYNTAX_HIGHLIGHTING_ENABLED: AtomicBool = AtomicBool::new(true);
+
+/// Toggle highlighting of code blocks and diffs (`tui.syntax_highlighting`).
+/// Bash command rendering is unaffected.
+pub(crate) fn set_syntax_highlighting_enabled(enabled: bool) {
+    SYNTAX_HIGHLIGHTING_ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Languages with a bundled tree-sitter grammar.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Language {
+    Bash,
+    Go,
+    JavaScript,
+    Json,
+    Python,
+    Rust,
+    Toml,
+    Tsx,
+    TypeScript,
+    Yaml,
+}
+
+impl Language {
+    const COUNT: usize = 10;
+
+    /// Detect the language named by a fenced code block's info string, e.g.
+    /// `rust`, `rust,ignore` or `python title="example.py"`.
+    pub(crate) fn from_fence_info(info: &str) -> Option<Self> {
+        let name = info
+            .split(|c: char| c.is_whitespace() || c == ',' || c == '{')
+            .next()?;
+        Self::from_name(&name.to_ascii_lowercase())
+    }
+
+    /// Detect the language of a file from its extension (or, for a few
+    /// well-known files, its name).
+    pub(crate) fn from_path(path: &Path) -> Option<Self> {
+        let file_name = path.file_name()?.to_str()?;
+        match file_name {
+            "Cargo.lock" | "Pipfile" | "poetry.lock" | "uv.lock" => return Some(Self::Toml),
+            ".bashrc" | ".bash_profile" | ".profile" | ".zshrc" | ".zprofile" => {
+                return Some(Self::Bash);
+            }
+            _ => {}
+        }
+        let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+        Self::from_name(&extension)
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        let language = match name {
+            "bash" | "sh" | "shell" | "zsh" => Self::Bash,
+            "go" | "golang" => Self::Go,
+            "js" | "javascript" | "jsx" | "mjs" | "cjs" | "node" => Self::JavaScript,
+            "json" | "jsonc" | "json5" | "jsonl" => Self::Json,
+            "py" | "python" | "python3" | "pyi" => Self::Python,
+            "rs" | "rust" => Self::Rust,
+            "toml" => Self::Toml,
+            "tsx" => Self::Tsx,
+            "ts" | "typescript" | "mts" | "cts" => Self::TypeScript,
+            "yaml" | "yml" => Self::Yaml,
+            _ => return None,
+        };
+        Some(language)
+    }
+
+    fn config(self) -> Option<&'static HighlightConfiguration> {
+        static CONFIGS: [OnceLock<Option<HighlightConfiguration>>; Language::COUNT] =
+            [const { OnceLock::new() }; Language::COUNT];
+        CONFIGS[self as usize]
+            .get_or_init(|| self.build_config())
+            .as_ref()
+    }
+
+    fn build_config(self) -> Option<HighlightConfiguration> {
+        let config = match self {
+            Self::Bash => HighlightConfiguration::new(
+                tree_sitter_bash::LANGUAGE.into(),
+                "bash",
+                tree_sitter_bash::HIGHLIGHT_QUERY,
+                "",
+                "",
+            ),
+            Self::Go => HighlightConfiguration::new(
+                tree_sitter_go::LANGUAGE.into(),
+                "go",
+                tree_sitter_go::HIGHLIGHTS_QUERY,
+                "",
+                "",
+            ),
+            Self::JavaScript => HighlightConfiguration::new(
+                tree_sitter_javascript::LANGUAGE.into(),
+                "javascript",
+                &format!(
+                    "{}\n{}",
+                    tree_sitter_javascript::JSX_HIGHLIGHT_QUERY,
+                    tree_sitter_javascript::HIGHLIGHT_QUERY
+                ),
+                "",
+                "",
+            ),
+            Self::Json => HighlightConfiguration::new(
+                tree_sitter_json::LANGUAGE.into(),
+                "json",
+                tree_sitter_json::HIGHLIGHTS_QUERY,
+                "",
+                "",
+            ),
+            Self::Python => HighlightConfiguration::new(
+                tree_sitter_python::LANGUAGE.into(),
+                "python",
+                tree_sitter_python::HIGHLIGHTS_QUERY,
+                "",
+                "",
+            ),
+            Self::Rust => HighlightConfiguration::new(
+                tree_sitter_rust::LANGUAGE.into(),
+                "rust",
+                tree_sitter_rust::HIGHLIGHTS_QUERY,
+                "",
+                "",
+            ),
+            Self::Toml => HighlightConfiguration::new(
+                tree_sitter_toml_ng::LANGUAGE.into(),
+                "toml",
+                tree_sitter_toml_ng::HIGHLIGHTS_QUERY,
+                "",
+                "",
+            ),
+            // The TypeScript queries only cover TypeScript-specific syntax and
+            // are layered over the JavaScript ones, as upstream does.
+            Self::Tsx => HighlightConfiguration::new(
+                tree_sitter_typescript::LANGUAGE_TSX.into(),
+                "tsx",
+                &format!(
+                    "{}\n{}\n{}",
+                    tree_sitter_typescript::HIGHLIGHTS_QUERY,
+                    tree_sitter_javascript::JSX_HIGHLIGHT_QUERY,
+                    tree_sitter_javascript::HIGHLIGHT_QUERY
+                ),
+                "",
+                "",
+            ),
+            Self::TypeScript => HighlightConfiguration::new(
+                tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into(),
+                "typescript",
+                &format!(
+                    "{}\n{}",
+                    tree_sitter_typescript::HIGHLIGHTS_QUERY,
+                    tree_sitter_javascript::HIGHLIGHT_QUERY
+                ),
+                "",
+                "",
+            ),
+            Self::Yaml => HighlightConfiguration::new(
+                tree_sitter_yaml::LANGUAGE.into(),
+                "yaml",
+                tree_sitter_yaml::HIGHLIGHTS_QUERY,
+                "",
+                "",
+            ),
         };
+        match config {
+            Ok(mut config) => {
+                config.configure(syntax_highlight_names());
+                Some(config)
+            }
+            Err(err) => {
+                tracing::warn!("failed to load {self:?} highlight query: {err}");
+                None
+            }
+        }
+    }
+}
+
+/// Capture names shared by the bundled grammars' highlight queries. Captures
+/// resolve to the longest matching prefix, so `function.method` uses
+/// `Function` and `punctuation.bracket` uses `Punctuation`.
+#[derive(Copy, Clone)]
+enum SyntaxHighlight {
+    Attribute,
+    Boolean,
+    Comment,
+    Constant,
+    ConstantBuiltin,
+    Constructor,
+    Escape,
+    Function,
+    FunctionBuiltin,
+    FunctionMacro,
+    Keyword,
+    Label,
+    Module,
+    Number,
+    Operator,
+    Property,
+    Punctuation,
+    String,
+    StringSpecial,
+    Tag,
+    Type,
+    TypeBuiltin,
+    VariableBuiltin,
+}
+
+impl SyntaxHighlight {
+    const ALL: [Self; 23] = [
+        Self::Attribute,
+        Self::Boolean,
+        Self::Comment,
+        Self::Constant,
+        Self::ConstantBuiltin,
+        Self::Constructor,
+        Self::Escape,
+        Self::Function,
+        Self::FunctionBuiltin,
+        Self::FunctionMacro,
+        Self::Keyword,
+        Self::Label,
+        Self::Module,
+        Self::Number,
+        Self::Operator,
+        Self::Property,
+        Self::Punctuation,
+        Self::String,
+        Self::StringSpecial,
+        Self::Tag,
+        Self::Type,
+        Self::TypeBuiltin,
+        Self::VariableBuiltin,
+    ];
+
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Attribute => "attribute",
+            Self::Boolean => "boolean",
+            Self::Comment => "comment",
+            Self::Constant => "constant",
+            Self::ConstantBuiltin => "constant.builtin",
+            Self::Constructor => "constructor",
+            Self::Escape => "escape",
+            Self::Function => "function",
+            Self::FunctionBuiltin => "function.builtin",
+            Self::FunctionMacro => "function.macro",
+            Self::Keyword => "keyword",
+            Self::Label => "label",
+            Self::Module => "module",
+            Self::Number => "number",
+            Self::Operator => "operator",
+            Self::Property => "property",
+            Self::Punctuation => "punctuation",
+            Self::String => "string",
+            Self::StringSpecial => "string.special",
+            Self::Tag => "tag",
+            Self::Type => "type",
+            Self::TypeBuiltin => "type.builtin",
+            Self::VariableBuiltin => "variable.builtin",
+        }
+    }
+
+    /// Sticks to the ANSI colors from `styles.md` so the terminal's own palette
+    /// decides the exact hues; only comments and punctuation derive a muted
+    /// color from the terminal's default fg/bg when those are known.
+    fn style(self) -> Style {
+        match self {
+            Self::Keyword
+            | Self::Boolean
+            | Self::Constant
+            | Self::ConstantBuiltin
+            | Self::Number
+            | Self::VariableBuiltin => Style::default().magenta(),
+            Self::Function
+            | Self::FunctionBuiltin
+            | Self::FunctionMacro
+            | Self::Constructor
+            | Self::Tag
+            | Self::Escape => Style::default().cyan(),
+            Self::String | Self::StringSpecial => Style::default().green(),
+            Self::Type | Self::TypeBuiltin | Self::Module | Self::Label => {
+                Style::default().italic()
+            }
+            Self::Comment => muted_style().italic(),
+            Self::Attribute | Self::Punctuation => muted_style(),
+            Self::Operator | Self::Property => Style::default(),
+        }
+    }
+}
+
+fn syntax_highlight_names() -> &'static [&'static str] {
+    static NAMES: OnceLock<[&'static str; SyntaxHighlight::ALL.len()]> = OnceLock::new();
+    NAMES
+        .get_or_init(|| SyntaxHighlight::ALL.map(SyntaxHighlight::as_str))
+        .as_slice()
+}
+
+/// Text halfway between the terminal's foreground and background, falling
+/// back to `dim` when the palette is unknown or can't be approximated.
+fn muted_style() -> Style {
+    let (Some(fg), Some(bg)) = (default_fg(), default_bg()) else {
+        return Style::default().dim();
+    };
+    match best_color(blend(fg, bg, 0.55)) {
+        Color::Reset => Style::default().dim(),
+        color => Style::default().fg(color),
+    }
+}
+
+/// Highlight `code` as `language`, one `Line` per source line. Returns `None`
+/// when highlighting is turned off, the input is too large, or the grammar
+/// fails, so callers can fall back to plain rendering.
+pub(crate) fn highlight_code_to_lines(
+    code: &str,
+    language: Language,
+) -> Option<Vec<Line<'static>>> {
+    if !SYNTAX_HIGHLIGHTING_ENABLED.load(Ordering::Relaxed) || code.len() > MAX_HIGHLIGHT_BYTES {
+        return None;
+    }
+    highlight_to_lines(language.config()?, code, |h| {
+        SyntaxHighlight::ALL[h.0].style()
+    })
+}
+
+fn highlight_to_lines(
+    config: &HighlightConfiguration,
+    source: &str,
+    style_for: impl Fn(Highlight) -> Style,
+) -> Option<Vec<Line<'static>>> {
+    let mut highlighter = Highlighter::new();
+    let iterator = highlighter
+        .highlight(config, source.as_bytes(), None, |_| None)
+        .ok()?;
 
     let mut lines: Vec<Line<'static>> = vec![Line::from("")];
     let mut highlight_stack: Vec<Highlight> = Vec::new();
 
     for event in iterator {
-        match event {
-            Ok(HighlightEvent::HighlightStart(highlight)) => highlight_stack.push(highlight),
-            Ok(HighlightEvent::HighlightEnd) => {
+        match event.ok()? {
+            HighlightEvent::HighlightStart(highlight) => highlight_stack.push(highlight),
+            HighlightEvent::HighlightEnd => {
                 highlight_stack.pop();
             }
-            Ok(HighlightEvent::Source { start, end }) => {
+            HighlightEvent::Source { start, end } => {
                 if start == end {
                     continue;
                 }
-                let style = highlight_stack.last().map(|h| highlight_for(*h).style());
-                push_segment(&mut lines, &script[start..end], style);
+                let style = highlight_stack.last().map(|h| style_for(*h));
+                push_segment(&mut lines, &source[start..end], style);
             }
-            Err(_) => return vec![script.to_string().into()],
         }
     }
 
-    if lines.is_empty() {
-        vec![Line::from("")]
-    } else {
-        lines
-    }
+    Some(lines)
 }
 
 #[cfg(test)]
@@ -233,4 +569,70 @@ mod tests {
         let body_style = body_style.expect("missing heredoc span");
         assert!(body_style.add_modifier.contains(Modifier::DIM));
     }
+
+    #[test]
+    fn detects_language_from_fence_info_and_path() {
+        assert_eq!(Language::from_fence_info("rust"), Some(Language::Rust));
+        assert_eq!(
+            Language::from_fence_info("rust,ignore"),
+            Some(Language::Rust)
+        );
+        assert_eq!(
+            Language::from_fence_info("Python title=\"x.py\""),
+            Some(Language::Python)
+        );
+        assert_eq!(Language::from_fence_info("text"), None);
+        assert_eq!(Language::from_fence_info(""), None);
+
+        assert_eq!(
+            Language::from_path(Path::new("src/app.tsx")),
+            Some(Language::Tsx)
+        );
+        assert_eq!(
+            Language::from_path(Path::new("config.YML")),
+            Some(Language::Yaml)
+        );
+        assert_eq!(
+            Language::from_path(Path::new("Cargo.lock")),
+            Some(Language::Toml)
+        );
+        assert_eq!(Language::from_path(Path::new("notes.txt")), None);
+    }
+
+    #[test]
+    fn every_language_loads_its_queries() {
+        for language in [
+            Language::Bash,
+            Language::Go,
+            Language::JavaScript,
+            Language::Json,
+            Language::Python,
+            Language::Rust,
+            Language::Toml,
+            Language::Tsx,
+            Language::TypeScript,
+            Language::Yaml,
+        ] {
+            assert!(language.config().is_some(), "{language:?}");
+        }
+    }
+
+    #[test]
+    fn highlights_rust_keywords_and_strings() {
+        let s = "fn main() {\n    let s = \"hi\";\n}";
+        let lines = highlight_code_to_lines(s, Language::Rust).expect("highlighted");
+        assert_eq!(reconstructed(&lines), s);
+
+        let style_of = |token: &str| {
+            lines
+                .iter()
+                .flat_map(|l| l.spans.iter())
+                .find(|sp| sp.content.as_ref() == token)
+                .map(|sp| sp.style)
+                .unwrap_or_else(|| panic!("missing span {token}"))
+        };
+        assert_eq!(style_of("fn").fg, Some(Color::Magenta));
+        assert_eq!(style_of("let").fg, Some(Color::Magenta));
+        assert_eq!(style_of("\"hi\"").fg, Some(Color::Green));
+    }
 }
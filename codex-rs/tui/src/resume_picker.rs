@@ -36,9 +36,19 @@ use crate::tui::FrameRequester;
 use crate::tui::Tui;
 use crate::tui::TuiEvent;
 use codex_protocol::ThreadId;
+use codex_protocol::models::LocalShellAction;
+use codex_protocol::models::ResponseItem;
+use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::RolloutItem;
 
 const PAGE_SIZE: usize = 25;
 const LOAD_NEAR_THRESHOLD: usize = 5;
+/// Rollouts read at once when content search loads thread text.
+const MAX_CONCURRENT_CONTENT_LOADS: usize = 4;
+/// Bytes kept from each message, command or tool input for content search.
+const MAX_SEARCH_PART_BYTES: usize = 1024;
+/// Bytes of searchable text kept per thread.
+const MAX_SEARCH_TEXT_BYTES: usize = 64 * 1024;
 #[derive(Debug, Clone)]
 pub enum SessionSelection {
     StartFresh,
@@ -88,12 +98,21 @@ struct PageLoadRequest {
 
 type PageLoader = Arc<dyn Fn(PageLoadRequest) + Send + Sync>;
 
+/// Loads the searchable text of the rollout at the given path and reports it
+/// back as [`BackgroundEvent::ContentLoaded`].
+type ContentLoader = Arc<dyn Fn(PathBuf) + Send + Sync>;
+
 enum BackgroundEvent {
     PageLoaded {
         request_token: usize,
         search_token: Option<usize>,
         page: std::io::Result<ThreadsPage>,
     },
+    ContentLoaded {
+        path: PathBuf,
+        /// Lowercased thread excerpt, or `None` if the rollout could not be read.
+        text: Option<String>,
+    },
 }
 
 /// Interactive session picker that lists recorded rollout files with simple
@@ -112,6 +131,10 @@ enum BackgroundEvent {
 /// 1. Provider and source filtering at the backend (only interactive CLI sessions
 ///    for the current model provider).
 /// 2. Working-directory filtering at the picker (unless `--all` is passed).
+///
+/// Typing matches the preview and thread name immediately. Once a query is
+/// entered, the full text of each listed thread (messages and commands) is
+/// loaded in the background and searched as well.
 pub async fn run_resume_picker(
     tui: &mut Tui,
     codex_home: &Path,
@@ -184,6 +207,27 @@ async fn run_session_picker(
         });
     });
 
+    let (content_tx, content_rx) = mpsc::unbounded_channel::<PathBuf>();
+    let content_events_tx = bg_tx.clone();
+    tokio::spawn(async move {
+        let loads = UnboundedReceiverStream::new(content_rx).map(|path| async move {
+            let text = RolloutRecorder::get_rollout_history(&path)
+                .await
+                .ok()
+                .map(|history| thread_search_text(&history.get_rollout_items()));
+            BackgroundEvent::ContentLoaded { path, text }
+        });
+        let mut loads = futures::StreamExt::buffer_unordered(loads, MAX_CONCURRENT_CONTENT_LOADS);
+        while let Some(event) = loads.next().await {
+            if content_events_tx.send(event).is_err() {
+                break;
+            }
+        }
+    });
+    let content_loader: ContentLoader = Arc::new(move |path: PathBuf| {
+        let _ = content_tx.send(path);
+    });
+
     let mut state = PickerState::new(
         codex_home.to_path_buf(),
         alt.tui.frame_requester(),
@@ -192,7 +236,8 @@ async fn run_session_picker(
         show_all,
         filter_cwd,
         action,
-    );
+    )
+    .with_content_loader(content_loader);
     state.start_initial_load();
     state.request_frame();
 
@@ -273,6 +318,10 @@ struct PickerState {
     next_request_token: usize,
     next_search_token: usize,
     page_loader: PageLoader,
+    content_loader: ContentLoader,
+    /// Capped searchable excerpt per rollout path, `None` while it is
+    /// loading. Rollouts that could not be read are treated as empty.
+    thread_contents: HashMap<PathBuf, Option<String>>,
     view_rows: Option<usize>,
     default_provider: String,
     show_all: bool,
@@ -390,6 +439,8 @@ impl PickerState {
             next_request_token: 0,
             next_search_token: 0,
             page_loader,
+            content_loader: Arc::new(|_| {}),
+            thread_contents: HashMap::new(),
             view_rows: None,
             default_provider,
             show_all,
@@ -400,6 +451,11 @@ impl PickerState {
         }
     }
 
+    fn with_content_loader(mut self, content_loader: ContentLoader) -> Self {
+        self.content_loader = content_loader;
+        self
+    }
+
     fn request_frame(&self) {
         self.requester.schedule_frame();
     }
@@ -532,10 +588,55 @@ impl PickerState {
                 let completed_token = pending.search_token.or(search_token);
                 self.continue_search_if_token_matches(completed_token);
             }
+            BackgroundEvent::ContentLoaded { path, text } => {
+                self.thread_contents
+                    .insert(path, Some(text.unwrap_or_default()));
+                if !self.query.is_empty() {
+                    self.apply_filter();
+                }
+            }
         }
         Ok(())
     }
 
+    /// Queue loading the searchable text of every listed thread that has not
+    /// been requested yet. Only done once the user searches, since it reads
+    /// each rollout file in full; the loader reads a few at a time.
+    fn load_thread_contents(&mut self) {
+        if self.query.is_empty() {
+            return;
+        }
+        let missing: Vec<PathBuf> = self
+            .all_rows
+            .iter()
+            .filter(|row| self.row_matches_filter(row))
+            .filter(|row| !self.thread_contents.contains_key(&row.path))
+            .map(|row| row.path.clone())
+            .collect();
+        for path in missing {
+            self.thread_contents.insert(path.clone(), None);
+            (self.content_loader)(path);
+        }
+    }
+
+    /// Whether thread contents that could still match the query are loading.
+    fn is_loading_thread_contents(&self) -> bool {
+        !self.query.is_empty()
+            && self.all_rows.iter().any(|row| {
+                self.row_matches_filter(row)
+                    && matches!(self.thread_contents.get(&row.path), Some(None))
+            })
+    }
+
+    fn row_matches_query(&self, row: &Row, query: &str) -> bool {
+        row.matches_query(query)
+            || self
+                .thread_contents
+                .get(&row.path)
+                .and_then(Option::as_deref)
+                .is_some_and(|text| text.contains(query))
+    }
+
     fn reset_pagination(&mut self) {
         self.pagination.next_cursor = None;
         self.pagination.num_scanned_files = 0;
@@ -564,6 +665,7 @@ impl PickerState {
             }
         }
 
+        self.load_thread_contents();
         self.apply_filter();
     }
 
@@ -618,7 +720,10 @@ impl PickerState {
             self.filtered_rows = base_iter.cloned().collect();
         } else {
             let q = self.query.to_lowercase();
-            self.filtered_rows = base_iter.filter(|r| r.matches_query(&q)).cloned().collect();
+            self.filtered_rows = base_iter
+                .filter(|r| self.row_matches_query(r, &q))
+                .cloned()
+                .collect();
         }
         if self.selected >= self.filtered_rows.len() {
             self.selected = self.filtered_rows.len().saturating_sub(1);
@@ -649,6 +754,7 @@ impl PickerState {
         }
         self.query = new_query;
         self.selected = 0;
+        self.load_thread_contents();
         self.apply_filter();
         if self.query.is_empty() {
             self.search_state = SearchState::Idle;
@@ -808,6 +914,52 @@ impl PickerState {
     }
 }
 
+/// Lowercased text of a thread that content search matches against: the user
+/// and agent messages plus the commands and tool inputs the agent issued.
+///
+/// Only an excerpt is kept so that searching many long threads stays cheap:
+/// each part is cut to [`MAX_SEARCH_PART_BYTES`] and the whole text stops
+/// growing at [`MAX_SEARCH_TEXT_BYTES`].
+fn thread_search_text(items: &[RolloutItem]) -> String {
+    let mut text = String::new();
+    let mut push = |part: &str| {
+        let remaining = MAX_SEARCH_TEXT_BYTES.saturating_sub(text.len());
+        if remaining == 0 {
+            return;
+        }
+        let part = truncate_at_char_boundary(part, MAX_SEARCH_PART_BYTES.min(remaining));
+        text.push_str(&part.to_lowercase());
+        text.push('\n');
+    };
+    for item in items {
+        match item {
+            RolloutItem::EventMsg(EventMsg::UserMessage(event)) => push(&event.message),
+            RolloutItem::EventMsg(EventMsg::AgentMessage(event)) => push(&event.message),
+            RolloutItem::ResponseItem(ResponseItem::FunctionCall { arguments, .. }) => {
+                push(arguments);
+            }
+            RolloutItem::ResponseItem(ResponseItem::CustomToolCall { input, .. }) => push(input),
+            RolloutItem::ResponseItem(ResponseItem::LocalShellCall {
+                action: LocalShellAction::Exec(exec),
+                ..
+            }) => push(&exec.command.join(" ")),
+            _ => {}
+        }
+    }
+    text
+}
+
+fn truncate_at_char_boundary(text: &str, max_bytes: usize) -> &str {
+    if text.len() <= max_bytes {
+        return text;
+    }
+    let mut end = max_bytes;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    &text[..end]
+}
+
 fn rows_from_items(items: Vec<ThreadItem>) -> Vec<Row> {
     items.into_iter().map(|item| head_to_row(&item)).collect()
 }
@@ -1056,6 +1208,7 @@ fn render_list(
 fn render_empty_state_line(state: &PickerState) -> Line<'static> {
     if !state.query.is_empty() {
         if state.search_state.is_active()
+            || state.is_loading_thread_contents()
             || (state.pagination.loading.is_pending() && state.pagination.next_cursor.is_some())
         {
             return vec!["Searching…".italic().dim()].into();
@@ -2313,4 +2466,114 @@ mod tests {
         assert!(!state.search_state.is_active());
         assert!(state.pagination.reached_scan_cap);
     }
+
+    #[tokio::test]
+    async fn set_query_searches_loaded_thread_contents() {
+        let loader: PageLoader = Arc::new(|_| {});
+        let requested: Arc<Mutex<Vec<PathBuf>>> = Arc::new(Mutex::new(Vec::new()));
+        let sink = requested.clone();
+        let content_loader: ContentLoader = Arc::new(move |path: PathBuf| {
+            sink.lock().unwrap().push(path);
+        });
+        let mut state = PickerState::new(
+            PathBuf::from("/tmp"),
+            FrameRequester::test_dummy(),
+            loader,
+            String::from("openai"),
+            true,
+            None,
+            SessionPickerAction::Resume,
+        )
+        .with_content_loader(content_loader);
+        state.reset_pagination();
+        state.ingest_page(page(
+            vec![
+                make_item("/tmp/a.jsonl", "2025-01-01T00:00:00Z", "alpha"),
+                make_item("/tmp/b.jsonl", "2025-01-02T00:00:00Z", "beta"),
+            ],
+            None,
+            2,
+            false,
+        ));
+        // Contents are only loaded once the user searches.
+        assert!(requested.lock().unwrap().is_empty());
+
+        state.set_query("Migration".to_string());
+        assert_eq!(
+            requested.lock().unwrap().clone(),
+            vec![PathBuf::from("/tmp/a.jsonl"), PathBuf::from("/tmp/b.jsonl")]
+        );
+        assert!(state.filtered_rows.is_empty());
+        assert!(state.is_loading_thread_contents());
+
+        state
+            .handle_background_event(BackgroundEvent::ContentLoaded {
+                path: PathBuf::from("/tmp/b.jsonl"),
+                text: Some("ran the migration in ./db\n".to_string()),
+            })
+            .await
+            .unwrap();
+        state
+            .handle_background_event(BackgroundEvent::ContentLoaded {
+                path: PathBuf::from("/tmp/a.jsonl"),
+                text: None,
+            })
+            .await
+            .unwrap();
+        let paths: Vec<PathBuf> = state.filtered_rows.iter().map(|r| r.path.clone()).collect();
+        assert_eq!(paths, vec![PathBuf::from("/tmp/b.jsonl")]);
+        assert!(!state.is_loading_thread_contents());
+
+        // Refining the query reuses the loaded contents.
+        state.set_query("Migrations".to_string());
+        assert_eq!(requested.lock().unwrap().len(), 2);
+        assert!(state.filtered_rows.is_empty());
+    }
+
+    #[test]
+    fn thread_search_text_includes_messages_and_commands() {
+        use codex_protocol::protocol::AgentMessageEvent;
+
+        let items = vec![
+            RolloutItem::EventMsg(EventMsg::UserMessage(UserMessageEvent {
+                message: "Please run the DB migration".to_string(),
+                images: None,
+                local_images: Vec::new(),
+                text_elements: Vec::new(),
+            })),
+            RolloutItem::ResponseItem(ResponseItem::FunctionCall {
+                id: None,
+                name: "shell".to_string(),
+                arguments: r#"{"command":["sqlx","migrate","run"]}"#.to_string(),
+                call_id: "call-1".to_string(),
+            }),
+            RolloutItem::EventMsg(EventMsg::AgentMessage(AgentMessageEvent {
+                message: "Done.".to_string(),
+            })),
+        ];
+
+        assert_eq!(
+            thread_search_text(&items),
+            "please run the db migration\n{\"command\":[\"sqlx\",\"migrate\",\"run\"]}\ndone.\n"
+        );
+    }
+
+    #[test]
+    fn thread_search_text_keeps_a_capped_excerpt() {
+        use codex_protocol::protocol::AgentMessageEvent;
+
+        let message = |message: String| {
+            RolloutItem::EventMsg(EventMsg::AgentMessage(AgentMessageEvent { message }))
+        };
+        let mut items = vec![message(format!(
+            "{}é",
+            "A".repeat(MAX_SEARCH_PART_BYTES - 1)
+        ))];
+        items.extend((0..200).map(|_| message("b".repeat(MAX_SEARCH_PART_BYTES))));
+
+        let text = thread_search_text(&items);
+        let first = text.lines().next().unwrap_or_default();
+        assert_eq!(first, "a".repeat(MAX_SEARCH_PART_BYTES - 1));
+        assert!(text.len() <= MAX_SEARCH_TEXT_BYTES + 1);
+    }
 }
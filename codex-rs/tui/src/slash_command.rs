@@ -29,8 +29,10 @@ pub enum SlashCommand {
     Plan,
     Collab,
     Agent,
-    // Undo,
+    Undo,
+    Redo,
     Diff,
+    Export,
     Mention,
     Status,
     DebugConfig,
@@ -59,15 +61,17 @@ impl SlashCommand {
             SlashCommand::Rename => "rename the current thread",
             SlashCommand::Resume => "resume a saved chat",
             SlashCommand::Fork => "fork the current chat",
-            // SlashCommand::Undo => "ask Codex to undo a turn",
+            SlashCommand::Undo => "restore files to how they were before an earlier turn",
+            SlashCommand::Redo => "reapply the file changes reverted by the last undo",
             SlashCommand::Quit | SlashCommand::Exit => "exit Codex",
             SlashCommand::Diff => "show git diff (including untracked files)",
+            SlashCommand::Export => "export this session as Markdown, HTML or JSON",
             SlashCommand::Mention => "mention a file",
             SlashCommand::Skills => "use skills to improve how Codex performs specific tasks",
             SlashCommand::Status => "show current session configuration and token usage",
             SlashCommand::DebugConfig => "show config layers and requirement sources for debugging",
             SlashCommand::Statusline => "configure which items appear in the status line",
-            SlashCommand::Ps => "list background terminals",
+            SlashCommand::Ps => "view and control background terminals",
             SlashCommand::Model => "choose what model and reasoning effort to use",
             SlashCommand::Personality => "choose a communication style for Codex",
             SlashCommand::Plan => "switch to Plan mode",
@@ -95,7 +99,7 @@ impl SlashCommand {
     pub fn supports_inline_args(self) -> bool {
         matches!(
             self,
-            SlashCommand::Review | SlashCommand::Rename | SlashCommand::Plan
+            SlashCommand::Review | SlashCommand::Rename | SlashCommand::Plan | SlashCommand::Export
         )
     }
 
@@ -107,7 +111,8 @@ impl SlashCommand {
             | SlashCommand::Fork
             | SlashCommand::Init
             | SlashCommand::Compact
-            // | SlashCommand::Undo
+            | SlashCommand::Undo
+            | SlashCommand::Redo
             | SlashCommand::Model
             | SlashCommand::Personality
             | SlashCommand::Approvals
@@ -118,6 +123,7 @@ impl SlashCommand {
             | SlashCommand::Plan
             | SlashCommand::Logout => false,
             SlashCommand::Diff
+            | SlashCommand::Export
             | SlashCommand::Rename
             | SlashCommand::Mention
             | SlashCommand::Skills
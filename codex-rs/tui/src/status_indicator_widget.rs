@@ -20,6 +20,7 @@ use crate::app_event::AppEvent;
 use crate::app_event_sender::AppEventSender;
 use crate::exec_cell::spinner;
 use crate::key_hint;
+use crate::key_hint::KeyBinding;
 use crate::render::renderable::Renderable;
 use crate::shimmer::shimmer_spans;
 use crate::text_formatting::capitalize_first;
@@ -35,6 +36,7 @@ pub(crate) struct StatusIndicatorWidget {
     header: String,
     details: Option<String>,
     show_interrupt_hint: bool,
+    interrupt_key: KeyBinding,
 
     elapsed_running: Duration,
     last_resume_at: Instant,
@@ -71,6 +73,7 @@ impl StatusIndicatorWidget {
             header: String::from("Working"),
             details: None,
             show_interrupt_hint: true,
+            interrupt_key: key_hint::plain(KeyCode::Esc),
             elapsed_running: Duration::ZERO,
             last_resume_at: Instant::now(),
             is_paused: false,
@@ -111,6 +114,11 @@ impl StatusIndicatorWidget {
         self.show_interrupt_hint = visible;
     }
 
+    /// Key advertised in the "to interrupt" hint.
+    pub(crate) fn set_interrupt_key(&mut self, key: KeyBinding) {
+        self.interrupt_key = key;
+    }
+
     #[cfg(test)]
     pub(crate) fn interrupt_hint_visible(&self) -> bool {
         self.show_interrupt_hint
@@ -219,7 +227,7 @@ impl Renderable for StatusIndicatorWidget {
         if self.show_interrupt_hint {
             spans.extend(vec![
                 format!("({pretty_elapsed} • ").dim(),
-                key_hint::plain(KeyCode::Esc).into(),
+                self.interrupt_key.into(),
                 " to interrupt)".dim(),
             ]);
         } else {
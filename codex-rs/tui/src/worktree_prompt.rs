@@ -0,0 +1,231 @@
+use std::path::Path;
+
+use crate::key_hint;
+use crate::render::Insets;
+use crate::render::renderable::ColumnRenderable;
+use crate::render::renderable::Renderable;
+use crate::render::renderable::RenderableExt as _;
+use crate::selection_list::selection_option_row;
+use crate::tui::FrameRequester;
+use crate::tui::Tui;
+use crate::tui::TuiEvent;
+use codex_core::config::types::WorktreeExitAction;
+use color_eyre::Result;
+use crossterm::event::KeyCode;
+use crossterm::event::KeyEvent;
+use crossterm::event::KeyEventKind;
+use crossterm::event::KeyModifiers;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::prelude::Widget;
+use ratatui::style::Stylize as _;
+use ratatui::text::Line;
+use ratatui::widgets::Clear;
+use ratatui::widgets::WidgetRef;
+use tokio_stream::StreamExt;
+
+const OPTIONS: [(WorktreeExitAction, &str); 4] = [
+    (
+        WorktreeExitAction::Merge,
+        "Merge the session branch into your current branch",
+    ),
+    (
+        WorktreeExitAction::CherryPick,
+        "Cherry-pick the session commits onto your current branch",
+    ),
+    (
+        WorktreeExitAction::Discard,
+        "Discard the worktree and its branch",
+    ),
+    (
+        WorktreeExitAction::Keep,
+        "Keep the worktree to review it later",
+    ),
+];
+
+/// Index of the option chosen when the prompt is dismissed.
+const KEEP_INDEX: usize = 3;
+
+/// Asks what to do with the changes left in a session worktree.
+pub(crate) async fn run_worktree_exit_prompt(
+    tui: &mut Tui,
+    worktree_path: &Path,
+    branch: &str,
+) -> Result<WorktreeExitAction> {
+    let mut screen = WorktreePromptScreen::new(
+        tui.frame_requester(),
+        worktree_path.display().to_string(),
+        branch.to_string(),
+    );
+    tui.draw(u16::MAX, |frame| {
+        frame.render_widget_ref(&screen, frame.area());
+    })?;
+
+    let events = tui.event_stream();
+    tokio::pin!(events);
+
+    while !screen.is_done() {
+        if let Some(event) = events.next().await {
+            match event {
+                TuiEvent::Key(key_event) => screen.handle_key(key_event),
+                TuiEvent::Paste(_) => {}
+                TuiEvent::Draw => {
+                    tui.draw(u16::MAX, |frame| {
+                        frame.render_widget_ref(&screen, frame.area());
+                    })?;
+                }
+            }
+        } else {
+            break;
+        }
+    }
+
+    Ok(screen.selection().unwrap_or(WorktreeExitAction::Keep))
+}
+
+struct WorktreePromptScreen {
+    request_frame: FrameRequester,
+    worktree_path: String,
+    branch: String,
+    highlighted: usize,
+    selection: Option<WorktreeExitAction>,
+}
+
+impl WorktreePromptScreen {
+    fn new(request_frame: FrameRequester, worktree_path: String, branch: String) -> Self {
+        Self {
+            request_frame,
+            worktree_path,
+            branch,
+            highlighted: 0,
+            selection: None,
+        }
+    }
+
+    fn handle_key(&mut self, key_event: KeyEvent) {
+        if key_event.kind == KeyEventKind::Release {
+            return;
+        }
+        if key_event.modifiers.contains(KeyModifiers::CONTROL)
+            && matches!(key_event.code, KeyCode::Char('c') | KeyCode::Char('d'))
+        {
+            self.select(KEEP_INDEX);
+            return;
+        }
+        match key_event.code {
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.set_highlight((self.highlighted + OPTIONS.len() - 1) % OPTIONS.len());
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.set_highlight((self.highlighted + 1) % OPTIONS.len());
+            }
+            KeyCode::Char(c @ '1'..='4') => self.select(c as usize - '1' as usize),
+            KeyCode::Enter => self.select(self.highlighted),
+            KeyCode::Esc => self.select(KEEP_INDEX),
+            _ => {}
+        }
+    }
+
+    fn set_highlight(&mut self, highlight: usize) {
+        if self.highlighted != highlight {
+            self.highlighted = highlight;
+            self.request_frame.schedule_frame();
+        }
+    }
+
+    fn select(&mut self, index: usize) {
+        self.highlighted = index;
+        self.selection = Some(OPTIONS[index].0);
+        self.request_frame.schedule_frame();
+    }
+
+    fn is_done(&self) -> bool {
+        self.selection.is_some()
+    }
+
+    fn selection(&self) -> Option<WorktreeExitAction> {
+        self.selection
+    }
+}
+
+impl WidgetRef for &WorktreePromptScreen {
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+        let mut column = ColumnRenderable::new();
+
+        column.push("");
+        column.push(Line::from(vec![
+            "This session made changes in branch ".into(),
+            self.branch.clone().bold(),
+        ]));
+        column.push("");
+        column.push(
+            Line::from(format!("Worktree: {}", self.worktree_path))
+                .dim()
+                .inset(Insets::tlbr(0, 2, 0, 0)),
+        );
+        column.push("");
+        for (index, (_, label)) in OPTIONS.iter().enumerate() {
+            column.push(selection_option_row(
+                index,
+                (*label).to_string(),
+                self.highlighted == index,
+            ));
+        }
+        column.push("");
+        column.push(
+            Line::from(vec![
+                "Press ".dim(),
+                key_hint::plain(KeyCode::Enter).into(),
+                " to continue".dim(),
+            ])
+            .inset(Insets::tlbr(0, 2, 0, 0)),
+        );
+        column.render(area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_backend::VT100Backend;
+    use pretty_assertions::assert_eq;
+    use ratatui::Terminal;
+
+    fn new_prompt() -> WorktreePromptScreen {
+        WorktreePromptScreen::new(
+            FrameRequester::test_dummy(),
+            "/Users/example/.codex/worktrees/repo/20250101-120000-abcd1234".to_string(),
+            "codex/20250101-120000-abcd1234".to_string(),
+        )
+    }
+
+    #[test]
+    fn worktree_prompt_snapshot() {
+        let screen = new_prompt();
+        let mut terminal = Terminal::new(VT100Backend::new(80, 12)).expect("terminal");
+        terminal
+            .draw(|frame| frame.render_widget_ref(&screen, frame.area()))
+            .expect("render worktree prompt");
+        insta::assert_snapshot!("worktree_exit_prompt", terminal.backend());
+    }
+
+    #[test]
+    fn worktree_prompt_keeps_worktree_on_escape() {
+        let mut screen = new_prompt();
+        screen.handle_key(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        assert_eq!(screen.selection(), Some(WorktreeExitAction::Keep));
+    }
+
+    #[test]
+    fn worktree_prompt_selects_highlighted_option() {
+        let mut screen = new_prompt();
+        screen.handle_key(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
+        screen.handle_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert_eq!(screen.selection(), Some(WorktreeExitAction::CherryPick));
+
+        let mut screen = new_prompt();
+        screen.handle_key(KeyEvent::new(KeyCode::Char('3'), KeyModifiers::NONE));
+        assert_eq!(screen.selection(), Some(WorktreeExitAction::Discard));
+    }
+}
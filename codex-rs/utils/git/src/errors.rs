@@ -22,6 +22,18 @@ pub enum GitToolingError {
     },
     #[error("{path:?} is not a git repository")]
     NotAGitRepository { path: PathBuf },
+    #[error("{path:?} has no commits yet")]
+    MissingHead { path: PathBuf },
+    #[error(
+        "{path:?} has {current} checked out but the worktree was created from {expected}; switch back before merging"
+    )]
+    CheckoutMoved {
+        path: PathBuf,
+        expected: String,
+        current: String,
+    },
+    #[error("{path:?} has uncommitted changes; commit or stash them before merging")]
+    CheckoutDirty { path: PathBuf },
     #[error("path {path:?} must be relative to the repository root")]
     NonRelativePath { path: PathBuf },
     #[error("path {path:?} escapes the repository root")]
@@ -462,6 +462,64 @@ pub fn restore_to_commit(repo_path: &Path, commit_id: &str) -> Result<(), GitToo
     restore_to_commit_inner(repo_root.as_path(), repo_prefix.as_deref(), commit_id)
 }
 
+/// Differences between two ghost commits, scoped to the session directory.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct GhostCommitDiff {
+    /// Paths that differ, relative to the repository path used for the diff.
+    pub paths: Vec<PathBuf>,
+    /// Unified diff from the first commit to the second.
+    pub unified_diff: String,
+}
+
+/// Compute the diff between two ghost commits, limited to `repo_path` and with
+/// paths reported relative to it.
+pub fn diff_ghost_commits(
+    repo_path: &Path,
+    from: &GhostCommit,
+    to: &GhostCommit,
+) -> Result<GhostCommitDiff, GitToolingError> {
+    ensure_git_repository(repo_path)?;
+
+    let names = run_git_for_stdout_all(
+        repo_path,
+        [
+            "diff",
+            "--relative",
+            "--no-ext-diff",
+            "--name-only",
+            "-z",
+            from.id(),
+            to.id(),
+        ],
+        None,
+    )?;
+    let paths = names
+        .split('\0')
+        .filter(|name| !name.is_empty())
+        .map(PathBuf::from)
+        .collect::<Vec<_>>();
+    if paths.is_empty() {
+        return Ok(GhostCommitDiff::default());
+    }
+
+    let unified_diff = run_git_for_stdout_all(
+        repo_path,
+        [
+            "diff",
+            "--relative",
+            "--no-ext-diff",
+            "--no-color",
+            from.id(),
+            to.id(),
+        ],
+        None,
+    )?;
+    Ok(GhostCommitDiff {
+        paths,
+        unified_diff,
+    })
+}
+
 /// Restores the working tree and index to the given commit using `git restore`.
 /// The repository root and optional repository-relative prefix limit the restore scope.
 fn restore_to_commit_inner(
@@ -1776,4 +1834,52 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    /// Diffs between snapshots only cover the session subdirectory.
+    fn diff_reports_changes_between_snapshots_relative_to_subdir() -> Result<(), GitToolingError> {
+        let temp = tempfile::tempdir()?;
+        let repo = temp.path();
+        init_test_repo(repo);
+        std::fs::create_dir_all(repo.join("app"))?;
+        std::fs::write(repo.join("app/main.rs"), "fn main() {}\n")?;
+        std::fs::write(repo.join("outside.txt"), "outside\n")?;
+        run_git_in(repo, &["add", "."]);
+        run_git_in(
+            repo,
+            &[
+                "-c",
+                "user.name=Tester",
+                "-c",
+                "user.email=test@example.com",
+                "commit",
+                "-m",
+                "init",
+            ],
+        );
+
+        let app = repo.join("app");
+        let before = create_ghost_commit(&CreateGhostCommitOptions::new(&app))?;
+        std::fs::write(app.join("main.rs"), "fn main() { println!(\"hi\"); }\n")?;
+        std::fs::write(app.join("new.rs"), "pub fn new() {}\n")?;
+        std::fs::write(repo.join("outside.txt"), "changed\n")?;
+        let after = create_ghost_commit(&CreateGhostCommitOptions::new(&app))?;
+
+        let diff = diff_ghost_commits(&app, &before, &after)?;
+        assert_eq!(
+            diff.paths,
+            vec![PathBuf::from("main.rs"), PathBuf::from("new.rs")]
+        );
+        assert!(
+            diff.unified_diff
+                .contains("+fn main() { println!(\"hi\"); }")
+        );
+        assert!(!diff.unified_diff.contains("outside.txt"));
+
+        assert_eq!(
+            diff_ghost_commits(&app, &after, &after)?,
+            GhostCommitDiff::default()
+        );
+        Ok(())
+    }
 }
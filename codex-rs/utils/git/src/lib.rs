@@ -7,6 +7,7 @@ mod errors;
 mod ghost_commits;
 mod operations;
 mod platform;
+mod worktree;
 
 pub use apply::ApplyGitRequest;
 pub use apply::ApplyGitResult;
@@ -17,6 +18,7 @@ pub use apply::stage_paths;
 pub use branch::merge_base_with_head;
 pub use errors::GitToolingError;
 pub use ghost_commits::CreateGhostCommitOptions;
+pub use ghost_commits::GhostCommitDiff;
 pub use ghost_commits::GhostSnapshotConfig;
 pub use ghost_commits::GhostSnapshotReport;
 pub use ghost_commits::IgnoredUntrackedFile;
@@ -25,6 +27,7 @@ pub use ghost_commits::RestoreGhostCommitOptions;
 pub use ghost_commits::capture_ghost_snapshot_report;
 pub use ghost_commits::create_ghost_commit;
 pub use ghost_commits::create_ghost_commit_with_report;
+pub use ghost_commits::diff_ghost_commits;
 pub use ghost_commits::restore_ghost_commit;
 pub use ghost_commits::restore_ghost_commit_with_options;
 pub use ghost_commits::restore_to_commit;
@@ -33,6 +36,13 @@ use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
 use ts_rs::TS;
+pub use worktree::GitWorktree;
+pub use worktree::cherry_pick_worktree;
+pub use worktree::commit_worktree_changes;
+pub use worktree::create_worktree;
+pub use worktree::merge_worktree;
+pub use worktree::remove_worktree;
+pub use worktree::worktree_has_changes;
 
 type CommitID = String;
 
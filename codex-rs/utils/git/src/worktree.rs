@@ -0,0 +1,379 @@
+use std::ffi::OsString;
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::GitToolingError;
+use crate::operations::ensure_git_repository;
+use crate::operations::resolve_head;
+use crate::operations::resolve_repository_root;
+use crate::operations::run_git_for_status;
+use crate::operations::run_git_for_stdout;
+
+/// A linked `git worktree` checked out on its own branch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitWorktree {
+    repo_root: PathBuf,
+    path: PathBuf,
+    branch: String,
+    base_commit: String,
+    /// Branch checked out in the main repository at creation; `None` when
+    /// `HEAD` was detached.
+    origin_branch: Option<String>,
+}
+
+impl GitWorktree {
+    /// Root of the repository the worktree was created from.
+    pub fn repo_root(&self) -> &Path {
+        &self.repo_root
+    }
+
+    /// Directory containing the worktree checkout.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Branch checked out in the worktree.
+    pub fn branch(&self) -> &str {
+        &self.branch
+    }
+
+    /// Commit the worktree branch was created from.
+    pub fn base_commit(&self) -> &str {
+        &self.base_commit
+    }
+
+    /// Branch the main repository had checked out when the worktree was
+    /// created, which is where merges and cherry-picks land.
+    pub fn origin_branch(&self) -> Option<&str> {
+        self.origin_branch.as_deref()
+    }
+}
+
+/// Creates a new worktree at `worktree_path` on a fresh `branch` that starts at
+/// the current `HEAD` of the repository containing `repo_path`.
+pub fn create_worktree(
+    repo_path: &Path,
+    worktree_path: &Path,
+    branch: &str,
+) -> Result<GitWorktree, GitToolingError> {
+    ensure_git_repository(repo_path)?;
+    let repo_root = resolve_repository_root(repo_path)?;
+    let Some(base_commit) = resolve_head(repo_root.as_path())? else {
+        return Err(GitToolingError::MissingHead { path: repo_root });
+    };
+    let origin_branch = current_branch(repo_root.as_path())?;
+    if let Some(parent) = worktree_path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    run_git_for_status(
+        repo_root.as_path(),
+        [
+            OsString::from("worktree"),
+            OsString::from("add"),
+            OsString::from("-b"),
+            OsString::from(branch),
+            worktree_path.as_os_str().to_os_string(),
+            OsString::from(&base_commit),
+        ],
+        None,
+    )?;
+
+    Ok(GitWorktree {
+        repo_root,
+        path: worktree_path.to_path_buf(),
+        branch: branch.to_string(),
+        base_commit,
+        origin_branch,
+    })
+}
+
+/// Returns true when the worktree has uncommitted changes or commits that are
+/// not part of its base commit.
+pub fn worktree_has_changes(worktree: &GitWorktree) -> Result<bool, GitToolingError> {
+    Ok(has_uncommitted_changes(worktree)? || commits_ahead_of_base(worktree)? > 0)
+}
+
+/// Commits every pending change in the worktree. Returns `false` when there
+/// was nothing to commit.
+pub fn commit_worktree_changes(
+    worktree: &GitWorktree,
+    message: &str,
+) -> Result<bool, GitToolingError> {
+    if !has_uncommitted_changes(worktree)? {
+        return Ok(false);
+    }
+    run_git_for_status(worktree.path(), ["add", "--all"], None)?;
+    run_git_for_status(
+        worktree.path(),
+        ["commit", "--no-verify", "-m", message],
+        None,
+    )?;
+    Ok(true)
+}
+
+/// Merges the worktree branch into the branch the main repository had checked
+/// out when the worktree was created, then removes the worktree. Pending
+/// changes are committed with `message` first. Refuses to run when the main
+/// checkout switched branches or has uncommitted changes. A conflicting merge
+/// is aborted and the worktree is kept.
+pub fn merge_worktree(worktree: &GitWorktree, message: &str) -> Result<(), GitToolingError> {
+    ensure_origin_checkout(worktree)?;
+    commit_worktree_changes(worktree, message)?;
+    if commits_ahead_of_base(worktree)? > 0
+        && let Err(err) = run_git_for_status(
+            worktree.repo_root(),
+            ["merge", "--no-edit", worktree.branch()],
+            None,
+        )
+    {
+        let _ = run_git_for_status(worktree.repo_root(), ["merge", "--abort"], None);
+        return Err(err);
+    }
+    remove_worktree(worktree)
+}
+
+/// Cherry-picks the commits made on the worktree branch onto the branch the
+/// main repository had checked out when the worktree was created, then removes
+/// the worktree. Pending changes are committed with `message` first. Refuses
+/// to run under the same conditions as [`merge_worktree`]. A conflicting
+/// cherry-pick is aborted and the worktree is kept.
+pub fn cherry_pick_worktree(worktree: &GitWorktree, message: &str) -> Result<(), GitToolingError> {
+    ensure_origin_checkout(worktree)?;
+    commit_worktree_changes(worktree, message)?;
+    if commits_ahead_of_base(worktree)? > 0
+        && let Err(err) = run_git_for_status(
+            worktree.repo_root(),
+            [
+                OsString::from("cherry-pick"),
+                OsString::from(format!("{}..{}", worktree.base_commit(), worktree.branch())),
+            ],
+            None,
+        )
+    {
+        let _ = run_git_for_status(worktree.repo_root(), ["cherry-pick", "--abort"], None);
+        return Err(err);
+    }
+    remove_worktree(worktree)
+}
+
+/// Deletes the worktree checkout and its branch, discarding any changes.
+pub fn remove_worktree(worktree: &GitWorktree) -> Result<(), GitToolingError> {
+    run_git_for_status(
+        worktree.repo_root(),
+        [
+            OsString::from("worktree"),
+            OsString::from("remove"),
+            OsString::from("--force"),
+            worktree.path().as_os_str().to_os_string(),
+        ],
+        None,
+    )?;
+    run_git_for_status(
+        worktree.repo_root(),
+        ["branch", "-D", worktree.branch()],
+        None,
+    )
+}
+
+/// Checks that the main checkout is still on the branch (or detached commit)
+/// the worktree was created from and has no uncommitted changes to tracked
+/// files.
+fn ensure_origin_checkout(worktree: &GitWorktree) -> Result<(), GitToolingError> {
+    let repo_root = worktree.repo_root();
+    let current = current_branch(repo_root)?;
+    let unchanged = match worktree.origin_branch() {
+        Some(branch) => current.as_deref() == Some(branch),
+        None => {
+            current.is_none() && resolve_head(repo_root)?.as_deref() == Some(worktree.base_commit())
+        }
+    };
+    if !unchanged {
+        let describe = |branch: Option<&str>| {
+            branch.map_or_else(
+                || "a detached HEAD".to_string(),
+                |branch| format!("`{branch}`"),
+            )
+        };
+        return Err(GitToolingError::CheckoutMoved {
+            path: repo_root.to_path_buf(),
+            expected: describe(worktree.origin_branch()),
+            current: describe(current.as_deref()),
+        });
+    }
+    let status = run_git_for_stdout(
+        repo_root,
+        ["status", "--porcelain", "--untracked-files=no"],
+        None,
+    )?;
+    if !status.is_empty() {
+        return Err(GitToolingError::CheckoutDirty {
+            path: repo_root.to_path_buf(),
+        });
+    }
+    Ok(())
+}
+
+/// The branch checked out at `repo_root`, or `None` for a detached `HEAD`.
+fn current_branch(repo_root: &Path) -> Result<Option<String>, GitToolingError> {
+    let branch = run_git_for_stdout(repo_root, ["rev-parse", "--abbrev-ref", "HEAD"], None)?;
+    Ok((branch != "HEAD").then_some(branch))
+}
+
+fn has_uncommitted_changes(worktree: &GitWorktree) -> Result<bool, GitToolingError> {
+    let status = run_git_for_stdout(worktree.path(), ["status", "--porcelain"], None)?;
+    Ok(!status.is_empty())
+}
+
+fn commits_ahead_of_base(worktree: &GitWorktree) -> Result<usize, GitToolingError> {
+    let count = run_git_for_stdout(
+        worktree.path(),
+        [
+            OsString::from("rev-list"),
+            OsString::from("--count"),
+            OsString::from(format!("{}..HEAD", worktree.base_commit())),
+        ],
+        None,
+    )?;
+    Ok(count.parse().unwrap_or(0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::process::Command;
+    use tempfile::tempdir;
+
+    fn run_git_in(repo_path: &Path, args: &[&str]) {
+        let status = Command::new("git")
+            .current_dir(repo_path)
+            .args([
+                "-c",
+                "user.name=Tester",
+                "-c",
+                "user.email=test@example.com",
+            ])
+            .args(args)
+            .status()
+            .expect("git command");
+        assert!(status.success(), "git command failed: {args:?}");
+    }
+
+    fn init_repo_with_commit(repo_path: &Path) {
+        run_git_in(repo_path, &["init", "--initial-branch=main"]);
+        run_git_in(repo_path, &["config", "core.autocrlf", "false"]);
+        run_git_in(repo_path, &["config", "user.name", "Tester"]);
+        run_git_in(repo_path, &["config", "user.email", "test@example.com"]);
+        std::fs::write(repo_path.join("README.md"), "base\n").expect("write readme");
+        run_git_in(repo_path, &["add", "README.md"]);
+        run_git_in(repo_path, &["commit", "-m", "base"]);
+    }
+
+    #[test]
+    fn merge_brings_worktree_changes_into_main_checkout() -> Result<(), GitToolingError> {
+        let temp = tempdir()?;
+        let repo = temp.path().join("repo");
+        std::fs::create_dir_all(&repo)?;
+        init_repo_with_commit(&repo);
+
+        let worktree = create_worktree(&repo, &temp.path().join("wt/session"), "codex/session")?;
+        assert!(!worktree_has_changes(&worktree)?);
+
+        std::fs::write(worktree.path().join("README.md"), "changed\n")?;
+        std::fs::write(worktree.path().join("new.txt"), "new\n")?;
+        assert!(worktree_has_changes(&worktree)?);
+
+        merge_worktree(&worktree, "Apply session changes")?;
+        assert_eq!(
+            std::fs::read_to_string(repo.join("README.md"))?,
+            "changed\n"
+        );
+        assert_eq!(std::fs::read_to_string(repo.join("new.txt"))?, "new\n");
+        assert!(!worktree.path().exists());
+        let branches = run_git_for_stdout(&repo, ["branch", "--list", "codex/*"], None)?;
+        assert_eq!(branches, "");
+        Ok(())
+    }
+
+    #[test]
+    fn cherry_pick_applies_commits_on_top_of_current_branch() -> Result<(), GitToolingError> {
+        let temp = tempdir()?;
+        let repo = temp.path().join("repo");
+        std::fs::create_dir_all(&repo)?;
+        init_repo_with_commit(&repo);
+
+        let worktree = create_worktree(&repo, &temp.path().join("wt/session"), "codex/session")?;
+        std::fs::write(worktree.path().join("feature.txt"), "feature\n")?;
+        std::fs::write(repo.join("main.txt"), "main\n")?;
+        run_git_in(&repo, &["add", "main.txt"]);
+        run_git_in(&repo, &["commit", "-m", "main change"]);
+
+        cherry_pick_worktree(&worktree, "Add feature")?;
+        let log = run_git_for_stdout(&repo, ["log", "--format=%s"], None)?;
+        assert_eq!(log, "Add feature\nmain change\nbase");
+        assert!(!worktree.path().exists());
+        Ok(())
+    }
+
+    #[test]
+    fn merge_refuses_when_main_checkout_moved_or_is_dirty() -> Result<(), GitToolingError> {
+        let temp = tempdir()?;
+        let repo = temp.path().join("repo");
+        std::fs::create_dir_all(&repo)?;
+        init_repo_with_commit(&repo);
+
+        let worktree = create_worktree(&repo, &temp.path().join("wt/session"), "codex/session")?;
+        assert_eq!(worktree.origin_branch(), Some("main"));
+        std::fs::write(worktree.path().join("feature.txt"), "feature\n")?;
+
+        run_git_in(&repo, &["checkout", "-b", "other"]);
+        let err = merge_worktree(&worktree, "Add feature").expect_err("HEAD moved");
+        assert!(matches!(err, GitToolingError::CheckoutMoved { .. }));
+
+        run_git_in(&repo, &["checkout", "main"]);
+        std::fs::write(repo.join("README.md"), "local edit\n")?;
+        let err = cherry_pick_worktree(&worktree, "Add feature").expect_err("dirty checkout");
+        assert!(matches!(err, GitToolingError::CheckoutDirty { .. }));
+        assert!(worktree.path().exists());
+
+        run_git_in(&repo, &["checkout", "--", "README.md"]);
+        merge_worktree(&worktree, "Add feature")?;
+        assert_eq!(
+            std::fs::read_to_string(repo.join("feature.txt"))?,
+            "feature\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn remove_discards_worktree_and_branch() -> Result<(), GitToolingError> {
+        let temp = tempdir()?;
+        let repo = temp.path().join("repo");
+        std::fs::create_dir_all(&repo)?;
+        init_repo_with_commit(&repo);
+
+        let worktree = create_worktree(&repo, &temp.path().join("wt/session"), "codex/session")?;
+        std::fs::write(worktree.path().join("README.md"), "discard me\n")?;
+
+        remove_worktree(&worktree)?;
+        assert!(!worktree.path().exists());
+        assert_eq!(std::fs::read_to_string(repo.join("README.md"))?, "base\n");
+        let branches = run_git_for_stdout(&repo, ["branch", "--list", "codex/*"], None)?;
+        assert_eq!(branches, "");
+        Ok(())
+    }
+
+    #[test]
+    fn create_requires_a_commit() -> Result<(), GitToolingError> {
+        let temp = tempdir()?;
+        let repo = temp.path().join("repo");
+        std::fs::create_dir_all(&repo)?;
+        run_git_in(&repo, &["init", "--initial-branch=main"]);
+
+        let err = create_worktree(&repo, &temp.path().join("wt"), "codex/session")
+            .expect_err("worktree without HEAD");
+        assert!(matches!(err, GitToolingError::MissingHead { .. }));
+        Ok(())
+    }
+}
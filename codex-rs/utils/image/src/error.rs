@@ -23,6 +23,12 @@ pub enum ImageProcessingError {
         #[source]
         source: image::ImageError,
     },
+    #[error("reading PDFs requires `{tool}` (poppler-utils) on PATH")]
+    PdfToolUnavailable { tool: &'static str },
+    #[error("failed to read PDF at {path}: {message}")]
+    Pdf { path: PathBuf, message: String },
+    #[error("invalid page selection `{selection}`: {reason}")]
+    InvalidPageSelection { selection: String, reason: String },
 }
 
 impl ImageProcessingError {
@@ -21,6 +21,7 @@ pub const MAX_WIDTH: u32 = 2048;
 pub const MAX_HEIGHT: u32 = 768;
 
 pub mod error;
+pub mod pdf;
 
 #[derive(Debug, Clone)]
 pub struct EncodedImage {
@@ -35,6 +36,14 @@ impl EncodedImage {
         let encoded = BASE64_STANDARD.encode(&self.bytes);
         format!("data:{};base64,{}", self.mime, encoded)
     }
+
+    /// Approximate number of input tokens the model is billed for this image,
+    /// using the 512px tile accounting of high detail images.
+    pub fn estimated_tokens(&self) -> usize {
+        let tiles_x = self.width.div_ceil(512) as usize;
+        let tiles_y = self.height.div_ceil(512) as usize;
+        85 + 170 * tiles_x * tiles_y
+    }
 }
 
 static IMAGE_CACHE: LazyLock<BlockingLruCache<[u8; 20], EncodedImage>> =
@@ -48,55 +57,65 @@ pub fn load_and_resize_to_fit(path: &Path) -> Result<EncodedImage, ImageProcessi
     let key = sha1_digest(&file_bytes);
 
     IMAGE_CACHE.get_or_try_insert_with(key, move || {
-        let format = match image::guess_format(&file_bytes) {
-            Ok(ImageFormat::Png) => Some(ImageFormat::Png),
-            Ok(ImageFormat::Jpeg) => Some(ImageFormat::Jpeg),
-            _ => None,
-        };
-
-        let dynamic = image::load_from_memory(&file_bytes).map_err(|source| {
-            ImageProcessingError::Decode {
-                path: path_buf.clone(),
-                source,
-            }
+        encode_to_fit(file_bytes, &path_buf, MAX_WIDTH, MAX_HEIGHT)
+    })
+}
+
+/// Decodes raw image bytes and re-encodes them within `max_width` x `max_height`,
+/// keeping the original bytes when they already fit.
+pub(crate) fn encode_to_fit(
+    file_bytes: Vec<u8>,
+    path: &Path,
+    max_width: u32,
+    max_height: u32,
+) -> Result<EncodedImage, ImageProcessingError> {
+    let format = match image::guess_format(&file_bytes) {
+        Ok(ImageFormat::Png) => Some(ImageFormat::Png),
+        Ok(ImageFormat::Jpeg) => Some(ImageFormat::Jpeg),
+        _ => None,
+    };
+
+    let dynamic =
+        image::load_from_memory(&file_bytes).map_err(|source| ImageProcessingError::Decode {
+            path: path.to_path_buf(),
+            source,
         })?;
 
-        let (width, height) = dynamic.dimensions();
-
-        let encoded = if width <= MAX_WIDTH && height <= MAX_HEIGHT {
-            if let Some(format) = format {
-                let mime = format_to_mime(format);
-                EncodedImage {
-                    bytes: file_bytes,
-                    mime,
-                    width,
-                    height,
-                }
-            } else {
-                let (bytes, output_format) = encode_image(&dynamic, ImageFormat::Png)?;
-                let mime = format_to_mime(output_format);
-                EncodedImage {
-                    bytes,
-                    mime,
-                    width,
-                    height,
-                }
+    let (width, height) = dynamic.dimensions();
+
+    let encoded = if width <= max_width && height <= max_height {
+        if let Some(format) = format {
+            let mime = format_to_mime(format);
+            EncodedImage {
+                bytes: file_bytes,
+                mime,
+                width,
+                height,
             }
         } else {
-            let resized = dynamic.resize(MAX_WIDTH, MAX_HEIGHT, FilterType::Triangle);
-            let target_format = format.unwrap_or(ImageFormat::Png);
-            let (bytes, output_format) = encode_image(&resized, target_format)?;
+            let (bytes, output_format) = encode_image(&dynamic, ImageFormat::Png)?;
             let mime = format_to_mime(output_format);
             EncodedImage {
                 bytes,
                 mime,
-                width: resized.width(),
-                height: resized.height(),
+                width,
+                height,
             }
-        };
+        }
+    } else {
+        let resized = dynamic.resize(max_width, max_height, FilterType::Triangle);
+        let target_format = format.unwrap_or(ImageFormat::Png);
+        let (bytes, output_format) = encode_image(&resized, target_format)?;
+        let mime = format_to_mime(output_format);
+        EncodedImage {
+            bytes,
+            mime,
+            width: resized.width(),
+            height: resized.height(),
+        }
+    };
 
-        Ok(encoded)
-    })
+    Ok(encoded)
 }
 
 fn read_file_bytes(path: &Path, path_for_error: &Path) -> Result<Vec<u8>, ImageProcessingError> {
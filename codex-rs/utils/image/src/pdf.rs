@@ -0,0 +1,502 @@
+//! PDF attachments. Pages are rendered to images with poppler's `pdftoppm`
+//! and their text layer is extracted with `pdftotext`, so the model gets both
+//! the layout and text it can quote exactly.
+
+use std::io::ErrorKind;
+use std::io::Read;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process::Command;
+use std::process::Output;
+use std::process::Stdio;
+use std::thread;
+use std::thread::JoinHandle;
+use std::time::Duration;
+use std::time::Instant;
+
+use crate::EncodedImage;
+use crate::encode_to_fit;
+use crate::error::ImageProcessingError;
+
+/// Maximum width of a rendered page.
+pub const MAX_PAGE_WIDTH: u32 = 1536;
+/// Maximum height of a rendered page. Pages are mostly portrait, so this is
+/// larger than the bound used for regular images.
+pub const MAX_PAGE_HEIGHT: u32 = 1536;
+/// Maximum number of pages attached from a single PDF.
+pub const MAX_PAGES: usize = 20;
+/// Token budget used when the caller does not know the model's context window.
+pub const DEFAULT_TOKEN_BUDGET: usize = 24_000;
+
+const PDF_MAGIC: &[u8; 5] = b"%PDF-";
+/// How long a single poppler invocation may run before it is killed.
+const TOOL_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Returns true when the file starts with the PDF header.
+pub fn is_pdf(path: &Path) -> bool {
+    let mut header = [0u8; 5];
+    std::fs::File::open(path)
+        .and_then(|mut file| file.read_exact(&mut header))
+        .is_ok()
+        && &header == PDF_MAGIC
+}
+
+/// A 1-based page selection such as `1-3,5,8-`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PageSelection {
+    selection: String,
+    ranges: Vec<(u32, Option<u32>)>,
+}
+
+impl PageSelection {
+    pub fn parse(selection: &str) -> Result<Self, ImageProcessingError> {
+        let invalid = |reason: &str| ImageProcessingError::InvalidPageSelection {
+            selection: selection.to_string(),
+            reason: reason.to_string(),
+        };
+        let parse_page = |value: &str| match value.trim().parse::<u32>() {
+            Ok(0) => Err(invalid("pages are numbered from 1")),
+            Ok(page) => Ok(page),
+            Err(_) => Err(invalid("expected page numbers such as `1-3,5`")),
+        };
+
+        let mut ranges = Vec::new();
+        for part in selection.split(',') {
+            let part = part.trim();
+            if part.is_empty() {
+                continue;
+            }
+            let range = match part.split_once('-') {
+                Some((start, end)) if end.trim().is_empty() => (parse_page(start)?, None),
+                Some((start, end)) => {
+                    let (start, end) = (parse_page(start)?, parse_page(end)?);
+                    if start > end {
+                        return Err(invalid("range start is after its end"));
+                    }
+                    (start, Some(end))
+                }
+                None => {
+                    let page = parse_page(part)?;
+                    (page, Some(page))
+                }
+            };
+            ranges.push(range);
+        }
+        if ranges.is_empty() {
+            return Err(invalid("no pages selected"));
+        }
+        Ok(Self {
+            selection: selection.to_string(),
+            ranges,
+        })
+    }
+
+    /// Selected pages that exist in a document of `page_count` pages, in
+    /// ascending order without duplicates.
+    pub fn pages(&self, page_count: u32) -> Vec<u32> {
+        let mut pages: Vec<u32> = self
+            .ranges
+            .iter()
+            .flat_map(|(start, end)| *start..=end.unwrap_or(page_count).min(page_count))
+            .collect();
+        pages.sort_unstable();
+        pages.dedup();
+        pages
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PdfRenderOptions {
+    /// Pages to attach; all pages (up to `MAX_PAGES`) when unset.
+    pub pages: Option<PageSelection>,
+    /// Approximate number of tokens the attached pages may use. The first
+    /// selected page is always attached.
+    pub token_budget: usize,
+}
+
+impl Default for PdfRenderOptions {
+    fn default() -> Self {
+        Self {
+            pages: None,
+            token_budget: DEFAULT_TOKEN_BUDGET,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PdfPage {
+    /// 1-based page number.
+    pub number: u32,
+    /// Rendered page, unless `pdftoppm` is unavailable.
+    pub image: Option<EncodedImage>,
+    /// Extracted text, unless the page has no text layer.
+    pub text: Option<String>,
+}
+
+impl PdfPage {
+    fn estimated_tokens(&self) -> usize {
+        let image_tokens = self
+            .image
+            .as_ref()
+            .map_or(0, EncodedImage::estimated_tokens);
+        let text_tokens = self.text.as_ref().map_or(0, |text| text.len().div_ceil(4));
+        image_tokens + text_tokens
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RenderedPdf {
+    pub page_count: u32,
+    pub pages: Vec<PdfPage>,
+    /// Selected pages left out to stay within `MAX_PAGES` or the token budget.
+    pub omitted_pages: Vec<u32>,
+}
+
+pub fn load_pdf(
+    path: &Path,
+    options: &PdfRenderOptions,
+) -> Result<RenderedPdf, ImageProcessingError> {
+    let page_count = page_count(path)?;
+    let requested = match &options.pages {
+        Some(selection) => selection.pages(page_count),
+        None => (1..=page_count).collect(),
+    };
+    if requested.is_empty() {
+        return Err(match &options.pages {
+            Some(selection) => ImageProcessingError::InvalidPageSelection {
+                selection: selection.selection.clone(),
+                reason: format!("the PDF has {page_count} pages"),
+            },
+            None => ImageProcessingError::Pdf {
+                path: path.to_path_buf(),
+                message: "the PDF has no pages".to_string(),
+            },
+        });
+    }
+
+    let (pages, omitted_pages) = collect_within_budget(requested, options.token_budget, |page| {
+        load_page(path, page)
+    })?;
+    Ok(RenderedPdf {
+        page_count,
+        pages,
+        omitted_pages,
+    })
+}
+
+/// Formats page numbers compactly, e.g. `1-3, 5`.
+pub fn format_pages(pages: &[u32]) -> String {
+    let mut parts = Vec::new();
+    let mut iter = pages.iter().copied().peekable();
+    while let Some(start) = iter.next() {
+        let mut end = start;
+        while iter.peek() == Some(&(end + 1)) {
+            end += 1;
+            iter.next();
+        }
+        if start == end {
+            parts.push(start.to_string());
+        } else {
+            parts.push(format!("{start}-{end}"));
+        }
+    }
+    parts.join(", ")
+}
+
+fn collect_within_budget(
+    mut requested: Vec<u32>,
+    token_budget: usize,
+    mut load: impl FnMut(u32) -> Result<PdfPage, ImageProcessingError>,
+) -> Result<(Vec<PdfPage>, Vec<u32>), ImageProcessingError> {
+    let mut omitted = if requested.len() > MAX_PAGES {
+        requested.split_off(MAX_PAGES)
+    } else {
+        Vec::new()
+    };
+
+    let mut pages = Vec::new();
+    let mut used = 0;
+    for (index, number) in requested.iter().copied().enumerate() {
+        let page = load(number)?;
+        let cost = page.estimated_tokens();
+        if !pages.is_empty() && used + cost > token_budget {
+            omitted.splice(0..0, requested[index..].iter().copied());
+            break;
+        }
+        used += cost;
+        pages.push(page);
+    }
+    Ok((pages, omitted))
+}
+
+fn load_page(path: &Path, number: u32) -> Result<PdfPage, ImageProcessingError> {
+    let image = match render_page(path, number) {
+        Ok(image) => Some(image),
+        Err(ImageProcessingError::PdfToolUnavailable { .. }) => None,
+        Err(err) => return Err(err),
+    };
+    let text = match run_tool("pdftotext", path, |command| {
+        command
+            .args([
+                "-f",
+                &number.to_string(),
+                "-l",
+                &number.to_string(),
+                "-layout",
+            ])
+            .arg(tool_path(path))
+            .arg("-");
+    }) {
+        Ok(stdout) => {
+            let text = String::from_utf8_lossy(&stdout).trim_end().to_string();
+            (!text.trim().is_empty()).then_some(text)
+        }
+        // Without text extraction the rendered page still carries the content.
+        Err(ImageProcessingError::PdfToolUnavailable { .. }) if image.is_some() => None,
+        Err(err) => return Err(err),
+    };
+    Ok(PdfPage {
+        number,
+        image,
+        text,
+    })
+}
+
+fn render_page(path: &Path, number: u32) -> Result<EncodedImage, ImageProcessingError> {
+    // With `-singlefile` and no output root, pdftoppm writes the page to stdout.
+    let png = run_tool("pdftoppm", path, |command| {
+        command
+            .args(["-f", &number.to_string(), "-l", &number.to_string()])
+            .args([
+                "-singlefile",
+                "-png",
+                "-scale-to",
+                &MAX_PAGE_HEIGHT.to_string(),
+            ])
+            .arg(tool_path(path));
+    })?;
+    encode_to_fit(png, path, MAX_PAGE_WIDTH, MAX_PAGE_HEIGHT)
+}
+
+fn page_count(path: &Path) -> Result<u32, ImageProcessingError> {
+    let stdout = run_tool("pdfinfo", path, |command| {
+        command.arg(tool_path(path));
+    })?;
+    String::from_utf8_lossy(&stdout)
+        .lines()
+        .find_map(|line| line.strip_prefix("Pages:"))
+        .and_then(|count| count.trim().parse().ok())
+        .ok_or_else(|| ImageProcessingError::Pdf {
+            path: path.to_path_buf(),
+            message: "pdfinfo did not report a page count".to_string(),
+        })
+}
+
+fn run_tool(
+    tool: &'static str,
+    path: &Path,
+    configure: impl FnOnce(&mut Command),
+) -> Result<Vec<u8>, ImageProcessingError> {
+    let mut command = Command::new(tool);
+    configure(&mut command);
+    let output = match tokio::runtime::Handle::try_current() {
+        // Same as reading image files: never block a runtime worker outright.
+        Ok(_) => tokio::task::block_in_place(|| output_with_timeout(&mut command, TOOL_TIMEOUT)),
+        Err(_) => output_with_timeout(&mut command, TOOL_TIMEOUT),
+    };
+    let output = match output {
+        Ok(Some(output)) => output,
+        Ok(None) => {
+            return Err(ImageProcessingError::Pdf {
+                path: path.to_path_buf(),
+                message: format!("{tool} timed out after {}s", TOOL_TIMEOUT.as_secs()),
+            });
+        }
+        Err(err) if err.kind() == ErrorKind::NotFound => {
+            return Err(ImageProcessingError::PdfToolUnavailable { tool });
+        }
+        Err(err) => {
+            return Err(ImageProcessingError::Pdf {
+                path: path.to_path_buf(),
+                message: format!("failed to run {tool}: {err}"),
+            });
+        }
+    };
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(ImageProcessingError::Pdf {
+            path: path.to_path_buf(),
+            message: format!("{tool} failed: {}", stderr.trim()),
+        });
+    }
+    Ok(output.stdout)
+}
+
+/// Poppler tools have no `--` separator, so relative paths are made absolute
+/// to keep a file such as `-foo.pdf` from being read as an option.
+fn tool_path(path: &Path) -> PathBuf {
+    std::path::absolute(path).unwrap_or_else(|_| Path::new(".").join(path))
+}
+
+/// Like [`Command::output`], but kills the process and returns `None` once
+/// `timeout` has elapsed.
+fn output_with_timeout(
+    command: &mut Command,
+    timeout: Duration,
+) -> std::io::Result<Option<Output>> {
+    let mut child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    // Drain both pipes while waiting so a chatty tool cannot fill them and
+    // block before it exits.
+    let stdout = read_in_background(child.stdout.take());
+    let stderr = read_in_background(child.stderr.take());
+
+    let deadline = Instant::now() + timeout;
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        if Instant::now() >= deadline {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Ok(None);
+        }
+        thread::sleep(Duration::from_millis(10));
+    };
+    Ok(Some(Output {
+        status,
+        stdout: stdout.join().unwrap_or_default(),
+        stderr: stderr.join().unwrap_or_default(),
+    }))
+}
+
+fn read_in_background(pipe: Option<impl Read + Send + 'static>) -> JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut buf = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut buf);
+        }
+        buf
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use tempfile::NamedTempFile;
+
+    fn page(number: u32, text_len: usize) -> PdfPage {
+        PdfPage {
+            number,
+            image: None,
+            text: Some("x".repeat(text_len)),
+        }
+    }
+
+    #[test]
+    fn parses_page_selection() {
+        let selection = PageSelection::parse("5, 1-3,2,8-").expect("valid selection");
+
+        assert_eq!(selection.pages(9), vec![1, 2, 3, 5, 8, 9]);
+        assert_eq!(selection.pages(4), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn rejects_invalid_page_selection() {
+        for selection in ["0", "3-1", "a-b", " , "] {
+            match PageSelection::parse(selection) {
+                Err(ImageProcessingError::InvalidPageSelection { .. }) => {}
+                other => panic!("expected invalid selection for `{selection}`: {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn formats_page_ranges() {
+        assert_eq!(format_pages(&[1, 2, 3, 5, 7, 8]), "1-3, 5, 7-8");
+    }
+
+    #[test]
+    fn budget_keeps_first_page_and_omits_the_rest() {
+        let (pages, omitted) =
+            collect_within_budget(vec![1, 2, 3, 4], 250, |number| Ok(page(number, 400)))
+                .expect("pages");
+
+        assert_eq!(
+            pages.iter().map(|page| page.number).collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+        assert_eq!(omitted, vec![3, 4]);
+    }
+
+    #[test]
+    fn budget_caps_page_count() {
+        let requested: Vec<u32> = (1..=25).collect();
+        let (pages, omitted) =
+            collect_within_budget(requested, usize::MAX, |number| Ok(page(number, 1)))
+                .expect("pages");
+
+        assert_eq!(pages.len(), MAX_PAGES);
+        assert_eq!(omitted, (21..=25).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn relative_paths_never_look_like_options() {
+        let path = tool_path(Path::new("-foo.pdf"));
+
+        assert!(path.is_absolute());
+        assert!(path.ends_with("-foo.pdf"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn timed_out_tools_are_killed() {
+        let mut command = Command::new("sleep");
+        command.arg("5");
+
+        let output = output_with_timeout(&mut command, Duration::from_millis(50))
+            .expect("sleep should start");
+
+        assert!(output.is_none());
+    }
+
+    #[test]
+    fn renders_fixture_pdf() {
+        if ["pdftoppm", "pdftotext", "pdfinfo"]
+            .iter()
+            .any(|tool| Command::new(tool).arg("-v").output().is_err())
+        {
+            eprintln!("skipping: poppler-utils is not installed");
+            return;
+        }
+        let pdf = NamedTempFile::new().expect("temp file");
+        std::fs::write(pdf.path(), include_bytes!("../tests/fixtures/hello.pdf"))
+            .expect("write fixture");
+
+        let rendered = load_pdf(pdf.path(), &PdfRenderOptions::default()).expect("render pdf");
+
+        assert_eq!(rendered.page_count, 1);
+        assert!(rendered.omitted_pages.is_empty());
+        let [page] = rendered.pages.as_slice() else {
+            panic!("expected one page, got {}", rendered.pages.len());
+        };
+        assert_eq!(page.number, 1);
+        assert!(page.image.is_some());
+        assert_eq!(page.text.as_deref().map(str::trim), Some("Hello PDF"));
+    }
+
+    #[test]
+    fn detects_pdf_header() {
+        let pdf = NamedTempFile::new().expect("temp file");
+        std::fs::write(pdf.path(), b"%PDF-1.7\n").expect("write pdf header");
+        let text = NamedTempFile::new().expect("temp file");
+        std::fs::write(text.path(), b"plain text").expect("write text");
+
+        assert!(is_pdf(pdf.path()));
+        assert!(!is_pdf(text.path()));
+    }
+}
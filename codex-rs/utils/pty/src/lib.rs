@@ -13,6 +13,8 @@ pub use pipe::spawn_process as spawn_pipe_process;
 pub use pipe::spawn_process_no_stdin as spawn_pipe_process_no_stdin;
 /// Handle for interacting with a spawned process (PTY or pipe).
 pub use process::ProcessHandle;
+/// Signals that can be delivered to a spawned process.
+pub use process::ProcessSignal;
 /// Bundle of process handles plus output and exit receivers returned by spawn helpers.
 pub use process::SpawnedProcess;
 /// Backwards-compatible alias for ProcessHandle.
@@ -20,6 +20,7 @@ use tokio::task::JoinHandle;
 
 use crate::process::ChildTerminator;
 use crate::process::ProcessHandle;
+use crate::process::ProcessSignal;
 use crate::process::SpawnedProcess;
 
 #[cfg(target_os = "linux")]
@@ -49,6 +50,18 @@ impl ChildTerminator for PipeChildTerminator {
             Ok(())
         }
     }
+
+    fn signal(&mut self, signal: ProcessSignal) -> io::Result<()> {
+        #[cfg(unix)]
+        {
+            crate::process_group::signal_process_group(self.process_group_id, signal)
+        }
+
+        #[cfg(not(unix))]
+        {
+            crate::process_group::signal_process_group(0, signal)
+        }
+    }
 }
 
 #[cfg(windows)]
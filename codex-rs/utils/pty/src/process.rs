@@ -14,6 +14,19 @@ use tokio::task::JoinHandle;
 
 pub(crate) trait ChildTerminator: Send + Sync {
     fn kill(&mut self) -> io::Result<()>;
+
+    /// Delivers `signal` to the child's process group.
+    fn signal(&mut self, signal: ProcessSignal) -> io::Result<()>;
+}
+
+/// Signals that can be delivered to a spawned process without killing it
+/// outright.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProcessSignal {
+    /// `SIGINT`, as if Ctrl-C were pressed in the process's terminal.
+    Interrupt,
+    /// `SIGTERM`, asking the process to exit.
+    Terminate,
 }
 
 pub struct PtyHandles {
@@ -101,6 +114,19 @@ impl ProcessHandle {
         self.exit_code.lock().ok().and_then(|guard| *guard)
     }
 
+    /// Delivers `signal` to the child's process group. A child that was
+    /// already terminated is left alone.
+    pub fn signal(&self, signal: ProcessSignal) -> io::Result<()> {
+        let mut killer = self
+            .killer
+            .lock()
+            .map_err(|_| io::Error::other("process handle lock poisoned"))?;
+        match killer.as_mut() {
+            Some(killer) => killer.signal(signal),
+            None => Ok(()),
+        }
+    }
+
     /// Attempts to kill the child and abort helper tasks.
     pub fn terminate(&self) {
         if let Ok(mut killer_opt) = self.killer.lock() {
@@ -9,6 +9,8 @@
 //! - `kill_process_group_by_pid` targets the whole group (children/grandchildren)
 //! - `kill_process_group` targets a known process group ID directly
 //!   instead of a single PID.
+//! - `signal_process_group` delivers `SIGINT`/`SIGTERM` to a process group so
+//!   the process can shut down on its own terms.
 //! - `set_parent_death_signal` (Linux only) arranges for the child to receive a
 //!   `SIGTERM` when the parent exits, and re-checks the parent PID to avoid
 //!   races during fork/exec.
@@ -19,6 +21,8 @@ use std::io;
 
 use tokio::process::Child;
 
+use crate::process::ProcessSignal;
+
 #[cfg(target_os = "linux")]
 /// Ensure the child receives SIGTERM when the original parent dies.
 ///
@@ -140,6 +144,36 @@ pub fn kill_process_group(_process_group_id: u32) -> io::Result<()> {
     Ok(())
 }
 
+#[cfg(unix)]
+/// Send `signal` to a specific process group ID (best-effort).
+pub fn signal_process_group(process_group_id: u32, signal: ProcessSignal) -> io::Result<()> {
+    use std::io::ErrorKind;
+
+    let signal = match signal {
+        ProcessSignal::Interrupt => libc::SIGINT,
+        ProcessSignal::Terminate => libc::SIGTERM,
+    };
+    let pgid = process_group_id as libc::pid_t;
+    let result = unsafe { libc::killpg(pgid, signal) };
+    if result == -1 {
+        let err = io::Error::last_os_error();
+        if err.kind() != ErrorKind::NotFound {
+            return Err(err);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(not(unix))]
+/// Signals are not supported on non-Unix platforms.
+pub fn signal_process_group(_process_group_id: u32, _signal: ProcessSignal) -> io::Result<()> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "process signals are only supported on Unix",
+    ))
+}
+
 #[cfg(unix)]
 /// Kill the process group for a tokio child (best-effort).
 pub fn kill_child_process_group(child: &mut Child) -> io::Result<()> {
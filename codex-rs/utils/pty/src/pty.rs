@@ -18,6 +18,7 @@ use tokio::task::JoinHandle;
 
 use crate::process::ChildTerminator;
 use crate::process::ProcessHandle;
+use crate::process::ProcessSignal;
 use crate::process::PtyHandles;
 use crate::process::SpawnedProcess;
 
@@ -35,12 +36,26 @@ pub fn conpty_supported() -> bool {
 
 struct PtyChildTerminator {
     killer: Box<dyn portable_pty::ChildKiller + Send + Sync>,
+    /// The child leads its own session, so its pid is also its process group id.
+    process_group_id: Option<u32>,
 }
 
 impl ChildTerminator for PtyChildTerminator {
     fn kill(&mut self) -> std::io::Result<()> {
         self.killer.kill()
     }
+
+    fn signal(&mut self, signal: ProcessSignal) -> std::io::Result<()> {
+        match self.process_group_id {
+            Some(process_group_id) => {
+                crate::process_group::signal_process_group(process_group_id, signal)
+            }
+            None => Err(std::io::Error::new(
+                ErrorKind::Unsupported,
+                "process id of the PTY child is unknown",
+            )),
+        }
+    }
 }
 
 fn platform_native_pty_system() -> Box<dyn portable_pty::PtySystem + Send> {
@@ -87,6 +102,7 @@ pub async fn spawn_process(
 
     let mut child = pair.slave.spawn_command(command_builder)?;
     let killer = child.clone_killer();
+    let process_group_id = if cfg!(unix) { child.process_id() } else { None };
 
     let (writer_tx, mut writer_rx) = mpsc::channel::<Vec<u8>>(128);
     let (output_tx, _) = broadcast::channel::<Vec<u8>>(256);
@@ -156,7 +172,10 @@ pub async fn spawn_process(
         writer_tx,
         output_tx,
         initial_output_rx,
-        Box::new(PtyChildTerminator { killer }),
+        Box::new(PtyChildTerminator {
+            killer,
+            process_group_id,
+        }),
         reader_handle,
         Vec::new(),
         writer_handle,
@@ -285,3 +285,38 @@ async fn pipe_terminate_aborts_detached_readers() -> anyhow::Result<()> {
         ),
     }
 }
+
+#[cfg(unix)]
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn signal_terminate_stops_pipe_and_pty_processes() -> anyhow::Result<()> {
+    let env_map: HashMap<String, String> = std::env::vars().collect();
+    let (program, args) = shell_command(
+        "trap 'echo stopping; exit 3' TERM; echo ready; while :; do sleep 0.05; done",
+    );
+
+    for use_pty in [false, true] {
+        let mut spawned = if use_pty {
+            spawn_pty_process(&program, &args, Path::new("."), &env_map, &None).await?
+        } else {
+            spawn_pipe_process(&program, &args, Path::new("."), &env_map, &None).await?
+        };
+        let _ = tokio::time::timeout(
+            tokio::time::Duration::from_millis(2_000),
+            spawned.output_rx.recv(),
+        )
+        .await
+        .map_err(|_| anyhow::anyhow!("expected output before signalling"))??;
+
+        let output_rx = spawned.session.output_receiver();
+        spawned.session.signal(crate::ProcessSignal::Terminate)?;
+        let (output, code) = collect_output_until_exit(output_rx, spawned.exit_rx, 5_000).await;
+
+        assert_eq!(code, 3, "use_pty={use_pty}");
+        assert!(
+            String::from_utf8_lossy(&output).contains("stopping"),
+            "use_pty={use_pty}"
+        );
+    }
+
+    Ok(())
+}
@@ -162,6 +162,7 @@ pub fn build_cli(
 	    skip_git_repo_check: options.skip_git_repo_check,
 	    add_dir,
 	    ephemeral: options.ephemeral.unwrap_or(false),
+	    worktree: false,
 	    output_schema: schema_path,
 	    config_overrides: CliConfigOverrides { raw_overrides },
 	    input_items: options.input_items.clone(),
@@ -174,6 +175,7 @@ pub fn build_cli(
 	    color: Color::Never,
 	    json: false,
 	    last_message_file: None,
+	    script: None,
 	    prompt: if options.thread_id.is_some() {
 	      None
     } else {